@@ -1,11 +1,12 @@
 use itertools::Itertools;
-use letters::{create_letter_group_function, LetterSequence, LetterSet};
+use letters::LetterSet;
 use rayon::iter::{ParallelBridge, ParallelIterator};
+use solver::control::{CancellationToken, SearchControl};
+use solver::{CandidateArena, Solver};
 use std::{
   str::{self},
   sync::RwLock,
 };
-use word_list::WORDS;
 
 /// The set of vowels always included in the letter pool.
 static VOWELS: &[u8] = b"AEIOU";
@@ -49,93 +50,43 @@ struct SequenceComboFilter {
   side_sets: [LetterSet; 4],
   // The sequence of 12 letters.
   sequence: [u8; 12],
-  // The letter pool from which to construct the sequence.
-  letter_pool: [u8; 12],
+  // The letters that have not yet been assigned to a side.
+  letter_pool: LetterSet,
 }
 
 impl SequenceComboFilter {
-  /// Creates a new [`SequenceComboFilter`] by copying the first 12 letters from `letter_pool`.
+  /// Creates a new [`SequenceComboFilter`] with every letter of `letter_pool` unassigned.
   fn new(letter_pool: &[u8]) -> Self {
-    let mut combo_filter = Self::default();
-    letter_pool
-      .iter()
-      .zip(combo_filter.letter_pool.iter_mut())
-      .for_each(|(lhs, rhs)| {
-        *rhs = *lhs;
-      });
-    combo_filter
+    Self {
+      letter_pool: LetterSet::from_ascii_slice(letter_pool),
+      ..Self::default()
+    }
   }
 
-  /// Assigns the first 3-letter `letter_set` to the first subset (index 0)
-  /// and copies those letters into the front of `sequence`, zeroing them out in `letter_pool`.
-  fn with_side1(mut self, letter_set: LetterSet) -> Self {
+  /// Assigns a 3-letter `letter_set` to the subset at index `side`, copies those letters
+  /// to `sequence[3 * side..3 * side + 3]`, and removes them from the `letter_pool`.
+  fn with_side(mut self, side: usize, letter_set: LetterSet) -> Self {
     debug_assert!(letter_set.len() == 3);
-    self.side_sets[0] = letter_set;
+    debug_assert!(letter_set.is_subset(self.letter_pool));
+    self.side_sets[side] = letter_set;
 
-    let mut index = 0;
-    self.letter_pool.iter_mut().for_each(|letter| {
-      if letter_set.has_ascii(*letter) {
-        self.sequence[index] = *letter;
-        index += 1;
-        *letter = 0;
-      }
-    });
+    self.sequence[3 * side..3 * side + 3]
+      .iter_mut()
+      .zip(letter_set.ascii_bytes())
+      .for_each(|(slot, letter)| *slot = letter);
+    self.letter_pool -= letter_set;
 
     self
   }
 
-  /// Assigns a 3-letter `letter_set` to the second subset (index 1)
-  /// and copies those letters to `sequence[3..6]`.
-  fn with_side2(mut self, letter_set: LetterSet) -> Self {
-    debug_assert!(letter_set.len() == 3);
-    self.side_sets[1] = letter_set;
-
-    let mut index = 3;
-    self.letter_pool.iter_mut().for_each(|letter| {
-      if letter_set.has_ascii(*letter) {
-        self.sequence[index] = *letter;
-        index += 1;
-        *letter = 0;
-      }
-    });
-
-    self
-  }
-
-  /// Assigns a 3-letter `letter_set` to the third subset (index 2)
-  /// and copies those letters to `sequence[6..9]`.
-  fn with_side3(mut self, letter_set: LetterSet) -> Self {
-    debug_assert!(letter_set.len() == 3);
-    self.side_sets[2] = letter_set;
-
-    let mut index = 6;
-    self.letter_pool.iter_mut().for_each(|letter| {
-      if letter_set.has_ascii(*letter) {
-        self.sequence[index] = *letter;
-        index += 1;
-        *letter = 0;
-      }
-    });
-
-    self
-  }
-
-  /// Assigns a 3-letter `letter_set` to the fourth subset (index 3)
-  /// and copies those letters to `sequence[9..12]`.
-  fn with_side4(mut self, letter_set: LetterSet) -> Self {
-    debug_assert!(letter_set.len() == 3);
-    self.side_sets[3] = letter_set;
-
-    let mut index = 9;
-    self.letter_pool.iter_mut().for_each(|letter| {
-      if letter_set.has_ascii(*letter) {
-        self.sequence[index] = *letter;
-        index += 1;
-        *letter = 0;
-      }
-    });
-
+  /// Returns every way to assign three of the remaining letters in the `letter_pool`
+  /// to the subset at index `side`.
+  fn with_each_side(self, side: usize) -> impl Iterator<Item = Self> {
     self
+      .letter_pool
+      .ascii_bytes()
+      .array_combinations::<3>()
+      .map(move |letters| self.with_side(side, LetterSet::from_ascii_slice(&letters)))
   }
 }
 
@@ -201,58 +152,20 @@ fn sequences_with_all_vowels() -> impl Iterator<Item = Vec<u8>> {
 /// 4. Chooses 3 letters for `side4`, storing them in `self.sequence[9..12]`.
 /// 5. Uses sorting + dedup to ensure uniqueness when the same sets are chosen in different orders.
 fn all_inputs_from_sequence(sequence: &[u8]) -> impl Iterator<Item = SequenceComboFilter> + '_ {
-  let one_side = sequence
-    .iter()
-    .copied()
-    .array_combinations::<3>()
-    .map(move |side1| {
-      let letter_set = LetterSet::from_ascii_slice(side1.as_slice());
-      let combo_filter = SequenceComboFilter::new(sequence);
-      combo_filter.with_side1(letter_set)
-    });
+  let one_side = SequenceComboFilter::new(sequence).with_each_side(0);
 
   let two_sides = one_side
-    .flat_map(|combo_filter| {
-      combo_filter
-        .letter_pool
-        .into_iter()
-        .filter(|&letter| letter != 0)
-        .array_combinations::<3>()
-        .map(move |side2| {
-          let letter_seq = LetterSet::from_ascii_slice(&side2);
-          combo_filter.with_side2(letter_seq)
-        })
-    })
+    .flat_map(|combo_filter| combo_filter.with_each_side(1))
     .sorted()
     .dedup();
 
   let three_sides = two_sides
-    .flat_map(|combo_filter| {
-      combo_filter
-        .letter_pool
-        .into_iter()
-        .filter(|&letter| letter != 0)
-        .array_combinations::<3>()
-        .map(move |side3| {
-          let letter_seq = LetterSet::from_ascii_slice(&side3);
-          combo_filter.with_side3(letter_seq)
-        })
-    })
+    .flat_map(|combo_filter| combo_filter.with_each_side(2))
     .sorted()
     .dedup();
 
   three_sides
-    .flat_map(|combo_filter| {
-      combo_filter
-        .letter_pool
-        .into_iter()
-        .filter(|&letter| letter != 0)
-        .array_combinations::<3>()
-        .map(move |side4| {
-          let letter_seq = LetterSet::from_ascii_slice(&side4);
-          combo_filter.with_side4(letter_seq)
-        })
-    })
+    .flat_map(|combo_filter| combo_filter.with_each_side(3))
     .sorted()
     .dedup()
 }
@@ -261,6 +174,14 @@ fn main() {
   let max_count = RwLock::new(0);
   let solved_count = RwLock::new(0);
 
+  // Interrupting the analysis stops every search in progress, so that a summary can be printed.
+  let token = CancellationToken::new();
+  ctrlc::set_handler({
+    let token = token.clone();
+    move || token.cancel()
+  })
+  .expect("The interrupt handler should only be set once.");
+
   // Generate sequences that definitely include all vowels,
   // then for each sequence, generate all ways to split into four three-letter subsets.
   sequences_with_all_vowels()
@@ -268,24 +189,29 @@ fn main() {
     .enumerate()
     .par_bridge()
     .for_each(|(n, combo_filter)| {
+      if token.is_cancelled() {
+        return;
+      }
+
       // Convert the 12-letter sequence to a &str (without re-checking UTF-8 validity).
       let input = unsafe { str::from_utf8_unchecked(combo_filter.sequence.as_slice()) };
 
-      // Create a letter group representation for verifying words.
-      let letter_group = create_letter_group_function!(input);
+      // Filter the global word list to only those valid for the chosen input.
+      let valid_words = &solver::valid_words(input);
 
-      // Filter the global WORDS list to only those valid for the chosen letter group.
-      let valid_words = &WORDS
-        .iter()
-        .copied()
-        .filter(|word| word.is_valid_word(&letter_group))
-        .collect::<Vec<_>>();
-
-      let mut solution_count = 0;
+      let arena = &mut CandidateArena::with_capacity(valid_words.len());
+      let control = SearchControl::new().with_cancellation_token(token.clone());
+      let mut solution_count = 0_usize;
 
       // Check how many valid ways exist to build up a 12-letter partition from these words.
       for &word in valid_words {
-        solve_partition_once(word, &mut solution_count, valid_words);
+        if Solver::default()
+          .solve_controlled(arena, word, &mut solution_count, valid_words, &control)
+          .is_break()
+        {
+          // The count for this input is incomplete, so it is not recorded.
+          return;
+        }
       }
 
       // Update the total solved count.
@@ -304,60 +230,12 @@ fn main() {
         );
       }
     });
-}
-
-fn solve_partition_once(
-  sequence: LetterSequence,
-  solution_count: &mut u32,
-  valid_words: &[LetterSequence],
-) {
-  match sequence.len() {
-    12 => *solution_count += 1,
-    11 => {}
-    _ => {
-      let (appendable_words, remaining_valid_words) = valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.shared_letter_count(sequence) <= 1)
-        .partition::<Vec<_>, _>(|word| word.can_append_to(sequence));
 
-      appendable_words.iter().copied().for_each(|word| {
-        solve_filter(
-          word.append_to(sequence),
-          solution_count,
-          &remaining_valid_words,
-        );
-      });
-    }
-  }
-}
-
-fn solve_filter(
-  sequence: LetterSequence,
-  solution_count: &mut u32,
-  valid_words: &[LetterSequence],
-) {
-  match sequence.len() {
-    12 => *solution_count += 1,
-    11 => {}
-    _ => {
-      let remaining_valid_words = valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.shared_letter_count(sequence) <= 1)
-        .collect::<Vec<_>>();
-
-      remaining_valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.can_append_to(sequence))
-        .for_each(|word| {
-          solve_filter(
-            word.append_to(sequence),
-            solution_count,
-            &remaining_valid_words,
-          );
-        });
-    }
+  if token.is_cancelled() {
+    println!(
+      "Stopped after solving {} inputs, with a maximum of {} solutions.",
+      *solved_count.read().unwrap(),
+      *max_count.read().unwrap()
+    );
   }
 }
@@ -0,0 +1,71 @@
+//! Benchmarks each strategy on samples of the historical puzzle archive, from its sparsest boards
+//! to its densest, along with solving the whole archive at once.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+use letrboxd_benchmarks::corpus::{Board, Corpus};
+use letrboxd_benchmarks::{solve_filter_only, solve_partition, solve_partition_once};
+use letters::LetterSequence;
+use solver::{CandidateArena, Solver};
+use std::time::Duration;
+
+/// The count of boards in each of the sparse, median and dense samples.
+const SAMPLE_BOARD_COUNT: usize = 16;
+
+fn solve_arena(
+  arena: &mut CandidateArena,
+) -> impl FnMut(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]) + '_ {
+  |word, solutions, valid_words| Solver::default().solve_in(arena, word, solutions, valid_words)
+}
+
+/// Benchmarks every strategy on the `boards` in a group with the given `name`.
+fn bench_boards(c: &mut Criterion, name: &str, boards: &[Board]) {
+  let mut group = c.benchmark_group(name);
+  let arena = &mut CandidateArena::default();
+
+  group.bench_function("filter_only", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(boards), solve_filter_only));
+  });
+  group.bench_function("partition", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(boards), solve_partition));
+  });
+  group.bench_function("partition_once", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(boards), solve_partition_once));
+  });
+  group.bench_function("coverage", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(boards), solver::solve));
+  });
+  group.bench_function("arena", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(boards), solve_arena(arena)));
+  });
+
+  group.finish();
+}
+
+fn bench_corpus(c: &mut Criterion) {
+  let corpus = Corpus::load();
+
+  bench_boards(c, "Corpus Sparse", corpus.sparse(SAMPLE_BOARD_COUNT));
+  bench_boards(c, "Corpus Median", corpus.median(SAMPLE_BOARD_COUNT));
+  bench_boards(c, "Corpus Dense", corpus.dense(SAMPLE_BOARD_COUNT));
+
+  // Solving the whole archive takes tens of seconds, so only the fastest strategies are
+  // measured, with the fewest samples that criterion allows.
+  let mut group = c.benchmark_group("Corpus Archive");
+  group
+    .sampling_mode(SamplingMode::Flat)
+    .sample_size(10)
+    .measurement_time(Duration::from_mins(10));
+  let arena = &mut CandidateArena::default();
+
+  group.bench_function("coverage", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(corpus.boards()), solver::solve));
+  });
+  group.bench_function("arena", |b| {
+    b.iter(|| Corpus::count_solutions(black_box(corpus.boards()), solve_arena(arena)));
+  });
+
+  group.finish();
+}
+
+criterion_group!(benches, bench_corpus);
+criterion_main!(benches);
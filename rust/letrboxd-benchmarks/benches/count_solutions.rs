@@ -1,10 +1,39 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use letrboxd_benchmarks::{
-  count_solutions, solve_filter_only, solve_partition, solve_partition_once, TEST_INPUT,
+  count_allocations, count_solutions, solve_filter_only, solve_partition, solve_partition_once,
+  CountingAllocator, TEST_INPUT,
 };
+use letters::LetterSequence;
+use solver::{CandidateArena, Solver};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Reports the number of heap allocations it takes to count the solutions with `solve`,
+/// so that it can be read alongside the timing results.
+fn report_allocations<F>(name: &str, solve: F)
+where
+  F: FnMut(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]),
+{
+  let (_, allocations) = count_allocations(|| count_solutions(TEST_INPUT, solve));
+  println!("{name}: {allocations} allocations");
+}
+
+fn solve_arena(
+  arena: &mut CandidateArena,
+) -> impl FnMut(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]) + '_ {
+  |word, solutions, valid_words| Solver::default().solve_in(arena, word, solutions, valid_words)
+}
 
 fn bench_count_solutions(c: &mut Criterion) {
   let mut group = c.benchmark_group("LetrBoxd Count Solutions");
+  let arena = &mut CandidateArena::default();
+
+  report_allocations("filter_only", solve_filter_only);
+  report_allocations("partition", solve_partition);
+  report_allocations("partition_once", solve_partition_once);
+  report_allocations("coverage", solver::solve);
+  report_allocations("arena", solve_arena(arena));
 
   group.bench_function("filter_only", |b| {
     b.iter(|| count_solutions(black_box(TEST_INPUT), black_box(solve_filter_only)));
@@ -15,6 +44,12 @@ fn bench_count_solutions(c: &mut Criterion) {
   group.bench_function("partition_once", |b| {
     b.iter(|| count_solutions(black_box(TEST_INPUT), black_box(solve_partition_once)));
   });
+  group.bench_function("coverage", |b| {
+    b.iter(|| count_solutions(black_box(TEST_INPUT), black_box(solver::solve)));
+  });
+  group.bench_function("arena", |b| {
+    b.iter(|| count_solutions(black_box(TEST_INPUT), black_box(solve_arena(arena))));
+  });
 
   group.finish();
 }
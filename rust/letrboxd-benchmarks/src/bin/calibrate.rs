@@ -0,0 +1,87 @@
+//! Finds the [`Solver`] partition threshold that solves a sample of the historical
+//! puzzle archive the fastest.
+//!
+//! ```text
+//! cargo run --release --package letrboxd-benchmarks --bin calibrate [SAMPLE_SIZE]
+//! ```
+
+use letrboxd_benchmarks::archive_inputs;
+use solver::Solver;
+use std::env;
+use std::time::{Duration, Instant};
+
+/// The number of archived boards to solve for each threshold when no sample size is given.
+const DEFAULT_SAMPLE_SIZE: usize = 100;
+
+/// The number of times to solve the sample for each threshold, keeping the fastest time.
+const ROUNDS: usize = 3;
+
+/// The partition thresholds to compare, from always partitioning to always filtering.
+const THRESHOLDS: &[usize] = &[0, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, usize::MAX];
+
+/// Solves every input with the given `solver`, returning the total solution count and elapsed time.
+fn solve_all(
+  solver: Solver,
+  inputs: &[(String, Vec<letters::LetterSequence>)],
+) -> (usize, Duration) {
+  let start = Instant::now();
+  let mut solution_count = 0;
+
+  for (_, valid_words) in inputs {
+    solver.solve_all(valid_words, &mut solution_count);
+  }
+
+  (solution_count, start.elapsed())
+}
+
+fn main() {
+  let sample_size = env::args().nth(1).map_or(DEFAULT_SAMPLE_SIZE, |arg| {
+    arg.parse().expect("The sample size should be a number.")
+  });
+
+  let archive = archive_inputs();
+  let step = (archive.len() / sample_size.max(1)).max(1);
+  let inputs = archive
+    .into_iter()
+    .step_by(step)
+    .take(sample_size)
+    .map(|input| {
+      let valid_words = solver::valid_words(&input);
+      (input, valid_words)
+    })
+    .collect::<Vec<_>>();
+
+  println!("Calibrating across {} archived boards", inputs.len());
+
+  let mut expected_count = None;
+  let mut fastest = vec![Duration::MAX; THRESHOLDS.len()];
+
+  // Interleave the thresholds within each round so that any drift in the
+  // machine's performance affects every threshold equally.
+  for _ in 0..ROUNDS {
+    for (&threshold, fastest) in THRESHOLDS.iter().zip(fastest.iter_mut()) {
+      let solver = Solver::default().with_partition_threshold(threshold);
+      let (solution_count, elapsed) = solve_all(solver, &inputs);
+
+      assert_eq!(
+        *expected_count.get_or_insert(solution_count),
+        solution_count,
+        "Every threshold should find the same solutions.",
+      );
+
+      *fastest = elapsed.min(*fastest);
+    }
+  }
+
+  let mut best = (usize::MAX, Duration::MAX);
+
+  for (&threshold, &elapsed) in THRESHOLDS.iter().zip(&fastest) {
+    println!("threshold {threshold:>20}: {elapsed:>12.3?}");
+
+    if elapsed < best.1 {
+      best = (threshold, elapsed);
+    }
+  }
+
+  println!("\nBest partition threshold: {} ({:.3?})", best.0, best.1);
+}
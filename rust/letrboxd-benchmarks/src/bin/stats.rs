@@ -0,0 +1,102 @@
+//! Compares the work done by each search strategy on the given boards, printing a JSON report
+//! of the statistics recorded by the `stats` feature of the solver.
+//!
+//! ```text
+//! cargo run --release --package letrboxd-benchmarks --features stats --bin stats [BOARD...]
+//! ```
+
+use letrboxd_benchmarks::{
+  count_solutions, solve_filter_only, solve_partition, solve_partition_once, TEST_INPUT,
+};
+use letters::LetterSequence;
+use serde_json::{json, Value};
+use solver::stats::{self, SearchStats};
+use std::env;
+
+/// The count of the slowest starting words to include for each strategy.
+const SLOWEST_COUNT: usize = 10;
+
+/// A search strategy, called once for each starting word.
+type Strategy = fn(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]);
+
+/// The search strategies to compare, by name, along with whether each one already records the
+/// time spent on each starting word, as the solver does.
+const STRATEGIES: &[(&str, Strategy, bool)] = &[
+  ("filter_only", solve_filter_only, false),
+  ("partition", solve_partition, false),
+  ("partition_once", solve_partition_once, false),
+  ("coverage", solver::solve, true),
+];
+
+/// Solves the `input` with the `strategy`, returning the statistics that it recorded.
+fn record_stats(input: &str, strategy: Strategy, times_starting_words: bool) -> SearchStats {
+  let _ = stats::take();
+
+  count_solutions(input, |word, solutions, valid_words| {
+    if times_starting_words {
+      strategy(word, solutions, valid_words);
+    } else {
+      stats::time_starting_word(word, || strategy(word, solutions, valid_words));
+    }
+  });
+
+  stats::take()
+}
+
+/// Returns the JSON representation of the `stats`.
+fn stats_json(stats: &SearchStats) -> Value {
+  let mut slowest = stats.starting_word_times.iter().collect::<Vec<_>>();
+  slowest.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
+
+  json!({
+    "nodesExpanded": stats.nodes_expanded,
+    "solutions": stats.solutions,
+    "pruned": {
+      "deadEnd": stats.pruned_dead_end,
+      "coverage": stats.pruned_coverage,
+      "noAppendableWords": stats.pruned_no_appendable_words,
+      "filter": stats.pruned_filter,
+    },
+    "searchTimeMs": stats.total_time().as_secs_f64() * 1000.0,
+    "depths": stats.depths.iter().enumerate().map(|(index, depth)| json!({
+      "depth": index + 1,
+      "nodesExpanded": depth.nodes_expanded,
+      "meanCandidates": depth.mean_candidates(),
+      "maxCandidates": depth.max_candidates,
+      "meanAppendable": depth.mean_appendable(),
+    })).collect::<Vec<_>>(),
+    "slowestStartingWords": slowest.into_iter().take(SLOWEST_COUNT).map(|(word, time)| json!({
+      "word": word,
+      "timeMs": time.as_secs_f64() * 1000.0,
+    })).collect::<Vec<_>>(),
+  })
+}
+
+fn main() {
+  let mut inputs = env::args().skip(1).collect::<Vec<_>>();
+  if inputs.is_empty() {
+    inputs.push(TEST_INPUT.to_owned());
+  }
+
+  let report = inputs
+    .iter()
+    .map(|input| {
+      solver::board::validate(input).unwrap_or_else(|error| panic!("{input}: {error}"));
+
+      let strategies = STRATEGIES
+        .iter()
+        .map(|&(name, strategy, times_starting_words)| {
+          let stats = record_stats(input, strategy, times_starting_words);
+          (name.to_owned(), stats_json(&stats))
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+      json!({ "input": input, "strategies": strategies })
+    })
+    .collect::<Vec<_>>();
+
+  println!(
+    "{}",
+    serde_json::to_string_pretty(&report).expect("The report should serialize to JSON.")
+  );
+}
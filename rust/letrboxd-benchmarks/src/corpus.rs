@@ -0,0 +1,110 @@
+//! Defines a benchmark corpus of every historical puzzle in the site's archive, ordered from the
+//! sparsest board to the densest, so that optimizations can be judged on the boards that the site
+//! actually serves rather than on a single unusually dense board.
+
+use crate::archive_inputs;
+use letters::LetterSequence;
+
+/// A single archived board, along with the words that are valid for it.
+#[derive(Debug, Clone)]
+pub struct Board {
+  /// The 12 letters of the board, given side by side.
+  pub input: String,
+  /// Every word that is valid for the board.
+  pub valid_words: Vec<LetterSequence>,
+  /// The estimated cost of searching for every solution to the board, which orders the corpus.
+  pub estimated_cost: u64,
+}
+
+impl Board {
+  /// Returns the [`Board`] for the `input`, estimating the cost of solving it.
+  #[must_use]
+  pub fn new(input: String) -> Self {
+    let valid_words = solver::valid_words(&input);
+    let estimated_cost = solver::cost::estimate_costs(&valid_words).iter().sum();
+
+    Self {
+      input,
+      valid_words,
+      estimated_cost,
+    }
+  }
+
+  /// Counts the solutions to this board by calling `solve` once for each starting word.
+  pub fn count_solutions<F>(&self, mut solve: F) -> usize
+  where
+    F: FnMut(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]),
+  {
+    let solutions = &mut Vec::new();
+
+    for &word in &self.valid_words {
+      solve(word, solutions, &self.valid_words);
+    }
+
+    solutions.len()
+  }
+}
+
+/// Every board in the site's archive, ordered by their estimated cost from sparsest to densest.
+#[derive(Debug, Clone)]
+pub struct Corpus {
+  boards: Vec<Board>,
+}
+
+impl Corpus {
+  /// Loads every board in the site's archive.
+  #[must_use]
+  pub fn load() -> Self {
+    Self::from_inputs(archive_inputs())
+  }
+
+  /// Returns the corpus of the boards for the `inputs`.
+  #[must_use]
+  pub fn from_inputs(inputs: impl IntoIterator<Item = String>) -> Self {
+    let mut boards = inputs.into_iter().map(Board::new).collect::<Vec<_>>();
+
+    // Ties are broken by input so that the order never depends on the archive's dates.
+    boards
+      .sort_by(|lhs, rhs| (lhs.estimated_cost, &lhs.input).cmp(&(rhs.estimated_cost, &rhs.input)));
+
+    Self { boards }
+  }
+
+  /// Returns every board, from sparsest to densest.
+  #[must_use]
+  pub fn boards(&self) -> &[Board] {
+    &self.boards
+  }
+
+  /// Returns the `count` sparsest boards.
+  #[must_use]
+  pub fn sparse(&self, count: usize) -> &[Board] {
+    &self.boards[..count.min(self.boards.len())]
+  }
+
+  /// Returns the `count` boards surrounding the median board.
+  #[must_use]
+  pub fn median(&self, count: usize) -> &[Board] {
+    let count = count.min(self.boards.len());
+    let start = (self.boards.len() - count) / 2;
+    &self.boards[start..start + count]
+  }
+
+  /// Returns the `count` densest boards.
+  #[must_use]
+  pub fn dense(&self, count: usize) -> &[Board] {
+    &self.boards[self.boards.len() - count.min(self.boards.len())..]
+  }
+
+  /// Counts the solutions to every board in `boards` by calling `solve` once for each
+  /// starting word of each board.
+  pub fn count_solutions<F>(boards: &[Board], mut solve: F) -> usize
+  where
+    F: FnMut(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]),
+  {
+    boards
+      .iter()
+      .map(|board| board.count_solutions(&mut solve))
+      .sum()
+  }
+}
@@ -1,19 +1,80 @@
-use letters::{create_letter_group_function, LetterSequence};
+pub mod corpus;
+
+use letters::{Adjacency, LetterSequence};
+use solver::stats::{self, PruneReason};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use word_list::WORDS;
 
 pub const TEST_INPUT: &str = "EIONRSTDGLAU";
 pub const TEST_INPUT_SOLUTION_COUNT: usize = 351_535;
 
-pub fn count_solutions<F>(input: &str, solve: F) -> usize
+/// The archive of historical puzzle inputs served by the site, keyed by date.
+static INPUTS_BY_DATE: &str = include_str!(concat!(
+  env!("CARGO_MANIFEST_DIR"),
+  "/../../site/generated/json/inputsByDate.json"
+));
+
+/// Returns every historical puzzle input from the site's archive, ordered by date.
+///
+/// # Panics
+///
+/// Panics if the archive is not a JSON object mapping dates to inputs.
+#[must_use]
+pub fn archive_inputs() -> Vec<String> {
+  serde_json::from_str::<BTreeMap<String, String>>(INPUTS_BY_DATE)
+    .expect("The archive should map dates to puzzle inputs.")
+    .into_values()
+    .collect()
+}
+
+/// The number of heap allocations made through the [`CountingAllocator`].
+static ALLOCATION_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that counts every heap allocation before delegating to the [`System`] allocator.
+///
+/// A benchmark binary must install it with `#[global_allocator]` for [`count_allocations`] to
+/// observe any allocations.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    System.alloc(layout)
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+  }
+
+  unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    ALLOCATION_COUNT.fetch_add(1, Ordering::Relaxed);
+    System.realloc(ptr, layout, new_size)
+  }
+}
+
+/// Runs `f`, returning its result along with the number of heap allocations it made.
+///
+/// Allocations are only counted when the [`CountingAllocator`] is the global allocator.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+  let before = ALLOCATION_COUNT.load(Ordering::Relaxed);
+  let result = f();
+  let after = ALLOCATION_COUNT.load(Ordering::Relaxed);
+
+  (result, after - before)
+}
+
+pub fn count_solutions<F>(input: &str, mut solve: F) -> usize
 where
-  F: Fn(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]),
+  F: FnMut(LetterSequence, &mut Vec<LetterSequence>, &[LetterSequence]),
 {
-  let letter_group = create_letter_group_function!(input);
+  let adjacency = Adjacency::standard(input);
 
   let valid_words = &WORDS
     .iter()
     .copied()
-    .filter(|word| word.is_valid_word(&letter_group))
+    .filter(|&word| adjacency.is_valid_word(word))
     .collect::<Vec<_>>();
 
   let solutions = &mut Vec::new();
@@ -25,20 +86,56 @@ where
   solutions.len()
 }
 
+/// Records the expansion of a node whose candidates have been filtered, but not partitioned.
+fn record_node(sequence: LetterSequence, candidates: &[LetterSequence]) {
+  if !stats::ENABLED {
+    return;
+  }
+
+  let appendable_count = candidates
+    .iter()
+    .filter(|word| word.can_append_to(sequence))
+    .count();
+  stats::record_node(sequence, candidates.len(), appendable_count);
+  if appendable_count == 0 {
+    stats::record_prune(PruneReason::NoAppendableWords);
+  }
+}
+
+/// Records the expansion of a node whose candidates have been partitioned by whether they can be appended.
+fn record_partition(
+  sequence: LetterSequence,
+  appendable: &[LetterSequence],
+  remaining: &[LetterSequence],
+) {
+  stats::record_node(
+    sequence,
+    appendable.len() + remaining.len(),
+    appendable.len(),
+  );
+  if appendable.is_empty() {
+    stats::record_prune(PruneReason::NoAppendableWords);
+  }
+}
+
 pub fn solve_filter_only(
   sequence: LetterSequence,
   solutions: &mut Vec<LetterSequence>,
   valid_words: &[LetterSequence],
 ) {
   match sequence.len() {
-    12 => solutions.push(sequence),
-    11 => (),
+    12 => {
+      stats::record_solution();
+      solutions.push(sequence);
+    }
+    11 => stats::record_prune(PruneReason::DeadEnd),
     _ => {
       let valid_words = valid_words
         .iter()
         .copied()
         .filter(|word| word.shared_letter_count(sequence) <= 1)
         .collect::<Vec<_>>();
+      record_node(sequence, &valid_words);
       valid_words
         .iter()
         .copied()
@@ -58,17 +155,19 @@ pub fn solve_partition(
   match sequence.len() {
     // If we have constructed a valid sequence with exactly 12 letters, it is a solution.
     12 => {
+      stats::record_solution();
       solutions.push(sequence);
     }
     // An 11-letter sequence cannot form a valid 12-letter solution given
     // that the minimum word length is 3 letters.
-    11 => {}
+    11 => stats::record_prune(PruneReason::DeadEnd),
     _ => {
       let (appendable_words, remaining_valid_words) = valid_words
         .iter()
         .copied()
         .filter(|word| word.shared_letter_count(sequence) <= 1)
         .partition::<Vec<_>, _>(|word| word.can_append_to(sequence));
+      record_partition(sequence, &appendable_words, &remaining_valid_words);
       appendable_words.iter().copied().for_each(|word| {
         solve_partition(word.append_to(sequence), solutions, &remaining_valid_words);
       });
@@ -84,17 +183,19 @@ pub fn solve_partition_once(
   match sequence.len() {
     // If we have constructed a valid sequence with exactly 12 letters, it is a solution.
     12 => {
+      stats::record_solution();
       solutions.push(sequence);
     }
     // An 11-letter sequence cannot form a valid 12-letter solution given
     // that the minimum word length is 3 letters.
-    11 => {}
+    11 => stats::record_prune(PruneReason::DeadEnd),
     _ => {
       let (appendable_words, remaining_valid_words) = valid_words
         .iter()
         .copied()
         .filter(|word| word.shared_letter_count(sequence) <= 1)
         .partition::<Vec<_>, _>(|word| word.can_append_to(sequence));
+      record_partition(sequence, &appendable_words, &remaining_valid_words);
       appendable_words.iter().copied().for_each(|word| {
         solve_filter_only(word.append_to(sequence), solutions, &remaining_valid_words);
       });
@@ -104,6 +205,7 @@ pub fn solve_partition_once(
 
 #[cfg(test)]
 mod test {
+  use crate::corpus::Corpus;
   use crate::*;
   #[test]
   fn filter_only() {
@@ -128,4 +230,57 @@ mod test {
       count_solutions(TEST_INPUT, solve_partition_once),
     );
   }
+
+  #[test]
+  fn coverage() {
+    assert_eq!(
+      TEST_INPUT_SOLUTION_COUNT,
+      count_solutions(TEST_INPUT, solver::solve),
+    );
+  }
+
+  #[test]
+  fn arena() {
+    let arena = &mut solver::CandidateArena::default();
+    assert_eq!(
+      TEST_INPUT_SOLUTION_COUNT,
+      count_solutions(TEST_INPUT, |word, solutions, valid_words| {
+        solver::Solver::default().solve_in(arena, word, solutions, valid_words);
+      }),
+    );
+  }
+
+  #[test]
+  fn corpus() {
+    fn inputs(boards: &[corpus::Board]) -> Vec<&str> {
+      boards.iter().map(|board| board.input.as_str()).collect()
+    }
+
+    let corpus = Corpus::from_inputs(
+      [
+        "EIONRSTDGLAU",
+        "VYQFIGXLUOTE",
+        "OXKASHRIYDCT",
+        "CKYSBDRENOIA",
+        "TIFNCUDHMGRL",
+      ]
+      .map(String::from),
+    );
+
+    assert!(
+      corpus
+        .boards()
+        .is_sorted_by_key(|board| board.estimated_cost),
+      "The boards should be ordered from sparsest to densest.",
+    );
+    assert_eq!(inputs(corpus.sparse(2)), ["VYQFIGXLUOTE", "TIFNCUDHMGRL"]);
+    assert_eq!(inputs(corpus.median(1)), ["OXKASHRIYDCT"]);
+    assert_eq!(inputs(corpus.dense(2)), ["CKYSBDRENOIA", "EIONRSTDGLAU"]);
+    assert_eq!(corpus.dense(10).len(), 5);
+
+    assert_eq!(
+      TEST_INPUT_SOLUTION_COUNT,
+      corpus.dense(1)[0].count_solutions(solver::solve),
+    );
+  }
 }
@@ -0,0 +1,71 @@
+//! Defines the `complete` command, which counts the solutions to every completion of a board
+//! that has `?` wildcards in place of unknown letters.
+
+use clap::Args;
+use indicatif::{ProgressBar, ProgressStyle};
+use rayon::prelude::*;
+use solver::PartialBoard;
+
+/// The arguments for the `complete` command.
+#[derive(Args)]
+pub struct CompleteArgs {
+  /// The 12 letters of the puzzle input with a ? for each unknown letter, e.g. "AI? DGT ESU NP?".
+  input: PartialBoard,
+
+  /// Prints only this many of the completions with the most solutions.
+  #[arg(long)]
+  top: Option<usize>,
+}
+
+/// Counts the solutions to every completion of the board, then prints the completions from the
+/// most solutions to the fewest.
+pub fn run(args: &CompleteArgs) {
+  let completions = args.input.completions();
+
+  // The dictionary is narrowed down once, and each completion filters only the candidates.
+  let candidate_words = &args.input.candidate_words();
+
+  let progress_bar = ProgressBar::new(completions.len() as u64).with_style(
+    ProgressStyle::with_template("{bar:40} {pos}/{len} completions [{elapsed}]")
+      .expect("The progress bar template should be valid."),
+  );
+
+  let mut solution_counts = completions
+    .into_par_iter()
+    .map(|completion| {
+      let valid_words = &solver::valid_words_among(candidate_words, &completion);
+      let mut solution_count = 0_usize;
+
+      solver::solve_all(valid_words, &mut solution_count);
+
+      progress_bar.inc(1);
+      (completion, solution_count)
+    })
+    .collect::<Vec<_>>();
+
+  progress_bar.finish_and_clear();
+
+  let completion_count = solution_counts.len();
+  let solvable_count = solution_counts
+    .iter()
+    .filter(|&&(_, solution_count)| solution_count > 0)
+    .count();
+
+  solution_counts.sort_by(|(lhs_input, lhs_count), (rhs_input, rhs_count)| {
+    rhs_count
+      .cmp(lhs_count)
+      .then_with(|| lhs_input.cmp(rhs_input))
+  });
+
+  for (completion, solution_count) in solution_counts
+    .into_iter()
+    .take(args.top.unwrap_or(usize::MAX))
+  {
+    println!("{completion}\t{solution_count}");
+  }
+
+  println!(
+    "\n\n{completion_count} completions of {}, {solvable_count} with solutions",
+    args.input
+  );
+}
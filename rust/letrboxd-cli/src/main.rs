@@ -1,91 +1,125 @@
-use crossbeam::thread;
-use letters::{create_letter_group_function, LetterSequence};
-use std::env;
-use word_list::WORDS;
+mod complete;
+mod par;
+mod participation;
+mod solve;
+mod stats;
+mod verify_archive;
 
-fn main() {
-  let args = env::args().collect::<Vec<_>>();
-  let input = &args[1];
-  let letter_group = create_letter_group_function!(input);
+use clap::{Args, Parser, Subcommand};
+use complete::CompleteArgs;
+use letters::{Adjacency, AdjacencyRule};
+use par::ParArgs;
+use participation::ParticipationArgs;
+use solve::SolveArgs;
+use stats::StatsArgs;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use verify_archive::VerifyArchiveArgs;
 
-  let valid_words = &WORDS
-    .iter()
-    .copied()
-    .filter(|word| word.is_valid_word(&letter_group))
-    .collect::<Vec<_>>();
+/// Finds every unique-letter solution to a Letter Boxed puzzle.
+///
+/// Solving is the default command, so `letrboxd AIODGTESUNPR` is the same as
+/// `letrboxd solve AIODGTESUNPR`.
+#[derive(Parser)]
+#[command(
+  name = "letrboxd",
+  args_conflicts_with_subcommands = true,
+  subcommand_negates_reqs = true
+)]
+struct Cli {
+  #[command(subcommand)]
+  command: Option<Command>,
 
-  let mut buckets = vec![Vec::new(); num_cpus::get()];
-  let words = valid_words
-    .chunks(valid_words.len() / buckets.len() + 1)
-    .collect::<Vec<_>>();
+  #[command(flatten)]
+  solve: Option<SolveArgs>,
+}
 
-  let _ = thread::scope(|s| {
-    buckets.iter_mut().zip(words).for_each(|(bucket, words)| {
-      s.spawn(move |_| {
-        for &word in words {
-          solve_partition_once(word, bucket, valid_words);
-        }
-      });
-    });
-  });
+/// The commands that letrboxd can run.
+#[derive(Subcommand)]
+enum Command {
+  /// Finds every unique-letter solution to a Letter Boxed puzzle.
+  Solve(Box<SolveArgs>),
+  /// Counts the solutions to every completion of a board with ? in place of unknown letters.
+  Complete(CompleteArgs),
+  /// Explains why a board has few or no solutions.
+  Stats(StatsArgs),
+  /// Tallies the words, starting words, and letters that take part in the most solutions.
+  Participation(ParticipationArgs),
+  /// Finds the fewest words that solve a board when letters may be repeated.
+  Par(ParArgs),
+  /// Re-solves every board in the site's archive and compares the counts against a golden file.
+  VerifyArchive(VerifyArchiveArgs),
+}
 
-  let mut solutions = buckets.into_iter().flatten().collect::<Vec<_>>();
-  let solution_count = solutions.len();
+/// Rejects any input that is not a valid board before the command begins.
+fn parse_board(input: &str) -> Result<String, solver::BoardError> {
+  solver::board::validate(input)?;
+  Ok(input.to_owned())
+}
 
-  solutions.sort_by_key(|solution| solution.word_count());
+/// Parses the name of an [`AdjacencyRule`], e.g. `clockwise`.
+fn parse_rule(name: &str) -> Result<AdjacencyRule, String> {
+  AdjacencyRule::ALL
+    .into_iter()
+    .find(|rule| rule.name().eq_ignore_ascii_case(name))
+    .ok_or_else(|| {
+      let names = AdjacencyRule::ALL.map(AdjacencyRule::name);
+      format!("Expected one of {}.", names.join(", "))
+    })
+}
 
-  for solution in solutions {
-    for word in solution.words() {
-      print!("{word} ");
-    }
-    println!();
-  }
+/// The arguments that decide which letters may follow one another within a word, shared by the
+/// commands that gather valid words.
+#[derive(Args)]
+struct RuleArgs {
+  /// The rule for which letters may follow one another within a word: "standard" for letters
+  /// from different sides, "easy" for any letters, or "clockwise" for a letter from the next
+  /// side clockwise.
+  #[arg(long, value_parser = parse_rule, default_value = "standard")]
+  rule: AdjacencyRule,
 
-  println!("\n\n{solution_count} solutions");
+  /// Reads pairs of letters that may not follow one another in either order, on top of the rule,
+  /// from a file of pairs separated by whitespace or commas, e.g. "QU, TH", with # comments.
+  #[arg(long, value_name = "PATH")]
+  forbidden_pairs: Option<PathBuf>,
 }
 
-fn solve_partition_once(
-  sequence: LetterSequence,
-  solutions: &mut Vec<LetterSequence>,
-  valid_words: &[LetterSequence],
-) {
-  match sequence.len() {
-    12 => solutions.push(sequence),
-    11 => (),
-    _ => {
-      let (appendable_words, remaining_valid_words) = valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.shared_letter_count(sequence) <= 1)
-        .partition::<Vec<_>, _>(|word| word.can_append_to(sequence));
-      appendable_words.iter().copied().for_each(|word| {
-        solve_filter(word.append_to(sequence), solutions, &remaining_valid_words);
-      });
-    }
+impl RuleArgs {
+  /// Reads the forbidden pairs from their file, or returns no pairs if none were given.
+  fn read_forbidden_pairs(&self) -> Result<String, Box<dyn Error>> {
+    let Some(path) = &self.forbidden_pairs else {
+      return Ok(String::new());
+    };
+
+    fs::read_to_string(path)
+      .map_err(|error| format!("Could not read {}: {error}", path.display()).into())
+  }
+
+  /// Returns the [`Adjacency`] of the `input` for the rule and forbidden pairs that were given,
+  /// or an error if the forbidden pairs cannot be read or parsed.
+  fn adjacency(&self, input: &str) -> Result<Adjacency, Box<dyn Error>> {
+    let pairs = self.read_forbidden_pairs()?;
+    Ok(Adjacency::new(input, self.rule).with_forbidden_pairs(&pairs)?)
   }
 }
 
-fn solve_filter(
-  sequence: LetterSequence,
-  solutions: &mut Vec<LetterSequence>,
-  valid_words: &[LetterSequence],
-) {
-  match sequence.len() {
-    12 => solutions.push(sequence),
-    11 => (),
-    _ => {
-      let remaining_valid_words = valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.shared_letter_count(sequence) <= 1)
-        .collect::<Vec<_>>();
-      remaining_valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.can_append_to(sequence))
-        .for_each(|word| {
-          solve_filter(word.append_to(sequence), solutions, &remaining_valid_words);
-        });
-    }
+fn main() -> Result<(), Box<dyn Error>> {
+  let cli = Cli::parse();
+
+  match cli.command {
+    Some(Command::Solve(args)) => solve::run(&args)?,
+    Some(Command::Complete(args)) => complete::run(&args),
+    Some(Command::Stats(args)) => stats::run(&args),
+    Some(Command::Participation(args)) => participation::run(&args),
+    Some(Command::Par(args)) => par::run(&args)?,
+    Some(Command::VerifyArchive(args)) => verify_archive::run(&args)?,
+    None => solve::run(
+      &cli
+        .solve
+        .expect("The solve arguments are required without a command."),
+    )?,
   }
+
+  Ok(())
 }
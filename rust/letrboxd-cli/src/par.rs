@@ -0,0 +1,170 @@
+//! Defines the `par` command, which finds the fewest words that solve a board under the standard
+//! rules, or under another adjacency rule, in which letters may be repeated, and compares it
+//! against the unique-letter solutions under the same rule.
+
+use crate::verify_archive::DEFAULT_ARCHIVE_PATH;
+use crate::RuleArgs;
+use clap::Args;
+use indicatif::{ProgressBar, ProgressStyle};
+use letters::{Adjacency, ParseError};
+use rayon::prelude::*;
+use solver::par::Par;
+use solver::WordCountTally;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+/// The arguments for the `par` command.
+#[derive(Args)]
+pub struct ParArgs {
+  /// The 12 letters of the puzzle input, given side by side, e.g. AIODGTESUNPR.
+  #[arg(value_parser = crate::parse_board, required_unless_present = "archive")]
+  input: Option<String>,
+
+  /// Prints this many example solutions that use the fewest words.
+  #[arg(long, default_value_t = 10)]
+  examples: usize,
+
+  /// Finds the par of every board in an archive of puzzle inputs keyed by date, instead of a
+  /// single board. Defaults to the site's archive.
+  #[arg(long, num_args = 0..=1, default_missing_value = DEFAULT_ARCHIVE_PATH, conflicts_with = "input")]
+  archive: Option<PathBuf>,
+
+  #[command(flatten)]
+  rules: RuleArgs,
+}
+
+/// Returns the unique-letter solution counts under the `adjacency` of a board by word count.
+fn unique_letter_counts(adjacency: &Adjacency) -> WordCountTally {
+  let valid_words = &solver::valid_words_with(adjacency);
+  let mut tally = WordCountTally::default();
+
+  solver::solve_all(valid_words, &mut tally);
+
+  tally
+}
+
+/// Returns the fewest words in any unique-letter solution, or [`None`] if there are none.
+fn fewest_unique_letter_words(tally: &WordCountTally) -> Option<usize> {
+  tally
+    .by_word_count
+    .iter()
+    .position(|&count| count > 0)
+    .map(|index| index + 1)
+}
+
+/// Prints the par of a single board under its `adjacency` with example solutions, followed by its
+/// unique-letter solutions for comparison.
+fn print_board(input: &str, adjacency: &Adjacency, example_count: usize) {
+  let Some(par) = solver::par::par_with(input, adjacency, example_count) else {
+    println!("No sequence of words covers every letter of {input}.");
+    return;
+  };
+
+  for example in &par.examples {
+    println!("{}", example.join(" "));
+  }
+
+  println!(
+    "\n\nPar: {} words, with {} solutions",
+    par.word_count, par.solution_count
+  );
+
+  let tally = unique_letter_counts(adjacency);
+  match fewest_unique_letter_words(&tally) {
+    Some(word_count) => println!(
+      "Unique letters: {} solutions, the shortest in {word_count} words with {} solutions",
+      tally.total(),
+      tally.by_word_count[word_count - 1]
+    ),
+    None => println!("Unique letters: no solutions"),
+  }
+}
+
+/// Finds the par and unique-letter solutions of every board in the archive under the `rules`,
+/// printing one line per board from the newest to the oldest, followed by a summary.
+fn print_archive(archive: &PathBuf, rules: &RuleArgs) -> Result<(), Box<dyn Error>> {
+  let archive = serde_json::from_str::<BTreeMap<String, String>>(&fs::read_to_string(archive)?)?;
+  let pairs = rules.read_forbidden_pairs()?;
+  let adjacencies = archive
+    .values()
+    .map(|input| Adjacency::new(input, rules.rule).with_forbidden_pairs(&pairs))
+    .collect::<Result<Vec<_>, ParseError>>()?;
+
+  let progress_bar = ProgressBar::new(archive.len() as u64).with_style(
+    ProgressStyle::with_template("{bar:40} {pos}/{len} boards [{elapsed}]")
+      .expect("The progress bar template should be valid."),
+  );
+
+  let boards = archive
+    .iter()
+    .zip(&adjacencies)
+    .rev()
+    .collect::<Vec<_>>()
+    .into_par_iter()
+    .map(|((date, input), adjacency)| {
+      let par = solver::par::par_with(input, adjacency, 1);
+      let tally = unique_letter_counts(adjacency);
+      progress_bar.inc(1);
+      (date, input, par, tally)
+    })
+    .collect::<Vec<_>>();
+
+  progress_bar.finish_and_clear();
+
+  println!("Date\tBoard\tPar\tPar solutions\tUnique-letter solutions\tFewest unique-letter words");
+
+  let mut par_histogram = BTreeMap::<Option<usize>, usize>::new();
+  let mut matching_count = 0;
+
+  for (date, input, par, tally) in &boards {
+    let par_word_count = par.as_ref().map(|par: &Par| par.word_count);
+    let fewest_words = fewest_unique_letter_words(tally);
+
+    *par_histogram.entry(par_word_count).or_default() += 1;
+    if par_word_count.is_some() && par_word_count == fewest_words {
+      matching_count += 1;
+    }
+
+    let format_count =
+      |count: Option<usize>| count.map_or("-".to_owned(), |count| count.to_string());
+    println!(
+      "{date}\t{input}\t{}\t{}\t{}\t{}",
+      format_count(par_word_count),
+      par.as_ref().map_or(0, |par| par.solution_count),
+      tally.total(),
+      format_count(fewest_words),
+    );
+  }
+
+  println!();
+  for (par_word_count, board_count) in par_histogram {
+    match par_word_count {
+      Some(word_count) => println!("Par {word_count}: {board_count} boards"),
+      None => println!("No par: {board_count} boards"),
+    }
+  }
+
+  println!(
+    "\n{matching_count} of {} boards have a unique-letter solution in par",
+    boards.len()
+  );
+
+  Ok(())
+}
+
+/// Finds and prints the par of a single board, or of every board in an archive.
+///
+/// # Errors
+///
+/// Returns an error if the archive or the forbidden pairs cannot be read or parsed.
+pub fn run(args: &ParArgs) -> Result<(), Box<dyn Error>> {
+  match (&args.archive, &args.input) {
+    (Some(archive), _) => print_archive(archive, &args.rules)?,
+    (None, Some(input)) => print_board(input, &args.rules.adjacency(input)?, args.examples),
+    (None, None) => unreachable!("The input is required without an archive."),
+  }
+
+  Ok(())
+}
@@ -0,0 +1,174 @@
+//! Defines the `participation` command, which tallies how the words and letters of a board
+//! take part in its solutions.
+
+use clap::Args;
+use letters::LetterSequence;
+use rayon::prelude::*;
+use serde::Serialize;
+use solver::Participation;
+
+/// The arguments for the `participation` command.
+#[derive(Args)]
+pub struct ParticipationArgs {
+  /// The 12 letters of the puzzle input, given side by side, e.g. AIODGTESUNPR.
+  #[arg(value_parser = crate::parse_board)]
+  input: String,
+
+  /// Prints only this many rows of each table.
+  #[arg(long, default_value_t = 20)]
+  top: usize,
+
+  /// Prints the tables as JSON instead of text.
+  #[arg(long)]
+  json: bool,
+}
+
+/// The tables of a [`Participation`], as printed with `--json`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ParticipationJson {
+  input: String,
+  solution_count: u64,
+  words: Vec<WordRow>,
+  first_words: Vec<WordRow>,
+  word_lengths: Vec<WordLengthsRow>,
+  junction_letters: Vec<LetterRow>,
+}
+
+/// A word and the count of solutions that it takes part in.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WordRow {
+  word: String,
+  solution_count: u64,
+}
+
+/// The length of each word in order, and the count of solutions with those lengths.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WordLengthsRow {
+  lengths: Vec<usize>,
+  solution_count: u64,
+}
+
+/// A letter and the count of solutions in which it joins two words.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LetterRow {
+  letter: char,
+  solution_count: u64,
+}
+
+impl ParticipationJson {
+  /// Returns the first `top` rows of each table of the `participation`.
+  fn new(input: &str, participation: &Participation, top: usize) -> Self {
+    let word_rows = |words: Vec<(LetterSequence, u64)>| {
+      words
+        .into_iter()
+        .take(top)
+        .map(|(word, solution_count)| WordRow {
+          word: word.to_string(),
+          solution_count,
+        })
+        .collect()
+    };
+
+    Self {
+      input: input.to_owned(),
+      solution_count: participation.solution_count,
+      words: word_rows(participation.words()),
+      first_words: word_rows(participation.first_words()),
+      word_lengths: participation
+        .word_lengths()
+        .into_iter()
+        .take(top)
+        .map(|(lengths, solution_count)| WordLengthsRow {
+          lengths,
+          solution_count,
+        })
+        .collect(),
+      junction_letters: participation
+        .junction_letters()
+        .into_iter()
+        .take(top)
+        .map(|(letter, solution_count)| LetterRow {
+          letter: letter as char,
+          solution_count,
+        })
+        .collect(),
+    }
+  }
+}
+
+/// Prints a table with a `title`, and a row for each label with its count of solutions.
+fn print_table(title: &str, rows: impl IntoIterator<Item = (String, u64)>) {
+  println!("\n{title}");
+  for (label, solution_count) in rows {
+    println!("  {label:<16}{solution_count:>10}");
+  }
+}
+
+/// Solves the board, then prints the words, starting words, word lengths, and junction letters
+/// that take part in the most solutions.
+pub fn run(args: &ParticipationArgs) {
+  let valid_words = &solver::valid_words(&args.input);
+
+  let participation = valid_words
+    .par_iter()
+    .fold(Participation::default, |mut participation, &word| {
+      solver::solve(word, &mut participation, valid_words);
+      participation
+    })
+    .reduce(Participation::default, |mut lhs, rhs| {
+      lhs.merge(&rhs);
+      lhs
+    });
+
+  if args.json {
+    let json = ParticipationJson::new(&args.input, &participation, args.top);
+    println!(
+      "{}",
+      serde_json::to_string_pretty(&json).expect("The tables should serialize to JSON.")
+    );
+    return;
+  }
+
+  let top = args.top;
+  print_table(
+    "Words in the most solutions",
+    participation
+      .words()
+      .into_iter()
+      .take(top)
+      .map(|(word, count)| (word.to_string(), count)),
+  );
+  print_table(
+    "Starting words with the most solutions",
+    participation
+      .first_words()
+      .into_iter()
+      .take(top)
+      .map(|(word, count)| (word.to_string(), count)),
+  );
+  print_table(
+    "Word lengths",
+    participation
+      .word_lengths()
+      .into_iter()
+      .take(top)
+      .map(|(lengths, count)| {
+        let lengths = lengths.iter().map(usize::to_string).collect::<Vec<_>>();
+        (lengths.join("-"), count)
+      }),
+  );
+  print_table(
+    "Letters that join the most words",
+    participation
+      .junction_letters()
+      .into_iter()
+      .take(top)
+      .map(|(letter, count)| ((letter as char).to_string(), count)),
+  );
+
+  println!("\n\n{} solutions", participation.solution_count);
+}
@@ -0,0 +1,396 @@
+//! Defines the `solve` command, which finds every solution to a single board.
+
+use crate::RuleArgs;
+use clap::Args;
+use indicatif::{HumanCount, ProgressBar, ProgressStyle};
+use letters::{LetterSequence, LetterSet};
+use rayon::prelude::*;
+use solver::control::{CancellationToken, SearchControl};
+use solver::{CandidateArena, SearchFilter, SolutionPaths, Solver, WordFamilies};
+use std::error::Error;
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// The count of cost-balanced ranges of starting words that are searched per thread, so that a
+/// thread that finishes its range early can take another instead of waiting.
+const RANGES_PER_THREAD: usize = 4;
+
+/// The arguments for the `solve` command.
+///
+/// Clap leaves the group of a struct with a flattened field empty, so the `input` is named as its
+/// member, which lets the `Cli` tell whether the default command was given.
+#[derive(Args)]
+#[group(args = ["input"])]
+pub struct SolveArgs {
+  /// The 12 letters of the puzzle input, given side by side, e.g. AIODGTESUNPR.
+  #[arg(value_parser = crate::parse_board)]
+  input: String,
+
+  /// The number of threads to solve with. Defaults to the number of logical CPUs.
+  #[arg(long)]
+  threads: Option<usize>,
+
+  /// Stops the search after visiting this many nodes, printing the solutions found so far.
+  #[arg(long)]
+  max_nodes: Option<u64>,
+
+  /// Stops the search after finding this many solutions.
+  #[arg(long)]
+  max_solutions: Option<u64>,
+
+  /// Stops the search after this many seconds, printing the solutions found so far.
+  #[arg(long, value_parser = parse_timeout)]
+  timeout: Option<Duration>,
+
+  /// Prints statistics about the search to stderr. Requires the `stats` feature.
+  #[arg(long)]
+  stats: bool,
+
+  /// Prints each distinct path through the letters once, followed by every way that it is split
+  /// into words, e.g. "IMPARTED DUNKS | IMPART TED DUNKS".
+  #[arg(long)]
+  paths: bool,
+
+  /// Prints one solution for each group of solutions whose words differ only by inflection,
+  /// such as a plural or a tense, followed by the count of solutions that it stands in for.
+  #[arg(long, conflicts_with = "paths")]
+  collapse_inflections: bool,
+
+  /// Only finds solutions that contain this word. May be given more than once.
+  #[arg(long = "require", value_name = "WORD")]
+  required_words: Vec<LetterSequence>,
+
+  /// Only finds solutions that start with this word.
+  #[arg(long = "first", value_name = "WORD")]
+  first_word: Option<LetterSequence>,
+
+  /// Only finds solutions that do not contain this word. May be given more than once.
+  #[arg(long = "exclude", value_name = "WORD")]
+  excluded_words: Vec<LetterSequence>,
+
+  /// Only finds solutions that do not start with any of these letters, e.g. XYZ.
+  #[arg(long, value_name = "LETTERS")]
+  exclude_first_letters: Option<LetterSet>,
+
+  /// Only finds solutions in which every word has at least this many letters.
+  #[arg(long, value_name = "N")]
+  min_word_len: Option<usize>,
+
+  /// Only finds solutions with exactly this many words.
+  #[arg(long, value_name = "N", conflicts_with_all = ["min_words", "max_words"])]
+  words: Option<usize>,
+
+  /// Only finds solutions with at least this many words.
+  #[arg(long, value_name = "N")]
+  min_words: Option<usize>,
+
+  /// Only finds solutions with at most this many words.
+  #[arg(long, value_name = "N")]
+  max_words: Option<usize>,
+
+  #[command(flatten)]
+  rules: RuleArgs,
+}
+
+impl SolveArgs {
+  /// Returns the [`SearchFilter`] for the flags that were given.
+  fn filter(&self) -> SearchFilter {
+    let mut filter = SearchFilter::new();
+
+    for &word in &self.required_words {
+      filter = filter.with_required_word(word);
+    }
+    if let Some(word) = self.first_word {
+      filter = filter.with_first_word(word);
+    }
+    for &word in &self.excluded_words {
+      filter = filter.with_excluded_word(word);
+    }
+    if let Some(letters) = self.exclude_first_letters {
+      filter = filter.with_excluded_first_letters(letters);
+    }
+    if let Some(min_word_len) = self.min_word_len {
+      filter = filter.with_min_word_len(min_word_len);
+    }
+
+    let (min_words, max_words) = match self.words {
+      Some(words) => (Some(words), Some(words)),
+      None => (self.min_words, self.max_words),
+    };
+    if min_words.is_some() || max_words.is_some() {
+      filter = filter.with_word_count_range(
+        min_words.unwrap_or(1)..=max_words.unwrap_or(solver::MAX_WORD_COUNT),
+      );
+    }
+
+    filter
+  }
+}
+
+/// Parses a timeout given as a non-negative number of seconds, e.g. `2.5`.
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+  let seconds = seconds.parse::<f64>().map_err(|error| error.to_string())?;
+  Duration::try_from_secs_f64(seconds).map_err(|error| error.to_string())
+}
+
+/// Redraws the `progress_bar` from the `control` until a message is received on `finished`.
+fn report_progress(
+  progress_bar: &ProgressBar,
+  control: &SearchControl,
+  finished: &mpsc::Receiver<()>,
+) {
+  while let Err(mpsc::RecvTimeoutError::Timeout) = finished.recv_timeout(Duration::from_millis(100))
+  {
+    let progress = control.progress();
+    progress_bar.set_position(progress.starting_words_completed as u64);
+    progress_bar.set_message(format!(
+      "{} nodes, {} solutions",
+      HumanCount(progress.nodes_visited),
+      HumanCount(progress.solutions_found),
+    ));
+  }
+
+  progress_bar.finish_and_clear();
+}
+
+/// Prints the chains that come closest to solving a board that has no solution,
+/// along with the letters that each one leaves uncovered.
+fn print_partial_chains(input: &str, valid_words: &[LetterSequence]) {
+  let chains = solver::partial::best_partial_chains(input, valid_words);
+
+  println!("\nNo unique-letter solution exists. The closest chains are:\n");
+
+  for chain in &chains {
+    println!(
+      "{}\tmissing {}",
+      chain.sequence.solution_string(),
+      chain.missing_letters
+    );
+  }
+
+  println!("\n{} partial chains", chains.len());
+}
+
+/// Prints each distinct path through the letters of the `solutions` once, with every split of it,
+/// followed by the count of solutions and distinct paths.
+fn print_paths(solutions: Vec<LetterSequence>) {
+  let paths = solutions.into_iter().collect::<SolutionPaths>();
+
+  for path in paths.paths() {
+    let splits = path
+      .solutions()
+      .map(LetterSequence::solution_string)
+      .collect::<Vec<_>>();
+    println!("{}", splits.join(" | "));
+  }
+
+  println!(
+    "\n\n{} solutions on {} distinct paths",
+    paths.solution_count(),
+    paths.path_count()
+  );
+}
+
+/// Prints the first solution of each group of `solutions` whose words differ only by inflection,
+/// followed by the count of solutions and groups.
+fn print_collapsed(mut solutions: Vec<LetterSequence>) {
+  let solution_count = solutions.len();
+
+  solutions.sort_by_key(|solution| solution.word_count());
+  let groups = WordFamilies::new().collapse(solutions);
+
+  for group in &groups {
+    print!("{}", group[0].solution_string());
+    if group.len() > 1 {
+      print!("  (+{} variants)", group.len() - 1);
+    }
+    println!();
+  }
+
+  println!(
+    "\n\n{solution_count} solutions in {} groups of inflections",
+    groups.len()
+  );
+}
+
+/// Returns the [`SearchControl`] for the limits that were given, which is also cancelled when
+/// the search is interrupted with Ctrl-C.
+fn search_control(args: &SolveArgs) -> SearchControl {
+  let token = CancellationToken::new();
+  ctrlc::set_handler({
+    let token = token.clone();
+    move || token.cancel()
+  })
+  .expect("The interrupt handler should only be set once.");
+
+  let mut control = SearchControl::new().with_cancellation_token(token);
+  if let Some(max_nodes) = args.max_nodes {
+    control = control.with_node_budget(max_nodes);
+  }
+  if let Some(max_solutions) = args.max_solutions {
+    control = control.with_solution_budget(max_solutions);
+  }
+  if let Some(timeout) = args.timeout {
+    control = control.with_timeout(timeout);
+  }
+  control
+}
+
+/// Finds every solution that starts with the `word`, searching beneath each of its second words
+/// in parallel.
+fn search_starting_word(
+  solver: &Solver,
+  filter: &SearchFilter,
+  word: LetterSequence,
+  valid_words: &[LetterSequence],
+  control: &SearchControl,
+) -> Vec<LetterSequence> {
+  if control.is_truncated() {
+    return Vec::new();
+  }
+
+  if !filter.is_starting_word(word) {
+    control.complete_starting_word();
+    return Vec::new();
+  }
+
+  let branches = solver.branches(word, valid_words);
+  let solutions = branches
+    .sequences()
+    .par_iter()
+    .map_init(
+      || CandidateArena::with_capacity(valid_words.len()),
+      |arena, &sequence| {
+        let mut solutions = Vec::new();
+        let _ = solver.solve_controlled(
+          arena,
+          sequence,
+          &mut solutions,
+          branches.valid_words(),
+          control,
+        );
+        solutions
+      },
+    )
+    .flatten_iter()
+    .collect::<Vec<_>>();
+
+  if !control.is_truncated() {
+    control.complete_starting_word();
+  }
+
+  solutions
+}
+
+/// Finds every solution that starts with one of the `valid_words`, reporting progress to stderr
+/// until the search finishes or the `control` stops it.
+fn search(
+  solver: &Solver,
+  filter: &SearchFilter,
+  valid_words: &[LetterSequence],
+  control: &SearchControl,
+) -> Vec<LetterSequence> {
+  // The progress bar is drawn to stderr, and only when stderr is a terminal.
+  let progress_bar = ProgressBar::new(valid_words.len() as u64).with_style(
+    ProgressStyle::with_template("{bar:40} {pos}/{len} starting words, {msg} [{elapsed}]")
+      .expect("The progress bar template should be valid."),
+  );
+
+  let (finished_sender, finished) = mpsc::channel();
+  std::thread::scope(|scope| {
+    scope.spawn(move || report_progress(&progress_bar, control, &finished));
+
+    // The starting words are divided into contiguous ranges of roughly equal estimated cost,
+    // several per thread, and each starting word is split further into one sub-search per second
+    // word, so that work stealing can even out the estimates that were wrong. Collecting the
+    // indexed parallel iterators preserves their order, so the output does not depend on the
+    // number of threads unless the search is stopped.
+    let costs = solver::cost::estimate_costs(valid_words);
+    let range_count = rayon::current_num_threads() * RANGES_PER_THREAD;
+    let solutions = solver::cost::cost_balanced_ranges(&costs, range_count)
+      .into_par_iter()
+      .flat_map_iter(|range| {
+        valid_words[range]
+          .iter()
+          .flat_map(|&word| search_starting_word(solver, filter, word, valid_words, control))
+      })
+      .collect::<Vec<_>>();
+
+    finished_sender
+      .send(())
+      .expect("The progress reporter should be running.");
+
+    solutions
+  })
+}
+
+/// Prints the `solutions` in the format that was chosen by the flags.
+fn print_solutions(args: &SolveArgs, mut solutions: Vec<LetterSequence>) {
+  if args.paths {
+    print_paths(solutions);
+  } else if args.collapse_inflections {
+    print_collapsed(solutions);
+  } else {
+    let solution_count = solutions.len();
+    solutions.sort_by_key(|solution| solution.word_count());
+
+    for solution in solutions {
+      for word in solution.words() {
+        print!("{word} ");
+      }
+      println!();
+    }
+
+    println!("\n\n{solution_count} solutions");
+  }
+}
+
+/// Prints the statistics that were recorded by every thread in the pool to stderr.
+fn print_stats() {
+  let mut stats = solver::stats::SearchStats::default();
+  for thread_stats in rayon::broadcast(|_| solver::stats::take()) {
+    stats.merge(&thread_stats);
+  }
+
+  eprintln!("\n{stats}");
+}
+
+/// Finds and prints every solution to the board, stopping early if any limit is reached.
+///
+/// # Errors
+///
+/// Returns an error if `--stats` is given without the `stats` feature, or if the forbidden pairs
+/// cannot be read or parsed.
+pub fn run(args: &SolveArgs) -> Result<(), Box<dyn Error>> {
+  if args.stats && !solver::stats::ENABLED {
+    return Err("The --stats flag requires letrboxd to be built with the `stats` feature.".into());
+  }
+
+  let filter = &args.filter();
+  let all_valid_words = &solver::valid_words_with(&args.rules.adjacency(&args.input)?);
+  let valid_words = &filter.valid_words(all_valid_words);
+  let solver = &Solver::default().with_filter(filter);
+
+  rayon::ThreadPoolBuilder::new()
+    .num_threads(args.threads.unwrap_or(0))
+    .build_global()
+    .expect("The global thread pool should only be built once.");
+
+  let control = &search_control(args);
+  let solutions = search(solver, filter, valid_words, control);
+  let solution_count = solutions.len();
+  print_solutions(args, solutions);
+
+  if let Some(reason) = control.stop_reason() {
+    eprintln!("{reason} Only the solutions that were found before it stopped are shown.");
+  } else if solution_count == 0 && filter.is_empty() {
+    print_partial_chains(&args.input, all_valid_words);
+  }
+
+  if args.stats {
+    // Statistics are recorded separately by each thread in the pool.
+    print_stats();
+  }
+
+  Ok(())
+}
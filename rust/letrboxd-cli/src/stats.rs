@@ -0,0 +1,38 @@
+//! Defines the `stats` command, which explains why a board has few or no solutions.
+
+use clap::Args;
+use rayon::prelude::*;
+use solver::report::{BoardReport, DEFAULT_RARE_WORD_COUNT};
+use solver::WordCountTally;
+
+/// The arguments for the `stats` command.
+#[derive(Args)]
+pub struct StatsArgs {
+  /// The 12 letters of the puzzle input, given side by side, e.g. AIODGTESUNPR.
+  #[arg(value_parser = crate::parse_board)]
+  input: String,
+
+  /// Reports the letters that are contained in fewer than this many valid words.
+  #[arg(long, default_value_t = DEFAULT_RARE_WORD_COUNT)]
+  rare: usize,
+}
+
+/// Prints the diagnostics for the board, followed by its count of solutions.
+pub fn run(args: &StatsArgs) {
+  let report = BoardReport::new(&args.input).with_rare_word_count(args.rare);
+  println!("{report}");
+
+  let valid_words = &solver::valid_words(&args.input);
+  let tally = valid_words
+    .par_iter()
+    .fold(WordCountTally::default, |mut tally, &word| {
+      solver::solve(word, &mut tally, valid_words);
+      tally
+    })
+    .reduce(WordCountTally::default, |mut lhs, rhs| {
+      lhs.merge(&rhs);
+      lhs
+    });
+
+  println!("\n{} solutions", tally.total());
+}
@@ -0,0 +1,221 @@
+//! Defines the `verify-archive` command, which re-solves every board in the site's archive
+//! and compares the solution counts against a golden file.
+//!
+//! The golden file records the count of solutions with each word count for every archived board,
+//! along with the CRC of the dictionary that the counts were found with, so that any change to
+//! the solver or the dictionary shows exactly which historical puzzles it affects.
+
+use clap::Args;
+use indicatif::{ProgressBar, ProgressStyle};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::ser::{Formatter, PrettyFormatter};
+use solver::{WordCountTally, MAX_WORD_COUNT};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use word_list::ALL_WORDS_CRC;
+
+/// The archive of historical puzzle inputs served by the site, keyed by date.
+pub const DEFAULT_ARCHIVE_PATH: &str = concat!(
+  env!("CARGO_MANIFEST_DIR"),
+  "/../../site/generated/json/inputsByDate.json"
+);
+
+/// The golden solution counts for every board in the archive.
+const DEFAULT_GOLDEN_PATH: &str = concat!(
+  env!("CARGO_MANIFEST_DIR"),
+  "/data/solutionCountsByInput.json"
+);
+
+/// The arguments for the `verify-archive` command.
+#[derive(Args)]
+pub struct VerifyArchiveArgs {
+  /// The archive of puzzle inputs keyed by date.
+  #[arg(long, default_value = DEFAULT_ARCHIVE_PATH)]
+  archive: PathBuf,
+
+  /// The golden file of solution counts to verify against.
+  #[arg(long, default_value = DEFAULT_GOLDEN_PATH)]
+  golden: PathBuf,
+
+  /// Regenerates the golden file from the current solver and dictionary instead of verifying it.
+  #[arg(long)]
+  update: bool,
+}
+
+/// The solution counts for every archived board, and the dictionary they were found with.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GoldenCounts {
+  /// The [`ALL_WORDS_CRC`] of the dictionary when the counts were generated.
+  dictionary_crc: String,
+  /// The count of solutions with each word count, from one word to [`MAX_WORD_COUNT`], by input.
+  solution_counts: BTreeMap<String, [u64; MAX_WORD_COUNT]>,
+}
+
+impl GoldenCounts {
+  /// Returns the JSON for these counts, with one board per line so that changes diff cleanly.
+  fn to_json(&self) -> String {
+    let mut json = Vec::new();
+    self
+      .serialize(&mut serde_json::Serializer::with_formatter(
+        &mut json,
+        GoldenFormatter::default(),
+      ))
+      .expect("The golden counts should serialize to JSON.");
+    json.push(b'\n');
+    String::from_utf8(json).expect("The serialized JSON should be UTF-8.")
+  }
+}
+
+/// Pretty-prints objects with one entry per line, but keeps each array of counts on one line.
+#[derive(Default)]
+struct GoldenFormatter(PrettyFormatter<'static>);
+
+impl Formatter for GoldenFormatter {
+  fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+    self.0.begin_object(writer)
+  }
+
+  fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+    self.0.end_object(writer)
+  }
+
+  fn begin_object_key<W: ?Sized + io::Write>(
+    &mut self,
+    writer: &mut W,
+    first: bool,
+  ) -> io::Result<()> {
+    self.0.begin_object_key(writer, first)
+  }
+
+  fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+    self.0.begin_object_value(writer)
+  }
+
+  fn end_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+    self.0.end_object_value(writer)
+  }
+
+  fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+    writer.write_all(b"[")
+  }
+
+  fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+    writer.write_all(b"]")
+  }
+
+  fn begin_array_value<W: ?Sized + io::Write>(
+    &mut self,
+    writer: &mut W,
+    first: bool,
+  ) -> io::Result<()> {
+    if first {
+      Ok(())
+    } else {
+      writer.write_all(b", ")
+    }
+  }
+}
+
+/// Solves every input in the archive, returning the solution counts for each input.
+fn solve_archive(inputs: &[String]) -> BTreeMap<String, [u64; MAX_WORD_COUNT]> {
+  let progress_bar = ProgressBar::new(inputs.len() as u64).with_style(
+    ProgressStyle::with_template("{bar:40} {pos}/{len} boards [{elapsed}]")
+      .expect("The progress bar template should be valid."),
+  );
+
+  let solution_counts = inputs
+    .par_iter()
+    .map(|input| {
+      let valid_words = &solver::valid_words(input);
+      let mut tally = WordCountTally::default();
+
+      solver::solve_all(valid_words, &mut tally);
+
+      progress_bar.inc(1);
+      (input.clone(), tally.by_word_count)
+    })
+    .collect();
+
+  progress_bar.finish_and_clear();
+  solution_counts
+}
+
+/// Re-solves every board in the archive, then either verifies the golden file or regenerates it.
+///
+/// # Errors
+///
+/// Returns an error if the archive or golden file cannot be read, parsed or written, if any
+/// board's solution counts do not match the golden file, or if the golden file has boards that
+/// are no longer in the archive.
+pub fn run(args: &VerifyArchiveArgs) -> Result<(), Box<dyn Error>> {
+  let archive =
+    serde_json::from_str::<BTreeMap<String, String>>(&fs::read_to_string(&args.archive)?)?;
+  let mut inputs = archive.into_values().collect::<Vec<_>>();
+  inputs.sort_unstable();
+  inputs.dedup();
+
+  let solution_counts = solve_archive(&inputs);
+
+  if args.update {
+    let golden = GoldenCounts {
+      dictionary_crc: ALL_WORDS_CRC.to_owned(),
+      solution_counts,
+    };
+    fs::write(&args.golden, golden.to_json())?;
+    println!(
+      "Wrote the solution counts for {} boards to {}",
+      inputs.len(),
+      args.golden.display()
+    );
+    return Ok(());
+  }
+
+  let golden = serde_json::from_str::<GoldenCounts>(&fs::read_to_string(&args.golden)?)?;
+  if golden.dictionary_crc != ALL_WORDS_CRC {
+    println!(
+      "The dictionary has changed since the golden file was generated ({} != {}), so some mismatches may be expected.",
+      golden.dictionary_crc, ALL_WORDS_CRC,
+    );
+  }
+
+  let mut mismatch_count = 0;
+  for (input, actual) in &solution_counts {
+    match golden.solution_counts.get(input) {
+      Some(expected) if expected == actual => {}
+      Some(expected) => {
+        mismatch_count += 1;
+        println!("{input}: expected {expected:?} solutions by word count, but found {actual:?}");
+      }
+      None => {
+        mismatch_count += 1;
+        println!("{input}: missing from the golden file, found {actual:?} solutions by word count");
+      }
+    }
+  }
+
+  let mut stale_count = 0;
+  for (input, expected) in &golden.solution_counts {
+    if !solution_counts.contains_key(input) {
+      stale_count += 1;
+      println!(
+        "{input}: stale, no longer in the archive, expected {expected:?} solutions by word count"
+      );
+    }
+  }
+
+  println!(
+    "Verified {} boards: {mismatch_count} mismatched, {stale_count} stale.",
+    solution_counts.len()
+  );
+
+  if mismatch_count > 0 || stale_count > 0 {
+    return Err(format!("{mismatch_count} boards mismatched and {stale_count} were stale").into());
+  }
+
+  Ok(())
+}
@@ -2,18 +2,328 @@
 //! represented by `LetterSequence` objects. It defines data structures and functions
 //! for serializing, deserializing, and working with these letter sequences.
 
-use letters::{create_letter_group_function, LetterSequence};
+use letters::{Adjacency, AdjacencyRule, LetterSequence, LetterSet, ParseError};
+use solver::wire::WireError;
+use solver::{
+  BoardError, CandidateArena, Participation, SearchFilter, SolutionPaths, Solver, WordFamilies,
+};
 use std::cell::RefCell;
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::OnceLock;
 use wasm_bindgen::prelude::*;
-use word_list::WORDS;
 
 thread_local! {
   /// Thread-local storage for the list of valid words. The list is registered by the worker
   /// thread and then used multiple times as it chunks the computation of finding solutions.
-  static VALID_WORDS: RefCell<Vec<LetterSequence>> = RefCell::new(Vec::with_capacity(0));
+  /// The list is [`None`] until words are registered, so that an empty list of valid words
+  /// can be told apart from a missing one.
+  static VALID_WORDS: RefCell<Option<Vec<LetterSequence>>> = const { RefCell::new(None) };
+
+  /// Thread-local storage for the filter that narrows down the solutions of every search on this
+  /// thread. The filter admits every solution until search options are registered.
+  static SEARCH_FILTER: RefCell<SearchFilter> = RefCell::default();
+}
+
+/// The families of inflections of every word, which are built from the whole word list on first
+/// use and then shared by every later call, since they do not depend on the board.
+static WORD_FAMILIES: OnceLock<WordFamilies> = OnceLock::new();
+
+/// The errors that can be returned by the exported functions, which are thrown to JavaScript
+/// as a [`WasmError`].
+#[derive(Debug)]
+pub enum LetrboxdError {
+  /// The puzzle input is not 12 distinct uppercase letters.
+  InvalidBoard(BoardError),
+  /// A word or set of letters in the search options could not be parsed.
+  InvalidWord(ParseError),
+  /// The adjacency rule is not one of the names of a [`WasmAdjacencyRule`].
+  InvalidRule,
+  /// A serialized list of words could not be decoded.
+  CorruptedBuffer(WireError),
+  /// A requested range does not fit within the registered valid words.
+  RangeOutOfBounds {
+    range_start: usize,
+    range_end: usize,
+    word_count: usize,
+  },
+  /// No range count was requested for dividing the registered valid words.
+  ZeroRangeCount,
+  /// A function that operates on the registered valid words was called before any were registered.
+  NoWordsRegistered,
+}
+
+impl LetrboxdError {
+  /// Returns the kind of this error, which JavaScript can switch on.
+  #[must_use]
+  pub const fn kind(&self) -> WasmErrorKind {
+    match self {
+      Self::InvalidBoard(_) => WasmErrorKind::InvalidBoard,
+      Self::InvalidWord(_) => WasmErrorKind::InvalidWord,
+      Self::InvalidRule => WasmErrorKind::InvalidRule,
+      Self::CorruptedBuffer(_) => WasmErrorKind::CorruptedBuffer,
+      Self::RangeOutOfBounds { .. } | Self::ZeroRangeCount => WasmErrorKind::RangeOutOfBounds,
+      Self::NoWordsRegistered => WasmErrorKind::NoWordsRegistered,
+    }
+  }
+}
+
+impl Display for LetrboxdError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::InvalidBoard(error) => write!(f, "{error}"),
+      Self::InvalidWord(error) => write!(f, "{error}"),
+      Self::InvalidRule => write!(
+        f,
+        "Expected an adjacency rule of \"standard\", \"easy\" or \"clockwise\"."
+      ),
+      Self::CorruptedBuffer(error) => write!(f, "The serialized words are corrupted: {error}"),
+      Self::RangeOutOfBounds {
+        range_start,
+        range_end,
+        word_count,
+      } => write!(
+        f,
+        "The range {range_start}..{range_end} is out of bounds for {word_count} registered words."
+      ),
+      Self::ZeroRangeCount => write!(
+        f,
+        "The registered words cannot be divided into zero ranges."
+      ),
+      Self::NoWordsRegistered => write!(f, "No valid words have been registered."),
+    }
+  }
+}
+
+/// The kinds of [`WasmError`], which appear in the TypeScript declarations as a union of
+/// string literals, e.g. `"RangeOutOfBounds"`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmErrorKind {
+  /// The puzzle input is not 12 distinct uppercase letters.
+  InvalidBoard = "InvalidBoard",
+  /// A word or set of letters in the search options could not be parsed.
+  InvalidWord = "InvalidWord",
+  /// The adjacency rule is not one of the names of a [`WasmAdjacencyRule`].
+  InvalidRule = "InvalidRule",
+  /// A serialized list of words could not be decoded.
+  CorruptedBuffer = "CorruptedBuffer",
+  /// A requested range does not fit within the registered valid words, or no ranges were requested.
+  RangeOutOfBounds = "RangeOutOfBounds",
+  /// A function that operates on the registered valid words was called before any were registered.
+  NoWordsRegistered = "NoWordsRegistered",
+}
+
+/// The rules for which letters of a board may follow one another within a word, which appear in
+/// the TypeScript declarations as a union of string literals. See [`AdjacencyRule`].
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmAdjacencyRule {
+  /// A letter may only follow a letter from a different side.
+  Standard = "standard",
+  /// A letter may follow any other letter on the board, including one from the same side.
+  Easy = "easy",
+  /// A letter may only follow a letter from the next side clockwise.
+  Clockwise = "clockwise",
+}
+
+impl TryFrom<WasmAdjacencyRule> for AdjacencyRule {
+  type Error = LetrboxdError;
+
+  fn try_from(rule: WasmAdjacencyRule) -> Result<Self, Self::Error> {
+    match rule {
+      WasmAdjacencyRule::Standard => Ok(Self::Standard),
+      WasmAdjacencyRule::Easy => Ok(Self::Easy),
+      WasmAdjacencyRule::Clockwise => Ok(Self::Clockwise),
+      WasmAdjacencyRule::__Invalid => Err(LetrboxdError::InvalidRule),
+    }
+  }
+}
+
+/// The error that is thrown by every fallible exported function, which carries its
+/// [`kind`](Self::kind) apart from its message, so that JavaScript can switch on it.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WasmError {
+  kind: WasmErrorKind,
+  message: String,
+}
+
+#[wasm_bindgen]
+impl WasmError {
+  /// Returns the kind of the error, e.g. `"RangeOutOfBounds"`.
+  #[wasm_bindgen(getter)]
+  #[must_use]
+  pub fn kind(&self) -> WasmErrorKind {
+    self.kind
+  }
+
+  /// Returns a description of the error, which does not include its kind.
+  #[wasm_bindgen(getter)]
+  #[must_use]
+  pub fn message(&self) -> String {
+    self.message.clone()
+  }
+
+  /// Returns the kind of the error followed by its message, e.g.
+  /// `"NoWordsRegistered: No valid words have been registered."`.
+  #[wasm_bindgen(js_name = "toString")]
+  #[must_use]
+  pub fn to_js_string(&self) -> String {
+    format!("{:?}: {}", self.kind, self.message)
+  }
+}
+
+impl From<LetrboxdError> for WasmError {
+  fn from(error: LetrboxdError) -> Self {
+    Self {
+      kind: error.kind(),
+      message: error.to_string(),
+    }
+  }
+}
+
+impl Error for LetrboxdError {
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match self {
+      Self::InvalidBoard(error) => Some(error),
+      Self::InvalidWord(error) => Some(error),
+      Self::CorruptedBuffer(error) => Some(error),
+      Self::InvalidRule
+      | Self::RangeOutOfBounds { .. }
+      | Self::ZeroRangeCount
+      | Self::NoWordsRegistered => None,
+    }
+  }
+}
+
+/// Calls `f` with the registered valid words, or returns an error if none are registered.
+fn with_valid_words<T>(
+  f: impl FnOnce(&[LetterSequence]) -> Result<T, LetrboxdError>,
+) -> Result<T, LetrboxdError> {
+  VALID_WORDS.with_borrow(|valid_words| match valid_words {
+    Some(valid_words) => f(valid_words),
+    None => Err(LetrboxdError::NoWordsRegistered),
+  })
+}
+
+/// Calls `f` with a [`Solver`] that applies the registered search options, and the `valid_words`
+/// that those options admit.
+fn with_search_filter<T>(
+  valid_words: &[LetterSequence],
+  f: impl FnOnce(Solver, &SearchFilter, &[LetterSequence]) -> T,
+) -> T {
+  SEARCH_FILTER.with_borrow(|filter| {
+    let solver = Solver::default().with_filter(filter);
+    f(solver, filter, &filter.valid_words(valid_words))
+  })
+}
+
+/// Options that narrow down the solutions of every search, such as words that every solution must
+/// contain, or the count of words in every solution. See [`solver::filter`].
+///
+/// The options only take effect once they are registered with `setSearchOptions`.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct SearchOptions {
+  filter: SearchFilter,
+}
+
+#[wasm_bindgen]
+impl SearchOptions {
+  /// Creates options with no constraints, which admit every solution.
+  #[must_use]
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> SearchOptions {
+    Self::default()
+  }
+
+  /// Only admits solutions that contain the `word`. May be called more than once.
+  ///
+  /// # Errors
+  ///
+  /// Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+  #[wasm_bindgen(js_name = "requireWord")]
+  pub fn require_word(&mut self, word: &str) -> Result<(), WasmError> {
+    let word = parse_word(word)?;
+    self.filter = std::mem::take(&mut self.filter).with_required_word(word);
+    Ok(())
+  }
+
+  /// Only admits solutions that start with the `word`.
+  ///
+  /// # Errors
+  ///
+  /// Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+  #[wasm_bindgen(js_name = "setFirstWord")]
+  pub fn set_first_word(&mut self, word: &str) -> Result<(), WasmError> {
+    let word = parse_word(word)?;
+    self.filter = std::mem::take(&mut self.filter).with_first_word(word);
+    Ok(())
+  }
+
+  /// Only admits solutions that do not contain the `word`. May be called more than once.
+  ///
+  /// # Errors
+  ///
+  /// Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+  #[wasm_bindgen(js_name = "excludeWord")]
+  pub fn exclude_word(&mut self, word: &str) -> Result<(), WasmError> {
+    let word = parse_word(word)?;
+    self.filter = std::mem::take(&mut self.filter).with_excluded_word(word);
+    Ok(())
+  }
+
+  /// Only admits solutions that do not start with any of the `letters`, e.g. `"XYZ"`.
+  ///
+  /// # Errors
+  ///
+  /// Throws an `InvalidWord` error if the `letters` are not distinct letters, in either case.
+  #[wasm_bindgen(js_name = "excludeFirstLetters")]
+  pub fn exclude_first_letters(&mut self, letters: &str) -> Result<(), WasmError> {
+    let letters = letters
+      .parse::<LetterSet>()
+      .map_err(LetrboxdError::InvalidWord)?;
+    self.filter = std::mem::take(&mut self.filter).with_excluded_first_letters(letters);
+    Ok(())
+  }
+
+  /// Only admits solutions in which every word has at least `min_word_len` letters.
+  #[wasm_bindgen(js_name = "setMinWordLength")]
+  pub fn set_min_word_len(&mut self, min_word_len: usize) {
+    self.filter = std::mem::take(&mut self.filter).with_min_word_len(min_word_len);
+  }
+
+  /// Only admits solutions with at least `min_word_count` and at most `max_word_count` words.
+  #[wasm_bindgen(js_name = "setWordCountRange")]
+  pub fn set_word_count_range(&mut self, min_word_count: usize, max_word_count: usize) {
+    self.filter =
+      std::mem::take(&mut self.filter).with_word_count_range(min_word_count..=max_word_count);
+  }
+}
+
+/// Parses a single uppercase word with unique letters for the [`SearchOptions`].
+fn parse_word(word: &str) -> Result<LetterSequence, LetrboxdError> {
+  word.parse().map_err(LetrboxdError::InvalidWord)
+}
+
+/// Registers the search options that narrow down the solutions of every later search on this
+/// thread, replacing any options that were registered before.
+#[wasm_bindgen(js_name = "setSearchOptions")]
+pub fn set_search_options(options: &SearchOptions) {
+  SEARCH_FILTER.replace(options.filter.clone());
+}
+
+/// Clears the registered search options, so that every later search admits every solution.
+#[wasm_bindgen(js_name = "clearSearchOptions")]
+pub fn clear_search_options() {
+  SEARCH_FILTER.replace(SearchFilter::default());
 }
 
 /// A structure holding serialized words along with the total word count.
+///
+/// The words are serialized in the compact format defined by [`solver::wire`],
+/// which holds only the packed letters of each word.
 #[wasm_bindgen]
 pub struct SerializedSequences {
   word_count: usize,
@@ -29,11 +339,14 @@ impl SerializedSequences {
     self.word_count
   }
 
-  /// Returns the serialized list of valid words.
-  #[must_use]
-  #[wasm_bindgen(getter, js_name = "serializedWords")]
-  pub fn serialized_words(&self) -> Vec<u8> {
-    self.serialized_words.clone()
+  /// Takes and returns the serialized list of valid words, leaving an empty list in its place.
+  ///
+  /// The buffer is moved out rather than cloned, so the resulting `Uint8Array` owns the only copy
+  /// outside of WASM memory, and its underlying `ArrayBuffer` may be transferred between workers.
+  /// Calling this again returns an empty list.
+  #[wasm_bindgen(js_name = "takeSerializedWords")]
+  pub fn take_serialized_words(&mut self) -> Vec<u8> {
+    std::mem::take(&mut self.serialized_words)
   }
 }
 
@@ -49,6 +362,17 @@ pub struct SolutionsPayload {
   solutions_5_word: Vec<String>,
 }
 
+impl SolutionsPayload {
+  /// Returns the total count of solutions held across every bucket.
+  fn len(&self) -> usize {
+    self.solutions_1_word.len()
+      + self.solutions_2_word.len()
+      + self.solutions_3_word.len()
+      + self.solutions_4_word.len()
+      + self.solutions_5_word.len()
+  }
+}
+
 #[wasm_bindgen]
 impl SolutionsPayload {
   /// Adds a [`LetterSequence`] solution to the relevant bucket based on the word count.
@@ -94,119 +418,589 @@ impl SolutionsPayload {
   }
 }
 
+impl solver::Solutions for SolutionsPayload {
+  fn push(&mut self, solution: LetterSequence) {
+    SolutionsPayload::push(self, solution);
+  }
+}
+
+/// A payload to hold the closest partial chains for a board that has no solution,
+/// along with the letters that each chain leaves uncovered.
+///
+/// The chain at position `n` in [`chains`](Self::take_chains) is missing the letters
+/// at position `n` in [`missingLetters`](Self::take_missing_letters).
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct PartialChainsPayload {
+  chains: Vec<String>,
+  missing_letters: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl PartialChainsPayload {
+  /// Takes and returns the solution string of each chain, e.g. `"FLACK KNIGHT"`,
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "chains")]
+  pub fn take_chains(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.chains)
+  }
+
+  /// Takes and returns the letters that each chain leaves uncovered, e.g. `"JP"`,
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "missingLetters")]
+  pub fn take_missing_letters(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.missing_letters)
+  }
+}
+
+/// A payload to hold the tables of a [`Participation`], each as a list of labels and a list of
+/// the counts of solutions that each label takes part in, from the most solutions to the fewest.
+///
+/// The tables are complete, so the payloads for separate ranges of starting words can be combined
+/// by adding the counts for each label. Counts that do not fit in a [`u32`] are saturated.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct ParticipationPayload {
+  words: Vec<String>,
+  word_counts: Vec<u32>,
+  first_words: Vec<String>,
+  first_word_counts: Vec<u32>,
+  word_lengths: Vec<String>,
+  word_length_counts: Vec<u32>,
+  junction_letters: Vec<String>,
+  junction_letter_counts: Vec<u32>,
+}
+
+impl ParticipationPayload {
+  /// Builds the payload from every table of the `participation`.
+  fn new(participation: &Participation) -> Self {
+    let mut payload = Self::default();
+
+    for (word, count) in participation.words() {
+      payload.words.push(word.to_string());
+      payload.word_counts.push(saturate(count));
+    }
+
+    for (word, count) in participation.first_words() {
+      payload.first_words.push(word.to_string());
+      payload.first_word_counts.push(saturate(count));
+    }
+
+    for (lengths, count) in participation.word_lengths() {
+      let lengths = lengths.iter().map(usize::to_string).collect::<Vec<_>>();
+      payload.word_lengths.push(lengths.join("-"));
+      payload.word_length_counts.push(saturate(count));
+    }
+
+    for (letter, count) in participation.junction_letters() {
+      payload
+        .junction_letters
+        .push(char::from(letter).to_string());
+      payload.junction_letter_counts.push(saturate(count));
+    }
+
+    payload
+  }
+}
+
+#[wasm_bindgen]
+impl ParticipationPayload {
+  /// Takes and returns each word that takes part in a solution, e.g. `"QUA"`,
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "words")]
+  pub fn take_words(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.words)
+  }
+
+  /// Takes and returns the count of solutions that contain each of the [`words`](Self::take_words),
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "wordSolutionCounts")]
+  pub fn take_word_counts(&mut self) -> Vec<u32> {
+    std::mem::take(&mut self.word_counts)
+  }
+
+  /// Takes and returns each word that starts a solution, clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "firstWords")]
+  pub fn take_first_words(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.first_words)
+  }
+
+  /// Takes and returns the count of solutions that start with each of the
+  /// [`firstWords`](Self::take_first_words), clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "firstWordSolutionCounts")]
+  pub fn take_first_word_counts(&mut self) -> Vec<u32> {
+    std::mem::take(&mut self.first_word_counts)
+  }
+
+  /// Takes and returns the length of each word in order for every pattern of word lengths,
+  /// e.g. `"6-7"`, clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "wordLengths")]
+  pub fn take_word_lengths(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.word_lengths)
+  }
+
+  /// Takes and returns the count of solutions that follow each of the
+  /// [`wordLengths`](Self::take_word_lengths), clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "wordLengthSolutionCounts")]
+  pub fn take_word_length_counts(&mut self) -> Vec<u32> {
+    std::mem::take(&mut self.word_length_counts)
+  }
+
+  /// Takes and returns each letter that joins two words in a solution, e.g. `"D"`,
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "junctionLetters")]
+  pub fn take_junction_letters(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.junction_letters)
+  }
+
+  /// Takes and returns the count of solutions in which each of the
+  /// [`junctionLetters`](Self::take_junction_letters) joins two words,
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "junctionLetterSolutionCounts")]
+  pub fn take_junction_letter_counts(&mut self) -> Vec<u32> {
+    std::mem::take(&mut self.junction_letter_counts)
+  }
+}
+
+/// A payload to hold a [`SolutionPaths`]: each distinct path through the letters, the count of
+/// its splits into words, and every split as a solution string, e.g. `"IMPART TED DUNKS"`.
+///
+/// The splits of every path are flattened into a single list, in which the splits of each path
+/// follow those of the path before it. The payloads for separate ranges of starting words can be
+/// combined by merging the splits of equal paths.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct SolutionPathsPayload {
+  paths: Vec<String>,
+  split_counts: Vec<u32>,
+  splits: Vec<String>,
+}
+
+impl SolutionPathsPayload {
+  /// Builds the payload from every path of the `paths`.
+  fn new(paths: &SolutionPaths) -> Self {
+    let mut payload = Self::default();
+
+    for path in paths.paths() {
+      payload.paths.push(path.letters.to_string());
+      payload
+        .split_counts
+        .push(saturate(path.splits.len() as u64));
+      payload
+        .splits
+        .extend(path.solutions().map(LetterSequence::solution_string));
+    }
+
+    payload
+  }
+}
+
+#[wasm_bindgen]
+impl SolutionPathsPayload {
+  /// Takes and returns the letters of each distinct path, e.g. `"IMPARTEDUNKS"`,
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "paths")]
+  pub fn take_paths(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.paths)
+  }
+
+  /// Takes and returns the count of splits of each of the [`paths`](Self::take_paths),
+  /// clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "splitCounts")]
+  pub fn take_split_counts(&mut self) -> Vec<u32> {
+    std::mem::take(&mut self.split_counts)
+  }
+
+  /// Takes and returns every split of every path as a solution string, from the fewest words to
+  /// the most within each path, clearing them from the internal list.
+  #[wasm_bindgen(getter, js_name = "splits")]
+  pub fn take_splits(&mut self) -> Vec<String> {
+    std::mem::take(&mut self.splits)
+  }
+}
+
+/// Returns the `count` as a [`u32`], saturating at [`u32::MAX`].
+fn saturate(count: u64) -> u32 {
+  u32::try_from(count).unwrap_or(u32::MAX)
+}
+
+/// Returns the valid words in the specified index range, or an error if it is out of bounds.
+fn starting_words(
+  valid_words: &[LetterSequence],
+  range_start: usize,
+  range_end: usize,
+) -> Result<&[LetterSequence], LetrboxdError> {
+  valid_words
+    .get(range_start..range_end)
+    .ok_or(LetrboxdError::RangeOutOfBounds {
+      range_start,
+      range_end,
+      word_count: valid_words.len(),
+    })
+}
+
+/// Returns the valid words for a 12-letter input under the given `rule`, which defaults to the
+/// standard rule, and without any of the `forbidden_pairs`.
+fn valid_words_under(
+  input: &str,
+  rule: Option<WasmAdjacencyRule>,
+  forbidden_pairs: Option<String>,
+) -> Result<Vec<LetterSequence>, LetrboxdError> {
+  solver::board::validate(input).map_err(LetrboxdError::InvalidBoard)?;
+
+  let rule = rule.map_or(Ok(AdjacencyRule::default()), AdjacencyRule::try_from)?;
+  let adjacency = Adjacency::new(input, rule)
+    .with_forbidden_pairs(&forbidden_pairs.unwrap_or_default())
+    .map_err(LetrboxdError::InvalidWord)?;
+
+  Ok(solver::valid_words_with(&adjacency))
+}
+
 /// Gathers valid words for a given 12-letter input, returning them in serialized form.
 ///
-/// # Panics
+/// The letters that may follow one another are decided by the `rule`, which defaults to the
+/// standard rule, and by the `forbiddenPairs`, if any, in the format that is accepted by
+/// [`Adjacency::with_forbidden_pairs`], e.g. `"QU, TH"`.
 ///
-/// Panics if the letter sequences cannot be serialized.
-#[must_use]
+/// # Errors
+///
+/// Throws an `InvalidBoard` error if the input is not 12 distinct uppercase letters, an
+/// `InvalidRule` error if the rule is not known, or an `InvalidWord` error if the forbidden
+/// pairs cannot be parsed.
 #[wasm_bindgen(js_name = "getValidWords")]
-pub fn get_valid_words(input: &str) -> SerializedSequences {
-  let letter_group = create_letter_group_function!(input);
-
-  let words = WORDS
-    .iter()
-    .copied()
-    .filter(|word| word.is_valid_word(&letter_group))
-    .collect::<Vec<_>>();
+pub fn get_valid_words(
+  input: &str,
+  rule: Option<WasmAdjacencyRule>,
+  #[wasm_bindgen(js_name = "forbiddenPairs")] forbidden_pairs: Option<String>,
+) -> Result<SerializedSequences, WasmError> {
+  let words = valid_words_under(input, rule, forbidden_pairs)?;
 
-  SerializedSequences {
+  Ok(SerializedSequences {
     word_count: words.len(),
-    serialized_words: bincode::serialize(&words).unwrap(),
+    serialized_words: solver::wire::encode_words(&words),
+  })
+}
+
+/// Finds the chains that come closest to solving a 12-letter input, for a board that has no
+/// unique-letter solution. See [`solver::partial::best_partial_chains`].
+///
+/// The valid words are gathered under the `rule` and `forbiddenPairs` in the same way as
+/// `getValidWords`.
+///
+/// # Errors
+///
+/// Throws the same errors as `getValidWords`.
+#[wasm_bindgen(js_name = "partialChains")]
+pub fn partial_chains(
+  input: &str,
+  rule: Option<WasmAdjacencyRule>,
+  #[wasm_bindgen(js_name = "forbiddenPairs")] forbidden_pairs: Option<String>,
+) -> Result<PartialChainsPayload, WasmError> {
+  let valid_words = valid_words_under(input, rule, forbidden_pairs)?;
+
+  let mut payload = PartialChainsPayload::default();
+  for chain in solver::partial::best_partial_chains(input, &valid_words) {
+    payload.chains.push(chain.sequence.solution_string());
+    payload.missing_letters.push(
+      chain
+        .missing_letters
+        .ascii_bytes()
+        .map(char::from)
+        .collect(),
+    );
   }
+
+  Ok(payload)
 }
 
 /// Deserializes and stores valid words in thread-local storage for later use.
 /// Solutions are generated in chunks, so this vector is reused multiple times.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if the serialized words cannot be deserialized.
+/// Throws a `CorruptedBuffer` error if the serialized words cannot be deserialized,
+/// in which case any previously registered words are left in place.
 #[wasm_bindgen(js_name = "registerValidWords")]
-pub fn register_valid_words(serialized_words: &[u8]) {
-  VALID_WORDS.replace(bincode::deserialize(serialized_words).unwrap());
+pub fn register_valid_words(serialized_words: &[u8]) -> Result<(), WasmError> {
+  let words =
+    solver::wire::decode_words(serialized_words).map_err(LetrboxdError::CorruptedBuffer)?;
+  VALID_WORDS.replace(Some(words));
+  Ok(())
 }
 
 /// Clears the currently registered valid words from thread-local storage.
 #[wasm_bindgen(js_name = "clearValidWords")]
 pub fn clear_valid_words() {
-  VALID_WORDS.replace(Vec::with_capacity(0));
+  VALID_WORDS.replace(None);
 }
 
-/// Generates puzzle solutions for valid words in the specified index range.
-#[must_use]
-#[wasm_bindgen]
-pub fn solutions(range_start: usize, range_end: usize) -> SolutionsPayload {
-  VALID_WORDS.with_borrow(|valid_words| {
-    let mut solutions = SolutionsPayload::default();
-    for index in range_start..range_end {
-      solve_partition_once(valid_words[index], &mut solutions, valid_words);
-    }
+/// Estimates the cost of searching for every solution that starts with each of the
+/// registered valid words, in order. Costs that do not fit in a [`u32`] are saturated.
+///
+/// # Errors
+///
+/// Throws a `NoWordsRegistered` error if no valid words have been registered.
+#[wasm_bindgen(js_name = "estimateCosts")]
+pub fn estimate_costs() -> Result<Vec<u32>, WasmError> {
+  let costs = with_valid_words(|valid_words| {
+    Ok(
+      solver::cost::estimate_costs(valid_words)
+        .into_iter()
+        .map(saturate)
+        .collect(),
+    )
+  })?;
 
-    solutions
-  })
+  Ok(costs)
 }
 
-/// Recursively solves for valid 12-letter sequences, grouping the solutions by word count in the provided `SolutionsPayload`.
-/// This version filters the valid words and then partitions them based on whether they are immediately appendable.
-/// This strategy tends to be faster when the `valid_words` list is large, which is why we do it only for the first pass.
-fn solve_partition_once(
-  sequence: LetterSequence,
-  solutions: &mut SolutionsPayload,
-  valid_words: &[LetterSequence],
-) {
-  match sequence.len() {
-    12 => {
-      // If we have constructed a valid sequence with exactly 12 letters, it is a solution.
-      solutions.push(sequence);
-    }
-    11 => {
-      // There are no words that can be appended to an 11-letter sequence to form a 12-letter
-      // solution because the minimum valid word length is 3 letters. This is a dead end.
-    }
-    _ => {
-      let (appendable_words, remaining_valid_words) = valid_words
+/// Returns the first word of the family of inflections of each of the registered valid words,
+/// in order, e.g. `"ABDUCT"` for `"ABDUCTS"`. Two solutions differ only by inflection if their words
+/// have the same family heads in the same order. See [`solver::families`].
+///
+/// # Errors
+///
+/// Throws a `NoWordsRegistered` error if no valid words have been registered.
+#[wasm_bindgen(js_name = "wordFamilyHeads")]
+pub fn word_family_heads() -> Result<Vec<String>, WasmError> {
+  let heads = with_valid_words(|valid_words| {
+    let families = WORD_FAMILIES.get_or_init(WordFamilies::new);
+    Ok(
+      valid_words
         .iter()
-        .copied()
-        .filter(|word| word.shared_letter_count(sequence) <= 1)
-        .partition::<Vec<_>, _>(|word| word.can_append_to(sequence));
-      appendable_words.iter().copied().for_each(|word| {
-        solve_filter(word.append_to(sequence), solutions, &remaining_valid_words);
-      });
+        .map(|&word| families.head(word).to_string())
+        .collect(),
+    )
+  })?;
+
+  Ok(heads)
+}
+
+/// Divides the indices of the registered valid words into `range_count` contiguous ranges
+/// of roughly equal estimated cost, returning the `range_count + 1` boundaries between them.
+///
+/// The range at position `n` runs from `boundaries[n]` (inclusive) to `boundaries[n + 1]` (exclusive).
+///
+/// # Errors
+///
+/// Throws a `RangeOutOfBounds` error if `range_count` is zero, or a `NoWordsRegistered`
+/// error if no valid words have been registered.
+#[wasm_bindgen(js_name = "costBalancedRanges")]
+pub fn cost_balanced_ranges(range_count: usize) -> Result<Vec<usize>, WasmError> {
+  let boundaries = with_valid_words(|valid_words| {
+    if range_count == 0 {
+      return Err(LetrboxdError::ZeroRangeCount);
     }
-  }
+
+    let costs = solver::cost::estimate_costs(valid_words);
+    let ranges = solver::cost::cost_balanced_ranges(&costs, range_count);
+
+    Ok(
+      std::iter::once(0)
+        .chain(ranges.into_iter().map(|range| range.end))
+        .collect(),
+    )
+  })?;
+
+  Ok(boundaries)
 }
 
-/// Recursively solves for valid 12-letter sequences, grouping the solutions by word count in the provided `SolutionsPayload`.
-/// This version filters the valid words, but does not partition them based on their immediate appendability.
-/// This strategy tends to be faster when the `valid_words` list is small.
-fn solve_filter(
-  sequence: LetterSequence,
-  solutions: &mut SolutionsPayload,
-  valid_words: &[LetterSequence],
-) {
-  match sequence.len() {
-    12 => {
-      // If we have constructed a valid sequence with exactly 12 letters, it is a solution.
-      solutions.push(sequence);
+/// Generates puzzle solutions for valid words in the specified index range, narrowed down by
+/// the registered search options.
+///
+/// # Errors
+///
+/// Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+/// valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+#[wasm_bindgen]
+pub fn solutions(range_start: usize, range_end: usize) -> Result<SolutionsPayload, WasmError> {
+  let solutions = with_valid_words(|valid_words| {
+    let starting_words = starting_words(valid_words, range_start, range_end)?;
+
+    let mut solutions = SolutionsPayload::default();
+    let arena = &mut CandidateArena::with_capacity(valid_words.len());
+    with_search_filter(valid_words, |solver, filter, valid_words| {
+      for &word in starting_words {
+        if filter.is_starting_word(word) {
+          solver.solve_in(arena, word, &mut solutions, valid_words);
+        }
+      }
+    });
+
+    Ok(solutions)
+  })?;
+
+  Ok(solutions)
+}
+
+/// Tallies how the words and letters take part in the solutions that start with the registered
+/// valid words in the specified index range, narrowed down by the registered search options.
+/// See [`solver::participation`].
+///
+/// # Errors
+///
+/// Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+/// valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+#[wasm_bindgen]
+pub fn participation(
+  range_start: usize,
+  range_end: usize,
+) -> Result<ParticipationPayload, WasmError> {
+  let payload = with_valid_words(|valid_words| {
+    let starting_words = starting_words(valid_words, range_start, range_end)?;
+
+    let mut participation = Participation::default();
+    let arena = &mut CandidateArena::with_capacity(valid_words.len());
+    with_search_filter(valid_words, |solver, filter, valid_words| {
+      for &word in starting_words {
+        if filter.is_starting_word(word) {
+          solver.solve_in(arena, word, &mut participation, valid_words);
+        }
+      }
+    });
+
+    Ok(ParticipationPayload::new(&participation))
+  })?;
+
+  Ok(payload)
+}
+
+/// Groups the solutions that start with the registered valid words in the specified index range
+/// by their path through the letters, narrowed down by the registered search options.
+/// See [`solver::paths`].
+///
+/// # Errors
+///
+/// Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+/// valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+#[wasm_bindgen(js_name = "solutionPaths")]
+pub fn solution_paths(
+  range_start: usize,
+  range_end: usize,
+) -> Result<SolutionPathsPayload, WasmError> {
+  let payload = with_valid_words(|valid_words| {
+    let starting_words = starting_words(valid_words, range_start, range_end)?;
+
+    let mut paths = SolutionPaths::default();
+    let arena = &mut CandidateArena::with_capacity(valid_words.len());
+    with_search_filter(valid_words, |solver, filter, valid_words| {
+      for &word in starting_words {
+        if filter.is_starting_word(word) {
+          solver.solve_in(arena, word, &mut paths, valid_words);
+        }
+      }
+    });
+
+    Ok(SolutionPathsPayload::new(&paths))
+  })?;
+
+  Ok(payload)
+}
+
+/// A resumable search over the registered valid words in an index range, which yields
+/// its solutions in batches so that the caller may report progress or cancel between them.
+///
+/// The stream keeps its own copy of the words that the registered search options admit when it
+/// is created, so later batches are unaffected by registering other words or options.
+#[wasm_bindgen]
+pub struct SolutionStream {
+  solver: Solver,
+  starting_words: Vec<LetterSequence>,
+  valid_words: Vec<LetterSequence>,
+  next_index: usize,
+  batch_size: usize,
+  arena: CandidateArena,
+  cancelled: bool,
+}
+
+#[wasm_bindgen]
+impl SolutionStream {
+  /// Creates a stream of solutions that start with the registered valid words in the specified
+  /// index range. Each batch holds the solutions from as many starting words as it takes to reach
+  /// at least `batch_size` solutions, or the rest of the range, whichever comes first.
+  ///
+  /// # Errors
+  ///
+  /// Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+  /// valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+  #[wasm_bindgen(constructor)]
+  pub fn new(
+    range_start: usize,
+    range_end: usize,
+    batch_size: usize,
+  ) -> Result<SolutionStream, WasmError> {
+    let stream = with_valid_words(|valid_words| {
+      let starting_words = starting_words(valid_words, range_start, range_end)?;
+
+      Ok(with_search_filter(
+        valid_words,
+        |solver, filter, valid_words| Self {
+          solver,
+          starting_words: starting_words
+            .iter()
+            .copied()
+            .filter(|&word| filter.is_starting_word(word))
+            .collect(),
+          valid_words: valid_words.to_vec(),
+          next_index: 0,
+          batch_size: batch_size.max(1),
+          arena: CandidateArena::with_capacity(valid_words.len()),
+          cancelled: false,
+        },
+      ))
+    })?;
+
+    Ok(stream)
+  }
+
+  /// Searches the next starting words in the range, returning the next batch of solutions,
+  /// or `undefined` once every starting word has been searched or the stream is cancelled.
+  #[must_use]
+  #[wasm_bindgen(js_name = "nextBatch")]
+  pub fn next_batch(&mut self) -> Option<SolutionsPayload> {
+    if self.is_done() {
+      return None;
     }
-    11 => {
-      // There are no words that can be appended to an 11-letter sequence to form a 12-letter
-      // solution because the minimum valid word length is 3 letters. This is a dead end.
+
+    let mut solutions = SolutionsPayload::default();
+    for &word in &self.starting_words[self.next_index..] {
+      self
+        .solver
+        .solve_in(&mut self.arena, word, &mut solutions, &self.valid_words);
+      self.next_index += 1;
+
+      if solutions.len() >= self.batch_size {
+        break;
+      }
     }
-    _ => {
-      let remaining_valid_words = valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.shared_letter_count(sequence) <= 1)
-        .collect::<Vec<_>>();
-      remaining_valid_words
-        .iter()
-        .copied()
-        .filter(|word| word.can_append_to(sequence))
-        .for_each(|word| {
-          solve_filter(word.append_to(sequence), solutions, &remaining_valid_words);
-        });
+
+    Some(solutions)
+  }
+
+  /// Cancels the stream, so that every later call to [`next_batch`](Self::next_batch)
+  /// returns `undefined` without searching.
+  pub fn cancel(&mut self) {
+    self.cancelled = true;
+  }
+
+  /// Returns `true` once every starting word has been searched, or the stream is cancelled.
+  #[must_use]
+  #[wasm_bindgen(getter, js_name = "isDone")]
+  pub fn is_done(&self) -> bool {
+    self.cancelled || self.next_index >= self.starting_words.len()
+  }
+
+  /// Returns the fraction of the starting words in the range that have been searched, from `0`
+  /// to `1`, counting only those that the search options admit. A range that has no such words
+  /// is reported as fully searched.
+  #[must_use]
+  #[wasm_bindgen(getter)]
+  #[expect(clippy::cast_precision_loss)]
+  pub fn progress(&self) -> f64 {
+    if self.starting_words.is_empty() {
+      return 1.0;
     }
+
+    self.next_index as f64 / self.starting_words.len() as f64
   }
 }
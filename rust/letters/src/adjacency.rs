@@ -0,0 +1,213 @@
+//! Defines the rules for which letters of a board may follow one another within a word.
+//!
+//! The standard rule only lets a letter follow a letter from a different side of the board,
+//! but variants of the puzzle relax or tighten it. Under every rule, a letter may never follow
+//! itself, since the word list leaves out every word with a doubled letter, such as `BALL`.
+//!
+//! An [`Adjacency`] is a matrix built from a board and an [`AdjacencyRule`], which records the
+//! letters that may follow each letter, and which can forbid further pairs of letters on top of
+//! the rule.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use letters::adjacency::{Adjacency, AdjacencyRule};
+//! # use letters::LetterSequence;
+//! let standard = Adjacency::new("ABCDEFGHIJKL", AdjacencyRule::Standard);
+//! let easy = Adjacency::new("ABCDEFGHIJKL", AdjacencyRule::Easy);
+//!
+//! assert!(standard.is_valid_word(LetterSequence::new("AGE")));
+//! assert!(!standard.is_valid_word(LetterSequence::new("CAB")));
+//! assert!(easy.is_valid_word(LetterSequence::new("CAB")));
+//!
+//! let forbidden = standard.with_forbidden_pairs("GA # No A next to G.").unwrap();
+//! assert!(!forbidden.is_valid_word(LetterSequence::new("AGE")));
+//! ```
+
+use crate::{compress_letter, LetterSequence, LetterSet, ParseError};
+
+/// The count of letters in the alphabet, and so of rows in an [`Adjacency`].
+const ALPHABET_LEN: usize = 26;
+
+/// The count of sides on a board, each of which holds three letters.
+const SIDE_COUNT: usize = 4;
+
+/// A rule that decides which letters of a board may follow one another, based on their sides.
+///
+/// The sides are numbered in the order that they are given in the 12-letter input, which runs
+/// clockwise around the board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AdjacencyRule {
+  /// A letter may only follow a letter from a different side.
+  #[default]
+  Standard,
+  /// A letter may follow any other letter on the board, including one from the same side, but
+  /// not itself.
+  Easy,
+  /// A letter may only follow a letter from the side before it, going clockwise, so that each
+  /// word moves around the board one side at a time.
+  Clockwise,
+}
+
+impl AdjacencyRule {
+  /// Every rule, in the order that they are listed in help text.
+  pub const ALL: [Self; 3] = [Self::Standard, Self::Easy, Self::Clockwise];
+
+  /// Returns the lowercase name of the rule, e.g. `"clockwise"`.
+  #[must_use]
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::Standard => "standard",
+      Self::Easy => "easy",
+      Self::Clockwise => "clockwise",
+    }
+  }
+
+  /// Returns [true] if a letter from the side at index `next` may follow a letter from the side
+  /// at index `previous`.
+  #[must_use]
+  #[inline]
+  pub const fn allows_sides(self, previous: usize, next: usize) -> bool {
+    match self {
+      Self::Standard => previous != next,
+      Self::Easy => true,
+      Self::Clockwise => (previous + 1) % SIDE_COUNT == next,
+    }
+  }
+}
+
+/// The letters of a board that may follow each letter within a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Adjacency {
+  /// The letters that may follow each compressed letter. Letters that are not on the board
+  /// may neither follow nor be followed by any letter.
+  followers: [LetterSet; ALPHABET_LEN],
+}
+
+impl Adjacency {
+  /// Returns the adjacency of the letters of the given 12-letter `input` under the `rule`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the `input` does not have exactly 12 characters.
+  #[must_use]
+  pub fn new(input: &str, rule: AdjacencyRule) -> Self {
+    assert_eq!(
+      input.len(),
+      LetterSequence::CAPACITY,
+      "The input must have exactly 12 letters."
+    );
+
+    let sides = input
+      .as_bytes()
+      .chunks(3)
+      .map(|side| side.iter().map(|&letter| compress_letter(letter)))
+      .collect::<Vec<_>>();
+
+    let mut followers = [LetterSet::empty(); ALPHABET_LEN];
+    for (previous_side, previous_letters) in sides.iter().enumerate() {
+      let next_letters = sides
+        .iter()
+        .enumerate()
+        .filter(|&(next_side, _)| rule.allows_sides(previous_side, next_side))
+        .flat_map(|(_, letters)| letters.clone())
+        .collect::<LetterSet>();
+
+      for letter in previous_letters.clone() {
+        followers[letter as usize] = next_letters.difference(LetterSet::empty().insert(letter));
+      }
+    }
+
+    Self { followers }
+  }
+
+  /// Returns the adjacency of the letters of the given 12-letter `input` under the standard rule.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the `input` does not have exactly 12 characters.
+  #[must_use]
+  pub fn standard(input: &str) -> Self {
+    Self::new(input, AdjacencyRule::Standard)
+  }
+
+  /// Returns the adjacency, forbidding the ASCII letters `lhs` and `rhs` from following one
+  /// another in either order.
+  ///
+  /// # Panics
+  ///
+  /// In debug mode, this will panic if either letter is not an uppercase ASCII letter.
+  #[must_use]
+  pub fn with_forbidden_pair(mut self, lhs: u8, rhs: u8) -> Self {
+    let (lhs, rhs) = (compress_letter(lhs), compress_letter(rhs));
+    let forbid =
+      |followers: LetterSet, letter: u8| followers.difference(LetterSet::empty().insert(letter));
+
+    self.followers[lhs as usize] = forbid(self.followers[lhs as usize], rhs);
+    self.followers[rhs as usize] = forbid(self.followers[rhs as usize], lhs);
+    self
+  }
+
+  /// Returns the adjacency, forbidding every pair of letters that is listed in the `pairs`.
+  ///
+  /// Each pair is two letters in either case, such as `QU`, and forbids those letters from
+  /// following one another in either order. Pairs are separated by whitespace or commas, and
+  /// anything after a `#` on a line is a comment, so that a list can be kept in a file:
+  ///
+  /// ```text
+  /// # Tonight's forbidden pairs.
+  /// QU, TH
+  /// ER
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns [`ParseError::InvalidLetter`] if a pair contains a character that is not an ASCII
+  /// letter, or [`ParseError::InvalidPair`] with the index of the first pair that does not have
+  /// exactly two letters.
+  pub fn with_forbidden_pairs(mut self, pairs: &str) -> Result<Self, ParseError> {
+    let pairs = pairs
+      .lines()
+      .map(|line| line.split_once('#').map_or(line, |(pair, _)| pair))
+      .flat_map(|line| line.split(|c: char| c.is_whitespace() || c == ','))
+      .filter(|pair| !pair.is_empty());
+
+    for (index, pair) in pairs.enumerate() {
+      if let Some(letter) = pair.chars().find(|letter| !letter.is_ascii_alphabetic()) {
+        return Err(ParseError::InvalidLetter(letter));
+      }
+
+      let &[lhs, rhs] = pair.as_bytes() else {
+        return Err(ParseError::InvalidPair(index));
+      };
+
+      self = self.with_forbidden_pair(lhs.to_ascii_uppercase(), rhs.to_ascii_uppercase());
+    }
+
+    Ok(self)
+  }
+
+  /// Returns the letters that may follow the compressed `letter`.
+  #[must_use]
+  #[inline]
+  pub const fn followers(&self, letter: u8) -> LetterSet {
+    self.followers[letter as usize]
+  }
+
+  /// Returns [true] if the compressed letter `next` may follow the compressed letter `previous`.
+  #[must_use]
+  #[inline]
+  pub const fn can_follow(&self, previous: u8, next: u8) -> bool {
+    self.followers(previous).has(next)
+  }
+
+  /// Returns [true] if every letter of the `word` may follow the letter before it.
+  #[must_use]
+  #[inline]
+  pub fn is_valid_word(&self, word: LetterSequence) -> bool {
+    word
+      .letters_rev()
+      .zip(word.letters_rev().skip(1))
+      .all(|(next, previous)| self.can_follow(previous, next))
+  }
+}
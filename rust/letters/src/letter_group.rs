@@ -1,4 +1,7 @@
 //! Defines a way to group the letters of the four input sides of a Letter Boxed puzzle.
+//!
+//! Deprecated in favour of [`Adjacency`](crate::Adjacency), which also supports the variant rules
+//! of the puzzle and forbidden pairs of letters.
 
 /// Creates a closure that classifies letters into one of four groups or marks
 /// them as invalid. Each group corresponds to one of the three letters on each
@@ -11,6 +14,7 @@
 /// # Example
 ///
 /// ```
+/// # #![allow(deprecated)]
 /// # use letters::create_letter_group_function;
 /// # use letters::letter_group::LetterGroup;
 /// // "ABC" -> Group1, "DEF" -> Group2, "GHI" -> Group3, "JKL" -> Group4
@@ -22,6 +26,7 @@
 /// assert_eq!(letter_group(compress(b'L')), LetterGroup::Group4);
 /// assert_eq!(letter_group(compress(b'X')), LetterGroup::Invalid);
 /// ```
+#[deprecated(note = "Use `Adjacency::standard` to check the words of a board instead.")]
 #[macro_export]
 macro_rules! create_letter_group_function {
   ($str:expr) => {{
@@ -82,6 +87,7 @@ macro_rules! create_letter_group_function {
 /// - [`Invalid`]: The letter does not fit any of the four defined groups.
 /// - [`Group1`], [`Group2`], [`Group3`], [`Group4`]: Each variant indicates that the
 ///   letter belongs to one of four different categories.
+#[deprecated(note = "Use `Adjacency::standard` to check the words of a board instead.")]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LetterGroup {
   /// A letter that does not belong to any defined group.
@@ -96,6 +102,7 @@ pub enum LetterGroup {
   Group4,
 }
 
+#[expect(deprecated)]
 impl LetterGroup {
   /// Determines whether this group can be adjacent to `other`.
   ///
@@ -106,6 +113,7 @@ impl LetterGroup {
   /// # Example
   ///
   /// ```
+  /// # #![allow(deprecated)]
   /// # use letters::letter_group::LetterGroup;
   /// assert!(LetterGroup::Group1.can_be_adjacent_to(LetterGroup::Group2));
   /// assert!(!LetterGroup::Group1.can_be_adjacent_to(LetterGroup::Group1));
@@ -1,14 +1,18 @@
 //! Defines functionality to represent the sequence of submitted letters to the game board.
 
 use crate::compress_letter;
+use crate::decompress_letter;
+#[expect(deprecated)]
 use crate::LetterGroup;
 use crate::LetterSet;
+use crate::ParseError;
 use crate::Solution;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
 use std::ops::RangeBounds;
-
-#[cfg(feature = "wasm")]
-use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 #[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
@@ -83,13 +87,8 @@ use wasm_bindgen::prelude::*;
 /// ```
 ///
 /// [ASCII]: https://en.wikipedia.org/wiki/ASCII
-#[derive(Clone, Copy, PartialOrd, Ord)]
-#[cfg_attr(feature = "wasm", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy)]
 #[cfg_attr(feature = "wasm", wasm_bindgen)]
-// The single use of unsafe in this code is a function that creates a string from raw
-// bytes and does not violate any constructor invariants for [`LetterSequence`] itself.
-// https://rust-lang.github.io/rust-clippy/master/index.html#unsafe_derive_deserialize
-#[allow(clippy::unsafe_derive_deserialize)]
 pub struct LetterSequence {
   letters: u64,
   letter_set: LetterSet,
@@ -104,6 +103,19 @@ impl PartialEq for LetterSequence {
   }
 }
 
+impl PartialOrd for LetterSequence {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// Orders only by the letters, in agreement with [`PartialEq`], which ignores the word boundaries.
+impl Ord for LetterSequence {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.letters.cmp(&other.letters)
+  }
+}
+
 impl Debug for LetterSequence {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("LetterSequence")
@@ -114,9 +126,76 @@ impl Debug for LetterSequence {
   }
 }
 
-impl From<&str> for LetterSequence {
-  fn from(letters: &str) -> Self {
-    Self::new(letters)
+/// Hashes only the letters, in agreement with [`PartialEq`], which ignores the word boundaries.
+impl Hash for LetterSequence {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.letters.hash(state);
+  }
+}
+
+/// Parses a single-word [`LetterSequence`] from up to 12 distinct ASCII letters in either case.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::{LetterSequence, ParseError};
+/// assert_eq!("nice".parse::<LetterSequence>(), Ok(LetterSequence::new("NICE")));
+/// assert_eq!("NINE".parse::<LetterSequence>(), Err(ParseError::RepeatedLetter('N')));
+/// assert_eq!("NICE!".parse::<LetterSequence>(), Err(ParseError::InvalidLetter('!')));
+/// assert_eq!(
+///   "ABCDEFGHIJKLM".parse::<LetterSequence>(),
+///   Err(ParseError::TooLong(13)),
+/// );
+/// ```
+/// Parses a [`LetterSequence`] from the letters of a single word, e.g. `"fish"`, or from words
+/// separated by spaces or hyphens, e.g. `"FISH HOPE"` or `"FISH-HOPE"`, which are parsed by
+/// [`Solution::parse_words`] and keep their word boundaries.
+impl FromStr for LetterSequence {
+  type Err = ParseError;
+
+  fn from_str(letters: &str) -> Result<Self, Self::Err> {
+    if letters.contains(|letter: char| letter.is_whitespace() || letter == '-') {
+      return Solution::parse_words(letters).map(|(sequence, _)| sequence);
+    }
+
+    let len = letters.chars().count();
+    if len > Self::CAPACITY {
+      return Err(ParseError::TooLong(len));
+    }
+
+    letters.chars().try_fold(Self::empty(), |sequence, letter| {
+      let byte = u8::try_from(letter).map_err(|_| ParseError::InvalidLetter(letter))?;
+      sequence.try_with_letter(byte)
+    })
+  }
+}
+
+/// Parses a [`LetterSequence`] in the same way as [`FromStr`].
+///
+/// This replaces the former infallible `From<&str>` implementation, which cannot coexist with
+/// it. Use [`LetterSequence::new`] to build a sequence from a known-good literal instead.
+impl TryFrom<&str> for LetterSequence {
+  type Error = ParseError;
+
+  fn try_from(letters: &str) -> Result<Self, Self::Error> {
+    letters.parse()
+  }
+}
+
+/// Serializes a [`LetterSequence`] as its words separated by spaces, e.g. `"FISH HOPE"`, so that
+/// the word boundaries survive a round trip, unlike its [`Display`] form.
+impl Serialize for LetterSequence {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&self.solution_string())
+  }
+}
+
+/// Deserializes a [`LetterSequence`] from any of the forms that are accepted by [`FromStr`].
+impl<'de> Deserialize<'de> for LetterSequence {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    String::deserialize(deserializer)?
+      .parse()
+      .map_err(serde::de::Error::custom)
   }
 }
 
@@ -196,10 +275,13 @@ impl LetterSequence {
   /// Creates a new [`LetterSequence`] from the provided `letters` string.
   /// This will convert each character into its compressed 5-bit representation.
   ///
+  /// This is intended for `const` contexts and letters that are known to be valid.
+  /// Use [`str::parse`] or [`TryFrom`] to build a sequence from untrusted input.
+  ///
   /// # Panics
   ///
   /// In debug mode, this function will panic if any of the letters are not uppercase ASCII,
-  /// or if the string length exceeds the capacity of 12.
+  /// if any letter is repeated, or if the string length exceeds the capacity of 12.
   #[must_use]
   #[inline]
   pub const fn new(letters: &str) -> Self {
@@ -234,13 +316,72 @@ impl LetterSequence {
     sequence
   }
 
+  /// Returns the packed [u64] representation of the letters in the [`LetterSequence`],
+  /// including the length-tracker bit. The [`LetterSet`] and [`Solution`] are not included.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSequence;
+  /// assert_eq!(LetterSequence::new("NICE").to_bits(), 0b1_01101_01000_00010_00100);
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn to_bits(self) -> u64 {
+    self.letters
+  }
+
+  /// Rebuilds a single-word [`LetterSequence`] from its packed [u64] representation,
+  /// as returned by [`to_bits`](Self::to_bits), recomputing its [`LetterSet`] and [`Solution`].
+  ///
+  /// Returns [`None`] if the `bits` are missing the length-tracker bit, hold more than 12 letters,
+  /// hold a 5-bit value that is not a letter, or hold the same letter more than once.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSequence;
+  /// let nice = LetterSequence::new("NICE");
+  /// assert_eq!(LetterSequence::from_bits(nice.to_bits()), Some(nice));
+  /// assert_eq!(LetterSequence::from_bits(0), None);
+  /// ```
+  #[must_use]
+  pub const fn from_bits(bits: u64) -> Option<Self> {
+    if bits == 0 {
+      return None;
+    }
+
+    let letter_bits = (u64::BITS - bits.leading_zeros() - 1) as usize;
+    if !letter_bits.is_multiple_of(Self::BITS_PER_LETTER)
+      || letter_bits / Self::BITS_PER_LETTER > Self::CAPACITY
+    {
+      return None;
+    }
+
+    let mut sequence = Self::empty();
+    let mut index = letter_bits / Self::BITS_PER_LETTER;
+    while index > 0 {
+      index -= 1;
+
+      #[expect(clippy::cast_possible_truncation)]
+      let letter = (bits >> (index * Self::BITS_PER_LETTER)) as u8 & 0b1_1111;
+      if letter > compress_letter(b'Z') || sequence.letter_set.has(letter) {
+        return None;
+      }
+
+      sequence = sequence.with_letter(decompress_letter(letter));
+    }
+
+    Some(sequence)
+  }
+
   /// Returns the count of letters in the [`LetterSequence`].
   ///
   /// # Example
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// assert_eq!(LetterSequence::from("NICE").len(), 4);
+  /// assert_eq!(LetterSequence::new("NICE").len(), 4);
   /// ```
   /// ```text
   ///                                 Length-tracker bit ╾┐  Length 4 ╾┐
@@ -261,8 +402,8 @@ impl LetterSequence {
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// let word1 = LetterSequence::from("FISH");
-  /// let word2 = LetterSequence::from("HOPE");
+  /// let word1 = LetterSequence::new("FISH");
+  /// let word2 = LetterSequence::new("HOPE");
   /// assert_eq!(word2.append_to(word1).word_count(), 2);
   /// ```
   #[must_use]
@@ -271,6 +412,44 @@ impl LetterSequence {
     self.solution.word_count()
   }
 
+  /// Returns the [`Solution`] that marks the word boundaries within this [`LetterSequence`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSequence, Solution};
+  /// let word1 = LetterSequence::new("FISH");
+  /// let word2 = LetterSequence::new("HOPE");
+  /// assert_eq!(word2.append_to(word1).solution(), "0001001".parse::<Solution>().unwrap());
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn solution(self) -> Solution {
+    self.solution
+  }
+
+  /// Returns the same letters with their word boundaries replaced by the given [`Solution`],
+  /// which splits the letters into a different sequence of words.
+  ///
+  /// The [`Solution`] should mark the final letter of the sequence as a boundary, as every
+  /// [`Solution`] of a complete sequence does.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSequence, Solution};
+  /// let (sequence, _) = Solution::parse_words("IMPARTED DUNKS").unwrap();
+  /// let split = sequence.with_solution("000001010001".parse::<Solution>().unwrap());
+  ///
+  /// assert_eq!(split, sequence);
+  /// assert_eq!(split.solution_string(), "IMPART TED DUNKS");
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn with_solution(self, solution: Solution) -> Self {
+    Self { solution, ..self }
+  }
+
   /// Returns [true] if the sequence contains no letters, otherwise [false].
   ///
   /// # Example
@@ -299,7 +478,7 @@ impl LetterSequence {
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// assert!(LetterSequence::from("ABCDEFGHIJKL").has_all_letters());
+  /// assert!(LetterSequence::new("ABCDEFGHIJKL").has_all_letters());
   /// ```
   #[must_use]
   #[inline]
@@ -314,7 +493,7 @@ impl LetterSequence {
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// LetterSequence::from("NIC").with_letter(b'E');
+  /// LetterSequence::new("NIC").with_letter(b'E');
   /// ```
   /// ```text
   ///                                       Length-tracker bit ╾┐
@@ -343,13 +522,41 @@ impl LetterSequence {
     }
   }
 
+  /// Returns a new [`LetterSequence`] with the given ASCII `letter` appended to the end, in either
+  /// case, or an error if the sequence is full, or the letter is invalid or already present.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`ParseError::TooLong`], [`ParseError::InvalidLetter`] or [`ParseError::RepeatedLetter`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSequence, ParseError};
+  /// let nic = LetterSequence::new("NIC");
+  ///
+  /// assert_eq!(nic.try_with_letter(b'e'), Ok(LetterSequence::new("NICE")));
+  /// assert_eq!(nic.try_with_letter(b'N'), Err(ParseError::RepeatedLetter('N')));
+  /// ```
+  pub const fn try_with_letter(self, letter: u8) -> Result<Self, ParseError> {
+    if self.len() == Self::CAPACITY {
+      return Err(ParseError::TooLong(Self::CAPACITY + 1));
+    }
+
+    if let Err(error) = self.letter_set.try_insert_ascii(letter) {
+      return Err(error);
+    }
+
+    Ok(self.with_letter(letter.to_ascii_uppercase()))
+  }
+
   /// Returns a new [`LetterSequence`] that has `n` letters cut from the start of the input [`LetterSequence`].
   ///
   /// # Example
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// LetterSequence::from("NICE").cut_from_start(2);
+  /// LetterSequence::new("NICE").cut_from_start(2);
   /// ```
   /// ```text
   ///                                 Length-tracker bit ╾┐
@@ -458,7 +665,7 @@ impl LetterSequence {
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// LetterSequence::from("NICE").cut_from_end(2);
+  /// LetterSequence::new("NICE").cut_from_end(2);
   /// ```
   ///
   /// **Before**
@@ -500,7 +707,7 @@ impl LetterSequence {
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// LetterSequence::from("NICE").slice(1..3);
+  /// LetterSequence::new("NICE").slice(1..3);
   /// ```
   /// ```text
   ///                                 Length-tracker bit ╾┐
@@ -562,7 +769,7 @@ impl LetterSequence {
   /// # use letters::LetterSequence;
   /// let compress = |byte| byte - b'A';
   /// assert_eq!(
-  ///   LetterSequence::from("NICE").letters_rev().collect::<Vec<_>>(),
+  ///   LetterSequence::new("NICE").letters_rev().collect::<Vec<_>>(),
   ///   vec![compress(b'E'), compress(b'C'), compress(b'I'), compress(b'N')],
   /// );
   /// ```
@@ -590,7 +797,7 @@ impl LetterSequence {
   /// ```rust
   /// # use letters::LetterSequence;
   /// assert_eq!(
-  ///   LetterSequence::from("NICE").ascii_bytes().collect::<Vec<_>>(),
+  ///   LetterSequence::new("NICE").ascii_bytes().collect::<Vec<_>>(),
   ///   vec![b'N', b'I', b'C', b'E'],
   /// );
   /// ```
@@ -598,6 +805,23 @@ impl LetterSequence {
     ASCIIBytesIter(self.reversed_internal_representation())
   }
 
+  /// Returns the [`LetterSet`] of all letters contained in this [`LetterSequence`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSequence, LetterSet};
+  /// assert_eq!(
+  ///   LetterSequence::new("NICE").letter_set(),
+  ///   LetterSet::from_ascii_slice(b"CEIN"),
+  /// );
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn letter_set(self) -> LetterSet {
+    self.letter_set
+  }
+
   /// Returns the count of letters that two [`LetterSequence`] have in common.
   #[must_use]
   #[inline]
@@ -661,6 +885,8 @@ impl LetterSequence {
 
   /// Returns [true] if this sequence of letters forms a valid word, according to
   /// the letter grouping logic provided by [`LetterGroup`].
+  #[deprecated(note = "Use `Adjacency::standard(input).is_valid_word(word)` instead.")]
+  #[expect(deprecated)]
   #[must_use]
   #[inline]
   pub fn is_valid_word<F>(self, letter_group: &F) -> bool
@@ -694,9 +920,9 @@ impl LetterSequence {
   ///
   /// ```rust
   /// # use letters::LetterSequence;
-  /// let word1 = LetterSequence::from("FISH");
-  /// let word2 = LetterSequence::from("HOPE");
-  /// let word3 = LetterSequence::from("EAT");
+  /// let word1 = LetterSequence::new("FISH");
+  /// let word2 = LetterSequence::new("HOPE");
+  /// let word3 = LetterSequence::new("EAT");
   ///
   /// let sequence = word1.prepend_to(word2).prepend_to(word3);
   /// assert_eq!(sequence.solution_string(), "FISH HOPE EAT");
@@ -724,13 +950,42 @@ impl LetterSequence {
     unsafe { String::from_utf8_unchecked(bytes) }
   }
 
+  /// Returns a canonical identifier for the solution, with its words separated by hyphens
+  /// rather than spaces, e.g. `"FISH-HOPE-EAT"`, so that it can be used in URLs, cache keys
+  /// and archives without escaping.
+  ///
+  /// The identifier can be parsed back with [`str::parse`] or [`Solution::parse_words`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSequence, Solution};
+  /// let word1 = LetterSequence::new("FISH");
+  /// let word2 = LetterSequence::new("HOPE");
+  /// let word3 = LetterSequence::new("EAT");
+  ///
+  /// let sequence = word1.prepend_to(word2).prepend_to(word3);
+  /// assert_eq!(sequence.solution_id(), "FISH-HOPE-EAT");
+  ///
+  /// let (parsed, solution) = Solution::parse_words(&sequence.solution_id()).unwrap();
+  /// assert_eq!(parsed, sequence);
+  /// assert_eq!(solution, sequence.solution());
+  ///
+  /// let parsed = sequence.solution_id().parse::<LetterSequence>().unwrap();
+  /// assert_eq!(parsed.solution(), sequence.solution());
+  /// ```
+  #[must_use]
+  pub fn solution_id(self) -> String {
+    self.solution_string().replace(' ', "-")
+  }
+
   /// Returns the byte corresponding to the first letter of the sequence.
   ///
   /// # Panics
   ///
   /// Panics in debug mode if the [`LetterSequence`] is empty.
   #[expect(clippy::cast_possible_truncation)]
-  const fn first_letter(self) -> u8 {
+  pub(crate) const fn first_letter(self) -> u8 {
     debug_assert!(!self.is_empty());
     (self.letters >> ((self.len() - 1) * LetterSequence::BITS_PER_LETTER)) as u8 & 0b1_1111
   }
@@ -741,7 +996,7 @@ impl LetterSequence {
   ///
   /// Panics in debug mode if the [`LetterSequence`] is empty.
   #[expect(clippy::cast_possible_truncation)]
-  const fn last_letter(self) -> u8 {
+  pub(crate) const fn last_letter(self) -> u8 {
     debug_assert!(!self.is_empty());
     self.letters as u8 & 0b1_1111
   }
@@ -935,7 +1190,7 @@ fn without_length_tracker_bit() {
 #[expect(clippy::unusual_byte_groupings)]
 fn reversed_internal_representation() {
   assert_eq!(
-    LetterSequence::from("NICE").reversed_internal_representation(),
+    LetterSequence::new("NICE").reversed_internal_representation(),
     0b_01101_01000_00010_00100_1_00000_00000_00000_00000_00000_00000_00000_00000_000,
     // │ N │ │ I │ │ C │ │ E │   └─────────────────────┬───────────────────────┘ └┬┘
     // └───┘ └───┘ └───┘ └───┘     Empty letter space ╾┘       Extra unused bits ╾┘
@@ -2,12 +2,17 @@
 
 use std::fmt::Debug;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::{
+  BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+use std::str::FromStr;
 
 use crate::compress_letter;
 use crate::LetterSequence;
+use crate::ParseError;
 
-#[cfg(feature = "wasm")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// [`LetterSet`] is a compact bitset representing uppercase ASCII letters
 /// using a single [u32]. Each of the 26 letters corresponds to a value in
@@ -19,8 +24,16 @@ use serde::{Deserialize, Serialize};
 /// ```
 ///
 /// [`LetterSet`] is immutable by design, and inserting a letter into the
-/// set returns a new set, leaving the original intact. There is no way to
-/// remove a letter from the set, since that functionality is not needed.
+/// set returns a new set, leaving the original intact. Sets are combined with
+/// the usual set operations, either by name or by their bitwise operators:
+///
+/// | Operation                                                 | Operator |
+/// |-----------------------------------------------------------|----------|
+/// | [`union`](LetterSet::union)                               | `a \| b` |
+/// | [`intersection`](LetterSet::intersection)                 | `a & b`  |
+/// | [`difference`](LetterSet::difference)                     | `a - b`  |
+/// | [`symmetric_difference`](LetterSet::symmetric_difference) | `a ^ b`  |
+/// | [`complement`](LetterSet::complement)                     | `!a`     |
 ///
 /// # Example
 ///
@@ -37,7 +50,6 @@ use serde::{Deserialize, Serialize};
 /// assert!(set_with_e.has(compress(b'E')));
 /// ```
 #[derive(Clone, Copy, Default, PartialOrd, Ord)]
-#[cfg_attr(feature = "wasm", derive(Serialize, Deserialize))]
 pub struct LetterSet(u32);
 
 impl Debug for LetterSet {
@@ -66,6 +78,67 @@ impl PartialEq for LetterSet {
   }
 }
 
+impl Hash for LetterSet {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.0.hash(state);
+  }
+}
+
+/// Parses a [`LetterSet`] from its letters in any order and either case, optionally wrapped
+/// in the square brackets of its [`Display`] form.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::{LetterSet, ParseError};
+/// let set = "nice".parse::<LetterSet>().unwrap();
+///
+/// assert_eq!(set, LetterSet::from_ascii_slice(b"CEIN"));
+/// assert_eq!(set.to_string().parse(), Ok(set));
+/// assert_eq!("NINE".parse::<LetterSet>(), Err(ParseError::RepeatedLetter('N')));
+/// ```
+impl FromStr for LetterSet {
+  type Err = ParseError;
+
+  fn from_str(letters: &str) -> Result<Self, Self::Err> {
+    let letters = letters
+      .strip_prefix('[')
+      .and_then(|letters| letters.strip_suffix(']'))
+      .unwrap_or(letters);
+
+    letters
+      .chars()
+      .try_fold(Self::empty(), |letter_set, letter| {
+        let byte = u8::try_from(letter).map_err(|_| ParseError::InvalidLetter(letter))?;
+        letter_set.try_insert_ascii(byte)
+      })
+  }
+}
+
+impl TryFrom<&str> for LetterSet {
+  type Error = ParseError;
+
+  fn try_from(letters: &str) -> Result<Self, Self::Error> {
+    letters.parse()
+  }
+}
+
+/// Serializes a [`LetterSet`] as its [`Display`] form, e.g. `"[CEIN]"`.
+impl Serialize for LetterSet {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+/// Deserializes a [`LetterSet`] from any string that it can be [parsed](FromStr) from.
+impl<'de> Deserialize<'de> for LetterSet {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    String::deserialize(deserializer)?
+      .parse()
+      .map_err(serde::de::Error::custom)
+  }
+}
+
 impl LetterSet {
   /// Returns an empty [`LetterSet`] with no letters included.
   ///
@@ -118,19 +191,37 @@ impl LetterSet {
     letter_set
   }
 
+  /// Returns a [`LetterSet`] that contains every letter from `'A'` through `'Z'`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// assert_eq!(LetterSet::all().len(), 26);
+  /// assert_eq!(LetterSet::all().complement(), LetterSet::empty());
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn all() -> Self {
+    Self(Self::ALL_LETTERS)
+  }
+
   /// Constructs a [`LetterSet`] from a slice of ASCII bytes.
   ///
   /// # Panics
   ///
   /// Panics in debug mode if one if any byte is not in the range from `b'A'..=b'Z'`.
   #[must_use]
-  pub fn from_ascii_slice(letters: &[u8]) -> Self {
-    letters
-      .iter()
-      .copied()
-      .fold(LetterSet::empty(), |letter_set, letter| {
-        letter_set.insert(compress_letter(letter))
-      })
+  pub const fn from_ascii_slice(letters: &[u8]) -> Self {
+    let mut letter_set = Self::empty();
+    let mut index = 0;
+
+    while index < letters.len() {
+      letter_set = letter_set.insert(compress_letter(letters[index]));
+      index += 1;
+    }
+
+    letter_set
   }
 
   /// Returns the count of letters contained in the set.
@@ -253,6 +344,36 @@ impl LetterSet {
     Self(self.0 | 1 << letter)
   }
 
+  /// Returns a new [`LetterSet`] with the given ASCII `letter` added, in either case,
+  /// or an error if it is not a letter or is already present in this [`LetterSet`].
+  ///
+  /// # Errors
+  ///
+  /// Returns [`ParseError::InvalidLetter`] or [`ParseError::RepeatedLetter`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSet, ParseError};
+  /// let set = LetterSet::empty().try_insert_ascii(b'a').unwrap();
+  ///
+  /// assert!(set.has_ascii(b'A'));
+  /// assert_eq!(set.try_insert_ascii(b'A'), Err(ParseError::RepeatedLetter('A')));
+  /// assert_eq!(set.try_insert_ascii(b'?'), Err(ParseError::InvalidLetter('?')));
+  /// ```
+  pub const fn try_insert_ascii(self, letter: u8) -> Result<Self, ParseError> {
+    if !letter.is_ascii_alphabetic() {
+      return Err(ParseError::InvalidLetter(letter as char));
+    }
+
+    let letter = letter.to_ascii_uppercase();
+    if self.has(compress_letter(letter)) {
+      return Err(ParseError::RepeatedLetter(letter as char));
+    }
+
+    Ok(self.insert(compress_letter(letter)))
+  }
+
   /// Returns a new [`LetterSet`] that contains only the letters present in both
   /// `self` and `other`.
   ///
@@ -317,6 +438,146 @@ impl LetterSet {
     Self(self.0 | other.0)
   }
 
+  /// Returns a new [`LetterSet`] that contains the letters present in `self`
+  /// but not present in `other`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let compress = |letter| letter - b'A';
+  ///
+  /// let lhs = LetterSet::empty()
+  ///   .insert(compress(b'A'))
+  ///   .insert(compress(b'B'))
+  ///   .insert(compress(b'C'));
+  ///
+  /// let rhs = LetterSet::empty()
+  ///   .insert(compress(b'A'))
+  ///   .insert(compress(b'B'))
+  ///   .insert(compress(b'D'));
+  ///
+  /// let difference = lhs.difference(rhs);
+  ///
+  /// assert!(!difference.has(compress(b'A')));
+  /// assert!(!difference.has(compress(b'B')));
+  /// assert!(difference.has(compress(b'C')));
+  /// assert!(!difference.has(compress(b'D')));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn difference(self, other: LetterSet) -> LetterSet {
+    Self(self.0 & !other.0)
+  }
+
+  /// Returns a new [`LetterSet`] that contains the letters present in exactly one
+  /// of `self` and `other`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let lhs = LetterSet::from_ascii_slice(b"ABC");
+  /// let rhs = LetterSet::from_ascii_slice(b"ABD");
+  ///
+  /// assert_eq!(lhs.symmetric_difference(rhs), LetterSet::from_ascii_slice(b"CD"));
+  /// assert_eq!(lhs ^ rhs, LetterSet::from_ascii_slice(b"CD"));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn symmetric_difference(self, other: LetterSet) -> LetterSet {
+    Self(self.0 ^ other.0)
+  }
+
+  /// Returns a new [`LetterSet`] that contains every letter from `'A'` through `'Z'`
+  /// that is not present in `self`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let set = LetterSet::from_ascii_slice(b"AEIOU");
+  ///
+  /// assert_eq!(set.complement().len(), 21);
+  /// assert!(set.complement().is_disjoint(set));
+  /// assert_eq!(!set, set.complement());
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn complement(self) -> LetterSet {
+    Self(!self.0 & Self::ALL_LETTERS)
+  }
+
+  /// Returns a new [`LetterSet`] that contains the letters of the `board` that are
+  /// not present in `self`, such as the letters a partial solution has yet to use.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let board = LetterSet::from_ascii_slice(b"AIODGTESUNPR");
+  /// let used = LetterSet::from_ascii_slice(b"DOG");
+  ///
+  /// assert_eq!(used.complement_in(board), LetterSet::from_ascii_slice(b"AITESUNPR"));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn complement_in(self, board: LetterSet) -> LetterSet {
+    board.difference(self)
+  }
+
+  /// Returns [true] if every letter in `self` is also present in `other`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let board = LetterSet::from_ascii_slice(b"AIODGTESUNPR");
+  ///
+  /// assert!(LetterSet::from_ascii_slice(b"DOG").is_subset(board));
+  /// assert!(!LetterSet::from_ascii_slice(b"CAT").is_subset(board));
+  /// assert!(LetterSet::empty().is_subset(board));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn is_subset(self, other: LetterSet) -> bool {
+    self.0 & !other.0 == 0
+  }
+
+  /// Returns [true] if every letter in `other` is also present in `self`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let board = LetterSet::from_ascii_slice(b"AIODGTESUNPR");
+  ///
+  /// assert!(board.is_superset(LetterSet::from_ascii_slice(b"DOG")));
+  /// assert!(!board.is_superset(LetterSet::from_ascii_slice(b"CAT")));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn is_superset(self, other: LetterSet) -> bool {
+    other.is_subset(self)
+  }
+
+  /// Returns [true] if `self` and `other` have no letters in common.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let dog = LetterSet::from_ascii_slice(b"DOG");
+  ///
+  /// assert!(dog.is_disjoint(LetterSet::from_ascii_slice(b"CAT")));
+  /// assert!(!dog.is_disjoint(LetterSet::from_ascii_slice(b"GNU")));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn is_disjoint(self, other: LetterSet) -> bool {
+    self.0 & other.0 == 0
+  }
+
   /// Returns an iterator over the ASCII bytes contained with this [`LetterSet`].
   #[must_use]
   pub fn ascii_bytes(self) -> AsciiBytes {
@@ -325,9 +586,155 @@ impl LetterSet {
       letter_set: self,
     }
   }
+
+  /// Returns an iterator over the compressed letters contained within this [`LetterSet`],
+  /// in alphabetical order.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::LetterSet;
+  /// let compress = |letter| letter - b'A';
+  /// let set = LetterSet::from_ascii_slice(b"NICE");
+  ///
+  /// assert_eq!(
+  ///   set.letters().collect::<Vec<_>>(),
+  ///   [compress(b'C'), compress(b'E'), compress(b'I'), compress(b'N')],
+  /// );
+  /// assert_eq!(set.letters().collect::<LetterSet>(), set);
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn letters(self) -> Letters {
+    Letters(self.0)
+  }
+
+  /// The bits of the 26 letters from `'A'` through `'Z'`.
+  const ALL_LETTERS: u32 = (1 << 26) - 1;
+}
+
+/// Collects compressed letters into a [`LetterSet`]. Unlike [`LetterSet::insert`],
+/// any letter may be repeated.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::LetterSet;
+/// let compress = |letter| letter - b'A';
+/// let set = b"NINE".iter().map(|&letter| compress(letter)).collect::<LetterSet>();
+///
+/// assert_eq!(set, LetterSet::from_ascii_slice(b"EIN"));
+/// ```
+impl FromIterator<u8> for LetterSet {
+  fn from_iter<I: IntoIterator<Item = u8>>(letters: I) -> Self {
+    let mut letter_set = Self::empty();
+    letter_set.extend(letters);
+    letter_set
+  }
+}
+
+/// Collects the union of many [`LetterSet`] instances.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::LetterSet;
+/// let sides = [b"AIO", b"DGT", b"ESU", b"NPR"].map(|side| LetterSet::from_ascii_slice(side));
+///
+/// assert_eq!(sides.into_iter().collect::<LetterSet>().len(), 12);
+/// ```
+impl FromIterator<LetterSet> for LetterSet {
+  fn from_iter<I: IntoIterator<Item = LetterSet>>(letter_sets: I) -> Self {
+    letter_sets.into_iter().fold(Self::empty(), Self::union)
+  }
+}
+
+/// Adds compressed letters to a [`LetterSet`]. Unlike [`LetterSet::insert`],
+/// letters that are already present are ignored.
+///
+/// # Panics
+///
+/// In debug mode, this will panic if a letter is not within the compressed-value
+/// range of A through Z.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::LetterSet;
+/// let compress = |letter| letter - b'A';
+/// let mut set = LetterSet::from_ascii_slice(b"DOG");
+/// set.extend([compress(b'G'), compress(b'U')]);
+///
+/// assert_eq!(set, LetterSet::from_ascii_slice(b"DOGU"));
+/// ```
+impl Extend<u8> for LetterSet {
+  fn extend<I: IntoIterator<Item = u8>>(&mut self, letters: I) {
+    for letter in letters {
+      debug_assert!(
+        letter <= compress_letter(b'Z'),
+        "The letter should be within range A through Z."
+      );
+      self.0 |= 1 << letter;
+    }
+  }
+}
+
+/// Adds the letters of each [`LetterSet`] to this one.
+impl Extend<LetterSet> for LetterSet {
+  fn extend<I: IntoIterator<Item = LetterSet>>(&mut self, letter_sets: I) {
+    *self = letter_sets.into_iter().fold(*self, Self::union);
+  }
+}
+
+/// Implements a binary operator, and its assigning form, in terms of a [`LetterSet`] method.
+macro_rules! impl_letter_set_operator {
+  ($operator:ident, $function:ident, $assign_operator:ident, $assign_function:ident, $method:ident) => {
+    /// Equivalent to
+    #[doc = concat!("[`LetterSet::", stringify!($method), "`].")]
+    impl $operator for LetterSet {
+      type Output = LetterSet;
+
+      #[inline]
+      fn $function(self, other: LetterSet) -> LetterSet {
+        self.$method(other)
+      }
+    }
+
+    /// Equivalent to
+    #[doc = concat!("[`LetterSet::", stringify!($method), "`],")]
+    /// assigning the result to the left-hand side.
+    impl $assign_operator for LetterSet {
+      #[inline]
+      fn $assign_function(&mut self, other: LetterSet) {
+        *self = self.$method(other);
+      }
+    }
+  };
+}
+
+impl_letter_set_operator!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+impl_letter_set_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+impl_letter_set_operator!(
+  BitXor,
+  bitxor,
+  BitXorAssign,
+  bitxor_assign,
+  symmetric_difference
+);
+impl_letter_set_operator!(Sub, sub, SubAssign, sub_assign, difference);
+
+/// Equivalent to [`LetterSet::complement`].
+impl Not for LetterSet {
+  type Output = LetterSet;
+
+  #[inline]
+  fn not(self) -> LetterSet {
+    self.complement()
+  }
 }
 
 /// An iterator over the ASCII bytes contained within a [`LetterSet`].
+#[derive(Clone)]
 pub struct AsciiBytes {
   current_letter: u8,
   letter_set: LetterSet,
@@ -352,3 +759,31 @@ impl Iterator for AsciiBytes {
     Some(self.current_letter - 1)
   }
 }
+
+/// An iterator over the compressed letters contained within a [`LetterSet`],
+/// in alphabetical order.
+#[derive(Clone)]
+pub struct Letters(u32);
+
+impl Iterator for Letters {
+  type Item = u8;
+
+  #[expect(clippy::cast_possible_truncation)]
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.0 == 0 {
+      return None;
+    }
+
+    let letter = self.0.trailing_zeros() as u8;
+    self.0 &= self.0 - 1;
+
+    Some(letter)
+  }
+
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    let len = self.0.count_ones() as usize;
+    (len, Some(len))
+  }
+}
+
+impl ExactSizeIterator for Letters {}
@@ -3,14 +3,22 @@
 #![expect(clippy::zero_prefixed_literal)]
 #![warn(missing_docs)]
 
+pub mod adjacency;
 pub mod letter_group;
 pub mod letter_sequence;
 pub mod letter_set;
+pub mod parse_error;
 pub mod solution;
 
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub use adjacency::{Adjacency, AdjacencyRule};
+#[expect(deprecated)]
 pub use letter_group::LetterGroup;
 pub use letter_sequence::LetterSequence;
 pub use letter_set::LetterSet;
+pub use parse_error::ParseError;
 pub use solution::Solution;
 
 /// Compresses an ASCII byte to the 5-bit format used by [`LetterSequence`]
@@ -0,0 +1,74 @@
+//! Defines the error that is returned when a string cannot be parsed into one of the letter types.
+
+use crate::Solution;
+use std::error::Error;
+use std::fmt::Display;
+
+#[cfg(doc)]
+use crate::{Adjacency, LetterSequence, LetterSet};
+
+/// The reasons that a string cannot be parsed into a [`LetterSequence`], [`LetterSet`] or [`Solution`],
+/// or into the forbidden pairs of an [`Adjacency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseError {
+  /// The string has more than 12 letters or word boundaries.
+  TooLong(usize),
+  /// The string contains a character that is not an ASCII letter.
+  InvalidLetter(char),
+  /// The string contains the same letter more than once.
+  RepeatedLetter(char),
+  /// The string contains a character that is neither a `0` nor a `1` word boundary.
+  InvalidBoundary(char),
+  /// The word boundaries describe more than [`Solution::MAX_WORD_COUNT`] words.
+  TooManyWords(u32),
+  /// The solution string contains no words.
+  NoWords,
+  /// The word at this index in a solution string has fewer than 3 letters.
+  ShortWord(usize),
+  /// The word at this index in a solution string does not start with the final letter of the word before it.
+  DisconnectedWord(usize),
+  /// The pair at this index in a list of forbidden pairs does not have exactly two letters.
+  InvalidPair(usize),
+}
+
+impl Display for ParseError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::TooLong(len) => write!(f, "Expected at most 12 characters, but found {len}."),
+      Self::InvalidLetter(letter) => {
+        write!(f, "Expected only ASCII letters, but found {letter:?}.")
+      }
+      Self::RepeatedLetter(letter) => write!(
+        f,
+        "Expected each letter at most once, but {letter:?} is repeated."
+      ),
+      Self::InvalidBoundary(boundary) => write!(
+        f,
+        "Expected word boundaries of only '0' or '1', but found {boundary:?}."
+      ),
+      Self::TooManyWords(word_count) => write!(
+        f,
+        "Expected at most {} words, but the boundaries describe {word_count}.",
+        Solution::MAX_WORD_COUNT,
+      ),
+      Self::NoWords => write!(f, "Expected at least one word, but found none."),
+      Self::ShortWord(index) => write!(
+        f,
+        "Expected every word to have at least 3 letters, but word {} does not.",
+        index + 1
+      ),
+      Self::DisconnectedWord(index) => write!(
+        f,
+        "Expected every word to start with the final letter of the word before it, but word {} does not.",
+        index + 1
+      ),
+      Self::InvalidPair(index) => write!(
+        f,
+        "Expected every forbidden pair to have exactly 2 letters, but pair {} does not.",
+        index + 1
+      ),
+    }
+  }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,189 @@
+//! A deliberately simple reference implementation of the puzzle rules, built on [`String`] and
+//! [`Vec`] rather than packed bits, for differential testing against the optimized types.
+//!
+//! Nothing here is fast. Every function favors being obviously correct, so that any disagreement
+//! with [`LetterSequence`](crate::LetterSequence) or [`LetterSet`](crate::LetterSet) points to a
+//! bug in the optimized code.
+//!
+//! Boards are given as 12 uppercase ASCII letters side by side, e.g. `"AIODGTESUNPR"`, and
+//! sequences and words as uppercase ASCII strings.
+
+/// The count of letters on a board.
+pub const BOARD_LEN: usize = 12;
+
+/// The count of letters on each side of a board.
+pub const SIDE_LEN: usize = 3;
+
+/// Returns the index of the side of the `board` that holds the `letter`, if any.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::reference::side_of;
+/// assert_eq!(side_of("ABCDEFGHIJKL", 'E'), Some(1));
+/// assert_eq!(side_of("ABCDEFGHIJKL", 'Z'), None);
+/// ```
+#[must_use]
+pub fn side_of(board: &str, letter: char) -> Option<usize> {
+  board
+    .chars()
+    .position(|board_letter| board_letter == letter)
+    .map(|index| index / SIDE_LEN)
+}
+
+/// Returns [true] if every letter of the `word` is on the `board`, and no two consecutive
+/// letters are on the same side.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::reference::is_valid_word;
+/// assert!(is_valid_word("ABCDEFGHIJKL", "ADG"));
+/// assert!(!is_valid_word("ABCDEFGHIJKL", "ABD"));
+/// assert!(!is_valid_word("ABCDEFGHIJKL", "ADZ"));
+/// ```
+#[must_use]
+pub fn is_valid_word(board: &str, word: &str) -> bool {
+  let sides = word
+    .chars()
+    .map(|letter| side_of(board, letter))
+    .collect::<Option<Vec<_>>>();
+
+  sides.is_some_and(|sides| sides.windows(2).all(|pair| pair[0] != pair[1]))
+}
+
+/// Returns [true] if no letter appears in `letters` more than once.
+#[must_use]
+pub fn has_unique_letters(letters: &str) -> bool {
+  letters
+    .chars()
+    .enumerate()
+    .all(|(index, letter)| !letters.chars().skip(index + 1).any(|other| other == letter))
+}
+
+/// Returns the count of distinct letters that appear in both `lhs` and `rhs`.
+#[must_use]
+pub fn shared_letter_count(lhs: &str, rhs: &str) -> usize {
+  let mut shared = lhs
+    .chars()
+    .filter(|&letter| rhs.contains(letter))
+    .collect::<Vec<_>>();
+  shared.sort_unstable();
+  shared.dedup();
+  shared.len()
+}
+
+/// Returns [true] if the `word` can be appended to the `sequence`: the word starts with the
+/// final letter of the sequence, it shares no other letter with it, and together they hold no
+/// more than 12 letters.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::reference::can_append;
+/// assert!(can_append("FISH", "HOPE"));
+/// assert!(!can_append("FISH", "HIS"));
+/// ```
+#[must_use]
+pub fn can_append(sequence: &str, word: &str) -> bool {
+  let (Some(last), Some(first)) = (sequence.chars().last(), word.chars().next()) else {
+    return false;
+  };
+
+  last == first
+    && shared_letter_count(sequence, word) == 1
+    && sequence.len() + word.len() - 1 <= BOARD_LEN
+}
+
+/// Returns the words of the `sequence` followed by the `word`, sharing the letter where they join.
+///
+/// # Panics
+///
+/// Panics if the `word` cannot be appended to the sequence according to [`can_append`].
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::reference::append;
+/// let sequence = append(&["FISH".to_owned()], "HOPE");
+/// assert_eq!(sequence, ["FISH", "HOPE"]);
+/// ```
+#[must_use]
+pub fn append(words: &[String], word: &str) -> Vec<String> {
+  assert!(
+    can_append(&letters(words), word),
+    "The word {word} cannot be appended to {words:?}."
+  );
+
+  let mut words = words.to_vec();
+  words.push(word.to_owned());
+  words
+}
+
+/// Returns the letters of a sequence of `words`, where the letter shared by consecutive words
+/// is only included once.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::reference::letters;
+/// assert_eq!(letters(&["FISH".to_owned(), "HOPE".to_owned()]), "FISHOPE");
+/// ```
+#[must_use]
+pub fn letters(words: &[String]) -> String {
+  let mut letters = String::new();
+
+  for word in words {
+    if letters.is_empty() {
+      letters.push_str(word);
+    } else {
+      letters.push_str(&word[1..]);
+    }
+  }
+
+  letters
+}
+
+/// Returns every word in the `dictionary` that can be part of a unique-letter solution to
+/// the `board`, in their original order.
+#[must_use]
+pub fn valid_words(board: &str, dictionary: &[String]) -> Vec<String> {
+  dictionary
+    .iter()
+    .filter(|word| word.len() >= SIDE_LEN && has_unique_letters(word) && is_valid_word(board, word))
+    .cloned()
+    .collect()
+}
+
+/// Returns every unique-letter solution that can be built from the `valid_words`, as strings of
+/// words separated by spaces, e.g. `"FISH HOPE EAT"`, sorted alphabetically.
+///
+/// A solution is a sequence of words in which each word starts with the final letter of the word
+/// before it, and every one of the 12 letters on the board is used exactly once.
+#[must_use]
+pub fn solutions(valid_words: &[String]) -> Vec<String> {
+  /// Extends the `sequence` with every word that can follow it, recording each complete solution.
+  fn extend(sequence: &[String], valid_words: &[String], solutions: &mut Vec<String>) {
+    let sequence_letters = letters(sequence);
+
+    if sequence_letters.len() == BOARD_LEN {
+      solutions.push(sequence.join(" "));
+      return;
+    }
+
+    for word in valid_words {
+      if can_append(&sequence_letters, word) {
+        extend(&append(sequence, word), valid_words, solutions);
+      }
+    }
+  }
+
+  let mut solutions = Vec::new();
+
+  for word in valid_words {
+    extend(std::slice::from_ref(word), valid_words, &mut solutions);
+  }
+
+  solutions.sort_unstable();
+  solutions
+}
@@ -1,13 +1,13 @@
 //! Defines functionality for representing a solution to a Letter Boxed puzzle,
 //! i.e. the positions of word boundaries within a [`LetterSequence`] of 12 letters.
 
-#[cfg(doc)]
-use crate::LetterSequence;
-
-use std::{fmt::Debug, ops::Range};
-
-#[cfg(feature = "wasm")]
-use serde::{Deserialize, Serialize};
+use crate::ParseError;
+use crate::{LetterSequence, LetterSet};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::ops::Range;
+use std::str::FromStr;
 
 /// Encodes word boundaries for a [`LetterSequence`] as individual bits in a single [`u16`].
 ///
@@ -24,8 +24,11 @@ use serde::{Deserialize, Serialize};
 /// 001000010001 -> IMP PARTED DUNKS
 /// 001001010001 -> IMP PART TED DUNKS
 /// ```
-#[derive(Copy, Clone)]
-#[cfg_attr(feature = "wasm", derive(Serialize, Deserialize))]
+///
+/// Two solutions are equal only if they have exactly the same boundaries, and they are ordered
+/// by their boundary bits. The looser comparisons that are useful while searching are available
+/// as [`Solution::has_same_len_as`] and [`Solution::cmp_word_count`].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Solution(u16);
 
 /// Debug prints a 16-bit binary representation of the underlying boundary bits.
@@ -44,31 +47,79 @@ impl Default for Solution {
   }
 }
 
-/// Equality is defined such that two non-empty solutions are considered equal if they
-/// have the same number of leading zeros in their underlying `u16` representation.
-/// Any empty `Solution` is considered equal to any other empty `Solution`.
-impl Eq for Solution {}
+/// Displays the word boundaries as a `1` or `0` for each letter, from the first letter
+/// through the final boundary, e.g. `"000001010001"` for `IMPART TED DUNKS`.
+impl Display for Solution {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let len = (u16::BITS - self.0.leading_zeros()) as usize;
+    for index in 0..len {
+      write!(f, "{}", self.0 >> index & 1)?;
+    }
 
-/// Partial equality follows the same rule as [`Eq`]: empty solutions are equal,
-/// otherwise equality depends on the number of leading zeros in the `u16`.
-impl PartialEq for Solution {
-  fn eq(&self, other: &Self) -> bool {
-    // Custom puzzle-specific definition of equality
-    self.is_empty() || other.is_empty() || self.0.leading_zeros() == other.0.leading_zeros()
+    Ok(())
+  }
+}
+
+/// Parses a [`Solution`] from its [`Display`] form.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::{ParseError, Solution};
+/// let solution = "000001010001".parse::<Solution>().unwrap();
+///
+/// assert_eq!(solution.word_count(), 3);
+/// assert_eq!(solution.to_string(), "000001010001");
+/// assert_eq!("0012".parse::<Solution>(), Err(ParseError::InvalidBoundary('2')));
+/// ```
+impl FromStr for Solution {
+  type Err = ParseError;
+
+  fn from_str(boundaries: &str) -> Result<Self, Self::Err> {
+    let len = boundaries.chars().count();
+    if len > Self::FINAL_LETTER_INDEX + 1 {
+      return Err(ParseError::TooLong(len));
+    }
+
+    let mut solution = Self::empty();
+    for (index, boundary) in boundaries.chars().enumerate() {
+      match boundary {
+        '0' => {}
+        '1' => solution = solution.mark(index),
+        _ => return Err(ParseError::InvalidBoundary(boundary)),
+      }
+    }
+
+    let word_count = solution.0.count_ones();
+    if word_count as usize > Self::MAX_WORD_COUNT {
+      return Err(ParseError::TooManyWords(word_count));
+    }
+
+    Ok(solution)
   }
 }
 
-/// Partially compares solutions by comparing their [`Solution::word_count`].
-impl PartialOrd for Solution {
-  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-    Some(self.cmp(other))
+impl TryFrom<&str> for Solution {
+  type Error = ParseError;
+
+  fn try_from(boundaries: &str) -> Result<Self, Self::Error> {
+    boundaries.parse()
   }
 }
 
-/// Orders solutions based on their [`Solution::word_count`].
-impl Ord for Solution {
-  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-    self.word_count().cmp(&other.word_count())
+/// Serializes a [`Solution`] as its [`Display`] form, e.g. `"000001010001"`.
+impl Serialize for Solution {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(self)
+  }
+}
+
+/// Deserializes a [`Solution`] from its [`Display`] form.
+impl<'de> Deserialize<'de> for Solution {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    String::deserialize(deserializer)?
+      .parse()
+      .map_err(serde::de::Error::custom)
   }
 }
 
@@ -76,6 +127,11 @@ impl Solution {
   /// The index of the final letter in a 12-letter sequence.
   pub const FINAL_LETTER_INDEX: usize = 11;
 
+  /// The largest count of words in a solution.
+  ///
+  /// Each word after the first adds at least two new letters, so five words cover at least 11.
+  pub const MAX_WORD_COUNT: usize = 5;
+
   /// Returns a new [`Solution`] with no word boundaries.
   #[must_use]
   #[inline]
@@ -94,17 +150,130 @@ impl Solution {
   ///
   /// # Panics
   ///
-  /// Panics in debug mode if the solution has more than 5 words.
+  /// Panics in debug mode if the solution has more than [`Solution::MAX_WORD_COUNT`] words.
   ///
   /// Since each [`Solution`] as only 12 letters, and each word must be
-  /// at least 3 letters, it is expected that this count should never exceed 5.
+  /// at least 3 letters, it is expected that this count should never exceed it.
   #[must_use]
   #[inline]
   pub const fn word_count(self) -> u32 {
-    debug_assert!(self.0.count_ones() <= 5);
+    debug_assert!(self.0.count_ones() as usize <= Self::MAX_WORD_COUNT);
     self.0.count_ones()
   }
 
+  /// Returns [true] if either solution is empty, or if both end their final word at the same
+  /// letter, i.e. they describe sequences of the same length.
+  ///
+  /// This was the definition of equality for a [`Solution`] before it had value semantics.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::Solution;
+  /// let imparted_dunks = "000000010001".parse::<Solution>().unwrap();
+  /// let impart_ted_dunks = "000001010001".parse::<Solution>().unwrap();
+  ///
+  /// assert_ne!(imparted_dunks, impart_ted_dunks);
+  /// assert!(imparted_dunks.has_same_len_as(impart_ted_dunks));
+  /// assert!(imparted_dunks.has_same_len_as(Solution::empty()));
+  /// ```
+  #[must_use]
+  #[inline]
+  pub const fn has_same_len_as(self, other: Self) -> bool {
+    self.is_empty() || other.is_empty() || self.0.leading_zeros() == other.0.leading_zeros()
+  }
+
+  /// Compares two solutions by their [`word_count`](Self::word_count) alone.
+  ///
+  /// This was the definition of ordering for a [`Solution`] before it had value semantics,
+  /// and it is useful for sorting solutions from the fewest words to the most.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::Solution;
+  /// # use std::cmp::Ordering;
+  /// let imparted_dunks = "000000010001".parse::<Solution>().unwrap();
+  /// let impart_ted_dunks = "000001010001".parse::<Solution>().unwrap();
+  ///
+  /// assert_eq!(imparted_dunks.cmp_word_count(impart_ted_dunks), Ordering::Less);
+  /// ```
+  #[must_use]
+  pub fn cmp_word_count(self, other: Self) -> Ordering {
+    self.word_count().cmp(&other.word_count())
+  }
+
+  /// Parses a solution string of words separated by spaces or hyphens, such as
+  /// `"IMPART TED DUNKS"` or `"impart-ted-dunks"`, into the [`LetterSequence`] of its letters
+  /// and the [`Solution`] that marks its word boundaries.
+  ///
+  /// Each word must have at least 3 letters and start with the final letter of the word before it,
+  /// and no other letter may appear more than once.
+  ///
+  /// # Errors
+  ///
+  /// Returns a [`ParseError`] describing the first problem found with the `words`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use letters::{LetterSequence, ParseError, Solution};
+  /// let (sequence, solution) = Solution::parse_words("IMPART TED DUNKS").unwrap();
+  ///
+  /// assert_eq!(sequence, LetterSequence::new("IMPARTEDUNKS"));
+  /// assert_eq!(solution, "000001010001".parse().unwrap());
+  /// assert_eq!(sequence.solution_string(), "IMPART TED DUNKS");
+  ///
+  /// assert_eq!(
+  ///   Solution::parse_words("IMPART DUNKS"),
+  ///   Err(ParseError::DisconnectedWord(1)),
+  /// );
+  /// ```
+  pub fn parse_words(words: &str) -> Result<(LetterSequence, Solution), ParseError> {
+    let mut sequence = LetterSequence::empty();
+
+    for (index, word) in words
+      .split(|letter: char| letter.is_whitespace() || letter == '-')
+      .filter(|word| !word.is_empty())
+      .enumerate()
+    {
+      let word = word.parse::<LetterSequence>()?;
+      if word.len() < 3 {
+        return Err(ParseError::ShortWord(index));
+      }
+
+      if sequence.is_empty() {
+        sequence = word;
+        continue;
+      }
+
+      if word.first_letter() != sequence.last_letter() {
+        return Err(ParseError::DisconnectedWord(index));
+      }
+
+      let joining_letter = LetterSet::empty().insert(word.first_letter());
+      let repeated_letters = sequence
+        .letter_set()
+        .intersection(word.letter_set())
+        .difference(joining_letter);
+      if let Some(letter) = repeated_letters.ascii_bytes().next() {
+        return Err(ParseError::RepeatedLetter(letter as char));
+      }
+
+      if !word.can_append_to(sequence) {
+        return Err(ParseError::TooLong(sequence.len() + word.len() - 1));
+      }
+
+      sequence = word.append_to(sequence);
+    }
+
+    if sequence.is_empty() {
+      return Err(ParseError::NoWords);
+    }
+
+    Ok((sequence, sequence.solution()))
+  }
+
   /// Returns a new [`Solution`] with a boundary bit set at the given `index`.
   ///
   /// # Panics
@@ -186,7 +355,7 @@ impl Iterator for WordRanges {
     }
 
     // Return a range from our word start up to (and including) the newly found boundary.
-    #[expect(clippy::range_plus_one)]
+    #[allow(clippy::range_plus_one)]
     Some(range_start..self.index + 1)
   }
 }
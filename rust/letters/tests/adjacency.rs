@@ -0,0 +1,234 @@
+use letters::{compress_letter, Adjacency, AdjacencyRule, LetterSequence, ParseError};
+
+const INPUT: &str = "ABCDEFGHIJKL";
+
+/// Returns the index of the side that holds the ASCII `letter` of the [`INPUT`].
+fn side(letter: u8) -> usize {
+  INPUT.bytes().position(|other| other == letter).unwrap() / 3
+}
+
+#[test]
+fn follows_rules() {
+  for rule in AdjacencyRule::ALL {
+    let adjacency = Adjacency::new(INPUT, rule);
+
+    for previous in INPUT.bytes() {
+      for next in INPUT.bytes().filter(|&next| next != previous) {
+        assert_eq!(
+          rule.allows_sides(side(previous), side(next)),
+          adjacency.can_follow(compress_letter(previous), compress_letter(next)),
+          "Under the {} rule, {} following {} should match the rule for their sides.",
+          rule.name(),
+          next as char,
+          previous as char,
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn forbids_doubled_letters() {
+  for rule in AdjacencyRule::ALL {
+    let adjacency = Adjacency::new(INPUT, rule);
+
+    for letter in INPUT.bytes() {
+      assert!(
+        !adjacency.can_follow(compress_letter(letter), compress_letter(letter)),
+        "Under the {} rule, {} should not follow itself.",
+        rule.name(),
+        letter as char,
+      );
+    }
+  }
+}
+
+#[test]
+fn excludes_letters_off_the_board() {
+  for rule in AdjacencyRule::ALL {
+    let adjacency = Adjacency::new(INPUT, rule);
+
+    for letter in b'M'..=b'Z' {
+      assert!(
+        adjacency.followers(compress_letter(letter)).is_empty(),
+        "Under the {} rule, no letter should follow {}.",
+        rule.name(),
+        letter as char,
+      );
+      assert!(
+        INPUT
+          .bytes()
+          .all(|other| !adjacency.can_follow(compress_letter(other), compress_letter(letter))),
+        "Under the {} rule, {} should not follow any letter.",
+        rule.name(),
+        letter as char,
+      );
+    }
+  }
+}
+
+#[test]
+fn validates_words() {
+  let standard = Adjacency::standard(INPUT);
+  let easy = Adjacency::new(INPUT, AdjacencyRule::Easy);
+  let clockwise = Adjacency::new(INPUT, AdjacencyRule::Clockwise);
+
+  for (word, is_standard, is_easy, is_clockwise) in [
+    ("ADGJ", true, true, true),
+    ("AGE", true, true, false),
+    ("CAB", false, true, false),
+    ("JAD", true, true, true),
+    ("JAM", false, false, false),
+  ] {
+    let word = LetterSequence::new(word);
+    assert_eq!(
+      is_standard,
+      standard.is_valid_word(word),
+      "{word} under the standard rule."
+    );
+    assert_eq!(
+      is_easy,
+      easy.is_valid_word(word),
+      "{word} under the easy rule."
+    );
+    assert_eq!(
+      is_clockwise,
+      clockwise.is_valid_word(word),
+      "{word} under the clockwise rule."
+    );
+  }
+}
+
+#[test]
+fn validates_words_across_sides() {
+  let adjacency = Adjacency::standard(INPUT);
+
+  let side1 = "ABC";
+  let side2 = "DEF";
+  let side3 = "GHI";
+  let side4 = "JKL";
+
+  for &g1 in side1.as_bytes() {
+    for &g2 in side2.as_bytes() {
+      for &g3 in side3.as_bytes() {
+        for &g4 in side4.as_bytes() {
+          assert!(
+            adjacency.is_valid_word(LetterSequence::new(
+              &String::from_utf8(vec![g1, g2, g3, g4]).unwrap()
+            )),
+            "A LetterSequence formed with adjacent letters from each side is valid."
+          );
+        }
+      }
+    }
+  }
+
+  for &g1 in side1.as_bytes() {
+    for &g1_invalid in side1.as_bytes().iter().filter(|&&byte| byte != g1) {
+      for &g2 in side2.as_bytes() {
+        for &g3 in side3.as_bytes() {
+          for &g4 in side4.as_bytes() {
+            assert!(
+              !adjacency.is_valid_word(LetterSequence::new(
+                &String::from_utf8(vec![g1, g1_invalid, g2, g3, g4]).unwrap()
+              )),
+              "A LetterSequence with two adjacent letters from side 1 is invalid.",
+            );
+          }
+        }
+      }
+    }
+  }
+
+  for &g1 in side1.as_bytes() {
+    for &g2 in side2.as_bytes() {
+      for &g2_invalid in side2.as_bytes().iter().filter(|&&byte| byte != g2) {
+        for &g3 in side3.as_bytes() {
+          for &g4 in side4.as_bytes() {
+            assert!(
+              !adjacency.is_valid_word(LetterSequence::new(
+                &String::from_utf8(vec![g1, g2, g2_invalid, g3, g4]).unwrap()
+              )),
+              "A LetterSequence with two adjacent letters from side 2 is invalid.",
+            );
+          }
+        }
+      }
+    }
+  }
+
+  for &g1 in side1.as_bytes() {
+    for &g2 in side2.as_bytes() {
+      for &g3 in side3.as_bytes() {
+        for &g3_invalid in side3.as_bytes().iter().filter(|&&byte| byte != g3) {
+          for &g4 in side4.as_bytes() {
+            assert!(
+              !adjacency.is_valid_word(LetterSequence::new(
+                &String::from_utf8(vec![g1, g2, g3, g3_invalid, g4]).unwrap()
+              )),
+              "A LetterSequence with two adjacent letters from side 3 is invalid.",
+            );
+          }
+        }
+      }
+    }
+  }
+
+  for &g1 in side1.as_bytes() {
+    for &g2 in side2.as_bytes() {
+      for &g3 in side3.as_bytes() {
+        for &g4 in side4.as_bytes() {
+          for &g4_invalid in side4.as_bytes().iter().filter(|&&byte| byte != g4) {
+            assert!(
+              !adjacency.is_valid_word(LetterSequence::new(
+                &String::from_utf8(vec![g1, g2, g3, g4, g4_invalid]).unwrap()
+              )),
+              "A LetterSequence with two adjacent letters from side 4 is invalid.",
+            );
+          }
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn forbids_pairs() {
+  let adjacency = Adjacency::standard(INPUT)
+    .with_forbidden_pairs("# Forbidden pairs.\nad, GB # Both orders.\n\n  jl\n")
+    .unwrap();
+
+  for (previous, next) in [(b'A', b'D'), (b'D', b'A'), (b'G', b'B'), (b'B', b'G')] {
+    assert!(
+      !adjacency.can_follow(compress_letter(previous), compress_letter(next)),
+      "{} should not follow {}.",
+      next as char,
+      previous as char,
+    );
+  }
+  assert!(
+    adjacency.can_follow(compress_letter(b'A'), compress_letter(b'E')),
+    "Letters that are not in a forbidden pair should still follow one another."
+  );
+  assert_eq!(
+    Adjacency::standard(INPUT),
+    Adjacency::standard(INPUT).with_forbidden_pairs("").unwrap(),
+    "An empty list should forbid no pairs."
+  );
+}
+
+#[test]
+fn rejects_invalid_pairs() {
+  for (pairs, expected) in [
+    ("AD, B", ParseError::InvalidPair(1)),
+    ("ADG", ParseError::InvalidPair(0)),
+    ("A1", ParseError::InvalidLetter('1')),
+    ("AD; GB", ParseError::InvalidLetter(';')),
+  ] {
+    assert_eq!(
+      Err(expected),
+      Adjacency::standard(INPUT).with_forbidden_pairs(pairs),
+      "{pairs:?} should not parse."
+    );
+  }
+}
@@ -1,3 +1,5 @@
+#![expect(deprecated)]
+
 use letters::compress_letter;
 use letters::create_letter_group_function;
 use letters::LetterGroup;
@@ -1,5 +1,7 @@
 use letters::compress_letter;
-use letters::LetterSequence;
+use letters::{LetterSequence, ParseError};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
 
 #[test]
 fn empty() {
@@ -236,15 +238,15 @@ fn can_append_to() {
 
   let invalid_suffixes_with_reasons = [
     (
-      LetterSequence::from("JKE"),
+      LetterSequence::new("JKE"),
       "does not join on the same letter.",
     ),
     (
-      LetterSequence::from("IKJLM"),
+      LetterSequence::new("IKJLM"),
       "extends the length beyond 12 letters.",
     ),
     (
-      LetterSequence::from("IJE"),
+      LetterSequence::new("IJE"),
       "shares more than one letter with the prefix.",
     ),
   ];
@@ -276,15 +278,15 @@ fn can_prepend_to() {
 
   let invalid_suffixes_with_reasons = [
     (
-      LetterSequence::from("JKE"),
+      LetterSequence::new("JKE"),
       "does not join on the same letter.",
     ),
     (
-      LetterSequence::from("IKJLM"),
+      LetterSequence::new("IKJLM"),
       "extends the length beyond 12 letters.",
     ),
     (
-      LetterSequence::from("IJE"),
+      LetterSequence::new("IJE"),
       "shares more than one letter with the suffix.",
     ),
   ];
@@ -338,6 +340,7 @@ fn prepend_to() {
 }
 
 #[test]
+#[expect(deprecated)]
 fn is_valid_word() {
   let letter_group = letters::create_letter_group_function!("ABCDEFGHIJKL");
 
@@ -424,3 +427,209 @@ fn is_valid_word() {
     }
   }
 }
+
+#[test]
+fn from_bits() {
+  let letters = "ABCDEFGHIJKL";
+  for n in 0..=letters.len() {
+    let sequence = LetterSequence::new(&letters[0..n]);
+    let rebuilt = LetterSequence::from_bits(sequence.to_bits())
+      .expect("The bits of a LetterSequence can be rebuilt into a LetterSequence.");
+
+    assert_eq!(
+      sequence, rebuilt,
+      "A LetterSequence rebuilt from its bits is equal to the original."
+    );
+    assert_eq!(
+      sequence.letter_set(),
+      rebuilt.letter_set(),
+      "A LetterSequence rebuilt from its bits has the same letter set."
+    );
+    assert_eq!(
+      u32::from(n > 0),
+      rebuilt.word_count(),
+      "A LetterSequence rebuilt from its bits has at most one word."
+    );
+  }
+
+  assert_eq!(
+    None,
+    LetterSequence::from_bits(0),
+    "Bits without a length-tracker bit are rejected."
+  );
+  assert_eq!(
+    None,
+    LetterSequence::from_bits(0b1_0000),
+    "Bits with a partial letter are rejected."
+  );
+  assert_eq!(
+    None,
+    LetterSequence::from_bits(0b1_11010),
+    "Bits with a 5-bit value past 'Z' are rejected."
+  );
+  assert_eq!(
+    None,
+    LetterSequence::from_bits(0b1_00000_00000),
+    "Bits with a repeated letter are rejected."
+  );
+  assert_eq!(
+    None,
+    LetterSequence::from_bits(u64::MAX),
+    "Bits with more than 12 letters are rejected."
+  );
+}
+
+#[test]
+fn parse() {
+  assert_eq!(
+    Ok(LetterSequence::new("NICE")),
+    "nIcE".parse::<LetterSequence>(),
+    "A sequence should parse from letters in either case.",
+  );
+  assert_eq!(
+    Ok(LetterSequence::empty()),
+    "".parse::<LetterSequence>(),
+    "An empty string should parse as an empty sequence.",
+  );
+  assert_eq!(
+    Ok(LetterSequence::new("ABCDEFGHIJKL")),
+    LetterSequence::try_from("ABCDEFGHIJKL"),
+    "A sequence of 12 letters should parse.",
+  );
+
+  for (letters, expected) in [
+    ("ABCDEFGHIJKLM", ParseError::TooLong(13)),
+    ("NI_CE", ParseError::InvalidLetter('_')),
+    ("NI CE", ParseError::ShortWord(0)),
+    ("FISH-NICE", ParseError::DisconnectedWord(1)),
+    ("NIÇE", ParseError::InvalidLetter('Ç')),
+    ("NINE", ParseError::RepeatedLetter('N')),
+    ("Nine", ParseError::RepeatedLetter('N')),
+  ] {
+    assert_eq!(
+      Err(expected),
+      letters.parse::<LetterSequence>(),
+      "{letters:?} should not parse as a sequence.",
+    );
+  }
+
+  for words in ["FISH HOPE", "fish-hope", "FISH  HOPE"] {
+    assert_eq!(
+      "FISH HOPE",
+      words.parse::<LetterSequence>().unwrap().solution_string(),
+      "{words:?} should parse as a multi-word sequence.",
+    );
+  }
+}
+
+#[test]
+fn solution_id() {
+  let fish_hope_eat = LetterSequence::new("FISH")
+    .prepend_to(LetterSequence::new("HOPE"))
+    .prepend_to(LetterSequence::new("EAT"));
+
+  for sequence in [LetterSequence::new("NICE"), fish_hope_eat] {
+    let id = sequence.solution_id();
+    let parsed = id.parse::<LetterSequence>().unwrap();
+
+    assert_eq!(
+      sequence.solution_string(),
+      parsed.solution_string(),
+      "{id:?} should parse back into the same words.",
+    );
+    assert_eq!(
+      sequence.solution_string(),
+      serde_json::from_str::<LetterSequence>(&format!("{id:?}"))
+        .unwrap()
+        .solution_string(),
+      "{id:?} should deserialize into the same words.",
+    );
+  }
+}
+
+#[test]
+fn hash() {
+  let fish = LetterSequence::new("FISH");
+  let hope = LetterSequence::new("HOPE");
+  let fishope = LetterSequence::new("FISHOPE");
+
+  let sequences = HashSet::from([hope.append_to(fish)]);
+
+  assert!(
+    sequences.contains(&fishope),
+    "Equal sequences should hash equally, regardless of their word boundaries.",
+  );
+  assert!(
+    !sequences.contains(&fish),
+    "Different sequences should not be found in the set.",
+  );
+}
+
+#[test]
+fn ord() {
+  let fish = LetterSequence::new("FISH");
+  let hope = LetterSequence::new("HOPE");
+  let fishope = LetterSequence::new("FISHOPE");
+
+  assert_eq!(
+    Ordering::Equal,
+    hope.append_to(fish).cmp(&fishope),
+    "Equal sequences should compare as equal, regardless of their word boundaries.",
+  );
+  assert_ne!(
+    Ordering::Equal,
+    fish.cmp(&hope),
+    "Different sequences should not compare as equal.",
+  );
+}
+
+#[test]
+fn serde() {
+  let nice = LetterSequence::new("NICE");
+
+  assert_eq!(
+    "\"NICE\"",
+    serde_json::to_string(&nice).unwrap(),
+    "A sequence should serialize as its letters.",
+  );
+  assert_eq!(
+    nice,
+    serde_json::from_str::<LetterSequence>("\"nice\"").unwrap(),
+    "A sequence should deserialize from its letters.",
+  );
+  assert!(
+    serde_json::from_str::<LetterSequence>("\"NINE\"").is_err(),
+    "An invalid sequence should fail to deserialize.",
+  );
+
+  let fish_hope = LetterSequence::new("FISH").prepend_to(LetterSequence::new("HOPE"));
+  assert_eq!(
+    "\"FISH HOPE\"",
+    serde_json::to_string(&fish_hope).unwrap(),
+    "A multi-word sequence should serialize as its words.",
+  );
+  assert_eq!(
+    "FISH HOPE",
+    serde_json::from_str::<LetterSequence>("\"FISH HOPE\"")
+      .unwrap()
+      .solution_string(),
+    "A multi-word sequence should keep its word boundaries through a round trip.",
+  );
+  assert!(
+    serde_json::from_str::<LetterSequence>("\"FISH NICE\"").is_err(),
+    "Words that do not chain together should fail to deserialize.",
+  );
+
+  let counts = BTreeMap::from([(nice, 1), (LetterSequence::new("FISH"), 2)]);
+  let json = serde_json::to_string(&counts).unwrap();
+
+  assert_eq!(
+    r#"{"FISH":2,"NICE":1}"#, json,
+    "Sequences should serialize as map keys.",
+  );
+  assert_eq!(
+    counts,
+    serde_json::from_str(&json).unwrap(),
+    "Sequences should deserialize as map keys.",
+  );
+}
@@ -1,5 +1,6 @@
 use letters::compress_letter;
-use letters::LetterSet;
+use letters::{LetterSet, ParseError};
+use std::collections::HashSet;
 
 #[test]
 fn is_empty() {
@@ -374,3 +375,318 @@ fn ascii_bytes() {
     fish_set.union(swim_set).ascii_bytes().collect::<Vec<_>>(),
   );
 }
+
+#[test]
+fn difference_with_overlapping_sets() {
+  let fish_set = LetterSet::from_ascii_slice(b"FISH");
+  let swim_set = LetterSet::from_ascii_slice(b"SWIM");
+
+  let difference = fish_set.difference(swim_set);
+
+  assert_eq!(
+    difference,
+    LetterSet::from_ascii_slice(b"FH"),
+    "The difference should contain only the letters unique to the left-hand set.",
+  );
+
+  assert!(
+    difference.intersection(swim_set).is_empty(),
+    "The difference should share no letters with the right-hand set.",
+  );
+
+  assert_eq!(
+    difference.union(fish_set.intersection(swim_set)),
+    fish_set,
+    "The difference and the intersection should together form the left-hand set.",
+  );
+}
+
+#[test]
+fn symmetric_difference_with_overlapping_sets() {
+  let fish_set = LetterSet::from_ascii_slice(b"FISH");
+  let swim_set = LetterSet::from_ascii_slice(b"SWIM");
+
+  let symmetric_difference = fish_set.symmetric_difference(swim_set);
+
+  assert_eq!(
+    symmetric_difference,
+    LetterSet::from_ascii_slice(b"FHWM"),
+    "The symmetric difference should contain the letters unique to either set.",
+  );
+
+  assert_eq!(
+    symmetric_difference,
+    fish_set
+      .union(swim_set)
+      .difference(fish_set.intersection(swim_set)),
+    "The symmetric difference should be the union without the intersection.",
+  );
+}
+
+#[test]
+fn complement() {
+  let vowels = LetterSet::from_ascii_slice(b"AEIOU");
+
+  assert_eq!(
+    21,
+    vowels.complement().len(),
+    "The complement should contain every other letter of the alphabet.",
+  );
+  assert_eq!(
+    vowels,
+    vowels.complement().complement(),
+    "The complement of the complement should be the original set.",
+  );
+  assert_eq!(
+    LetterSet::all(),
+    LetterSet::empty().complement(),
+    "The complement of the empty set should be every letter.",
+  );
+}
+
+#[test]
+fn complement_in() {
+  let board = LetterSet::from_ascii_slice(b"AIODGTESUNPR");
+  let used = LetterSet::from_ascii_slice(b"DOGS");
+
+  let remaining = used.complement_in(board);
+
+  assert_eq!(
+    LetterSet::from_ascii_slice(b"AITEUNPR"),
+    remaining,
+    "The complement in a board should contain the unused letters of the board.",
+  );
+  assert!(
+    remaining.is_subset(board) && remaining.is_disjoint(used),
+    "The complement in a board should be part of the board and share no letters with the set.",
+  );
+}
+
+#[test]
+fn subset_and_superset() {
+  let board = LetterSet::from_ascii_slice(b"AIODGTESUNPR");
+  let dog = LetterSet::from_ascii_slice(b"DOG");
+  let cat = LetterSet::from_ascii_slice(b"CAT");
+
+  assert!(dog.is_subset(board), "DOG should be a subset of the board.");
+  assert!(
+    !cat.is_subset(board),
+    "CAT should not be a subset of the board."
+  );
+  assert!(
+    board.is_superset(dog),
+    "The board should be a superset of DOG."
+  );
+  assert!(
+    !board.is_superset(cat),
+    "The board should not be a superset of CAT."
+  );
+  assert!(
+    board.is_subset(board) && board.is_superset(board),
+    "A set should be both a subset and a superset of itself.",
+  );
+  assert!(
+    LetterSet::empty().is_subset(dog),
+    "The empty set should be a subset of every set.",
+  );
+  assert!(
+    dog.is_disjoint(cat.difference(dog)),
+    "Disjoint sets share no letters."
+  );
+}
+
+#[test]
+fn letters() {
+  let nice = LetterSet::from_ascii_slice(b"NICE");
+
+  assert_eq!(
+    vec![
+      compress_letter(b'C'),
+      compress_letter(b'E'),
+      compress_letter(b'I'),
+      compress_letter(b'N'),
+    ],
+    nice.letters().collect::<Vec<_>>(),
+    "The compressed letters should be yielded in alphabetical order.",
+  );
+  assert_eq!(
+    4,
+    nice.letters().len(),
+    "The letters iterator should know its exact length.",
+  );
+  assert_eq!(
+    nice.ascii_bytes().collect::<Vec<_>>(),
+    nice
+      .letters()
+      .map(letters::decompress_letter)
+      .collect::<Vec<_>>(),
+    "The compressed letters should match the ASCII bytes.",
+  );
+  assert_eq!(
+    0,
+    LetterSet::empty().letters().count(),
+    "An empty set should yield no letters.",
+  );
+}
+
+#[test]
+fn from_iter_and_extend() {
+  let nice = LetterSet::from_ascii_slice(b"NICE");
+
+  assert_eq!(
+    nice,
+    nice.letters().collect::<LetterSet>(),
+    "Collecting the letters of a set should produce the same set.",
+  );
+  assert_eq!(
+    LetterSet::from_ascii_slice(b"EIN"),
+    b"NINE"
+      .iter()
+      .map(|&letter| compress_letter(letter))
+      .collect::<LetterSet>(),
+    "Collecting repeated letters should include each letter once.",
+  );
+  assert_eq!(
+    LetterSet::from_ascii_slice(b"FISHWM"),
+    [b"FISH".as_slice(), b"SWIM"]
+      .map(LetterSet::from_ascii_slice)
+      .into_iter()
+      .collect::<LetterSet>(),
+    "Collecting sets should produce their union.",
+  );
+
+  let mut letter_set = nice;
+  letter_set.extend([compress_letter(b'E'), compress_letter(b'S')]);
+  letter_set.extend([LetterSet::from_ascii_slice(b"TY")]);
+
+  assert_eq!(
+    LetterSet::from_ascii_slice(b"NICESTY"),
+    letter_set,
+    "Extending a set should add only the new letters.",
+  );
+}
+
+#[test]
+fn operators() {
+  let fish_set = LetterSet::from_ascii_slice(b"FISH");
+  let swim_set = LetterSet::from_ascii_slice(b"SWIM");
+
+  assert_eq!(fish_set.union(swim_set), fish_set | swim_set, "| is union.");
+  assert_eq!(
+    fish_set.intersection(swim_set),
+    fish_set & swim_set,
+    "& is intersection.",
+  );
+  assert_eq!(
+    fish_set.difference(swim_set),
+    fish_set - swim_set,
+    "- is difference.",
+  );
+  assert_eq!(
+    fish_set.symmetric_difference(swim_set),
+    fish_set ^ swim_set,
+    "^ is symmetric difference.",
+  );
+  assert_eq!(fish_set.complement(), !fish_set, "! is complement.");
+
+  let mut letter_set = fish_set;
+  letter_set |= swim_set;
+  assert_eq!(fish_set | swim_set, letter_set, "|= assigns the union.");
+  letter_set &= swim_set;
+  assert_eq!(swim_set, letter_set, "&= assigns the intersection.");
+  letter_set -= fish_set;
+  assert_eq!(
+    swim_set - fish_set,
+    letter_set,
+    "-= assigns the difference."
+  );
+  letter_set ^= fish_set;
+  assert_eq!(
+    (swim_set - fish_set) ^ fish_set,
+    letter_set,
+    "^= assigns the symmetric difference.",
+  );
+}
+
+#[test]
+fn parse() {
+  let expected = LetterSet::from_ascii_slice(b"CEIN");
+
+  for letters in ["NICE", "nice", "[CEIN]", "[ecin]"] {
+    assert_eq!(
+      Ok(expected),
+      letters.parse::<LetterSet>(),
+      "{letters:?} should parse as the set of its letters.",
+    );
+  }
+
+  assert_eq!(
+    Ok(expected),
+    expected.to_string().parse::<LetterSet>(),
+    "A set should parse from its display form.",
+  );
+  assert_eq!(
+    Ok(LetterSet::empty()),
+    LetterSet::try_from("[]"),
+    "An empty display form should parse as an empty set.",
+  );
+
+  for (letters, expected) in [
+    ("NINE", ParseError::RepeatedLetter('N')),
+    ("[NICE", ParseError::InvalidLetter('[')),
+    ("N1CE", ParseError::InvalidLetter('1')),
+  ] {
+    assert_eq!(
+      Err(expected),
+      letters.parse::<LetterSet>(),
+      "{letters:?} should not parse as a set.",
+    );
+  }
+}
+
+#[test]
+fn try_insert_ascii() {
+  let set = LetterSet::empty();
+
+  assert_eq!(
+    Ok(LetterSet::from_ascii_slice(b"Q")),
+    set.try_insert_ascii(b'q'),
+    "A lowercase letter should be inserted as uppercase.",
+  );
+  assert_eq!(
+    Err(ParseError::RepeatedLetter('Q')),
+    LetterSet::from_ascii_slice(b"Q").try_insert_ascii(b'q'),
+    "A letter should not be inserted twice.",
+  );
+  assert_eq!(
+    Err(ParseError::InvalidLetter('@')),
+    set.try_insert_ascii(b'@'),
+    "A character that is not a letter should not be inserted.",
+  );
+}
+
+#[test]
+fn hash() {
+  let sets = HashSet::from([LetterSet::from_ascii_slice(b"FISH")]);
+
+  assert!(
+    sets.contains(&LetterSet::from_ascii_slice(b"HISF")),
+    "Equal sets should hash equally.",
+  );
+}
+
+#[test]
+fn serde() {
+  let set = LetterSet::from_ascii_slice(b"NICE");
+
+  assert_eq!(
+    "\"[CEIN]\"",
+    serde_json::to_string(&set).unwrap(),
+    "A set should serialize as its display form.",
+  );
+  assert_eq!(
+    set,
+    serde_json::from_str::<LetterSet>("\"nice\"").unwrap(),
+    "A set should deserialize from its letters.",
+  );
+}
@@ -0,0 +1,336 @@
+//! Differential tests that compare the optimized types against the simple reference
+//! implementation in [`letters::reference`], across many randomly generated cases.
+//!
+//! Every case is derived from a seed, which is included in each assertion message so that
+//! a failure can be reproduced by running that seed alone.
+
+use letters::reference;
+use letters::{Adjacency, LetterSequence, LetterSet};
+
+/// The count of seeds to run each of the cheaper properties with.
+const SEED_COUNT: u64 = 512;
+
+/// The count of seeds to run the solution enumeration property with.
+const BOARD_SEED_COUNT: u64 = 64;
+
+/// A small, deterministic pseudo-random number generator (`SplitMix64`), so that the tests
+/// need no external dependencies and every failure is reproducible from its seed.
+struct Rng(u64);
+
+impl Rng {
+  /// Returns the next pseudo-random 64-bit value.
+  fn next_u64(&mut self) -> u64 {
+    self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = self.0;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+  }
+
+  /// Returns a pseudo-random value in the range `0..bound`.
+  fn below(&mut self, bound: usize) -> usize {
+    usize::try_from(self.next_u64() % bound as u64).expect("The value is less than the bound.")
+  }
+
+  /// Returns a pseudo-random value in the inclusive range `min..=max`.
+  fn between(&mut self, min: usize, max: usize) -> usize {
+    min + self.below(max - min + 1)
+  }
+
+  /// Shuffles the `items` in place.
+  fn shuffle<T>(&mut self, items: &mut [T]) {
+    for index in (1..items.len()).rev() {
+      items.swap(index, self.below(index + 1));
+    }
+  }
+}
+
+/// Returns a random board of 12 distinct letters.
+fn random_board(rng: &mut Rng) -> String {
+  let mut alphabet = ('A'..='Z').collect::<Vec<_>>();
+  rng.shuffle(&mut alphabet);
+  alphabet[..12].iter().collect()
+}
+
+/// Returns a random string of `len` distinct letters, none of which are in `excluded`.
+fn random_letters(rng: &mut Rng, len: usize, excluded: &str) -> String {
+  let mut alphabet = ('A'..='Z')
+    .filter(|&letter| !excluded.contains(letter))
+    .collect::<Vec<_>>();
+  rng.shuffle(&mut alphabet);
+  alphabet[..len].iter().collect()
+}
+
+/// Returns a random word of distinct letters, mostly drawn from the `board`, that walks between
+/// sides of the board. Some words stay on one side or leave the board, so they are invalid.
+fn random_word(rng: &mut Rng, board: &str) -> String {
+  let board = board.chars().collect::<Vec<_>>();
+  let len = rng.between(2, 8);
+  let mut word = String::new();
+
+  while word.len() < len {
+    let letter = if rng.below(16) == 0 {
+      char::from(b'A' + u8::try_from(rng.below(26)).expect("The letter offset is below 26."))
+    } else {
+      board[rng.below(board.len())]
+    };
+
+    if !word.contains(letter) {
+      word.push(letter);
+    }
+  }
+
+  word
+}
+
+/// Returns a random dictionary of words that are valid on the `board`, which are walks between
+/// different sides of the board with no repeated letters.
+fn random_dictionary(rng: &mut Rng, board: &str, word_count: usize) -> Vec<String> {
+  let letters = board.chars().collect::<Vec<_>>();
+  let mut dictionary = Vec::new();
+
+  while dictionary.len() < word_count {
+    let len = rng.between(3, 7);
+    let mut word = String::from(letters[rng.below(letters.len())]);
+
+    for _ in 0..100 {
+      if word.len() == len {
+        break;
+      }
+
+      let letter = letters[rng.below(letters.len())];
+      let previous = word.chars().last().expect("The word should not be empty.");
+
+      if reference::side_of(board, letter) != reference::side_of(board, previous)
+        && !word.contains(letter)
+      {
+        word.push(letter);
+      }
+    }
+
+    if word.len() == len && !dictionary.contains(&word) {
+      dictionary.push(word);
+    }
+  }
+
+  dictionary
+}
+
+/// Returns every solution that can be built from the `valid_words` using the optimized types.
+fn optimized_solutions(valid_words: &[LetterSequence]) -> Vec<String> {
+  fn extend(sequence: LetterSequence, valid_words: &[LetterSequence], solutions: &mut Vec<String>) {
+    if sequence.has_all_letters() {
+      solutions.push(sequence.solution_string());
+      return;
+    }
+
+    for &word in valid_words {
+      if word.can_append_to(sequence) {
+        extend(word.append_to(sequence), valid_words, solutions);
+      }
+    }
+  }
+
+  let mut solutions = Vec::new();
+
+  for &word in valid_words {
+    extend(word, valid_words, &mut solutions);
+  }
+
+  solutions.sort_unstable();
+  solutions
+}
+
+#[test]
+fn word_validity() {
+  for seed in 0..SEED_COUNT {
+    let rng = &mut Rng(seed);
+    let board = random_board(rng);
+    let adjacency = Adjacency::standard(&board);
+
+    for _ in 0..32 {
+      let word = random_word(rng, &board);
+
+      assert_eq!(
+        reference::is_valid_word(&board, &word),
+        adjacency.is_valid_word(LetterSequence::new(word.as_str())),
+        "Seed {seed}: the validity of {word} on {board} should match the reference.",
+      );
+    }
+  }
+}
+
+#[test]
+fn sequence_operations() {
+  for seed in 0..SEED_COUNT {
+    let rng = &mut Rng(seed);
+    let len = rng.between(1, 12);
+    let letters = random_letters(rng, len, "");
+    let sequence = LetterSequence::new(letters.as_str());
+
+    assert_eq!(
+      letters,
+      sequence.to_string(),
+      "Seed {seed}: {letters} should round-trip through a sequence.",
+    );
+    assert_eq!(
+      len,
+      sequence.len(),
+      "Seed {seed}: {letters} should have a length of {len}.",
+    );
+    assert_eq!(
+      LetterSet::from_ascii_slice(letters.as_bytes()),
+      sequence.letter_set(),
+      "Seed {seed}: {letters} should have the letter set of its letters.",
+    );
+    assert_eq!(
+      Some(sequence),
+      LetterSequence::from_bits(sequence.to_bits()),
+      "Seed {seed}: {letters} should round-trip through its bits.",
+    );
+
+    let n = rng.between(0, len);
+    assert_eq!(
+      letters[n..],
+      sequence.cut_from_start(n).to_string(),
+      "Seed {seed}: cutting {n} letters from the start of {letters} should match the reference.",
+    );
+    assert_eq!(
+      letters[..len - n],
+      sequence.cut_from_end(n).to_string(),
+      "Seed {seed}: cutting {n} letters from the end of {letters} should match the reference.",
+    );
+
+    let start = rng.between(0, len - 1);
+    let end = rng.between(start, len);
+    assert_eq!(
+      letters[start..end],
+      sequence.slice(start..end).to_string(),
+      "Seed {seed}: slicing {letters} from {start} to {end} should match the reference.",
+    );
+
+    if len < LetterSequence::CAPACITY {
+      let letter = random_letters(rng, 1, &letters);
+      assert_eq!(
+        format!("{letters}{letter}"),
+        sequence.with_letter(letter.as_bytes()[0]).to_string(),
+        "Seed {seed}: adding {letter} to {letters} should match the reference.",
+      );
+    }
+
+    let other_len = rng.between(1, 12);
+    let other = random_letters(rng, other_len, "");
+    assert_eq!(
+      reference::shared_letter_count(&letters, &other),
+      sequence.shared_letter_count(LetterSequence::new(other.as_str())),
+      "Seed {seed}: the letters shared by {letters} and {other} should match the reference.",
+    );
+  }
+}
+
+#[test]
+fn appended_words() {
+  for seed in 0..SEED_COUNT {
+    let rng = &mut Rng(seed);
+    let len = rng.between(3, 8);
+    let first_word = random_letters(rng, len, "");
+    let mut words = vec![first_word.clone()];
+    let mut sequence = LetterSequence::new(first_word.as_str());
+
+    for _ in 0..8 {
+      let letters = reference::letters(&words);
+      let last = letters
+        .chars()
+        .last()
+        .expect("The sequence should not be empty.");
+
+      // Most candidate words avoid the letters of the sequence, but some collide with them.
+      let excluded = if rng.below(4) == 0 {
+        last.to_string()
+      } else {
+        letters.clone()
+      };
+      let len = rng.between(2, 6);
+      let word = format!("{last}{}", random_letters(rng, len, &excluded));
+      let word_sequence = LetterSequence::new(word.as_str());
+
+      let can_append = reference::can_append(&letters, &word);
+      assert_eq!(
+        can_append,
+        word_sequence.can_append_to(sequence),
+        "Seed {seed}: whether {word} can be appended to {words:?} should match the reference.",
+      );
+
+      if can_append {
+        words = reference::append(&words, &word);
+        sequence = word_sequence.append_to(sequence);
+      }
+
+      assert_eq!(
+        reference::letters(&words),
+        sequence.to_string(),
+        "Seed {seed}: the letters of {words:?} should match the reference.",
+      );
+      assert_eq!(
+        words.join(" "),
+        sequence.solution_string(),
+        "Seed {seed}: the solution string of {words:?} should match the reference.",
+      );
+      assert_eq!(
+        words,
+        sequence
+          .words()
+          .map(|word| word.to_string())
+          .collect::<Vec<_>>(),
+        "Seed {seed}: the words of {words:?} should match the reference.",
+      );
+      assert_eq!(
+        words.len(),
+        sequence.word_count() as usize,
+        "Seed {seed}: the word count of {words:?} should match the reference.",
+      );
+    }
+  }
+}
+
+#[test]
+fn solutions() {
+  let mut solution_count = 0;
+
+  for seed in 0..BOARD_SEED_COUNT {
+    let rng = &mut Rng(seed);
+    let board = random_board(rng);
+    let dictionary = random_dictionary(rng, &board, 200);
+    let adjacency = Adjacency::standard(&board);
+
+    let valid_words = reference::valid_words(&board, &dictionary);
+    let optimized_valid_words = dictionary
+      .iter()
+      .map(|word| LetterSequence::new(word.as_str()))
+      .filter(|&word| adjacency.is_valid_word(word))
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      valid_words,
+      optimized_valid_words
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>(),
+      "Seed {seed}: the valid words on {board} should match the reference.",
+    );
+
+    let expected = reference::solutions(&valid_words);
+    solution_count += expected.len();
+
+    assert_eq!(
+      expected,
+      optimized_solutions(&optimized_valid_words),
+      "Seed {seed}: the solutions to {board} should match the reference.",
+    );
+  }
+
+  assert!(
+    solution_count > 0,
+    "The random boards should have some solutions to compare.",
+  );
+}
@@ -1,6 +1,8 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::ops::Range;
 
-use letters::Solution;
+use letters::{LetterSequence, ParseError, Solution};
 
 #[test]
 fn empty() {
@@ -151,3 +153,179 @@ fn word_ranges() {
 
   assert_eq!(expected, actual,);
 }
+
+#[test]
+fn display() {
+  assert_eq!(
+    "",
+    Solution::empty().to_string(),
+    "An empty solution displays as an empty string.",
+  );
+  assert_eq!(
+    "000001010001",
+    Solution::empty().mark(5).mark(7).mark(11).to_string(),
+    "A solution displays a digit for each letter through its final boundary.",
+  );
+}
+
+#[test]
+fn parse() {
+  let solution = Solution::empty().mark(5).mark(7).mark(11);
+
+  assert_eq!(
+    Ok(solution),
+    "000001010001".parse::<Solution>(),
+    "A solution should parse from its display form.",
+  );
+  assert_eq!(
+    Ok(3),
+    Solution::try_from("000001010001").map(Solution::word_count),
+    "A parsed solution should have a word for each boundary.",
+  );
+
+  for (boundaries, expected) in [
+    ("0000010100010", ParseError::TooLong(13)),
+    ("00000101000x", ParseError::InvalidBoundary('x')),
+    ("101010101010", ParseError::TooManyWords(6)),
+  ] {
+    assert_eq!(
+      Err(expected),
+      boundaries.parse::<Solution>(),
+      "{boundaries:?} should not parse as a solution.",
+    );
+  }
+}
+
+#[test]
+fn serde() {
+  let solution = Solution::empty().mark(5).mark(7).mark(11);
+  let json = serde_json::to_string(&solution).unwrap();
+
+  assert_eq!(
+    "\"000001010001\"", json,
+    "A solution should serialize as its display form.",
+  );
+  assert_eq!(
+    solution,
+    serde_json::from_str::<Solution>(&json).unwrap(),
+    "A solution should deserialize from its display form.",
+  );
+}
+
+#[test]
+fn equality() {
+  let imparted_dunks = Solution::empty().mark(7).mark(11);
+  let impart_ted_dunks = Solution::empty().mark(5).mark(7).mark(11);
+
+  assert_eq!(
+    impart_ted_dunks,
+    Solution::empty().mark(5).mark(7).mark(11),
+    "Solutions with the same boundaries are equal.",
+  );
+  assert_ne!(
+    imparted_dunks, impart_ted_dunks,
+    "Solutions with different boundaries are not equal, even if they have the same length.",
+  );
+  assert_ne!(
+    Solution::empty(),
+    impart_ted_dunks,
+    "An empty solution is not equal to a non-empty solution.",
+  );
+  assert_eq!(
+    2,
+    [imparted_dunks, impart_ted_dunks, impart_ted_dunks]
+      .into_iter()
+      .collect::<HashSet<_>>()
+      .len(),
+    "Equal solutions hash the same, and different solutions are kept apart in a set.",
+  );
+}
+
+#[test]
+fn has_same_len_as() {
+  let imparted_dunks = Solution::empty().mark(7).mark(11);
+  let impart_ted_dunks = Solution::empty().mark(5).mark(7).mark(11);
+
+  assert!(
+    imparted_dunks.has_same_len_as(impart_ted_dunks),
+    "Solutions whose final boundaries match have the same length.",
+  );
+  assert!(
+    !imparted_dunks.has_same_len_as(Solution::empty().mark(7).mark(10)),
+    "Solutions whose final boundaries differ do not have the same length.",
+  );
+  assert!(
+    Solution::empty().has_same_len_as(impart_ted_dunks),
+    "An empty solution has the same length as any solution.",
+  );
+}
+
+#[test]
+fn cmp_word_count() {
+  let imparted_dunks = Solution::empty().mark(7).mark(11);
+  let impart_ted_dunks = Solution::empty().mark(5).mark(7).mark(11);
+
+  assert_eq!(
+    Ordering::Less,
+    imparted_dunks.cmp_word_count(impart_ted_dunks),
+    "A solution with fewer words compares as less.",
+  );
+  assert_eq!(
+    Ordering::Equal,
+    imparted_dunks.cmp_word_count(Solution::empty().mark(3).mark(11)),
+    "Solutions with the same word count compare as equal.",
+  );
+}
+
+#[test]
+fn parse_words() {
+  let expected = (
+    LetterSequence::new("IMPARTEDUNKS"),
+    Solution::empty().mark(5).mark(7).mark(11),
+  );
+
+  for words in [
+    "IMPART TED DUNKS",
+    "impart ted dunks",
+    "IMPART-TED-DUNKS",
+    "  IMPART \tTED  DUNKS ",
+  ] {
+    assert_eq!(
+      Ok(expected),
+      Solution::parse_words(words),
+      "{words:?} should parse into its letters and word boundaries.",
+    );
+  }
+
+  for (words, expected) in [
+    ("", ParseError::NoWords),
+    (" - ", ParseError::NoWords),
+    ("IMPART TE EDUNKS", ParseError::ShortWord(1)),
+    ("IMPART DUNKS", ParseError::DisconnectedWord(1)),
+    ("IMPART TEA AMUCK", ParseError::RepeatedLetter('A')),
+    ("IMPART TED DUNKSY", ParseError::TooLong(13)),
+    ("IMPART TED DUN?", ParseError::InvalidLetter('?')),
+  ] {
+    assert_eq!(
+      Err(expected),
+      Solution::parse_words(words),
+      "{words:?} should not parse as a solution.",
+    );
+  }
+}
+
+#[test]
+fn solution_id() {
+  let (sequence, solution) = Solution::parse_words("impart ted dunks").unwrap();
+
+  assert_eq!(
+    "IMPART-TED-DUNKS",
+    sequence.solution_id(),
+    "A solution ID is the uppercase words joined by hyphens.",
+  );
+  assert_eq!(
+    Ok((sequence, solution)),
+    Solution::parse_words(&sequence.solution_id()),
+    "A solution ID should parse back into the same sequence and solution.",
+  );
+}
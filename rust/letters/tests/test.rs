@@ -1,6 +1,7 @@
 mod letter_group;
 mod letter_sequence;
 mod letter_set;
+mod reference;
 mod solution;
 
 #[test]
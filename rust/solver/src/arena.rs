@@ -0,0 +1,53 @@
+//! Defines preallocated storage for the candidate words at each depth of the search.
+
+use letters::LetterSequence;
+
+#[cfg(doc)]
+use crate::Solver;
+
+/// A set of reusable buffers that hold the candidate words at each depth of the search,
+/// allowing [`Solver::solve_in`] to run without allocating.
+///
+/// A solution has at most 5 words, and the candidates only need to be narrowed down before
+/// appending the 2nd through the 5th word, so the arena holds one buffer for each of those
+/// 4 depths. Each buffer is reused by every sibling node at the same depth.
+///
+/// The buffers grow to the length of the largest list of valid words they are used with,
+/// so reusing the same [`CandidateArena`] across many starting words (or many puzzle inputs)
+/// only allocates on first use.
+#[derive(Debug, Clone, Default)]
+pub struct CandidateArena {
+  depths: [Vec<LetterSequence>; CandidateArena::DEPTH_COUNT],
+}
+
+impl CandidateArena {
+  /// The number of depths at which the search narrows down the candidate words.
+  pub const DEPTH_COUNT: usize = 4;
+
+  /// Returns a new [`CandidateArena`] with room for `capacity` candidates at each depth.
+  #[must_use]
+  pub fn with_capacity(capacity: usize) -> Self {
+    Self {
+      depths: std::array::from_fn(|_| Vec::with_capacity(capacity)),
+    }
+  }
+
+  /// Returns the number of candidates that each depth can hold without allocating.
+  #[must_use]
+  pub fn capacity(&self) -> usize {
+    self.depths.iter().map(Vec::capacity).min().unwrap_or(0)
+  }
+
+  /// Ensures that each depth can hold at least `capacity` candidates without allocating.
+  pub fn reserve(&mut self, capacity: usize) {
+    for depth in &mut self.depths {
+      depth.clear();
+      depth.reserve(capacity);
+    }
+  }
+
+  /// Returns the buffers for every depth, starting from the shallowest.
+  pub(crate) fn depths_mut(&mut self) -> &mut [Vec<LetterSequence>] {
+    &mut self.depths
+  }
+}
@@ -0,0 +1,81 @@
+//! Defines validation for the 12-letter input of a Letter Boxed puzzle.
+
+use std::error::Error;
+use std::fmt::Display;
+
+/// The reasons that a string cannot be used as the 12-letter input of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardError {
+  /// The input does not have exactly 12 characters.
+  WrongLength(usize),
+  /// The input contains a character that is not an uppercase ASCII letter.
+  InvalidLetter(char),
+  /// The input contains the same letter more than once.
+  RepeatedLetter(char),
+  /// The input contains more `?` wildcards than [`MAX_WILDCARD_COUNT`](crate::wildcard::MAX_WILDCARD_COUNT).
+  TooManyWildcards(usize),
+}
+
+impl Display for BoardError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::WrongLength(len) => write!(
+        f,
+        "Expected the board to have exactly 12 letters, but it has {len}."
+      ),
+      Self::InvalidLetter(letter) => write!(
+        f,
+        "Expected the board to contain only uppercase ASCII letters, but it contains {letter:?}."
+      ),
+      Self::RepeatedLetter(letter) => write!(
+        f,
+        "Expected the board to contain 12 distinct letters, but {letter:?} is repeated."
+      ),
+      Self::TooManyWildcards(count) => write!(
+        f,
+        "Expected the board to contain at most {} wildcards, but it contains {count}.",
+        crate::wildcard::MAX_WILDCARD_COUNT
+      ),
+    }
+  }
+}
+
+impl Error for BoardError {}
+
+/// Checks that the `input` is made of 12 distinct uppercase ASCII letters, three for each
+/// side of the board, given side by side.
+///
+/// # Errors
+///
+/// Returns a [`BoardError`] describing the first problem found with the `input`.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::board::{validate, BoardError};
+/// assert_eq!(validate("AIODGTESUNPR"), Ok(()));
+/// assert_eq!(validate("AIODGT"), Err(BoardError::WrongLength(6)));
+/// assert_eq!(validate("AIODGTESUNP?"), Err(BoardError::InvalidLetter('?')));
+/// assert_eq!(validate("AIODGTESUNPA"), Err(BoardError::RepeatedLetter('A')));
+/// ```
+pub fn validate(input: &str) -> Result<(), BoardError> {
+  let len = input.chars().count();
+  if len != 12 {
+    return Err(BoardError::WrongLength(len));
+  }
+
+  if let Some(letter) = input.chars().find(|letter| !letter.is_ascii_uppercase()) {
+    return Err(BoardError::InvalidLetter(letter));
+  }
+
+  let bytes = input.as_bytes();
+  if let Some((_, &letter)) = bytes
+    .iter()
+    .enumerate()
+    .find(|&(index, letter)| bytes[..index].contains(letter))
+  {
+    return Err(BoardError::RepeatedLetter(letter as char));
+  }
+
+  Ok(())
+}
@@ -0,0 +1,54 @@
+//! Defines a way to split the search beneath a sequence into independent sub-searches.
+
+use letters::LetterSequence;
+
+#[cfg(doc)]
+use crate::Solver;
+
+/// The independent sub-searches that continue a [`LetterSequence`] by one more word,
+/// as returned by [`Solver::branches`].
+///
+/// Every sequence in [`sequences`](Self::sequences) can be searched separately, in any order
+/// and on any thread, by passing it to [`Solver::solve`] or [`Solver::solve_in`] along with
+/// [`valid_words`](Self::valid_words). Concatenating the solutions of each sequence in order
+/// yields the same solutions, in the same order, as solving the original sequence directly.
+#[derive(Debug, Clone, Default)]
+pub struct Branches {
+  sequences: Vec<LetterSequence>,
+  valid_words: Vec<LetterSequence>,
+}
+
+impl Branches {
+  /// Returns a new [`Branches`] from the extended `sequences` and the candidate
+  /// `valid_words` that they share.
+  pub(crate) fn new(sequences: Vec<LetterSequence>, valid_words: Vec<LetterSequence>) -> Self {
+    Self {
+      sequences,
+      valid_words,
+    }
+  }
+
+  /// Returns the sequences at the start of each sub-search.
+  #[must_use]
+  pub fn sequences(&self) -> &[LetterSequence] {
+    &self.sequences
+  }
+
+  /// Returns the candidate words that each sub-search should be solved with.
+  #[must_use]
+  pub fn valid_words(&self) -> &[LetterSequence] {
+    &self.valid_words
+  }
+
+  /// Returns [true] if there are no sub-searches, otherwise [false].
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.sequences.is_empty()
+  }
+
+  /// Returns the number of sub-searches.
+  #[must_use]
+  pub fn len(&self) -> usize {
+    self.sequences.len()
+  }
+}
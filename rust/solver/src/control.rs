@@ -0,0 +1,349 @@
+//! Defines the controls for bounding, cancelling, and monitoring a search that is in progress.
+
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+/// A cloneable flag that requests a search to stop as soon as possible.
+///
+/// Every clone of a [`CancellationToken`] shares the same flag, so a clone may be moved to
+/// another thread, e.g. a signal handler, and cancelled from there.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::control::CancellationToken;
+/// let token = CancellationToken::new();
+/// let clone = token.clone();
+///
+/// clone.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+  /// Returns a new [`CancellationToken`] that has not been cancelled.
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Requests that every search controlled by this token stop as soon as possible.
+  pub fn cancel(&self) {
+    self.0.store(true, Ordering::Relaxed);
+  }
+
+  /// Returns [true] if this token has been cancelled.
+  #[must_use]
+  pub fn is_cancelled(&self) -> bool {
+    self.0.load(Ordering::Relaxed)
+  }
+}
+
+/// The reasons that a controlled search may stop before it has found every solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+  /// The [`CancellationToken`] was cancelled.
+  Cancelled,
+  /// The search visited its maximum count of nodes.
+  NodeBudget,
+  /// The search found its maximum count of solutions.
+  SolutionBudget,
+  /// The search ran past its deadline.
+  Deadline,
+}
+
+impl Display for StopReason {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Cancelled => write!(f, "The search was cancelled."),
+      Self::NodeBudget => write!(f, "The search visited its maximum count of nodes."),
+      Self::SolutionBudget => write!(f, "The search found its maximum count of solutions."),
+      Self::Deadline => write!(f, "The search ran past its deadline."),
+    }
+  }
+}
+
+impl Error for StopReason {}
+
+/// A snapshot of how far a controlled search has progressed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Progress {
+  /// The count of starting words whose searches have been completed.
+  pub starting_words_completed: usize,
+  /// The count of nodes that have been visited in the search tree.
+  pub nodes_visited: u64,
+  /// The count of solutions that have been found.
+  pub solutions_found: u64,
+}
+
+/// The shared state of a controlled search: its limits, its progress so far, and whether it
+/// has stopped. A single [`SearchControl`] may be shared by every thread that is searching.
+///
+/// A search stops at the first of the following, after which it returns the solutions it has found:
+///
+/// * The [`CancellationToken`] is cancelled.
+/// * More than the node budget of nodes have been visited.
+/// * A solution is found beyond the solution budget.
+/// * The deadline has passed.
+///
+/// Nodes are tallied in batches of [`SearchControl::CHECK_INTERVAL`], and the cancellation token,
+/// node budget and deadline are only checked when a batch is tallied. A search may therefore visit
+/// up to that many extra nodes per thread before it stops. The solution budget is exact.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::control::{SearchControl, StopReason};
+/// # use solver::Solver;
+/// let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+/// let control = SearchControl::new().with_solution_budget(100);
+///
+/// let outcome = Solver::default().search::<Vec<_>>(valid_words, &control);
+///
+/// assert_eq!(outcome.solutions.len(), 100);
+/// assert_eq!(outcome.stop_reason, Some(StopReason::SolutionBudget));
+/// ```
+#[derive(Debug, Default)]
+pub struct SearchControl {
+  token: CancellationToken,
+  node_budget: Option<u64>,
+  solution_budget: Option<u64>,
+  deadline: Option<Instant>,
+  starting_words_completed: AtomicUsize,
+  nodes_visited: AtomicU64,
+  solutions_found: AtomicU64,
+  stop_reason: OnceLock<StopReason>,
+}
+
+impl SearchControl {
+  /// The count of nodes that each thread visits between checks of the limits.
+  pub const CHECK_INTERVAL: u64 = 1024;
+
+  /// Returns a new [`SearchControl`] with no limits.
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns this [`SearchControl`] with the search stopping once the `token` is cancelled.
+  #[must_use]
+  pub fn with_cancellation_token(self, token: CancellationToken) -> Self {
+    Self { token, ..self }
+  }
+
+  /// Returns this [`SearchControl`] with the search stopping once it has visited more than
+  /// `node_budget` nodes.
+  #[must_use]
+  pub fn with_node_budget(self, node_budget: u64) -> Self {
+    Self {
+      node_budget: Some(node_budget),
+      ..self
+    }
+  }
+
+  /// Returns this [`SearchControl`] with the search stopping once it finds a solution beyond the
+  /// first `solution_budget` solutions. A search that finds exactly `solution_budget` solutions
+  /// is not truncated.
+  #[must_use]
+  pub fn with_solution_budget(self, solution_budget: u64) -> Self {
+    Self {
+      solution_budget: Some(solution_budget),
+      ..self
+    }
+  }
+
+  /// Returns this [`SearchControl`] with the search stopping once the `deadline` has passed.
+  #[must_use]
+  pub fn with_deadline(self, deadline: Instant) -> Self {
+    Self {
+      deadline: Some(deadline),
+      ..self
+    }
+  }
+
+  /// Returns this [`SearchControl`] with the search stopping once the `timeout` has elapsed
+  /// from now.
+  #[must_use]
+  pub fn with_timeout(self, timeout: Duration) -> Self {
+    self.with_deadline(Instant::now() + timeout)
+  }
+
+  /// Returns the [`CancellationToken`] that stops the search.
+  #[must_use]
+  pub fn cancellation_token(&self) -> &CancellationToken {
+    &self.token
+  }
+
+  /// Returns a snapshot of the progress of the search.
+  #[must_use]
+  pub fn progress(&self) -> Progress {
+    Progress {
+      starting_words_completed: self.starting_words_completed.load(Ordering::Relaxed),
+      nodes_visited: self.nodes_visited.load(Ordering::Relaxed),
+      solutions_found: self.solutions_found.load(Ordering::Relaxed),
+    }
+  }
+
+  /// Returns the reason that the search stopped early, or [`None`] if it has not stopped.
+  #[must_use]
+  pub fn stop_reason(&self) -> Option<StopReason> {
+    self.stop_reason.get().copied()
+  }
+
+  /// Returns [true] if the search stopped before it could find every solution.
+  #[must_use]
+  pub fn is_truncated(&self) -> bool {
+    self.stop_reason().is_some()
+  }
+
+  /// Records that the search for every solution that starts with one of the starting words
+  /// has been completed.
+  pub fn complete_starting_word(&self) {
+    self
+      .starting_words_completed
+      .fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Returns [`ControlFlow::Break`] if the search has stopped or should stop now because
+  /// it was cancelled or ran past its deadline.
+  pub(crate) fn check(&self) -> ControlFlow<StopReason> {
+    if let Some(reason) = self.stop_reason() {
+      return ControlFlow::Break(reason);
+    }
+
+    if self.token.is_cancelled() {
+      return self.stop(StopReason::Cancelled);
+    }
+
+    if self
+      .deadline
+      .is_some_and(|deadline| Instant::now() >= deadline)
+    {
+      return self.stop(StopReason::Deadline);
+    }
+
+    ControlFlow::Continue(())
+  }
+
+  /// Tallies a batch of visited nodes, then checks every limit.
+  fn add_nodes(&self, node_count: u64) -> ControlFlow<StopReason> {
+    let nodes_visited = self.nodes_visited.fetch_add(node_count, Ordering::Relaxed) + node_count;
+    if self
+      .node_budget
+      .is_some_and(|budget| nodes_visited > budget)
+    {
+      return self.stop(StopReason::NodeBudget);
+    }
+
+    self.check()
+  }
+
+  /// Reserves a place for one more solution within the solution budget, returning
+  /// [`ControlFlow::Break`] if there is none left.
+  fn admit_solution(&self) -> ControlFlow<StopReason> {
+    let solutions_found = self.solutions_found.fetch_add(1, Ordering::Relaxed);
+    if self
+      .solution_budget
+      .is_some_and(|budget| solutions_found >= budget)
+    {
+      self.solutions_found.fetch_sub(1, Ordering::Relaxed);
+      return self.stop(StopReason::SolutionBudget);
+    }
+
+    ControlFlow::Continue(())
+  }
+
+  /// Records the first reason that the search stopped, and returns the reason that was recorded.
+  fn stop(&self, reason: StopReason) -> ControlFlow<StopReason> {
+    ControlFlow::Break(*self.stop_reason.get_or_init(|| reason))
+  }
+}
+
+/// The solutions found by a controlled search, along with whether it stopped early.
+#[derive(Debug, Clone, Default)]
+pub struct SearchOutcome<S> {
+  /// The solutions that were found before the search completed or stopped.
+  pub solutions: S,
+  /// The progress of the search when it completed or stopped.
+  pub progress: Progress,
+  /// The reason that the search stopped early, or [`None`] if it found every solution.
+  pub stop_reason: Option<StopReason>,
+}
+
+impl<S> SearchOutcome<S> {
+  /// Returns [true] if the search stopped before it could find every solution,
+  /// in which case the [`solutions`](Self::solutions) are partial.
+  #[must_use]
+  pub fn is_truncated(&self) -> bool {
+    self.stop_reason.is_some()
+  }
+}
+
+/// Observes each node and solution in a search, and decides whether the search continues.
+pub(crate) trait Meter {
+  /// Observes a newly visited node.
+  fn enter_node(&mut self) -> ControlFlow<StopReason>;
+
+  /// Observes a newly found solution, which is only recorded if this returns [`ControlFlow::Continue`].
+  fn admit_solution(&mut self) -> ControlFlow<StopReason>;
+}
+
+/// A [`Meter`] that never stops the search, which compiles to nothing.
+pub(crate) struct Unmetered;
+
+impl Meter for Unmetered {
+  #[inline]
+  fn enter_node(&mut self) -> ControlFlow<StopReason> {
+    ControlFlow::Continue(())
+  }
+
+  #[inline]
+  fn admit_solution(&mut self) -> ControlFlow<StopReason> {
+    ControlFlow::Continue(())
+  }
+}
+
+/// A [`Meter`] that tallies nodes locally and reports them to a shared [`SearchControl`]
+/// every [`SearchControl::CHECK_INTERVAL`] nodes.
+pub(crate) struct ControlMeter<'a> {
+  control: &'a SearchControl,
+  pending_nodes: u64,
+}
+
+impl<'a> ControlMeter<'a> {
+  /// Returns a new [`ControlMeter`] that reports to the `control`.
+  pub(crate) fn new(control: &'a SearchControl) -> Self {
+    Self {
+      control,
+      pending_nodes: 0,
+    }
+  }
+
+  /// Reports any nodes that have not yet been tallied, then checks every limit.
+  pub(crate) fn flush(&mut self) -> ControlFlow<StopReason> {
+    let pending_nodes = std::mem::take(&mut self.pending_nodes);
+    self.control.add_nodes(pending_nodes)
+  }
+}
+
+impl Meter for ControlMeter<'_> {
+  #[inline]
+  fn enter_node(&mut self) -> ControlFlow<StopReason> {
+    self.pending_nodes += 1;
+    if self.pending_nodes >= SearchControl::CHECK_INTERVAL {
+      return self.flush();
+    }
+
+    ControlFlow::Continue(())
+  }
+
+  #[inline]
+  fn admit_solution(&mut self) -> ControlFlow<StopReason> {
+    self.control.admit_solution()
+  }
+}
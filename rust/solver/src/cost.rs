@@ -0,0 +1,103 @@
+//! Defines cheap estimates of how expensive it is to search beneath each starting word,
+//! so that the starting words can be divided evenly among threads or workers.
+
+use crate::{is_candidate, Coverage};
+use letters::LetterSequence;
+use std::ops::Range;
+
+/// Returns an estimate of the cost of searching for every solution that starts with `word`.
+///
+/// The estimate is derived from a single filter pass over the `valid_words`: every starting
+/// word costs one pass over the list, and each word that can be appended to it costs roughly
+/// one more pass over the candidates that survive the filter. A starting word whose candidates
+/// cannot cover the remaining letters only costs the initial pass.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::LetterSequence;
+/// let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+///
+/// // DIAL can be followed by many words, such as LYTE or LOUT.
+/// let dial = solver::cost::estimate_cost(LetterSequence::new("DIAL"), valid_words);
+/// assert!(dial > valid_words.len() as u64);
+/// ```
+#[must_use]
+pub fn estimate_cost(word: LetterSequence, valid_words: &[LetterSequence]) -> u64 {
+  let initial_pass = valid_words.len() as u64;
+
+  if word.len() >= 11 {
+    return initial_pass;
+  }
+
+  let mut coverage = Coverage::default();
+  let mut candidate_count = 0_u64;
+  let mut appendable_count = 0_u64;
+
+  for &candidate in valid_words {
+    if is_candidate(candidate, word) {
+      coverage.add(candidate);
+      candidate_count += 1;
+      appendable_count += u64::from(candidate.can_append_to(word));
+    }
+  }
+
+  if !coverage.can_complete(word) {
+    return initial_pass;
+  }
+
+  initial_pass + appendable_count * candidate_count
+}
+
+/// Returns the [`estimate_cost`] of each of the `valid_words` as a starting word, in order.
+#[must_use]
+pub fn estimate_costs(valid_words: &[LetterSequence]) -> Vec<u64> {
+  valid_words
+    .iter()
+    .map(|&word| estimate_cost(word, valid_words))
+    .collect()
+}
+
+/// Divides the indices of `costs` into exactly `range_count` contiguous ranges whose
+/// total costs are as close to equal as a single greedy pass allows.
+///
+/// The ranges are returned in order, they cover every index exactly once, and some of
+/// them may be empty if there are fewer indices than ranges.
+///
+/// # Panics
+///
+/// Panics if `range_count` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::cost::cost_balanced_ranges;
+/// let costs = [1, 1, 1, 1, 8, 1, 1, 1, 1];
+/// assert_eq!(cost_balanced_ranges(&costs, 2), vec![0..5, 5..9]);
+/// ```
+#[must_use]
+pub fn cost_balanced_ranges(costs: &[u64], range_count: usize) -> Vec<Range<usize>> {
+  assert!(range_count > 0, "There must be at least one range.");
+
+  let total_cost = costs.iter().sum::<u64>();
+  let mut ranges = Vec::with_capacity(range_count);
+  let mut range_start = 0;
+  let mut cumulative_cost = 0;
+
+  for (index, &cost) in costs.iter().enumerate() {
+    cumulative_cost += cost;
+
+    // Close the current range once it reaches its share of the total cost,
+    // as long as there are ranges left to hold the remaining indices.
+    let target_cost = total_cost * (ranges.len() as u64 + 1) / range_count as u64;
+    if cumulative_cost >= target_cost && ranges.len() + 1 < range_count {
+      ranges.push(range_start..index + 1);
+      range_start = index + 1;
+    }
+  }
+
+  ranges.push(range_start..costs.len());
+  ranges.resize(range_count, costs.len()..costs.len());
+
+  ranges
+}
@@ -0,0 +1,105 @@
+//! Groups solutions whose words differ only by inflection, such as a plural or a tense.
+//!
+//! The word list assigns each word in [`WORDS`] to a family of inflections at build time, e.g.
+//! `ABDUCT`, `ABDUCTS` and `ABDUCTING`, stored in [`WORD_FAMILIES`]. Two solutions collapse
+//! together if their words belong to the same families in the same order.
+//!
+//! Since an inflection changes the letters of a word, the other words of a unique-letter solution
+//! usually have to change to make up for it, so most groups hold a single solution. The families
+//! are also useful on their own, e.g. to hide the solutions that only differ from a more common
+//! one by the inflection of a word.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use letters::LetterSequence;
+//! # use solver::families::WordFamilies;
+//! let families = WordFamilies::new();
+//!
+//! assert_eq!(
+//!   families.head(LetterSequence::new("ABDUCT")),
+//!   families.head(LetterSequence::new("ABDUCTING")),
+//! );
+//! assert_ne!(
+//!   families.head(LetterSequence::new("THE")),
+//!   families.head(LetterSequence::new("THING")),
+//! );
+//! ```
+
+use letters::LetterSequence;
+use std::collections::HashMap;
+use word_list::{WORDS, WORD_FAMILIES};
+
+/// The family of inflections of each word in [`WORDS`].
+#[derive(Debug, Clone)]
+pub struct WordFamilies {
+  /// The first word in [`WORDS`] of the family of each word.
+  head_by_word: HashMap<LetterSequence, LetterSequence>,
+}
+
+impl Default for WordFamilies {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl WordFamilies {
+  /// Returns the families of every word in [`WORDS`].
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      head_by_word: WORDS
+        .iter()
+        .zip(WORD_FAMILIES)
+        .map(|(&word, &family)| (word, WORDS[family as usize]))
+        .collect(),
+    }
+  }
+
+  /// Returns the first word in [`WORDS`] of the family of the `word`, which is shared by every
+  /// inflection of the `word`, or the `word` itself if it is not in [`WORDS`].
+  #[must_use]
+  pub fn head(&self, word: LetterSequence) -> LetterSequence {
+    self.head_by_word.get(&word).copied().unwrap_or(word)
+  }
+
+  /// Returns [true] if the words of the `lhs` and `rhs` solutions belong to the same families
+  /// in the same order.
+  #[must_use]
+  pub fn is_inflection_of(&self, lhs: LetterSequence, rhs: LetterSequence) -> bool {
+    lhs.word_count() == rhs.word_count()
+      && lhs
+        .words()
+        .zip(rhs.words())
+        .all(|(lhs, rhs)| self.head(lhs) == self.head(rhs))
+  }
+
+  /// Groups the `solutions` whose words belong to the same families in the same order.
+  ///
+  /// The groups are in the order in which their first solution appears, and the solutions of
+  /// each group are sorted alphabetically, so that the first solution of a group can stand in
+  /// for the rest.
+  #[must_use]
+  pub fn collapse(
+    &self,
+    solutions: impl IntoIterator<Item = LetterSequence>,
+  ) -> Vec<Vec<LetterSequence>> {
+    let mut groups = Vec::<Vec<LetterSequence>>::new();
+    let mut group_by_heads = HashMap::<Vec<LetterSequence>, usize>::new();
+
+    for solution in solutions {
+      let heads = solution.words().map(|word| self.head(word)).collect();
+      let index = *group_by_heads.entry(heads).or_insert_with(|| {
+        groups.push(Vec::new());
+        groups.len() - 1
+      });
+      groups[index].push(solution);
+    }
+
+    for group in &mut groups {
+      group.sort_by_cached_key(|solution| solution.solution_string());
+    }
+
+    groups
+  }
+}
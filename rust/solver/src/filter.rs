@@ -0,0 +1,225 @@
+//! Defines filters that narrow a search down to the solutions that meet some constraints,
+//! such as solutions that contain a particular word or that have exactly three words.
+//!
+//! A [`SearchFilter`] is applied in two places, so that the search never explores a branch that
+//! could only lead to filtered solutions:
+//!
+//! * The words that can never be part of an admitted solution are removed from the valid words
+//!   up front with [`SearchFilter::valid_words`], and the starting words that cannot begin one are
+//!   skipped with [`SearchFilter::is_starting_word`].
+//! * The constraints on the words of a solution as a whole are checked at every node in the search
+//!   by a [`Solver`] that was configured with [`Solver::with_filter`], which prunes a branch as soon
+//!   as it can no longer meet them.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use letters::LetterSequence;
+//! # use solver::filter::SearchFilter;
+//! # use solver::Solver;
+//! let filter = SearchFilter::new()
+//!   .with_required_word(LetterSequence::new("QUA"))
+//!   .with_word_count_range(3..=3);
+//!
+//! let valid_words = &filter.valid_words(&solver::valid_words("AYDUNEIOLQTR"));
+//! let solver = Solver::default().with_filter(&filter);
+//! let mut solutions = Vec::new();
+//!
+//! for &word in valid_words {
+//!   if filter.is_starting_word(word) {
+//!     solver.solve(word, &mut solutions, valid_words);
+//!   }
+//! }
+//!
+//! assert!(!solutions.is_empty());
+//! assert!(solutions.iter().all(|solution| solution.word_count() == 3));
+//! assert!(solutions
+//!   .iter()
+//!   .all(|solution| solution.words().any(|word| word == "QUA")));
+//! ```
+
+#[cfg(doc)]
+use crate::Solver;
+use crate::{is_candidate, MAX_WORD_COUNT};
+use letters::{LetterSequence, LetterSet};
+use std::ops::RangeInclusive;
+
+/// The constraints that narrow a search down to particular solutions.
+///
+/// Every constraint is optional, and a filter with no constraints admits every solution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilter {
+  required_words: Vec<LetterSequence>,
+  first_word: Option<LetterSequence>,
+  excluded_words: Vec<LetterSequence>,
+  excluded_first_letters: LetterSet,
+  min_word_len: usize,
+  word_count_range: Option<RangeInclusive<usize>>,
+}
+
+impl SearchFilter {
+  /// Returns a new [`SearchFilter`] with no constraints, which admits every solution.
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns the filter, only admitting solutions that contain the `word`.
+  ///
+  /// No solution has more than [`MAX_WORD_COUNT`] words, so a filter that requires more words
+  /// than that admits no solutions at all.
+  #[must_use]
+  pub fn with_required_word(mut self, word: LetterSequence) -> Self {
+    self.required_words.push(word);
+    self
+  }
+
+  /// Returns the filter, only admitting solutions that start with the `word`.
+  #[must_use]
+  pub fn with_first_word(self, word: LetterSequence) -> Self {
+    Self {
+      first_word: Some(word),
+      ..self
+    }
+  }
+
+  /// Returns the filter, only admitting solutions that do not contain the `word`.
+  #[must_use]
+  pub fn with_excluded_word(mut self, word: LetterSequence) -> Self {
+    self.excluded_words.push(word);
+    self
+  }
+
+  /// Returns the filter, only admitting solutions whose first word does not start with any of
+  /// the `letters`.
+  #[must_use]
+  pub fn with_excluded_first_letters(self, letters: LetterSet) -> Self {
+    Self {
+      excluded_first_letters: self.excluded_first_letters | letters,
+      ..self
+    }
+  }
+
+  /// Returns the filter, only admitting solutions in which every word has at least
+  /// `min_word_len` letters.
+  #[must_use]
+  pub fn with_min_word_len(self, min_word_len: usize) -> Self {
+    Self {
+      min_word_len,
+      ..self
+    }
+  }
+
+  /// Returns the filter, only admitting solutions whose count of words is within the `range`.
+  #[must_use]
+  pub fn with_word_count_range(self, range: RangeInclusive<usize>) -> Self {
+    Self {
+      word_count_range: Some(range),
+      ..self
+    }
+  }
+
+  /// Returns [true] if the filter has no constraints, and admits every solution.
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    *self == Self::default()
+  }
+
+  /// Returns [true] if the `word` could be part of an admitted solution, because it is neither
+  /// excluded nor too short.
+  #[must_use]
+  pub fn admits_word(&self, word: LetterSequence) -> bool {
+    word.len() >= self.min_word_len && !self.excluded_words.contains(&word)
+  }
+
+  /// Returns the `valid_words` that could be part of an admitted solution, in their original order,
+  /// so that the search never considers the others as candidates.
+  #[must_use]
+  pub fn valid_words(&self, valid_words: &[LetterSequence]) -> Vec<LetterSequence> {
+    valid_words
+      .iter()
+      .copied()
+      .filter(|&word| self.admits_word(word))
+      .collect()
+  }
+
+  /// Returns [true] if an admitted solution could start with the `word`.
+  #[must_use]
+  pub fn is_starting_word(&self, word: LetterSequence) -> bool {
+    let is_first_word = self.first_word.is_none_or(|first_word| first_word == word);
+    let is_excluded_start = word
+      .ascii_bytes()
+      .next()
+      .is_some_and(|letter| self.excluded_first_letters.has_ascii(letter));
+
+    self.admits_word(word) && is_first_word && !is_excluded_start
+  }
+
+  /// Returns the constraints that a [`Solver`] checks at every node in the search.
+  pub(crate) fn constraints(&self) -> Constraints {
+    let mut constraints = Constraints::NONE;
+
+    if let Some(range) = &self.word_count_range {
+      constraints.min_word_count = *range.start();
+      constraints.max_word_count = *range.end();
+    }
+
+    if self.required_words.len() > MAX_WORD_COUNT {
+      constraints.max_word_count = 0;
+      return constraints;
+    }
+
+    constraints.required_words[..self.required_words.len()].copy_from_slice(&self.required_words);
+    constraints.required_word_count = self.required_words.len();
+    constraints
+  }
+}
+
+/// The constraints of a [`SearchFilter`] that apply to the words of a solution as a whole,
+/// in a form that can be copied into a [`Solver`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Constraints {
+  /// The words that every admitted solution contains, of which only the first
+  /// `required_word_count` are used.
+  required_words: [LetterSequence; MAX_WORD_COUNT],
+  /// The count of `required_words`.
+  required_word_count: usize,
+  /// The fewest words in an admitted solution.
+  min_word_count: usize,
+  /// The most words in an admitted solution.
+  max_word_count: usize,
+}
+
+impl Constraints {
+  /// The constraints of a filter that admits every solution.
+  pub(crate) const NONE: Self = Self {
+    required_words: [LetterSequence::empty(); MAX_WORD_COUNT],
+    required_word_count: 0,
+    min_word_count: 1,
+    max_word_count: MAX_WORD_COUNT,
+  };
+
+  /// Returns [true] if the `sequence` could still be, or be extended into, an admitted solution.
+  #[inline]
+  pub(crate) fn admits(self, sequence: LetterSequence) -> bool {
+    let word_count = sequence.word_count() as usize;
+
+    let is_word_count_admitted = if sequence.has_all_letters() {
+      (self.min_word_count..=self.max_word_count).contains(&word_count)
+    } else {
+      word_count < self.max_word_count
+    };
+
+    is_word_count_admitted
+      && self.required_words[..self.required_word_count]
+        .iter()
+        .all(|&word| is_candidate(word, sequence) || contains_word(sequence, word))
+  }
+}
+
+/// Returns [true] if the `word` is one of the words of the `sequence`.
+#[inline]
+fn contains_word(sequence: LetterSequence, word: LetterSequence) -> bool {
+  word.letter_set().is_subset(sequence.letter_set())
+    && sequence.words().any(|sequence_word| sequence_word == word)
+}
@@ -0,0 +1,702 @@
+//! The shared search algorithm for finding unique-letter solutions to a Letter Boxed puzzle.
+//!
+//! A solution is built by starting from a single valid word and repeatedly appending words
+//! whose first letter is the final letter of the sequence so far, until all 12 letters of
+//! the puzzle input have been used exactly once.
+
+pub mod arena;
+pub mod board;
+pub mod branches;
+pub mod control;
+pub mod cost;
+pub mod families;
+pub mod filter;
+pub mod par;
+pub mod partial;
+pub mod participation;
+pub mod paths;
+pub mod report;
+pub mod stats;
+pub mod wildcard;
+pub mod wire;
+
+pub use arena::CandidateArena;
+pub use board::BoardError;
+pub use branches::Branches;
+pub use control::{SearchControl, SearchOutcome, StopReason};
+pub use families::WordFamilies;
+pub use filter::SearchFilter;
+pub use partial::PartialChain;
+pub use participation::Participation;
+pub use paths::SolutionPaths;
+pub use wildcard::PartialBoard;
+
+use control::{ControlMeter, Meter, Unmetered};
+use filter::Constraints;
+use letters::{Adjacency, LetterSequence, LetterSet, Solution};
+use stats::PruneReason;
+use std::ops::ControlFlow;
+use word_list::WORDS;
+
+/// A destination for the solutions that are found by the solver.
+pub trait Solutions {
+  /// Records a complete 12-letter solution.
+  fn push(&mut self, solution: LetterSequence);
+}
+
+/// Retains every solution that is found.
+impl Solutions for Vec<LetterSequence> {
+  fn push(&mut self, solution: LetterSequence) {
+    Vec::push(self, solution);
+  }
+}
+
+/// Counts the solutions that are found without retaining them.
+impl Solutions for usize {
+  fn push(&mut self, _: LetterSequence) {
+    *self += 1;
+  }
+}
+
+/// The largest count of words in any solution, as given by [`Solution::MAX_WORD_COUNT`].
+pub const MAX_WORD_COUNT: usize = Solution::MAX_WORD_COUNT;
+
+/// Counts the solutions that are found by their word count, without retaining them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WordCountTally {
+  /// The count of solutions with each word count, where index `0` counts one-word solutions.
+  pub by_word_count: [u64; MAX_WORD_COUNT],
+}
+
+impl WordCountTally {
+  /// Returns the total count of solutions.
+  #[must_use]
+  pub fn total(&self) -> u64 {
+    self.by_word_count.iter().sum()
+  }
+
+  /// Adds every count from the `other` tally to this one.
+  pub fn merge(&mut self, other: &Self) {
+    for (count, other) in self.by_word_count.iter_mut().zip(other.by_word_count) {
+      *count += other;
+    }
+  }
+}
+
+impl Solutions for WordCountTally {
+  fn push(&mut self, solution: LetterSequence) {
+    self.by_word_count[solution.word_count() as usize - 1] += 1;
+  }
+}
+
+/// Returns every word from [`WORDS`] that can be spelled on the given 12-letter input,
+/// or an error if the input is not a valid board.
+///
+/// # Errors
+///
+/// Returns a [`BoardError`] if the `input` fails [`board::validate`].
+pub fn try_valid_words(input: &str) -> Result<Vec<LetterSequence>, BoardError> {
+  board::validate(input)?;
+  Ok(valid_words(input))
+}
+
+/// Returns every word from [`WORDS`] that can be spelled on the given 12-letter input.
+///
+/// # Panics
+///
+/// Panics if the `input` does not have exactly 12 characters.
+#[must_use]
+pub fn valid_words(input: &str) -> Vec<LetterSequence> {
+  valid_words_among(WORDS, input)
+}
+
+/// Returns every word from `words` that can be spelled on the given 12-letter input,
+/// in their original order.
+///
+/// This is useful for filtering a list of words that has already been narrowed down from
+/// [`WORDS`], such as the [`candidate_words`](PartialBoard::candidate_words) of a board with wildcards.
+///
+/// # Panics
+///
+/// Panics if the `input` does not have exactly 12 characters.
+#[must_use]
+pub fn valid_words_among(words: &[LetterSequence], input: &str) -> Vec<LetterSequence> {
+  valid_words_among_with(words, &Adjacency::standard(input))
+}
+
+/// Returns every word from [`WORDS`] that can be spelled under the `adjacency` of a board,
+/// which decides the letters that may follow one another under a variant of the rules.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::{Adjacency, AdjacencyRule};
+/// let input = "AIODGTESUNPR";
+/// let easy = solver::valid_words_with(&Adjacency::new(input, AdjacencyRule::Easy));
+///
+/// assert!(easy.len() > solver::valid_words(input).len());
+/// ```
+#[must_use]
+pub fn valid_words_with(adjacency: &Adjacency) -> Vec<LetterSequence> {
+  valid_words_among_with(WORDS, adjacency)
+}
+
+/// Returns every word from `words` that can be spelled under the `adjacency` of a board,
+/// in their original order.
+#[must_use]
+pub fn valid_words_among_with(
+  words: &[LetterSequence],
+  adjacency: &Adjacency,
+) -> Vec<LetterSequence> {
+  words
+    .iter()
+    .copied()
+    .filter(|&word| adjacency.is_valid_word(word))
+    .collect()
+}
+
+/// Tracks which letters can still be covered by the candidate words at a node in the search.
+#[derive(Clone, Copy, Default)]
+struct Coverage {
+  /// The letters that are contained in at least one candidate word.
+  reachable: LetterSet,
+  /// The letters that are contained in at least two candidate words.
+  repeated: LetterSet,
+}
+
+impl Coverage {
+  /// Adds the letters of a candidate word to the coverage.
+  #[inline]
+  fn add(&mut self, word: LetterSequence) {
+    self.repeated = self
+      .repeated
+      .union(self.reachable.intersection(word.letter_set()));
+    self.reachable = self.reachable.union(word.letter_set());
+  }
+
+  /// Returns [true] if every letter that is missing from the `sequence` is contained
+  /// in at least one candidate word. If this is [false], the branch is a dead end.
+  #[inline]
+  fn can_complete(self, sequence: LetterSequence) -> bool {
+    sequence.letter_set().union(self.reachable).len() == LetterSequence::CAPACITY
+  }
+
+  /// Returns the letters that are missing from the `sequence` and are contained in exactly
+  /// one candidate word. These are the least-covered letters, and any branch that covers
+  /// them should be explored first.
+  #[inline]
+  fn most_constrained(self, sequence: LetterSequence) -> LetterSet {
+    self
+      .reachable
+      .difference(self.repeated)
+      .difference(sequence.letter_set())
+  }
+}
+
+/// Returns [true] if the `word` could still be used somewhere after the end of the `sequence`.
+///
+/// A word that is appended next must share exactly one letter with the sequence: the letter at
+/// the word boundary. Any word that is appended after that must share no letters with the sequence
+/// at all. Every other word can never be part of a solution that starts with this `sequence`.
+#[inline]
+fn is_candidate(word: LetterSequence, sequence: LetterSequence) -> bool {
+  word.shared_letter_count(sequence) == 0 || word.can_append_to(sequence)
+}
+
+/// Yields the `appendable_words` that contain any of the `constrained` letters first,
+/// followed by the rest of the `appendable_words`.
+#[inline]
+fn constrained_first(
+  appendable_words: impl Iterator<Item = LetterSequence> + Clone,
+  constrained: LetterSet,
+) -> impl Iterator<Item = LetterSequence> {
+  let covers_constrained =
+    move |word: &LetterSequence| !word.letter_set().intersection(constrained).is_empty();
+
+  appendable_words
+    .clone()
+    .filter(covers_constrained)
+    .chain(appendable_words.filter(move |word| !covers_constrained(word)))
+}
+
+/// How the search continues beneath a node, before its candidate words are gathered.
+enum Visit {
+  /// The branch can no longer lead to a solution.
+  Pruned,
+  /// The sequence is a complete solution.
+  Solution,
+  /// The sequence is incomplete, so its candidate words should be expanded.
+  Inner,
+}
+
+/// The candidate words at a node in the search, once they have been written into a buffer
+/// with the immediately appendable words at the front.
+#[derive(Clone, Copy)]
+struct Expansion {
+  /// The number of appendable words at the front of the buffer.
+  appendable_count: usize,
+  /// The letters that are missing from the sequence and contained in exactly one candidate word.
+  constrained: LetterSet,
+  /// Whether only the words after the appendable words are passed to the next depth.
+  partition: bool,
+}
+
+impl Expansion {
+  /// Yields the appendable words among the `candidates`, with the most constrained first.
+  #[inline]
+  fn appendable_words(
+    self,
+    candidates: &[LetterSequence],
+  ) -> impl Iterator<Item = LetterSequence> + '_ {
+    constrained_first(
+      candidates[..self.appendable_count].iter().copied(),
+      self.constrained,
+    )
+  }
+
+  /// Returns the `candidates` that are passed to the next depth: the words after the appendable
+  /// words when partitioning, or every candidate when filtering.
+  #[inline]
+  fn remaining_words(self, candidates: &[LetterSequence]) -> &[LetterSequence] {
+    if self.partition {
+      &candidates[self.appendable_count..]
+    } else {
+      candidates
+    }
+  }
+}
+
+/// Solves beneath the given `sequence` with the default [`Solver`]. See [`Solver::solve`].
+pub fn solve<S: Solutions>(
+  sequence: LetterSequence,
+  solutions: &mut S,
+  valid_words: &[LetterSequence],
+) {
+  Solver::default().solve(sequence, solutions, valid_words);
+}
+
+/// Solves for every solution among the `valid_words` with the default [`Solver`], starting from
+/// each word in turn. See [`Solver::solve_all`].
+///
+/// # Example
+///
+/// ```rust
+/// let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+/// let mut solution_count = 0;
+///
+/// solver::solve_all(valid_words, &mut solution_count);
+///
+/// assert_eq!(solution_count, 936);
+/// ```
+pub fn solve_all<S: Solutions>(valid_words: &[LetterSequence], solutions: &mut S) {
+  Solver::default().solve_all(valid_words, solutions);
+}
+
+/// The configuration for a search over the valid words of a puzzle input.
+///
+/// At each node in the search, the candidate words are narrowed down in one of two ways:
+///
+/// * **Partition**: The candidates are split into the words that are immediately appendable and
+///   the remaining words, and only the remaining words are passed to the next depth. This strategy
+///   tends to be faster when the list of candidates is large.
+/// * **Filter**: The candidates are collected into a single list that is passed to the next depth,
+///   and the appendable words are found by filtering that list again. This strategy tends to be
+///   faster when the list of candidates is small.
+///
+/// The [`Solver`] partitions whenever there are at least
+/// [`partition_threshold`](Self::partition_threshold) candidates, and filters otherwise.
+///
+/// A [`Solver`] may also be configured [`with_filter`](Self::with_filter) to prune every branch
+/// that can no longer lead to a solution that the [`SearchFilter`] admits.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::Solver;
+/// let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+/// let solver = Solver::default().with_partition_threshold(512);
+/// let mut solution_count = 0;
+///
+/// solver.solve_all(valid_words, &mut solution_count);
+///
+/// assert_eq!(solution_count, 936);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Solver {
+  partition_threshold: usize,
+  constraints: Constraints,
+}
+
+/// Defaults to a [`Solver`] with the [`Solver::DEFAULT_PARTITION_THRESHOLD`].
+impl Default for Solver {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Solver {
+  /// The candidate count at which the [`Solver`] switches from filtering to partitioning,
+  /// as measured by the `calibrate` binary in `letrboxd-benchmarks`.
+  pub const DEFAULT_PARTITION_THRESHOLD: usize = 128;
+
+  /// Returns a new [`Solver`] with the [`Solver::DEFAULT_PARTITION_THRESHOLD`].
+  #[must_use]
+  pub const fn new() -> Self {
+    Self {
+      partition_threshold: Self::DEFAULT_PARTITION_THRESHOLD,
+      constraints: Constraints::NONE,
+    }
+  }
+
+  /// Returns a new [`Solver`] that partitions the candidate words at any node with at least
+  /// `partition_threshold` candidates, and filters them otherwise.
+  ///
+  /// A threshold of `0` always partitions, and a threshold of [`usize::MAX`] always filters.
+  #[must_use]
+  pub const fn with_partition_threshold(self, partition_threshold: usize) -> Self {
+    Self {
+      partition_threshold,
+      ..self
+    }
+  }
+
+  /// Returns a new [`Solver`] that prunes every branch of the search that can no longer lead to
+  /// a solution with the words that the `filter` requires, or with a count of words that it admits.
+  ///
+  /// The constraints of the `filter` on individual words are not checked during the search, so the
+  /// valid words and starting words should also be narrowed down with [`SearchFilter::valid_words`]
+  /// and [`SearchFilter::is_starting_word`]. See the [`filter`] module.
+  #[must_use]
+  pub fn with_filter(self, filter: &SearchFilter) -> Self {
+    Self {
+      constraints: filter.constraints(),
+      ..self
+    }
+  }
+
+  /// Returns the candidate count at which this [`Solver`] switches from filtering to partitioning.
+  #[must_use]
+  pub const fn partition_threshold(self) -> usize {
+    self.partition_threshold
+  }
+
+  /// Recursively solves for valid 12-letter sequences that start with the given `sequence`,
+  /// pushing each solution into `solutions`.
+  ///
+  /// Each node prunes the branch if the candidate words can no longer cover every missing letter,
+  /// and it explores the words that cover the least-covered letters first.
+  pub fn solve<S: Solutions>(
+    self,
+    sequence: LetterSequence,
+    solutions: &mut S,
+    valid_words: &[LetterSequence],
+  ) {
+    stats::time_starting_word(sequence, || {
+      self.solve_node(sequence, solutions, valid_words);
+    });
+  }
+
+  /// Solves beneath a single node in the search, writing its candidate words into a newly
+  /// allocated list.
+  fn solve_node<S: Solutions>(
+    self,
+    sequence: LetterSequence,
+    solutions: &mut S,
+    valid_words: &[LetterSequence],
+  ) {
+    match self.visit(sequence) {
+      Visit::Pruned => {}
+      Visit::Solution => {
+        stats::record_solution();
+        solutions.push(sequence);
+      }
+      Visit::Inner => {
+        let candidates = &mut Vec::new();
+        let Some(expansion) = self.expand(sequence, valid_words, candidates) else {
+          return;
+        };
+
+        for word in expansion.appendable_words(candidates) {
+          self.solve_node(
+            word.append_to(sequence),
+            solutions,
+            expansion.remaining_words(candidates),
+          );
+        }
+      }
+    }
+  }
+
+  /// Solves for every solution among the `valid_words`, starting from each word in turn,
+  /// pushing each solution into `solutions`.
+  pub fn solve_all<S: Solutions>(self, valid_words: &[LetterSequence], solutions: &mut S) {
+    for &word in valid_words {
+      self.solve(word, solutions, valid_words);
+    }
+  }
+
+  /// Finds the same solutions as [`Solver::solve`] without allocating, by writing the candidate
+  /// words at each depth into the buffers of the given [`CandidateArena`] instead of newly
+  /// allocated lists. The `arena` grows to fit the `valid_words` if needed, so it should be reused across calls.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use solver::{CandidateArena, Solver};
+  /// let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+  /// let arena = &mut CandidateArena::with_capacity(valid_words.len());
+  /// let mut solution_count = 0;
+  ///
+  /// for &word in valid_words {
+  ///   Solver::default().solve_in(arena, word, &mut solution_count, valid_words);
+  /// }
+  ///
+  /// assert_eq!(solution_count, 936);
+  /// ```
+  pub fn solve_in<S: Solutions>(
+    self,
+    arena: &mut CandidateArena,
+    sequence: LetterSequence,
+    solutions: &mut S,
+    valid_words: &[LetterSequence],
+  ) {
+    if arena.capacity() < valid_words.len() {
+      arena.reserve(valid_words.len());
+    }
+
+    stats::time_starting_word(sequence, || {
+      let _ = self.solve_arena(
+        arena.depths_mut(),
+        sequence,
+        solutions,
+        valid_words,
+        &mut Unmetered,
+      );
+    });
+  }
+
+  /// Finds the same solutions as [`Solver::solve_in`] until the search is stopped by the
+  /// `control`, in which case it returns [`ControlFlow::Break`] with the reason that the search
+  /// stopped and the `solutions` hold only those that were found before it stopped. Once a `control` has
+  /// stopped, every later call with that `control` returns immediately.
+  ///
+  /// The caller is responsible for calling [`SearchControl::complete_starting_word`]
+  /// once every search for a starting word has returned.
+  pub fn solve_controlled<S: Solutions>(
+    self,
+    arena: &mut CandidateArena,
+    sequence: LetterSequence,
+    solutions: &mut S,
+    valid_words: &[LetterSequence],
+    control: &SearchControl,
+  ) -> ControlFlow<StopReason> {
+    control.check()?;
+
+    if arena.capacity() < valid_words.len() {
+      arena.reserve(valid_words.len());
+    }
+
+    let meter = &mut ControlMeter::new(control);
+    let flow = stats::time_starting_word(sequence, || {
+      self.solve_arena(arena.depths_mut(), sequence, solutions, valid_words, meter)
+    });
+
+    // The search beneath this sequence is complete unless it was stopped, so any limit that is
+    // reached while tallying the final nodes only stops the next search.
+    let _ = meter.flush();
+    flow
+  }
+
+  /// Searches for every solution among the `valid_words`, one starting word at a time,
+  /// until the search completes or is stopped by the `control`.
+  ///
+  /// If the search is stopped, the returned [`SearchOutcome`] holds the solutions
+  /// that were found up to that point, along with the reason that it stopped.
+  #[must_use]
+  pub fn search<S: Solutions + Default>(
+    self,
+    valid_words: &[LetterSequence],
+    control: &SearchControl,
+  ) -> SearchOutcome<S> {
+    let mut solutions = S::default();
+    let arena = &mut CandidateArena::with_capacity(valid_words.len());
+
+    for &word in valid_words {
+      if self
+        .solve_controlled(arena, word, &mut solutions, valid_words, control)
+        .is_break()
+      {
+        break;
+      }
+
+      control.complete_starting_word();
+    }
+
+    SearchOutcome {
+      solutions,
+      progress: control.progress(),
+      stop_reason: control.stop_reason(),
+    }
+  }
+
+  /// Solves beneath a single node in the search, writing its candidate words into the first of
+  /// the `depths` and recursing with the remaining `depths`.
+  ///
+  /// Each visited node is reported to the `meter`, which may stop the search.
+  fn solve_arena<S: Solutions, M: Meter>(
+    self,
+    depths: &mut [Vec<LetterSequence>],
+    sequence: LetterSequence,
+    solutions: &mut S,
+    valid_words: &[LetterSequence],
+    meter: &mut M,
+  ) -> ControlFlow<StopReason> {
+    meter.enter_node()?;
+
+    match self.visit(sequence) {
+      Visit::Pruned => ControlFlow::Continue(()),
+      Visit::Solution => {
+        meter.admit_solution()?;
+        stats::record_solution();
+        solutions.push(sequence);
+        ControlFlow::Continue(())
+      }
+      Visit::Inner => {
+        let Some((candidates, deeper_depths)) = depths.split_first_mut() else {
+          unreachable!("A sequence with 5 words has at least 11 letters.");
+        };
+        let Some(expansion) = self.expand(sequence, valid_words, candidates) else {
+          return ControlFlow::Continue(());
+        };
+
+        expansion.appendable_words(candidates).try_for_each(|word| {
+          self.solve_arena(
+            deeper_depths,
+            word.append_to(sequence),
+            solutions,
+            expansion.remaining_words(candidates),
+            meter,
+          )
+        })
+      }
+    }
+  }
+
+  /// Splits the search beneath the given `sequence` into independent sub-searches, one for
+  /// each word that can be appended to the `sequence`, so that they can be distributed among
+  /// threads at a finer granularity than one starting word each.
+  ///
+  /// A `sequence` that is already a solution yields a single sub-search for itself.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use solver::Solver;
+  /// let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+  /// let solver = Solver::default();
+  /// let mut solution_count = 0;
+  ///
+  /// for &word in valid_words {
+  ///   let branches = solver.branches(word, valid_words);
+  ///   for &sequence in branches.sequences() {
+  ///     solver.solve(sequence, &mut solution_count, branches.valid_words());
+  ///   }
+  /// }
+  ///
+  /// assert_eq!(solution_count, 936);
+  /// ```
+  #[must_use]
+  pub fn branches(self, sequence: LetterSequence, valid_words: &[LetterSequence]) -> Branches {
+    stats::time_starting_word(sequence, || self.branches_node(sequence, valid_words))
+  }
+
+  /// Splits the search beneath a single node into one sub-search for each appendable word.
+  fn branches_node(self, sequence: LetterSequence, valid_words: &[LetterSequence]) -> Branches {
+    match self.visit(sequence) {
+      Visit::Pruned => Branches::default(),
+      Visit::Solution => Branches::new(vec![sequence], Vec::new()),
+      Visit::Inner => {
+        let candidates = &mut Vec::new();
+        let Some(expansion) = self.expand(sequence, valid_words, candidates) else {
+          return Branches::default();
+        };
+
+        let sequences = expansion
+          .appendable_words(candidates)
+          .map(|word| word.append_to(sequence))
+          .collect();
+
+        Branches::new(sequences, expansion.remaining_words(candidates).to_vec())
+      }
+    }
+  }
+
+  /// Returns how the search continues beneath the `sequence`, recording the reason for any prune.
+  fn visit(self, sequence: LetterSequence) -> Visit {
+    if !self.constraints.admits(sequence) {
+      stats::record_prune(PruneReason::Filter);
+      return Visit::Pruned;
+    }
+
+    match sequence.len() {
+      // If we have constructed a valid sequence with exactly 12 letters, it is a solution.
+      12 => Visit::Solution,
+      // There are no words that can be appended to an 11-letter sequence to form a 12-letter
+      // solution because the minimum valid word length is 3 letters. This is a dead end.
+      11 => {
+        stats::record_prune(PruneReason::DeadEnd);
+        Visit::Pruned
+      }
+      _ => Visit::Inner,
+    }
+  }
+
+  /// Writes the candidate words for the `sequence` into the `candidates` buffer, with the
+  /// immediately appendable words at the front, and records the node in the search statistics.
+  ///
+  /// Returns [None] if the candidates can no longer cover every letter that is missing from
+  /// the `sequence`, in which case the branch is a dead end.
+  #[inline]
+  fn expand(
+    self,
+    sequence: LetterSequence,
+    valid_words: &[LetterSequence],
+    candidates: &mut Vec<LetterSequence>,
+  ) -> Option<Expansion> {
+    let mut coverage = Coverage::default();
+    let mut appendable_count = 0;
+
+    candidates.clear();
+
+    for &word in valid_words {
+      if word.can_append_to(sequence) {
+        // Swap each appendable word to the front of the buffer.
+        let index = candidates.len();
+        candidates.push(word);
+        candidates.swap(appendable_count, index);
+        appendable_count += 1;
+        coverage.add(word);
+      } else if word.shared_letter_count(sequence) == 0 {
+        candidates.push(word);
+        coverage.add(word);
+      }
+    }
+
+    stats::record_node(sequence, candidates.len(), appendable_count);
+
+    if !coverage.can_complete(sequence) {
+      stats::record_prune(PruneReason::Coverage);
+      return None;
+    }
+
+    if appendable_count == 0 {
+      stats::record_prune(PruneReason::NoAppendableWords);
+    }
+
+    Some(Expansion {
+      appendable_count,
+      constrained: coverage.most_constrained(sequence),
+      partition: valid_words.len() >= self.partition_threshold,
+    })
+  }
+}
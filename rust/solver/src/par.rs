@@ -0,0 +1,282 @@
+//! Defines a search for the par of a board: the fewest words that cover every letter under the
+//! standard rules, in which letters may be repeated.
+//!
+//! Unlike a unique-letter solution, a standard solution may use any letter more than once, so the
+//! words are drawn from [`PLAYABLE_WORDS`] rather than [`WORDS`](word_list::WORDS). The search is
+//! a breadth-first search over states made of the letters covered so far and the final letter of
+//! the last word, since those are all that decide which words may follow and when the board is
+//! covered. There are at most `2^12 * 12` such states, so the search is cheap for any board.
+//!
+//! A board that is played under a variant of the rules can be searched with [`par_with`], which
+//! takes an [`Adjacency`] that decides the letters that may follow one another.
+
+use letters::{compress_letter, Adjacency, LetterSet};
+use std::collections::HashMap;
+use word_list::PLAYABLE_WORDS;
+
+/// The fewest words that solve a board under the standard rules, along with examples of
+/// the solutions that use that many words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Par {
+  /// The fewest words in any solution.
+  pub word_count: usize,
+  /// The count of distinct solutions that use exactly [`word_count`](Self::word_count) words.
+  pub solution_count: u64,
+  /// Up to the requested count of solutions that use exactly [`word_count`](Self::word_count)
+  /// words, each as its words in order.
+  pub examples: Vec<Vec<&'static str>>,
+}
+
+/// A playable word on a particular board, reduced to what decides where it may be played.
+#[derive(Debug, Clone, Copy)]
+struct ParWord {
+  /// The uppercase word.
+  word: &'static str,
+  /// The compressed first letter of the word.
+  first_letter: u8,
+  /// The compressed final letter of the word.
+  last_letter: u8,
+  /// The distinct letters of the word.
+  letter_set: LetterSet,
+}
+
+/// The letters covered so far, and the compressed final letter of the last word.
+type State = (LetterSet, u8);
+
+/// Returns every word from [`PLAYABLE_WORDS`] that can be spelled on the given 12-letter input
+/// under the standard rules, in which letters may be repeated but no two consecutive letters may
+/// be on the same side.
+///
+/// # Panics
+///
+/// Panics if the `input` does not have exactly 12 characters.
+#[must_use]
+pub fn playable_words(input: &str) -> Vec<&'static str> {
+  playable_words_with(&Adjacency::standard(input))
+}
+
+/// Returns every word from [`PLAYABLE_WORDS`] that can be spelled under the `adjacency` of a board,
+/// in which letters may be repeated.
+#[must_use]
+pub fn playable_words_with(adjacency: &Adjacency) -> Vec<&'static str> {
+  PLAYABLE_WORDS
+    .iter()
+    .copied()
+    .filter(|word| is_playable(word, adjacency))
+    .collect()
+}
+
+/// Returns the par of the board for the given 12-letter input, with up to `example_limit`
+/// example solutions, or [`None`] if no sequence of words covers every letter.
+///
+/// # Panics
+///
+/// Panics if the `input` does not have exactly 12 characters.
+///
+/// # Example
+///
+/// ```rust
+/// let par = solver::par::par("AIODGTESUNPR", 3).unwrap();
+///
+/// assert_eq!(par.word_count, 2);
+/// assert_eq!(par.examples.len(), 3);
+/// assert!(par.examples.iter().all(|example| example.len() == 2));
+/// ```
+#[must_use]
+pub fn par(input: &str, example_limit: usize) -> Option<Par> {
+  par_with(input, &Adjacency::standard(input), example_limit)
+}
+
+/// Returns the par of the board for the given 12-letter input under the `adjacency` of a variant
+/// of the rules, with up to `example_limit` example solutions, or [`None`] if no sequence of words
+/// covers every letter.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::{Adjacency, AdjacencyRule};
+/// let input = "AIODGTESUNPR";
+/// let easy = solver::par::par_with(input, &Adjacency::new(input, AdjacencyRule::Easy), 1);
+///
+/// assert!(easy.unwrap().word_count <= solver::par::par(input, 1).unwrap().word_count);
+/// ```
+#[must_use]
+pub fn par_with(input: &str, adjacency: &Adjacency, example_limit: usize) -> Option<Par> {
+  let board = LetterSet::from_ascii_slice(input.as_bytes());
+  let words = playable_words_with(adjacency)
+    .into_iter()
+    .map(|word| {
+      let bytes = word.as_bytes();
+      ParWord {
+        word,
+        first_letter: compress_letter(bytes[0]),
+        last_letter: compress_letter(bytes[bytes.len() - 1]),
+        letter_set: bytes
+          .iter()
+          .map(|&letter| compress_letter(letter))
+          .collect(),
+      }
+    })
+    .collect::<Vec<_>>();
+
+  let mut words_by_first_letter = vec![Vec::new(); 26];
+  for (index, word) in words.iter().enumerate() {
+    words_by_first_letter[word.first_letter as usize].push(index);
+  }
+
+  let search = ParSearch::run(board, &words, &words_by_first_letter)?;
+  Some(Par {
+    word_count: search.word_count,
+    solution_count: search.solution_count(),
+    examples: search.examples(example_limit),
+  })
+}
+
+/// The result of a breadth-first search from every starting word to the first depth at which
+/// the board is covered.
+struct ParSearch<'a> {
+  /// The playable words on the board.
+  words: &'a [ParWord],
+  /// The fewest words that cover the board.
+  word_count: usize,
+  /// For each state that was reached, the state before it and the index of the word that
+  /// reached it, for every shortest path to it. Starting states have no state before them.
+  parents: HashMap<State, Vec<(Option<State>, usize)>>,
+  /// The states at the final depth that cover the board.
+  goals: Vec<State>,
+}
+
+impl<'a> ParSearch<'a> {
+  /// Searches one depth at a time until some state covers the `board`, or returns [`None`]
+  /// if the search runs out of new states first.
+  fn run(
+    board: LetterSet,
+    words: &'a [ParWord],
+    words_by_first_letter: &[Vec<usize>],
+  ) -> Option<Self> {
+    let mut parents = HashMap::<State, Vec<(Option<State>, usize)>>::new();
+    for (index, word) in words.iter().enumerate() {
+      parents
+        .entry((word.letter_set, word.last_letter))
+        .or_default()
+        .push((None, index));
+    }
+
+    let mut frontier = parents.keys().copied().collect::<Vec<_>>();
+    let mut word_count = 1;
+
+    loop {
+      let goals = frontier
+        .iter()
+        .copied()
+        .filter(|&(covered, _)| covered == board)
+        .collect::<Vec<_>>();
+
+      if !goals.is_empty() {
+        return Some(Self {
+          words,
+          word_count,
+          parents,
+          goals,
+        });
+      }
+
+      // Every shortest path to a new state is recorded, but only the first depth that reaches
+      // a state may record paths to it.
+      let mut next = HashMap::<State, Vec<(Option<State>, usize)>>::new();
+      for &state @ (covered, last_letter) in &frontier {
+        for &index in &words_by_first_letter[last_letter as usize] {
+          let word = words[index];
+          let next_state = (covered | word.letter_set, word.last_letter);
+
+          if !parents.contains_key(&next_state) {
+            next
+              .entry(next_state)
+              .or_default()
+              .push((Some(state), index));
+          }
+        }
+      }
+
+      if next.is_empty() {
+        return None;
+      }
+
+      frontier = next.keys().copied().collect();
+      parents.extend(next);
+      word_count += 1;
+    }
+  }
+
+  /// Returns the count of distinct sequences of [`word_count`](Self::word_count) words that
+  /// cover the board.
+  fn solution_count(&self) -> u64 {
+    let mut path_counts = HashMap::new();
+    self
+      .goals
+      .iter()
+      .map(|&goal| self.path_count(goal, &mut path_counts))
+      .sum()
+  }
+
+  /// Returns the count of shortest paths to the `state`, memoized in `path_counts`.
+  fn path_count(&self, state: State, path_counts: &mut HashMap<State, u64>) -> u64 {
+    if let Some(&count) = path_counts.get(&state) {
+      return count;
+    }
+
+    let count = self.parents[&state]
+      .iter()
+      .map(|&(parent, _)| parent.map_or(1, |parent| self.path_count(parent, path_counts)))
+      .sum();
+
+    path_counts.insert(state, count);
+    count
+  }
+
+  /// Returns up to `limit` sequences of [`word_count`](Self::word_count) words that cover
+  /// the board, sorted alphabetically.
+  fn examples(&self, limit: usize) -> Vec<Vec<&'static str>> {
+    let mut examples = Vec::new();
+    let mut suffix = Vec::new();
+
+    for &goal in &self.goals {
+      self.collect_paths(goal, &mut suffix, &mut examples, limit);
+    }
+
+    examples.sort();
+    examples
+  }
+
+  /// Walks back from the `state` to each starting word, recording each path with the words of
+  /// the `suffix` after it, until `limit` paths have been recorded.
+  fn collect_paths(
+    &self,
+    state: State,
+    suffix: &mut Vec<&'static str>,
+    paths: &mut Vec<Vec<&'static str>>,
+    limit: usize,
+  ) {
+    for &(parent, index) in &self.parents[&state] {
+      if paths.len() >= limit {
+        return;
+      }
+
+      suffix.push(self.words[index].word);
+      match parent {
+        Some(parent) => self.collect_paths(parent, suffix, paths, limit),
+        None => paths.push(suffix.iter().rev().copied().collect()),
+      }
+      suffix.pop();
+    }
+  }
+}
+
+/// Returns [true] if every letter of the uppercase `word` may follow the letter before it under
+/// the `adjacency`, which also requires every letter to be on the board.
+fn is_playable(word: &str, adjacency: &Adjacency) -> bool {
+  word
+    .as_bytes()
+    .windows(2)
+    .all(|pair| adjacency.can_follow(compress_letter(pair[0]), compress_letter(pair[1])))
+}
@@ -0,0 +1,123 @@
+//! Defines a search for the best partial chains on a board that has no unique-letter solution.
+//!
+//! A partial chain follows the same rules as a solution, with each word starting on the final
+//! letter of the word before it and no letter used twice, but it does not cover every letter.
+//! When a board has no solution, the most useful thing to show is how close it comes: the chains
+//! that miss only one or two letters, or failing that, the longest chains there are.
+
+use letters::{LetterSequence, LetterSet};
+
+/// The largest count of uncovered letters for which every chain is reported.
+///
+/// Chains that miss more letters are only reported if no chain comes closer.
+pub const MAX_MISSING_LETTERS: usize = 2;
+
+/// A chain of words that cannot be extended any further, and the letters of the board
+/// that it leaves uncovered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialChain {
+  /// The words of the chain.
+  pub sequence: LetterSequence,
+  /// The letters of the board that the chain does not use.
+  pub missing_letters: LetterSet,
+}
+
+/// Returns the best partial chains that can be built from the `valid_words` for the `input`.
+///
+/// Every chain that misses at most [`MAX_MISSING_LETTERS`] letters is returned. If there are none,
+/// the longest chains are returned instead. Only chains that cannot be extended by another valid
+/// word are included, and they are sorted from the fewest missing letters to the most, then from
+/// the fewest words to the most, then alphabetically.
+///
+/// This is meant for boards without a solution. For any other board, the solutions themselves
+/// are returned as chains with no missing letters, alongside the near misses.
+///
+/// # Example
+///
+/// ```rust
+/// let input = "AIVROKMPTJEC";
+/// let valid_words = &solver::valid_words(input);
+/// let chains = solver::partial::best_partial_chains(input, valid_words);
+///
+/// // No chain covers every letter, but the best ones miss only a single letter.
+/// assert_eq!(chains[0].missing_letters.len(), 1);
+/// assert!(chains.iter().all(|chain| chain.missing_letters.len() <= 2));
+/// ```
+#[must_use]
+pub fn best_partial_chains(input: &str, valid_words: &[LetterSequence]) -> Vec<PartialChain> {
+  let board = LetterSet::from_ascii_slice(input.as_bytes());
+  let mut search = ChainSearch {
+    threshold: 0,
+    chains: Vec::new(),
+  };
+
+  for &word in valid_words {
+    search.extend(word, valid_words);
+  }
+
+  let threshold = search.threshold;
+  let mut chains = search
+    .chains
+    .into_iter()
+    .filter(|sequence| sequence.len() >= threshold)
+    .map(|sequence| PartialChain {
+      sequence,
+      missing_letters: sequence.letter_set().complement_in(board),
+    })
+    .collect::<Vec<_>>();
+
+  chains.sort_by_cached_key(|chain| {
+    (
+      chain.missing_letters.len(),
+      chain.sequence.word_count(),
+      chain.sequence.solution_string(),
+    )
+  });
+
+  chains
+}
+
+/// The state of a search for the best partial chains.
+struct ChainSearch {
+  /// The length that a chain must reach to be reported, which rises as longer chains are found,
+  /// up to the length of a chain that misses [`MAX_MISSING_LETTERS`] letters.
+  threshold: usize,
+  /// The chains that reached the threshold at the time that they were found.
+  chains: Vec<LetterSequence>,
+}
+
+impl ChainSearch {
+  /// Extends the `sequence` with every candidate word that can follow it, recording each chain
+  /// that cannot be extended any further.
+  fn extend(&mut self, sequence: LetterSequence, candidates: &[LetterSequence]) {
+    // A word that shares more than its first letter with the sequence can never be appended.
+    let candidates = candidates
+      .iter()
+      .copied()
+      .filter(|&word| word.shared_letter_count(sequence) <= 1)
+      .collect::<Vec<_>>();
+
+    let reachable = candidates
+      .iter()
+      .map(|word| word.letter_set())
+      .collect::<LetterSet>();
+    if (sequence.letter_set() | reachable).len() < self.threshold {
+      return;
+    }
+
+    let mut is_extended = false;
+    for &word in &candidates {
+      if word.can_append_to(sequence) {
+        is_extended = true;
+        self.extend(word.append_to(sequence), &candidates);
+      }
+    }
+
+    if !is_extended && sequence.len() >= self.threshold {
+      self.threshold = sequence
+        .len()
+        .min(LetterSequence::CAPACITY - MAX_MISSING_LETTERS);
+      self.chains.push(sequence);
+    }
+  }
+}
@@ -0,0 +1,132 @@
+//! Tallies how the words and letters of a board take part in its solutions, such as which words
+//! appear in the most solutions and which starting words lead to the most solutions.
+//!
+//! A [`Participation`] is a destination for [`Solutions`], so the tables are built while the
+//! search runs, without retaining the solutions themselves. Searches that run on separate threads
+//! can each tally into their own [`Participation`] and be combined with [`Participation::merge`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use solver::participation::Participation;
+//! let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+//! let mut participation = Participation::default();
+//!
+//! solver::solve_all(valid_words, &mut participation);
+//!
+//! assert_eq!(participation.solution_count, 936);
+//!
+//! // No word can take part in more solutions than there are.
+//! let (_, most_solutions) = participation.words()[0];
+//! assert!(most_solutions <= 936);
+//! ```
+
+use crate::Solutions;
+use letters::{LetterSequence, Solution};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The count of solutions that each word, starting word, pattern of word lengths, and letter
+/// takes part in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Participation {
+  /// The count of solutions that were tallied.
+  pub solution_count: u64,
+  /// The count of solutions that contain each word.
+  pub by_word: HashMap<LetterSequence, u64>,
+  /// The count of solutions that start with each word.
+  pub by_first_word: HashMap<LetterSequence, u64>,
+  /// The count of solutions with each pattern of word boundaries, which determines the length
+  /// of each word in order.
+  pub by_word_lengths: HashMap<Solution, u64>,
+  /// The count of solutions in which each uppercase ASCII letter joins two words,
+  /// as the final letter of one word and the first letter of the next.
+  pub by_junction_letter: HashMap<u8, u64>,
+}
+
+impl Solutions for Participation {
+  fn push(&mut self, solution: LetterSequence) {
+    self.solution_count += 1;
+
+    for (index, word) in solution.words().enumerate() {
+      *self.by_word.entry(word).or_default() += 1;
+
+      if index == 0 {
+        *self.by_first_word.entry(word).or_default() += 1;
+      } else if let Some(letter) = word.ascii_bytes().next() {
+        *self.by_junction_letter.entry(letter).or_default() += 1;
+      }
+    }
+
+    *self.by_word_lengths.entry(solution.solution()).or_default() += 1;
+  }
+}
+
+impl Participation {
+  /// Adds every count from the `other` tally to this one.
+  pub fn merge(&mut self, other: &Self) {
+    self.solution_count += other.solution_count;
+    merge_counts(&mut self.by_word, &other.by_word);
+    merge_counts(&mut self.by_first_word, &other.by_first_word);
+    merge_counts(&mut self.by_word_lengths, &other.by_word_lengths);
+    merge_counts(&mut self.by_junction_letter, &other.by_junction_letter);
+  }
+
+  /// Returns each word with the count of solutions that contain it, from the most solutions
+  /// to the fewest, then alphabetically.
+  #[must_use]
+  pub fn words(&self) -> Vec<(LetterSequence, u64)> {
+    ranked(&self.by_word, ToString::to_string)
+  }
+
+  /// Returns each starting word with the count of solutions that start with it, from the most
+  /// solutions to the fewest, then alphabetically.
+  #[must_use]
+  pub fn first_words(&self) -> Vec<(LetterSequence, u64)> {
+    ranked(&self.by_first_word, ToString::to_string)
+  }
+
+  /// Returns the length of each word in order, e.g. `[6, 7]`, for every pattern of word lengths
+  /// with the count of solutions that follow it, from the most solutions to the fewest, then
+  /// from the fewest words to the most.
+  #[must_use]
+  pub fn word_lengths(&self) -> Vec<(Vec<usize>, u64)> {
+    ranked(&self.by_word_lengths, |&solution| {
+      (solution.word_count(), word_lengths(solution))
+    })
+    .into_iter()
+    .map(|(solution, count)| (word_lengths(solution), count))
+    .collect()
+  }
+
+  /// Returns each uppercase ASCII letter with the count of solutions in which it joins two words,
+  /// from the most solutions to the fewest, then alphabetically.
+  #[must_use]
+  pub fn junction_letters(&self) -> Vec<(u8, u64)> {
+    ranked(&self.by_junction_letter, |&letter| letter)
+  }
+}
+
+/// Adds each count in `other` to the count for the same key in `counts`.
+fn merge_counts<K: Copy + Eq + Hash>(counts: &mut HashMap<K, u64>, other: &HashMap<K, u64>) {
+  for (&key, &count) in other {
+    *counts.entry(key).or_default() += count;
+  }
+}
+
+/// Returns the `counts` from the largest to the smallest, breaking ties by the `tiebreak` of
+/// each key in ascending order.
+fn ranked<K: Copy, T: Ord>(counts: &HashMap<K, u64>, tiebreak: impl Fn(&K) -> T) -> Vec<(K, u64)> {
+  let mut ranked = counts
+    .iter()
+    .map(|(&key, &count)| (key, count))
+    .collect::<Vec<_>>();
+
+  ranked.sort_by_cached_key(|(key, count)| (std::cmp::Reverse(*count), tiebreak(key)));
+  ranked
+}
+
+/// Returns the length of each word in a 12-letter sequence with the boundaries of the `solution`.
+fn word_lengths(solution: Solution) -> Vec<usize> {
+  solution.word_ranges().map(|range| range.len()).collect()
+}
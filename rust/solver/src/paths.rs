@@ -0,0 +1,124 @@
+//! Groups solutions that follow the same path through the letters of a board, but split the
+//! letters into words differently.
+//!
+//! A single 12-letter path often yields several solutions, such as `IMPARTED DUNKS` and
+//! `IMPART TED DUNKS`, and these variants dominate the lists of boards with many solutions.
+//! A [`SolutionPaths`] is a destination for [`Solutions`] that keeps each path once, along with
+//! the [`Solution`] of every split of it.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use solver::paths::SolutionPaths;
+//! let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+//! let mut paths = SolutionPaths::default();
+//!
+//! solver::solve_all(valid_words, &mut paths);
+//!
+//! assert_eq!(paths.solution_count(), 936);
+//! assert!(paths.path_count() < 936);
+//! ```
+
+use crate::Solutions;
+use letters::{LetterSequence, Solution};
+use std::collections::HashMap;
+
+/// A path through the letters of a board, and every way that its letters are split into words
+/// in a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionPath {
+  /// The letters of the path, split into words by the first of the [`splits`](Self::splits).
+  pub letters: LetterSequence,
+  /// The word boundaries of each solution that follows the path, from the fewest words to
+  /// the most, then by their boundaries.
+  pub splits: Vec<Solution>,
+}
+
+impl SolutionPath {
+  /// Returns each solution that follows the path, in the order of its [`splits`](Self::splits).
+  pub fn solutions(&self) -> impl Iterator<Item = LetterSequence> + '_ {
+    self
+      .splits
+      .iter()
+      .map(|&split| self.letters.with_solution(split))
+  }
+}
+
+/// Groups solutions by their letters, which compare equal regardless of their word boundaries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolutionPaths {
+  /// The word boundaries of each solution, grouped by its letters.
+  splits_by_letters: HashMap<LetterSequence, Vec<Solution>>,
+  /// The count of solutions that were grouped.
+  solution_count: u64,
+}
+
+impl Solutions for SolutionPaths {
+  fn push(&mut self, solution: LetterSequence) {
+    self
+      .splits_by_letters
+      .entry(solution)
+      .or_default()
+      .push(solution.solution());
+    self.solution_count += 1;
+  }
+}
+
+/// Groups the solutions from an iterator.
+impl FromIterator<LetterSequence> for SolutionPaths {
+  fn from_iter<I: IntoIterator<Item = LetterSequence>>(solutions: I) -> Self {
+    let mut paths = Self::default();
+    for solution in solutions {
+      paths.push(solution);
+    }
+    paths
+  }
+}
+
+impl SolutionPaths {
+  /// Returns the count of distinct paths among the solutions.
+  #[must_use]
+  pub fn path_count(&self) -> usize {
+    self.splits_by_letters.len()
+  }
+
+  /// Returns the count of solutions across every path.
+  #[must_use]
+  pub fn solution_count(&self) -> u64 {
+    self.solution_count
+  }
+
+  /// Adds every solution from the `other` paths to these paths.
+  pub fn merge(&mut self, other: Self) {
+    for (letters, splits) in other.splits_by_letters {
+      self
+        .splits_by_letters
+        .entry(letters)
+        .or_default()
+        .extend(splits);
+    }
+    self.solution_count += other.solution_count;
+  }
+
+  /// Returns every path with its splits, from the paths whose best split has the fewest words
+  /// to the most, then alphabetically.
+  #[must_use]
+  pub fn paths(&self) -> Vec<SolutionPath> {
+    let mut paths = self
+      .splits_by_letters
+      .iter()
+      .map(|(&letters, splits)| {
+        let mut splits = splits.clone();
+        splits.sort_by(|lhs, rhs| lhs.cmp_word_count(*rhs).then_with(|| lhs.cmp(rhs)));
+
+        SolutionPath {
+          letters: letters.with_solution(splits[0]),
+          splits,
+        }
+      })
+      .collect::<Vec<_>>();
+
+    paths.sort_by_cached_key(|path| (path.letters.word_count(), path.letters.to_string()));
+    paths
+  }
+}
@@ -0,0 +1,268 @@
+//! Defines a report that explains why a board has few or no solutions.
+//!
+//! A board is sparse when its letters combine into few valid words, and the report breaks down
+//! where the words of [`WORDS`] were lost, which letters the valid words can start or end on,
+//! how many valid words cover each letter, and which pairs of sides no valid word moves between.
+
+use letters::{compress_letter, LetterSet};
+use std::fmt::Display;
+use word_list::WORDS;
+
+/// The count of sides on a board.
+pub const SIDE_COUNT: usize = 4;
+
+/// The count of valid words below which a letter is reported as rarely covered, by default.
+pub const DEFAULT_RARE_WORD_COUNT: usize = 10;
+
+/// How the valid words of a board use one of its letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LetterReport {
+  /// The uppercase ASCII letter.
+  pub letter: u8,
+  /// The index of the side of the board that holds the letter, from `0` to `3`.
+  pub side: usize,
+  /// The count of valid words that contain the letter.
+  pub word_count: usize,
+  /// The count of valid words that start with the letter.
+  pub start_count: usize,
+  /// The count of valid words that end with the letter.
+  pub end_count: usize,
+}
+
+/// Diagnostics for a 12-letter input, describing how the words of [`WORDS`] are narrowed down
+/// to the valid words, and how those valid words cover the board.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::report::BoardReport;
+/// let report = BoardReport::new("KFTLJIANGCHP");
+///
+/// assert_eq!(report.valid_word_count, solver::valid_words("KFTLJIANGCHP").len());
+/// assert!(report.rare_letters().has_ascii(b'J'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardReport {
+  /// The 12-letter input, given side by side.
+  pub input: String,
+  /// The count of words in [`WORDS`].
+  pub dictionary_word_count: usize,
+  /// The count of words that use a letter that is not on the board.
+  pub rejected_by_letters: usize,
+  /// The count of words whose letters are all on the board, but which place two consecutive
+  /// letters on the same side.
+  pub rejected_by_sides: usize,
+  /// The count of words that can be spelled on the board.
+  pub valid_word_count: usize,
+  /// How the valid words use each letter of the board, in the order of the input.
+  pub letters: [LetterReport; 12],
+  /// The count of consecutive letter pairs in the valid words that move from the side at
+  /// the first index to the side at the second index.
+  pub transitions: [[usize; SIDE_COUNT]; SIDE_COUNT],
+  /// The count of valid words below which a letter is reported as [rare](Self::rare_letters).
+  pub rare_word_count: usize,
+}
+
+impl BoardReport {
+  /// Builds the report for the `input` from every word in [`WORDS`].
+  ///
+  /// # Panics
+  ///
+  /// Panics if the `input` does not have exactly 12 characters.
+  #[must_use]
+  pub fn new(input: &str) -> Self {
+    let board = LetterSet::from_ascii_slice(input.as_bytes());
+    let valid_words = crate::valid_words(input);
+
+    let rejected_by_letters = WORDS
+      .iter()
+      .filter(|word| !word.letter_set().is_subset(board))
+      .count();
+
+    let mut letters = [LetterReport {
+      letter: 0,
+      side: 0,
+      word_count: 0,
+      start_count: 0,
+      end_count: 0,
+    }; 12];
+    for (index, (report, &letter)) in letters.iter_mut().zip(input.as_bytes()).enumerate() {
+      report.letter = letter;
+      report.side = index / 3;
+    }
+
+    let side_of = |letter: u8| position(input, letter) / 3;
+
+    let mut transitions = [[0; SIDE_COUNT]; SIDE_COUNT];
+    for word in &valid_words {
+      let word_letters = word.ascii_bytes().collect::<Vec<_>>();
+
+      for report in &mut letters {
+        if word.letter_set().has_ascii(report.letter) {
+          report.word_count += 1;
+        }
+      }
+
+      letters[position(input, word_letters[0])].start_count += 1;
+      letters[position(input, word_letters[word_letters.len() - 1])].end_count += 1;
+
+      for pair in word_letters.windows(2) {
+        transitions[side_of(pair[0])][side_of(pair[1])] += 1;
+      }
+    }
+
+    Self {
+      input: input.to_owned(),
+      dictionary_word_count: WORDS.len(),
+      rejected_by_letters,
+      rejected_by_sides: WORDS.len() - rejected_by_letters - valid_words.len(),
+      valid_word_count: valid_words.len(),
+      letters,
+      transitions,
+      rare_word_count: DEFAULT_RARE_WORD_COUNT,
+    }
+  }
+
+  /// Returns the report with letters reported as [rare](Self::rare_letters) if they are
+  /// contained in fewer than `rare_word_count` valid words.
+  #[must_use]
+  pub fn with_rare_word_count(self, rare_word_count: usize) -> Self {
+    Self {
+      rare_word_count,
+      ..self
+    }
+  }
+
+  /// Returns the letters of the board that no valid word starts with.
+  #[must_use]
+  pub fn unstartable_letters(&self) -> LetterSet {
+    self.letters_where(|report| report.start_count == 0)
+  }
+
+  /// Returns the letters of the board that no valid word ends with.
+  #[must_use]
+  pub fn unendable_letters(&self) -> LetterSet {
+    self.letters_where(|report| report.end_count == 0)
+  }
+
+  /// Returns the letters of the board that are contained in fewer than
+  /// [`rare_word_count`](Self::rare_word_count) valid words.
+  #[must_use]
+  pub fn rare_letters(&self) -> LetterSet {
+    self.letters_where(|report| report.word_count < self.rare_word_count)
+  }
+
+  /// Returns each pair of distinct sides, from one side to another, that no consecutive letters
+  /// in any valid word move between.
+  #[must_use]
+  pub fn missing_transitions(&self) -> Vec<(usize, usize)> {
+    (0..SIDE_COUNT)
+      .flat_map(|from| (0..SIDE_COUNT).map(move |to| (from, to)))
+      .filter(|&(from, to)| from != to && self.transitions[from][to] == 0)
+      .collect()
+  }
+
+  /// Returns the letters of the board whose [`LetterReport`] matches the `predicate`.
+  fn letters_where(&self, predicate: impl Fn(&LetterReport) -> bool) -> LetterSet {
+    self
+      .letters
+      .iter()
+      .filter(|report| predicate(report))
+      .map(|report| compress_letter(report.letter))
+      .collect()
+  }
+}
+
+/// Returns the index of the ASCII `letter` in the `input`.
+fn position(input: &str, letter: u8) -> usize {
+  input
+    .bytes()
+    .position(|board_letter| board_letter == letter)
+    .expect("Every letter of a valid word should be on the board.")
+}
+
+/// Displays the report as tables of the letters and side transitions, followed by a summary
+/// of the sparse parts of the board.
+impl Display for BoardReport {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let sides = self
+      .input
+      .as_bytes()
+      .chunks(3)
+      .map(|side| String::from_utf8_lossy(side).into_owned())
+      .collect::<Vec<_>>();
+
+    writeln!(f, "Board: {}", sides.join(" "))?;
+    writeln!(f, "Dictionary: {} words", self.dictionary_word_count)?;
+    writeln!(
+      f,
+      "  {} use a letter that is not on the board",
+      self.rejected_by_letters
+    )?;
+    writeln!(
+      f,
+      "  {} place consecutive letters on the same side",
+      self.rejected_by_sides
+    )?;
+    writeln!(f, "  {} are valid words", self.valid_word_count)?;
+
+    writeln!(f, "\nLetter  Side  Words  Starts  Ends")?;
+    for report in &self.letters {
+      writeln!(
+        f,
+        "{:<6}  {:>4}  {:>5}  {:>6}  {:>4}",
+        report.letter as char,
+        report.side + 1,
+        report.word_count,
+        report.start_count,
+        report.end_count,
+      )?;
+    }
+
+    writeln!(f, "\nTransitions  To 1  To 2  To 3  To 4")?;
+    for (from, counts) in self.transitions.iter().enumerate() {
+      write!(f, "From {}     ", from + 1)?;
+      for (to, count) in counts.iter().enumerate() {
+        if from == to {
+          write!(f, "  {:>4}", "-")?;
+        } else {
+          write!(f, "  {count:>4}")?;
+        }
+      }
+      writeln!(f)?;
+    }
+
+    writeln!(f)?;
+    writeln!(
+      f,
+      "Letters that no valid word starts with: {}",
+      self.unstartable_letters()
+    )?;
+    writeln!(
+      f,
+      "Letters that no valid word ends with: {}",
+      self.unendable_letters()
+    )?;
+    writeln!(
+      f,
+      "Letters in fewer than {} valid words: {}",
+      self.rare_word_count,
+      self.rare_letters()
+    )?;
+
+    let missing_transitions = self
+      .missing_transitions()
+      .into_iter()
+      .map(|(from, to)| format!("{} to {}", from + 1, to + 1))
+      .collect::<Vec<_>>();
+    write!(
+      f,
+      "Sides that no valid word moves between: {}",
+      if missing_transitions.is_empty() {
+        "none".to_owned()
+      } else {
+        missing_transitions.join(", ")
+      }
+    )
+  }
+}
@@ -0,0 +1,260 @@
+//! Records statistics about the work that the search does, so that strategies can be compared
+//! by more than their wall time.
+//!
+//! Statistics are only recorded when the `stats` feature is enabled. Otherwise, every recording
+//! function is empty and compiles to nothing, and [`take`] always returns empty statistics.
+//!
+//! Statistics are recorded separately by each thread. Each thread should [`take`] its own
+//! statistics once its searches are complete, and they can be combined with [`SearchStats::merge`].
+
+use letters::LetterSequence;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::time::Duration;
+
+#[cfg(feature = "stats")]
+use std::cell::RefCell;
+
+#[cfg(feature = "stats")]
+use std::time::Instant;
+
+/// Whether the `stats` feature is enabled, and statistics are being recorded.
+pub const ENABLED: bool = cfg!(feature = "stats");
+
+/// The count of depths that are tracked, one for each possible word count of a sequence.
+pub const DEPTH_COUNT: usize = crate::MAX_WORD_COUNT;
+
+#[cfg(feature = "stats")]
+thread_local! {
+  /// The statistics that have been recorded on this thread since they were last taken.
+  static STATS: RefCell<SearchStats> = RefCell::default();
+}
+
+/// The reasons that a branch of the search may end without a solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneReason {
+  /// The sequence has 11 letters, and no word is short enough to complete it.
+  DeadEnd,
+  /// The candidate words cannot cover every letter that is missing from the sequence.
+  Coverage,
+  /// No candidate word can be appended to the sequence.
+  NoAppendableWords,
+  /// The sequence can no longer lead to a solution that the [`SearchFilter`](crate::SearchFilter)
+  /// of the search admits.
+  Filter,
+}
+
+/// Statistics about the nodes that were expanded at a single depth of the search.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DepthStats {
+  /// The count of nodes that were expanded at this depth.
+  pub nodes_expanded: u64,
+  /// The total count of candidate words across every node at this depth.
+  pub candidates: u64,
+  /// The largest count of candidate words at any node at this depth.
+  pub max_candidates: u64,
+  /// The total count of immediately appendable words across every node at this depth.
+  pub appendable: u64,
+}
+
+impl DepthStats {
+  /// Returns the mean count of candidate words per node at this depth.
+  #[must_use]
+  #[expect(clippy::cast_precision_loss)]
+  pub fn mean_candidates(&self) -> f64 {
+    self.candidates as f64 / self.nodes_expanded.max(1) as f64
+  }
+
+  /// Returns the mean count of immediately appendable words per node at this depth.
+  #[must_use]
+  #[expect(clippy::cast_precision_loss)]
+  pub fn mean_appendable(&self) -> f64 {
+    self.appendable as f64 / self.nodes_expanded.max(1) as f64
+  }
+}
+
+/// Statistics about the work done by one or more searches.
+///
+/// A node is expanded when the candidate words for its sequence are gathered. The depth of
+/// a node is the count of words in its sequence, so starting words are expanded at depth 1.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchStats {
+  /// The count of nodes that were expanded.
+  pub nodes_expanded: u64,
+  /// The count of solutions that were found.
+  pub solutions: u64,
+  /// The count of branches that ended at an 11-letter sequence.
+  pub pruned_dead_end: u64,
+  /// The count of branches that ended because the candidates could not cover the missing letters.
+  pub pruned_coverage: u64,
+  /// The count of branches that ended because no candidate could be appended.
+  pub pruned_no_appendable_words: u64,
+  /// The count of branches that ended because they could not lead to a solution that the filter admits.
+  pub pruned_filter: u64,
+  /// The statistics for the nodes at each depth, where index `0` is depth 1.
+  pub depths: [DepthStats; DEPTH_COUNT],
+  /// The time spent searching beneath each starting word.
+  pub starting_word_times: BTreeMap<String, Duration>,
+}
+
+impl SearchStats {
+  /// Returns the count of branches that ended for the given `reason`.
+  #[must_use]
+  pub fn pruned(&self, reason: PruneReason) -> u64 {
+    match reason {
+      PruneReason::DeadEnd => self.pruned_dead_end,
+      PruneReason::Coverage => self.pruned_coverage,
+      PruneReason::NoAppendableWords => self.pruned_no_appendable_words,
+      PruneReason::Filter => self.pruned_filter,
+    }
+  }
+
+  /// Returns the total time spent searching beneath every starting word.
+  #[must_use]
+  pub fn total_time(&self) -> Duration {
+    self.starting_word_times.values().sum()
+  }
+
+  /// Adds the `other` statistics into these statistics.
+  pub fn merge(&mut self, other: &SearchStats) {
+    self.nodes_expanded += other.nodes_expanded;
+    self.solutions += other.solutions;
+    self.pruned_dead_end += other.pruned_dead_end;
+    self.pruned_coverage += other.pruned_coverage;
+    self.pruned_no_appendable_words += other.pruned_no_appendable_words;
+    self.pruned_filter += other.pruned_filter;
+
+    for (depth, other) in self.depths.iter_mut().zip(&other.depths) {
+      depth.nodes_expanded += other.nodes_expanded;
+      depth.candidates += other.candidates;
+      depth.max_candidates = depth.max_candidates.max(other.max_candidates);
+      depth.appendable += other.appendable;
+    }
+
+    for (word, &time) in &other.starting_word_times {
+      *self.starting_word_times.entry(word.clone()).or_default() += time;
+    }
+  }
+}
+
+/// Prints a table of the statistics, followed by the slowest starting words.
+impl Display for SearchStats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// The count of the slowest starting words to print.
+    const SLOWEST_COUNT: usize = 10;
+
+    writeln!(f, "nodes expanded:              {}", self.nodes_expanded)?;
+    writeln!(f, "solutions:                   {}", self.solutions)?;
+    writeln!(f, "pruned (dead end):           {}", self.pruned_dead_end)?;
+    writeln!(f, "pruned (coverage):           {}", self.pruned_coverage)?;
+    writeln!(
+      f,
+      "pruned (no appendable words): {}",
+      self.pruned_no_appendable_words
+    )?;
+    writeln!(f, "pruned (filter):             {}", self.pruned_filter)?;
+    writeln!(f, "search time:                 {:?}", self.total_time())?;
+    writeln!(f)?;
+    writeln!(
+      f,
+      "{:>5} {:>12} {:>16} {:>15} {:>16}",
+      "depth", "nodes", "mean candidates", "max candidates", "mean appendable"
+    )?;
+    for (index, depth) in self.depths.iter().enumerate() {
+      writeln!(
+        f,
+        "{:>5} {:>12} {:>16.1} {:>15} {:>16.1}",
+        index + 1,
+        depth.nodes_expanded,
+        depth.mean_candidates(),
+        depth.max_candidates,
+        depth.mean_appendable(),
+      )?;
+    }
+
+    let mut slowest = self.starting_word_times.iter().collect::<Vec<_>>();
+    slowest.sort_by(|(_, lhs), (_, rhs)| rhs.cmp(lhs));
+    if !slowest.is_empty() {
+      writeln!(f)?;
+      writeln!(f, "slowest starting words:")?;
+      for (word, time) in slowest.into_iter().take(SLOWEST_COUNT) {
+        writeln!(f, "  {word:<12} {time:?}")?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Records the expansion of a node for the `sequence`, which has `candidate_count` candidate words,
+/// of which `appendable_count` can be appended immediately.
+#[inline]
+#[cfg_attr(not(feature = "stats"), expect(unused_variables))]
+pub fn record_node(sequence: LetterSequence, candidate_count: usize, appendable_count: usize) {
+  #[cfg(feature = "stats")]
+  STATS.with_borrow_mut(|stats| {
+    stats.nodes_expanded += 1;
+
+    let depth = &mut stats.depths[sequence.word_count() as usize - 1];
+    depth.nodes_expanded += 1;
+    depth.candidates += candidate_count as u64;
+    depth.max_candidates = depth.max_candidates.max(candidate_count as u64);
+    depth.appendable += appendable_count as u64;
+  });
+}
+
+/// Records a branch of the search that ended without a solution for the given `reason`.
+#[inline]
+#[cfg_attr(not(feature = "stats"), expect(unused_variables))]
+pub fn record_prune(reason: PruneReason) {
+  #[cfg(feature = "stats")]
+  STATS.with_borrow_mut(|stats| match reason {
+    PruneReason::DeadEnd => stats.pruned_dead_end += 1,
+    PruneReason::Coverage => stats.pruned_coverage += 1,
+    PruneReason::NoAppendableWords => stats.pruned_no_appendable_words += 1,
+    PruneReason::Filter => stats.pruned_filter += 1,
+  });
+}
+
+/// Records a solution that was found.
+#[inline]
+pub fn record_solution() {
+  #[cfg(feature = "stats")]
+  STATS.with_borrow_mut(|stats| stats.solutions += 1);
+}
+
+/// Calls `search`, recording the time it takes against the first word of the `sequence`.
+#[inline]
+#[cfg_attr(not(feature = "stats"), expect(unused_variables))]
+pub fn time_starting_word<T>(sequence: LetterSequence, search: impl FnOnce() -> T) -> T {
+  #[cfg(feature = "stats")]
+  {
+    let start = Instant::now();
+    let result = search();
+    let elapsed = start.elapsed();
+
+    if let Some(word) = sequence.words().next() {
+      STATS.with_borrow_mut(|stats| {
+        *stats
+          .starting_word_times
+          .entry(word.to_string())
+          .or_default() += elapsed;
+      });
+    }
+
+    result
+  }
+
+  #[cfg(not(feature = "stats"))]
+  search()
+}
+
+/// Takes the statistics that have been recorded on this thread, leaving empty statistics in their place.
+#[must_use]
+pub fn take() -> SearchStats {
+  #[cfg(feature = "stats")]
+  return STATS.take();
+
+  #[cfg(not(feature = "stats"))]
+  SearchStats::default()
+}
@@ -0,0 +1,242 @@
+//! Defines boards with `?` wildcards in place of unknown letters, such as a board read from a
+//! partial screenshot, and enumerates every way to complete them.
+//!
+//! The words from [`WORDS`] are narrowed down only once for a [`PartialBoard`], to the
+//! [`candidate_words`](PartialBoard::candidate_words) that could be valid on any completion.
+//! Each completion then filters only those candidates with [`valid_words_among`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # use solver::PartialBoard;
+//! let board = "AIO DGT ESU NP?".parse::<PartialBoard>().unwrap();
+//! let candidate_words = board.candidate_words();
+//!
+//! for completion in board.completions() {
+//!   let valid_words = solver::valid_words_among(&candidate_words, &completion);
+//!   assert_eq!(valid_words, solver::valid_words(&completion));
+//! }
+//! ```
+
+use crate::board::BoardError;
+#[cfg(doc)]
+use crate::valid_words_among;
+use letters::{compress_letter, decompress_letter, LetterSequence, LetterSet};
+use std::fmt::Display;
+use std::str::FromStr;
+use word_list::WORDS;
+
+/// The character that stands in for an unknown letter on a [`PartialBoard`].
+pub const WILDCARD: char = '?';
+
+/// The largest count of wildcards on a [`PartialBoard`].
+///
+/// Each wildcard multiplies the count of completions by up to 23, so three wildcards already
+/// describe several thousand boards.
+pub const MAX_WILDCARD_COUNT: usize = 3;
+
+/// The input of a puzzle in which some of the 12 letters may be unknown, given side by side
+/// with a `?` for each unknown letter, e.g. `AI?DGTESUNP?`.
+///
+/// Whitespace is ignored when parsing, so the sides may also be separated, e.g. `AI? DGT ESU NP?`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PartialBoard {
+  /// The compressed letter in each position, or [`None`] for a wildcard.
+  letters: [Option<u8>; 12],
+}
+
+/// Parses a [`PartialBoard`] from 12 uppercase ASCII letters or wildcards, ignoring whitespace.
+///
+/// # Example
+///
+/// ```rust
+/// # use solver::{BoardError, PartialBoard};
+/// assert!("AI? DGT ESU NP?".parse::<PartialBoard>().is_ok());
+/// assert!("AIODGTESUNPR".parse::<PartialBoard>().is_ok());
+/// assert_eq!("AI? DGT".parse::<PartialBoard>(), Err(BoardError::WrongLength(6)));
+/// assert_eq!(
+///   "??? ??? ESU NPR".parse::<PartialBoard>(),
+///   Err(BoardError::TooManyWildcards(6)),
+/// );
+/// ```
+impl FromStr for PartialBoard {
+  type Err = BoardError;
+
+  fn from_str(input: &str) -> Result<Self, Self::Err> {
+    let input = input
+      .chars()
+      .filter(|letter| !letter.is_whitespace())
+      .collect::<Vec<_>>();
+
+    if input.len() != 12 {
+      return Err(BoardError::WrongLength(input.len()));
+    }
+
+    let mut letters = [None; 12];
+    let mut letter_set = LetterSet::empty();
+
+    for (slot, &letter) in letters.iter_mut().zip(&input) {
+      if letter == WILDCARD {
+        continue;
+      }
+
+      if !letter.is_ascii_uppercase() {
+        return Err(BoardError::InvalidLetter(letter));
+      }
+
+      let compressed = compress_letter(letter as u8);
+      if letter_set.has(compressed) {
+        return Err(BoardError::RepeatedLetter(letter));
+      }
+
+      letter_set = letter_set.insert(compressed);
+      *slot = Some(compressed);
+    }
+
+    let wildcard_count = 12 - letter_set.len();
+    if wildcard_count > MAX_WILDCARD_COUNT {
+      return Err(BoardError::TooManyWildcards(wildcard_count));
+    }
+
+    Ok(Self { letters })
+  }
+}
+
+/// Displays the sides of the board separated by spaces, e.g. `AI? DGT ESU NP?`.
+impl Display for PartialBoard {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for (index, letter) in self.letters.iter().enumerate() {
+      if index > 0 && index % 3 == 0 {
+        write!(f, " ")?;
+      }
+
+      match letter {
+        Some(letter) => write!(f, "{}", decompress_letter(*letter) as char)?,
+        None => write!(f, "{WILDCARD}")?,
+      }
+    }
+
+    Ok(())
+  }
+}
+
+impl PartialBoard {
+  /// Returns the count of wildcards on the board.
+  #[must_use]
+  pub fn wildcard_count(self) -> usize {
+    self
+      .letters
+      .iter()
+      .filter(|letter| letter.is_none())
+      .count()
+  }
+
+  /// Returns the set of letters that are known on the board.
+  #[must_use]
+  pub fn known_letters(self) -> LetterSet {
+    self.letters.iter().flatten().copied().collect()
+  }
+
+  /// Returns every word from [`WORDS`] that could be spelled on at least one completion of
+  /// the board, in their original order.
+  ///
+  /// A candidate word has at most one letter that is not known for each wildcard, and no two
+  /// consecutive letters that are known to be on the same side.
+  #[must_use]
+  pub fn candidate_words(self) -> Vec<LetterSequence> {
+    let known_letters = self.known_letters();
+    let wildcard_count = self.wildcard_count();
+
+    WORDS
+      .iter()
+      .copied()
+      .filter(|word| word.letter_set().difference(known_letters).len() <= wildcard_count)
+      .filter(|word| {
+        word
+          .letters_rev()
+          .zip(word.letters_rev().skip(1))
+          .all(|(lhs, rhs)| {
+            !matches!((self.side_of(lhs), self.side_of(rhs)), (Some(lhs), Some(rhs)) if lhs == rhs)
+          })
+      })
+      .collect()
+  }
+
+  /// Returns every 12-letter input that completes the board, by replacing each wildcard with
+  /// a letter that is not already on the board.
+  ///
+  /// Since the order of the letters within a side does not matter, wildcards that share a side
+  /// are only ever filled in alphabetical order, so that each distinct board appears once.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// # use solver::PartialBoard;
+  /// let board = "AIODGTESUNPR".parse::<PartialBoard>().unwrap();
+  /// assert_eq!(board.completions(), ["AIODGTESUNPR"]);
+  ///
+  /// let board = "AIO DGT ESU NP?".parse::<PartialBoard>().unwrap();
+  /// assert_eq!(board.completions().len(), 15);
+  /// assert_eq!(board.completions()[0], "AIODGTESUNPB");
+  ///
+  /// let board = "AIO DGT ESU N??".parse::<PartialBoard>().unwrap();
+  /// assert_eq!(board.completions().len(), 16 * 15 / 2);
+  /// ```
+  #[must_use]
+  pub fn completions(self) -> Vec<String> {
+    let mut completions = Vec::new();
+    self.complete_from(self.letters, 0, self.known_letters(), &mut completions);
+    completions
+  }
+
+  /// Fills each wildcard at or after the `index` in the `filled` letters with every letter that
+  /// is not in `used_letters`, recording each complete board in `completions`.
+  fn complete_from(
+    self,
+    mut filled: [Option<u8>; 12],
+    index: usize,
+    used_letters: LetterSet,
+    completions: &mut Vec<String>,
+  ) {
+    let Some(wildcard_index) = (index..12).find(|&index| self.letters[index].is_none()) else {
+      completions.push(
+        filled
+          .iter()
+          .flatten()
+          .map(|&letter| decompress_letter(letter) as char)
+          .collect(),
+      );
+      return;
+    };
+
+    // A wildcard that follows another on the same side only takes letters after the other's letter.
+    let side_start = wildcard_index - wildcard_index % 3;
+    let min_letter = (side_start..wildcard_index)
+      .filter(|&index| self.letters[index].is_none())
+      .filter_map(|index| filled[index])
+      .max();
+
+    for letter in used_letters.complement().letters() {
+      if min_letter.is_some_and(|min_letter| letter <= min_letter) {
+        continue;
+      }
+
+      filled[wildcard_index] = Some(letter);
+      self.complete_from(
+        filled,
+        wildcard_index + 1,
+        used_letters.insert(letter),
+        completions,
+      );
+    }
+  }
+
+  /// Returns the index of the side that holds the compressed `letter`, if it is known.
+  fn side_of(self, letter: u8) -> Option<usize> {
+    self
+      .letters
+      .iter()
+      .position(|&known| known == Some(letter))
+      .map(|index| index / 3)
+  }
+}
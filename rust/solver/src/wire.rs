@@ -0,0 +1,147 @@
+//! Defines a compact, versioned wire format for exchanging lists of single-word
+//! [`LetterSequence`] values, e.g. between web workers.
+//!
+//! The format is little-endian throughout:
+//!
+//! ```text
+//! ┌─────────────┬──────────────┬──────────────┬──────────────┬─────┐
+//! │ version u32 │ count u32    │ word 0 u64   │ word 1 u64   │ ... │
+//! └─────────────┴──────────────┴──────────────┴──────────────┴─────┘
+//! ```
+//!
+//! Only the packed letters of each word are sent, as returned by [`LetterSequence::to_bits`].
+//! The [`LetterSet`](letters::LetterSet) and [`Solution`](letters::Solution) of each word
+//! are rebuilt by [`LetterSequence::from_bits`] when the buffer is decoded.
+
+use letters::LetterSequence;
+use std::error::Error;
+use std::fmt::Display;
+
+/// The version of the wire format written by [`encode_words`].
+pub const VERSION: u32 = 1;
+
+/// The number of bytes before the first word in an encoded buffer.
+pub const HEADER_LEN: usize = 8;
+
+/// The number of bytes that each word occupies in an encoded buffer.
+pub const WORD_LEN: usize = 8;
+
+/// The reasons that a buffer cannot be decoded by [`decode_words`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireError {
+  /// The buffer is too short to hold the header.
+  MissingHeader(usize),
+  /// The buffer was written with a version of the format that is not supported.
+  UnsupportedVersion(u32),
+  /// The length of the buffer does not match the count of words in its header.
+  LengthMismatch { expected: usize, actual: usize },
+  /// The word at the given index does not hold a valid sequence of distinct letters.
+  InvalidWord { index: usize, bits: u64 },
+}
+
+impl Display for WireError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::MissingHeader(len) => write!(
+        f,
+        "Expected at least {HEADER_LEN} bytes for the header, but the buffer has {len}."
+      ),
+      Self::UnsupportedVersion(version) => write!(
+        f,
+        "Expected version {VERSION} of the wire format, but the buffer has version {version}."
+      ),
+      Self::LengthMismatch { expected, actual } => write!(
+        f,
+        "Expected the buffer to have {expected} bytes, but it has {actual}."
+      ),
+      Self::InvalidWord { index, bits } => {
+        write!(f, "The word at index {index} has invalid bits {bits:#x}.")
+      }
+    }
+  }
+}
+
+impl Error for WireError {}
+
+/// Encodes the `words` into a buffer of the current [`VERSION`] of the wire format.
+///
+/// # Panics
+///
+/// Panics if there are more than [`u32::MAX`] words.
+///
+/// # Example
+///
+/// ```rust
+/// # use letters::LetterSequence;
+/// let words = [LetterSequence::new("FISH"), LetterSequence::new("HOPE")];
+/// let buffer = solver::wire::encode_words(&words);
+///
+/// assert_eq!(buffer.len(), solver::wire::HEADER_LEN + 2 * solver::wire::WORD_LEN);
+/// assert_eq!(solver::wire::decode_words(&buffer), Ok(words.to_vec()));
+/// ```
+#[must_use]
+pub fn encode_words(words: &[LetterSequence]) -> Vec<u8> {
+  let count = u32::try_from(words.len()).expect("The word count should fit in a u32.");
+
+  let mut buffer = Vec::with_capacity(HEADER_LEN + words.len() * WORD_LEN);
+  buffer.extend_from_slice(&VERSION.to_le_bytes());
+  buffer.extend_from_slice(&count.to_le_bytes());
+  for word in words {
+    buffer.extend_from_slice(&word.to_bits().to_le_bytes());
+  }
+
+  buffer
+}
+
+/// Returns the count of words in an encoded `buffer`, as given by its header,
+/// without decoding the words themselves.
+///
+/// # Errors
+///
+/// Returns a [`WireError`] if the header is missing or has an unsupported version.
+pub fn word_count(buffer: &[u8]) -> Result<usize, WireError> {
+  let missing_header = WireError::MissingHeader(buffer.len());
+  let (version, rest) = buffer.split_first_chunk().ok_or(missing_header)?;
+  let (count, _) = rest.split_first_chunk().ok_or(missing_header)?;
+
+  let version = u32::from_le_bytes(*version);
+  if version != VERSION {
+    return Err(WireError::UnsupportedVersion(version));
+  }
+
+  Ok(u32::from_le_bytes(*count) as usize)
+}
+
+/// Decodes the words from a `buffer` that was written by [`encode_words`].
+///
+/// # Errors
+///
+/// Returns a [`WireError`] if the header is missing or has an unsupported version,
+/// if the length of the buffer does not match its header, or if any word is invalid.
+pub fn decode_words(buffer: &[u8]) -> Result<Vec<LetterSequence>, WireError> {
+  let count = word_count(buffer)?;
+
+  let expected = count
+    .checked_mul(WORD_LEN)
+    .and_then(|len| len.checked_add(HEADER_LEN));
+  let length_mismatch = WireError::LengthMismatch {
+    expected: expected.unwrap_or(usize::MAX),
+    actual: buffer.len(),
+  };
+  if expected != Some(buffer.len()) {
+    return Err(length_mismatch);
+  }
+
+  let mut remaining = &buffer[HEADER_LEN..];
+  (0..count)
+    .map(|index| {
+      let (bytes, rest) = remaining
+        .split_first_chunk::<WORD_LEN>()
+        .ok_or(length_mismatch)?;
+      remaining = rest;
+
+      let bits = u64::from_le_bytes(*bytes);
+      LetterSequence::from_bits(bits).ok_or(WireError::InvalidWord { index, bits })
+    })
+    .collect()
+}
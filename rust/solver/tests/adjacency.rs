@@ -0,0 +1,118 @@
+use letters::{Adjacency, AdjacencyRule, LetterSequence, LetterSet};
+use word_list::WORDS;
+
+const INPUTS: [&str; 3] = ["AIODGTESUNPR", "AYDUNEIOLQTR", "KFTLJIANGCHP"];
+
+/// Returns every solution to a board with the given `valid_words`.
+fn solutions(valid_words: &[LetterSequence]) -> Vec<LetterSequence> {
+  let mut solutions = Vec::new();
+
+  solver::solve_all(valid_words, &mut solutions);
+
+  solutions
+}
+
+/// Returns [true] if every letter of the `word` is on the board of the `input`, and no two
+/// consecutive letters are on the same side.
+fn alternates_sides(input: &str, word: LetterSequence) -> bool {
+  let sides = word
+    .to_string()
+    .bytes()
+    .map(|letter| {
+      input
+        .bytes()
+        .position(|other| other == letter)
+        .map(|index| index / 3)
+    })
+    .collect::<Option<Vec<_>>>();
+
+  sides.is_some_and(|sides| sides.windows(2).all(|pair| pair[0] != pair[1]))
+}
+
+#[test]
+fn standard_alternates_sides() {
+  for input in INPUTS {
+    let expected = WORDS
+      .iter()
+      .copied()
+      .filter(|&word| alternates_sides(input, word))
+      .collect::<Vec<_>>();
+
+    assert_eq!(expected, solver::valid_words(input));
+    assert_eq!(
+      expected,
+      solver::valid_words_with(&Adjacency::standard(input))
+    );
+  }
+}
+
+#[test]
+fn easy_allows_every_word_on_the_board() {
+  for input in INPUTS {
+    let board = LetterSet::from_ascii_slice(input.as_bytes());
+    let expected = WORDS
+      .iter()
+      .copied()
+      .filter(|word| word.letter_set().is_subset(board))
+      .collect::<Vec<_>>();
+
+    let valid_words = solver::valid_words_with(&Adjacency::new(input, AdjacencyRule::Easy));
+    assert_eq!(expected, valid_words);
+  }
+
+  let input = "AYDUNEIOLQTR";
+  let standard = solutions(&solver::valid_words(input));
+  let easy = solutions(&solver::valid_words_with(&Adjacency::new(
+    input,
+    AdjacencyRule::Easy,
+  )));
+
+  assert!(
+    standard.iter().all(|solution| easy.contains(solution)),
+    "Every standard solution should also solve the board in easy mode."
+  );
+  assert!(
+    easy.len() > standard.len(),
+    "Easy mode should find more solutions than the standard rule."
+  );
+}
+
+#[test]
+fn forbidden_pairs_remove_words() {
+  let input = "AYDUNEIOLQTR";
+  let adjacency = Adjacency::standard(input)
+    .with_forbidden_pairs("QU EY")
+    .unwrap();
+  let valid_words = solver::valid_words_with(&adjacency);
+
+  assert!(valid_words.len() < solver::valid_words(input).len());
+  for word in &valid_words {
+    let word = word.to_string();
+    assert!(
+      ["QU", "UQ", "EY", "YE"]
+        .iter()
+        .all(|pair| !word.contains(pair)),
+      "{word} should not contain a forbidden pair."
+    );
+  }
+}
+
+#[test]
+fn par_follows_adjacency() {
+  let input = "AIODGTESUNPR";
+  let easy = Adjacency::new(input, AdjacencyRule::Easy);
+
+  assert_eq!(
+    solver::par::playable_words(input),
+    solver::par::playable_words_with(&Adjacency::standard(input))
+  );
+  assert!(
+    solver::par::playable_words_with(&easy).len() > solver::par::playable_words(input).len(),
+    "Easy mode should allow more playable words than the standard rule."
+  );
+
+  let expected = solver::par::par(input, 0).unwrap();
+  let actual = solver::par::par_with(input, &Adjacency::standard(input), 0).unwrap();
+  assert_eq!(expected.word_count, actual.word_count);
+  assert_eq!(expected.solution_count, actual.solution_count);
+}
@@ -0,0 +1,136 @@
+use letters::LetterSequence;
+use solver::control::{CancellationToken, SearchControl, StopReason};
+use solver::Solver;
+use std::time::Instant;
+
+#[test]
+fn unbounded_search() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let control = SearchControl::new();
+  let outcome = Solver::default().search::<usize>(valid_words, &control);
+
+  assert_eq!(
+    3050, outcome.solutions,
+    "An unbounded search should find every solution.",
+  );
+  assert!(
+    !outcome.is_truncated(),
+    "An unbounded search should not be truncated.",
+  );
+  assert_eq!(
+    valid_words.len(),
+    outcome.progress.starting_words_completed,
+    "An unbounded search should complete every starting word.",
+  );
+  assert_eq!(
+    3050, outcome.progress.solutions_found,
+    "The progress should count every solution.",
+  );
+  assert!(
+    outcome.progress.nodes_visited >= valid_words.len() as u64,
+    "The progress should count at least one node per starting word.",
+  );
+}
+
+#[test]
+fn solution_budget() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let expected =
+    Solver::default().search::<Vec<LetterSequence>>(valid_words, &SearchControl::new());
+
+  for budget in [0_usize, 1, 17, 3049] {
+    let control = SearchControl::new().with_solution_budget(budget as u64);
+    let outcome = Solver::default().search::<Vec<LetterSequence>>(valid_words, &control);
+
+    assert_eq!(
+      Some(StopReason::SolutionBudget),
+      outcome.stop_reason,
+      "A search with fewer solutions allowed than exist should stop at its budget.",
+    );
+    assert_eq!(
+      &expected.solutions[..budget],
+      &outcome.solutions[..],
+      "A sequential search with a solution budget of {budget} should find the first {budget} solutions.",
+    );
+  }
+}
+
+#[test]
+fn exact_solution_budget() {
+  let valid_words = &solver::valid_words("AYDUNEIOLQTR");
+
+  for (budget, truncated) in [(935, true), (936, false), (937, false)] {
+    let control = SearchControl::new().with_solution_budget(budget);
+    let outcome = Solver::default().search::<usize>(valid_words, &control);
+
+    assert_eq!(
+      truncated,
+      outcome.is_truncated(),
+      "A search with a solution budget of {budget} for 936 solutions should only be truncated if \
+       the budget is smaller.",
+    );
+    assert_eq!(
+      budget.min(936),
+      outcome.solutions as u64,
+      "A search with a solution budget of {budget} should find that many solutions, up to all 936.",
+    );
+  }
+}
+
+#[test]
+fn node_budget() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let control = SearchControl::new().with_node_budget(5000);
+  let outcome = Solver::default().search::<usize>(valid_words, &control);
+
+  assert_eq!(
+    Some(StopReason::NodeBudget),
+    outcome.stop_reason,
+    "A search with a small node budget should stop at its budget.",
+  );
+  assert!(
+    outcome.progress.nodes_visited <= 5000 + SearchControl::CHECK_INTERVAL,
+    "A search should overshoot its node budget by at most one check interval.",
+  );
+  assert!(
+    outcome.solutions < 3050,
+    "A search that stops at its node budget should return partial results.",
+  );
+}
+
+#[test]
+fn cancellation() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let token = CancellationToken::new();
+  let control = SearchControl::new().with_cancellation_token(token.clone());
+
+  token.cancel();
+  let outcome = Solver::default().search::<usize>(valid_words, &control);
+
+  assert_eq!(
+    Some(StopReason::Cancelled),
+    outcome.stop_reason,
+    "A cancelled search should report that it was cancelled.",
+  );
+  assert_eq!(
+    0, outcome.solutions,
+    "A search that is cancelled before it starts should find no solutions.",
+  );
+}
+
+#[test]
+fn deadline() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let control = SearchControl::new().with_deadline(Instant::now());
+  let outcome = Solver::default().search::<usize>(valid_words, &control);
+
+  assert_eq!(
+    Some(StopReason::Deadline),
+    outcome.stop_reason,
+    "A search that starts after its deadline should report the deadline.",
+  );
+  assert_eq!(
+    0, outcome.solutions,
+    "A search that starts after its deadline should find no solutions.",
+  );
+}
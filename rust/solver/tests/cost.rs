@@ -0,0 +1,69 @@
+use solver::cost::{cost_balanced_ranges, estimate_costs};
+
+#[test]
+fn estimate_costs_for_every_word() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let costs = estimate_costs(valid_words);
+
+  assert_eq!(
+    valid_words.len(),
+    costs.len(),
+    "There should be one cost estimate for each valid word.",
+  );
+
+  assert!(
+    costs.iter().all(|&cost| cost >= valid_words.len() as u64),
+    "Every starting word should cost at least one pass over the valid words.",
+  );
+}
+
+#[test]
+fn ranges_cover_every_index() {
+  let costs = estimate_costs(&solver::valid_words("ELGRUVOINFBA"));
+
+  for range_count in [1, 2, 3, 8, 16, costs.len() + 5] {
+    let ranges = cost_balanced_ranges(&costs, range_count);
+
+    assert_eq!(
+      range_count,
+      ranges.len(),
+      "There should be exactly {range_count} ranges.",
+    );
+
+    assert_eq!(0, ranges[0].start, "The first range should start at zero.");
+    assert_eq!(
+      costs.len(),
+      ranges[range_count - 1].end,
+      "The final range should end at the final index.",
+    );
+
+    for (lhs, rhs) in ranges.iter().zip(ranges.iter().skip(1)) {
+      assert_eq!(lhs.end, rhs.start, "The ranges should be contiguous.");
+    }
+  }
+}
+
+#[test]
+fn ranges_are_balanced() {
+  let costs = estimate_costs(&solver::valid_words("ELGRUVOINFBA"));
+  let total_cost = costs.iter().sum::<u64>();
+  let max_cost = costs.iter().copied().max().unwrap_or(0);
+  let range_count = 4;
+
+  for range in cost_balanced_ranges(&costs, range_count) {
+    let range_cost = costs[range].iter().sum::<u64>();
+    assert!(
+      range_cost <= total_cost / range_count as u64 + max_cost,
+      "No range should exceed its share of the cost by more than a single word.",
+    );
+  }
+}
+
+#[test]
+fn ranges_with_empty_costs() {
+  assert_eq!(
+    vec![0..0, 0..0, 0..0],
+    cost_balanced_ranges(&[], 3),
+    "Empty costs should produce only empty ranges.",
+  );
+}
@@ -0,0 +1,91 @@
+use letters::{LetterSequence, Solution};
+use solver::WordFamilies;
+use word_list::{WORDS, WORD_FAMILIES};
+
+#[test]
+fn families_match_words() {
+  assert_eq!(WORDS.len(), WORD_FAMILIES.len());
+
+  for (index, &family) in WORD_FAMILIES.iter().enumerate() {
+    assert!(
+      family as usize <= index,
+      "The family of {} should be the index of its first word.",
+      WORDS[index]
+    );
+    assert_eq!(
+      family, WORD_FAMILIES[family as usize],
+      "The first word of a family should be in its own family."
+    );
+  }
+}
+
+#[test]
+fn groups_inflections() {
+  let families = WordFamilies::new();
+  let head = |word: &str| families.head(LetterSequence::new(word));
+
+  assert_eq!(head("ABDUCT"), head("ABDUCTS"));
+  assert_eq!(head("ABDUCT"), head("ABDUCTING"));
+  assert_eq!(head("HOPE"), head("HOPING"));
+  assert_eq!(head("PONY"), head("PONIES"));
+  assert_eq!(head("BOX"), head("BOXES"));
+
+  assert_ne!(head("THE"), head("THING"));
+  assert_ne!(head("ABDUCT"), head("ABDUCTION"));
+}
+
+#[test]
+fn collapses_solutions() {
+  let families = WordFamilies::new();
+  let solutions = [
+    "FISH HOPE",
+    "WHIP PLANETS",
+    "FISH HOPED",
+    "HOPED DUNKS",
+    "WHIP PLANET",
+  ]
+  .map(|words| Solution::parse_words(words).unwrap().0);
+
+  let groups = families
+    .collapse(solutions)
+    .into_iter()
+    .map(|group| {
+      group
+        .into_iter()
+        .map(LetterSequence::solution_string)
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    vec![
+      vec!["FISH HOPE", "FISH HOPED"],
+      vec!["WHIP PLANET", "WHIP PLANETS"],
+      vec!["HOPED DUNKS"],
+    ],
+    groups
+  );
+  assert!(families.is_inflection_of(solutions[0], solutions[2]));
+  assert!(!families.is_inflection_of(solutions[0], solutions[3]));
+}
+
+#[test]
+fn collapses_every_solution() {
+  let valid_words = &solver::valid_words("AIODGTESUNPR");
+  let mut solutions = Vec::new();
+
+  solver::solve_all(valid_words, &mut solutions);
+
+  let families = WordFamilies::new();
+  let groups = families.collapse(solutions.iter().copied());
+
+  assert_eq!(solutions.len(), groups.iter().map(Vec::len).sum::<usize>());
+  for group in &groups {
+    assert!(
+      group
+        .iter()
+        .all(|&solution| families.is_inflection_of(group[0], solution)),
+      "Every solution of a group should be an inflection of its first."
+    );
+  }
+}
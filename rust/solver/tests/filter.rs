@@ -0,0 +1,160 @@
+use letters::{LetterSequence, LetterSet};
+use solver::{CandidateArena, SearchFilter, Solver};
+
+/// Returns every solution to the `input` that the `filter` admits, found by the `solver`
+/// with the filter applied during the search.
+fn filtered_solutions(input: &str, filter: &SearchFilter, solver: Solver) -> Vec<LetterSequence> {
+  let valid_words = &filter.valid_words(&solver::valid_words(input));
+  let solver = solver.with_filter(filter);
+  let mut solutions = Vec::new();
+
+  for &word in valid_words {
+    if filter.is_starting_word(word) {
+      solver.solve(word, &mut solutions, valid_words);
+    }
+  }
+
+  solutions
+}
+
+/// Returns every solution to the `input` that meets the `predicate`, found without a filter.
+fn post_filtered_solutions(
+  input: &str,
+  predicate: impl Fn(&LetterSequence) -> bool,
+) -> Vec<LetterSequence> {
+  let valid_words = &solver::valid_words(input);
+  let mut solutions = Vec::new();
+
+  solver::solve_all(valid_words, &mut solutions);
+
+  solutions.retain(predicate);
+  solutions
+}
+
+/// Returns the solution strings of the `solutions`, sorted.
+fn sorted(solutions: Vec<LetterSequence>) -> Vec<String> {
+  let mut solutions = solutions
+    .into_iter()
+    .map(LetterSequence::solution_string)
+    .collect::<Vec<_>>();
+  solutions.sort_unstable();
+  solutions
+}
+
+/// Asserts that the `filter` finds exactly the solutions that meet the `predicate`,
+/// both when partitioning and when filtering the candidate words.
+fn assert_filter(input: &str, filter: &SearchFilter, predicate: impl Fn(&LetterSequence) -> bool) {
+  let expected = sorted(post_filtered_solutions(input, predicate));
+  assert!(
+    !expected.is_empty(),
+    "The filter should admit some solutions."
+  );
+
+  for threshold in [0, usize::MAX] {
+    let solver = Solver::default().with_partition_threshold(threshold);
+    assert_eq!(
+      expected,
+      sorted(filtered_solutions(input, filter, solver)),
+      "The filter {filter:?} should find the same solutions with a partition threshold of {threshold}.",
+    );
+  }
+}
+
+/// Returns [true] if the `solution` contains the `word`.
+fn contains(solution: &LetterSequence, word: &str) -> bool {
+  solution.words().any(|solution_word| solution_word == word)
+}
+
+#[test]
+fn no_constraints() {
+  let filter = SearchFilter::new();
+
+  assert!(filter.is_empty());
+  assert_filter("AYDUNEIOLQTR", &filter, |_| true);
+}
+
+#[test]
+fn required_words() {
+  let filter = SearchFilter::new()
+    .with_required_word(LetterSequence::new("QUA"))
+    .with_required_word(LetterSequence::new("ELD"));
+
+  assert_filter("AYDUNEIOLQTR", &filter, |solution| {
+    contains(solution, "QUA") && contains(solution, "ELD")
+  });
+}
+
+#[test]
+fn first_word() {
+  let filter = SearchFilter::new().with_first_word(LetterSequence::new("QUA"));
+
+  assert_filter("AYDUNEIOLQTR", &filter, |solution| {
+    solution.words().next().is_some_and(|word| word == "QUA")
+  });
+}
+
+#[test]
+fn excluded_words_and_first_letters() {
+  let filter = SearchFilter::new()
+    .with_excluded_word(LetterSequence::new("QUA"))
+    .with_excluded_first_letters(LetterSet::from_ascii_slice(b"DT"));
+
+  assert_filter("AYDUNEIOLQTR", &filter, |solution| {
+    !contains(solution, "QUA") && !matches!(solution.ascii_bytes().next(), Some(b'D' | b'T'))
+  });
+}
+
+#[test]
+fn min_word_len_and_word_count() {
+  let filter = SearchFilter::new()
+    .with_min_word_len(6)
+    .with_word_count_range(2..=2);
+
+  assert_filter("AIODGTESUNPR", &filter, |solution| {
+    solution.word_count() == 2 && solution.words().all(|word| word.len() >= 6)
+  });
+}
+
+#[test]
+fn too_many_required_words() {
+  let mut filter = SearchFilter::new();
+  for word in ["QUA", "ELD", "DUN", "NOT", "TRY", "YON"] {
+    filter = filter.with_required_word(LetterSequence::new(word));
+  }
+
+  assert!(
+    filtered_solutions("AYDUNEIOLQTR", &filter, Solver::default()).is_empty(),
+    "No solution can contain more words than the most words in any solution.",
+  );
+}
+
+#[test]
+fn every_search() {
+  let input = "AYDUNEIOLQTR";
+  let filter = SearchFilter::new()
+    .with_required_word(LetterSequence::new("QUA"))
+    .with_word_count_range(4..=5);
+  let expected = sorted(filtered_solutions(input, &filter, Solver::default()));
+
+  let valid_words = &filter.valid_words(&solver::valid_words(input));
+  let solver = Solver::default().with_filter(&filter);
+  let arena = &mut CandidateArena::with_capacity(valid_words.len());
+  let mut in_arena = Vec::new();
+  let mut from_branches = Vec::new();
+
+  for &word in valid_words {
+    if !filter.is_starting_word(word) {
+      continue;
+    }
+
+    solver.solve_in(arena, word, &mut in_arena, valid_words);
+
+    let branches = solver.branches(word, valid_words);
+    for &sequence in branches.sequences() {
+      solver.solve(sequence, &mut from_branches, branches.valid_words());
+    }
+  }
+
+  assert_eq!(expected, sorted(in_arena));
+  assert_eq!(expected, sorted(from_branches));
+}
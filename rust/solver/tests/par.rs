@@ -0,0 +1,101 @@
+use letters::{compress_letter, LetterSet};
+use solver::par::{par, playable_words};
+
+/// Returns [true] if the `words` chain together and cover every letter of the `input`.
+fn is_solution(input: &str, words: &[&str]) -> bool {
+  let is_chained = words
+    .windows(2)
+    .all(|pair| pair[0].as_bytes().last() == pair[1].as_bytes().first());
+
+  let covered = words
+    .iter()
+    .flat_map(|word| word.bytes())
+    .map(compress_letter)
+    .collect::<LetterSet>();
+
+  is_chained && covered == LetterSet::from_ascii_slice(input.as_bytes())
+}
+
+#[test]
+fn playable_words_include_valid_words() {
+  let input = "AIODGTESUNPR";
+  let playable_words = playable_words(input);
+
+  for word in solver::valid_words(input) {
+    assert!(
+      playable_words.contains(&word.to_string().as_str()),
+      "Every unique-letter word should be playable, but {word} is not.",
+    );
+  }
+
+  assert!(
+    playable_words.contains(&"GONADOTROPINS"),
+    "Words with repeated letters should be playable.",
+  );
+  assert!(
+    !playable_words.contains(&"AIR"),
+    "Words with consecutive letters on the same side should not be playable.",
+  );
+}
+
+#[test]
+fn examples_are_solutions() {
+  let input = "AIODGTESUNPR";
+  let par = par(input, 100).unwrap();
+
+  assert_eq!(2, par.word_count);
+  assert_eq!(100, par.examples.len());
+  assert!(par.solution_count >= 100);
+
+  let playable_words = playable_words(input);
+  for example in &par.examples {
+    assert_eq!(par.word_count, example.len());
+    assert!(
+      is_solution(input, example),
+      "{example:?} should solve {input}.",
+    );
+    assert!(example.iter().all(|word| playable_words.contains(word)));
+  }
+}
+
+#[test]
+fn board_without_unique_letter_solutions() {
+  let par = par("KFTLJIANGCHP", 10).unwrap();
+
+  assert_eq!(2, par.word_count);
+  assert_eq!(1, par.solution_count);
+  assert_eq!(
+    vec![vec!["FLAPJACK", "KNIGHT"]],
+    par.examples,
+    "Repeating the A should solve a board without unique-letter solutions.",
+  );
+}
+
+#[test]
+fn one_word_par() {
+  let par = par("IOHCNPYATREL", 10).unwrap();
+
+  assert_eq!(1, par.word_count);
+  assert_eq!(vec![vec!["PYROTECHNICAL"]], par.examples);
+}
+
+#[test]
+fn every_example() {
+  let input = "UHOGWEJFILCN";
+  let par = par(input, usize::MAX).unwrap();
+
+  assert_eq!(3, par.word_count);
+  assert_eq!(
+    par.solution_count,
+    par.examples.len() as u64,
+    "Every solution in par should be returned when there is no limit.",
+  );
+  assert!(par
+    .examples
+    .iter()
+    .all(|example| is_solution(input, example)));
+  assert!(
+    par.examples.windows(2).all(|pair| pair[0] < pair[1]),
+    "The examples should be distinct and sorted.",
+  );
+}
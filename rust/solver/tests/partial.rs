@@ -0,0 +1,83 @@
+use letters::LetterSet;
+use solver::partial::{best_partial_chains, MAX_MISSING_LETTERS};
+
+#[test]
+fn board_without_solutions() {
+  let input = "KFTLJIANGCHP";
+  let valid_words = &solver::valid_words(input);
+
+  let chains = best_partial_chains(input, valid_words)
+    .into_iter()
+    .map(|chain| {
+      (
+        chain.sequence.solution_string(),
+        chain.missing_letters.to_string(),
+      )
+    })
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    vec![
+      ("FLACK KNIGHT".to_owned(), "[JP]".to_owned()),
+      ("PLACK KNIGHT".to_owned(), "[FJ]".to_owned()),
+    ],
+    chains,
+    "The closest chains for {input} should each miss two letters.",
+  );
+}
+
+#[test]
+fn chains_cannot_be_extended() {
+  for input in ["AIVROKMPTJEC", "CKPHFATDRSNL", "CBTRYLAHGSZM"] {
+    let valid_words = &solver::valid_words(input);
+    let board = LetterSet::from_ascii_slice(input.as_bytes());
+    let chains = best_partial_chains(input, valid_words);
+
+    assert!(
+      !chains.is_empty(),
+      "There should be partial chains for {input}."
+    );
+
+    let fewest_missing = chains[0].missing_letters.len();
+    for chain in &chains {
+      assert!(
+        chain
+          .sequence
+          .words()
+          .all(|word| valid_words.contains(&word)),
+        "Every word in {chain:?} should be a valid word for {input}.",
+      );
+      assert!(
+        !valid_words
+          .iter()
+          .any(|word| word.can_append_to(chain.sequence)),
+        "The chain {chain:?} should not be extendable on {input}.",
+      );
+      assert_eq!(
+        board,
+        chain.sequence.letter_set() | chain.missing_letters,
+        "The chain {chain:?} and its missing letters should cover {input}.",
+      );
+      assert!(
+        chain.missing_letters.len() <= MAX_MISSING_LETTERS.max(fewest_missing),
+        "The chain {chain:?} should miss no more letters than the closest chain for {input}, or {MAX_MISSING_LETTERS}.",
+      );
+    }
+  }
+}
+
+#[test]
+fn board_with_solutions() {
+  let input = "AYDUNEIOLQTR";
+  let valid_words = &solver::valid_words(input);
+  let chains = best_partial_chains(input, valid_words);
+
+  assert_eq!(
+    936,
+    chains
+      .iter()
+      .filter(|chain| chain.missing_letters.is_empty())
+      .count(),
+    "Every solution to {input} should be reported as a chain with no missing letters.",
+  );
+}
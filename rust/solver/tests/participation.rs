@@ -0,0 +1,134 @@
+use letters::LetterSequence;
+use solver::Participation;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// Returns every solution to the `input`, and the tally of their participation.
+fn solve(input: &str) -> (Vec<LetterSequence>, Participation) {
+  let valid_words = &solver::valid_words(input);
+  let mut solutions = Vec::new();
+  let mut participation = Participation::default();
+
+  solver::solve_all(valid_words, &mut solutions);
+  solver::solve_all(valid_words, &mut participation);
+
+  (solutions, participation)
+}
+
+#[test]
+fn matches_solutions() {
+  let (solutions, participation) = solve("AYDUNEIOLQTR");
+
+  assert_eq!(solutions.len() as u64, participation.solution_count);
+
+  let mut by_word = HashMap::<String, u64>::new();
+  let mut by_first_word = HashMap::<String, u64>::new();
+  let mut by_word_lengths = HashMap::<Vec<usize>, u64>::new();
+  for solution in &solutions {
+    for word in solution.words() {
+      *by_word.entry(word.to_string()).or_default() += 1;
+    }
+    *by_first_word
+      .entry(solution.words().next().unwrap().to_string())
+      .or_default() += 1;
+    *by_word_lengths
+      .entry(solution.words().map(LetterSequence::len).collect())
+      .or_default() += 1;
+  }
+
+  for (word, count) in participation.words() {
+    assert_eq!(
+      by_word[&word.to_string()],
+      count,
+      "The count of solutions that contain {word} should match the solutions.",
+    );
+  }
+  assert_eq!(by_word.len(), participation.words().len());
+
+  for (word, count) in participation.first_words() {
+    assert_eq!(
+      by_first_word[&word.to_string()],
+      count,
+      "The count of solutions that start with {word} should match the solutions.",
+    );
+  }
+  assert_eq!(by_first_word.len(), participation.first_words().len());
+
+  for (lengths, count) in participation.word_lengths() {
+    assert_eq!(
+      by_word_lengths[&lengths], count,
+      "The count of solutions with word lengths {lengths:?} should match the solutions.",
+    );
+  }
+  assert_eq!(by_word_lengths.len(), participation.word_lengths().len());
+}
+
+#[test]
+fn junction_letters() {
+  let (solutions, participation) = solve("AYDUNEIOLQTR");
+
+  let junction_count = solutions
+    .iter()
+    .map(|solution| u64::from(solution.word_count() - 1))
+    .sum::<u64>();
+
+  assert_eq!(
+    junction_count,
+    participation
+      .junction_letters()
+      .iter()
+      .map(|&(_, count)| count)
+      .sum::<u64>(),
+    "Every boundary between two words should be tallied once.",
+  );
+}
+
+#[test]
+fn ranked() {
+  let (_, participation) = solve("AYDUNEIOLQTR");
+
+  for table in [participation.words(), participation.first_words()] {
+    let keys = table
+      .iter()
+      .map(|(word, count)| (Reverse(*count), word.to_string()))
+      .collect::<Vec<_>>();
+
+    assert!(
+      keys.is_sorted(),
+      "Words should be ranked by count, then alphabetically.",
+    );
+  }
+}
+
+#[test]
+fn merge() {
+  let input = "AYDUNEIOLQTR";
+  let valid_words = &solver::valid_words(input);
+  let (first_half, second_half) = valid_words.split_at(valid_words.len() / 2);
+
+  let mut lhs = Participation::default();
+  for &word in first_half {
+    solver::solve(word, &mut lhs, valid_words);
+  }
+
+  let mut rhs = Participation::default();
+  for &word in second_half {
+    solver::solve(word, &mut rhs, valid_words);
+  }
+
+  lhs.merge(&rhs);
+
+  assert_eq!(
+    solve(input).1,
+    lhs,
+    "Merging the tallies of each half should match the tally of the whole."
+  );
+}
+
+#[test]
+fn no_solutions() {
+  let (_, participation) = solve("KFTLJIANGCHP");
+
+  assert_eq!(Participation::default(), participation);
+  assert!(participation.words().is_empty());
+}
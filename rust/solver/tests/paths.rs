@@ -0,0 +1,95 @@
+use letters::LetterSequence;
+use solver::SolutionPaths;
+use std::collections::HashSet;
+
+/// Returns every solution to the `input`.
+fn solutions(input: &str) -> Vec<LetterSequence> {
+  let valid_words = &solver::valid_words(input);
+  let mut solutions = Vec::new();
+
+  solver::solve_all(valid_words, &mut solutions);
+
+  solutions
+}
+
+#[test]
+fn groups_every_solution() {
+  let solutions = solutions("AYDUNEIOLQTR");
+  let paths = solutions.iter().copied().collect::<SolutionPaths>();
+
+  assert_eq!(solutions.len() as u64, paths.solution_count());
+  assert_eq!(paths.path_count(), paths.paths().len());
+  assert!(
+    paths.path_count() < solutions.len(),
+    "Some path should be split into words in more than one way."
+  );
+
+  let expected = solutions
+    .iter()
+    .map(|solution| solution.solution_string())
+    .collect::<HashSet<_>>();
+  let actual = paths
+    .paths()
+    .iter()
+    .flat_map(|path| path.solutions().map(LetterSequence::solution_string))
+    .collect::<HashSet<_>>();
+
+  assert_eq!(expected, actual);
+}
+
+#[test]
+fn splits_follow_the_same_path() {
+  let paths = solutions("AIODGTESUNPR")
+    .into_iter()
+    .collect::<SolutionPaths>();
+
+  for path in paths.paths() {
+    let letters = path.letters.to_string();
+    assert_eq!(
+      path.letters,
+      path.solutions().next().unwrap(),
+      "The letters of a path should be split by its first split."
+    );
+    assert!(
+      path.solutions().all(|solution| solution == letters),
+      "Every split of {letters} should have the same letters."
+    );
+    assert!(
+      path.splits.is_sorted_by_key(|split| split.word_count()),
+      "The splits of {letters} should be sorted by their count of words."
+    );
+  }
+
+  let split = paths
+    .paths()
+    .into_iter()
+    .find(|path| path.letters.solution_string() == "DATERS SOUPING")
+    .expect("DATERS SOUPING should be a solution.");
+  let split = split
+    .solutions()
+    .map(LetterSequence::solution_string)
+    .collect::<Vec<_>>();
+
+  assert_eq!(
+    vec![
+      "DATERS SOUPING",
+      "DATE ERS SOUPING",
+      "DATERS SOUP PING",
+      "DATE ERS SOUP PING",
+    ],
+    split
+  );
+}
+
+#[test]
+fn merges_paths() {
+  let solutions = solutions("AYDUNEIOLQTR");
+  let (first, second) = solutions.split_at(solutions.len() / 2);
+  let expected = solutions.iter().copied().collect::<SolutionPaths>();
+
+  let mut actual = first.iter().copied().collect::<SolutionPaths>();
+  actual.merge(second.iter().copied().collect());
+
+  assert_eq!(expected.solution_count(), actual.solution_count());
+  assert_eq!(expected.paths(), actual.paths());
+}
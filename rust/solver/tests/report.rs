@@ -0,0 +1,87 @@
+use letters::LetterSet;
+use solver::report::{BoardReport, DEFAULT_RARE_WORD_COUNT};
+use word_list::WORDS;
+
+#[test]
+fn rejected_words_add_up() {
+  for input in ["AIODGTESUNPR", "KFTLJIANGCHP", "CBTRYLAHGSZM"] {
+    let report = BoardReport::new(input);
+
+    assert_eq!(
+      WORDS.len(),
+      report.rejected_by_letters + report.rejected_by_sides + report.valid_word_count,
+      "Every word should be either rejected or valid for {input}.",
+    );
+    assert_eq!(
+      solver::valid_words(input).len(),
+      report.valid_word_count,
+      "The report should count the valid words for {input}.",
+    );
+  }
+}
+
+#[test]
+fn letter_counts() {
+  let input = "KFTLJIANGCHP";
+  let report = BoardReport::new(input);
+  let valid_words = solver::valid_words(input);
+
+  for (index, letter) in report.letters.iter().enumerate() {
+    assert_eq!(input.as_bytes()[index], letter.letter);
+    assert_eq!(index / 3, letter.side);
+    assert_eq!(
+      valid_words
+        .iter()
+        .filter(|word| word.letter_set().has_ascii(letter.letter))
+        .count(),
+      letter.word_count,
+      "The word count for {} should match the valid words.",
+      letter.letter as char,
+    );
+  }
+
+  assert_eq!(
+    report.valid_word_count,
+    report.letters.iter().map(|letter| letter.start_count).sum(),
+    "Every valid word should start with exactly one letter.",
+  );
+  assert_eq!(
+    report.valid_word_count,
+    report.letters.iter().map(|letter| letter.end_count).sum(),
+    "Every valid word should end with exactly one letter.",
+  );
+}
+
+#[test]
+fn rare_letters() {
+  let report = BoardReport::new("KFTLJIANGCHP");
+
+  assert_eq!(DEFAULT_RARE_WORD_COUNT, report.rare_word_count);
+  assert_eq!(LetterSet::from_ascii_slice(b"J"), report.rare_letters());
+  assert_eq!(
+    LetterSet::empty(),
+    report.clone().with_rare_word_count(0).rare_letters(),
+    "No letter should be in fewer than zero words.",
+  );
+  assert_eq!(
+    LetterSet::from_ascii_slice(b"KFTLJIANGCHP"),
+    report.with_rare_word_count(usize::MAX).rare_letters(),
+    "Every letter should be in fewer than usize::MAX words.",
+  );
+}
+
+#[test]
+fn transitions() {
+  let report = BoardReport::new("AIODGTESUNPR");
+
+  for side in 0..4 {
+    assert_eq!(
+      0, report.transitions[side][side],
+      "No valid word should stay on side {side}.",
+    );
+  }
+  assert!(
+    report.missing_transitions().is_empty(),
+    "Valid words should move between every pair of sides.",
+  );
+}
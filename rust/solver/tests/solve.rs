@@ -0,0 +1,190 @@
+use letters::LetterSequence;
+use solver::{CandidateArena, Solver};
+
+/// Counts the solutions for the given input using the shared solver.
+fn count_solutions(input: &str) -> usize {
+  let valid_words = &solver::valid_words(input);
+  let mut solution_count = 0;
+
+  solver::solve_all(valid_words, &mut solution_count);
+
+  solution_count
+}
+
+#[test]
+fn solution_counts() {
+  for (input, expected) in [
+    ("EIYTLXGMCZAO", 122),
+    ("AYDUNEIOLQTR", 936),
+    ("ELGRUVOINFBA", 3050),
+  ] {
+    assert_eq!(
+      expected,
+      count_solutions(input),
+      "The input {input} should have {expected} solutions.",
+    );
+  }
+}
+
+#[test]
+fn word_count_tally() {
+  let valid_words = &solver::valid_words("ELGRUVOINFBA");
+  let mut tally = solver::WordCountTally::default();
+
+  solver::solve_all(valid_words, &mut tally);
+
+  assert_eq!(
+    3050,
+    tally.total(),
+    "The tally should count every solution.",
+  );
+  assert_eq!(
+    [0, 0, 43, 1084, 1923],
+    tally.by_word_count,
+    "ELGRUVOINFBA should have only three-, four- and five-word solutions.",
+  );
+
+  let (first_half, second_half) = valid_words.split_at(valid_words.len() / 2);
+  let mut merged = solver::WordCountTally::default();
+  for half in [first_half, second_half] {
+    let mut half_tally = solver::WordCountTally::default();
+    for &word in half {
+      solver::solve(word, &mut half_tally, valid_words);
+    }
+    merged.merge(&half_tally);
+  }
+
+  assert_eq!(
+    tally, merged,
+    "Merging the tallies of each half of the starting words should match the whole tally.",
+  );
+}
+
+#[test]
+fn reference_solutions() {
+  for input in ["EIYTLXGMCZAO", "AYDUNEIOLQTR"] {
+    let valid_words = &solver::valid_words(input);
+    let mut solutions = Vec::new();
+
+    for &word in valid_words {
+      solver::solve(word, &mut solutions, valid_words);
+    }
+
+    let mut actual = solutions
+      .into_iter()
+      .map(LetterSequence::solution_string)
+      .collect::<Vec<_>>();
+    actual.sort_unstable();
+
+    let reference_words = valid_words
+      .iter()
+      .map(ToString::to_string)
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      letters::reference::solutions(&reference_words),
+      actual,
+      "The solutions to {input} should match the reference implementation.",
+    );
+  }
+}
+
+#[test]
+fn partition_thresholds() {
+  let input = "ELGRUVOINFBA";
+  let valid_words = &solver::valid_words(input);
+
+  for threshold in [0, 1, 64, Solver::DEFAULT_PARTITION_THRESHOLD, usize::MAX] {
+    let solver = Solver::default().with_partition_threshold(threshold);
+    let mut solution_count = 0;
+
+    solver.solve_all(valid_words, &mut solution_count);
+
+    assert_eq!(
+      3050, solution_count,
+      "A partition threshold of {threshold} should not change the solution count.",
+    );
+  }
+}
+
+#[test]
+fn solve_in_arena() {
+  let arena = &mut CandidateArena::default();
+
+  for input in ["EIYTLXGMCZAO", "AYDUNEIOLQTR", "ELGRUVOINFBA"] {
+    let valid_words = &solver::valid_words(input);
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+
+    for &word in valid_words {
+      solver::solve(word, &mut expected, valid_words);
+      Solver::default().solve_in(arena, word, &mut actual, valid_words);
+    }
+
+    expected.sort();
+    actual.sort();
+
+    assert_eq!(
+      expected, actual,
+      "Solving in an arena should find the same solutions for {input}.",
+    );
+  }
+}
+
+#[test]
+fn branches() {
+  for threshold in [0, usize::MAX] {
+    let solver = Solver::default().with_partition_threshold(threshold);
+    let valid_words = &solver::valid_words("ELGRUVOINFBA");
+    let mut expected = Vec::new();
+    let mut actual = Vec::new();
+
+    for &word in valid_words {
+      solver.solve(word, &mut expected, valid_words);
+
+      let branches = solver.branches(word, valid_words);
+      for &sequence in branches.sequences() {
+        solver.solve(sequence, &mut actual, branches.valid_words());
+      }
+    }
+
+    assert_eq!(
+      expected, actual,
+      "Solving each branch in order should find the same solutions in the same order.",
+    );
+  }
+}
+
+#[test]
+fn solutions_use_every_letter() {
+  let input = "AYDUNEIOLQTR";
+  let valid_words = &solver::valid_words(input);
+  let mut solutions = Vec::new();
+
+  solver::solve_all(valid_words, &mut solutions);
+
+  for solution in solutions {
+    assert!(
+      solution.has_all_letters(),
+      "Every solution should contain all 12 letters.",
+    );
+    assert!(
+      solution.words().all(|word| valid_words.contains(&word)),
+      "Every word in {solution:?} should be a valid word for {input}.",
+    );
+  }
+}
+
+#[test]
+fn valid_words() {
+  let valid_words = solver::valid_words("AYDUNEIOLQTR");
+
+  assert!(
+    valid_words.contains(&LetterSequence::new("DIAL")),
+    "DIAL alternates sides, so it should be a valid word.",
+  );
+  assert!(
+    !valid_words.contains(&LetterSequence::new("AYE")),
+    "AYE has adjacent letters from the same side, so it should not be a valid word.",
+  );
+}
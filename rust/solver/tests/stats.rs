@@ -0,0 +1,76 @@
+use solver::stats::{self, SearchStats};
+use solver::{CandidateArena, Solver};
+
+/// Solves the input with both the allocating and the arena search, returning the statistics
+/// recorded by each.
+fn record_stats(input: &str) -> (SearchStats, SearchStats) {
+  let valid_words = &solver::valid_words(input);
+  let arena = &mut CandidateArena::default();
+  let _ = stats::take();
+
+  Solver::default().solve_all(valid_words, &mut 0);
+  let allocating = stats::take();
+
+  for &word in valid_words {
+    Solver::default().solve_in(arena, word, &mut 0, valid_words);
+  }
+  let arena = stats::take();
+
+  (allocating, arena)
+}
+
+#[test]
+#[cfg(feature = "stats")]
+fn records_stats() {
+  let valid_words = solver::valid_words("ELGRUVOINFBA");
+  let (allocating, arena) = record_stats("ELGRUVOINFBA");
+
+  for stats in [&allocating, &arena] {
+    assert_eq!(3050, stats.solutions, "Every solution should be recorded.");
+    assert_eq!(
+      valid_words.len() as u64,
+      stats.depths[0].nodes_expanded,
+      "Every starting word should be expanded once at depth 1.",
+    );
+    assert_eq!(
+      stats.nodes_expanded,
+      stats.depths.iter().map(|depth| depth.nodes_expanded).sum(),
+      "The nodes at every depth should add up to the total.",
+    );
+    assert_eq!(
+      valid_words.len(),
+      stats.starting_word_times.len(),
+      "Every starting word should be timed.",
+    );
+  }
+
+  assert_eq!(
+    allocating.nodes_expanded, arena.nodes_expanded,
+    "The allocating and arena searches should expand the same nodes.",
+  );
+
+  let mut merged = allocating.clone();
+  merged.merge(&arena);
+  assert_eq!(
+    allocating.solutions + arena.solutions,
+    merged.solutions,
+    "Merging statistics should add their counts.",
+  );
+}
+
+#[test]
+#[cfg(not(feature = "stats"))]
+fn records_nothing() {
+  let (allocating, arena) = record_stats("ELGRUVOINFBA");
+
+  assert_eq!(
+    SearchStats::default(),
+    allocating,
+    "No statistics should be recorded without the stats feature.",
+  );
+  assert_eq!(
+    SearchStats::default(),
+    arena,
+    "No statistics should be recorded without the stats feature.",
+  );
+}
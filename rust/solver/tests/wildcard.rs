@@ -0,0 +1,109 @@
+use solver::{BoardError, PartialBoard};
+use std::collections::HashSet;
+
+#[test]
+fn parse() {
+  for (input, expected) in [
+    ("AI?DGTESUNP?", 2),
+    ("AI? DGT ESU NP?", 2),
+    ("AIODGTESUNPR", 0),
+    ("??? DGT ESU NPR", 3),
+  ] {
+    assert_eq!(
+      Ok(expected),
+      input
+        .parse::<PartialBoard>()
+        .map(PartialBoard::wildcard_count),
+      "{input:?} should parse with {expected} wildcards.",
+    );
+  }
+
+  for (input, expected) in [
+    ("AI? DGT ESU NP", BoardError::WrongLength(11)),
+    ("AI? DGT ESU Np?", BoardError::InvalidLetter('p')),
+    ("AI? DGT ESU NPA", BoardError::RepeatedLetter('A')),
+    ("??? ?GT ESU NPR", BoardError::TooManyWildcards(4)),
+  ] {
+    assert_eq!(
+      Err(expected),
+      input.parse::<PartialBoard>(),
+      "{input:?} should not parse as a partial board.",
+    );
+  }
+}
+
+#[test]
+fn display() {
+  let board = "AI?DGTESUNP?".parse::<PartialBoard>().unwrap();
+
+  assert_eq!(
+    "AI? DGT ESU NP?",
+    board.to_string(),
+    "A partial board should display its sides separated by spaces.",
+  );
+}
+
+#[test]
+fn completions() {
+  for (input, expected) in [
+    ("AIO DGT ESU NPR", 1),
+    ("AIO DGT ESU NP?", 15),
+    ("AI? DGT ESU NP?", 16 * 15),
+    ("AIO DGT ESU N??", 16 * 15 / 2),
+    ("AIO DGT ESU ???", 17 * 16 * 15 / 6),
+  ] {
+    let board = input.parse::<PartialBoard>().unwrap();
+    let completions = board.completions();
+
+    assert_eq!(
+      expected,
+      completions.len(),
+      "{input:?} should have {expected} completions.",
+    );
+
+    let distinct_boards = completions
+      .iter()
+      .map(|completion| {
+        let mut sides = completion
+          .as_bytes()
+          .chunks(3)
+          .map(|side| {
+            let mut side = side.to_vec();
+            side.sort_unstable();
+            side
+          })
+          .collect::<Vec<_>>();
+        sides.sort_unstable();
+        sides
+      })
+      .collect::<HashSet<_>>();
+
+    assert_eq!(
+      completions.len(),
+      distinct_boards.len(),
+      "Every completion of {input:?} should be a distinct board.",
+    );
+    assert!(
+      completions
+        .iter()
+        .all(|completion| solver::board::validate(completion).is_ok()),
+      "Every completion of {input:?} should be a valid board.",
+    );
+  }
+}
+
+#[test]
+fn candidate_words() {
+  for input in ["AI? DGT ESU NP?", "AIO DGT ESU N??", "A?? DGT ESU NP?"] {
+    let board = input.parse::<PartialBoard>().unwrap();
+    let candidate_words = board.candidate_words();
+
+    for completion in board.completions() {
+      assert_eq!(
+        solver::valid_words(&completion),
+        solver::valid_words_among(&candidate_words, &completion),
+        "The candidate words of {input:?} should include every valid word for {completion}.",
+      );
+    }
+  }
+}
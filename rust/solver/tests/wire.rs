@@ -0,0 +1,83 @@
+use letters::LetterSequence;
+use solver::wire::{decode_words, encode_words, word_count, WireError, HEADER_LEN, VERSION};
+
+/// Counts the solutions that can be formed from the given valid words.
+fn count_solutions(valid_words: &[LetterSequence]) -> usize {
+  let mut solution_count = 0;
+
+  solver::solve_all(valid_words, &mut solution_count);
+
+  solution_count
+}
+
+#[test]
+fn round_trip() {
+  let valid_words = solver::valid_words("ELGRUVOINFBA");
+  let buffer = encode_words(&valid_words);
+
+  assert_eq!(
+    Ok(valid_words.len()),
+    word_count(&buffer),
+    "The header should hold the count of encoded words.",
+  );
+
+  let decoded = decode_words(&buffer).expect("An encoded buffer should decode.");
+  assert_eq!(
+    valid_words, decoded,
+    "Decoding an encoded buffer should return the original words.",
+  );
+  assert!(
+    valid_words
+      .iter()
+      .zip(&decoded)
+      .all(|(word, decoded)| word.letter_set() == decoded.letter_set()
+        && word.word_count() == decoded.word_count()),
+    "Decoded words should have their letter sets and solutions rebuilt.",
+  );
+  assert_eq!(
+    count_solutions(&valid_words),
+    count_solutions(&decoded),
+    "Decoded words should have the same solutions as the original words.",
+  );
+}
+
+#[test]
+fn corrupted_buffers() {
+  let buffer = encode_words(&solver::valid_words("AYDUNEIOLQTR"));
+
+  assert_eq!(
+    Err(WireError::MissingHeader(3)),
+    decode_words(&buffer[..3]),
+    "A buffer shorter than the header should be rejected.",
+  );
+  assert_eq!(
+    Err(WireError::MissingHeader(HEADER_LEN - 1)),
+    decode_words(&buffer[..HEADER_LEN - 1]),
+    "A buffer with a version but a truncated count should be rejected.",
+  );
+
+  let mut wrong_version = buffer.clone();
+  wrong_version[..4].copy_from_slice(&(VERSION + 1).to_le_bytes());
+  assert_eq!(
+    Err(WireError::UnsupportedVersion(VERSION + 1)),
+    decode_words(&wrong_version),
+    "A buffer with an unknown version should be rejected.",
+  );
+
+  assert_eq!(
+    Err(WireError::LengthMismatch {
+      expected: buffer.len(),
+      actual: buffer.len() - 1,
+    }),
+    decode_words(&buffer[..buffer.len() - 1]),
+    "A truncated buffer should be rejected.",
+  );
+
+  let mut invalid_word = buffer.clone();
+  invalid_word[HEADER_LEN..HEADER_LEN + 8].copy_from_slice(&0_u64.to_le_bytes());
+  assert_eq!(
+    Err(WireError::InvalidWord { index: 0, bits: 0 }),
+    decode_words(&invalid_word),
+    "A buffer with an invalid word should be rejected.",
+  );
+}
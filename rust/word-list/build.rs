@@ -1,11 +1,14 @@
 //! This module processes the raw Wordnik word-list file and generates a Rust source file
-//! containing a static array of valid words as [`LetterSequence`] instances.
+//! containing a static array of valid words as [`LetterSequence`] instances, along with
+//! a static array of every playable word, including those with repeated letters, and a static
+//! array of the inflectional family of each valid word.
 //!
-//! This build script maintains a CRC hash over the word-list data, only regenerating the
-//! static array if the word list has changed from the previous build.
+//! This build script maintains a CRC hash over the word-list data, and another over the build
+//! script itself, only regenerating the static arrays if the word list or the rules that filter
+//! and group it have changed from the previous build.
 
 use crc32fast::Hasher;
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::fs::File;
 use std::io;
 use std::io::{BufRead, BufReader, Read, Write};
@@ -15,8 +18,24 @@ use std::path::Path;
 use letters::LetterSequence;
 
 static ALL_WORDS_CRC_PATH: &str = "data/all_words.crc";
+static BUILD_SCRIPT_CRC_PATH: &str = "data/build_script.crc";
+static BUILD_SCRIPT_PATH: &str = "build.rs";
 static ALL_WORDS_SOURCE_PATH: &str = "data/all_words.txt";
 static VALID_WORDS_OUTPUT_PATH: &str = "src/generated/words.rs";
+static PLAYABLE_WORDS_OUTPUT_PATH: &str = "src/generated/playable_words.rs";
+static WORD_FAMILIES_OUTPUT_PATH: &str = "src/generated/word_families.rs";
+
+/// The inflectional suffixes that are stripped from a word to find its stem, each with the
+/// endings that may replace it in order of preference, e.g. "IES" is replaced by "Y" to turn
+/// "PONIES" into "PONY", and "ING" is replaced by "E" before nothing to prefer "HOPE" over "HOP".
+static INFLECTION_RULES: &[(&str, &[&str])] = &[
+  ("ies", &["y"]),
+  ("ied", &["y"]),
+  ("s", &[""]),
+  ("es", &[""]),
+  ("ed", &["e", ""]),
+  ("ing", &["e", ""]),
+];
 
 /// Checks if a word has all unique letters.
 fn has_unique_letters(word: &str) -> bool {
@@ -24,6 +43,17 @@ fn has_unique_letters(word: &str) -> bool {
   word.chars().all(|c| unique_chars.insert(c))
 }
 
+/// Checks if a word has no letter that appears twice in a row, which would place two consecutive
+/// letters on the same side of any board, and which no adjacency rule allows.
+fn has_no_doubled_letters(word: &str) -> bool {
+  word.as_bytes().windows(2).all(|pair| pair[0] != pair[1])
+}
+
+/// Checks if a word has at most 12 distinct letters, so that it fits on a single board.
+fn fits_on_board(word: &str) -> bool {
+  word.chars().collect::<BTreeSet<_>>().len() <= 12
+}
+
 /// Calculates the CRC32 hash of the contents of the given file.
 fn calculate_file_hash<P: AsRef<Path>>(path: P) -> io::Result<u32> {
   let bytes = &mut Vec::new();
@@ -35,16 +65,16 @@ fn calculate_file_hash<P: AsRef<Path>>(path: P) -> io::Result<u32> {
   Ok(hasher.finalize())
 }
 
-/// Saves the given hash value to the predefined hash file.
-fn save_hash(hash: u32) -> io::Result<()> {
-  let mut file = File::create(ALL_WORDS_CRC_PATH)?;
+/// Saves the given hash value to the hash file at the given path.
+fn save_hash<P: AsRef<Path>>(path: P, hash: u32) -> io::Result<()> {
+  let mut file = File::create(path)?;
   write!(file, "{hash:08X}")?;
   Ok(())
 }
 
-/// Loads the stored CRC32 hash from the predefined hash file, if it exists.
-fn load_hash() -> io::Result<Option<u32>> {
-  let path = Path::new(ALL_WORDS_CRC_PATH);
+/// Loads the stored CRC32 hash from the hash file at the given path, if it exists.
+fn load_hash<P: AsRef<Path>>(path: P) -> io::Result<Option<u32>> {
+  let path = path.as_ref();
 
   if !path.exists() {
     return Ok(None);
@@ -60,36 +90,54 @@ fn load_hash() -> io::Result<Option<u32>> {
   Ok(Some(hash))
 }
 
+/// Reads every word from the source word file, skipping the comments at the top of the file.
+fn source_words() -> std::io::Result<Vec<String>> {
+  let file = File::open(ALL_WORDS_SOURCE_PATH)?;
+  let reader = BufReader::new(file);
+
+  Ok(
+    reader
+      .lines()
+      .skip_while(|line| line.is_err() || line.as_ref().is_ok_and(|line| line.starts_with("//")))
+      .flatten()
+      .collect(),
+  )
+}
+
 /// Reads the source word file and filters valid words based on the following criteria that would make
 /// them compatible to exist within a unique-letter solution to a Letter Boxed puzzle.
 ///
 /// - The length of the word is in range 3..11, or exactly 12.
 /// - All letters in the word are unique.
 fn valid_words() -> std::io::Result<Vec<String>> {
-  let file = File::open(ALL_WORDS_SOURCE_PATH)?;
-  let reader = BufReader::new(file);
+  let mut valid_words = source_words()?;
 
-  let mut valid_words = Vec::new();
+  valid_words.retain(|word| {
+    let len = word.len();
+    ((3..11).contains(&len) || len == 12) && has_unique_letters(word)
+  });
 
-  let lines = reader
-    .lines()
-    .skip_while(|line| line.is_err() || line.as_ref().is_ok_and(|line| line.starts_with("//")))
-    .flatten();
+  Ok(valid_words)
+}
 
-  for word in lines {
-    let len = word.len();
+/// Reads the source word file and filters the words that could be played on at least one board
+/// under the standard rules, in which letters may be repeated.
+///
+/// - The length of the word is at least 3.
+/// - No letter appears twice in a row.
+/// - The word has at most 12 distinct letters.
+fn playable_words() -> std::io::Result<Vec<String>> {
+  let mut playable_words = source_words()?;
 
-    if ((3..11).contains(&len) || len == 12) && has_unique_letters(&word) {
-      valid_words.push(word);
-    }
-  }
+  playable_words
+    .retain(|word| word.len() >= 3 && has_no_doubled_letters(word) && fits_on_board(word));
 
-  Ok(valid_words)
+  Ok(playable_words)
 }
 
 /// Processes the word list by generating a Rust source file containing
 /// a static array of [`LetterSequence`] representing the valid words.
-fn process_word_list() -> std::io::Result<()> {
+fn process_valid_words() -> std::io::Result<()> {
   let file = &mut File::create(VALID_WORDS_OUTPUT_PATH)?;
   let valid_words = valid_words()?;
 
@@ -112,16 +160,139 @@ fn process_word_list() -> std::io::Result<()> {
   writeln!(file, "];")
 }
 
+/// Returns the index of the stem that the `word` is an inflection of, if any, by stripping each
+/// suffix of the [`INFLECTION_RULES`] in order until the result is one of the `words`. A doubled
+/// final consonant is undone if no ending matches, as in "STOPPED" to "STOP".
+///
+/// The stripped word must keep at least 3 letters, so that "THING" is not an inflection of "THE",
+/// and a plural "S" never follows another "S", so that "GLASS" is not an inflection of "GLAS".
+fn stem(word: &str, words: &HashMap<&str, usize>) -> Option<usize> {
+  for (suffix, endings) in INFLECTION_RULES {
+    let Some(root) = word.strip_suffix(suffix) else {
+      continue;
+    };
+
+    if root.len() < 3 || (*suffix == "s" && root.ends_with('s')) {
+      continue;
+    }
+
+    let bytes = root.as_bytes();
+    let undoubled = (bytes[bytes.len() - 1] == bytes[bytes.len() - 2])
+      .then(|| root[..root.len() - 1].to_string());
+
+    let stem = endings
+      .iter()
+      .map(|ending| format!("{root}{ending}"))
+      .chain(undoubled)
+      .find_map(|stem| words.get(stem.as_str()).copied());
+
+    if stem.is_some() {
+      return stem;
+    }
+  }
+
+  None
+}
+
+/// Returns the representative of the family of the word at `index` in the union-find `parents`,
+/// compressing the path to it along the way.
+fn find_family(parents: &mut [usize], mut index: usize) -> usize {
+  while parents[index] != index {
+    parents[index] = parents[parents[index]];
+    index = parents[index];
+  }
+  index
+}
+
+/// Groups every source word with the stem that it is an inflection of, then returns the family
+/// of each valid word as the index of the first valid word in the same family.
+///
+/// Families are found among all source words, so that two valid words are grouped together
+/// even if their shared stem is not a valid word itself, as in "STEERS" and "STEERING".
+fn word_families(valid_words: &[String]) -> std::io::Result<Vec<u32>> {
+  let source_words = source_words()?;
+  let index_by_word = source_words
+    .iter()
+    .enumerate()
+    .map(|(index, word)| (word.as_str(), index))
+    .collect::<HashMap<_, _>>();
+
+  let mut parents = (0..source_words.len()).collect::<Vec<_>>();
+  for (index, word) in source_words.iter().enumerate() {
+    if let Some(stem_index) = stem(word, &index_by_word) {
+      let family = find_family(&mut parents, index);
+      let stem_family = find_family(&mut parents, stem_index);
+      parents[family] = stem_family;
+    }
+  }
+
+  let mut first_index_by_family = HashMap::new();
+  Ok(
+    valid_words
+      .iter()
+      .enumerate()
+      .map(|(valid_index, word)| {
+        let family = find_family(&mut parents, index_by_word[word.as_str()]);
+        let valid_index = u32::try_from(valid_index).expect("The word list should fit in a u32.");
+        *first_index_by_family.entry(family).or_insert(valid_index)
+      })
+      .collect(),
+  )
+}
+
+/// Processes the word list by generating a Rust source file containing a static array of
+/// the family of each valid word, at the same index as the word.
+fn process_word_families() -> std::io::Result<()> {
+  let file = &mut File::create(WORD_FAMILIES_OUTPUT_PATH)?;
+  let word_families = word_families(&valid_words()?)?;
+
+  writeln!(file, "#[rustfmt::skip]")?;
+  writeln!(file, "pub static WORD_FAMILIES: &[u32] = &[")?;
+
+  for family in word_families {
+    writeln!(file, "    {family},")?;
+  }
+
+  writeln!(file, "];")
+}
+
+/// Processes the word list by generating a Rust source file containing
+/// a static array of every playable word as an uppercase string.
+fn process_playable_words() -> std::io::Result<()> {
+  let file = &mut File::create(PLAYABLE_WORDS_OUTPUT_PATH)?;
+  let playable_words = playable_words()?;
+
+  writeln!(file, "#[rustfmt::skip]")?;
+  writeln!(file, "pub static PLAYABLE_WORDS: &[&str] = &[")?;
+
+  for word in playable_words {
+    writeln!(file, r#"    "{}","#, word.to_ascii_uppercase())?;
+  }
+
+  writeln!(file, "];")
+}
+
 fn main() -> std::io::Result<()> {
   let file_hash = calculate_file_hash(ALL_WORDS_SOURCE_PATH)?;
+  let script_hash = calculate_file_hash(BUILD_SCRIPT_PATH)?;
+  let outputs_exist = [
+    VALID_WORDS_OUTPUT_PATH,
+    PLAYABLE_WORDS_OUTPUT_PATH,
+    WORD_FAMILIES_OUTPUT_PATH,
+  ]
+  .iter()
+  .all(|path| Path::new(path).exists());
 
-  if let Some(stored_hash) = load_hash()? {
-    if file_hash == stored_hash {
-      // The file has not changed, nothing to do.
-      return Ok(());
-    }
+  let is_fresh = load_hash(ALL_WORDS_CRC_PATH)? == Some(file_hash)
+    && load_hash(BUILD_SCRIPT_CRC_PATH)? == Some(script_hash);
+  if is_fresh && outputs_exist {
+    // Neither the word list nor the rules have changed, nothing to do.
+    return Ok(());
   }
 
-  process_word_list()?;
-  save_hash(file_hash)
+  process_valid_words()?;
+  process_playable_words()?;
+  process_word_families()?;
+  save_hash(ALL_WORDS_CRC_PATH, file_hash)?;
+  save_hash(BUILD_SCRIPT_CRC_PATH, script_hash)
 }
@@ -0,0 +1,41505 @@
+#[rustfmt::skip]
+pub static WORD_FAMILIES: &[u32] = &[
+    0,
+    0,
+    2,
+    3,
+    4,
+    2,
+    2,
+    7,
+    7,
+    9,
+    9,
+    11,
+    11,
+    11,
+    7,
+    15,
+    16,
+    17,
+    18,
+    18,
+    20,
+    20,
+    22,
+    22,
+    24,
+    25,
+    26,
+    26,
+    25,
+    29,
+    30,
+    31,
+    31,
+    33,
+    34,
+    34,
+    34,
+    34,
+    38,
+    38,
+    40,
+    41,
+    42,
+    38,
+    44,
+    45,
+    46,
+    47,
+    48,
+    49,
+    49,
+    51,
+    52,
+    52,
+    54,
+    54,
+    56,
+    57,
+    57,
+    59,
+    60,
+    61,
+    61,
+    61,
+    64,
+    64,
+    66,
+    67,
+    67,
+    69,
+    70,
+    71,
+    72,
+    73,
+    73,
+    73,
+    76,
+    77,
+    73,
+    79,
+    60,
+    81,
+    82,
+    83,
+    83,
+    85,
+    86,
+    86,
+    88,
+    89,
+    89,
+    91,
+    92,
+    88,
+    94,
+    95,
+    95,
+    97,
+    98,
+    99,
+    100,
+    101,
+    102,
+    103,
+    104,
+    105,
+    106,
+    107,
+    108,
+    109,
+    110,
+    111,
+    111,
+    113,
+    114,
+    111,
+    116,
+    117,
+    118,
+    119,
+    120,
+    121,
+    122,
+    123,
+    123,
+    125,
+    123,
+    127,
+    128,
+    129,
+    130,
+    130,
+    129,
+    133,
+    133,
+    135,
+    136,
+    136,
+    136,
+    135,
+    140,
+    141,
+    142,
+    143,
+    144,
+    145,
+    146,
+    143,
+    148,
+    141,
+    150,
+    151,
+    151,
+    153,
+    154,
+    154,
+    156,
+    157,
+    157,
+    159,
+    160,
+    160,
+    160,
+    163,
+    164,
+    160,
+    166,
+    167,
+    167,
+    169,
+    170,
+    171,
+    172,
+    171,
+    174,
+    175,
+    176,
+    177,
+    178,
+    179,
+    180,
+    181,
+    181,
+    183,
+    184,
+    185,
+    185,
+    187,
+    187,
+    187,
+    190,
+    190,
+    192,
+    193,
+    193,
+    195,
+    195,
+    197,
+    197,
+    199,
+    199,
+    199,
+    202,
+    203,
+    203,
+    203,
+    206,
+    207,
+    207,
+    209,
+    209,
+    209,
+    212,
+    213,
+    214,
+    215,
+    216,
+    217,
+    217,
+    219,
+    219,
+    221,
+    221,
+    223,
+    223,
+    216,
+    226,
+    227,
+    227,
+    229,
+    227,
+    227,
+    229,
+    233,
+    233,
+    235,
+    235,
+    233,
+    238,
+    239,
+    238,
+    241,
+    242,
+    243,
+    244,
+    242,
+    227,
+    247,
+    248,
+    249,
+    250,
+    250,
+    252,
+    253,
+    254,
+    252,
+    256,
+    257,
+    257,
+    256,
+    260,
+    260,
+    262,
+    262,
+    264,
+    265,
+    265,
+    267,
+    268,
+    267,
+    270,
+    271,
+    272,
+    272,
+    274,
+    275,
+    276,
+    276,
+    278,
+    279,
+    280,
+    280,
+    282,
+    282,
+    284,
+    284,
+    286,
+    286,
+    288,
+    289,
+    290,
+    288,
+    292,
+    292,
+    292,
+    295,
+    296,
+    295,
+    298,
+    299,
+    300,
+    301,
+    301,
+    303,
+    303,
+    305,
+    305,
+    307,
+    307,
+    309,
+    310,
+    310,
+    312,
+    313,
+    314,
+    315,
+    315,
+    317,
+    318,
+    319,
+    319,
+    318,
+    322,
+    323,
+    318,
+    325,
+    325,
+    325,
+    328,
+    329,
+    329,
+    314,
+    332,
+    333,
+    334,
+    335,
+    336,
+    337,
+    338,
+    339,
+    340,
+    341,
+    340,
+    343,
+    344,
+    345,
+    346,
+    347,
+    346,
+    349,
+    350,
+    351,
+    351,
+    353,
+    353,
+    351,
+    356,
+    356,
+    358,
+    358,
+    360,
+    361,
+    361,
+    361,
+    364,
+    364,
+    366,
+    367,
+    368,
+    369,
+    370,
+    371,
+    372,
+    373,
+    373,
+    373,
+    376,
+    376,
+    378,
+    379,
+    380,
+    381,
+    380,
+    383,
+    384,
+    384,
+    386,
+    387,
+    388,
+    389,
+    389,
+    391,
+    392,
+    392,
+    394,
+    394,
+    388,
+    397,
+    397,
+    399,
+    400,
+    401,
+    402,
+    403,
+    404,
+    405,
+    406,
+    407,
+    407,
+    409,
+    410,
+    409,
+    412,
+    413,
+    414,
+    414,
+    413,
+    417,
+    418,
+    419,
+    420,
+    421,
+    422,
+    423,
+    424,
+    425,
+    425,
+    427,
+    428,
+    427,
+    430,
+    430,
+    418,
+    433,
+    434,
+    435,
+    436,
+    436,
+    438,
+    439,
+    439,
+    441,
+    441,
+    443,
+    444,
+    445,
+    445,
+    447,
+    448,
+    449,
+    449,
+    448,
+    452,
+    453,
+    453,
+    453,
+    456,
+    457,
+    458,
+    459,
+    459,
+    461,
+    462,
+    463,
+    463,
+    465,
+    466,
+    467,
+    467,
+    467,
+    458,
+    462,
+    472,
+    473,
+    472,
+    473,
+    476,
+    477,
+    478,
+    479,
+    480,
+    480,
+    482,
+    483,
+    484,
+    484,
+    484,
+    487,
+    488,
+    489,
+    490,
+    491,
+    492,
+    493,
+    494,
+    495,
+    496,
+    493,
+    498,
+    498,
+    500,
+    501,
+    502,
+    503,
+    504,
+    505,
+    505,
+    504,
+    508,
+    509,
+    503,
+    511,
+    512,
+    512,
+    514,
+    514,
+    516,
+    516,
+    518,
+    518,
+    520,
+    520,
+    516,
+    523,
+    523,
+    525,
+    525,
+    527,
+    523,
+    529,
+    530,
+    529,
+    532,
+    533,
+    534,
+    532,
+    536,
+    537,
+    537,
+    539,
+    539,
+    541,
+    542,
+    542,
+    544,
+    544,
+    546,
+    547,
+    547,
+    549,
+    532,
+    551,
+    552,
+    553,
+    554,
+    555,
+    555,
+    557,
+    557,
+    557,
+    555,
+    561,
+    562,
+    563,
+    564,
+    564,
+    566,
+    567,
+    568,
+    568,
+    567,
+    566,
+    572,
+    572,
+    574,
+    574,
+    576,
+    577,
+    577,
+    579,
+    580,
+    579,
+    582,
+    583,
+    584,
+    585,
+    586,
+    586,
+    588,
+    589,
+    589,
+    591,
+    591,
+    593,
+    593,
+    584,
+    596,
+    597,
+    598,
+    598,
+    600,
+    600,
+    602,
+    597,
+    604,
+    605,
+    606,
+    607,
+    607,
+    609,
+    609,
+    611,
+    611,
+    611,
+    614,
+    615,
+    614,
+    617,
+    618,
+    618,
+    620,
+    621,
+    621,
+    623,
+    623,
+    625,
+    626,
+    626,
+    628,
+    628,
+    630,
+    630,
+    630,
+    620,
+    634,
+    635,
+    634,
+    637,
+    637,
+    639,
+    639,
+    641,
+    642,
+    642,
+    644,
+    645,
+    646,
+    644,
+    648,
+    649,
+    649,
+    651,
+    652,
+    653,
+    654,
+    655,
+    655,
+    653,
+    658,
+    658,
+    660,
+    661,
+    661,
+    660,
+    664,
+    665,
+    664,
+    667,
+    667,
+    667,
+    670,
+    670,
+    672,
+    672,
+    670,
+    675,
+    676,
+    676,
+    678,
+    679,
+    679,
+    681,
+    681,
+    679,
+    684,
+    684,
+    686,
+    686,
+    688,
+    689,
+    690,
+    691,
+    692,
+    693,
+    694,
+    695,
+    696,
+    697,
+    697,
+    699,
+    699,
+    701,
+    701,
+    703,
+    696,
+    705,
+    705,
+    707,
+    707,
+    709,
+    710,
+    710,
+    709,
+    713,
+    714,
+    714,
+    716,
+    716,
+    718,
+    719,
+    719,
+    721,
+    721,
+    723,
+    724,
+    725,
+    726,
+    726,
+    728,
+    729,
+    729,
+    728,
+    732,
+    732,
+    734,
+    734,
+    734,
+    737,
+    738,
+    737,
+    740,
+    740,
+    740,
+    743,
+    744,
+    745,
+    745,
+    747,
+    748,
+    749,
+    750,
+    751,
+    752,
+    753,
+    754,
+    754,
+    756,
+    756,
+    752,
+    759,
+    760,
+    761,
+    762,
+    763,
+    764,
+    764,
+    766,
+    764,
+    768,
+    769,
+    769,
+    771,
+    771,
+    773,
+    763,
+    775,
+    776,
+    777,
+    778,
+    778,
+    777,
+    781,
+    776,
+    783,
+    784,
+    784,
+    786,
+    775,
+    788,
+    789,
+    790,
+    790,
+    789,
+    793,
+    794,
+    795,
+    795,
+    797,
+    797,
+    799,
+    799,
+    801,
+    801,
+    803,
+    803,
+    801,
+    801,
+    807,
+    808,
+    809,
+    810,
+    809,
+    812,
+    813,
+    813,
+    815,
+    815,
+    812,
+    818,
+    818,
+    820,
+    818,
+    822,
+    823,
+    824,
+    825,
+    825,
+    827,
+    828,
+    828,
+    830,
+    827,
+    832,
+    832,
+    834,
+    835,
+    836,
+    837,
+    837,
+    839,
+    840,
+    841,
+    841,
+    843,
+    844,
+    844,
+    846,
+    846,
+    848,
+    848,
+    840,
+    851,
+    852,
+    852,
+    854,
+    854,
+    856,
+    857,
+    857,
+    859,
+    859,
+    856,
+    862,
+    862,
+    836,
+    865,
+    865,
+    867,
+    868,
+    869,
+    870,
+    871,
+    872,
+    872,
+    874,
+    874,
+    876,
+    877,
+    877,
+    879,
+    879,
+    881,
+    882,
+    883,
+    884,
+    885,
+    886,
+    886,
+    888,
+    888,
+    888,
+    891,
+    892,
+    892,
+    892,
+    895,
+    895,
+    897,
+    898,
+    898,
+    900,
+    900,
+    902,
+    903,
+    904,
+    905,
+    898,
+    907,
+    908,
+    909,
+    910,
+    911,
+    911,
+    913,
+    914,
+    914,
+    916,
+    917,
+    917,
+    898,
+    920,
+    921,
+    921,
+    920,
+    924,
+    924,
+    926,
+    927,
+    927,
+    929,
+    929,
+    926,
+    932,
+    932,
+    934,
+    935,
+    932,
+    937,
+    938,
+    939,
+    939,
+    941,
+    942,
+    943,
+    943,
+    945,
+    945,
+    947,
+    948,
+    949,
+    941,
+    951,
+    952,
+    953,
+    954,
+    955,
+    956,
+    956,
+    958,
+    958,
+    960,
+    960,
+    956,
+    955,
+    964,
+    964,
+    966,
+    966,
+    968,
+    969,
+    970,
+    971,
+    972,
+    971,
+    970,
+    975,
+    976,
+    977,
+    978,
+    979,
+    980,
+    980,
+    975,
+    983,
+    984,
+    984,
+    986,
+    987,
+    987,
+    989,
+    990,
+    991,
+    992,
+    993,
+    994,
+    995,
+    993,
+    997,
+    998,
+    999,
+    998,
+    1001,
+    1002,
+    1003,
+    1003,
+    1005,
+    1005,
+    1007,
+    1008,
+    1007,
+    1003,
+    1011,
+    1011,
+    1013,
+    1014,
+    1014,
+    1016,
+    1017,
+    1018,
+    1019,
+    1020,
+    1021,
+    1022,
+    1023,
+    1023,
+    1025,
+    1026,
+    1026,
+    1028,
+    1029,
+    1030,
+    1031,
+    1032,
+    1031,
+    1034,
+    1034,
+    1036,
+    1029,
+    1038,
+    1039,
+    1040,
+    1041,
+    1041,
+    1043,
+    1043,
+    1043,
+    1046,
+    1046,
+    1048,
+    1049,
+    1050,
+    1050,
+    1052,
+    1053,
+    1053,
+    1055,
+    1056,
+    1057,
+    1058,
+    1059,
+    1059,
+    1058,
+    1062,
+    1062,
+    1064,
+    1064,
+    1066,
+    1067,
+    1068,
+    1068,
+    1070,
+    1070,
+    1072,
+    1072,
+    1074,
+    1075,
+    1076,
+    1077,
+    1078,
+    1079,
+    1080,
+    1081,
+    1082,
+    1083,
+    1084,
+    1085,
+    1085,
+    1084,
+    1088,
+    1084,
+    1090,
+    1091,
+    1092,
+    1091,
+    1094,
+    1095,
+    1095,
+    1094,
+    1098,
+    1099,
+    1100,
+    1101,
+    1100,
+    1103,
+    1104,
+    1105,
+    1106,
+    1107,
+    1108,
+    1108,
+    1110,
+    1110,
+    1110,
+    1113,
+    1114,
+    1107,
+    1116,
+    1117,
+    1118,
+    1119,
+    1120,
+    1121,
+    1122,
+    1122,
+    1124,
+    1125,
+    1125,
+    1127,
+    1127,
+    1129,
+    1129,
+    1131,
+    1132,
+    1132,
+    1129,
+    1135,
+    1136,
+    1103,
+    1138,
+    1139,
+    1140,
+    1141,
+    1142,
+    1143,
+    1144,
+    1145,
+    1145,
+    1147,
+    1148,
+    1148,
+    1150,
+    1150,
+    1083,
+    1153,
+    1154,
+    1155,
+    1156,
+    1157,
+    1158,
+    1158,
+    1158,
+    1161,
+    1161,
+    1163,
+    1164,
+    1165,
+    1166,
+    1167,
+    1168,
+    1169,
+    1170,
+    1171,
+    1172,
+    1173,
+    1174,
+    1175,
+    1176,
+    1177,
+    1178,
+    1178,
+    1177,
+    1181,
+    1182,
+    1174,
+    1184,
+    1185,
+    1185,
+    1187,
+    1188,
+    1188,
+    1188,
+    1191,
+    1192,
+    1192,
+    1194,
+    1194,
+    1196,
+    1196,
+    1198,
+    1199,
+    1200,
+    1200,
+    1200,
+    1203,
+    1204,
+    1204,
+    1206,
+    1207,
+    1208,
+    1209,
+    1210,
+    1211,
+    1212,
+    1213,
+    1213,
+    1215,
+    1215,
+    1217,
+    1218,
+    1219,
+    1220,
+    1221,
+    1222,
+    1221,
+    1224,
+    1225,
+    1225,
+    1227,
+    1227,
+    1229,
+    1230,
+    1219,
+    1232,
+    1233,
+    1234,
+    1235,
+    1235,
+    1237,
+    1237,
+    1239,
+    1240,
+    1241,
+    1241,
+    1243,
+    1243,
+    1245,
+    1243,
+    1247,
+    1248,
+    1249,
+    1250,
+    1251,
+    1252,
+    1253,
+    1254,
+    1255,
+    1256,
+    1251,
+    1258,
+    1259,
+    1259,
+    1261,
+    1262,
+    1262,
+    1264,
+    1265,
+    1265,
+    1267,
+    1267,
+    1269,
+    1270,
+    1270,
+    1269,
+    1269,
+    1274,
+    1274,
+    1276,
+    1276,
+    1278,
+    1279,
+    1279,
+    1281,
+    1281,
+    1269,
+    1269,
+    1285,
+    1285,
+    1285,
+    1288,
+    1288,
+    1290,
+    1291,
+    1291,
+    1267,
+    1267,
+    1295,
+    1296,
+    1297,
+    1267,
+    1299,
+    1300,
+    1301,
+    1302,
+    1302,
+    1304,
+    1305,
+    1306,
+    1301,
+    1308,
+    1309,
+    1310,
+    1311,
+    1312,
+    1313,
+    1308,
+    1315,
+    1315,
+    1317,
+    1318,
+    1318,
+    1320,
+    1321,
+    1322,
+    1320,
+    1324,
+    1324,
+    1326,
+    1327,
+    1327,
+    1329,
+    1329,
+    1329,
+    1332,
+    1332,
+    1334,
+    1334,
+    1336,
+    1337,
+    1338,
+    1337,
+    1340,
+    1340,
+    1340,
+    1343,
+    1343,
+    1343,
+    1346,
+    1346,
+    1348,
+    1349,
+    1350,
+    1350,
+    1352,
+    1353,
+    1354,
+    1355,
+    1355,
+    1357,
+    1358,
+    1358,
+    1358,
+    1361,
+    1362,
+    1363,
+    1364,
+    1365,
+    1366,
+    1367,
+    1368,
+    1369,
+    1367,
+    1371,
+    1371,
+    1373,
+    1373,
+    1375,
+    1375,
+    1377,
+    1377,
+    1379,
+    1379,
+    1381,
+    1382,
+    1373,
+    1373,
+    1385,
+    1385,
+    1387,
+    1388,
+    1388,
+    1390,
+    1390,
+    1373,
+    1393,
+    1381,
+    1395,
+    1396,
+    1396,
+    1398,
+    1398,
+    1398,
+    1401,
+    1402,
+    1403,
+    1403,
+    1403,
+    1406,
+    1407,
+    1407,
+    1407,
+    1410,
+    1410,
+    1412,
+    1412,
+    1414,
+    1415,
+    1415,
+    1417,
+    1418,
+    1419,
+    1420,
+    1421,
+    1422,
+    1423,
+    1424,
+    1425,
+    1426,
+    1426,
+    1428,
+    1429,
+    1430,
+    1431,
+    1431,
+    1433,
+    1433,
+    1433,
+    1436,
+    1429,
+    1425,
+    1439,
+    1440,
+    1441,
+    1442,
+    1442,
+    1444,
+    1445,
+    1446,
+    1446,
+    1448,
+    1449,
+    1449,
+    1451,
+    1451,
+    1453,
+    1454,
+    1455,
+    1456,
+    1457,
+    1458,
+    1459,
+    1459,
+    1461,
+    1462,
+    1463,
+    1464,
+    1464,
+    1466,
+    1467,
+    1468,
+    1469,
+    1470,
+    1464,
+    1472,
+    1472,
+    1474,
+    1475,
+    1476,
+    1477,
+    1478,
+    1478,
+    1480,
+    1481,
+    1478,
+    1483,
+    1484,
+    1485,
+    1486,
+    1487,
+    1488,
+    1489,
+    1490,
+    1491,
+    1492,
+    1493,
+    1493,
+    1495,
+    1496,
+    1497,
+    1498,
+    1499,
+    1500,
+    1501,
+    1502,
+    1503,
+    1504,
+    1505,
+    1505,
+    1507,
+    1508,
+    1509,
+    1509,
+    1511,
+    1512,
+    1513,
+    1514,
+    1515,
+    1516,
+    1517,
+    1518,
+    1517,
+    1520,
+    1521,
+    1521,
+    1523,
+    1524,
+    1525,
+    1525,
+    1527,
+    1528,
+    1527,
+    1530,
+    1531,
+    1531,
+    1533,
+    1534,
+    1534,
+    1536,
+    1537,
+    1537,
+    1539,
+    1539,
+    1537,
+    1530,
+    1533,
+    1544,
+    1544,
+    1546,
+    1546,
+    1544,
+    1549,
+    1549,
+    1551,
+    1551,
+    1553,
+    1554,
+    1555,
+    1555,
+    1557,
+    1558,
+    1559,
+    1559,
+    1561,
+    1562,
+    1562,
+    1562,
+    1562,
+    1566,
+    1567,
+    1567,
+    1566,
+    1570,
+    1571,
+    1572,
+    1573,
+    1573,
+    1573,
+    1576,
+    1576,
+    1578,
+    1579,
+    1579,
+    1581,
+    1581,
+    1583,
+    1583,
+    1585,
+    1586,
+    1585,
+    1588,
+    1588,
+    1590,
+    1591,
+    1591,
+    1593,
+    1590,
+    1595,
+    1596,
+    1596,
+    1598,
+    1598,
+    1600,
+    1600,
+    1602,
+    1603,
+    1603,
+    1605,
+    1605,
+    1607,
+    1608,
+    1608,
+    1607,
+    1611,
+    1612,
+    1612,
+    1611,
+    1615,
+    1616,
+    1617,
+    1618,
+    1619,
+    1620,
+    1621,
+    1621,
+    1623,
+    1624,
+    1624,
+    1626,
+    1627,
+    1628,
+    1629,
+    1630,
+    1631,
+    1632,
+    1626,
+    1629,
+    1635,
+    1636,
+    1637,
+    1638,
+    1639,
+    1639,
+    1639,
+    1642,
+    1643,
+    1644,
+    1645,
+    1646,
+    1646,
+    1646,
+    1649,
+    1650,
+    1651,
+    1652,
+    1652,
+    1652,
+    1655,
+    1655,
+    1657,
+    1657,
+    1657,
+    1652,
+    1661,
+    1662,
+    1663,
+    1664,
+    1664,
+    1666,
+    1662,
+    1668,
+    1669,
+    1662,
+    1671,
+    1671,
+    1673,
+    1671,
+    1675,
+    1675,
+    1675,
+    1675,
+    1679,
+    1680,
+    1680,
+    1682,
+    1682,
+    1684,
+    1685,
+    1685,
+    1685,
+    1662,
+    1689,
+    1690,
+    1690,
+    1692,
+    1692,
+    1694,
+    1695,
+    1695,
+    1697,
+    1698,
+    1698,
+    1700,
+    1701,
+    1701,
+    1701,
+    1694,
+    1705,
+    1706,
+    1707,
+    1708,
+    1708,
+    1710,
+    1711,
+    1712,
+    1713,
+    1714,
+    1715,
+    1715,
+    1717,
+    1717,
+    1719,
+    1720,
+    1720,
+    1719,
+    1714,
+    1724,
+    1725,
+    1725,
+    1727,
+    1727,
+    1725,
+    1725,
+    1731,
+    1732,
+    1732,
+    1734,
+    1735,
+    1735,
+    1732,
+    1738,
+    1732,
+    1740,
+    1740,
+    1740,
+    1743,
+    1744,
+    1744,
+    1746,
+    1747,
+    1748,
+    1749,
+    1746,
+    1751,
+    1752,
+    1753,
+    1754,
+    1755,
+    1756,
+    1757,
+    1758,
+    1759,
+    1759,
+    1761,
+    1762,
+    1763,
+    1763,
+    1765,
+    1763,
+    1767,
+    1768,
+    1769,
+    1770,
+    1770,
+    1772,
+    1773,
+    1774,
+    1775,
+    1776,
+    1777,
+    1777,
+    1779,
+    1775,
+    1781,
+    1782,
+    1783,
+    1782,
+    1785,
+    1786,
+    1786,
+    1788,
+    1788,
+    1790,
+    1790,
+    1792,
+    1792,
+    1794,
+    1794,
+    1794,
+    1797,
+    1798,
+    1799,
+    1800,
+    1801,
+    1801,
+    1803,
+    1800,
+    1805,
+    1806,
+    1805,
+    1808,
+    1808,
+    1810,
+    1811,
+    1811,
+    1813,
+    1814,
+    1815,
+    1816,
+    1816,
+    1815,
+    1819,
+    1820,
+    1820,
+    1822,
+    1823,
+    1823,
+    1825,
+    1825,
+    1827,
+    1827,
+    1829,
+    1830,
+    1831,
+    1831,
+    1833,
+    1834,
+    1833,
+    1836,
+    1836,
+    1838,
+    1836,
+    1840,
+    1840,
+    1842,
+    1843,
+    1843,
+    1845,
+    1845,
+    1847,
+    1847,
+    1849,
+    1849,
+    1851,
+    1851,
+    1853,
+    1854,
+    1855,
+    1856,
+    1857,
+    1858,
+    1857,
+    1860,
+    1861,
+    1861,
+    1863,
+    1863,
+    1861,
+    1861,
+    1861,
+    1868,
+    1869,
+    1869,
+    1871,
+    1871,
+    1873,
+    1868,
+    1875,
+    1875,
+    1877,
+    1877,
+    1879,
+    1879,
+    1879,
+    1882,
+    1882,
+    1884,
+    1884,
+    1886,
+    1886,
+    1888,
+    1889,
+    1889,
+    1889,
+    1889,
+    1893,
+    1868,
+    1868,
+    1896,
+    1896,
+    1898,
+    1898,
+    1900,
+    1900,
+    1902,
+    1903,
+    1903,
+    1905,
+    1905,
+    1907,
+    1908,
+    1908,
+    1910,
+    1911,
+    1868,
+    1913,
+    1914,
+    1915,
+    1916,
+    1917,
+    1918,
+    1919,
+    1920,
+    1921,
+    1922,
+    1923,
+    1924,
+    1925,
+    1926,
+    1926,
+    1928,
+    1928,
+    1930,
+    1930,
+    1932,
+    1932,
+    1860,
+    1935,
+    1935,
+    1937,
+    1938,
+    1938,
+    1940,
+    1941,
+    1942,
+    1943,
+    1944,
+    1945,
+    1944,
+    1943,
+    1948,
+    1949,
+    1950,
+    1950,
+    1941,
+    1953,
+    1953,
+    1955,
+    1956,
+    1956,
+    1958,
+    1959,
+    1959,
+    1961,
+    1961,
+    1963,
+    1964,
+    1963,
+    1966,
+    1967,
+    1968,
+    1968,
+    1958,
+    1971,
+    1972,
+    1972,
+    1974,
+    1974,
+    1976,
+    1977,
+    1977,
+    1979,
+    1980,
+    1981,
+    1981,
+    1983,
+    1983,
+    1985,
+    1985,
+    1987,
+    1987,
+    1989,
+    1989,
+    1991,
+    1991,
+    1981,
+    1994,
+    1995,
+    1996,
+    1995,
+    1998,
+    1998,
+    2000,
+    2000,
+    2002,
+    1998,
+    2004,
+    2004,
+    2006,
+    2006,
+    2008,
+    2008,
+    2010,
+    2011,
+    2011,
+    2013,
+    2008,
+    2015,
+    2008,
+    2008,
+    2018,
+    2019,
+    2019,
+    2019,
+    2022,
+    2023,
+    2024,
+    2025,
+    2026,
+    2022,
+    2028,
+    2029,
+    2030,
+    2030,
+    2029,
+    2022,
+    2026,
+    2035,
+    2035,
+    2037,
+    2037,
+    2035,
+    2035,
+    2035,
+    2042,
+    2042,
+    2044,
+    2044,
+    2046,
+    2047,
+    2042,
+    2042,
+    2050,
+    2051,
+    2052,
+    2053,
+    2051,
+    2055,
+    2056,
+    2056,
+    2018,
+    2059,
+    2060,
+    2059,
+    2062,
+    2063,
+    2062,
+    2065,
+    2066,
+    2067,
+    2066,
+    2069,
+    2070,
+    2071,
+    2071,
+    2070,
+    2074,
+    2075,
+    2076,
+    2076,
+    2075,
+    2079,
+    2080,
+    2081,
+    2082,
+    2083,
+    2083,
+    2085,
+    2082,
+    2087,
+    2087,
+    2089,
+    2089,
+    2091,
+    2091,
+    2074,
+    2094,
+    2080,
+    2096,
+    2096,
+    2098,
+    2096,
+    2100,
+    2100,
+    2102,
+    2102,
+    2104,
+    2104,
+    2100,
+    2107,
+    2108,
+    2108,
+    2110,
+    2111,
+    2112,
+    2112,
+    2114,
+    2112,
+    2116,
+    2116,
+    2118,
+    2119,
+    2118,
+    2121,
+    2121,
+    2123,
+    2124,
+    2124,
+    2123,
+    2123,
+    2116,
+    2129,
+    2130,
+    2130,
+    2069,
+    2133,
+    2134,
+    2135,
+    2135,
+    2137,
+    2134,
+    2137,
+    2140,
+    2140,
+    2142,
+    2142,
+    2144,
+    2145,
+    2145,
+    2147,
+    2147,
+    2145,
+    2150,
+    2150,
+    2152,
+    2153,
+    2154,
+    2154,
+    2156,
+    2157,
+    2154,
+    2153,
+    2160,
+    2161,
+    2161,
+    2163,
+    2164,
+    2161,
+    2166,
+    2167,
+    2167,
+    2169,
+    2167,
+    2169,
+    2172,
+    2172,
+    2167,
+    2175,
+    2176,
+    2177,
+    2177,
+    2177,
+    2180,
+    2181,
+    2182,
+    2182,
+    2184,
+    2161,
+    2186,
+    2186,
+    2188,
+    2188,
+    2190,
+    2190,
+    2192,
+    2192,
+    2194,
+    2195,
+    2192,
+    2192,
+    2198,
+    2199,
+    2199,
+    2201,
+    2201,
+    2203,
+    2203,
+    2205,
+    2206,
+    2207,
+    2208,
+    2209,
+    2205,
+    2211,
+    2212,
+    2212,
+    2214,
+    2215,
+    2215,
+    2217,
+    2218,
+    2212,
+    2220,
+    2221,
+    2222,
+    2223,
+    2222,
+    2225,
+    2225,
+    2227,
+    2221,
+    2227,
+    2230,
+    2231,
+    2230,
+    2233,
+    2233,
+    2235,
+    2235,
+    2237,
+    2237,
+    2152,
+    2240,
+    2240,
+    2242,
+    2243,
+    2243,
+    2245,
+    2246,
+    2245,
+    2248,
+    2248,
+    2250,
+    2251,
+    2252,
+    2252,
+    2251,
+    2255,
+    2256,
+    2257,
+    2258,
+    2258,
+    2260,
+    2261,
+    2262,
+    2263,
+    2263,
+    2265,
+    2266,
+    2263,
+    2268,
+    2269,
+    2270,
+    2271,
+    2272,
+    2273,
+    2274,
+    2274,
+    2276,
+    2277,
+    2258,
+    2279,
+    2280,
+    2280,
+    2282,
+    2283,
+    2284,
+    2280,
+    2286,
+    2287,
+    2288,
+    2289,
+    2290,
+    2291,
+    2291,
+    2293,
+    2294,
+    2291,
+    2296,
+    2296,
+    2298,
+    2299,
+    2300,
+    2301,
+    2302,
+    2302,
+    2304,
+    2304,
+    2302,
+    2302,
+    2308,
+    2308,
+    2308,
+    2311,
+    2312,
+    2312,
+    2314,
+    2314,
+    2312,
+    2312,
+    2318,
+    2318,
+    2320,
+    2321,
+    2321,
+    2323,
+    2323,
+    2321,
+    2321,
+    2321,
+    2328,
+    2320,
+    2330,
+    2330,
+    2330,
+    2308,
+    2334,
+    2335,
+    2336,
+    2336,
+    2301,
+    2339,
+    2340,
+    2341,
+    2342,
+    2342,
+    2344,
+    2341,
+    2346,
+    2346,
+    2348,
+    2348,
+    2350,
+    2350,
+    2352,
+    2353,
+    2348,
+    2348,
+    2356,
+    2357,
+    2357,
+    2359,
+    2360,
+    2360,
+    2362,
+    2363,
+    2364,
+    2365,
+    2366,
+    2367,
+    2368,
+    2368,
+    2370,
+    2370,
+    2363,
+    2365,
+    2374,
+    2375,
+    2375,
+    2377,
+    2377,
+    2379,
+    2379,
+    2377,
+    2377,
+    2383,
+    2383,
+    2385,
+    2385,
+    2387,
+    2388,
+    2389,
+    2389,
+    2391,
+    2392,
+    2393,
+    2393,
+    2395,
+    2393,
+    2397,
+    2398,
+    2398,
+    2393,
+    2401,
+    2402,
+    2402,
+    2404,
+    2404,
+    2392,
+    2407,
+    2408,
+    2408,
+    2410,
+    2410,
+    2412,
+    2413,
+    2412,
+    2415,
+    2416,
+    2416,
+    2418,
+    2419,
+    2418,
+    2418,
+    2418,
+    2423,
+    2423,
+    2425,
+    2426,
+    2426,
+    2428,
+    2429,
+    2429,
+    2429,
+    2429,
+    2433,
+    2434,
+    2433,
+    2436,
+    2437,
+    2438,
+    2433,
+    2440,
+    2441,
+    2442,
+    2442,
+    2441,
+    2445,
+    2446,
+    2447,
+    2447,
+    2447,
+    2450,
+    2451,
+    2451,
+    2446,
+    2446,
+    2455,
+    2456,
+    2446,
+    2458,
+    2459,
+    2460,
+    2461,
+    2462,
+    2463,
+    2461,
+    2461,
+    2466,
+    2466,
+    2461,
+    2469,
+    2470,
+    2469,
+    2472,
+    2473,
+    2472,
+    2475,
+    2476,
+    2477,
+    2477,
+    2477,
+    2480,
+    2480,
+    2482,
+    2482,
+    2482,
+    2485,
+    2485,
+    2485,
+    2488,
+    2488,
+    2490,
+    2490,
+    2488,
+    2493,
+    2493,
+    2495,
+    2495,
+    2497,
+    2497,
+    2497,
+    2500,
+    2500,
+    2502,
+    2503,
+    2503,
+    2503,
+    2506,
+    2506,
+    2508,
+    2509,
+    2508,
+    2511,
+    2511,
+    2513,
+    2513,
+    2513,
+    2516,
+    2516,
+    2516,
+    2519,
+    2519,
+    2521,
+    2522,
+    2523,
+    2524,
+    2525,
+    2525,
+    2527,
+    2528,
+    2528,
+    2530,
+    2531,
+    2531,
+    2533,
+    2533,
+    2535,
+    2535,
+    2537,
+    2538,
+    2539,
+    2539,
+    2538,
+    2542,
+    2542,
+    2544,
+    2544,
+    2546,
+    2547,
+    2547,
+    2549,
+    2549,
+    2551,
+    2552,
+    2552,
+    2554,
+    2555,
+    2555,
+    2524,
+    2558,
+    2559,
+    2560,
+    2561,
+    2562,
+    2563,
+    2564,
+    2564,
+    2566,
+    2567,
+    2568,
+    2568,
+    2570,
+    2571,
+    2572,
+    2572,
+    2572,
+    2575,
+    2575,
+    2577,
+    2577,
+    2579,
+    2580,
+    2580,
+    2582,
+    2582,
+    2582,
+    2585,
+    2586,
+    2587,
+    2588,
+    2589,
+    2590,
+    2591,
+    2592,
+    2592,
+    2594,
+    2594,
+    2596,
+    2597,
+    2597,
+    2599,
+    2599,
+    2601,
+    2601,
+    2603,
+    2604,
+    2605,
+    2605,
+    2607,
+    2608,
+    2609,
+    2609,
+    2611,
+    2611,
+    2585,
+    2614,
+    2614,
+    2616,
+    2616,
+    2618,
+    2619,
+    2620,
+    2621,
+    2622,
+    2622,
+    2624,
+    2624,
+    2626,
+    2626,
+    2628,
+    2628,
+    2628,
+    2631,
+    2632,
+    2632,
+    2632,
+    2635,
+    2636,
+    2637,
+    2637,
+    2639,
+    2640,
+    2640,
+    2642,
+    2642,
+    2644,
+    2645,
+    2645,
+    2647,
+    2648,
+    2649,
+    2649,
+    2649,
+    2652,
+    2652,
+    2652,
+    2655,
+    2655,
+    2657,
+    2657,
+    2659,
+    2660,
+    2660,
+    2662,
+    2662,
+    2664,
+    2665,
+    2665,
+    2664,
+    2668,
+    2668,
+    2644,
+    2671,
+    2671,
+    2671,
+    2671,
+    2675,
+    2675,
+    2677,
+    2677,
+    2679,
+    2680,
+    2681,
+    2680,
+    2683,
+    2684,
+    2685,
+    2686,
+    2687,
+    2687,
+    2689,
+    2689,
+    2689,
+    2692,
+    2693,
+    2694,
+    2695,
+    2696,
+    2696,
+    2698,
+    2699,
+    2699,
+    2701,
+    2702,
+    2703,
+    2703,
+    2702,
+    2706,
+    2707,
+    2707,
+    2709,
+    2710,
+    2711,
+    2710,
+    2713,
+    2714,
+    2714,
+    2695,
+    2717,
+    2718,
+    2719,
+    2720,
+    2721,
+    2721,
+    2717,
+    2724,
+    2725,
+    2726,
+    2727,
+    2728,
+    2729,
+    2729,
+    2731,
+    2731,
+    2733,
+    2734,
+    2733,
+    2736,
+    2736,
+    2738,
+    2739,
+    2740,
+    2741,
+    2742,
+    2743,
+    2744,
+    2745,
+    2745,
+    2744,
+    2748,
+    2749,
+    2750,
+    2750,
+    2752,
+    2752,
+    2754,
+    2755,
+    2754,
+    2757,
+    2758,
+    2759,
+    2759,
+    2758,
+    2758,
+    2758,
+    2764,
+    2764,
+    2766,
+    2767,
+    2767,
+    2769,
+    2770,
+    2771,
+    2771,
+    2773,
+    2766,
+    2775,
+    2776,
+    2777,
+    2778,
+    2779,
+    2780,
+    2781,
+    2782,
+    2783,
+    2784,
+    2785,
+    2786,
+    2787,
+    2788,
+    2789,
+    2786,
+    2791,
+    2792,
+    2793,
+    2792,
+    2795,
+    2796,
+    2796,
+    2798,
+    2799,
+    2800,
+    2801,
+    2801,
+    2803,
+    2804,
+    2805,
+    2804,
+    2807,
+    2808,
+    2809,
+    2809,
+    2811,
+    2811,
+    2813,
+    2813,
+    2808,
+    2816,
+    2816,
+    2816,
+    2819,
+    2819,
+    2821,
+    2822,
+    2822,
+    2822,
+    2825,
+    2803,
+    2827,
+    2827,
+    2829,
+    2829,
+    2831,
+    2831,
+    2833,
+    2834,
+    2834,
+    2836,
+    2837,
+    2837,
+    2839,
+    2840,
+    2840,
+    2840,
+    2843,
+    2843,
+    2845,
+    2846,
+    2846,
+    2846,
+    2849,
+    2850,
+    2850,
+    2852,
+    2852,
+    2854,
+    2854,
+    2849,
+    2857,
+    2858,
+    2858,
+    2860,
+    2860,
+    2862,
+    2862,
+    2864,
+    2864,
+    2866,
+    2866,
+    2868,
+    2868,
+    2870,
+    2871,
+    2872,
+    2872,
+    2871,
+    2875,
+    2876,
+    2876,
+    2878,
+    2878,
+    2880,
+    2880,
+    2882,
+    2882,
+    2884,
+    2885,
+    2885,
+    2887,
+    2888,
+    2888,
+    2887,
+    2891,
+    2892,
+    2893,
+    2893,
+    2895,
+    2896,
+    2897,
+    2897,
+    2896,
+    2900,
+    2900,
+    2902,
+    2903,
+    2904,
+    2905,
+    2906,
+    2906,
+    2903,
+    2909,
+    2909,
+    2911,
+    2902,
+    2913,
+    2913,
+    2915,
+    2916,
+    2917,
+    2917,
+    2919,
+    2919,
+    2921,
+    2922,
+    2922,
+    2922,
+    2925,
+    2925,
+    2927,
+    2928,
+    2929,
+    2929,
+    2931,
+    2931,
+    2931,
+    2934,
+    2935,
+    2936,
+    2937,
+    2938,
+    2939,
+    2940,
+    2940,
+    2942,
+    2942,
+    2944,
+    2944,
+    2944,
+    2947,
+    2948,
+    2948,
+    2948,
+    2951,
+    2952,
+    2952,
+    2954,
+    2955,
+    2952,
+    2934,
+    2958,
+    2959,
+    2960,
+    2960,
+    2962,
+    2963,
+    2964,
+    2965,
+    2965,
+    2967,
+    2967,
+    2965,
+    2970,
+    2970,
+    2972,
+    2972,
+    2974,
+    2974,
+    2976,
+    2976,
+    2978,
+    2978,
+    2978,
+    2981,
+    2982,
+    2982,
+    2984,
+    2984,
+    2982,
+    2987,
+    2988,
+    2988,
+    2990,
+    2991,
+    2991,
+    2993,
+    2990,
+    2995,
+    2996,
+    2996,
+    2998,
+    2998,
+    3000,
+    3001,
+    3002,
+    3002,
+    3004,
+    3005,
+    3006,
+    3007,
+    3008,
+    3009,
+    3010,
+    3010,
+    3012,
+    3013,
+    3013,
+    3009,
+    3016,
+    3017,
+    3017,
+    3016,
+    3020,
+    3021,
+    3021,
+    3023,
+    3023,
+    3021,
+    3026,
+    3027,
+    3027,
+    3027,
+    3020,
+    3031,
+    3031,
+    3033,
+    3033,
+    3035,
+    3036,
+    3036,
+    3004,
+    3039,
+    3039,
+    3041,
+    3042,
+    3043,
+    3044,
+    3044,
+    3046,
+    3047,
+    3048,
+    3048,
+    3050,
+    3051,
+    3046,
+    3053,
+    3053,
+    3055,
+    3055,
+    3057,
+    3058,
+    3058,
+    3060,
+    3061,
+    3042,
+    3063,
+    3064,
+    3064,
+    3066,
+    3066,
+    3068,
+    3068,
+    3070,
+    3071,
+    3072,
+    3072,
+    3074,
+    3075,
+    3076,
+    3077,
+    3078,
+    3077,
+    3080,
+    3080,
+    3082,
+    3082,
+    3084,
+    3084,
+    3086,
+    3087,
+    3088,
+    3089,
+    3089,
+    3091,
+    3089,
+    3093,
+    3094,
+    3094,
+    3096,
+    3097,
+    3098,
+    3093,
+    3100,
+    3101,
+    3102,
+    3103,
+    3104,
+    3105,
+    3104,
+    3107,
+    3108,
+    3108,
+    3108,
+    3107,
+    3112,
+    3112,
+    3114,
+    3115,
+    3116,
+    3116,
+    3115,
+    3119,
+    3119,
+    3121,
+    3115,
+    3123,
+    3124,
+    3125,
+    3126,
+    3127,
+    3128,
+    3129,
+    3129,
+    3131,
+    3132,
+    3133,
+    3134,
+    3135,
+    3136,
+    3137,
+    3138,
+    3139,
+    3140,
+    3140,
+    3142,
+    3143,
+    3144,
+    3145,
+    3146,
+    3146,
+    3148,
+    3149,
+    3146,
+    3151,
+    3152,
+    3153,
+    3153,
+    3152,
+    3156,
+    3156,
+    3158,
+    3158,
+    3145,
+    3161,
+    3162,
+    3162,
+    3164,
+    3165,
+    3166,
+    3166,
+    3168,
+    3169,
+    3170,
+    3168,
+    3172,
+    3173,
+    3173,
+    3175,
+    3176,
+    3176,
+    3178,
+    3178,
+    3180,
+    3180,
+    3182,
+    3183,
+    3184,
+    3184,
+    3186,
+    3187,
+    3187,
+    3178,
+    3178,
+    3191,
+    3192,
+    3192,
+    3178,
+    3195,
+    3196,
+    3197,
+    3197,
+    3199,
+    3200,
+    3201,
+    3201,
+    3200,
+    3200,
+    3200,
+    3199,
+    3207,
+    3208,
+    3208,
+    3210,
+    3210,
+    3212,
+    3212,
+    3214,
+    3215,
+    3216,
+    3214,
+    3218,
+    3218,
+    3220,
+    3221,
+    3221,
+    3223,
+    3223,
+    3221,
+    3221,
+    3220,
+    3228,
+    3228,
+    3230,
+    3230,
+    3228,
+    3233,
+    3234,
+    3235,
+    3236,
+    3237,
+    3238,
+    3238,
+    3240,
+    3241,
+    3242,
+    3242,
+    3244,
+    3245,
+    3245,
+    3238,
+    3248,
+    3248,
+    3248,
+    3248,
+    3252,
+    3252,
+    3254,
+    3255,
+    3256,
+    3257,
+    3257,
+    3259,
+    3260,
+    3261,
+    3262,
+    3257,
+    3264,
+    3265,
+    3266,
+    3267,
+    3268,
+    3268,
+    3268,
+    3266,
+    3272,
+    3273,
+    3273,
+    3273,
+    3276,
+    3273,
+    3278,
+    3278,
+    3280,
+    3280,
+    3282,
+    3282,
+    3280,
+    3280,
+    3286,
+    3286,
+    3288,
+    3288,
+    3290,
+    3290,
+    3286,
+    3293,
+    3293,
+    3295,
+    3296,
+    3295,
+    3298,
+    3299,
+    3299,
+    3299,
+    3302,
+    3303,
+    3303,
+    3303,
+    3306,
+    3307,
+    3308,
+    3309,
+    3310,
+    3310,
+    3312,
+    3313,
+    3314,
+    3314,
+    3316,
+    3317,
+    3317,
+    3319,
+    3319,
+    3321,
+    3321,
+    3323,
+    3323,
+    3321,
+    3326,
+    3327,
+    3328,
+    3329,
+    3328,
+    3331,
+    3332,
+    3333,
+    3334,
+    3334,
+    3336,
+    3336,
+    3338,
+    3339,
+    3339,
+    3333,
+    3342,
+    3342,
+    3344,
+    3344,
+    3344,
+    3347,
+    3348,
+    3349,
+    3349,
+    3348,
+    3352,
+    3352,
+    3348,
+    3332,
+    3356,
+    3357,
+    3357,
+    3359,
+    3360,
+    3360,
+    3362,
+    3363,
+    3364,
+    3365,
+    3365,
+    3367,
+    3368,
+    3368,
+    3370,
+    3370,
+    3372,
+    3372,
+    3370,
+    3375,
+    3376,
+    3376,
+    3378,
+    3378,
+    3380,
+    3380,
+    3378,
+    3378,
+    3378,
+    3385,
+    3386,
+    3387,
+    3388,
+    3388,
+    3387,
+    3387,
+    3392,
+    3392,
+    3386,
+    3395,
+    3395,
+    3397,
+    3397,
+    3399,
+    3400,
+    3386,
+    3386,
+    3403,
+    3386,
+    3405,
+    3385,
+    3407,
+    3407,
+    3409,
+    3410,
+    3409,
+    3412,
+    3413,
+    3414,
+    3415,
+    3416,
+    3417,
+    3416,
+    3419,
+    3420,
+    3415,
+    3422,
+    3423,
+    3424,
+    3424,
+    3424,
+    3427,
+    3424,
+    3429,
+    3423,
+    3431,
+    3431,
+    3433,
+    3431,
+    3435,
+    3436,
+    3436,
+    3436,
+    3439,
+    3440,
+    3440,
+    3439,
+    3443,
+    3443,
+    3445,
+    3446,
+    3446,
+    3448,
+    3448,
+    3450,
+    3451,
+    3452,
+    3439,
+    3454,
+    3439,
+    3456,
+    3456,
+    3458,
+    3459,
+    3460,
+    3460,
+    3462,
+    3463,
+    3463,
+    3465,
+    3466,
+    3467,
+    3468,
+    3468,
+    3470,
+    3471,
+    3471,
+    3473,
+    3473,
+    3470,
+    3476,
+    3477,
+    3477,
+    3479,
+    3479,
+    3476,
+    3482,
+    3482,
+    3484,
+    3476,
+    3476,
+    3487,
+    3488,
+    3488,
+    3490,
+    3490,
+    3492,
+    3492,
+    3494,
+    3494,
+    3496,
+    3476,
+    3498,
+    3499,
+    3500,
+    3501,
+    3502,
+    3503,
+    3504,
+    3504,
+    3503,
+    3507,
+    3507,
+    3509,
+    3510,
+    3476,
+    3476,
+    3513,
+    3514,
+    3514,
+    3514,
+    3514,
+    3518,
+    3518,
+    3520,
+    3520,
+    3522,
+    3522,
+    3520,
+    3525,
+    3526,
+    3526,
+    3528,
+    3526,
+    3530,
+    3530,
+    3532,
+    3532,
+    3532,
+    3532,
+    3536,
+    3536,
+    3538,
+    3536,
+    3540,
+    3540,
+    3542,
+    3543,
+    3543,
+    3545,
+    3546,
+    3546,
+    3548,
+    3549,
+    3549,
+    3549,
+    3552,
+    3553,
+    3549,
+    3555,
+    3556,
+    3548,
+    3558,
+    3558,
+    3545,
+    3561,
+    3561,
+    3563,
+    3564,
+    3561,
+    3566,
+    3566,
+    3568,
+    3568,
+    3570,
+    3570,
+    3572,
+    3572,
+    3574,
+    3566,
+    3566,
+    3577,
+    3578,
+    3579,
+    3579,
+    3566,
+    3582,
+    3583,
+    3583,
+    3585,
+    3585,
+    3587,
+    3588,
+    3589,
+    3589,
+    3588,
+    3592,
+    3593,
+    3594,
+    3595,
+    3596,
+    3596,
+    3598,
+    3598,
+    3600,
+    3601,
+    3588,
+    3603,
+    3588,
+    3605,
+    3605,
+    3607,
+    3587,
+    3600,
+    3600,
+    3611,
+    3612,
+    3613,
+    3614,
+    3615,
+    3616,
+    3616,
+    3618,
+    3618,
+    3620,
+    3620,
+    3622,
+    3623,
+    3623,
+    3625,
+    3625,
+    3625,
+    3628,
+    3629,
+    3628,
+    3631,
+    3632,
+    3633,
+    3633,
+    3635,
+    3635,
+    3622,
+    3638,
+    3639,
+    3640,
+    3632,
+    3642,
+    3643,
+    3644,
+    3645,
+    3646,
+    3646,
+    3648,
+    3649,
+    3649,
+    3648,
+    3652,
+    3652,
+    3645,
+    3655,
+    3655,
+    3657,
+    3658,
+    3658,
+    3660,
+    3660,
+    3658,
+    3663,
+    3663,
+    3663,
+    3666,
+    3666,
+    3666,
+    3657,
+    3670,
+    3670,
+    3672,
+    3673,
+    3674,
+    3673,
+    3672,
+    3677,
+    3678,
+    3677,
+    3680,
+    3681,
+    3682,
+    3683,
+    3684,
+    3684,
+    3684,
+    3680,
+    3680,
+    3689,
+    3689,
+    3691,
+    3692,
+    3693,
+    3693,
+    3695,
+    3695,
+    3695,
+    3698,
+    3699,
+    3700,
+    3701,
+    3701,
+    3703,
+    3703,
+    3705,
+    3705,
+    3707,
+    3707,
+    3703,
+    3703,
+    3711,
+    3712,
+    3712,
+    3714,
+    3715,
+    3716,
+    3716,
+    3718,
+    3718,
+    3720,
+    3721,
+    3721,
+    3723,
+    3723,
+    3720,
+    3726,
+    3726,
+    3728,
+    3728,
+    3730,
+    3731,
+    3731,
+    3728,
+    3734,
+    3735,
+    3735,
+    3737,
+    3737,
+    3739,
+    3739,
+    3739,
+    3742,
+    3743,
+    3744,
+    3744,
+    3746,
+    3746,
+    3748,
+    3748,
+    3750,
+    3748,
+    3752,
+    3753,
+    3754,
+    3755,
+    3756,
+    3757,
+    3758,
+    3757,
+    3760,
+    3760,
+    3762,
+    3763,
+    3764,
+    3764,
+    3766,
+    3766,
+    3768,
+    3769,
+    3769,
+    3771,
+    3771,
+    3763,
+    3774,
+    3774,
+    3774,
+    3774,
+    3778,
+    3778,
+    3780,
+    3781,
+    3782,
+    3783,
+    3783,
+    3780,
+    3786,
+    3787,
+    3788,
+    3786,
+    3790,
+    3786,
+    3792,
+    3793,
+    3793,
+    3786,
+    3796,
+    3786,
+    3798,
+    3798,
+    3798,
+    3798,
+    3798,
+    3803,
+    3803,
+    3805,
+    3806,
+    3807,
+    3807,
+    3762,
+    3810,
+    3811,
+    3812,
+    3813,
+    3814,
+    3814,
+    3816,
+    3817,
+    3817,
+    3819,
+    3820,
+    3821,
+    3822,
+    3823,
+    3824,
+    3825,
+    3826,
+    3827,
+    3828,
+    3829,
+    3830,
+    3831,
+    3832,
+    3833,
+    3834,
+    3835,
+    3836,
+    3837,
+    3837,
+    3839,
+    3839,
+    3841,
+    3841,
+    3839,
+    3836,
+    3834,
+    3846,
+    3846,
+    3848,
+    3849,
+    3849,
+    3851,
+    3846,
+    3853,
+    3854,
+    3846,
+    3856,
+    3857,
+    3858,
+    3858,
+    3860,
+    3860,
+    3862,
+    3863,
+    3863,
+    3863,
+    3866,
+    3867,
+    3868,
+    3869,
+    3869,
+    3867,
+    3833,
+    3873,
+    3873,
+    3875,
+    3876,
+    3876,
+    3878,
+    3878,
+    3880,
+    3881,
+    3881,
+    3881,
+    3884,
+    3885,
+    3886,
+    3886,
+    3888,
+    3888,
+    3890,
+    3890,
+    3890,
+    3893,
+    3894,
+    3894,
+    3896,
+    3896,
+    3898,
+    3898,
+    3898,
+    3898,
+    3902,
+    3903,
+    3903,
+    3905,
+    3905,
+    3903,
+    3908,
+    3909,
+    3910,
+    3911,
+    3912,
+    3913,
+    3913,
+    3915,
+    3916,
+    3915,
+    3912,
+    3902,
+    3920,
+    3920,
+    3920,
+    3923,
+    3924,
+    3924,
+    3926,
+    3927,
+    3927,
+    3926,
+    3930,
+    3931,
+    3932,
+    3932,
+    3932,
+    3935,
+    3936,
+    3937,
+    3937,
+    3936,
+    3940,
+    3940,
+    3942,
+    3943,
+    3943,
+    3945,
+    3946,
+    3945,
+    3948,
+    3949,
+    3949,
+    3951,
+    3951,
+    3951,
+    3954,
+    3954,
+    3954,
+    3957,
+    3949,
+    3959,
+    3960,
+    3960,
+    3962,
+    3962,
+    3964,
+    3964,
+    3966,
+    3966,
+    3964,
+    3969,
+    3949,
+    3971,
+    3949,
+    3973,
+    3974,
+    3974,
+    3973,
+    3977,
+    3977,
+    3979,
+    3979,
+    3981,
+    3982,
+    3982,
+    3973,
+    3973,
+    3973,
+    3987,
+    3988,
+    3989,
+    3990,
+    3990,
+    3948,
+    3993,
+    3993,
+    3995,
+    3993,
+    3997,
+    3998,
+    3999,
+    4000,
+    4001,
+    4002,
+    4003,
+    4003,
+    4005,
+    4006,
+    4006,
+    4005,
+    4009,
+    4010,
+    4010,
+    4005,
+    4013,
+    4014,
+    4014,
+    4016,
+    4016,
+    4016,
+    4016,
+    4020,
+    4021,
+    4022,
+    4005,
+    4005,
+    4025,
+    4025,
+    4027,
+    4028,
+    4029,
+    4030,
+    4031,
+    4032,
+    4032,
+    4034,
+    4031,
+    4036,
+    4037,
+    4037,
+    4039,
+    4040,
+    4040,
+    4039,
+    4043,
+    4043,
+    4045,
+    4046,
+    4046,
+    4030,
+    4049,
+    4050,
+    4050,
+    4050,
+    4053,
+    4053,
+    4055,
+    4055,
+    4057,
+    4053,
+    4050,
+    4060,
+    4050,
+    4062,
+    4062,
+    4064,
+    4065,
+    4065,
+    4067,
+    4067,
+    4067,
+    4070,
+    4071,
+    4071,
+    4073,
+    4073,
+    4073,
+    4076,
+    4076,
+    4078,
+    4078,
+    4080,
+    4081,
+    4080,
+    4083,
+    4083,
+    4085,
+    4085,
+    4085,
+    4088,
+    4088,
+    4088,
+    4091,
+    4092,
+    4093,
+    4093,
+    4095,
+    4095,
+    4097,
+    4098,
+    4098,
+    4098,
+    4101,
+    4098,
+    4103,
+    4104,
+    4105,
+    4106,
+    4106,
+    4106,
+    4109,
+    4110,
+    4110,
+    4112,
+    4113,
+    4114,
+    4115,
+    4113,
+    4114,
+    4118,
+    4119,
+    4119,
+    4121,
+    4105,
+    4123,
+    4123,
+    4049,
+    4126,
+    4127,
+    4128,
+    4129,
+    4130,
+    4131,
+    4131,
+    4133,
+    4133,
+    4135,
+    4135,
+    4137,
+    4135,
+    4139,
+    4140,
+    4135,
+    4142,
+    4142,
+    4142,
+    4142,
+    4142,
+    4147,
+    4148,
+    4149,
+    4149,
+    4151,
+    4152,
+    4149,
+    4149,
+    4155,
+    4156,
+    4157,
+    4158,
+    4156,
+    4160,
+    4147,
+    4162,
+    4162,
+    4164,
+    4164,
+    4164,
+    4164,
+    4168,
+    4168,
+    4170,
+    4171,
+    4170,
+    4168,
+    4174,
+    4174,
+    4168,
+    4177,
+    4177,
+    4179,
+    4179,
+    4181,
+    4181,
+    4179,
+    4184,
+    4185,
+    4185,
+    4187,
+    4188,
+    4189,
+    4189,
+    4188,
+    4188,
+    4193,
+    4193,
+    4188,
+    4196,
+    4196,
+    4198,
+    4198,
+    4198,
+    4201,
+    4201,
+    4203,
+    4204,
+    4205,
+    4206,
+    4206,
+    4204,
+    4209,
+    4210,
+    4210,
+    4212,
+    4212,
+    4214,
+    4215,
+    4215,
+    4217,
+    4218,
+    4219,
+    4219,
+    4221,
+    4221,
+    4223,
+    4223,
+    4225,
+    4226,
+    4226,
+    4226,
+    4229,
+    4226,
+    4231,
+    4232,
+    4233,
+    4233,
+    4235,
+    4235,
+    4237,
+    4237,
+    4235,
+    4240,
+    4241,
+    4241,
+    4243,
+    4243,
+    4245,
+    4245,
+    4247,
+    4247,
+    4249,
+    4249,
+    4251,
+    4251,
+    4253,
+    4253,
+    4255,
+    4256,
+    4257,
+    4256,
+    4255,
+    4260,
+    4261,
+    4261,
+    4263,
+    4263,
+    4265,
+    4266,
+    4266,
+    4268,
+    4265,
+    4260,
+    4271,
+    4272,
+    4272,
+    4274,
+    4275,
+    4274,
+    4277,
+    4278,
+    4279,
+    4280,
+    4280,
+    4282,
+    4282,
+    4282,
+    4285,
+    4285,
+    4287,
+    4287,
+    4279,
+    4290,
+    4291,
+    4292,
+    4292,
+    4294,
+    4291,
+    4296,
+    4296,
+    4298,
+    4299,
+    4300,
+    4301,
+    4302,
+    4302,
+    4304,
+    4305,
+    4306,
+    4307,
+    4307,
+    4309,
+    4310,
+    4311,
+    4312,
+    4313,
+    4314,
+    4314,
+    4311,
+    4317,
+    4318,
+    4318,
+    4320,
+    4321,
+    4322,
+    4323,
+    4323,
+    4323,
+    4323,
+    4327,
+    4328,
+    4328,
+    4330,
+    4331,
+    4332,
+    4327,
+    4334,
+    4334,
+    4334,
+    4337,
+    4337,
+    4337,
+    4340,
+    4340,
+    4342,
+    4343,
+    4342,
+    4345,
+    4346,
+    4346,
+    4348,
+    4348,
+    4345,
+    4351,
+    4351,
+    4353,
+    4354,
+    4354,
+    4353,
+    4357,
+    4357,
+    4359,
+    4360,
+    4360,
+    4360,
+    4363,
+    4363,
+    4365,
+    4366,
+    4367,
+    4365,
+    4365,
+    4370,
+    4370,
+    4372,
+    4372,
+    4374,
+    4374,
+    4376,
+    4377,
+    4377,
+    4377,
+    4377,
+    4381,
+    4382,
+    4382,
+    4384,
+    4385,
+    4385,
+    4384,
+    4388,
+    4388,
+    4381,
+    4391,
+    4392,
+    4393,
+    4393,
+    4392,
+    4396,
+    4397,
+    4397,
+    4397,
+    4400,
+    4400,
+    4396,
+    4403,
+    4403,
+    4403,
+    4406,
+    4407,
+    4408,
+    4409,
+    4410,
+    4411,
+    4406,
+    4413,
+    4414,
+    4414,
+    4414,
+    4417,
+    4418,
+    4322,
+    4420,
+    4421,
+    4422,
+    4423,
+    4423,
+    4422,
+    4426,
+    4427,
+    4428,
+    4428,
+    4430,
+    4431,
+    4431,
+    4433,
+    4433,
+    4435,
+    4436,
+    4437,
+    4438,
+    4439,
+    4440,
+    4433,
+    4438,
+    4431,
+    4444,
+    4444,
+    4444,
+    4447,
+    4448,
+    4449,
+    4449,
+    4448,
+    4452,
+    4452,
+    4454,
+    4454,
+    4456,
+    4456,
+    4458,
+    4458,
+    4460,
+    4460,
+    4460,
+    4463,
+    4464,
+    4464,
+    4466,
+    4466,
+    4468,
+    4468,
+    4470,
+    4471,
+    4471,
+    4473,
+    4473,
+    4473,
+    4476,
+    4476,
+    4478,
+    4479,
+    4479,
+    4481,
+    4481,
+    4447,
+    4484,
+    4484,
+    4484,
+    4487,
+    4488,
+    4489,
+    4490,
+    4491,
+    4492,
+    4493,
+    4494,
+    4494,
+    4496,
+    4497,
+    4497,
+    4497,
+    4500,
+    4500,
+    4502,
+    4500,
+    4504,
+    4500,
+    4506,
+    4507,
+    4508,
+    4492,
+    4510,
+    4510,
+    4510,
+    4510,
+    4514,
+    4515,
+    4516,
+    4516,
+    4518,
+    4519,
+    4519,
+    4518,
+    4522,
+    4522,
+    4524,
+    4524,
+    4524,
+    4518,
+    4528,
+    4529,
+    4529,
+    4531,
+    4531,
+    4531,
+    4529,
+    4529,
+    4536,
+    4536,
+    4536,
+    4539,
+    4540,
+    4540,
+    4540,
+    4540,
+    4539,
+    4518,
+    4546,
+    4547,
+    4548,
+    4548,
+    4550,
+    4550,
+    4552,
+    4552,
+    4554,
+    4555,
+    4555,
+    4557,
+    4558,
+    4558,
+    4557,
+    4561,
+    4562,
+    4561,
+    4564,
+    4564,
+    4566,
+    4566,
+    4568,
+    4569,
+    4569,
+    4554,
+    4572,
+    4572,
+    4574,
+    4574,
+    4576,
+    4577,
+    4577,
+    4579,
+    4579,
+    4577,
+    4582,
+    4576,
+    4584,
+    4585,
+    4585,
+    4587,
+    4587,
+    4589,
+    4589,
+    4591,
+    4592,
+    4592,
+    4594,
+    4595,
+    4595,
+    4594,
+    4598,
+    4599,
+    4600,
+    4601,
+    4601,
+    4603,
+    4603,
+    4605,
+    4605,
+    4607,
+    4607,
+    4605,
+    4610,
+    4611,
+    4612,
+    4613,
+    4614,
+    4614,
+    4613,
+    4617,
+    4617,
+    4619,
+    4619,
+    4621,
+    4622,
+    4613,
+    4613,
+    4625,
+    4626,
+    4627,
+    4627,
+    4627,
+    4627,
+    4631,
+    4632,
+    4632,
+    4634,
+    4634,
+    4636,
+    4636,
+    4638,
+    4638,
+    4640,
+    4640,
+    4640,
+    4643,
+    4643,
+    4645,
+    4646,
+    4647,
+    4638,
+    4649,
+    4650,
+    4649,
+    4638,
+    4653,
+    4631,
+    4655,
+    4655,
+    4657,
+    4658,
+    4658,
+    4660,
+    4661,
+    4661,
+    4663,
+    4663,
+    4661,
+    4666,
+    4667,
+    4668,
+    4669,
+    4670,
+    4670,
+    4670,
+    4670,
+    4674,
+    4675,
+    4676,
+    4677,
+    4677,
+    4676,
+    4674,
+    4681,
+    4681,
+    4683,
+    4683,
+    4685,
+    4686,
+    4686,
+    4685,
+    4689,
+    4689,
+    4691,
+    4691,
+    4693,
+    4693,
+    4691,
+    4685,
+    4697,
+    4698,
+    4698,
+    4700,
+    4700,
+    4702,
+    4702,
+    4704,
+    4704,
+    4706,
+    4706,
+    4708,
+    4708,
+    4708,
+    4708,
+    4700,
+    4657,
+    4714,
+    4715,
+    4714,
+    4717,
+    4717,
+    4714,
+    4720,
+    4721,
+    4721,
+    4723,
+    4723,
+    4725,
+    4726,
+    4726,
+    4728,
+    4728,
+    4730,
+    4731,
+    4725,
+    4725,
+    4725,
+    4735,
+    4736,
+    4737,
+    4738,
+    4739,
+    4740,
+    4741,
+    4741,
+    4740,
+    4744,
+    4745,
+    4745,
+    4747,
+    4747,
+    4749,
+    4749,
+    4744,
+    4752,
+    4752,
+    4754,
+    4754,
+    4756,
+    4757,
+    4757,
+    4759,
+    4760,
+    4759,
+    4762,
+    4762,
+    4762,
+    4765,
+    4765,
+    4756,
+    4768,
+    4769,
+    4769,
+    4771,
+    4771,
+    4773,
+    4773,
+    4775,
+    4776,
+    4776,
+    4778,
+    4778,
+    4778,
+    4781,
+    4781,
+    4778,
+    4784,
+    4784,
+    4775,
+    4787,
+    4788,
+    4788,
+    4787,
+    4791,
+    4792,
+    4792,
+    4794,
+    4787,
+    4787,
+    4797,
+    4798,
+    4798,
+    4800,
+    4800,
+    4802,
+    4802,
+    4804,
+    4804,
+    4798,
+    4807,
+    4808,
+    4809,
+    4809,
+    4809,
+    4809,
+    4813,
+    4813,
+    4739,
+    4816,
+    4817,
+    4818,
+    4819,
+    4820,
+    4821,
+    4821,
+    4821,
+    4824,
+    4825,
+    4826,
+    4826,
+    4828,
+    4828,
+    4771,
+    4771,
+    4832,
+    4832,
+    4834,
+    4835,
+    4836,
+    4835,
+    4838,
+    4838,
+    4840,
+    4841,
+    4842,
+    4843,
+    4844,
+    4845,
+    4846,
+    4835,
+    4848,
+    4849,
+    4850,
+    4851,
+    4852,
+    4853,
+    4854,
+    4855,
+    4856,
+    4857,
+    4858,
+    4859,
+    4832,
+    4861,
+    4861,
+    4863,
+    4864,
+    4865,
+    4865,
+    4861,
+    4868,
+    4869,
+    4870,
+    4871,
+    4872,
+    4873,
+    4872,
+    4875,
+    4876,
+    4877,
+    4872,
+    4879,
+    4879,
+    4881,
+    4882,
+    4879,
+    4884,
+    4857,
+    4857,
+    4887,
+    4888,
+    4889,
+    4889,
+    4891,
+    4891,
+    4893,
+    4894,
+    4894,
+    4896,
+    4894,
+    4898,
+    4893,
+    4900,
+    4901,
+    4901,
+    4900,
+    4904,
+    4904,
+    4906,
+    4906,
+    4908,
+    4904,
+    4904,
+    4911,
+    4911,
+    4888,
+    4914,
+    4914,
+    4916,
+    4916,
+    4918,
+    4919,
+    4919,
+    4921,
+    4922,
+    4923,
+    4924,
+    4925,
+    4926,
+    4926,
+    4925,
+    4925,
+    4930,
+    4930,
+    4932,
+    4933,
+    4933,
+    4932,
+    4936,
+    4936,
+    4938,
+    4938,
+    4940,
+    4941,
+    4941,
+    4943,
+    4943,
+    4945,
+    4945,
+    4943,
+    4948,
+    4948,
+    4950,
+    4951,
+    4951,
+    4953,
+    4953,
+    4955,
+    4955,
+    4957,
+    4958,
+    4957,
+    4957,
+    4957,
+    4962,
+    4962,
+    4964,
+    4964,
+    4966,
+    4967,
+    4968,
+    4968,
+    4970,
+    4970,
+    4972,
+    4972,
+    4974,
+    4974,
+    4976,
+    4976,
+    4978,
+    4979,
+    4979,
+    4981,
+    4982,
+    4982,
+    4984,
+    4984,
+    4986,
+    4986,
+    4988,
+    4989,
+    4988,
+    4991,
+    4986,
+    4993,
+    4993,
+    4995,
+    4995,
+    4993,
+    4998,
+    4998,
+    4993,
+    4993,
+    5002,
+    5003,
+    5004,
+    5004,
+    5006,
+    5007,
+    5008,
+    5008,
+    5010,
+    5010,
+    4978,
+    5013,
+    5014,
+    5015,
+    5014,
+    5017,
+    5017,
+    5019,
+    5020,
+    5021,
+    5021,
+    5020,
+    5024,
+    5025,
+    5026,
+    5025,
+    5028,
+    5028,
+    5013,
+    5031,
+    5032,
+    5033,
+    5033,
+    5035,
+    5032,
+    5037,
+    5038,
+    5038,
+    5040,
+    5040,
+    5042,
+    5042,
+    5038,
+    5045,
+    5046,
+    5047,
+    5048,
+    5049,
+    5050,
+    5050,
+    5037,
+    5053,
+    5054,
+    5054,
+    5056,
+    5056,
+    5058,
+    5058,
+    5060,
+    5060,
+    5062,
+    5062,
+    5064,
+    5064,
+    5066,
+    5066,
+    5068,
+    5068,
+    5068,
+    5071,
+    5072,
+    5072,
+    5074,
+    5074,
+    5076,
+    5076,
+    5078,
+    5074,
+    5074,
+    5081,
+    5081,
+    5083,
+    5084,
+    5085,
+    5084,
+    5084,
+    5088,
+    5089,
+    5090,
+    5084,
+    5092,
+    5093,
+    5094,
+    5094,
+    5096,
+    5097,
+    5098,
+    5098,
+    5100,
+    5101,
+    5101,
+    5103,
+    5103,
+    5103,
+    5106,
+    5107,
+    5107,
+    5109,
+    5110,
+    5110,
+    5112,
+    5112,
+    5114,
+    5114,
+    5116,
+    5116,
+    5118,
+    5118,
+    5120,
+    5116,
+    5116,
+    5120,
+    5116,
+    5125,
+    5125,
+    5127,
+    5128,
+    5125,
+    5125,
+    5131,
+    5132,
+    5133,
+    5134,
+    5135,
+    5135,
+    5135,
+    5134,
+    5132,
+    5140,
+    5140,
+    5142,
+    5142,
+    5144,
+    5144,
+    5146,
+    5146,
+    5146,
+    5146,
+    5093,
+    5151,
+    5151,
+    5153,
+    5153,
+    5155,
+    5155,
+    5157,
+    5157,
+    5157,
+    5160,
+    5160,
+    5162,
+    5162,
+    5164,
+    5162,
+    5162,
+    5167,
+    5168,
+    5168,
+    5170,
+    5171,
+    5171,
+    5173,
+    5173,
+    5175,
+    5175,
+    5177,
+    5178,
+    5179,
+    5179,
+    5179,
+    5182,
+    5183,
+    5184,
+    5185,
+    5185,
+    5184,
+    5188,
+    5188,
+    5188,
+    5183,
+    5192,
+    5193,
+    5193,
+    5192,
+    5196,
+    5197,
+    5198,
+    5198,
+    5200,
+    5200,
+    5202,
+    5202,
+    5204,
+    5204,
+    5206,
+    5207,
+    5207,
+    5209,
+    5210,
+    5210,
+    5212,
+    5212,
+    5214,
+    5214,
+    5216,
+    5216,
+    5218,
+    5219,
+    5220,
+    5221,
+    5202,
+    5202,
+    5224,
+    5224,
+    5226,
+    5227,
+    5227,
+    5226,
+    5230,
+    5230,
+    5202,
+    5233,
+    5234,
+    5235,
+    5235,
+    5235,
+    5238,
+    5178,
+    5240,
+    5241,
+    5242,
+    5243,
+    5244,
+    5245,
+    5245,
+    5243,
+    5248,
+    5248,
+    5250,
+    5250,
+    5242,
+    5253,
+    5253,
+    5255,
+    5256,
+    5256,
+    5258,
+    5258,
+    5253,
+    5261,
+    5261,
+    5263,
+    5263,
+    5265,
+    5265,
+    5267,
+    5267,
+    5269,
+    5269,
+    5271,
+    5272,
+    5273,
+    5274,
+    5273,
+    5276,
+    5277,
+    5277,
+    5279,
+    5280,
+    5280,
+    5277,
+    5283,
+    5283,
+    5283,
+    5241,
+    5287,
+    5288,
+    5289,
+    5290,
+    5291,
+    5291,
+    5290,
+    5294,
+    5294,
+    5296,
+    5297,
+    5298,
+    5298,
+    5300,
+    5301,
+    5302,
+    5301,
+    5300,
+    5305,
+    5305,
+    5290,
+    5308,
+    5309,
+    5310,
+    5311,
+    5312,
+    5312,
+    5314,
+    5314,
+    5316,
+    5316,
+    5316,
+    5312,
+    5320,
+    5321,
+    5321,
+    5323,
+    5324,
+    5324,
+    5326,
+    5326,
+    5328,
+    5312,
+    5330,
+    5330,
+    5332,
+    5332,
+    5334,
+    5335,
+    5335,
+    5337,
+    5337,
+    5339,
+    5340,
+    5341,
+    5341,
+    5343,
+    5343,
+    5345,
+    5345,
+    5345,
+    5340,
+    5339,
+    5350,
+    5351,
+    5351,
+    5350,
+    5354,
+    5355,
+    5356,
+    5357,
+    5357,
+    5357,
+    5354,
+    5311,
+    5362,
+    5363,
+    5363,
+    5365,
+    5366,
+    5367,
+    5367,
+    5367,
+    5370,
+    5370,
+    5372,
+    5372,
+    5372,
+    5375,
+    5376,
+    5377,
+    5377,
+    5379,
+    5379,
+    5381,
+    5381,
+    5381,
+    5381,
+    5385,
+    5386,
+    5387,
+    5388,
+    5388,
+    5390,
+    5390,
+    5392,
+    5392,
+    5394,
+    5395,
+    5395,
+    5395,
+    5398,
+    5399,
+    5399,
+    5401,
+    5401,
+    5403,
+    5403,
+    5403,
+    5398,
+    5407,
+    5407,
+    5394,
+    5410,
+    5410,
+    5412,
+    5412,
+    5412,
+    5387,
+    5416,
+    5417,
+    5416,
+    5416,
+    5420,
+    5420,
+    5422,
+    5422,
+    5416,
+    5425,
+    5425,
+    5427,
+    5425,
+    5429,
+    5429,
+    5431,
+    5432,
+    5432,
+    5434,
+    5435,
+    5435,
+    5434,
+    5438,
+    5438,
+    5440,
+    5440,
+    5442,
+    5442,
+    5444,
+    5445,
+    5425,
+    5447,
+    5425,
+    5449,
+    5449,
+    5451,
+    5452,
+    5452,
+    5452,
+    5452,
+    5456,
+    5457,
+    5457,
+    5459,
+    5460,
+    5460,
+    5457,
+    5457,
+    5459,
+    5465,
+    5466,
+    5456,
+    5468,
+    5469,
+    5469,
+    5471,
+    5472,
+    5472,
+    5474,
+    5474,
+    5476,
+    5477,
+    5478,
+    5471,
+    5477,
+    5481,
+    5482,
+    5482,
+    5484,
+    5484,
+    5486,
+    5484,
+    5484,
+    5484,
+    5490,
+    5491,
+    5491,
+    5491,
+    5491,
+    5495,
+    5496,
+    5496,
+    5498,
+    5498,
+    5500,
+    5500,
+    5502,
+    5500,
+    5504,
+    5504,
+    5506,
+    5506,
+    5508,
+    5508,
+    5508,
+    5508,
+    5512,
+    5504,
+    5514,
+    5504,
+    5504,
+    5517,
+    5386,
+    5519,
+    5520,
+    5521,
+    5522,
+    5522,
+    5524,
+    5524,
+    5522,
+    5527,
+    5527,
+    5522,
+    5530,
+    5530,
+    5530,
+    5533,
+    5521,
+    5535,
+    5535,
+    5537,
+    5537,
+    5539,
+    5535,
+    5535,
+    5535,
+    5543,
+    5544,
+    5544,
+    5546,
+    5547,
+    5546,
+    5549,
+    5549,
+    5551,
+    5552,
+    5553,
+    5554,
+    5555,
+    5556,
+    5555,
+    5558,
+    5559,
+    5555,
+    5561,
+    5546,
+    5563,
+    5564,
+    5564,
+    5566,
+    5566,
+    5564,
+    5569,
+    5570,
+    5570,
+    5572,
+    5573,
+    5573,
+    5575,
+    5573,
+    5577,
+    5577,
+    5577,
+    5580,
+    5581,
+    5582,
+    5583,
+    5583,
+    5585,
+    5586,
+    5587,
+    5587,
+    5589,
+    5589,
+    5589,
+    5589,
+    5585,
+    5594,
+    5595,
+    5596,
+    5597,
+    5597,
+    5599,
+    5600,
+    5600,
+    5602,
+    5602,
+    5604,
+    5605,
+    5606,
+    5606,
+    5605,
+    5609,
+    5609,
+    5582,
+    5612,
+    5613,
+    5614,
+    5614,
+    5616,
+    5617,
+    5618,
+    5618,
+    5620,
+    5621,
+    5622,
+    5622,
+    5621,
+    5620,
+    5626,
+    5627,
+    5628,
+    5628,
+    5630,
+    5630,
+    5628,
+    5628,
+    5628,
+    5620,
+    5636,
+    5636,
+    5638,
+    5638,
+    5640,
+    5640,
+    5642,
+    5642,
+    5644,
+    5645,
+    5642,
+    5647,
+    5648,
+    5649,
+    5650,
+    5650,
+    5652,
+    5652,
+    5650,
+    5655,
+    5655,
+    5657,
+    5658,
+    5659,
+    5660,
+    5661,
+    5662,
+    5661,
+    5660,
+    5655,
+    5666,
+    5667,
+    5668,
+    5668,
+    5670,
+    5670,
+    5668,
+    5655,
+    5655,
+    5675,
+    5676,
+    5676,
+    5676,
+    5676,
+    5680,
+    5667,
+    5682,
+    5682,
+    5682,
+    5685,
+    5685,
+    5682,
+    5688,
+    5689,
+    5689,
+    5691,
+    5692,
+    5693,
+    5694,
+    5695,
+    5696,
+    5696,
+    5698,
+    5699,
+    5700,
+    5700,
+    5702,
+    5702,
+    5704,
+    5705,
+    5704,
+    5707,
+    5708,
+    5709,
+    5708,
+    5711,
+    5711,
+    5713,
+    5713,
+    5715,
+    5715,
+    5717,
+    5718,
+    5718,
+    5720,
+    5720,
+    5722,
+    5717,
+    5724,
+    5724,
+    5726,
+    5727,
+    5728,
+    5728,
+    5730,
+    5731,
+    5731,
+    5733,
+    5734,
+    5735,
+    5736,
+    5737,
+    5738,
+    5739,
+    5739,
+    5741,
+    5742,
+    5742,
+    5744,
+    5745,
+    5745,
+    5747,
+    5747,
+    5749,
+    5749,
+    5751,
+    5751,
+    5753,
+    5754,
+    5755,
+    5754,
+    5757,
+    5758,
+    5759,
+    5759,
+    5761,
+    5761,
+    5737,
+    5764,
+    5765,
+    5765,
+    5767,
+    5768,
+    5769,
+    5770,
+    5769,
+    5772,
+    5773,
+    5773,
+    5775,
+    5772,
+    5777,
+    5778,
+    5778,
+    5780,
+    5781,
+    5782,
+    5782,
+    5784,
+    5785,
+    5785,
+    5787,
+    5787,
+    5789,
+    5789,
+    5791,
+    5792,
+    5793,
+    5794,
+    5795,
+    5792,
+    5797,
+    5797,
+    5799,
+    5800,
+    5801,
+    5802,
+    5803,
+    5804,
+    5805,
+    5806,
+    5807,
+    5808,
+    5809,
+    5809,
+    5811,
+    5811,
+    5813,
+    5814,
+    5814,
+    5816,
+    5813,
+    5818,
+    5818,
+    5820,
+    5820,
+    5822,
+    5822,
+    5820,
+    5820,
+    5826,
+    5827,
+    5827,
+    5827,
+    5830,
+    5831,
+    5832,
+    5832,
+    5834,
+    5834,
+    5832,
+    5831,
+    5838,
+    5838,
+    5840,
+    5838,
+    5842,
+    5830,
+    5844,
+    5845,
+    5846,
+    5846,
+    5848,
+    5848,
+    5850,
+    5850,
+    5852,
+    5853,
+    5850,
+    5850,
+    5856,
+    5857,
+    5857,
+    5859,
+    5845,
+    5861,
+    5862,
+    5863,
+    5864,
+    5864,
+    5864,
+    5867,
+    5868,
+    5868,
+    5868,
+    5871,
+    5871,
+    5873,
+    5874,
+    5875,
+    5875,
+    5877,
+    5878,
+    5878,
+    5880,
+    5880,
+    5882,
+    5878,
+    5884,
+    5884,
+    5884,
+    5878,
+    5888,
+    5863,
+    5890,
+    5890,
+    5892,
+    5893,
+    5894,
+    5894,
+    5896,
+    5897,
+    5897,
+    5894,
+    5900,
+    5900,
+    5893,
+    5903,
+    5904,
+    5904,
+    5906,
+    5906,
+    5908,
+    5908,
+    5910,
+    5910,
+    5912,
+    5913,
+    5913,
+    5915,
+    5915,
+    5906,
+    5912,
+    5919,
+    5919,
+    5921,
+    5922,
+    5923,
+    5923,
+    5925,
+    5925,
+    5922,
+    5928,
+    5928,
+    5930,
+    5930,
+    5932,
+    5921,
+    5934,
+    5935,
+    5935,
+    5935,
+    5938,
+    5939,
+    5939,
+    5941,
+    5941,
+    5941,
+    5944,
+    5945,
+    5945,
+    5945,
+    5948,
+    5949,
+    5941,
+    5951,
+    5951,
+    5951,
+    5954,
+    5955,
+    5956,
+    5956,
+    5956,
+    5956,
+    5960,
+    5960,
+    5962,
+    5962,
+    5964,
+    5964,
+    5964,
+    5967,
+    5964,
+    5969,
+    5969,
+    5971,
+    5971,
+    5973,
+    5974,
+    5973,
+    5938,
+    5977,
+    5977,
+    5977,
+    5980,
+    5977,
+    5982,
+    5983,
+    5807,
+    5985,
+    5985,
+    5987,
+    5985,
+    5989,
+    5989,
+    5991,
+    5992,
+    5993,
+    5994,
+    5995,
+    5996,
+    5997,
+    5998,
+    5998,
+    5997,
+    6001,
+    6001,
+    6003,
+    6003,
+    6005,
+    6005,
+    6003,
+    6008,
+    6009,
+    6008,
+    6011,
+    6011,
+    6013,
+    6013,
+    6011,
+    6011,
+    6017,
+    6018,
+    6018,
+    6017,
+    6021,
+    6022,
+    6023,
+    6024,
+    6024,
+    6022,
+    6027,
+    6027,
+    6027,
+    6030,
+    6031,
+    6032,
+    6032,
+    6031,
+    6031,
+    6036,
+    6036,
+    6038,
+    6039,
+    6039,
+    6041,
+    6041,
+    6043,
+    6044,
+    6044,
+    6046,
+    6047,
+    6048,
+    6049,
+    6050,
+    6051,
+    6051,
+    6053,
+    6047,
+    6055,
+    6056,
+    6057,
+    6058,
+    6059,
+    6059,
+    6061,
+    6061,
+    6063,
+    6064,
+    6065,
+    6065,
+    6064,
+    6068,
+    6068,
+    6070,
+    6071,
+    6072,
+    6073,
+    6074,
+    6074,
+    6076,
+    6077,
+    6077,
+    6079,
+    6079,
+    6081,
+    6081,
+    6083,
+    6083,
+    6085,
+    6085,
+    6087,
+    6087,
+    6085,
+    6090,
+    6091,
+    6092,
+    6093,
+    6094,
+    6093,
+    6096,
+    6097,
+    6098,
+    6099,
+    6100,
+    6100,
+    6098,
+    6103,
+    6104,
+    6105,
+    6105,
+    6104,
+    6108,
+    6109,
+    6109,
+    6111,
+    6108,
+    6113,
+    6114,
+    6114,
+    6113,
+    6117,
+    6117,
+    6119,
+    6119,
+    6121,
+    6122,
+    6122,
+    6124,
+    6121,
+    6126,
+    6126,
+    6128,
+    6128,
+    6130,
+    6131,
+    6131,
+    6133,
+    6130,
+    6135,
+    6135,
+    6137,
+    6138,
+    6138,
+    6137,
+    6135,
+    6142,
+    6142,
+    6144,
+    6144,
+    6146,
+    6146,
+    6148,
+    6149,
+    6149,
+    6151,
+    6152,
+    6153,
+    6152,
+    6155,
+    6155,
+    6155,
+    6158,
+    6158,
+    6160,
+    6160,
+    6160,
+    6163,
+    6164,
+    6164,
+    6151,
+    6167,
+    6167,
+    6169,
+    6170,
+    6170,
+    6172,
+    6172,
+    6174,
+    6175,
+    6175,
+    6177,
+    6178,
+    6178,
+    6180,
+    6180,
+    6182,
+    6182,
+    6184,
+    6184,
+    6177,
+    6187,
+    6188,
+    6189,
+    6189,
+    6191,
+    6189,
+    6193,
+    6194,
+    6194,
+    6194,
+    6197,
+    6197,
+    6199,
+    6199,
+    6199,
+    6202,
+    6203,
+    6204,
+    6204,
+    6092,
+    6207,
+    6208,
+    6209,
+    6209,
+    6211,
+    6211,
+    6213,
+    6208,
+    6215,
+    6216,
+    6217,
+    6218,
+    6218,
+    6215,
+    6221,
+    6222,
+    6223,
+    6224,
+    6225,
+    6226,
+    6226,
+    6228,
+    6229,
+    6229,
+    6228,
+    6232,
+    6233,
+    6233,
+    6232,
+    6236,
+    6237,
+    6238,
+    6238,
+    6236,
+    6241,
+    6241,
+    6243,
+    6244,
+    6244,
+    6246,
+    6246,
+    6248,
+    6248,
+    6248,
+    6251,
+    6251,
+    6253,
+    6253,
+    6255,
+    6256,
+    6255,
+    6251,
+    6259,
+    6259,
+    6261,
+    6262,
+    6263,
+    6251,
+    6265,
+    6262,
+    6267,
+    6267,
+    6269,
+    6269,
+    6271,
+    6271,
+    6273,
+    6274,
+    6274,
+    6273,
+    6277,
+    6278,
+    6278,
+    6280,
+    6280,
+    6277,
+    6283,
+    6283,
+    6285,
+    6285,
+    6283,
+    6283,
+    6289,
+    6290,
+    6290,
+    6292,
+    6292,
+    6289,
+    6295,
+    6296,
+    6297,
+    6297,
+    6296,
+    6295,
+    6301,
+    6302,
+    6303,
+    6304,
+    6304,
+    6303,
+    6307,
+    6308,
+    6309,
+    6309,
+    6307,
+    6307,
+    6307,
+    6314,
+    6315,
+    6316,
+    6315,
+    6314,
+    6319,
+    6320,
+    6321,
+    6314,
+    6323,
+    6324,
+    6324,
+    6326,
+    6326,
+    6314,
+    6329,
+    6329,
+    6331,
+    6331,
+    6331,
+    6331,
+    6335,
+    6335,
+    6335,
+    6338,
+    6339,
+    6340,
+    6341,
+    6341,
+    6343,
+    6341,
+    6345,
+    6346,
+    6347,
+    6348,
+    6348,
+    6348,
+    6347,
+    6347,
+    6353,
+    6354,
+    6347,
+    6356,
+    6356,
+    6356,
+    6359,
+    6359,
+    6361,
+    6362,
+    6363,
+    6364,
+    6365,
+    6366,
+    6367,
+    6368,
+    6368,
+    6370,
+    6370,
+    6372,
+    6372,
+    6374,
+    6374,
+    6376,
+    6376,
+    6376,
+    6379,
+    6379,
+    6381,
+    6376,
+    6376,
+    6384,
+    6384,
+    6386,
+    6386,
+    6386,
+    6389,
+    6390,
+    6390,
+    6392,
+    6393,
+    6393,
+    6395,
+    6396,
+    6397,
+    6398,
+    6398,
+    6400,
+    6400,
+    6402,
+    6402,
+    6404,
+    6404,
+    6406,
+    6407,
+    6407,
+    6407,
+    6407,
+    6406,
+    6412,
+    6412,
+    6414,
+    6414,
+    6416,
+    6417,
+    6418,
+    6414,
+    6420,
+    6421,
+    6421,
+    6423,
+    6424,
+    6424,
+    6426,
+    6426,
+    6428,
+    6428,
+    6428,
+    6431,
+    6431,
+    6431,
+    6434,
+    6435,
+    6435,
+    6435,
+    6435,
+    6439,
+    6439,
+    6441,
+    6441,
+    6443,
+    6443,
+    6445,
+    6446,
+    6446,
+    6448,
+    6449,
+    6450,
+    6451,
+    6452,
+    6452,
+    6454,
+    6454,
+    6456,
+    6456,
+    6458,
+    6458,
+    6460,
+    6461,
+    6461,
+    6463,
+    6460,
+    6460,
+    6466,
+    6466,
+    6468,
+    6469,
+    6470,
+    6470,
+    6469,
+    6468,
+    6474,
+    6474,
+    6476,
+    6477,
+    6478,
+    6479,
+    6478,
+    6481,
+    6482,
+    6483,
+    6484,
+    6484,
+    6486,
+    6486,
+    6482,
+    6489,
+    6490,
+    6491,
+    6492,
+    6492,
+    6494,
+    6494,
+    6496,
+    6496,
+    6498,
+    6498,
+    6500,
+    6501,
+    6501,
+    6503,
+    6503,
+    6505,
+    6505,
+    6507,
+    6508,
+    6509,
+    6509,
+    6511,
+    6512,
+    6513,
+    6514,
+    6515,
+    6516,
+    6517,
+    6517,
+    6519,
+    6520,
+    6520,
+    6522,
+    6522,
+    6520,
+    6525,
+    6526,
+    6526,
+    6525,
+    6529,
+    6529,
+    6531,
+    6532,
+    6532,
+    6534,
+    6534,
+    6536,
+    6536,
+    6538,
+    6539,
+    6539,
+    6541,
+    6542,
+    6543,
+    6515,
+    6545,
+    6546,
+    6547,
+    6547,
+    6549,
+    6549,
+    6551,
+    6552,
+    6552,
+    6554,
+    6555,
+    6551,
+    6557,
+    6558,
+    6558,
+    6560,
+    6560,
+    6562,
+    6562,
+    6564,
+    6564,
+    6562,
+    6567,
+    6568,
+    6568,
+    6568,
+    6571,
+    6572,
+    6572,
+    6572,
+    6572,
+    6576,
+    6576,
+    6576,
+    6576,
+    6580,
+    6581,
+    6581,
+    6580,
+    6584,
+    6584,
+    6580,
+    6580,
+    6567,
+    6589,
+    6590,
+    6590,
+    6592,
+    6592,
+    6594,
+    6594,
+    6594,
+    6597,
+    6597,
+    6597,
+    6590,
+    6601,
+    6601,
+    6603,
+    6604,
+    6601,
+    6606,
+    6589,
+    6608,
+    6608,
+    6610,
+    6611,
+    6612,
+    6612,
+    6611,
+    6615,
+    6615,
+    6617,
+    6618,
+    6619,
+    6619,
+    6621,
+    6621,
+    6623,
+    6623,
+    6621,
+    6626,
+    6627,
+    6627,
+    6627,
+    6630,
+    6631,
+    6617,
+    6633,
+    6633,
+    6635,
+    6633,
+    6637,
+    6637,
+    6639,
+    6637,
+    6641,
+    6642,
+    6643,
+    6643,
+    6645,
+    6645,
+    6645,
+    6648,
+    6649,
+    6650,
+    6651,
+    6651,
+    6651,
+    6650,
+    6655,
+    6656,
+    6656,
+    6658,
+    6655,
+    6660,
+    6661,
+    6661,
+    6663,
+    6663,
+    6661,
+    6661,
+    6667,
+    6667,
+    6669,
+    6669,
+    6671,
+    6672,
+    6669,
+    6674,
+    6675,
+    6675,
+    6669,
+    6678,
+    6679,
+    6680,
+    6681,
+    6681,
+    6679,
+    6684,
+    6684,
+    6686,
+    6686,
+    6686,
+    6689,
+    6689,
+    6686,
+    6692,
+    6692,
+    6694,
+    6694,
+    6694,
+    6697,
+    6698,
+    6698,
+    6700,
+    6700,
+    6700,
+    6703,
+    6703,
+    6705,
+    6706,
+    6707,
+    6706,
+    6709,
+    6710,
+    6711,
+    6712,
+    6713,
+    6714,
+    6714,
+    6716,
+    6717,
+    6718,
+    6718,
+    6717,
+    6721,
+    6717,
+    6723,
+    6724,
+    6725,
+    6725,
+    6725,
+    6728,
+    6728,
+    6730,
+    6731,
+    6731,
+    6733,
+    6734,
+    6734,
+    6736,
+    6736,
+    6736,
+    6739,
+    6740,
+    6740,
+    6739,
+    6743,
+    6743,
+    6739,
+    6746,
+    6746,
+    6748,
+    6748,
+    6750,
+    6750,
+    6752,
+    6752,
+    6750,
+    6755,
+    6756,
+    6756,
+    6758,
+    6758,
+    6756,
+    6761,
+    6762,
+    6763,
+    6763,
+    6762,
+    6766,
+    6767,
+    6767,
+    6767,
+    6770,
+    6771,
+    6772,
+    6772,
+    6774,
+    6775,
+    6775,
+    6775,
+    6775,
+    6779,
+    6780,
+    6781,
+    6781,
+    6780,
+    6784,
+    6785,
+    6785,
+    6787,
+    6788,
+    6789,
+    6790,
+    6790,
+    6790,
+    6790,
+    6794,
+    6795,
+    6795,
+    6797,
+    6797,
+    6795,
+    6800,
+    6801,
+    6801,
+    6803,
+    6804,
+    6801,
+    6806,
+    6794,
+    6808,
+    6809,
+    6809,
+    6811,
+    6811,
+    6813,
+    6813,
+    6815,
+    6816,
+    6816,
+    6818,
+    6813,
+    6820,
+    6820,
+    6820,
+    6823,
+    6824,
+    6824,
+    6826,
+    6827,
+    6828,
+    6828,
+    6828,
+    6831,
+    6832,
+    6832,
+    6828,
+    6828,
+    6827,
+    6823,
+    6838,
+    6838,
+    6838,
+    6838,
+    6842,
+    6843,
+    6844,
+    6845,
+    6843,
+    6843,
+    6848,
+    6849,
+    6849,
+    6851,
+    6851,
+    6851,
+    6851,
+    6842,
+    6856,
+    6856,
+    6858,
+    6858,
+    6856,
+    6856,
+    6862,
+    6863,
+    6864,
+    6864,
+    6866,
+    6862,
+    6868,
+    6869,
+    6869,
+    6871,
+    6871,
+    6873,
+    6874,
+    6871,
+    6868,
+    6877,
+    6877,
+    6877,
+    6877,
+    6881,
+    6881,
+    6883,
+    6883,
+    6885,
+    6886,
+    6886,
+    6888,
+    6888,
+    6890,
+    6891,
+    6891,
+    6893,
+    6885,
+    6895,
+    6896,
+    6897,
+    6897,
+    6897,
+    6897,
+    6897,
+    6902,
+    6902,
+    6904,
+    6904,
+    6906,
+    6906,
+    6908,
+    6908,
+    6910,
+    6911,
+    6904,
+    6913,
+    6904,
+    6915,
+    6916,
+    6917,
+    6918,
+    6919,
+    6919,
+    6921,
+    6921,
+    6923,
+    6924,
+    6919,
+    6926,
+    6927,
+    6927,
+    6929,
+    6929,
+    6931,
+    6931,
+    6933,
+    6934,
+    6935,
+    6936,
+    6937,
+    6938,
+    6939,
+    6940,
+    6941,
+    6942,
+    6943,
+    6943,
+    6945,
+    6945,
+    6941,
+    6941,
+    6949,
+    6949,
+    6951,
+    6952,
+    6952,
+    6951,
+    6955,
+    6955,
+    6957,
+    6958,
+    6959,
+    6960,
+    6951,
+    6962,
+    6962,
+    6951,
+    6965,
+    6966,
+    6967,
+    6967,
+    6969,
+    6969,
+    6969,
+    6969,
+    6973,
+    6974,
+    6975,
+    6976,
+    6977,
+    6977,
+    6979,
+    6977,
+    6981,
+    6982,
+    6982,
+    6984,
+    6984,
+    6986,
+    6982,
+    6982,
+    6986,
+    6982,
+    6991,
+    6991,
+    6993,
+    6993,
+    6991,
+    6991,
+    6997,
+    6991,
+    6999,
+    7000,
+    7001,
+    7001,
+    7000,
+    7004,
+    7004,
+    7006,
+    7006,
+    7008,
+    7008,
+    7010,
+    7010,
+    6999,
+    7013,
+    7014,
+    7015,
+    7014,
+    7017,
+    7018,
+    7018,
+    7020,
+    7021,
+    7021,
+    7020,
+    7024,
+    7024,
+    7026,
+    7026,
+    7017,
+    7029,
+    7030,
+    7031,
+    7032,
+    7032,
+    7034,
+    7017,
+    7017,
+    7037,
+    7038,
+    7039,
+    7038,
+    7038,
+    7039,
+    7043,
+    7044,
+    7043,
+    7013,
+    7047,
+    7048,
+    7048,
+    7047,
+    7051,
+    7051,
+    7051,
+    7054,
+    7055,
+    7056,
+    7055,
+    7058,
+    7059,
+    7060,
+    7059,
+    7062,
+    7063,
+    7064,
+    7065,
+    7066,
+    7067,
+    7068,
+    7069,
+    7068,
+    7071,
+    7071,
+    7073,
+    7074,
+    7075,
+    7075,
+    7077,
+    7077,
+    7075,
+    7064,
+    7081,
+    7082,
+    7082,
+    7084,
+    7084,
+    7086,
+    7086,
+    7084,
+    7089,
+    7089,
+    7089,
+    7092,
+    7092,
+    7094,
+    7095,
+    7095,
+    7097,
+    7097,
+    7097,
+    7100,
+    7101,
+    7101,
+    7101,
+    7100,
+    7105,
+    7105,
+    7107,
+    7107,
+    7105,
+    7110,
+    7111,
+    7112,
+    7112,
+    7110,
+    7115,
+    7115,
+    7117,
+    7117,
+    7119,
+    7119,
+    7110,
+    7122,
+    7122,
+    7124,
+    7124,
+    7126,
+    7126,
+    7128,
+    7128,
+    7130,
+    7131,
+    7132,
+    7131,
+    7134,
+    7135,
+    7135,
+    7135,
+    7135,
+    7135,
+    7140,
+    7141,
+    7142,
+    7143,
+    7143,
+    7142,
+    7146,
+    7146,
+    7146,
+    7149,
+    7150,
+    7151,
+    7152,
+    7149,
+    7154,
+    7155,
+    7155,
+    7155,
+    7154,
+    7154,
+    7160,
+    7161,
+    7162,
+    7163,
+    7164,
+    7163,
+    7166,
+    7166,
+    7168,
+    7141,
+    7170,
+    7171,
+    7171,
+    7173,
+    7173,
+    7175,
+    7170,
+    7177,
+    7177,
+    7179,
+    7180,
+    7181,
+    7181,
+    7183,
+    7183,
+    7185,
+    7186,
+    7187,
+    7187,
+    7185,
+    7190,
+    7185,
+    7192,
+    7192,
+    7162,
+    7195,
+    7195,
+    7197,
+    7198,
+    7199,
+    7200,
+    7201,
+    7202,
+    7202,
+    7201,
+    7201,
+    7206,
+    7207,
+    7198,
+    7209,
+    7209,
+    7211,
+    7211,
+    7213,
+    7213,
+    7215,
+    7216,
+    7216,
+    7218,
+    7218,
+    7216,
+    7221,
+    7216,
+    7223,
+    7223,
+    7225,
+    7225,
+    7223,
+    7228,
+    7229,
+    7229,
+    7229,
+    7232,
+    7232,
+    7229,
+    7223,
+    7223,
+    7228,
+    7238,
+    7215,
+    7240,
+    7240,
+    7240,
+    7243,
+    7244,
+    7245,
+    7246,
+    7246,
+    7248,
+    7249,
+    7250,
+    7251,
+    7251,
+    7245,
+    7254,
+    7255,
+    7256,
+    7254,
+    7258,
+    7259,
+    7260,
+    7261,
+    7261,
+    7263,
+    7263,
+    7265,
+    7245,
+    7267,
+    7267,
+    7267,
+    7245,
+    7271,
+    7272,
+    7273,
+    7274,
+    7274,
+    7276,
+    7276,
+    7276,
+    7279,
+    7279,
+    7279,
+    7279,
+    7283,
+    7283,
+    7283,
+    7283,
+    7287,
+    7288,
+    7289,
+    7273,
+    7291,
+    7291,
+    7293,
+    7294,
+    7293,
+    7296,
+    7297,
+    7298,
+    7299,
+    7299,
+    7301,
+    7301,
+    7299,
+    7273,
+    7273,
+    7306,
+    7306,
+    7308,
+    7306,
+    7310,
+    7310,
+    7312,
+    7312,
+    7314,
+    7315,
+    7316,
+    7315,
+    7318,
+    7319,
+    7320,
+    7321,
+    7321,
+    7320,
+    7324,
+    7325,
+    7325,
+    7324,
+    7320,
+    7320,
+    7330,
+    7331,
+    7331,
+    7333,
+    7333,
+    7333,
+    7273,
+    7337,
+    7337,
+    7337,
+    7337,
+    7341,
+    7342,
+    7343,
+    7343,
+    7345,
+    7346,
+    7345,
+    7343,
+    7343,
+    7350,
+    7351,
+    7350,
+    7197,
+    7354,
+    7355,
+    7355,
+    7357,
+    7358,
+    7359,
+    7360,
+    7361,
+    7362,
+    7363,
+    7363,
+    7365,
+    7366,
+    7366,
+    7368,
+    7368,
+    7370,
+    7370,
+    7372,
+    7372,
+    7368,
+    7375,
+    7375,
+    7377,
+    7378,
+    7378,
+    7380,
+    7380,
+    7382,
+    7383,
+    7382,
+    7385,
+    7386,
+    7386,
+    7385,
+    7389,
+    7389,
+    7389,
+    7392,
+    7392,
+    7392,
+    7395,
+    7395,
+    7397,
+    7397,
+    7399,
+    7400,
+    7400,
+    7400,
+    7403,
+    7403,
+    7377,
+    7406,
+    7406,
+    7408,
+    7409,
+    7409,
+    7411,
+    7411,
+    7409,
+    7414,
+    7414,
+    7414,
+    7417,
+    7418,
+    7418,
+    7420,
+    7420,
+    7417,
+    7423,
+    7424,
+    7425,
+    7426,
+    7427,
+    7428,
+    7429,
+    7430,
+    7430,
+    7432,
+    7432,
+    7434,
+    7435,
+    7435,
+    7435,
+    7438,
+    7438,
+    7438,
+    7441,
+    7441,
+    7443,
+    7443,
+    7441,
+    7446,
+    7447,
+    7447,
+    7449,
+    7358,
+    7451,
+    7452,
+    7453,
+    7453,
+    7455,
+    7456,
+    7457,
+    7457,
+    7459,
+    7460,
+    7461,
+    7462,
+    7462,
+    7464,
+    7465,
+    7466,
+    7467,
+    7467,
+    7469,
+    7470,
+    7471,
+    7472,
+    7472,
+    7474,
+    7475,
+    7475,
+    7474,
+    7478,
+    7478,
+    7478,
+    7481,
+    7482,
+    7482,
+    7484,
+    7485,
+    7485,
+    7487,
+    7488,
+    7488,
+    7490,
+    7491,
+    7492,
+    7493,
+    7493,
+    7495,
+    7495,
+    7429,
+    7498,
+    7499,
+    7500,
+    7500,
+    7499,
+    7503,
+    7503,
+    7505,
+    7506,
+    7506,
+    7505,
+    7509,
+    7509,
+    7511,
+    7511,
+    7513,
+    7513,
+    7509,
+    7516,
+    7517,
+    7518,
+    7518,
+    7517,
+    7521,
+    7521,
+    7509,
+    7509,
+    7525,
+    7526,
+    7526,
+    7525,
+    7529,
+    7529,
+    7498,
+    7532,
+    7533,
+    7534,
+    7534,
+    7536,
+    7537,
+    7538,
+    7539,
+    7537,
+    7541,
+    7541,
+    7541,
+    7541,
+    7545,
+    7546,
+    7517,
+    7548,
+    7549,
+    7550,
+    7550,
+    7552,
+    7552,
+    7517,
+    7555,
+    7556,
+    7556,
+    7558,
+    7558,
+    7560,
+    7560,
+    7562,
+    7563,
+    7562,
+    7565,
+    7566,
+    7566,
+    7565,
+    7569,
+    7570,
+    7570,
+    7572,
+    7572,
+    7574,
+    7575,
+    7575,
+    7577,
+    7577,
+    7577,
+    7577,
+    7581,
+    7582,
+    7583,
+    7583,
+    7582,
+    7586,
+    7587,
+    7587,
+    7589,
+    7590,
+    7591,
+    7592,
+    7592,
+    7586,
+    7586,
+    7596,
+    7596,
+    7598,
+    7586,
+    7600,
+    7600,
+    7602,
+    7602,
+    7604,
+    7604,
+    7606,
+    7607,
+    7602,
+    7609,
+    7610,
+    7610,
+    7612,
+    7602,
+    7614,
+    7614,
+    7616,
+    7617,
+    7617,
+    7616,
+    7620,
+    7616,
+    7616,
+    7623,
+    7624,
+    7625,
+    7625,
+    7627,
+    7627,
+    7629,
+    7624,
+    7631,
+    7632,
+    7633,
+    7634,
+    7633,
+    7632,
+    7637,
+    7637,
+    7639,
+    7639,
+    7641,
+    7642,
+    7642,
+    7644,
+    7644,
+    7646,
+    7647,
+    7648,
+    7649,
+    7650,
+    7650,
+    7632,
+    7653,
+    7654,
+    7655,
+    7656,
+    7657,
+    7655,
+    7659,
+    7659,
+    7661,
+    7662,
+    7663,
+    7664,
+    7665,
+    7666,
+    7667,
+    7569,
+    7669,
+    7670,
+    7671,
+    7671,
+    7673,
+    7674,
+    7675,
+    7676,
+    7676,
+    7675,
+    7679,
+    7680,
+    7680,
+    7682,
+    7682,
+    7684,
+    7684,
+    7686,
+    7687,
+    7688,
+    7688,
+    7688,
+    7691,
+    7692,
+    7692,
+    7692,
+    7695,
+    7696,
+    7697,
+    7698,
+    7697,
+    7700,
+    7701,
+    7702,
+    7703,
+    7704,
+    7705,
+    7705,
+    7707,
+    7707,
+    7705,
+    7710,
+    7711,
+    7712,
+    7713,
+    7713,
+    7715,
+    7716,
+    7717,
+    7718,
+    7719,
+    7720,
+    7721,
+    7722,
+    7723,
+    7724,
+    7719,
+    7726,
+    7719,
+    7728,
+    7729,
+    7730,
+    7731,
+    7732,
+    7733,
+    7734,
+    7734,
+    7736,
+    7737,
+    7738,
+    7739,
+    7734,
+    7711,
+    7711,
+    7743,
+    7744,
+    7744,
+    7746,
+    7747,
+    7747,
+    7749,
+    7746,
+    7746,
+    7752,
+    7752,
+    7754,
+    7755,
+    7756,
+    7754,
+    7758,
+    7746,
+    7760,
+    7760,
+    7762,
+    7762,
+    7743,
+    7765,
+    7765,
+    7767,
+    7768,
+    7768,
+    7770,
+    7770,
+    7772,
+    7772,
+    7774,
+    7774,
+    7776,
+    7776,
+    7774,
+    7779,
+    7780,
+    7781,
+    7782,
+    7783,
+    7783,
+    7785,
+    7785,
+    7787,
+    7788,
+    7789,
+    7790,
+    7788,
+    7792,
+    7793,
+    7794,
+    7792,
+    7796,
+    7797,
+    7798,
+    7799,
+    7800,
+    7799,
+    7802,
+    7788,
+    7798,
+    7805,
+    7806,
+    7806,
+    7806,
+    7806,
+    7810,
+    7810,
+    7812,
+    7812,
+    7810,
+    7815,
+    7815,
+    7810,
+    7810,
+    7805,
+    7820,
+    7821,
+    7821,
+    7823,
+    7824,
+    7824,
+    7823,
+    7827,
+    7828,
+    7829,
+    7829,
+    7820,
+    7832,
+    7832,
+    7832,
+    7835,
+    7835,
+    7837,
+    7838,
+    7839,
+    7839,
+    7835,
+    7842,
+    7835,
+    7844,
+    7845,
+    7846,
+    7847,
+    7848,
+    7849,
+    7850,
+    7850,
+    7852,
+    7853,
+    7854,
+    7855,
+    7852,
+    7857,
+    7858,
+    7859,
+    7859,
+    7861,
+    7862,
+    7862,
+    7862,
+    7862,
+    7866,
+    7866,
+    7868,
+    7868,
+    7870,
+    7871,
+    7871,
+    7871,
+    7874,
+    7874,
+    7871,
+    7877,
+    7878,
+    7879,
+    7878,
+    7881,
+    7881,
+    7866,
+    7884,
+    7884,
+    7886,
+    7884,
+    7888,
+    7888,
+    7890,
+    7891,
+    7891,
+    7866,
+    7866,
+    7890,
+    7896,
+    7897,
+    7897,
+    7899,
+    7900,
+    7901,
+    7900,
+    7903,
+    7903,
+    7905,
+    7905,
+    7907,
+    7907,
+    7896,
+    7910,
+    7911,
+    7911,
+    7911,
+    7914,
+    7915,
+    7915,
+    7917,
+    7917,
+    7919,
+    7919,
+    7921,
+    7921,
+    7923,
+    7923,
+    7925,
+    7925,
+    7927,
+    7927,
+    7929,
+    7930,
+    7896,
+    7932,
+    7933,
+    7933,
+    7935,
+    7935,
+    7937,
+    7935,
+    7935,
+    7935,
+    7941,
+    7942,
+    7943,
+    7944,
+    7944,
+    7946,
+    7946,
+    7948,
+    7948,
+    7943,
+    7951,
+    7951,
+    7953,
+    7954,
+    7955,
+    7955,
+    7954,
+    7896,
+    7959,
+    7960,
+    7961,
+    7962,
+    7963,
+    7964,
+    7965,
+    7966,
+    7967,
+    7967,
+    7963,
+    7963,
+    7963,
+    7972,
+    7972,
+    7974,
+    7975,
+    7976,
+    7976,
+    7975,
+    7979,
+    7980,
+    7975,
+    7982,
+    7983,
+    7983,
+    7975,
+    7986,
+    7987,
+    7988,
+    7988,
+    7987,
+    7991,
+    7991,
+    7993,
+    7994,
+    7995,
+    7994,
+    7997,
+    7998,
+    7994,
+    7994,
+    8001,
+    8002,
+    8001,
+    8004,
+    8005,
+    8005,
+    8007,
+    8005,
+    8005,
+    8010,
+    8010,
+    8012,
+    8010,
+    8010,
+    8015,
+    8016,
+    8017,
+    8017,
+    8019,
+    8020,
+    8020,
+    8022,
+    8023,
+    8023,
+    8025,
+    8025,
+    8025,
+    8028,
+    8029,
+    8030,
+    8030,
+    8032,
+    8033,
+    8034,
+    8030,
+    8036,
+    8036,
+    8038,
+    8038,
+    8030,
+    8041,
+    8022,
+    8043,
+    8044,
+    8044,
+    8044,
+    8047,
+    8047,
+    8049,
+    8049,
+    8051,
+    8052,
+    8053,
+    8054,
+    8055,
+    8055,
+    8055,
+    8058,
+    8059,
+    8049,
+    8061,
+    8043,
+    8063,
+    8064,
+    8064,
+    8064,
+    8064,
+    8063,
+    8069,
+    8070,
+    8071,
+    8071,
+    8071,
+    8074,
+    8075,
+    8075,
+    8077,
+    8077,
+    8075,
+    8075,
+    8081,
+    8081,
+    8083,
+    8083,
+    8085,
+    8086,
+    8085,
+    8083,
+    8083,
+    8083,
+    8091,
+    8092,
+    8092,
+    8094,
+    8094,
+    8096,
+    8094,
+    8094,
+    8099,
+    8091,
+    8101,
+    8102,
+    8103,
+    8103,
+    8105,
+    8103,
+    8101,
+    8108,
+    8108,
+    8108,
+    8111,
+    8112,
+    8113,
+    8108,
+    8108,
+    8111,
+    8117,
+    8118,
+    8117,
+    8117,
+    8121,
+    8122,
+    8123,
+    8122,
+    8122,
+    8126,
+    8127,
+    8128,
+    8129,
+    8130,
+    8131,
+    8130,
+    8133,
+    8133,
+    8135,
+    8136,
+    8137,
+    8138,
+    8139,
+    8138,
+    8141,
+    8141,
+    8135,
+    8144,
+    8145,
+    8146,
+    8147,
+    8148,
+    8148,
+    8145,
+    8151,
+    8152,
+    8153,
+    8154,
+    8155,
+    8155,
+    8157,
+    8158,
+    8159,
+    8160,
+    8161,
+    8160,
+    8163,
+    8164,
+    8165,
+    8164,
+    8167,
+    8167,
+    8169,
+    8169,
+    8171,
+    8169,
+    8173,
+    8174,
+    8174,
+    8176,
+    8177,
+    8178,
+    8179,
+    8180,
+    8180,
+    8182,
+    8182,
+    8184,
+    8184,
+    8184,
+    8182,
+    8188,
+    8179,
+    8190,
+    8190,
+    8192,
+    8193,
+    8193,
+    8195,
+    8195,
+    8195,
+    8198,
+    8198,
+    8200,
+    8200,
+    8200,
+    8203,
+    8204,
+    8204,
+    8206,
+    8207,
+    8207,
+    8206,
+    8210,
+    8211,
+    8212,
+    8212,
+    8211,
+    8215,
+    8216,
+    8217,
+    8218,
+    8219,
+    8220,
+    8221,
+    8222,
+    8221,
+    8224,
+    8224,
+    8226,
+    8227,
+    8224,
+    8224,
+    8230,
+    8231,
+    8231,
+    8231,
+    8231,
+    8235,
+    8235,
+    8237,
+    8237,
+    8239,
+    8240,
+    8240,
+    8242,
+    8243,
+    8244,
+    8245,
+    8243,
+    8247,
+    8248,
+    8249,
+    8249,
+    8248,
+    8252,
+    8252,
+    8252,
+    8255,
+    8256,
+    8256,
+    8258,
+    8259,
+    8259,
+    8261,
+    8262,
+    8258,
+    8264,
+    8265,
+    8266,
+    8267,
+    8268,
+    8269,
+    8269,
+    8271,
+    8272,
+    8272,
+    8274,
+    8275,
+    8276,
+    8274,
+    8274,
+    8276,
+    8271,
+    8271,
+    8282,
+    8282,
+    8284,
+    8284,
+    8282,
+    8271,
+    8288,
+    8289,
+    8289,
+    8291,
+    8292,
+    8293,
+    8294,
+    8293,
+    8296,
+    8297,
+    8298,
+    8299,
+    8299,
+    8292,
+    8302,
+    8303,
+    8304,
+    8304,
+    8306,
+    8303,
+    8308,
+    8308,
+    8310,
+    8310,
+    8312,
+    8313,
+    8313,
+    8315,
+    8316,
+    8317,
+    8318,
+    8318,
+    8320,
+    8318,
+    8322,
+    8322,
+    8322,
+    8325,
+    8322,
+    8322,
+    8328,
+    8318,
+    8330,
+    8331,
+    8331,
+    8333,
+    8333,
+    8331,
+    8336,
+    8336,
+    8336,
+    8339,
+    8336,
+    8341,
+    8331,
+    8343,
+    8344,
+    8344,
+    8346,
+    8347,
+    8347,
+    8291,
+    8350,
+    8350,
+    8352,
+    8353,
+    8354,
+    8355,
+    8356,
+    8355,
+    8355,
+    8359,
+    8360,
+    8361,
+    8362,
+    8363,
+    8361,
+    8365,
+    8361,
+    8367,
+    8368,
+    8369,
+    8369,
+    8371,
+    8371,
+    8371,
+    8374,
+    8374,
+    8376,
+    8376,
+    8378,
+    8378,
+    8380,
+    8380,
+    8382,
+    8382,
+    8384,
+    8385,
+    8386,
+    8387,
+    8387,
+    8386,
+    8384,
+    8391,
+    8392,
+    8393,
+    8394,
+    8394,
+    8396,
+    8397,
+    8397,
+    8399,
+    8400,
+    8400,
+    8402,
+    8402,
+    8400,
+    8405,
+    8400,
+    8407,
+    8408,
+    8409,
+    8410,
+    8409,
+    8412,
+    8413,
+    8412,
+    8396,
+    8416,
+    8417,
+    8417,
+    8419,
+    8419,
+    8416,
+    8422,
+    8423,
+    8422,
+    8425,
+    8422,
+    8427,
+    8428,
+    8428,
+    8430,
+    8431,
+    8432,
+    8432,
+    8434,
+    8434,
+    8436,
+    8436,
+    8438,
+    8439,
+    8440,
+    8440,
+    8442,
+    8443,
+    8444,
+    8444,
+    8444,
+    8440,
+    8440,
+    8449,
+    8450,
+    8451,
+    8451,
+    8453,
+    8454,
+    8455,
+    8456,
+    8457,
+    8458,
+    8458,
+    8460,
+    8461,
+    8462,
+    8462,
+    8456,
+    8465,
+    8466,
+    8467,
+    8467,
+    8466,
+    8470,
+    8470,
+    8470,
+    8473,
+    8474,
+    8474,
+    8476,
+    8476,
+    8476,
+    8479,
+    8479,
+    8481,
+    8481,
+    8473,
+    8484,
+    8485,
+    8486,
+    8487,
+    8488,
+    8488,
+    8490,
+    8490,
+    8492,
+    8492,
+    8494,
+    8494,
+    8496,
+    8497,
+    8497,
+    8499,
+    8499,
+    8499,
+    8502,
+    8502,
+    8504,
+    8504,
+    8506,
+    8507,
+    8507,
+    8507,
+    8507,
+    8511,
+    8511,
+    8496,
+    8514,
+    8515,
+    8516,
+    8517,
+    8517,
+    8519,
+    8520,
+    8520,
+    8520,
+    8520,
+    8524,
+    8524,
+    8526,
+    8527,
+    8526,
+    8529,
+    8529,
+    8529,
+    8529,
+    8529,
+    8534,
+    8535,
+    8536,
+    8537,
+    8536,
+    8539,
+    8539,
+    8539,
+    8536,
+    8543,
+    8544,
+    8544,
+    8544,
+    8547,
+    8547,
+    8549,
+    8550,
+    8550,
+    8549,
+    8553,
+    8554,
+    8555,
+    8556,
+    8556,
+    8558,
+    8544,
+    8560,
+    8560,
+    8562,
+    8563,
+    8563,
+    8565,
+    8565,
+    8567,
+    8567,
+    8569,
+    8565,
+    8565,
+    8565,
+    8573,
+    8574,
+    8574,
+    8514,
+    8577,
+    8577,
+    8579,
+    8577,
+    8581,
+    8582,
+    8583,
+    8584,
+    8585,
+    8585,
+    8585,
+    8588,
+    8588,
+    8588,
+    8591,
+    8591,
+    8593,
+    8594,
+    8594,
+    8596,
+    8597,
+    8597,
+    8599,
+    8599,
+    8599,
+    8602,
+    8602,
+    8604,
+    8602,
+    8606,
+    8606,
+    8606,
+    8609,
+    8610,
+    8602,
+    8612,
+    8613,
+    8614,
+    8615,
+    8616,
+    8617,
+    8618,
+    8618,
+    8620,
+    8621,
+    8622,
+    8623,
+    8624,
+    8625,
+    8625,
+    8623,
+    8628,
+    8629,
+    8630,
+    8631,
+    8632,
+    8633,
+    8634,
+    8635,
+    8636,
+    8637,
+    8638,
+    8639,
+    8640,
+    8641,
+    8642,
+    8642,
+    8644,
+    8645,
+    8646,
+    8647,
+    8647,
+    8649,
+    8649,
+    8651,
+    8652,
+    8653,
+    8651,
+    8655,
+    8656,
+    8656,
+    8658,
+    8658,
+    8660,
+    8660,
+    8662,
+    8663,
+    8664,
+    8664,
+    8666,
+    8667,
+    8667,
+    8669,
+    8669,
+    8646,
+    8672,
+    8672,
+    8674,
+    8674,
+    8676,
+    8676,
+    8678,
+    8678,
+    8680,
+    8681,
+    8682,
+    8682,
+    8681,
+    8685,
+    8685,
+    8687,
+    8688,
+    8680,
+    8690,
+    8691,
+    8692,
+    8692,
+    8694,
+    8694,
+    8696,
+    8696,
+    8698,
+    8699,
+    8699,
+    8701,
+    8701,
+    8703,
+    8703,
+    8705,
+    8706,
+    8707,
+    8708,
+    8708,
+    8706,
+    8711,
+    8712,
+    8712,
+    8714,
+    8711,
+    8716,
+    8716,
+    8718,
+    8718,
+    8720,
+    8721,
+    8721,
+    8720,
+    8724,
+    8724,
+    8726,
+    8727,
+    8727,
+    8729,
+    8729,
+    8731,
+    8732,
+    8733,
+    8734,
+    8735,
+    8735,
+    8735,
+    8738,
+    8739,
+    8739,
+    8741,
+    8742,
+    8743,
+    8744,
+    8745,
+    8746,
+    8747,
+    8748,
+    8749,
+    8750,
+    8751,
+    8751,
+    8753,
+    8754,
+    8755,
+    8755,
+    8757,
+    8757,
+    8759,
+    8760,
+    8761,
+    8762,
+    8762,
+    8764,
+    8765,
+    8761,
+    8767,
+    8767,
+    8769,
+    8770,
+    8770,
+    8772,
+    8772,
+    8774,
+    8775,
+    8775,
+    8775,
+    8774,
+    8779,
+    8779,
+    8781,
+    8782,
+    8783,
+    8783,
+    8785,
+    8786,
+    8785,
+    8788,
+    8789,
+    8790,
+    8791,
+    8790,
+    8789,
+    8794,
+    8795,
+    8796,
+    8797,
+    8798,
+    8798,
+    8800,
+    8800,
+    8800,
+    8797,
+    8804,
+    8805,
+    8805,
+    8804,
+    8808,
+    8808,
+    8810,
+    8810,
+    8812,
+    8813,
+    8814,
+    8814,
+    8814,
+    8817,
+    8817,
+    8819,
+    8820,
+    8821,
+    8822,
+    8823,
+    8824,
+    8825,
+    8826,
+    8827,
+    8827,
+    8829,
+    8825,
+    8831,
+    8832,
+    8832,
+    8834,
+    8835,
+    8835,
+    8837,
+    8837,
+    8831,
+    8840,
+    8841,
+    8842,
+    8843,
+    8841,
+    8840,
+    8846,
+    8847,
+    8847,
+    8849,
+    8849,
+    8851,
+    8852,
+    8852,
+    8854,
+    8855,
+    8851,
+    8857,
+    8858,
+    8858,
+    8860,
+    8861,
+    8861,
+    8863,
+    8863,
+    8865,
+    8860,
+    8860,
+    8868,
+    8869,
+    8860,
+    8871,
+    8846,
+    8873,
+    8874,
+    8875,
+    8876,
+    8877,
+    8877,
+    8876,
+    8880,
+    8881,
+    8882,
+    8883,
+    8884,
+    8884,
+    8886,
+    8887,
+    8888,
+    8887,
+    8890,
+    8891,
+    8892,
+    8892,
+    8891,
+    8895,
+    8896,
+    8897,
+    8886,
+    8899,
+    8899,
+    8901,
+    8902,
+    8903,
+    8904,
+    8905,
+    8902,
+    8875,
+    8908,
+    8909,
+    8909,
+    8911,
+    8912,
+    8912,
+    8911,
+    8915,
+    8916,
+    8917,
+    8916,
+    8917,
+    8920,
+    8921,
+    8922,
+    8921,
+    8924,
+    8924,
+    8926,
+    8927,
+    8927,
+    8921,
+    8930,
+    8921,
+    8932,
+    8932,
+    8926,
+    8935,
+    8936,
+    8936,
+    8938,
+    8939,
+    8939,
+    8941,
+    8942,
+    8935,
+    8944,
+    8945,
+    8945,
+    8947,
+    8945,
+    8945,
+    8950,
+    8935,
+    8952,
+    8942,
+    8954,
+    8954,
+    8956,
+    8957,
+    8957,
+    8959,
+    8956,
+    8961,
+    8961,
+    8963,
+    8964,
+    8964,
+    8964,
+    8961,
+    8968,
+    8969,
+    8970,
+    8971,
+    8972,
+    8969,
+    8974,
+    8975,
+    8976,
+    8977,
+    8978,
+    8979,
+    8979,
+    8978,
+    8978,
+    8978,
+    8984,
+    8985,
+    8984,
+    8987,
+    8987,
+    8989,
+    8990,
+    8990,
+    8992,
+    8993,
+    8994,
+    8995,
+    8995,
+    8997,
+    8994,
+    8999,
+    9000,
+    8994,
+    9002,
+    9003,
+    9003,
+    8993,
+    9006,
+    9007,
+    9007,
+    9009,
+    9010,
+    9010,
+    9009,
+    9013,
+    9014,
+    9014,
+    9013,
+    9017,
+    9017,
+    9019,
+    9020,
+    9020,
+    9019,
+    9019,
+    9024,
+    9025,
+    9025,
+    9024,
+    9028,
+    9029,
+    9029,
+    9028,
+    9032,
+    9033,
+    9032,
+    9035,
+    9036,
+    9036,
+    9038,
+    9039,
+    9038,
+    9032,
+    9042,
+    9043,
+    9044,
+    9044,
+    9043,
+    9043,
+    9048,
+    9049,
+    9050,
+    9050,
+    9052,
+    9053,
+    9053,
+    9055,
+    9055,
+    9057,
+    9058,
+    9048,
+    9060,
+    9061,
+    9062,
+    9063,
+    9063,
+    9065,
+    9065,
+    9067,
+    9067,
+    9067,
+    9070,
+    9071,
+    9070,
+    9073,
+    9074,
+    9075,
+    9076,
+    9076,
+    9078,
+    9079,
+    9080,
+    9080,
+    9078,
+    9078,
+    9078,
+    9085,
+    9086,
+    9086,
+    9088,
+    9089,
+    9090,
+    9091,
+    9089,
+    9093,
+    9093,
+    9095,
+    9096,
+    9096,
+    9098,
+    9099,
+    9100,
+    9100,
+    9102,
+    9102,
+    9104,
+    9105,
+    9099,
+    9107,
+    9108,
+    9109,
+    9109,
+    9111,
+    9112,
+    9112,
+    9114,
+    9115,
+    9115,
+    9115,
+    9114,
+    9119,
+    9120,
+    9121,
+    9121,
+    9123,
+    9123,
+    9125,
+    9126,
+    9127,
+    9128,
+    9129,
+    9111,
+    9131,
+    9132,
+    9132,
+    9131,
+    9135,
+    9135,
+    9137,
+    9137,
+    9139,
+    9139,
+    9141,
+    9142,
+    9142,
+    9142,
+    9145,
+    9145,
+    9147,
+    9147,
+    9149,
+    9150,
+    9150,
+    9149,
+    9153,
+    9153,
+    9153,
+    9156,
+    9157,
+    9156,
+    9159,
+    9159,
+    9161,
+    9161,
+    9163,
+    9163,
+    9163,
+    9166,
+    9166,
+    9168,
+    9168,
+    9170,
+    9170,
+    9172,
+    9172,
+    9172,
+    9172,
+    9176,
+    9176,
+    9178,
+    9179,
+    9179,
+    9179,
+    9182,
+    9183,
+    9184,
+    9183,
+    9186,
+    9186,
+    9182,
+    9189,
+    9190,
+    9190,
+    9190,
+    9193,
+    9193,
+    9195,
+    9195,
+    9197,
+    9197,
+    9199,
+    9199,
+    9201,
+    9199,
+    9203,
+    9204,
+    9205,
+    9205,
+    9207,
+    9208,
+    9209,
+    9210,
+    9211,
+    9212,
+    9212,
+    9214,
+    9214,
+    9216,
+    9216,
+    9216,
+    9219,
+    9220,
+    9221,
+    9222,
+    9219,
+    9224,
+    9225,
+    9225,
+    9227,
+    9227,
+    9229,
+    9230,
+    9230,
+    9230,
+    9233,
+    9233,
+    9235,
+    9235,
+    9237,
+    9237,
+    9239,
+    9240,
+    9240,
+    9242,
+    9240,
+    9244,
+    9245,
+    9245,
+    9247,
+    9247,
+    9247,
+    9250,
+    9250,
+    9250,
+    9253,
+    9253,
+    9255,
+    9256,
+    9257,
+    9258,
+    9259,
+    9260,
+    9260,
+    9262,
+    9263,
+    9263,
+    9265,
+    9262,
+    9267,
+    9268,
+    9268,
+    9270,
+    9271,
+    9271,
+    9273,
+    9273,
+    9275,
+    9276,
+    9276,
+    9276,
+    9279,
+    9280,
+    9281,
+    9282,
+    9283,
+    9283,
+    9285,
+    9285,
+    9287,
+    9288,
+    9289,
+    9290,
+    9291,
+    9292,
+    9293,
+    9293,
+    9295,
+    9295,
+    9297,
+    9297,
+    9297,
+    9300,
+    9300,
+    9302,
+    9302,
+    9304,
+    9305,
+    9305,
+    9307,
+    9307,
+    9309,
+    9309,
+    9304,
+    9312,
+    9313,
+    9314,
+    9314,
+    9316,
+    9317,
+    9318,
+    9291,
+    9320,
+    9321,
+    9322,
+    9321,
+    9324,
+    9320,
+    9326,
+    9327,
+    9328,
+    9329,
+    9330,
+    9330,
+    9330,
+    9333,
+    9333,
+    9335,
+    9336,
+    9336,
+    9338,
+    9339,
+    9339,
+    9341,
+    9341,
+    9343,
+    9344,
+    9344,
+    9346,
+    9346,
+    9348,
+    9349,
+    9350,
+    9343,
+    9352,
+    9353,
+    9354,
+    9354,
+    9356,
+    9357,
+    9358,
+    9353,
+    9360,
+    9343,
+    9362,
+    9362,
+    9364,
+    9365,
+    9365,
+    9367,
+    9368,
+    9369,
+    9369,
+    9371,
+    9372,
+    9373,
+    9374,
+    9375,
+    9373,
+    9377,
+    9378,
+    9379,
+    9380,
+    9381,
+    9382,
+    9382,
+    9384,
+    9384,
+    9380,
+    9372,
+    9388,
+    9389,
+    9390,
+    9391,
+    9392,
+    9392,
+    9391,
+    9395,
+    9396,
+    9397,
+    9397,
+    9390,
+    9400,
+    9401,
+    9402,
+    9403,
+    9404,
+    9404,
+    9406,
+    9407,
+    9408,
+    9408,
+    9410,
+    9410,
+    9410,
+    9413,
+    9414,
+    9414,
+    9413,
+    9417,
+    9417,
+    9419,
+    9420,
+    9421,
+    9421,
+    9421,
+    9424,
+    9425,
+    9426,
+    9426,
+    9426,
+    9429,
+    9430,
+    9431,
+    9432,
+    9433,
+    9433,
+    9435,
+    9436,
+    9437,
+    9437,
+    9407,
+    9440,
+    9440,
+    9442,
+    9443,
+    9444,
+    9445,
+    9445,
+    9447,
+    9447,
+    9449,
+    9450,
+    9451,
+    9451,
+    9449,
+    9454,
+    9454,
+    9456,
+    9457,
+    9458,
+    9459,
+    9458,
+    9461,
+    9462,
+    9457,
+    9464,
+    9465,
+    9464,
+    9467,
+    9467,
+    9469,
+    9470,
+    9471,
+    9470,
+    9473,
+    9473,
+    9475,
+    9476,
+    9477,
+    9478,
+    9479,
+    9479,
+    9481,
+    9482,
+    9483,
+    9484,
+    9485,
+    9486,
+    9487,
+    9488,
+    9489,
+    9490,
+    9491,
+    9491,
+    9493,
+    9494,
+    9495,
+    9496,
+    9497,
+    9498,
+    9499,
+    9500,
+    9501,
+    9502,
+    9502,
+    9504,
+    9505,
+    9506,
+    9506,
+    9508,
+    9508,
+    9510,
+    9510,
+    9512,
+    9512,
+    9514,
+    9514,
+    9514,
+    9517,
+    9518,
+    9517,
+    9520,
+    9520,
+    9522,
+    9523,
+    9524,
+    9525,
+    9526,
+    9526,
+    9528,
+    9529,
+    9530,
+    9529,
+    9532,
+    9533,
+    9534,
+    9534,
+    9536,
+    9536,
+    9538,
+    9539,
+    9540,
+    9539,
+    9542,
+    9543,
+    9544,
+    9532,
+    9546,
+    9547,
+    9548,
+    9548,
+    9550,
+    9550,
+    9552,
+    9553,
+    9553,
+    9553,
+    9556,
+    9557,
+    9528,
+    9559,
+    9560,
+    9560,
+    9562,
+    9562,
+    9564,
+    9564,
+    9566,
+    9559,
+    9568,
+    9568,
+    9570,
+    9570,
+    9570,
+    9559,
+    9574,
+    9575,
+    9576,
+    9576,
+    9578,
+    9578,
+    9580,
+    9580,
+    9582,
+    9583,
+    9584,
+    9585,
+    9585,
+    9587,
+    9587,
+    9589,
+    9589,
+    9591,
+    9591,
+    9593,
+    9594,
+    9594,
+    9596,
+    9596,
+    9598,
+    9599,
+    9600,
+    9600,
+    9602,
+    9602,
+    9599,
+    9605,
+    9605,
+    9607,
+    9607,
+    9609,
+    9609,
+    9611,
+    9611,
+    9613,
+    9614,
+    9615,
+    9616,
+    9616,
+    9618,
+    9619,
+    9620,
+    9621,
+    9622,
+    9622,
+    9624,
+    9624,
+    9626,
+    9627,
+    9627,
+    9626,
+    9630,
+    9631,
+    9631,
+    9630,
+    9621,
+    9635,
+    9636,
+    9637,
+    9638,
+    9638,
+    9637,
+    9641,
+    9642,
+    9642,
+    9644,
+    9644,
+    9646,
+    9647,
+    9648,
+    9649,
+    9650,
+    9651,
+    9652,
+    9653,
+    9654,
+    9654,
+    9656,
+    9656,
+    9658,
+    9659,
+    9659,
+    9658,
+    9662,
+    9662,
+    9664,
+    9665,
+    9666,
+    9666,
+    9668,
+    9668,
+    9665,
+    9671,
+    9672,
+    9673,
+    9674,
+    9675,
+    9674,
+    9677,
+    9678,
+    9679,
+    9679,
+    9681,
+    9681,
+    9677,
+    9684,
+    9685,
+    9685,
+    9687,
+    9687,
+    9689,
+    9689,
+    9691,
+    9692,
+    9693,
+    9692,
+    9695,
+    9695,
+    9697,
+    9698,
+    9698,
+    9700,
+    9701,
+    9702,
+    9702,
+    9701,
+    9705,
+    9700,
+    9707,
+    9708,
+    9709,
+    9710,
+    9709,
+    9712,
+    9712,
+    9714,
+    9715,
+    9716,
+    9717,
+    9718,
+    9719,
+    9719,
+    9721,
+    9721,
+    9723,
+    9724,
+    9724,
+    9714,
+    9727,
+    9727,
+    9729,
+    9730,
+    9730,
+    9729,
+    9733,
+    9734,
+    9735,
+    9735,
+    9734,
+    9738,
+    9738,
+    9740,
+    9741,
+    9741,
+    9743,
+    9744,
+    9744,
+    9743,
+    9747,
+    9747,
+    9749,
+    9750,
+    9751,
+    9752,
+    9753,
+    9752,
+    9751,
+    9756,
+    9756,
+    9758,
+    9759,
+    9759,
+    9761,
+    9761,
+    9763,
+    9764,
+    9764,
+    9766,
+    9763,
+    9749,
+    9769,
+    9770,
+    9770,
+    9772,
+    9773,
+    9774,
+    9774,
+    9773,
+    9772,
+    9778,
+    9779,
+    9779,
+    9781,
+    9782,
+    9782,
+    9781,
+    9785,
+    9785,
+    9787,
+    9788,
+    9788,
+    9790,
+    9790,
+    9790,
+    9778,
+    9794,
+    9795,
+    9796,
+    9797,
+    9797,
+    9799,
+    9796,
+    9801,
+    9801,
+    9803,
+    9804,
+    9804,
+    9806,
+    9807,
+    9769,
+    9809,
+    9809,
+    9811,
+    9812,
+    9813,
+    9813,
+    9815,
+    9816,
+    9816,
+    9818,
+    9819,
+    9819,
+    9821,
+    9822,
+    9823,
+    9823,
+    9822,
+    9826,
+    9827,
+    9828,
+    9828,
+    9830,
+    9831,
+    9831,
+    9833,
+    9834,
+    9833,
+    9836,
+    9837,
+    9837,
+    9836,
+    9840,
+    9840,
+    9842,
+    9842,
+    9844,
+    9845,
+    9845,
+    9826,
+    9848,
+    9849,
+    9850,
+    9851,
+    9852,
+    9852,
+    9854,
+    9855,
+    9856,
+    9857,
+    9857,
+    9859,
+    9859,
+    9861,
+    9861,
+    9863,
+    9863,
+    9865,
+    9866,
+    9867,
+    9866,
+    9869,
+    9870,
+    9871,
+    9872,
+    9873,
+    9872,
+    9875,
+    9875,
+    9877,
+    9877,
+    9879,
+    9879,
+    9881,
+    9882,
+    9882,
+    9881,
+    9885,
+    9886,
+    9887,
+    9888,
+    9889,
+    9890,
+    9891,
+    9892,
+    9893,
+    9894,
+    9895,
+    9896,
+    9897,
+    9898,
+    9899,
+    9900,
+    9901,
+    9902,
+    9903,
+    9904,
+    9905,
+    9906,
+    9907,
+    9908,
+    9909,
+    9910,
+    9911,
+    9912,
+    9913,
+    9914,
+    9915,
+    9916,
+    9917,
+    9918,
+    9919,
+    9920,
+    9921,
+    9922,
+    9923,
+    9924,
+    9925,
+    9926,
+    9927,
+    9928,
+    9929,
+    9930,
+    9931,
+    9932,
+    9933,
+    9934,
+    9935,
+    9936,
+    9937,
+    9938,
+    9939,
+    9940,
+    9941,
+    9942,
+    9943,
+    9944,
+    9945,
+    9946,
+    9947,
+    9948,
+    9949,
+    9950,
+    9951,
+    9952,
+    9953,
+    9954,
+    9955,
+    9956,
+    9957,
+    9958,
+    9959,
+    9960,
+    9961,
+    9962,
+    9963,
+    9964,
+    9965,
+    9966,
+    9967,
+    9968,
+    9969,
+    9970,
+    9971,
+    9972,
+    9973,
+    9974,
+    9975,
+    9976,
+    9977,
+    9978,
+    9979,
+    9980,
+    9981,
+    9982,
+    9983,
+    9982,
+    9985,
+    9986,
+    9986,
+    9985,
+    9989,
+    9989,
+    9991,
+    9991,
+    9993,
+    9994,
+    9994,
+    9996,
+    9981,
+    9998,
+    9999,
+    9999,
+    10001,
+    10002,
+    10002,
+    10004,
+    10004,
+    10006,
+    10007,
+    10008,
+    10009,
+    10010,
+    10010,
+    10007,
+    10013,
+    10014,
+    10014,
+    10016,
+    10016,
+    10013,
+    10019,
+    10020,
+    10021,
+    10021,
+    10023,
+    10023,
+    10006,
+    10026,
+    10026,
+    10028,
+    10029,
+    10029,
+    10028,
+    10032,
+    10033,
+    10033,
+    10035,
+    10036,
+    10037,
+    10037,
+    10039,
+    10040,
+    10040,
+    10042,
+    10043,
+    10044,
+    10043,
+    10046,
+    10046,
+    10046,
+    10049,
+    10050,
+    10050,
+    10052,
+    10053,
+    10054,
+    10054,
+    10056,
+    10056,
+    10049,
+    10053,
+    10060,
+    10061,
+    10061,
+    10063,
+    10064,
+    10065,
+    10065,
+    10067,
+    10067,
+    10069,
+    10069,
+    10069,
+    10064,
+    10073,
+    10064,
+    10060,
+    10076,
+    10077,
+    10077,
+    10079,
+    10080,
+    10079,
+    10082,
+    10083,
+    10083,
+    10085,
+    10086,
+    10086,
+    10082,
+    10089,
+    10090,
+    10091,
+    10092,
+    10093,
+    10094,
+    10094,
+    10096,
+    10097,
+    10097,
+    10099,
+    10099,
+    10101,
+    10102,
+    10102,
+    10101,
+    10105,
+    10106,
+    10106,
+    10108,
+    10108,
+    10110,
+    10111,
+    10112,
+    10113,
+    10114,
+    10115,
+    10114,
+    10117,
+    10117,
+    10119,
+    10120,
+    10121,
+    10120,
+    10123,
+    10124,
+    10124,
+    10123,
+    10093,
+    10128,
+    10129,
+    10130,
+    10130,
+    10132,
+    10132,
+    10134,
+    10134,
+    10112,
+    10137,
+    10137,
+    10139,
+    10140,
+    10141,
+    10141,
+    10143,
+    10143,
+    10145,
+    10146,
+    10147,
+    10148,
+    10149,
+    10148,
+    10151,
+    10148,
+    10153,
+    10154,
+    10155,
+    10155,
+    10154,
+    10158,
+    10159,
+    10158,
+    10161,
+    10161,
+    10145,
+    10164,
+    10165,
+    10164,
+    10167,
+    10168,
+    10169,
+    10169,
+    10168,
+    10172,
+    10173,
+    10173,
+    10175,
+    10176,
+    10177,
+    10178,
+    10178,
+    10180,
+    10180,
+    10173,
+    10167,
+    10184,
+    10185,
+    10186,
+    10187,
+    10187,
+    10189,
+    10186,
+    10191,
+    10191,
+    10193,
+    10193,
+    10195,
+    10196,
+    10196,
+    10198,
+    10199,
+    10200,
+    10200,
+    10202,
+    10202,
+    10199,
+    10205,
+    10205,
+    10185,
+    10208,
+    10209,
+    10210,
+    10211,
+    10211,
+    10213,
+    10213,
+    10215,
+    10216,
+    10217,
+    10217,
+    10219,
+    10219,
+    10216,
+    10222,
+    10223,
+    10223,
+    10222,
+    10226,
+    10227,
+    10228,
+    10229,
+    10230,
+    10222,
+    10222,
+    10215,
+    10234,
+    10235,
+    10236,
+    10236,
+    10238,
+    10238,
+    10240,
+    10240,
+    10242,
+    10242,
+    10244,
+    10245,
+    10246,
+    10245,
+    10248,
+    10249,
+    10250,
+    10250,
+    10252,
+    10253,
+    10254,
+    10255,
+    10256,
+    10256,
+    10249,
+    10259,
+    10260,
+    10260,
+    10262,
+    10262,
+    10264,
+    10264,
+    10235,
+    10267,
+    10268,
+    10269,
+    10270,
+    10271,
+    10272,
+    10272,
+    10274,
+    10244,
+    10276,
+    10277,
+    10278,
+    10279,
+    10280,
+    10281,
+    10282,
+    10283,
+    10284,
+    10285,
+    10286,
+    10287,
+    10282,
+    10289,
+    10290,
+    10291,
+    10291,
+    10293,
+    10294,
+    10295,
+    10295,
+    10294,
+    10298,
+    10299,
+    10294,
+    10301,
+    10302,
+    10290,
+    10304,
+    10305,
+    10306,
+    10307,
+    10308,
+    10308,
+    10307,
+    10311,
+    10312,
+    10311,
+    10307,
+    10315,
+    10316,
+    10317,
+    10317,
+    10316,
+    10320,
+    10316,
+    10322,
+    10323,
+    10324,
+    10325,
+    10326,
+    10327,
+    10328,
+    10329,
+    10329,
+    10328,
+    10328,
+    10328,
+    10334,
+    10322,
+    10336,
+    10337,
+    10337,
+    10339,
+    10340,
+    10341,
+    10336,
+    10343,
+    10344,
+    10345,
+    10346,
+    10347,
+    10348,
+    10348,
+    10350,
+    10350,
+    10352,
+    10353,
+    10353,
+    10352,
+    10356,
+    10357,
+    10357,
+    10359,
+    10360,
+    10361,
+    10361,
+    10360,
+    10364,
+    10365,
+    10365,
+    10367,
+    10368,
+    10369,
+    10368,
+    10371,
+    10372,
+    10371,
+    10371,
+    10375,
+    10376,
+    10377,
+    10377,
+    10376,
+    10380,
+    10380,
+    10382,
+    10383,
+    10384,
+    10385,
+    10385,
+    10387,
+    10388,
+    10388,
+    10388,
+    10388,
+    10392,
+    10392,
+    10392,
+    10395,
+    10396,
+    10397,
+    10383,
+    10399,
+    10399,
+    10401,
+    10402,
+    10403,
+    10401,
+    10405,
+    10406,
+    10407,
+    10408,
+    10408,
+    10410,
+    10411,
+    10412,
+    10412,
+    10414,
+    10414,
+    10416,
+    10417,
+    10418,
+    10419,
+    10419,
+    10421,
+    10422,
+    10422,
+    10424,
+    10424,
+    10426,
+    10406,
+    10428,
+    10429,
+    10430,
+    10431,
+    10432,
+    10433,
+    10434,
+    10434,
+    10436,
+    10436,
+    10438,
+    10439,
+    10439,
+    10441,
+    10441,
+    10383,
+    10444,
+    10445,
+    10446,
+    10445,
+    10448,
+    10448,
+    10450,
+    10451,
+    10451,
+    10453,
+    10454,
+    10454,
+    10456,
+    10453,
+    10458,
+    10459,
+    10459,
+    10461,
+    10461,
+    10463,
+    10463,
+    10458,
+    10466,
+    10467,
+    10468,
+    10458,
+    10470,
+    10471,
+    10472,
+    10471,
+    10474,
+    10475,
+    10475,
+    10477,
+    10478,
+    10477,
+    10477,
+    10477,
+    10482,
+    10483,
+    10484,
+    10485,
+    10485,
+    10487,
+    10487,
+    10489,
+    10490,
+    10491,
+    10491,
+    10493,
+    10494,
+    10490,
+    10484,
+    10497,
+    10497,
+    10499,
+    10499,
+    10501,
+    10501,
+    10503,
+    10503,
+    10505,
+    10506,
+    10507,
+    10508,
+    10508,
+    10510,
+    10511,
+    10508,
+    10507,
+    10514,
+    10515,
+    10515,
+    10517,
+    10517,
+    10519,
+    10520,
+    10521,
+    10521,
+    10521,
+    10524,
+    10525,
+    10526,
+    10527,
+    10526,
+    10526,
+    10530,
+    10531,
+    10521,
+    10533,
+    10533,
+    10535,
+    10535,
+    10537,
+    10535,
+    10539,
+    10540,
+    10541,
+    10542,
+    10540,
+    10540,
+    10545,
+    10546,
+    10547,
+    10548,
+    10547,
+    10550,
+    10551,
+    10551,
+    10553,
+    10554,
+    10555,
+    10555,
+    10557,
+    10558,
+    10559,
+    10560,
+    10561,
+    10561,
+    10563,
+    10564,
+    10564,
+    10563,
+    10567,
+    10568,
+    10569,
+    10570,
+    10571,
+    10571,
+    10570,
+    10574,
+    10575,
+    10576,
+    10577,
+    10577,
+    10579,
+    10576,
+    10581,
+    10581,
+    10583,
+    10584,
+    10585,
+    10586,
+    10587,
+    10587,
+    10589,
+    10586,
+    10591,
+    10592,
+    10592,
+    10594,
+    10594,
+    10596,
+    10597,
+    10597,
+    10599,
+    10599,
+    10601,
+    10602,
+    10602,
+    10604,
+    10605,
+    10606,
+    10607,
+    10607,
+    10609,
+    10609,
+    10606,
+    10612,
+    10613,
+    10614,
+    10615,
+    10616,
+    10617,
+    10617,
+    10619,
+    10620,
+    10620,
+    10620,
+    10623,
+    10623,
+    10625,
+    10626,
+    10626,
+    10626,
+    10626,
+    10630,
+    10631,
+    10631,
+    10630,
+    10634,
+    10635,
+    10634,
+    10637,
+    10638,
+    10638,
+    10640,
+    10640,
+    10642,
+    10643,
+    10643,
+    10643,
+    10646,
+    10646,
+    10646,
+    10649,
+    10650,
+    10651,
+    10652,
+    10652,
+    10654,
+    10642,
+    10656,
+    10657,
+    10658,
+    10659,
+    10657,
+    10661,
+    10662,
+    10662,
+    10661,
+    10665,
+    10666,
+    10667,
+    10668,
+    10669,
+    10670,
+    10671,
+    10668,
+    10673,
+    10674,
+    10674,
+    10676,
+    10676,
+    10668,
+    10679,
+    10680,
+    10681,
+    10682,
+    10683,
+    10684,
+    10685,
+    10686,
+    10687,
+    10688,
+    10688,
+    10682,
+    10691,
+    10691,
+    10693,
+    10693,
+    10695,
+    10695,
+    10697,
+    10698,
+    10697,
+    10700,
+    10701,
+    10702,
+    10702,
+    10704,
+    10704,
+    10706,
+    10706,
+    10708,
+    10709,
+    10710,
+    10710,
+    10712,
+    10713,
+    10714,
+    10715,
+    10709,
+    10717,
+    10718,
+    10718,
+    10720,
+    10720,
+    10709,
+    10723,
+    10723,
+    10714,
+    10726,
+    10727,
+    10728,
+    10726,
+    10726,
+    10726,
+    10732,
+    10732,
+    10734,
+    10735,
+    10735,
+    10735,
+    10738,
+    10735,
+    10734,
+    10741,
+    10741,
+    10741,
+    10744,
+    10745,
+    10744,
+    10747,
+    10748,
+    10749,
+    10749,
+    10751,
+    10751,
+    10753,
+    10753,
+    10755,
+    10755,
+    10755,
+    10758,
+    10758,
+    10760,
+    10760,
+    10762,
+    10762,
+    10764,
+    10765,
+    10765,
+    10767,
+    10767,
+    10769,
+    10769,
+    10771,
+    10771,
+    10773,
+    10773,
+    10775,
+    10776,
+    10777,
+    10778,
+    10778,
+    10780,
+    10781,
+    10781,
+    10783,
+    10784,
+    10780,
+    10786,
+    10787,
+    10787,
+    10780,
+    10790,
+    10791,
+    10792,
+    10793,
+    10793,
+    10795,
+    10796,
+    10797,
+    10797,
+    10799,
+    10799,
+    10801,
+    10802,
+    10803,
+    10796,
+    10796,
+    10806,
+    10807,
+    10807,
+    10807,
+    10796,
+    10811,
+    10812,
+    10813,
+    10814,
+    10815,
+    10815,
+    10817,
+    10817,
+    10819,
+    10819,
+    10821,
+    10822,
+    10823,
+    10823,
+    10825,
+    10826,
+    10826,
+    10828,
+    10829,
+    10825,
+    10831,
+    10832,
+    10832,
+    10834,
+    10835,
+    10835,
+    10834,
+    10814,
+    10839,
+    10840,
+    10840,
+    10842,
+    10843,
+    10842,
+    10845,
+    10846,
+    10847,
+    10848,
+    10848,
+    10850,
+    10847,
+    10847,
+    10847,
+    10854,
+    10854,
+    10856,
+    10857,
+    10857,
+    10859,
+    10859,
+    10861,
+    10862,
+    10862,
+    10864,
+    10865,
+    10845,
+    10867,
+    10868,
+    10868,
+    10870,
+    10871,
+    10872,
+    10872,
+    10874,
+    10874,
+    10867,
+    10877,
+    10877,
+    10879,
+    10879,
+    10881,
+    10881,
+    10883,
+    10884,
+    10884,
+    10881,
+    10887,
+    10887,
+    10889,
+    10890,
+    10890,
+    10892,
+    10893,
+    10893,
+    10892,
+    10896,
+    10897,
+    10898,
+    10899,
+    10900,
+    10901,
+    10902,
+    10903,
+    10900,
+    10905,
+    10906,
+    10907,
+    10908,
+    10909,
+    10910,
+    10911,
+    10912,
+    10906,
+    10914,
+    10915,
+    10916,
+    10917,
+    10918,
+    10919,
+    10920,
+    10921,
+    10922,
+    10923,
+    10924,
+    10924,
+    10926,
+    10926,
+    10928,
+    10928,
+    10930,
+    10930,
+    10932,
+    10932,
+    10934,
+    10934,
+    10936,
+    10937,
+    10937,
+    10939,
+    10940,
+    10941,
+    10940,
+    10943,
+    10944,
+    10945,
+    10946,
+    10940,
+    10948,
+    10949,
+    10950,
+    10951,
+    10951,
+    10953,
+    10954,
+    10955,
+    10955,
+    10955,
+    10958,
+    10958,
+    10955,
+    10961,
+    10961,
+    10963,
+    10963,
+    10963,
+    10966,
+    10967,
+    10967,
+    10969,
+    10970,
+    10971,
+    10971,
+    10970,
+    10974,
+    10974,
+    10976,
+    10977,
+    10978,
+    10979,
+    10979,
+    10978,
+    10982,
+    10983,
+    10984,
+    10985,
+    10986,
+    10987,
+    10988,
+    10989,
+    10990,
+    10991,
+    10992,
+    10993,
+    10994,
+    10995,
+    10996,
+    10997,
+    10998,
+    10999,
+    11000,
+    11001,
+    11002,
+    11003,
+    11004,
+    11005,
+    11006,
+    11006,
+    11008,
+    11008,
+    11010,
+    11010,
+    11012,
+    11013,
+    11013,
+    11015,
+    11016,
+    11016,
+    11018,
+    11019,
+    11019,
+    11021,
+    11021,
+    11015,
+    11015,
+    11025,
+    11026,
+    11026,
+    11028,
+    11028,
+    11025,
+    11031,
+    11032,
+    11033,
+    11033,
+    11035,
+    11035,
+    11015,
+    11038,
+    11039,
+    11040,
+    11041,
+    11042,
+    11040,
+    11044,
+    11044,
+    11046,
+    11047,
+    11040,
+    11049,
+    11050,
+    11050,
+    11052,
+    11053,
+    11054,
+    11055,
+    11056,
+    11055,
+    11058,
+    11059,
+    11060,
+    11061,
+    11059,
+    11059,
+    11059,
+    11065,
+    11065,
+    11067,
+    11068,
+    11069,
+    11068,
+    11071,
+    11072,
+    11072,
+    11074,
+    11075,
+    11076,
+    11077,
+    11077,
+    11079,
+    11079,
+    11081,
+    11082,
+    11083,
+    11084,
+    11085,
+    11085,
+    11084,
+    11088,
+    11084,
+    11090,
+    11091,
+    11092,
+    11092,
+    11094,
+    11094,
+    11096,
+    11097,
+    11098,
+    11099,
+    11099,
+    11097,
+    11102,
+    11102,
+    11104,
+    11105,
+    11105,
+    11107,
+    11108,
+    11109,
+    11110,
+    11110,
+    11112,
+    11113,
+    11114,
+    11115,
+    11116,
+    11117,
+    11117,
+    11119,
+    11120,
+    11119,
+    11122,
+    11123,
+    11124,
+    11124,
+    11123,
+    11127,
+    11128,
+    11129,
+    11129,
+    11131,
+    11131,
+    11133,
+    11134,
+    11135,
+    11136,
+    11136,
+    11138,
+    11138,
+    11135,
+    11141,
+    11142,
+    11143,
+    11143,
+    11145,
+    11145,
+    11147,
+    11148,
+    11149,
+    11149,
+    11151,
+    11152,
+    11153,
+    11154,
+    11151,
+    11156,
+    11157,
+    11157,
+    11159,
+    11160,
+    11161,
+    11162,
+    11163,
+    11162,
+    11165,
+    11165,
+    11167,
+    11168,
+    11169,
+    11169,
+    11168,
+    11172,
+    11172,
+    11174,
+    11175,
+    11176,
+    11176,
+    11178,
+    11179,
+    11179,
+    11181,
+    11182,
+    11182,
+    11181,
+    11185,
+    11185,
+    11187,
+    11188,
+    11189,
+    11190,
+    11191,
+    11191,
+    11193,
+    11193,
+    11193,
+    11193,
+    11197,
+    11198,
+    11199,
+    11198,
+    11201,
+    11202,
+    11198,
+    11204,
+    11205,
+    11205,
+    11207,
+    11204,
+    11209,
+    11210,
+    11210,
+    11212,
+    11213,
+    11213,
+    11212,
+    11216,
+    11216,
+    11218,
+    11219,
+    11220,
+    11221,
+    11221,
+    11223,
+    11223,
+    11225,
+    11226,
+    11227,
+    11228,
+    11229,
+    11229,
+    11231,
+    11231,
+    11233,
+    11234,
+    11235,
+    11236,
+    11237,
+    11238,
+    11238,
+    11240,
+    11241,
+    11242,
+    11243,
+    11244,
+    11245,
+    11246,
+    11247,
+    11248,
+    11249,
+    11250,
+    11250,
+    11252,
+    11252,
+    11254,
+    11255,
+    11256,
+    11256,
+    11256,
+    11254,
+    11260,
+    11260,
+    11260,
+    11263,
+    11264,
+    11265,
+    11264,
+    11267,
+    11268,
+    11268,
+    11270,
+    11271,
+    11272,
+    11273,
+    11274,
+    11275,
+    11276,
+    11276,
+    11278,
+    11278,
+    11278,
+    11281,
+    11282,
+    11283,
+    11283,
+    11285,
+    11285,
+    11287,
+    11288,
+    11289,
+    11290,
+    11290,
+    11292,
+    11293,
+    11294,
+    11292,
+    11289,
+    11297,
+    11298,
+    11299,
+    11300,
+    11301,
+    11301,
+    11303,
+    11303,
+    11305,
+    11305,
+    11307,
+    11307,
+    11309,
+    11309,
+    11311,
+    11312,
+    11312,
+    11314,
+    11311,
+    11316,
+    11317,
+    11318,
+    11319,
+    11320,
+    11321,
+    11322,
+    11323,
+    11322,
+    11325,
+    11326,
+    11327,
+    11328,
+    11329,
+    11326,
+    11331,
+    11331,
+    11333,
+    11334,
+    11335,
+    11336,
+    11336,
+    11338,
+    11338,
+    11340,
+    11341,
+    11342,
+    11343,
+    11334,
+    11345,
+    11345,
+    11347,
+    11348,
+    11349,
+    11349,
+    11351,
+    11348,
+    11353,
+    11353,
+    11355,
+    11355,
+    11357,
+    11358,
+    11359,
+    11360,
+    11360,
+    11362,
+    11363,
+    11364,
+    11365,
+    11366,
+    11367,
+    11368,
+    11368,
+    11370,
+    11371,
+    11372,
+    11373,
+    11374,
+    11375,
+    11376,
+    11376,
+    11378,
+    11379,
+    11380,
+    11380,
+    11382,
+    11383,
+    11384,
+    11385,
+    11385,
+    11387,
+    11388,
+    11389,
+    11390,
+    11390,
+    11392,
+    11392,
+    11371,
+    11395,
+    11396,
+    11396,
+    11398,
+    11399,
+    11399,
+    11401,
+    11402,
+    11403,
+    11404,
+    11404,
+    11406,
+    11406,
+    11408,
+    11409,
+    11410,
+    11410,
+    11412,
+    11412,
+    11414,
+    11414,
+    11416,
+    11416,
+    11402,
+    11419,
+    11419,
+    11421,
+    11421,
+    11423,
+    11423,
+    11425,
+    11425,
+    11427,
+    11427,
+    11429,
+    11430,
+    11431,
+    11432,
+    11433,
+    11434,
+    11434,
+    11436,
+    11437,
+    11436,
+    11439,
+    11440,
+    11441,
+    11441,
+    11443,
+    11443,
+    11445,
+    11446,
+    11447,
+    11448,
+    11449,
+    11449,
+    11451,
+    11452,
+    11453,
+    11454,
+    11455,
+    11456,
+    11456,
+    11458,
+    11459,
+    11459,
+    11461,
+    11462,
+    11462,
+    11464,
+    11464,
+    11466,
+    11467,
+    11467,
+    11469,
+    11469,
+    11471,
+    11472,
+    11473,
+    11474,
+    11475,
+    11476,
+    11477,
+    11478,
+    11478,
+    11480,
+    11480,
+    11482,
+    11482,
+    11484,
+    11485,
+    11485,
+    11487,
+    11487,
+    11489,
+    11490,
+    11491,
+    11489,
+    11493,
+    11494,
+    11495,
+    11495,
+    11497,
+    11498,
+    11499,
+    11497,
+    11501,
+    11501,
+    11503,
+    11504,
+    11504,
+    11506,
+    11506,
+    11508,
+    11509,
+    11509,
+    11511,
+    11511,
+    11513,
+    11514,
+    11515,
+    11513,
+    11517,
+    11518,
+    11519,
+    11520,
+    11521,
+    11522,
+    11523,
+    11523,
+    11519,
+    11526,
+    11526,
+    11528,
+    11529,
+    11529,
+    11531,
+    11532,
+    11532,
+    11534,
+    11535,
+    11535,
+    11537,
+    11537,
+    11539,
+    11540,
+    11541,
+    11542,
+    11543,
+    11544,
+    11545,
+    11546,
+    11545,
+    11548,
+    11549,
+    11550,
+    11549,
+    11552,
+    11553,
+    11553,
+    11555,
+    11556,
+    11557,
+    11558,
+    11558,
+    11560,
+    11558,
+    11562,
+    11563,
+    11564,
+    11564,
+    11566,
+    11566,
+    11568,
+    11568,
+    11570,
+    11571,
+    11572,
+    11572,
+    11574,
+    11575,
+    11576,
+    11577,
+    11578,
+    11578,
+    11580,
+    11581,
+    11582,
+    11583,
+    11583,
+    11585,
+    11586,
+    11587,
+    11588,
+    11589,
+    11590,
+    11588,
+    11592,
+    11593,
+    11594,
+    11592,
+    11586,
+    11597,
+    11598,
+    11599,
+    11599,
+    11598,
+    11602,
+    11602,
+    11604,
+    11604,
+    11606,
+    11607,
+    11607,
+    11609,
+    11610,
+    11610,
+    11612,
+    11613,
+    11614,
+    11615,
+    11616,
+    11615,
+    11614,
+    11619,
+    11620,
+    11621,
+    11622,
+    11623,
+    11623,
+    11623,
+    11626,
+    11626,
+    11628,
+    11628,
+    11630,
+    11630,
+    11628,
+    11633,
+    11634,
+    11633,
+    11636,
+    11636,
+    11638,
+    11638,
+    11640,
+    11640,
+    11642,
+    11643,
+    11644,
+    11645,
+    11646,
+    11647,
+    11648,
+    11648,
+    11650,
+    11651,
+    11652,
+    11653,
+    11653,
+    11655,
+    11655,
+    11657,
+    11657,
+    11659,
+    11660,
+    11661,
+    11662,
+    11663,
+    11664,
+    11665,
+    11666,
+    11667,
+    11668,
+    11669,
+    11669,
+    11671,
+    11672,
+    11673,
+    11674,
+    11675,
+    11676,
+    11677,
+    11678,
+    11679,
+    11679,
+    11681,
+    11682,
+    11683,
+    11684,
+    11685,
+    11686,
+    11687,
+    11688,
+    11688,
+    11690,
+    11690,
+    11687,
+    11693,
+    11693,
+    11693,
+    11696,
+    11697,
+    11698,
+    11698,
+    11700,
+    11701,
+    11701,
+    11700,
+    11704,
+    11704,
+    11706,
+    11706,
+    11708,
+    11709,
+    11708,
+    11711,
+    11712,
+    11711,
+    11714,
+    11715,
+    11715,
+    11717,
+    11718,
+    11719,
+    11719,
+    11696,
+    11722,
+    11723,
+    11722,
+    11725,
+    11725,
+    11727,
+    11727,
+    11729,
+    11729,
+    11731,
+    11732,
+    11733,
+    11733,
+    11735,
+    11736,
+    11735,
+    11738,
+    11739,
+    11740,
+    11741,
+    11741,
+    11743,
+    11743,
+    11745,
+    11746,
+    11745,
+    11748,
+    11748,
+    11750,
+    11750,
+    11752,
+    11753,
+    11754,
+    11755,
+    11755,
+    11757,
+    11758,
+    11759,
+    11759,
+    11761,
+    11762,
+    11761,
+    11764,
+    11765,
+    11766,
+    11767,
+    11768,
+    11768,
+    11770,
+    11771,
+    11772,
+    11773,
+    11773,
+    11771,
+    11776,
+    11777,
+    11776,
+    11779,
+    11779,
+    11781,
+    11782,
+    11783,
+    11784,
+    11785,
+    11785,
+    11787,
+    11788,
+    11789,
+    11790,
+    11791,
+    11792,
+    11793,
+    11794,
+    11794,
+    11793,
+    11797,
+    11797,
+    11799,
+    11800,
+    11801,
+    11799,
+    11803,
+    11804,
+    11805,
+    11805,
+    11807,
+    11807,
+    11809,
+    11809,
+    11811,
+    11812,
+    11812,
+    11814,
+    11815,
+    11815,
+    11809,
+    11818,
+    11818,
+    11818,
+    11821,
+    11822,
+    11821,
+    11824,
+    11824,
+    11826,
+    11827,
+    11828,
+    11828,
+    11830,
+    11830,
+    11832,
+    11832,
+    11834,
+    11835,
+    11836,
+    11837,
+    11838,
+    11839,
+    11840,
+    11841,
+    11842,
+    11843,
+    11843,
+    11845,
+    11846,
+    11846,
+    11848,
+    11849,
+    11849,
+    11849,
+    11852,
+    11853,
+    11854,
+    11855,
+    11856,
+    11856,
+    11858,
+    11859,
+    11859,
+    11861,
+    11862,
+    11863,
+    11864,
+    11865,
+    11866,
+    11865,
+    11868,
+    11869,
+    11870,
+    11870,
+    11872,
+    11873,
+    11874,
+    11875,
+    11876,
+    11876,
+    11878,
+    11879,
+    11880,
+    11879,
+    11882,
+    11883,
+    11884,
+    11884,
+    11883,
+    11887,
+    11887,
+    11889,
+    11889,
+    11891,
+    11892,
+    11893,
+    11893,
+    11895,
+    11895,
+    11897,
+    11898,
+    11899,
+    11899,
+    11901,
+    11902,
+    11903,
+    11903,
+    11903,
+    11902,
+    11907,
+    11908,
+    11909,
+    11910,
+    11910,
+    11912,
+    11913,
+    11914,
+    11915,
+    11916,
+    11917,
+    11918,
+    11918,
+    11920,
+    11921,
+    11921,
+    11923,
+    11920,
+    11925,
+    11925,
+    11927,
+    11928,
+    11929,
+    11930,
+    11930,
+    11932,
+    11933,
+    11933,
+    11935,
+    11936,
+    11935,
+    11935,
+    11939,
+    11940,
+    11941,
+    11941,
+    11939,
+    11944,
+    11945,
+    11946,
+    11947,
+    11948,
+    11948,
+    11950,
+    11950,
+    11952,
+    11953,
+    11953,
+    11955,
+    11955,
+    11953,
+    11953,
+    11959,
+    11959,
+    11961,
+    11961,
+    11952,
+    11964,
+    11965,
+    11966,
+    11966,
+    11968,
+    11968,
+    11970,
+    11970,
+    11966,
+    11973,
+    11974,
+    11974,
+    11976,
+    11973,
+    11978,
+    11979,
+    11979,
+    11981,
+    11982,
+    11966,
+    11966,
+    11985,
+    11986,
+    11987,
+    11987,
+    11989,
+    11990,
+    11991,
+    11991,
+    11993,
+    11993,
+    11995,
+    11993,
+    11997,
+    11997,
+    11999,
+    11999,
+    11999,
+    11993,
+    12003,
+    11995,
+    11986,
+    12006,
+    12006,
+    12008,
+    12008,
+    12010,
+    12010,
+    12012,
+    12013,
+    12014,
+    12014,
+    12016,
+    12016,
+    12018,
+    12018,
+    12013,
+    12021,
+    12021,
+    12013,
+    12013,
+    12025,
+    12026,
+    12026,
+    12012,
+    12029,
+    12030,
+    12031,
+    12032,
+    12033,
+    12033,
+    12035,
+    12035,
+    12037,
+    12037,
+    12035,
+    12032,
+    12041,
+    12041,
+    12043,
+    12043,
+    12045,
+    12045,
+    12045,
+    12048,
+    12048,
+    12050,
+    12051,
+    12052,
+    12050,
+    12054,
+    12054,
+    12056,
+    12056,
+    12058,
+    12054,
+    12060,
+    12061,
+    12061,
+    12063,
+    12064,
+    12061,
+    12066,
+    12067,
+    12067,
+    12067,
+    12070,
+    12070,
+    12072,
+    12073,
+    12073,
+    12075,
+    12075,
+    12077,
+    12073,
+    12079,
+    12080,
+    12080,
+    12073,
+    12083,
+    12083,
+    12085,
+    12086,
+    12086,
+    12088,
+    12089,
+    12089,
+    12091,
+    12091,
+    12093,
+    12093,
+    12088,
+    12096,
+    12096,
+    12098,
+    12099,
+    12100,
+    12101,
+    12102,
+    12102,
+    12102,
+    12105,
+    12106,
+    12106,
+    12106,
+    12109,
+    12110,
+    12110,
+    12106,
+    12113,
+    12113,
+    12115,
+    12116,
+    12117,
+    12118,
+    12119,
+    12119,
+    12121,
+    12122,
+    12122,
+    12121,
+    12125,
+    12126,
+    12121,
+    12128,
+    12128,
+    12130,
+    12131,
+    12131,
+    12130,
+    12134,
+    12134,
+    12136,
+    12136,
+    12138,
+    12138,
+    12140,
+    12141,
+    12142,
+    12136,
+    12144,
+    12144,
+    12146,
+    12147,
+    12146,
+    12149,
+    12149,
+    12151,
+    12152,
+    12152,
+    12154,
+    12118,
+    12156,
+    12157,
+    12157,
+    12159,
+    12159,
+    12161,
+    12161,
+    12163,
+    12164,
+    12164,
+    12166,
+    12167,
+    12167,
+    12169,
+    12169,
+    12171,
+    12172,
+    12172,
+    12172,
+    12175,
+    12176,
+    12177,
+    12172,
+    12177,
+    12180,
+    12181,
+    12181,
+    12180,
+    12180,
+    12185,
+    12186,
+    12185,
+    12185,
+    12185,
+    12190,
+    12191,
+    12192,
+    12192,
+    12191,
+    12195,
+    12195,
+    12197,
+    12195,
+    12195,
+    12195,
+    12201,
+    12202,
+    12203,
+    12203,
+    12205,
+    12171,
+    12207,
+    12208,
+    12209,
+    12210,
+    12211,
+    12211,
+    12213,
+    12213,
+    12211,
+    12216,
+    12216,
+    12211,
+    12219,
+    12220,
+    12221,
+    12221,
+    12221,
+    12224,
+    12224,
+    12226,
+    12227,
+    12228,
+    12228,
+    12230,
+    12231,
+    12232,
+    12228,
+    12234,
+    12235,
+    12236,
+    12236,
+    12238,
+    12238,
+    12238,
+    12241,
+    12241,
+    12241,
+    12244,
+    12241,
+    12246,
+    12246,
+    12248,
+    12248,
+    12246,
+    12246,
+    12252,
+    12252,
+    12252,
+    12238,
+    12256,
+    12257,
+    12257,
+    12259,
+    12235,
+    12261,
+    12262,
+    12263,
+    12264,
+    12263,
+    12266,
+    12267,
+    12267,
+    12269,
+    12269,
+    12271,
+    12269,
+    12269,
+    12274,
+    12275,
+    12276,
+    12277,
+    12275,
+    12279,
+    12280,
+    12281,
+    12280,
+    12283,
+    12283,
+    12285,
+    12286,
+    12287,
+    12288,
+    12289,
+    12288,
+    12291,
+    12292,
+    12292,
+    12292,
+    12295,
+    12295,
+    12292,
+    12298,
+    12299,
+    12299,
+    12299,
+    12299,
+    12303,
+    12304,
+    12305,
+    12305,
+    12307,
+    12307,
+    12309,
+    12310,
+    12311,
+    12305,
+    12313,
+    12304,
+    12315,
+    12315,
+    12315,
+    12315,
+    12319,
+    12319,
+    12319,
+    12322,
+    12323,
+    12319,
+    12325,
+    12325,
+    12325,
+    12325,
+    12329,
+    12329,
+    12331,
+    12332,
+    12332,
+    12332,
+    12335,
+    12336,
+    12337,
+    12337,
+    12339,
+    12340,
+    12339,
+    12342,
+    12343,
+    12344,
+    12345,
+    12346,
+    12346,
+    12348,
+    12349,
+    12350,
+    12349,
+    12352,
+    12353,
+    12354,
+    12355,
+    12356,
+    12356,
+    12355,
+    12359,
+    12359,
+    12361,
+    12362,
+    12361,
+    12364,
+    12364,
+    12366,
+    12366,
+    12368,
+    12368,
+    12370,
+    12371,
+    12372,
+    12373,
+    12374,
+    12374,
+    12376,
+    12377,
+    12376,
+    12379,
+    12380,
+    12381,
+    12382,
+    12382,
+    12382,
+    12382,
+    12386,
+    12387,
+    12387,
+    12389,
+    12390,
+    12391,
+    12392,
+    12389,
+    12394,
+    12394,
+    12396,
+    12397,
+    12397,
+    12399,
+    12400,
+    12400,
+    12402,
+    12396,
+    12404,
+    12404,
+    12406,
+    12407,
+    12406,
+    12409,
+    12410,
+    12411,
+    12411,
+    12413,
+    12413,
+    12415,
+    12416,
+    12415,
+    12418,
+    12419,
+    12420,
+    12421,
+    12422,
+    12422,
+    12421,
+    12420,
+    12426,
+    12426,
+    12428,
+    12429,
+    12430,
+    12430,
+    12432,
+    12433,
+    12434,
+    12435,
+    12436,
+    12437,
+    12438,
+    12439,
+    12440,
+    12441,
+    12442,
+    12443,
+    12442,
+    12445,
+    12445,
+    12447,
+    12448,
+    12448,
+    12450,
+    12451,
+    12452,
+    12453,
+    12454,
+    12455,
+    12455,
+    12457,
+    12457,
+    12441,
+    12460,
+    12461,
+    12462,
+    12463,
+    12463,
+    12465,
+    12466,
+    12467,
+    12468,
+    12469,
+    12470,
+    12471,
+    12465,
+    12473,
+    12465,
+    12462,
+    12462,
+    12477,
+    12478,
+    12479,
+    12479,
+    12481,
+    12482,
+    12482,
+    12484,
+    12484,
+    12486,
+    12486,
+    12488,
+    12489,
+    12489,
+    12491,
+    12492,
+    12493,
+    12493,
+    12495,
+    12495,
+    12495,
+    12498,
+    12499,
+    12499,
+    12501,
+    12502,
+    12492,
+    12504,
+    12505,
+    12506,
+    12507,
+    12505,
+    12509,
+    12509,
+    12511,
+    12511,
+    12513,
+    12513,
+    12515,
+    12516,
+    12517,
+    12518,
+    12519,
+    12519,
+    12519,
+    12522,
+    12523,
+    12524,
+    12525,
+    12525,
+    12527,
+    12527,
+    12529,
+    12530,
+    12530,
+    12524,
+    12533,
+    12534,
+    12534,
+    12536,
+    12537,
+    12537,
+    12539,
+    12539,
+    12541,
+    12542,
+    12542,
+    12544,
+    12545,
+    12546,
+    12547,
+    12548,
+    12549,
+    12550,
+    12550,
+    12548,
+    12553,
+    12553,
+    12555,
+    12555,
+    12547,
+    12558,
+    12559,
+    12560,
+    12560,
+    12562,
+    12563,
+    12563,
+    12565,
+    12563,
+    12567,
+    12567,
+    12569,
+    12569,
+    12571,
+    12572,
+    12573,
+    12574,
+    12574,
+    12576,
+    12577,
+    12577,
+    12579,
+    12579,
+    12581,
+    12581,
+    12583,
+    12583,
+    12581,
+    12586,
+    12586,
+    12588,
+    12589,
+    12589,
+    12586,
+    12592,
+    12592,
+    12594,
+    12594,
+    12596,
+    12597,
+    12597,
+    12596,
+    12600,
+    12600,
+    12602,
+    12602,
+    12596,
+    12605,
+    12606,
+    12607,
+    12607,
+    12609,
+    12572,
+    12611,
+    12611,
+    12613,
+    12613,
+    12615,
+    12616,
+    12617,
+    12617,
+    12619,
+    12620,
+    12621,
+    12621,
+    12623,
+    12623,
+    12621,
+    12626,
+    12627,
+    12627,
+    12626,
+    12630,
+    12630,
+    12632,
+    12632,
+    12634,
+    12634,
+    12636,
+    12637,
+    12638,
+    12638,
+    12636,
+    12641,
+    12641,
+    12643,
+    12644,
+    12645,
+    12641,
+    12647,
+    12648,
+    12649,
+    12650,
+    12648,
+    12652,
+    12652,
+    12652,
+    12655,
+    12655,
+    12657,
+    12657,
+    12619,
+    12660,
+    12660,
+    12662,
+    12663,
+    12663,
+    12665,
+    12666,
+    12667,
+    12668,
+    12668,
+    12670,
+    12670,
+    12672,
+    12673,
+    12673,
+    12675,
+    12675,
+    12667,
+    12678,
+    12678,
+    12680,
+    12680,
+    12682,
+    12682,
+    12684,
+    12685,
+    12685,
+    12687,
+    12687,
+    12689,
+    12689,
+    12667,
+    12692,
+    12692,
+    12694,
+    12695,
+    12696,
+    12696,
+    12695,
+    12699,
+    12700,
+    12695,
+    12702,
+    12702,
+    12665,
+    12705,
+    12706,
+    12707,
+    12708,
+    12708,
+    12710,
+    12711,
+    12712,
+    12713,
+    12714,
+    12715,
+    12716,
+    12717,
+    12712,
+    12719,
+    12720,
+    12721,
+    12722,
+    12723,
+    12724,
+    12725,
+    12726,
+    12727,
+    12728,
+    12729,
+    12730,
+    12731,
+    12732,
+    12732,
+    12734,
+    12735,
+    12736,
+    12737,
+    12738,
+    12739,
+    12739,
+    12741,
+    12741,
+    12743,
+    12744,
+    12745,
+    12738,
+    12747,
+    12748,
+    12748,
+    12747,
+    12751,
+    12752,
+    12753,
+    12753,
+    12753,
+    12751,
+    12757,
+    12758,
+    12758,
+    12751,
+    12761,
+    12762,
+    12762,
+    12764,
+    12764,
+    12766,
+    12767,
+    12767,
+    12769,
+    12770,
+    12769,
+    12772,
+    12772,
+    12774,
+    12774,
+    12776,
+    12774,
+    12774,
+    12779,
+    12779,
+    12781,
+    12782,
+    12783,
+    12783,
+    12781,
+    12786,
+    12787,
+    12788,
+    12789,
+    12789,
+    12791,
+    12792,
+    12792,
+    12794,
+    12794,
+    12792,
+    12797,
+    12798,
+    12799,
+    12792,
+    12791,
+    12802,
+    12803,
+    12804,
+    12805,
+    12804,
+    12807,
+    12807,
+    12809,
+    12810,
+    12810,
+    12809,
+    12813,
+    12813,
+    12815,
+    12815,
+    12807,
+    12818,
+    12819,
+    12820,
+    12820,
+    12807,
+    12823,
+    12823,
+    12823,
+    12803,
+    12827,
+    12828,
+    12829,
+    12828,
+    12831,
+    12831,
+    12833,
+    12833,
+    12835,
+    12835,
+    12833,
+    12838,
+    12838,
+    12840,
+    12840,
+    12838,
+    12828,
+    12844,
+    12845,
+    12845,
+    12844,
+    12848,
+    12848,
+    12848,
+    12851,
+    12851,
+    12848,
+    12854,
+    12855,
+    12854,
+    12857,
+    12858,
+    12859,
+    12854,
+    12861,
+    12862,
+    12863,
+    12864,
+    12865,
+    12866,
+    12867,
+    12867,
+    12869,
+    12869,
+    12866,
+    12872,
+    12873,
+    12874,
+    12874,
+    12876,
+    12876,
+    12878,
+    12878,
+    12880,
+    12880,
+    12882,
+    12878,
+    12884,
+    12885,
+    12886,
+    12886,
+    12885,
+    12889,
+    12889,
+    12891,
+    12891,
+    12891,
+    12891,
+    12895,
+    12891,
+    12897,
+    12898,
+    12898,
+    12898,
+    12898,
+    12902,
+    12903,
+    12903,
+    12905,
+    12906,
+    12903,
+    12908,
+    12903,
+    12910,
+    12911,
+    12912,
+    12911,
+    12914,
+    12915,
+    12916,
+    12917,
+    12917,
+    12919,
+    12919,
+    12917,
+    12917,
+    12923,
+    12924,
+    12924,
+    12926,
+    12926,
+    12923,
+    12929,
+    12929,
+    12931,
+    12931,
+    12931,
+    12934,
+    12935,
+    12935,
+    12937,
+    12938,
+    12939,
+    12939,
+    12941,
+    12942,
+    12943,
+    12944,
+    12944,
+    12946,
+    12947,
+    12948,
+    12948,
+    12948,
+    12951,
+    12951,
+    12953,
+    12954,
+    12954,
+    12956,
+    12957,
+    12957,
+    12956,
+    12960,
+    12960,
+    12962,
+    12963,
+    12963,
+    12965,
+    12965,
+    12967,
+    12967,
+    12967,
+    12970,
+    12971,
+    12971,
+    12973,
+    12973,
+    12975,
+    12975,
+    12977,
+    12973,
+    12970,
+    12980,
+    12981,
+    12981,
+    12983,
+    12984,
+    12985,
+    12985,
+    12985,
+    12988,
+    12989,
+    12989,
+    12991,
+    12992,
+    12992,
+    12994,
+    12994,
+    12992,
+    12997,
+    12997,
+    12999,
+    13000,
+    13000,
+    12999,
+    13003,
+    13003,
+    13005,
+    13005,
+    13003,
+    13008,
+    13009,
+    13009,
+    13011,
+    13011,
+    13013,
+    13013,
+    13013,
+    13016,
+    13017,
+    13018,
+    13019,
+    13019,
+    13019,
+    13022,
+    13022,
+    13022,
+    13018,
+    13026,
+    13027,
+    13027,
+    13029,
+    13029,
+    13031,
+    13027,
+    13027,
+    13034,
+    13035,
+    13036,
+    13037,
+    13037,
+    13039,
+    13040,
+    13037,
+    13037,
+    13037,
+    13044,
+    13036,
+    13046,
+    13046,
+    13048,
+    13048,
+    13050,
+    13050,
+    13052,
+    13052,
+    13054,
+    13054,
+    13056,
+    13057,
+    13058,
+    13057,
+    13060,
+    13060,
+    13062,
+    13062,
+    13064,
+    13065,
+    13066,
+    13066,
+    13068,
+    13069,
+    13069,
+    13071,
+    13071,
+    13073,
+    13056,
+    13075,
+    13075,
+    13077,
+    13078,
+    13079,
+    13080,
+    13080,
+    13079,
+    13083,
+    13084,
+    13085,
+    13085,
+    13085,
+    13088,
+    13089,
+    13090,
+    13091,
+    13091,
+    13093,
+    13093,
+    13095,
+    13093,
+    13097,
+    13097,
+    13093,
+    13100,
+    13101,
+    13101,
+    13103,
+    13103,
+    13101,
+    13101,
+    13107,
+    13108,
+    13108,
+    13110,
+    13110,
+    13110,
+    13107,
+    13114,
+    13114,
+    13114,
+    13117,
+    13107,
+    13119,
+    13107,
+    13121,
+    13122,
+    13123,
+    13124,
+    13124,
+    13126,
+    13126,
+    13128,
+    13129,
+    13130,
+    13130,
+    13126,
+    13133,
+    13134,
+    13135,
+    13135,
+    13134,
+    13138,
+    13139,
+    13139,
+    13139,
+    13142,
+    13143,
+    13144,
+    13139,
+    13146,
+    13147,
+    13147,
+    13147,
+    13147,
+    13151,
+    13151,
+    13151,
+    13151,
+    13155,
+    13156,
+    13156,
+    13158,
+    13158,
+    13160,
+    13160,
+    13162,
+    13163,
+    13156,
+    13163,
+    13166,
+    13167,
+    13168,
+    13169,
+    13170,
+    13171,
+    13171,
+    13169,
+    13174,
+    13175,
+    13176,
+    13176,
+    13174,
+    13179,
+    13179,
+    13167,
+    13122,
+    13183,
+    13183,
+    13185,
+    13183,
+    13187,
+    13187,
+    13189,
+    13189,
+    13191,
+    13191,
+    13189,
+    13194,
+    13195,
+    13189,
+    13189,
+    13198,
+    13199,
+    13199,
+    13199,
+    13202,
+    13202,
+    13199,
+    13205,
+    13205,
+    13207,
+    13207,
+    13209,
+    13210,
+    13210,
+    13212,
+    13212,
+    13214,
+    13214,
+    13209,
+    13209,
+    13218,
+    13219,
+    13220,
+    13220,
+    13222,
+    13223,
+    13224,
+    13225,
+    13226,
+    13226,
+    13226,
+    13229,
+    13229,
+    13226,
+    13226,
+    13233,
+    13233,
+    13235,
+    13235,
+    13237,
+    13237,
+    13237,
+    13237,
+    13237,
+    13242,
+    13242,
+    13244,
+    13244,
+    13246,
+    13247,
+    13248,
+    13242,
+    13250,
+    13242,
+    13252,
+    13253,
+    13253,
+    13255,
+    13256,
+    13256,
+    13258,
+    13258,
+    13258,
+    13261,
+    13262,
+    13263,
+    13262,
+    13265,
+    13262,
+    13267,
+    13268,
+    13269,
+    13269,
+    13271,
+    13272,
+    13268,
+    13274,
+    13275,
+    13276,
+    13277,
+    13278,
+    13279,
+    13280,
+    13281,
+    13279,
+    13283,
+    13284,
+    13285,
+    13278,
+    13284,
+    13288,
+    13289,
+    13290,
+    13291,
+    13291,
+    13290,
+    13294,
+    13294,
+    13296,
+    13296,
+    13298,
+    13298,
+    13298,
+    13301,
+    13302,
+    13303,
+    13303,
+    13303,
+    13306,
+    13306,
+    13308,
+    13308,
+    13310,
+    13310,
+    13312,
+    13313,
+    13313,
+    13312,
+    13312,
+    13317,
+    13317,
+    13319,
+    13319,
+    13321,
+    13322,
+    13322,
+    13322,
+    13325,
+    13326,
+    13326,
+    13326,
+    13329,
+    13330,
+    13331,
+    13331,
+    13333,
+    13334,
+    13335,
+    13336,
+    13337,
+    13338,
+    13333,
+    13340,
+    13341,
+    13342,
+    13343,
+    13343,
+    13336,
+    13346,
+    13346,
+    13348,
+    13349,
+    13349,
+    13351,
+    13352,
+    13353,
+    13354,
+    13355,
+    13356,
+    13356,
+    13355,
+    13359,
+    13352,
+    13361,
+    13362,
+    13362,
+    13361,
+    13365,
+    13366,
+    13351,
+    13368,
+    13369,
+    13368,
+    13371,
+    13371,
+    13373,
+    13374,
+    13375,
+    13375,
+    13375,
+    13378,
+    13379,
+    13379,
+    13378,
+    13382,
+    13383,
+    13383,
+    13383,
+    13383,
+    13387,
+    13388,
+    13389,
+    13390,
+    13391,
+    13391,
+    13390,
+    13387,
+    13395,
+    13396,
+    13397,
+    13398,
+    13397,
+    13400,
+    13401,
+    13397,
+    13403,
+    13404,
+    13397,
+    13406,
+    13407,
+    13408,
+    13407,
+    13407,
+    13411,
+    13412,
+    13412,
+    13414,
+    13415,
+    13416,
+    13417,
+    13417,
+    13419,
+    13419,
+    13421,
+    13422,
+    13423,
+    13424,
+    13424,
+    13426,
+    13426,
+    13428,
+    13429,
+    13430,
+    13430,
+    13432,
+    13433,
+    13434,
+    13434,
+    13436,
+    13436,
+    13411,
+    13439,
+    13440,
+    13441,
+    13442,
+    13443,
+    13444,
+    13445,
+    13446,
+    13447,
+    13448,
+    13445,
+    13450,
+    13451,
+    13451,
+    13453,
+    13454,
+    13455,
+    13456,
+    13456,
+    13456,
+    13459,
+    13459,
+    13461,
+    13462,
+    13463,
+    13464,
+    13463,
+    13466,
+    13466,
+    13468,
+    13468,
+    13470,
+    13470,
+    13472,
+    13473,
+    13470,
+    13470,
+    13476,
+    13477,
+    13478,
+    13479,
+    13480,
+    13481,
+    13481,
+    13483,
+    13483,
+    13485,
+    13486,
+    13487,
+    13487,
+    13487,
+    13480,
+    13491,
+    13491,
+    13493,
+    13494,
+    13494,
+    13496,
+    13496,
+    13493,
+    13499,
+    13499,
+    13499,
+    13502,
+    13503,
+    13503,
+    13505,
+    13506,
+    13499,
+    13477,
+    13509,
+    13510,
+    13511,
+    13509,
+    13513,
+    13513,
+    13513,
+    13516,
+    13517,
+    13517,
+    13517,
+    13520,
+    13520,
+    13522,
+    13523,
+    13523,
+    13523,
+    13526,
+    13527,
+    13528,
+    13529,
+    13530,
+    13529,
+    13532,
+    13533,
+    13534,
+    13535,
+    13536,
+    13536,
+    13538,
+    13538,
+    13538,
+    13541,
+    13542,
+    13543,
+    13544,
+    13535,
+    13546,
+    13546,
+    13546,
+    13543,
+    13550,
+    13551,
+    13551,
+    13553,
+    13554,
+    13555,
+    13556,
+    13557,
+    13557,
+    13559,
+    13560,
+    13561,
+    13562,
+    13563,
+    13563,
+    13565,
+    13566,
+    13567,
+    13568,
+    13569,
+    13569,
+    13571,
+    13571,
+    13573,
+    13574,
+    13571,
+    13571,
+    13568,
+    13578,
+    13579,
+    13579,
+    13581,
+    13581,
+    13578,
+    13584,
+    13584,
+    13586,
+    13587,
+    13586,
+    13589,
+    13590,
+    13589,
+    13561,
+    13593,
+    13594,
+    13595,
+    13596,
+    13597,
+    13598,
+    13597,
+    13600,
+    13600,
+    13602,
+    13602,
+    13600,
+    13600,
+    13600,
+    13607,
+    13608,
+    13608,
+    13608,
+    13611,
+    13611,
+    13613,
+    13613,
+    13611,
+    13616,
+    13617,
+    13618,
+    13619,
+    13608,
+    13608,
+    13622,
+    13623,
+    13624,
+    13624,
+    13626,
+    13627,
+    13628,
+    13628,
+    13630,
+    13627,
+    13632,
+    13633,
+    13634,
+    13635,
+    13636,
+    13637,
+    13636,
+    13639,
+    13639,
+    13639,
+    13639,
+    13639,
+    13644,
+    13644,
+    13646,
+    13647,
+    13647,
+    13647,
+    13650,
+    13651,
+    13652,
+    13653,
+    13653,
+    13655,
+    13656,
+    13657,
+    13657,
+    13655,
+    13660,
+    13661,
+    13660,
+    13663,
+    13664,
+    13665,
+    13666,
+    13666,
+    13666,
+    13666,
+    13666,
+    13671,
+    13672,
+    13672,
+    13674,
+    13674,
+    13676,
+    13676,
+    13678,
+    13678,
+    13678,
+    13681,
+    13671,
+    13683,
+    13684,
+    13684,
+    13686,
+    13687,
+    13684,
+    13689,
+    13689,
+    13691,
+    13692,
+    13693,
+    13694,
+    13693,
+    13635,
+    13697,
+    13698,
+    13699,
+    13697,
+    13701,
+    13702,
+    13702,
+    13704,
+    13704,
+    13704,
+    13707,
+    13707,
+    13701,
+    13710,
+    13710,
+    13710,
+    13710,
+    13710,
+    13715,
+    13715,
+    13717,
+    13718,
+    13717,
+    13720,
+    13721,
+    13722,
+    13722,
+    13717,
+    13725,
+    13726,
+    13727,
+    13728,
+    13728,
+    13730,
+    13731,
+    13732,
+    13733,
+    13734,
+    13735,
+    13736,
+    13736,
+    13738,
+    13739,
+    13740,
+    13740,
+    13742,
+    13742,
+    13744,
+    13744,
+    13746,
+    13747,
+    13748,
+    13748,
+    13750,
+    13751,
+    13752,
+    13753,
+    13753,
+    13755,
+    13755,
+    13757,
+    13757,
+    13759,
+    13759,
+    13761,
+    13762,
+    13763,
+    13762,
+    13765,
+    13766,
+    13767,
+    13767,
+    13766,
+    13770,
+    13770,
+    13772,
+    13772,
+    13770,
+    13766,
+    13776,
+    13777,
+    13777,
+    13779,
+    13779,
+    13779,
+    13782,
+    13783,
+    13784,
+    13785,
+    13785,
+    13787,
+    13788,
+    13789,
+    13790,
+    13790,
+    13792,
+    13792,
+    13790,
+    13795,
+    13795,
+    13797,
+    13797,
+    13799,
+    13799,
+    13799,
+    13802,
+    13803,
+    13803,
+    13803,
+    13806,
+    13807,
+    13807,
+    13807,
+    13807,
+    13811,
+    13811,
+    13813,
+    13814,
+    13814,
+    13816,
+    13817,
+    13818,
+    13813,
+    13820,
+    13821,
+    13822,
+    13823,
+    13821,
+    13825,
+    13826,
+    13826,
+    13828,
+    13829,
+    13829,
+    13831,
+    13832,
+    13833,
+    13833,
+    13835,
+    13835,
+    13832,
+    13832,
+    13839,
+    13839,
+    13839,
+    13832,
+    13806,
+    13844,
+    13845,
+    13845,
+    13847,
+    13845,
+    13849,
+    13850,
+    13851,
+    13852,
+    13852,
+    13854,
+    13852,
+    13852,
+    13852,
+    13858,
+    13859,
+    13859,
+    13859,
+    13862,
+    13863,
+    13864,
+    13865,
+    13865,
+    13865,
+    13864,
+    13869,
+    13869,
+    13869,
+    13872,
+    13873,
+    13874,
+    13875,
+    13876,
+    13877,
+    13878,
+    13879,
+    13880,
+    13881,
+    13882,
+    13883,
+    13884,
+    13882,
+    13886,
+    13887,
+    13887,
+    13889,
+    13889,
+    13886,
+    13886,
+    13893,
+    13894,
+    13895,
+    13896,
+    13897,
+    13898,
+    13895,
+    13900,
+    13901,
+    13902,
+    13903,
+    13904,
+    13905,
+    13906,
+    13907,
+    13906,
+    13909,
+    13909,
+    13911,
+    13912,
+    13911,
+    13914,
+    13915,
+    13916,
+    13917,
+    13918,
+    13919,
+    13920,
+    13920,
+    13922,
+    13922,
+    13924,
+    13924,
+    13920,
+    13920,
+    13928,
+    13928,
+    13930,
+    13931,
+    13930,
+    13933,
+    13934,
+    13935,
+    13935,
+    13937,
+    13938,
+    13939,
+    13934,
+    13941,
+    13941,
+    13941,
+    13944,
+    13945,
+    13946,
+    13947,
+    13948,
+    13948,
+    13950,
+    13951,
+    13951,
+    13953,
+    13953,
+    13955,
+    13953,
+    13957,
+    13945,
+    13959,
+    13959,
+    13961,
+    13962,
+    13963,
+    13964,
+    13965,
+    13965,
+    13967,
+    13967,
+    13969,
+    13969,
+    13969,
+    13972,
+    13972,
+    13972,
+    13975,
+    13976,
+    13977,
+    13978,
+    13979,
+    13980,
+    13980,
+    13982,
+    13983,
+    13983,
+    13985,
+    13985,
+    13987,
+    13987,
+    13985,
+    13985,
+    13991,
+    13991,
+    13993,
+    13993,
+    13991,
+    13996,
+    13996,
+    13998,
+    13999,
+    14000,
+    14000,
+    14002,
+    14002,
+    14002,
+    14005,
+    14005,
+    13991,
+    14008,
+    14009,
+    13977,
+    14011,
+    14012,
+    14013,
+    14013,
+    14015,
+    14016,
+    14017,
+    14017,
+    14019,
+    14020,
+    14021,
+    14022,
+    14015,
+    14024,
+    14025,
+    14025,
+    14027,
+    14028,
+    14029,
+    14029,
+    14031,
+    14032,
+    14032,
+    14034,
+    14035,
+    14035,
+    14037,
+    14037,
+    14035,
+    14028,
+    14041,
+    14041,
+    14043,
+    14043,
+    14045,
+    14045,
+    14047,
+    14047,
+    14049,
+    14050,
+    14051,
+    14043,
+    14053,
+    14054,
+    14055,
+    14012,
+    14057,
+    14058,
+    14059,
+    14060,
+    14060,
+    14062,
+    14063,
+    14059,
+    14065,
+    14065,
+    14067,
+    14067,
+    14069,
+    14070,
+    14070,
+    14072,
+    14070,
+    14070,
+    14075,
+    14075,
+    14077,
+    14078,
+    14079,
+    14079,
+    14079,
+    14082,
+    14082,
+    14079,
+    14085,
+    14086,
+    14087,
+    14088,
+    14088,
+    14088,
+    14091,
+    14091,
+    14093,
+    14058,
+    14095,
+    14096,
+    14097,
+    14077,
+    14099,
+    14099,
+    14101,
+    14102,
+    14103,
+    14104,
+    14105,
+    14105,
+    14107,
+    14108,
+    14109,
+    14110,
+    14111,
+    14105,
+    14113,
+    14114,
+    14115,
+    14115,
+    14117,
+    14118,
+    14119,
+    14120,
+    14121,
+    14122,
+    14122,
+    14124,
+    14124,
+    14126,
+    14126,
+    14128,
+    14128,
+    14130,
+    14131,
+    14131,
+    14133,
+    14134,
+    14134,
+    14134,
+    14134,
+    14138,
+    14138,
+    14138,
+    14141,
+    14141,
+    14138,
+    14144,
+    14144,
+    14146,
+    14146,
+    14148,
+    14148,
+    14150,
+    14151,
+    14151,
+    14153,
+    14154,
+    14155,
+    14156,
+    14156,
+    14158,
+    14158,
+    14158,
+    14161,
+    14153,
+    14155,
+    14164,
+    14164,
+    14166,
+    14166,
+    14164,
+    14169,
+    14170,
+    14170,
+    14172,
+    14172,
+    14174,
+    14174,
+    14176,
+    14177,
+    14169,
+    14179,
+    14180,
+    14181,
+    14182,
+    14180,
+    14184,
+    14184,
+    14186,
+    14186,
+    14188,
+    14189,
+    14190,
+    14190,
+    14192,
+    14192,
+    14194,
+    14195,
+    14190,
+    14197,
+    14198,
+    14198,
+    14200,
+    14200,
+    14202,
+    14202,
+    14200,
+    14205,
+    14205,
+    14207,
+    14208,
+    14209,
+    14209,
+    14211,
+    14209,
+    14213,
+    14213,
+    14215,
+    14215,
+    14217,
+    14217,
+    14217,
+    14220,
+    14220,
+    14222,
+    14223,
+    14223,
+    14208,
+    14226,
+    14226,
+    14226,
+    14229,
+    14230,
+    14231,
+    14231,
+    14233,
+    14234,
+    14235,
+    14235,
+    14237,
+    14237,
+    14239,
+    14239,
+    14241,
+    14241,
+    14243,
+    14243,
+    14241,
+    14246,
+    14246,
+    14246,
+    14249,
+    14249,
+    14230,
+    14252,
+    14252,
+    14254,
+    14255,
+    14255,
+    14257,
+    14258,
+    14258,
+    14252,
+    14261,
+    14262,
+    14263,
+    14264,
+    14265,
+    14266,
+    14267,
+    14268,
+    14269,
+    14269,
+    14268,
+    14272,
+    14272,
+    14229,
+    14275,
+    14275,
+    14277,
+    14278,
+    14279,
+    14280,
+    14280,
+    14282,
+    14283,
+    14283,
+    14285,
+    14285,
+    14287,
+    14288,
+    14288,
+    14290,
+    14290,
+    14278,
+    14293,
+    14294,
+    14294,
+    14296,
+    14296,
+    14293,
+    14299,
+    14299,
+    14301,
+    14302,
+    14302,
+    14301,
+    14305,
+    14305,
+    14301,
+    14308,
+    14309,
+    14309,
+    14311,
+    14311,
+    14309,
+    14314,
+    14314,
+    14316,
+    14316,
+    14308,
+    14319,
+    14320,
+    14321,
+    14322,
+    14322,
+    14324,
+    14324,
+    14324,
+    14327,
+    14328,
+    14328,
+    14321,
+    14331,
+    14331,
+    14333,
+    14334,
+    14334,
+    14333,
+    14337,
+    14338,
+    14339,
+    14339,
+    14341,
+    14342,
+    14343,
+    14344,
+    14345,
+    14343,
+    14347,
+    14347,
+    14349,
+    14349,
+    14351,
+    14352,
+    14352,
+    14354,
+    14354,
+    14354,
+    14357,
+    14357,
+    14320,
+    14360,
+    14361,
+    14361,
+    14363,
+    14364,
+    14364,
+    14366,
+    14367,
+    14368,
+    14369,
+    14369,
+    14371,
+    14372,
+    14373,
+    14374,
+    14375,
+    14376,
+    14377,
+    14378,
+    14379,
+    14380,
+    14381,
+    14382,
+    14382,
+    14384,
+    14385,
+    14386,
+    14386,
+    14388,
+    14389,
+    14390,
+    14391,
+    14392,
+    14393,
+    14393,
+    14395,
+    14395,
+    14393,
+    14398,
+    14398,
+    14400,
+    14400,
+    14402,
+    14402,
+    14398,
+    14405,
+    14406,
+    14406,
+    14405,
+    14409,
+    14409,
+    14392,
+    14412,
+    14413,
+    14414,
+    14415,
+    14415,
+    14417,
+    14418,
+    14418,
+    14415,
+    14421,
+    14422,
+    14422,
+    14424,
+    14425,
+    14426,
+    14427,
+    14428,
+    14429,
+    14430,
+    14425,
+    14428,
+    14433,
+    14433,
+    14435,
+    14435,
+    14435,
+    14438,
+    14439,
+    14440,
+    14440,
+    14442,
+    14443,
+    14444,
+    14445,
+    14445,
+    14447,
+    14447,
+    14449,
+    14449,
+    14413,
+    14452,
+    14452,
+    14454,
+    14455,
+    14456,
+    14457,
+    14458,
+    14459,
+    14460,
+    14460,
+    14462,
+    14462,
+    14459,
+    14465,
+    14465,
+    14467,
+    14468,
+    14468,
+    14470,
+    14470,
+    14472,
+    14472,
+    14474,
+    14475,
+    14476,
+    14477,
+    14478,
+    14479,
+    14470,
+    14475,
+    14482,
+    14482,
+    14484,
+    14484,
+    14482,
+    14487,
+    14467,
+    14489,
+    14490,
+    14491,
+    14492,
+    14493,
+    14491,
+    14495,
+    14496,
+    14496,
+    14495,
+    14499,
+    14499,
+    14501,
+    14501,
+    14495,
+    14504,
+    14505,
+    14505,
+    14507,
+    14507,
+    14505,
+    14510,
+    14510,
+    14512,
+    14513,
+    14513,
+    14515,
+    14515,
+    14517,
+    14518,
+    14517,
+    14520,
+    14521,
+    14522,
+    14522,
+    14524,
+    14525,
+    14526,
+    14526,
+    14525,
+    14529,
+    14529,
+    14531,
+    14532,
+    14533,
+    14533,
+    14535,
+    14536,
+    14536,
+    14538,
+    14539,
+    14540,
+    14539,
+    14542,
+    14542,
+    14538,
+    14545,
+    14545,
+    14547,
+    14547,
+    14549,
+    14549,
+    14551,
+    14551,
+    14553,
+    14535,
+    14555,
+    14555,
+    14557,
+    14558,
+    14559,
+    14560,
+    14560,
+    14557,
+    14563,
+    14564,
+    14565,
+    14566,
+    14567,
+    14568,
+    14567,
+    14570,
+    14571,
+    14572,
+    14572,
+    14574,
+    14575,
+    14575,
+    14577,
+    14577,
+    14579,
+    14580,
+    14580,
+    14582,
+    14582,
+    14584,
+    14585,
+    14586,
+    14586,
+    14585,
+    14589,
+    14590,
+    14590,
+    14566,
+    14593,
+    14594,
+    14595,
+    14596,
+    14597,
+    14593,
+    14599,
+    14600,
+    14601,
+    14602,
+    14600,
+    14604,
+    14605,
+    14606,
+    14606,
+    14608,
+    14609,
+    14608,
+    14611,
+    14612,
+    14613,
+    14614,
+    14604,
+    14616,
+    14617,
+    14618,
+    14618,
+    14620,
+    14620,
+    14622,
+    14622,
+    14624,
+    14624,
+    14626,
+    14626,
+    14628,
+    14629,
+    14630,
+    14629,
+    14632,
+    14633,
+    14634,
+    14628,
+    14636,
+    14637,
+    14638,
+    14639,
+    14640,
+    14639,
+    14642,
+    14643,
+    14644,
+    14645,
+    14646,
+    14647,
+    14648,
+    14648,
+    14647,
+    14651,
+    14651,
+    14653,
+    14653,
+    14655,
+    14656,
+    14657,
+    14658,
+    14659,
+    14660,
+    14660,
+    14662,
+    14662,
+    14664,
+    14664,
+    14664,
+    14667,
+    14667,
+    14669,
+    14669,
+    14671,
+    14671,
+    14673,
+    14673,
+    14675,
+    14676,
+    14677,
+    14678,
+    14679,
+    14680,
+    14681,
+    14681,
+    14680,
+    14684,
+    14684,
+    14686,
+    14686,
+    14688,
+    14689,
+    14689,
+    14691,
+    14692,
+    14692,
+    14689,
+    14695,
+    14695,
+    14688,
+    14698,
+    14699,
+    14700,
+    14700,
+    14702,
+    14703,
+    14704,
+    14702,
+    14706,
+    14706,
+    14708,
+    14709,
+    14709,
+    14708,
+    14712,
+    14708,
+    14714,
+    14714,
+    14716,
+    14716,
+    14718,
+    14718,
+    14720,
+    14720,
+    14722,
+    14723,
+    14723,
+    14722,
+    14726,
+    14727,
+    14728,
+    14729,
+    14729,
+    14728,
+    14732,
+    14732,
+    14732,
+    14735,
+    14735,
+    14737,
+    14737,
+    14739,
+    14739,
+    14739,
+    14742,
+    14743,
+    14744,
+    14744,
+    14746,
+    14746,
+    14743,
+    14749,
+    14749,
+    14751,
+    14751,
+    14753,
+    14754,
+    14754,
+    14753,
+    14757,
+    14758,
+    14759,
+    14759,
+    14761,
+    14762,
+    14763,
+    14764,
+    14764,
+    14763,
+    14767,
+    14767,
+    14769,
+    14770,
+    14770,
+    14770,
+    14773,
+    14774,
+    14774,
+    14773,
+    14777,
+    14778,
+    14778,
+    14780,
+    14780,
+    14780,
+    14778,
+    14784,
+    14785,
+    14786,
+    14787,
+    14788,
+    14789,
+    14790,
+    14790,
+    14792,
+    14792,
+    14794,
+    14794,
+    14789,
+    14797,
+    14798,
+    14799,
+    14800,
+    14800,
+    14802,
+    14802,
+    14804,
+    14804,
+    14799,
+    14807,
+    14807,
+    14809,
+    14809,
+    14811,
+    14812,
+    14812,
+    14812,
+    14815,
+    14815,
+    14817,
+    14818,
+    14819,
+    14819,
+    14821,
+    14822,
+    14818,
+    14824,
+    14825,
+    14826,
+    14827,
+    14828,
+    14829,
+    14830,
+    14831,
+    14831,
+    14831,
+    14830,
+    14835,
+    14836,
+    14836,
+    14836,
+    14839,
+    14840,
+    14841,
+    14841,
+    14843,
+    14843,
+    14843,
+    14840,
+    14847,
+    14847,
+    14849,
+    14847,
+    14839,
+    14852,
+    14852,
+    14854,
+    14854,
+    14852,
+    14857,
+    14858,
+    14857,
+    14857,
+    14861,
+    14862,
+    14862,
+    14862,
+    14865,
+    14866,
+    14867,
+    14868,
+    14869,
+    14870,
+    14871,
+    14871,
+    14873,
+    14874,
+    14874,
+    14871,
+    14877,
+    14877,
+    14879,
+    14880,
+    14881,
+    14882,
+    14882,
+    14884,
+    14885,
+    14885,
+    14887,
+    14888,
+    14889,
+    14889,
+    14891,
+    14891,
+    14893,
+    14894,
+    14893,
+    14896,
+    14897,
+    14897,
+    14899,
+    14899,
+    14901,
+    14901,
+    14903,
+    14904,
+    14905,
+    14905,
+    14907,
+    14907,
+    14904,
+    14910,
+    14911,
+    14912,
+    14913,
+    14913,
+    14913,
+    14916,
+    14916,
+    14918,
+    14912,
+    14920,
+    14920,
+    14920,
+    14923,
+    14924,
+    14924,
+    14926,
+    14927,
+    14928,
+    14929,
+    14930,
+    14930,
+    14932,
+    14929,
+    14934,
+    14934,
+    14934,
+    14937,
+    14938,
+    14938,
+    14940,
+    14940,
+    14942,
+    14942,
+    14940,
+    14945,
+    14946,
+    14946,
+    14948,
+    14948,
+    14950,
+    14948,
+    14952,
+    14953,
+    14953,
+    14945,
+    14956,
+    14957,
+    14957,
+    14959,
+    14960,
+    14961,
+    14960,
+    14963,
+    14964,
+    14964,
+    14966,
+    14966,
+    14968,
+    14968,
+    14970,
+    14968,
+    14972,
+    14973,
+    14973,
+    14973,
+    14976,
+    14976,
+    14978,
+    14978,
+    14976,
+    14981,
+    14981,
+    14983,
+    14983,
+    14981,
+    14986,
+    14986,
+    14986,
+    14989,
+    14989,
+    14991,
+    14991,
+    14993,
+    14994,
+    14995,
+    14995,
+    14997,
+    14997,
+    14999,
+    14999,
+    14995,
+    15002,
+    15003,
+    15003,
+    15005,
+    15006,
+    15007,
+    15008,
+    15009,
+    15009,
+    15011,
+    15012,
+    15013,
+    15008,
+    15015,
+    15015,
+    15015,
+    15018,
+    15018,
+    15020,
+    15021,
+    15021,
+    15023,
+    15023,
+    15023,
+    15026,
+    15027,
+    15028,
+    15028,
+    15026,
+    15020,
+    15032,
+    15032,
+    15034,
+    15034,
+    15036,
+    15036,
+    15038,
+    15039,
+    15039,
+    15038,
+    15042,
+    15043,
+    15044,
+    15045,
+    15046,
+    15044,
+    15048,
+    15048,
+    15050,
+    15051,
+    15051,
+    15053,
+    15051,
+    15055,
+    15050,
+    15057,
+    15057,
+    15059,
+    15060,
+    15061,
+    15059,
+    15063,
+    15063,
+    15065,
+    15065,
+    15063,
+    15068,
+    15068,
+    15070,
+    15071,
+    15072,
+    15073,
+    15074,
+    15075,
+    15076,
+    15076,
+    15078,
+    15078,
+    15080,
+    15081,
+    15082,
+    15081,
+    15084,
+    15084,
+    15086,
+    15086,
+    15088,
+    15088,
+    15086,
+    15091,
+    15080,
+    15093,
+    15094,
+    15095,
+    15095,
+    15097,
+    15098,
+    15099,
+    15100,
+    15093,
+    15102,
+    15103,
+    15104,
+    15105,
+    15106,
+    15106,
+    15108,
+    15109,
+    15110,
+    15111,
+    15111,
+    15113,
+    15113,
+    15105,
+    15116,
+    15110,
+    15118,
+    15119,
+    15120,
+    15121,
+    15121,
+    15120,
+    15124,
+    15124,
+    15126,
+    15127,
+    15128,
+    15129,
+    15130,
+    15130,
+    15118,
+    15133,
+    15134,
+    15134,
+    15136,
+    15137,
+    15137,
+    15139,
+    15139,
+    15136,
+    15142,
+    15143,
+    15144,
+    15145,
+    15146,
+    15147,
+    15147,
+    15149,
+    15149,
+    15151,
+    15151,
+    15151,
+    15154,
+    15154,
+    15156,
+    15157,
+    15158,
+    15158,
+    15160,
+    15160,
+    15162,
+    15163,
+    15164,
+    15165,
+    15166,
+    15167,
+    15157,
+    15169,
+    15170,
+    15170,
+    15172,
+    15173,
+    15174,
+    15174,
+    15173,
+    15177,
+    15177,
+    15179,
+    15179,
+    15177,
+    15182,
+    15182,
+    15184,
+    15184,
+    15186,
+    15186,
+    15188,
+    15189,
+    15189,
+    15191,
+    15191,
+    15188,
+    15194,
+    15195,
+    15195,
+    15197,
+    15198,
+    15199,
+    15198,
+    15201,
+    15201,
+    15203,
+    15204,
+    15204,
+    15206,
+    15206,
+    15208,
+    15209,
+    15209,
+    15211,
+    15212,
+    15212,
+    15197,
+    15215,
+    15216,
+    15216,
+    15218,
+    15218,
+    15220,
+    15221,
+    15222,
+    15223,
+    15223,
+    15222,
+    15226,
+    15227,
+    15228,
+    15228,
+    15230,
+    15230,
+    15230,
+    15233,
+    15233,
+    15235,
+    15236,
+    15237,
+    15235,
+    15239,
+    15240,
+    15241,
+    15242,
+    15240,
+    15239,
+    15239,
+    15246,
+    15247,
+    15247,
+    15249,
+    15250,
+    15251,
+    15252,
+    15253,
+    15254,
+    15255,
+    15256,
+    15257,
+    15258,
+    15259,
+    15260,
+    15260,
+    15259,
+    15263,
+    15264,
+    15265,
+    15265,
+    15264,
+    15268,
+    15268,
+    15270,
+    15270,
+    15272,
+    15273,
+    15274,
+    15274,
+    15274,
+    15277,
+    15278,
+    15278,
+    15280,
+    15277,
+    15282,
+    15283,
+    15284,
+    15283,
+    15286,
+    15286,
+    15288,
+    15289,
+    15290,
+    15288,
+    15292,
+    15293,
+    15294,
+    15294,
+    15293,
+    15297,
+    15297,
+    15297,
+    15300,
+    15301,
+    15301,
+    15303,
+    15303,
+    15305,
+    15305,
+    15307,
+    15307,
+    15309,
+    15309,
+    15309,
+    15312,
+    15313,
+    15313,
+    15315,
+    15316,
+    15317,
+    15318,
+    15315,
+    15320,
+    15321,
+    15321,
+    15320,
+    15324,
+    15324,
+    15326,
+    15324,
+    15328,
+    15328,
+    15330,
+    15331,
+    15332,
+    15332,
+    15334,
+    15335,
+    15335,
+    15337,
+    15337,
+    15339,
+    15340,
+    15340,
+    15339,
+    15334,
+    15344,
+    15345,
+    15345,
+    15345,
+    15348,
+    15348,
+    15350,
+    15350,
+    15350,
+    15353,
+    15354,
+    15355,
+    15356,
+    15357,
+    15358,
+    15359,
+    15360,
+    15359,
+    15362,
+    15360,
+    15356,
+    15365,
+    15366,
+    15367,
+    15368,
+    15369,
+    15370,
+    15366,
+    15372,
+    15372,
+    15374,
+    15374,
+    15365,
+    15377,
+    15377,
+    15377,
+    15380,
+    15380,
+    15382,
+    15382,
+    15384,
+    15385,
+    15386,
+    15387,
+    15387,
+    15389,
+    15390,
+    15391,
+    15391,
+    15393,
+    15393,
+    15395,
+    15395,
+    15397,
+    15397,
+    15399,
+    15391,
+    15401,
+    15402,
+    15402,
+    15404,
+    15405,
+    15405,
+    15404,
+    15408,
+    15408,
+    15410,
+    15411,
+    15411,
+    15413,
+    15414,
+    15414,
+    15416,
+    15414,
+    15418,
+    15418,
+    15420,
+    15421,
+    15422,
+    15421,
+    15424,
+    15425,
+    15425,
+    15427,
+    15428,
+    15428,
+    15430,
+    15430,
+    15432,
+    15433,
+    15428,
+    15435,
+    15436,
+    15437,
+    15438,
+    15439,
+    15439,
+    15441,
+    15427,
+    15438,
+    15444,
+    15444,
+    15446,
+    15447,
+    15448,
+    15448,
+    15450,
+    15451,
+    15451,
+    15444,
+    15454,
+    15454,
+    15454,
+    15457,
+    15458,
+    15459,
+    15460,
+    15459,
+    15462,
+    15463,
+    15463,
+    15465,
+    15465,
+    15467,
+    15467,
+    15469,
+    15469,
+    15471,
+    15472,
+    15473,
+    15474,
+    15474,
+    15476,
+    15472,
+    15478,
+    15479,
+    15480,
+    15480,
+    15482,
+    15482,
+    15479,
+    15485,
+    15485,
+    15487,
+    15487,
+    15489,
+    15490,
+    15491,
+    15491,
+    15491,
+    15494,
+    15495,
+    15496,
+    15497,
+    15497,
+    15497,
+    15500,
+    15500,
+    15500,
+    15503,
+    15504,
+    15505,
+    15506,
+    15506,
+    15508,
+    15508,
+    15505,
+    15511,
+    15511,
+    15513,
+    15514,
+    15514,
+    15514,
+    15517,
+    15518,
+    15519,
+    15513,
+    15521,
+    15522,
+    15523,
+    15524,
+    15525,
+    15526,
+    15527,
+    15528,
+    15529,
+    15530,
+    15531,
+    15532,
+    15533,
+    15533,
+    15532,
+    15536,
+    15536,
+    15538,
+    15539,
+    15539,
+    15541,
+    15541,
+    15541,
+    15544,
+    15544,
+    15546,
+    15547,
+    15548,
+    15548,
+    15548,
+    15551,
+    15551,
+    15553,
+    15554,
+    15555,
+    15555,
+    15555,
+    15558,
+    15559,
+    15560,
+    15561,
+    15562,
+    15563,
+    15563,
+    15565,
+    15565,
+    15565,
+    15568,
+    15569,
+    15570,
+    15571,
+    15572,
+    15571,
+    15574,
+    15575,
+    15576,
+    15575,
+    15578,
+    15578,
+    15575,
+    15581,
+    15582,
+    15582,
+    15584,
+    15584,
+    15584,
+    15587,
+    15588,
+    15588,
+    15590,
+    15590,
+    15588,
+    15593,
+    15594,
+    15595,
+    15596,
+    15597,
+    15597,
+    15599,
+    15597,
+    15601,
+    15602,
+    15603,
+    15603,
+    15595,
+    15606,
+    15606,
+    15608,
+    15609,
+    15609,
+    15611,
+    15611,
+    15613,
+    15613,
+    15615,
+    15616,
+    15616,
+    15615,
+    15619,
+    15620,
+    15620,
+    15620,
+    15623,
+    15624,
+    15625,
+    15625,
+    15627,
+    15628,
+    15628,
+    15630,
+    15631,
+    15631,
+    15633,
+    15634,
+    15635,
+    15628,
+    15637,
+    15638,
+    15638,
+    15640,
+    15640,
+    15640,
+    15638,
+    15644,
+    15645,
+    15645,
+    15645,
+    15648,
+    15649,
+    15650,
+    15651,
+    15651,
+    15653,
+    15653,
+    15655,
+    15656,
+    15656,
+    15655,
+    15659,
+    15660,
+    15661,
+    15661,
+    15663,
+    15660,
+    15665,
+    15665,
+    15667,
+    15667,
+    15669,
+    15669,
+    15671,
+    15672,
+    15672,
+    15674,
+    15674,
+    15671,
+    15677,
+    15678,
+    15679,
+    15680,
+    15680,
+    15682,
+    15683,
+    15684,
+    15684,
+    15682,
+    15687,
+    15687,
+    15689,
+    15689,
+    15678,
+    15692,
+    15693,
+    15693,
+    15692,
+    15696,
+    15697,
+    15698,
+    15698,
+    15698,
+    15701,
+    15701,
+    15701,
+    15704,
+    15705,
+    15705,
+    15707,
+    15707,
+    15709,
+    15709,
+    15711,
+    15711,
+    15713,
+    15714,
+    15714,
+    15716,
+    15717,
+    15717,
+    15719,
+    15720,
+    15721,
+    15721,
+    15720,
+    15724,
+    15724,
+    15726,
+    15726,
+    15728,
+    15728,
+    15730,
+    15730,
+    15732,
+    15733,
+    15730,
+    15735,
+    15736,
+    15737,
+    15737,
+    15739,
+    15739,
+    15741,
+    15742,
+    15743,
+    15737,
+    15745,
+    15719,
+    15747,
+    15748,
+    15749,
+    15750,
+    15750,
+    15749,
+    15753,
+    15753,
+    15755,
+    15755,
+    15757,
+    15758,
+    15758,
+    15760,
+    15760,
+    15762,
+    15762,
+    15748,
+    15765,
+    15766,
+    15766,
+    15768,
+    15769,
+    15769,
+    15771,
+    15771,
+    15773,
+    15773,
+    15775,
+    15775,
+    15777,
+    15777,
+    15777,
+    15780,
+    15781,
+    15775,
+    15783,
+    15784,
+    15784,
+    15786,
+    15786,
+    15788,
+    15788,
+    15790,
+    15790,
+    15792,
+    15792,
+    15768,
+    15795,
+    15796,
+    15797,
+    15798,
+    15799,
+    15800,
+    15800,
+    15802,
+    15802,
+    15804,
+    15805,
+    15805,
+    15807,
+    15808,
+    15809,
+    15809,
+    15811,
+    15812,
+    15811,
+    15814,
+    15815,
+    15816,
+    15816,
+    15818,
+    15818,
+    15815,
+    15808,
+    15822,
+    15823,
+    15824,
+    15824,
+    15826,
+    15827,
+    15828,
+    15829,
+    15830,
+    15831,
+    15832,
+    15833,
+    15834,
+    15833,
+    15836,
+    15837,
+    15838,
+    15839,
+    15840,
+    15841,
+    15840,
+    15843,
+    15844,
+    15845,
+    15846,
+    15846,
+    15848,
+    15848,
+    15850,
+    15851,
+    15851,
+    15853,
+    15853,
+    15848,
+    15856,
+    15857,
+    15857,
+    15856,
+    15860,
+    15861,
+    15861,
+    15863,
+    15863,
+    15863,
+    15866,
+    15867,
+    15868,
+    15869,
+    15870,
+    15871,
+    15871,
+    15867,
+    15874,
+    15875,
+    15876,
+    15877,
+    15878,
+    15879,
+    15880,
+    15881,
+    15882,
+    15883,
+    15884,
+    15882,
+    15886,
+    15886,
+    15888,
+    15889,
+    15889,
+    15891,
+    15891,
+    15888,
+    15894,
+    15895,
+    15896,
+    15897,
+    15898,
+    15899,
+    15900,
+    15901,
+    15902,
+    15902,
+    15902,
+    15905,
+    15905,
+    15907,
+    15907,
+    15907,
+    15910,
+    15910,
+    15912,
+    15913,
+    15914,
+    15915,
+    15915,
+    15917,
+    15917,
+    15913,
+    15920,
+    15921,
+    15922,
+    15922,
+    15924,
+    15924,
+    15924,
+    15927,
+    15927,
+    15929,
+    15930,
+    15930,
+    15930,
+    15933,
+    15933,
+    15935,
+    15935,
+    15937,
+    15938,
+    15939,
+    15939,
+    15941,
+    15941,
+    15943,
+    15944,
+    15944,
+    15943,
+    15947,
+    15947,
+    15949,
+    15950,
+    15950,
+    15943,
+    15943,
+    15954,
+    15954,
+    15956,
+    15956,
+    15954,
+    15959,
+    15960,
+    15954,
+    15962,
+    15963,
+    15964,
+    15964,
+    15943,
+    15967,
+    15968,
+    15968,
+    15968,
+    15971,
+    15972,
+    15972,
+    15974,
+    15974,
+    15976,
+    15977,
+    15976,
+    15967,
+    15980,
+    15981,
+    15982,
+    15981,
+    15984,
+    15985,
+    15986,
+    15986,
+    15988,
+    15984,
+    15990,
+    15981,
+    15992,
+    15992,
+    15994,
+    15995,
+    15995,
+    15997,
+    15997,
+    15999,
+    15999,
+    16001,
+    16001,
+    15999,
+    15999,
+    16005,
+    16006,
+    16007,
+    16007,
+    16009,
+    16009,
+    16011,
+    16011,
+    16013,
+    16005,
+    16015,
+    16015,
+    16017,
+    16017,
+    16019,
+    16019,
+    16021,
+    16021,
+    16023,
+    16023,
+    16021,
+    16026,
+    16027,
+    16028,
+    16029,
+    16029,
+    16031,
+    16031,
+    16033,
+    16034,
+    16034,
+    16036,
+    16036,
+    16033,
+    16039,
+    16039,
+    16041,
+    16041,
+    16033,
+    16044,
+    16045,
+    16046,
+    16046,
+    16048,
+    16048,
+    16050,
+    16050,
+    16052,
+    16052,
+    16054,
+    16054,
+    16056,
+    16056,
+    16058,
+    16058,
+    16060,
+    16060,
+    16062,
+    16062,
+    16064,
+    16064,
+    16066,
+    16062,
+    16068,
+    16069,
+    16070,
+    16069,
+    16072,
+    16072,
+    16074,
+    16074,
+    16076,
+    16076,
+    16078,
+    16078,
+    16080,
+    16081,
+    16081,
+    16083,
+    16084,
+    16084,
+    16083,
+    16080,
+    16088,
+    16089,
+    16088,
+    16062,
+    16092,
+    16093,
+    16093,
+    16095,
+    16096,
+    16096,
+    16098,
+    16098,
+    16098,
+    16101,
+    16102,
+    16101,
+    16104,
+    16104,
+    16098,
+    16107,
+    16107,
+    16098,
+    16110,
+    16111,
+    16111,
+    16113,
+    16114,
+    16113,
+    16111,
+    16111,
+    16118,
+    16119,
+    16120,
+    16120,
+    16122,
+    16122,
+    16120,
+    16120,
+    16120,
+    16127,
+    16128,
+    16129,
+    16129,
+    16129,
+    16132,
+    16132,
+    16134,
+    16134,
+    16136,
+    16136,
+    16138,
+    16139,
+    16139,
+    16139,
+    16127,
+    16143,
+    16144,
+    16145,
+    16146,
+    16147,
+    16148,
+    16149,
+    16149,
+    16151,
+    16152,
+    16152,
+    16154,
+    16154,
+    16156,
+    16156,
+    16158,
+    16159,
+    16160,
+    16161,
+    16161,
+    16163,
+    16164,
+    16164,
+    16163,
+    16167,
+    16168,
+    16169,
+    16169,
+    16171,
+    16171,
+    16173,
+    16173,
+    16151,
+    16176,
+    16177,
+    16178,
+    16179,
+    16180,
+    16181,
+    16182,
+    16183,
+    16183,
+    16185,
+    16186,
+    16186,
+    16188,
+    16189,
+    16190,
+    16191,
+    16191,
+    16190,
+    16194,
+    16194,
+    16196,
+    16196,
+    16198,
+    16199,
+    16199,
+    16201,
+    16201,
+    16190,
+    16204,
+    16205,
+    16205,
+    16207,
+    16207,
+    16209,
+    16209,
+    16211,
+    16212,
+    16207,
+    16212,
+    16215,
+    16216,
+    16217,
+    16218,
+    16218,
+    16220,
+    16220,
+    16218,
+    16223,
+    16224,
+    16224,
+    16226,
+    16227,
+    16227,
+    16229,
+    16229,
+    16231,
+    16231,
+    16233,
+    16234,
+    16235,
+    16234,
+    16237,
+    16226,
+    16239,
+    16240,
+    16239,
+    16242,
+    16243,
+    16243,
+    16245,
+    16246,
+    16246,
+    16248,
+    16249,
+    16250,
+    16251,
+    16252,
+    16252,
+    16254,
+    16255,
+    16256,
+    16245,
+    16258,
+    16259,
+    16259,
+    16249,
+    16262,
+    16262,
+    16264,
+    16265,
+    16265,
+    16267,
+    16267,
+    16262,
+    16270,
+    16270,
+    16262,
+    16273,
+    16273,
+    16275,
+    16275,
+    16273,
+    16273,
+    16279,
+    16280,
+    16280,
+    16282,
+    16282,
+    16279,
+    16285,
+    16285,
+    16287,
+    16288,
+    16289,
+    16289,
+    16285,
+    16288,
+    16293,
+    16293,
+    16295,
+    16296,
+    16296,
+    16298,
+    16299,
+    16299,
+    16299,
+    16295,
+    16285,
+    16304,
+    16304,
+    16306,
+    16306,
+    16308,
+    16308,
+    16310,
+    16311,
+    16308,
+    16308,
+    16311,
+    16315,
+    16308,
+    16310,
+    16318,
+    16319,
+    16320,
+    16321,
+    16320,
+    16323,
+    16324,
+    16325,
+    16325,
+    16327,
+    16328,
+    16329,
+    16330,
+    16330,
+    16330,
+    16333,
+    16334,
+    16334,
+    16336,
+    16337,
+    16338,
+    16339,
+    16334,
+    16341,
+    16342,
+    16343,
+    16343,
+    16345,
+    16345,
+    16347,
+    16348,
+    16348,
+    16345,
+    16351,
+    16351,
+    16345,
+    16354,
+    16355,
+    16355,
+    16357,
+    16357,
+    16342,
+    16360,
+    16361,
+    16361,
+    16363,
+    16363,
+    16365,
+    16365,
+    16367,
+    16368,
+    16367,
+    16370,
+    16370,
+    16372,
+    16372,
+    16367,
+    16367,
+    16376,
+    16377,
+    16378,
+    16376,
+    16380,
+    16367,
+    16382,
+    16383,
+    16383,
+    16385,
+    16385,
+    16383,
+    16388,
+    16389,
+    16390,
+    16391,
+    16392,
+    16392,
+    16394,
+    16395,
+    16396,
+    16397,
+    16397,
+    16399,
+    16399,
+    16401,
+    16401,
+    16401,
+    16396,
+    16396,
+    16406,
+    16406,
+    16408,
+    16408,
+    16410,
+    16411,
+    16412,
+    16412,
+    16414,
+    16410,
+    16416,
+    16416,
+    16416,
+    16419,
+    16419,
+    16421,
+    16421,
+    16423,
+    16423,
+    16425,
+    16425,
+    16419,
+    16419,
+    16429,
+    16419,
+    16431,
+    16431,
+    16416,
+    16434,
+    16435,
+    16436,
+    16436,
+    16438,
+    16438,
+    16440,
+    16440,
+    16442,
+    16442,
+    16436,
+    16436,
+    16446,
+    16446,
+    16448,
+    16448,
+    16450,
+    16450,
+    16452,
+    16452,
+    16436,
+    16455,
+    16455,
+    16457,
+    16457,
+    16459,
+    16460,
+    16460,
+    16459,
+    16463,
+    16463,
+    16457,
+    16466,
+    16467,
+    16468,
+    16457,
+    16457,
+    16471,
+    16472,
+    16473,
+    16473,
+    16475,
+    16476,
+    16476,
+    16478,
+    16472,
+    16472,
+    16481,
+    16481,
+    16483,
+    16484,
+    16485,
+    16485,
+    16472,
+    16488,
+    16489,
+    16490,
+    16491,
+    16491,
+    16493,
+    16494,
+    16495,
+    16494,
+    16494,
+    16494,
+    16499,
+    16499,
+    16499,
+    16502,
+    16503,
+    16504,
+    16503,
+    16503,
+    16503,
+    16508,
+    16509,
+    16510,
+    16509,
+    16509,
+    16513,
+    16514,
+    16515,
+    16515,
+    16515,
+    16515,
+    16519,
+    16520,
+    16521,
+    16522,
+    16522,
+    16524,
+    16525,
+    16524,
+    16527,
+    16528,
+    16528,
+    16530,
+    16530,
+    16530,
+    16533,
+    16534,
+    16535,
+    16535,
+    16537,
+    16538,
+    16539,
+    16540,
+    16541,
+    16540,
+    16540,
+    16544,
+    16545,
+    16545,
+    16547,
+    16547,
+    16549,
+    16549,
+    16551,
+    16551,
+    16553,
+    16554,
+    16555,
+    16556,
+    16556,
+    16555,
+    16559,
+    16560,
+    16560,
+    16562,
+    16562,
+    16564,
+    16565,
+    16566,
+    16567,
+    16567,
+    16569,
+    16569,
+    16571,
+    16572,
+    16572,
+    16571,
+    16575,
+    16575,
+    16577,
+    16577,
+    16579,
+    16580,
+    16580,
+    16582,
+    16582,
+    16584,
+    16585,
+    16585,
+    16585,
+    16588,
+    16588,
+    16590,
+    16591,
+    16592,
+    16590,
+    16594,
+    16594,
+    16594,
+    16594,
+    16598,
+    16599,
+    16600,
+    16601,
+    16601,
+    16603,
+    16603,
+    16605,
+    16606,
+    16607,
+    16608,
+    16609,
+    16610,
+    16609,
+    16612,
+    16612,
+    16614,
+    16615,
+    16615,
+    16617,
+    16618,
+    16619,
+    16620,
+    16621,
+    16621,
+    16623,
+    16599,
+    16625,
+    16626,
+    16626,
+    16628,
+    16628,
+    16630,
+    16630,
+    16625,
+    16633,
+    16633,
+    16635,
+    16636,
+    16637,
+    16637,
+    16639,
+    16639,
+    16641,
+    16641,
+    16635,
+    16644,
+    16645,
+    16645,
+    16647,
+    16647,
+    16649,
+    16650,
+    16651,
+    16650,
+    16653,
+    16650,
+    16655,
+    16656,
+    16657,
+    16658,
+    16656,
+    16655,
+    16661,
+    16662,
+    16663,
+    16663,
+    16662,
+    16662,
+    16667,
+    16662,
+    16669,
+    16670,
+    16670,
+    16672,
+    16673,
+    16673,
+    16675,
+    16676,
+    16677,
+    16678,
+    16679,
+    16679,
+    16675,
+    16682,
+    16683,
+    16684,
+    16683,
+    16682,
+    16687,
+    16688,
+    16689,
+    16688,
+    16691,
+    16691,
+    16693,
+    16694,
+    16694,
+    16687,
+    16649,
+    16698,
+    16699,
+    16700,
+    16701,
+    16702,
+    16703,
+    16704,
+    16705,
+    16705,
+    16704,
+    16708,
+    16708,
+    16710,
+    16708,
+    16712,
+    16713,
+    16714,
+    16713,
+    16716,
+    16716,
+    16718,
+    16718,
+    16720,
+    16721,
+    16721,
+    16723,
+    16712,
+    16725,
+    16726,
+    16727,
+    16726,
+    16729,
+    16729,
+    16731,
+    16732,
+    16733,
+    16734,
+    16735,
+    16736,
+    16737,
+    16738,
+    16738,
+    16740,
+    16735,
+    16742,
+    16743,
+    16744,
+    16744,
+    16746,
+    16747,
+    16748,
+    16749,
+    16749,
+    16751,
+    16751,
+    16746,
+    16754,
+    16755,
+    16756,
+    16757,
+    16758,
+    16759,
+    16754,
+    16761,
+    16761,
+    16763,
+    16763,
+    16765,
+    16765,
+    16763,
+    16768,
+    16768,
+    16770,
+    16770,
+    16772,
+    16772,
+    16770,
+    16775,
+    16776,
+    16777,
+    16778,
+    16778,
+    16778,
+    16781,
+    16782,
+    16783,
+    16784,
+    16784,
+    16783,
+    16787,
+    16788,
+    16789,
+    16790,
+    16790,
+    16792,
+    16792,
+    16794,
+    16789,
+    16796,
+    16797,
+    16797,
+    16799,
+    16799,
+    16797,
+    16796,
+    16803,
+    16788,
+    16805,
+    16805,
+    16807,
+    16807,
+    16805,
+    16810,
+    16811,
+    16811,
+    16813,
+    16810,
+    16815,
+    16816,
+    16817,
+    16818,
+    16818,
+    16818,
+    16821,
+    16821,
+    16821,
+    16824,
+    16825,
+    16825,
+    16827,
+    16828,
+    16829,
+    16830,
+    16830,
+    16832,
+    16833,
+    16834,
+    16835,
+    16836,
+    16837,
+    16838,
+    16838,
+    16840,
+    16841,
+    16837,
+    16843,
+    16843,
+    16845,
+    16843,
+    16847,
+    16848,
+    16849,
+    16849,
+    16849,
+    16852,
+    16853,
+    16853,
+    16855,
+    16856,
+    16856,
+    16856,
+    16856,
+    16855,
+    16861,
+    16862,
+    16855,
+    16864,
+    16865,
+    16866,
+    16867,
+    16848,
+    16869,
+    16870,
+    16870,
+    16870,
+    16873,
+    16873,
+    16875,
+    16875,
+    16873,
+    16873,
+    16879,
+    16880,
+    16881,
+    16882,
+    16882,
+    16882,
+    16879,
+    16886,
+    16887,
+    16887,
+    16889,
+    16889,
+    16891,
+    16892,
+    16892,
+    16887,
+    16895,
+    16887,
+    16897,
+    16897,
+    16897,
+    16900,
+    16901,
+    16902,
+    16900,
+    16904,
+    16905,
+    16904,
+    16907,
+    16907,
+    16904,
+    16910,
+    16911,
+    16911,
+    16913,
+    16913,
+    16915,
+    16916,
+    16916,
+    16918,
+    16918,
+    16920,
+    16920,
+    16910,
+    16923,
+    16923,
+    16923,
+    16926,
+    16927,
+    16927,
+    16927,
+    16930,
+    16930,
+    16932,
+    16932,
+    16934,
+    16934,
+    16936,
+    16936,
+    16938,
+    16939,
+    16940,
+    16940,
+    16940,
+    16943,
+    16944,
+    16945,
+    16946,
+    16947,
+    16940,
+    16944,
+    16950,
+    16950,
+    16952,
+    16953,
+    16953,
+    16955,
+    16956,
+    16956,
+    16958,
+    16959,
+    16959,
+    16961,
+    16955,
+    16955,
+    16955,
+    16965,
+    16965,
+    16967,
+    16967,
+    16967,
+    16970,
+    16971,
+    16971,
+    16973,
+    16974,
+    16974,
+    16973,
+    16977,
+    16978,
+    16979,
+    16967,
+    16981,
+    16982,
+    16983,
+    16983,
+    16983,
+    16983,
+    16987,
+    16988,
+    16988,
+    16990,
+    16991,
+    16987,
+    16993,
+    16994,
+    16995,
+    16996,
+    16997,
+    16997,
+    16999,
+    16999,
+    17001,
+    16978,
+    17003,
+    17003,
+    17005,
+    17006,
+    17007,
+    17007,
+    17009,
+    17009,
+    17007,
+    17006,
+    17013,
+    17013,
+    17015,
+    17015,
+    17017,
+    17018,
+    17017,
+    17020,
+    17020,
+    17022,
+    17022,
+    17024,
+    17025,
+    17026,
+    17025,
+    17025,
+    17017,
+    17030,
+    17031,
+    17032,
+    17033,
+    17034,
+    17034,
+    17036,
+    17036,
+    17038,
+    17039,
+    17040,
+    17041,
+    17042,
+    17043,
+    17017,
+    17045,
+    17005,
+    17040,
+    17048,
+    17049,
+    17050,
+    17050,
+    17052,
+    17052,
+    17049,
+    17055,
+    17055,
+    17057,
+    17057,
+    17055,
+    17060,
+    17061,
+    17062,
+    17063,
+    17064,
+    17063,
+    17066,
+    17066,
+    17068,
+    17069,
+    17069,
+    17069,
+    17068,
+    17073,
+    17074,
+    17074,
+    17076,
+    17076,
+    17078,
+    17078,
+    17080,
+    17081,
+    17074,
+    17081,
+    17048,
+    17085,
+    17086,
+    17086,
+    17088,
+    17088,
+    17090,
+    17090,
+    17092,
+    17092,
+    17088,
+    17088,
+    17096,
+    17097,
+    17097,
+    17085,
+    17100,
+    17100,
+    17102,
+    17103,
+    17102,
+    17105,
+    17106,
+    17106,
+    17105,
+    17105,
+    17110,
+    17111,
+    17111,
+    17113,
+    17113,
+    17113,
+    17113,
+    17117,
+    17118,
+    17118,
+    17117,
+    17121,
+    17121,
+    17123,
+    17124,
+    17125,
+    17126,
+    17127,
+    17117,
+    17129,
+    17129,
+    17131,
+    17132,
+    17133,
+    17134,
+    17133,
+    17136,
+    17137,
+    17138,
+    17139,
+    17140,
+    17141,
+    17142,
+    17143,
+    17133,
+    17145,
+    17146,
+    17147,
+    17148,
+    17149,
+    17149,
+    17151,
+    17152,
+    17153,
+    17154,
+    17154,
+    17156,
+    17157,
+    17149,
+    17159,
+    17160,
+    17161,
+    17162,
+    17163,
+    17164,
+    17165,
+    17163,
+    17167,
+    17167,
+    17169,
+    17170,
+    17171,
+    17163,
+    17173,
+    17174,
+    17175,
+    17176,
+    17177,
+    17177,
+    17179,
+    17179,
+    17181,
+    17182,
+    17183,
+    17182,
+    17185,
+    17186,
+    17187,
+    17187,
+    17186,
+    17190,
+    17190,
+    17192,
+    17176,
+    17194,
+    17195,
+    17195,
+    17197,
+    17198,
+    17198,
+    17197,
+    17201,
+    17202,
+    17202,
+    17204,
+    17204,
+    17201,
+    17207,
+    17208,
+    17207,
+    17210,
+    17211,
+    17211,
+    17213,
+    17214,
+    17215,
+    17216,
+    17217,
+    17218,
+    17219,
+    17220,
+    17221,
+    17207,
+    17223,
+    17224,
+    17224,
+    17226,
+    17227,
+    17228,
+    17228,
+    17228,
+    17231,
+    17232,
+    17233,
+    17232,
+    17232,
+    17226,
+    17237,
+    17238,
+    17239,
+    17240,
+    17241,
+    17241,
+    17241,
+    17244,
+    17244,
+    17246,
+    17246,
+    17248,
+    17248,
+    17250,
+    17250,
+    17250,
+    17250,
+    17254,
+    17254,
+    17256,
+    17256,
+    17258,
+    17258,
+    17254,
+    17254,
+    17237,
+    17263,
+    17264,
+    17265,
+    17265,
+    17267,
+    17268,
+    17268,
+    17270,
+    17270,
+    17272,
+    17272,
+    17267,
+    17275,
+    17275,
+    17277,
+    17277,
+    17279,
+    17280,
+    17281,
+    17281,
+    17283,
+    17280,
+    17285,
+    17286,
+    17286,
+    17285,
+    17289,
+    17290,
+    17291,
+    17292,
+    17293,
+    17293,
+    17291,
+    17296,
+    17297,
+    17298,
+    17299,
+    17300,
+    17296,
+    17302,
+    17303,
+    17304,
+    17304,
+    17306,
+    17303,
+    17308,
+    17308,
+    17310,
+    17310,
+    17312,
+    17312,
+    17314,
+    17315,
+    17312,
+    17312,
+    17318,
+    17319,
+    17320,
+    17321,
+    17322,
+    17323,
+    17324,
+    17325,
+    17326,
+    17326,
+    17328,
+    17329,
+    17330,
+    17330,
+    17332,
+    17332,
+    17330,
+    17335,
+    17336,
+    17337,
+    17337,
+    17321,
+    17320,
+    17341,
+    17341,
+    17343,
+    17343,
+    17345,
+    17345,
+    17343,
+    17348,
+    17343,
+    17350,
+    17351,
+    17351,
+    17353,
+    17354,
+    17354,
+    17356,
+    17357,
+    17357,
+    17359,
+    17360,
+    17361,
+    17362,
+    17363,
+    17363,
+    17365,
+    17366,
+    17366,
+    17368,
+    17369,
+    17370,
+    17369,
+    17369,
+    17373,
+    17369,
+    17375,
+    17376,
+    17376,
+    17376,
+    17375,
+    17380,
+    17380,
+    17382,
+    17383,
+    17384,
+    17375,
+    17375,
+    17383,
+    17319,
+    17389,
+    17390,
+    17391,
+    17391,
+    17393,
+    17394,
+    17395,
+    17396,
+    17397,
+    17398,
+    17398,
+    17400,
+    17400,
+    17402,
+    17403,
+    17404,
+    17405,
+    17397,
+    17404,
+    17389,
+    17409,
+    17410,
+    17409,
+    17412,
+    17413,
+    17413,
+    17409,
+    17416,
+    17416,
+    17418,
+    17419,
+    17420,
+    17420,
+    17420,
+    17423,
+    17424,
+    17424,
+    17426,
+    17426,
+    17428,
+    17424,
+    17424,
+    17424,
+    17428,
+    17433,
+    17434,
+    17434,
+    17436,
+    17436,
+    17436,
+    17436,
+    17434,
+    17441,
+    17442,
+    17443,
+    17444,
+    17445,
+    17445,
+    17447,
+    17448,
+    17449,
+    17450,
+    17445,
+    17452,
+    17453,
+    17454,
+    17454,
+    17456,
+    17454,
+    17458,
+    17459,
+    17460,
+    17461,
+    17459,
+    17463,
+    17463,
+    17465,
+    17466,
+    17466,
+    17468,
+    17469,
+    17470,
+    17470,
+    17469,
+    17473,
+    17473,
+    17475,
+    17475,
+    17477,
+    17477,
+    17479,
+    17480,
+    17480,
+    17479,
+    17483,
+    17484,
+    17485,
+    17486,
+    17486,
+    17484,
+    17489,
+    17490,
+    17490,
+    17490,
+    17493,
+    17493,
+    17495,
+    17495,
+    17497,
+    17497,
+    17499,
+    17500,
+    17501,
+    17502,
+    17503,
+    17504,
+    17504,
+    17506,
+    17506,
+    17508,
+    17509,
+    17510,
+    17510,
+    17512,
+    17513,
+    17513,
+    17515,
+    17516,
+    17517,
+    17502,
+    17519,
+    17520,
+    17521,
+    17522,
+    17523,
+    17524,
+    17525,
+    17525,
+    17527,
+    17528,
+    17524,
+    17522,
+    17531,
+    17532,
+    17533,
+    17534,
+    17534,
+    17536,
+    17536,
+    17538,
+    17539,
+    17538,
+    17541,
+    17542,
+    17543,
+    17544,
+    17545,
+    17542,
+    17547,
+    17548,
+    17548,
+    17550,
+    17547,
+    17552,
+    17553,
+    17554,
+    17555,
+    17556,
+    17547,
+    17558,
+    17559,
+    17560,
+    17558,
+    17562,
+    17563,
+    17564,
+    17564,
+    17566,
+    17567,
+    17568,
+    17568,
+    17570,
+    17571,
+    17572,
+    17571,
+    17574,
+    17574,
+    17576,
+    17576,
+    17578,
+    17579,
+    17579,
+    17566,
+    17582,
+    17583,
+    17584,
+    17564,
+    17564,
+    17587,
+    17588,
+    17589,
+    17590,
+    17590,
+    17592,
+    17593,
+    17594,
+    17594,
+    17594,
+    17597,
+    17598,
+    17599,
+    17600,
+    17601,
+    17602,
+    17602,
+    17604,
+    17605,
+    17606,
+    17605,
+    17608,
+    17609,
+    17604,
+    17597,
+    17612,
+    17613,
+    17614,
+    17615,
+    17616,
+    17616,
+    17597,
+    17619,
+    17619,
+    17621,
+    17621,
+    17597,
+    17624,
+    17625,
+    17626,
+    17626,
+    17628,
+    17629,
+    17629,
+    17631,
+    17563,
+    17633,
+    17634,
+    17634,
+    17636,
+    17636,
+    17638,
+    17639,
+    17640,
+    17641,
+    17642,
+    17643,
+    17644,
+    17644,
+    17646,
+    17647,
+    17648,
+    17648,
+    17650,
+    17651,
+    17651,
+    17653,
+    17654,
+    17655,
+    17656,
+    17657,
+    17657,
+    17650,
+    17660,
+    17660,
+    17662,
+    17662,
+    17664,
+    17664,
+    17662,
+    17667,
+    17668,
+    17668,
+    17670,
+    17667,
+    17672,
+    17673,
+    17673,
+    17673,
+    17676,
+    17677,
+    17678,
+    17679,
+    17680,
+    17681,
+    17682,
+    17683,
+    17684,
+    17683,
+    17682,
+    17687,
+    17688,
+    17688,
+    17690,
+    17690,
+    17692,
+    17692,
+    17694,
+    17681,
+    17696,
+    17697,
+    17697,
+    17696,
+    17700,
+    17701,
+    17702,
+    17703,
+    17704,
+    17705,
+    17705,
+    17707,
+    17703,
+    17709,
+    17710,
+    17711,
+    17711,
+    17713,
+    17714,
+    17715,
+    17716,
+    17716,
+    17718,
+    17719,
+    17720,
+    17721,
+    17722,
+    17723,
+    17724,
+    17725,
+    17725,
+    17725,
+    17728,
+    17728,
+    17730,
+    17730,
+    17732,
+    17732,
+    17734,
+    17734,
+    17736,
+    17737,
+    17738,
+    17739,
+    17740,
+    17741,
+    17742,
+    17741,
+    17744,
+    17744,
+    17746,
+    17746,
+    17748,
+    17744,
+    17750,
+    17750,
+    17750,
+    17753,
+    17753,
+    17755,
+    17756,
+    17756,
+    17756,
+    17759,
+    17760,
+    17760,
+    17762,
+    17762,
+    17762,
+    17765,
+    17766,
+    17766,
+    17768,
+    17769,
+    17769,
+    17771,
+    17772,
+    17772,
+    17772,
+    17775,
+    17775,
+    17775,
+    17778,
+    17778,
+    17778,
+    17781,
+    17781,
+    17781,
+    17784,
+    17785,
+    17785,
+    17787,
+    17787,
+    17789,
+    17790,
+    17790,
+    17785,
+    17793,
+    17793,
+    17795,
+    17795,
+    17793,
+    17798,
+    17798,
+    17800,
+    17800,
+    17800,
+    17803,
+    17804,
+    17804,
+    17804,
+    17807,
+    17807,
+    17809,
+    17809,
+    17811,
+    17812,
+    17812,
+    17812,
+    17815,
+    17816,
+    17816,
+    17784,
+    17819,
+    17819,
+    17821,
+    17821,
+    17823,
+    17824,
+    17825,
+    17825,
+    17827,
+    17827,
+    17825,
+    17830,
+    17830,
+    17832,
+    17833,
+    17834,
+    17834,
+    17836,
+    17836,
+    17836,
+    17839,
+    17840,
+    17841,
+    17841,
+    17841,
+    17844,
+    17845,
+    17846,
+    17847,
+    17844,
+    17844,
+    17850,
+    17850,
+    17850,
+    17853,
+    17854,
+    17854,
+    17856,
+    17857,
+    17857,
+    17859,
+    17860,
+    17861,
+    17862,
+    17863,
+    17863,
+    17862,
+    17866,
+    17866,
+    17868,
+    17868,
+    17870,
+    17871,
+    17872,
+    17872,
+    17874,
+    17875,
+    17876,
+    17876,
+    17876,
+    17874,
+    17880,
+    17784,
+    17882,
+    17883,
+    17884,
+    17885,
+    17886,
+    17885,
+    17888,
+    17888,
+    17885,
+    17891,
+    17891,
+    17893,
+    17894,
+    17895,
+    17896,
+    17897,
+    17898,
+    17898,
+    17900,
+    17900,
+    17898,
+    17903,
+    17904,
+    17905,
+    17906,
+    17906,
+    17906,
+    17909,
+    17909,
+    17909,
+    17912,
+    17913,
+    17914,
+    17914,
+    17916,
+    17916,
+    17918,
+    17918,
+    17920,
+    17921,
+    17922,
+    17923,
+    17923,
+    17923,
+    17926,
+    17926,
+    17928,
+    17929,
+    17929,
+    17928,
+    17932,
+    17933,
+    17933,
+    17935,
+    17935,
+    17933,
+    17938,
+    17939,
+    17940,
+    17941,
+    17941,
+    17943,
+    17943,
+    17945,
+    17946,
+    17946,
+    17948,
+    17949,
+    17950,
+    17950,
+    17952,
+    17952,
+    17954,
+    17955,
+    17955,
+    17954,
+    17958,
+    17959,
+    17960,
+    17960,
+    17962,
+    17962,
+    17964,
+    17964,
+    17964,
+    17967,
+    17967,
+    17969,
+    17970,
+    17971,
+    17972,
+    17973,
+    17973,
+    17973,
+    17976,
+    17977,
+    17977,
+    17979,
+    17980,
+    17980,
+    17982,
+    17979,
+    17984,
+    17984,
+    17984,
+    17987,
+    17987,
+    17987,
+    17990,
+    17991,
+    17991,
+    17993,
+    17994,
+    17995,
+    17996,
+    17996,
+    17998,
+    17999,
+    18000,
+    18001,
+    18002,
+    18002,
+    18001,
+    18005,
+    18006,
+    18006,
+    18008,
+    18008,
+    18010,
+    18010,
+    18012,
+    18012,
+    18014,
+    18015,
+    18015,
+    18014,
+    18018,
+    18019,
+    18019,
+    18018,
+    18022,
+    18022,
+    18024,
+    18025,
+    18025,
+    18024,
+    18028,
+    18028,
+    18030,
+    18030,
+    18032,
+    18033,
+    18034,
+    18035,
+    18035,
+    18037,
+    18038,
+    18039,
+    18040,
+    18041,
+    18040,
+    18043,
+    18044,
+    18045,
+    18046,
+    18047,
+    18048,
+    18049,
+    18050,
+    18050,
+    18050,
+    18053,
+    18053,
+    18055,
+    18056,
+    18056,
+    18055,
+    18059,
+    18060,
+    18061,
+    18060,
+    18063,
+    18064,
+    18064,
+    18066,
+    18066,
+    18064,
+    18069,
+    18069,
+    18071,
+    18071,
+    18069,
+    18074,
+    18074,
+    18076,
+    18077,
+    18077,
+    18076,
+    18080,
+    18080,
+    18082,
+    18082,
+    18084,
+    18085,
+    18086,
+    18086,
+    18085,
+    18089,
+    18090,
+    18089,
+    18092,
+    18089,
+    18084,
+    18095,
+    18095,
+    18097,
+    18098,
+    18099,
+    18099,
+    18099,
+    18102,
+    18103,
+    18104,
+    18105,
+    18106,
+    18106,
+    18108,
+    18109,
+    18110,
+    18110,
+    18112,
+    18112,
+    18114,
+    18115,
+    18116,
+    18116,
+    18118,
+    18119,
+    18119,
+    18119,
+    18122,
+    18122,
+    18122,
+    18125,
+    18125,
+    18127,
+    18127,
+    18129,
+    18129,
+    18109,
+    18132,
+    18132,
+    18132,
+    18135,
+    18135,
+    18137,
+    18137,
+    18135,
+    18140,
+    18141,
+    18141,
+    18140,
+    18144,
+    18145,
+    18145,
+    18147,
+    18147,
+    18149,
+    18149,
+    18147,
+    18152,
+    18153,
+    18153,
+    18152,
+    18156,
+    18156,
+    18158,
+    18159,
+    18159,
+    18159,
+    18162,
+    18163,
+    18164,
+    18164,
+    18163,
+    18167,
+    18167,
+    18169,
+    18169,
+    18171,
+    18171,
+    18173,
+    18173,
+    18163,
+    18176,
+    18177,
+    18177,
+    18177,
+    18180,
+    18181,
+    18182,
+    18182,
+    18181,
+    18185,
+    18185,
+    18187,
+    18188,
+    18189,
+    18189,
+    18191,
+    18192,
+    18193,
+    18194,
+    18194,
+    18194,
+    18197,
+    18198,
+    18199,
+    18200,
+    18200,
+    18200,
+    18203,
+    18203,
+    18205,
+    18206,
+    18207,
+    18207,
+    18209,
+    18210,
+    18211,
+    18212,
+    18212,
+    18214,
+    18215,
+    18216,
+    18217,
+    18218,
+    18219,
+    18220,
+    18221,
+    18221,
+    18223,
+    18224,
+    18225,
+    18225,
+    18227,
+    18227,
+    18229,
+    18230,
+    18231,
+    18232,
+    18233,
+    18234,
+    18235,
+    18236,
+    18237,
+    18238,
+    18239,
+    18240,
+    18240,
+    18242,
+    18243,
+    18243,
+    18245,
+    18246,
+    18247,
+    18247,
+    18249,
+    18249,
+    18251,
+    18252,
+    18252,
+    18254,
+    18254,
+    18256,
+    18257,
+    18258,
+    18259,
+    18259,
+    18261,
+    18261,
+    18263,
+    18263,
+    18265,
+    18265,
+    18267,
+    18267,
+    18269,
+    18269,
+    18271,
+    18272,
+    18272,
+    18257,
+    18275,
+    18275,
+    18277,
+    18277,
+    18279,
+    18280,
+    18280,
+    18280,
+    18283,
+    18284,
+    18285,
+    18285,
+    18287,
+    18284,
+    18289,
+    18289,
+    18291,
+    18292,
+    18293,
+    18294,
+    18294,
+    18294,
+    18297,
+    18298,
+    18299,
+    18299,
+    18298,
+    18302,
+    18302,
+    18304,
+    18304,
+    18306,
+    18307,
+    18308,
+    18308,
+    18308,
+    18311,
+    18311,
+    18313,
+    18313,
+    18311,
+    18316,
+    18317,
+    18317,
+    18319,
+    18319,
+    18319,
+    18322,
+    18323,
+    18322,
+    18325,
+    18326,
+    18327,
+    18327,
+    18329,
+    18330,
+    18330,
+    18332,
+    18332,
+    18334,
+    18335,
+    18335,
+    18337,
+    18338,
+    18338,
+    18340,
+    18341,
+    18342,
+    18343,
+    18344,
+    18342,
+    18346,
+    18346,
+    18346,
+    18349,
+    18350,
+    18351,
+    18352,
+    18352,
+    18354,
+    18354,
+    18354,
+    18351,
+    18358,
+    18359,
+    18360,
+    18361,
+    18362,
+    18363,
+    18363,
+    18365,
+    18366,
+    18367,
+    18368,
+    18369,
+    18370,
+    18371,
+    18372,
+    18373,
+    18374,
+    18375,
+    18375,
+    18377,
+    18378,
+    18379,
+    18380,
+    18381,
+    18382,
+    18382,
+    18384,
+    18385,
+    18386,
+    18387,
+    18388,
+    18389,
+    18390,
+    18391,
+    18392,
+    18393,
+    18394,
+    18395,
+    18396,
+    18396,
+    18398,
+    18398,
+    18398,
+    18401,
+    18402,
+    18402,
+    18404,
+    18405,
+    18406,
+    18407,
+    18408,
+    18409,
+    18410,
+    18411,
+    18411,
+    18413,
+    18413,
+    18415,
+    18416,
+    18416,
+    18415,
+    18419,
+    18420,
+    18420,
+    18422,
+    18422,
+    18419,
+    18425,
+    18426,
+    18427,
+    18427,
+    18426,
+    18430,
+    18430,
+    18432,
+    18432,
+    18434,
+    18434,
+    18434,
+    18437,
+    18438,
+    18438,
+    18440,
+    18430,
+    18442,
+    18442,
+    18444,
+    18444,
+    18446,
+    18446,
+    18430,
+    18449,
+    18450,
+    18451,
+    18452,
+    18440,
+    18454,
+    18454,
+    18456,
+    18457,
+    18457,
+    18459,
+    18460,
+    18459,
+    18459,
+    18463,
+    18464,
+    18465,
+    18466,
+    18467,
+    18467,
+    18469,
+    18465,
+    18471,
+    18472,
+    18472,
+    18474,
+    18474,
+    18476,
+    18477,
+    18477,
+    18472,
+    18480,
+    18481,
+    18482,
+    18481,
+    18484,
+    18485,
+    18480,
+    18487,
+    18488,
+    18487,
+    18488,
+    18491,
+    18491,
+    18493,
+    18494,
+    18495,
+    18495,
+    18495,
+    18495,
+    18494,
+    18500,
+    18501,
+    18502,
+    18493,
+    18504,
+    18493,
+    18506,
+    18506,
+    18508,
+    18508,
+    18510,
+    18510,
+    18508,
+    18513,
+    18514,
+    18515,
+    18516,
+    18517,
+    18517,
+    18519,
+    18520,
+    18521,
+    18522,
+    18523,
+    18524,
+    18524,
+    18526,
+    18526,
+    18528,
+    18524,
+    18524,
+    18531,
+    18523,
+    18533,
+    18534,
+    18534,
+    18536,
+    18536,
+    18538,
+    18538,
+    18540,
+    18540,
+    18542,
+    18543,
+    18544,
+    18544,
+    18544,
+    18536,
+    18548,
+    18549,
+    18549,
+    18551,
+    18552,
+    18552,
+    18551,
+    18555,
+    18556,
+    18556,
+    18556,
+    18559,
+    18559,
+    18533,
+    18562,
+    18563,
+    18564,
+    18564,
+    18566,
+    18567,
+    18568,
+    18569,
+    18570,
+    18571,
+    18572,
+    18573,
+    18573,
+    18575,
+    18575,
+    18575,
+    18575,
+    18579,
+    18579,
+    18579,
+    18582,
+    18582,
+    18584,
+    18584,
+    18586,
+    18587,
+    18584,
+    18589,
+    18590,
+    18590,
+    18590,
+    18590,
+    18594,
+    18595,
+    18595,
+    18597,
+    18598,
+    18598,
+    18600,
+    18600,
+    18598,
+    18597,
+    18597,
+    18605,
+    18606,
+    18606,
+    18597,
+    18609,
+    18610,
+    18611,
+    18611,
+    18610,
+    18614,
+    18615,
+    18616,
+    18617,
+    18617,
+    18619,
+    18619,
+    18621,
+    18621,
+    18623,
+    18624,
+    18624,
+    18626,
+    18623,
+    18628,
+    18628,
+    18630,
+    18631,
+    18631,
+    18633,
+    18633,
+    18635,
+    18636,
+    18636,
+    18636,
+    18639,
+    18640,
+    18640,
+    18642,
+    18642,
+    18644,
+    18645,
+    18646,
+    18644,
+    18648,
+    18646,
+    18644,
+    18651,
+    18652,
+    18653,
+    18652,
+    18655,
+    18656,
+    18657,
+    18658,
+    18659,
+    18659,
+    18661,
+    18661,
+    18663,
+    18663,
+    18665,
+    18665,
+    18658,
+    18668,
+    18669,
+    18670,
+    18671,
+    18671,
+    18673,
+    18674,
+    18675,
+    18676,
+    18675,
+    18675,
+    18679,
+    18679,
+    18681,
+    18681,
+    18683,
+    18684,
+    18685,
+    18685,
+    18687,
+    18687,
+    18685,
+    18683,
+    18691,
+    18692,
+    18691,
+    18694,
+    18694,
+    18696,
+    18697,
+    18697,
+    18699,
+    18699,
+    18701,
+    18701,
+    18699,
+    18704,
+    18705,
+    18706,
+    18707,
+    18708,
+    18709,
+    18710,
+    18711,
+    18711,
+    18713,
+    18713,
+    18715,
+    18715,
+    18717,
+    18717,
+    18715,
+    18720,
+    18721,
+    18721,
+    18723,
+    18723,
+    18725,
+    18725,
+    18723,
+    18710,
+    18729,
+    18729,
+    18729,
+    18732,
+    18732,
+    18734,
+    18735,
+    18736,
+    18736,
+    18738,
+    18738,
+    18740,
+    18740,
+    18738,
+    18743,
+    18744,
+    18745,
+    18746,
+    18747,
+    18748,
+    18748,
+    18750,
+    18750,
+    18747,
+    18753,
+    18754,
+    18754,
+    18756,
+    18754,
+    18758,
+    18759,
+    18760,
+    18753,
+    18762,
+    18763,
+    18764,
+    18765,
+    18766,
+    18767,
+    18768,
+    18769,
+    18770,
+    18771,
+    18771,
+    18773,
+    18773,
+    18775,
+    18775,
+    18777,
+    18777,
+    18779,
+    18779,
+    18781,
+    18782,
+    18783,
+    18783,
+    18781,
+    18786,
+    18786,
+    18788,
+    18781,
+    18790,
+    18790,
+    18792,
+    18793,
+    18793,
+    18795,
+    18790,
+    18797,
+    18797,
+    18797,
+    18800,
+    18800,
+    18802,
+    18802,
+    18804,
+    18804,
+    18802,
+    18807,
+    18808,
+    18809,
+    18810,
+    18802,
+    18812,
+    18813,
+    18814,
+    18815,
+    18815,
+    18814,
+    18818,
+    18818,
+    18820,
+    18820,
+    18822,
+    18818,
+    18818,
+    18825,
+    18826,
+    18827,
+    18828,
+    18828,
+    18827,
+    18831,
+    18831,
+    18833,
+    18834,
+    18834,
+    18836,
+    18836,
+    18833,
+    18839,
+    18839,
+    18841,
+    18842,
+    18842,
+    18844,
+    18842,
+    18846,
+    18847,
+    18847,
+    18849,
+    18847,
+    18851,
+    18852,
+    18852,
+    18851,
+    18855,
+    18856,
+    18856,
+    18858,
+    18859,
+    18859,
+    18859,
+    18859,
+    18863,
+    18863,
+    18865,
+    18865,
+    18865,
+    18868,
+    18869,
+    18870,
+    18871,
+    18872,
+    18872,
+    18874,
+    18875,
+    18876,
+    18872,
+    18878,
+    18878,
+    18871,
+    18881,
+    18881,
+    18883,
+    18881,
+    18885,
+    18886,
+    18887,
+    18888,
+    18889,
+    18889,
+    18888,
+    18888,
+    18893,
+    18893,
+    18895,
+    18896,
+    18897,
+    18893,
+    18899,
+    18888,
+    18901,
+    18902,
+    18902,
+    18901,
+    18905,
+    18901,
+    18907,
+    18907,
+    18909,
+    18909,
+    18901,
+    18912,
+    18913,
+    18913,
+    18915,
+    18916,
+    18916,
+    18918,
+    18918,
+    18920,
+    18921,
+    18921,
+    18921,
+    18924,
+    18924,
+    18926,
+    18926,
+    18928,
+    18929,
+    18930,
+    18931,
+    18932,
+    18932,
+    18931,
+    18935,
+    18936,
+    18937,
+    18938,
+    18937,
+    18940,
+    18941,
+    18941,
+    18943,
+    18944,
+    18945,
+    18946,
+    18947,
+    18948,
+    18948,
+    18945,
+    18928,
+    18944,
+    18953,
+    18954,
+    18955,
+    18956,
+    18957,
+    18957,
+    18959,
+    18953,
+    18961,
+    18961,
+    18963,
+    18963,
+    18961,
+    18966,
+    18967,
+    18968,
+    18967,
+    18967,
+    18967,
+    18972,
+    18972,
+    18974,
+    18974,
+    18974,
+    18974,
+    18978,
+    18979,
+    18979,
+    18981,
+    18982,
+    18983,
+    18983,
+    18985,
+    18985,
+    18983,
+    18983,
+    18989,
+    18989,
+    18982,
+    18992,
+    18993,
+    18992,
+    18995,
+    18995,
+    18997,
+    18998,
+    18999,
+    18992,
+    19001,
+    18992,
+    18992,
+    19004,
+    19005,
+    19006,
+    19007,
+    19007,
+    19007,
+    19010,
+    19010,
+    19010,
+    19013,
+    19014,
+    19015,
+    19016,
+    19017,
+    19018,
+    19018,
+    19017,
+    19017,
+    19022,
+    19022,
+    19024,
+    19024,
+    19026,
+    19027,
+    19027,
+    19026,
+    19030,
+    19030,
+    19032,
+    19033,
+    19034,
+    19035,
+    19024,
+    19034,
+    19038,
+    19038,
+    19040,
+    19040,
+    19042,
+    19042,
+    19044,
+    19045,
+    19045,
+    19047,
+    19047,
+    19049,
+    19050,
+    19051,
+    19051,
+    19053,
+    19053,
+    19055,
+    19056,
+    19056,
+    19058,
+    19058,
+    19060,
+    19058,
+    19058,
+    19063,
+    19064,
+    19065,
+    19066,
+    19067,
+    19068,
+    19068,
+    19070,
+    19071,
+    19072,
+    19068,
+    19074,
+    19074,
+    19074,
+    19077,
+    19078,
+    19079,
+    19079,
+    19078,
+    19082,
+    19083,
+    19083,
+    19082,
+    19086,
+    19086,
+    19088,
+    19088,
+    19090,
+    19091,
+    19092,
+    19093,
+    19093,
+    19092,
+    19096,
+    19096,
+    19098,
+    19098,
+    19100,
+    19101,
+    19100,
+    19103,
+    19104,
+    19104,
+    19103,
+    19107,
+    19107,
+    19109,
+    19109,
+    19111,
+    19112,
+    19112,
+    19114,
+    19114,
+    19116,
+    19116,
+    19118,
+    19118,
+    19120,
+    19120,
+    19122,
+    19122,
+    19111,
+    19125,
+    19126,
+    19127,
+    19127,
+    19129,
+    19129,
+    19131,
+    19132,
+    19131,
+    19134,
+    19135,
+    19135,
+    19137,
+    19137,
+    19134,
+    19140,
+    19140,
+    19142,
+    19142,
+    19144,
+    19144,
+    19146,
+    19147,
+    19147,
+    19149,
+    19149,
+    19151,
+    19151,
+    19153,
+    19153,
+    19155,
+    19156,
+    19157,
+    19158,
+    19157,
+    19160,
+    19160,
+    19162,
+    19162,
+    19156,
+    19165,
+    19165,
+    19167,
+    19167,
+    19169,
+    19170,
+    19170,
+    19169,
+    19173,
+    19174,
+    19173,
+    19176,
+    19176,
+    19178,
+    19178,
+    19180,
+    19180,
+    19182,
+    19183,
+    19184,
+    19184,
+    19186,
+    19187,
+    19188,
+    19189,
+    19189,
+    19189,
+    19189,
+    19193,
+    19194,
+    19194,
+    19196,
+    19197,
+    19197,
+    19199,
+    19200,
+    19201,
+    19202,
+    19202,
+    19204,
+    19205,
+    19205,
+    19207,
+    19208,
+    19208,
+    19210,
+    19207,
+    19201,
+    19213,
+    19214,
+    19213,
+    19214,
+    19217,
+    19217,
+    19219,
+    19220,
+    19220,
+    19222,
+    19222,
+    19222,
+    19222,
+    19222,
+    19219,
+    19228,
+    19228,
+    19230,
+    19230,
+    19232,
+    19232,
+    19234,
+    19235,
+    19235,
+    19237,
+    19237,
+    19234,
+    19240,
+    19241,
+    19240,
+    19243,
+    19244,
+    19244,
+    19243,
+    19247,
+    19247,
+    19249,
+    19250,
+    19250,
+    19252,
+    19252,
+    19254,
+    19254,
+    19256,
+    19256,
+    19258,
+    19259,
+    19258,
+    19258,
+    19262,
+    19263,
+    19264,
+    19264,
+    19266,
+    19266,
+    19268,
+    19269,
+    19268,
+    19271,
+    19271,
+    19273,
+    19274,
+    19275,
+    19276,
+    19276,
+    19278,
+    19279,
+    19280,
+    19279,
+    19282,
+    19282,
+    19284,
+    19275,
+    19286,
+    19286,
+    19288,
+    19289,
+    19289,
+    19291,
+    19291,
+    19288,
+    19294,
+    19295,
+    19295,
+    19297,
+    19298,
+    19297,
+    19300,
+    19301,
+    19302,
+    19302,
+    19302,
+    19305,
+    19305,
+    19305,
+    19308,
+    19309,
+    19310,
+    19310,
+    19312,
+    19312,
+    19314,
+    19315,
+    19315,
+    19317,
+    19318,
+    19319,
+    19320,
+    19321,
+    19322,
+    19322,
+    19324,
+    19325,
+    19325,
+    19327,
+    19328,
+    19329,
+    19329,
+    19331,
+    19332,
+    19332,
+    19321,
+    19335,
+    19335,
+    19337,
+    19338,
+    19339,
+    19340,
+    19340,
+    19342,
+    19342,
+    19339,
+    19345,
+    19345,
+    19347,
+    19347,
+    19349,
+    19339,
+    19351,
+    19351,
+    19353,
+    19354,
+    19354,
+    19356,
+    19356,
+    19358,
+    19358,
+    19360,
+    19361,
+    19361,
+    19363,
+    19363,
+    19365,
+    19366,
+    19365,
+    19368,
+    19369,
+    19369,
+    19371,
+    19372,
+    19372,
+    19374,
+    19374,
+    19376,
+    19377,
+    19377,
+    19379,
+    19379,
+    19381,
+    19381,
+    19383,
+    19383,
+    19385,
+    19385,
+    19387,
+    19387,
+    19389,
+    19390,
+    19390,
+    19389,
+    19393,
+    19393,
+    19395,
+    19396,
+    19397,
+    19398,
+    19399,
+    19399,
+    19398,
+    19402,
+    19402,
+    19395,
+    19405,
+    19406,
+    19406,
+    19408,
+    19409,
+    19409,
+    19411,
+    19411,
+    19413,
+    19414,
+    19415,
+    19415,
+    19415,
+    19418,
+    19419,
+    19419,
+    19421,
+    19422,
+    19422,
+    19424,
+    19424,
+    19426,
+    19426,
+    19428,
+    19429,
+    19430,
+    19430,
+    19418,
+    19433,
+    19433,
+    19435,
+    19435,
+    19437,
+    19437,
+    19435,
+    19440,
+    19441,
+    19442,
+    19443,
+    19443,
+    19445,
+    19446,
+    19447,
+    19448,
+    19449,
+    19450,
+    19451,
+    19452,
+    19452,
+    19451,
+    19455,
+    19447,
+    19457,
+    19458,
+    19458,
+    19457,
+    19461,
+    19462,
+    19462,
+    19464,
+    19464,
+    19466,
+    19466,
+    19468,
+    19468,
+    19461,
+    19471,
+    19471,
+    19473,
+    19474,
+    19461,
+    19476,
+    19476,
+    19442,
+    19479,
+    19480,
+    19480,
+    19479,
+    19483,
+    19484,
+    19484,
+    19484,
+    19487,
+    19487,
+    19483,
+    19490,
+    19491,
+    19492,
+    19492,
+    19492,
+    19495,
+    19496,
+    19497,
+    19498,
+    19499,
+    19500,
+    19501,
+    19502,
+    19503,
+    19503,
+    19505,
+    19505,
+    19507,
+    19507,
+    19509,
+    19509,
+    19507,
+    19512,
+    19513,
+    19513,
+    19513,
+    19512,
+    19502,
+    19518,
+    19519,
+    19520,
+    19520,
+    19522,
+    19523,
+    19523,
+    19525,
+    19525,
+    19527,
+    19528,
+    19528,
+    19530,
+    19530,
+    19532,
+    19533,
+    19532,
+    19535,
+    19535,
+    19537,
+    19537,
+    19539,
+    19540,
+    19540,
+    19542,
+    19542,
+    19544,
+    19545,
+    19546,
+    19547,
+    19548,
+    19548,
+    19548,
+    19551,
+    19551,
+    19553,
+    19554,
+    19555,
+    19556,
+    19556,
+    19558,
+    19558,
+    19560,
+    19560,
+    19562,
+    19563,
+    19562,
+    19565,
+    19565,
+    19567,
+    19568,
+    19567,
+    19570,
+    19571,
+    19572,
+    19573,
+    19573,
+    19572,
+    19576,
+    19576,
+    19578,
+    19579,
+    19580,
+    19581,
+    19581,
+    19583,
+    19583,
+    19581,
+    19586,
+    19586,
+    19588,
+    19586,
+    19590,
+    19591,
+    19591,
+    19593,
+    19594,
+    19595,
+    19595,
+    19597,
+    19597,
+    19599,
+    19600,
+    19601,
+    19601,
+    19600,
+    19604,
+    19604,
+    19604,
+    19607,
+    19608,
+    19609,
+    19610,
+    19610,
+    19607,
+    19613,
+    19613,
+    19615,
+    19616,
+    19616,
+    19618,
+    19616,
+    19620,
+    19615,
+    19622,
+    19622,
+    19624,
+    19625,
+    19625,
+    19624,
+    19628,
+    19629,
+    19628,
+    19631,
+    19631,
+    19633,
+    19633,
+    19635,
+    19635,
+    19637,
+    19638,
+    19638,
+    19640,
+    19641,
+    19641,
+    19640,
+    19644,
+    19645,
+    19645,
+    19644,
+    19648,
+    19648,
+    19650,
+    19651,
+    19650,
+    19653,
+    19654,
+    19655,
+    19655,
+    19657,
+    19657,
+    19659,
+    19660,
+    19660,
+    19662,
+    19662,
+    19659,
+    19665,
+    19666,
+    19667,
+    19666,
+    19669,
+    19669,
+    19671,
+    19671,
+    19673,
+    19673,
+    19665,
+    19676,
+    19677,
+    19678,
+    19678,
+    19680,
+    19676,
+    19682,
+    19683,
+    19684,
+    19684,
+    19686,
+    19687,
+    19688,
+    19689,
+    19690,
+    19690,
+    19692,
+    19693,
+    19692,
+    19695,
+    19695,
+    19697,
+    19697,
+    19699,
+    19699,
+    19701,
+    19701,
+    19703,
+    19704,
+    19705,
+    19706,
+    19706,
+    19708,
+    19708,
+    19710,
+    19710,
+    19712,
+    19713,
+    19714,
+    19715,
+    19716,
+    19717,
+    19717,
+    19719,
+    19719,
+    19721,
+    19721,
+    19723,
+    19723,
+    19725,
+    19726,
+    19726,
+    19728,
+    19728,
+    19730,
+    19730,
+    19732,
+    19733,
+    19732,
+    19735,
+    19735,
+    19737,
+    19737,
+    19739,
+    19739,
+    19741,
+    19742,
+    19741,
+    19744,
+    19744,
+    19746,
+    19747,
+    19748,
+    19749,
+    19750,
+    19751,
+    19751,
+    19753,
+    19753,
+    19753,
+    19756,
+    19756,
+    19758,
+    19758,
+    19760,
+    19760,
+    19762,
+    19762,
+    19764,
+    19765,
+    19765,
+    19767,
+    19768,
+    19769,
+    19769,
+    19771,
+    19772,
+    19773,
+    19774,
+    19775,
+    19775,
+    19777,
+    19777,
+    19779,
+    19779,
+    19779,
+    19782,
+    19782,
+    19784,
+    19784,
+    19786,
+    19786,
+    19788,
+    19788,
+    19788,
+    19791,
+    19792,
+    19792,
+    19794,
+    19794,
+    19796,
+    19797,
+    19798,
+    19798,
+    19800,
+    19800,
+    19802,
+    19803,
+    19804,
+    19803,
+    19806,
+    19806,
+    19808,
+    19809,
+    19810,
+    19810,
+    19812,
+    19813,
+    19813,
+    19815,
+    19815,
+    19817,
+    19817,
+    19817,
+    19817,
+    19821,
+    19821,
+    19823,
+    19824,
+    19825,
+    19826,
+    19826,
+    19826,
+    19829,
+    19829,
+    19826,
+    19832,
+    19832,
+    19832,
+    19835,
+    19835,
+    19832,
+    19838,
+    19838,
+    19840,
+    19841,
+    19841,
+    19843,
+    19843,
+    19845,
+    19823,
+    19847,
+    19847,
+    19849,
+    19850,
+    19850,
+    19852,
+    19852,
+    19854,
+    19854,
+    19850,
+    19857,
+    19858,
+    19858,
+    19860,
+    19860,
+    19862,
+    19863,
+    19864,
+    19865,
+    19866,
+    19850,
+    19850,
+    19869,
+    19869,
+    19871,
+    19871,
+    19871,
+    19874,
+    19874,
+    19874,
+    19869,
+    19869,
+    19879,
+    19880,
+    19881,
+    19881,
+    19881,
+    19884,
+    19884,
+    19884,
+    19849,
+    19888,
+    19889,
+    19889,
+    19891,
+    19892,
+    19892,
+    19894,
+    19895,
+    19896,
+    19897,
+    19898,
+    19899,
+    19899,
+    19901,
+    19901,
+    19898,
+    19904,
+    19905,
+    19898,
+    19898,
+    19908,
+    19908,
+    19910,
+    19911,
+    19911,
+    19910,
+    19897,
+    19904,
+    19916,
+    19916,
+    19918,
+    19919,
+    19920,
+    19921,
+    19921,
+    19923,
+    19924,
+    19924,
+    19926,
+    19927,
+    19928,
+    19928,
+    19930,
+    19930,
+    19927,
+    19933,
+    19933,
+    19935,
+    19935,
+    19937,
+    19938,
+    19938,
+    19937,
+    19941,
+    19942,
+    19942,
+    19944,
+    19944,
+    19946,
+    19947,
+    19948,
+    19948,
+    19947,
+    19947,
+    19952,
+    19953,
+    19954,
+    19955,
+    19955,
+    19957,
+    19957,
+    19959,
+    19959,
+    19961,
+    19961,
+    19955,
+    19964,
+    19964,
+    19966,
+    19967,
+    19968,
+    19955,
+    19955,
+    19971,
+    19972,
+    19973,
+    19973,
+    19975,
+    19976,
+    19977,
+    19977,
+    19979,
+    19979,
+    19981,
+    19982,
+    19983,
+    19984,
+    19973,
+    19973,
+    19987,
+    19987,
+    19989,
+    19990,
+    19973,
+    19992,
+    19983,
+    19994,
+    19994,
+    19996,
+    19996,
+    19994,
+    19999,
+    19999,
+    20001,
+    19994,
+    20003,
+    19994,
+    20005,
+    20006,
+    20007,
+    20008,
+    20009,
+    20009,
+    20011,
+    20011,
+    20009,
+    20014,
+    20014,
+    20016,
+    20016,
+    20009,
+    20019,
+    20019,
+    19972,
+    20022,
+    20023,
+    20023,
+    20025,
+    20025,
+    20023,
+    20028,
+    20028,
+    20030,
+    20030,
+    20030,
+    20033,
+    20033,
+    20035,
+    20036,
+    20037,
+    20038,
+    20038,
+    20040,
+    20040,
+    20036,
+    20043,
+    20044,
+    20044,
+    20046,
+    20047,
+    20048,
+    20049,
+    20049,
+    20049,
+    20052,
+    20052,
+    20054,
+    20055,
+    20055,
+    20057,
+    20058,
+    20058,
+    20060,
+    20060,
+    20062,
+    20063,
+    20064,
+    20065,
+    20066,
+    20062,
+    20068,
+    20069,
+    20070,
+    20071,
+    20071,
+    20073,
+    20073,
+    20075,
+    20076,
+    20077,
+    20077,
+    20079,
+    20080,
+    20080,
+    20082,
+    20076,
+    20084,
+    20084,
+    20086,
+    20084,
+    20088,
+    20088,
+    20090,
+    20091,
+    20092,
+    20093,
+    20094,
+    20095,
+    20094,
+    20097,
+    20098,
+    20097,
+    20100,
+    20100,
+    20097,
+    20103,
+    20104,
+    20104,
+    20106,
+    20107,
+    20108,
+    20106,
+    20110,
+    20111,
+    20112,
+    20112,
+    20114,
+    20115,
+    20114,
+    20117,
+    20117,
+    20119,
+    20117,
+    20121,
+    20117,
+    20123,
+    20124,
+    20125,
+    20125,
+    20125,
+    20128,
+    20129,
+    20090,
+    20131,
+    20131,
+    20133,
+    20134,
+    20134,
+    20136,
+    20137,
+    20137,
+    20139,
+    20139,
+    20141,
+    20141,
+    20143,
+    20141,
+    20137,
+    20146,
+    20147,
+    20148,
+    20147,
+    20150,
+    20147,
+    20152,
+    20153,
+    20153,
+    20153,
+    20153,
+    20157,
+    20157,
+    20159,
+    20159,
+    20161,
+    20162,
+    20161,
+    20164,
+    20165,
+    20166,
+    20167,
+    20167,
+    20169,
+    20169,
+    20169,
+    20172,
+    20167,
+    20174,
+    20175,
+    20167,
+    20167,
+    20174,
+    20166,
+    20180,
+    20180,
+    20182,
+    20183,
+    20184,
+    20185,
+    20186,
+    20186,
+    20186,
+    20189,
+    20189,
+    20191,
+    20192,
+    20192,
+    20194,
+    20194,
+    20152,
+    20197,
+    20198,
+    20199,
+    20200,
+    20200,
+    20199,
+    20199,
+    20204,
+    20204,
+    20206,
+    20206,
+    20208,
+    20208,
+    20206,
+    20211,
+    20211,
+    20213,
+    20213,
+    20215,
+    20215,
+    20217,
+    20217,
+    20215,
+    20220,
+    20221,
+    20221,
+    20223,
+    20223,
+    20225,
+    20226,
+    20226,
+    20228,
+    20229,
+    20229,
+    20231,
+    20232,
+    20232,
+    20231,
+    20229,
+    20229,
+    20237,
+    20237,
+    20239,
+    20240,
+    20240,
+    20228,
+    20243,
+    20244,
+    20244,
+    20246,
+    20247,
+    20247,
+    20249,
+    20249,
+    20244,
+    20244,
+    20253,
+    20253,
+    20255,
+    20256,
+    20256,
+    20256,
+    20259,
+    20260,
+    20243,
+    20262,
+    20263,
+    20263,
+    20263,
+    20263,
+    20267,
+    20268,
+    20267,
+    20270,
+    20271,
+    20272,
+    20272,
+    20274,
+    20274,
+    20274,
+    20274,
+    20278,
+    20272,
+    20278,
+    20281,
+    20282,
+    20282,
+    20284,
+    20284,
+    20286,
+    20272,
+    20288,
+    20288,
+    20290,
+    20291,
+    20291,
+    20291,
+    20294,
+    20295,
+    20294,
+    20297,
+    20291,
+    20299,
+    20299,
+    20301,
+    20301,
+    20294,
+    20304,
+    20294,
+    20306,
+    20307,
+    20308,
+    20308,
+    20310,
+    20311,
+    20311,
+    20311,
+    20311,
+    20315,
+    20315,
+    20317,
+    20317,
+    20319,
+    20320,
+    20320,
+    20320,
+    20323,
+    20323,
+    20325,
+    20326,
+    20326,
+    20326,
+    20329,
+    20330,
+    20330,
+    20332,
+    20332,
+    20334,
+    20335,
+    20336,
+    20337,
+    20337,
+    20336,
+    20336,
+    20341,
+    20342,
+    20343,
+    20343,
+    20345,
+    20342,
+    20347,
+    20307,
+    20349,
+    20349,
+    20351,
+    20352,
+    20353,
+    20354,
+    20354,
+    20356,
+    20356,
+    20358,
+    20359,
+    20360,
+    20360,
+    20359,
+    20363,
+    20363,
+    20365,
+    20366,
+    20365,
+    20368,
+    20368,
+    20370,
+    20371,
+    20371,
+    20373,
+    20374,
+    20375,
+    20376,
+    20377,
+    20378,
+    20376,
+    20380,
+    20380,
+    20382,
+    20383,
+    20384,
+    20385,
+    20386,
+    20386,
+    20388,
+    20389,
+    20389,
+    20388,
+    20392,
+    20392,
+    20394,
+    20395,
+    20394,
+    20397,
+    20398,
+    20398,
+    20400,
+    20384,
+    20402,
+    20402,
+    20404,
+    20404,
+    20406,
+    20406,
+    20408,
+    20408,
+    20410,
+    20410,
+    20412,
+    20413,
+    20413,
+    20412,
+    20416,
+    20416,
+    20418,
+    20419,
+    20419,
+    20421,
+    20422,
+    20423,
+    20424,
+    20425,
+    20425,
+    20427,
+    20428,
+    20427,
+    20430,
+    20431,
+    20431,
+    20433,
+    20434,
+    20434,
+    20433,
+    20437,
+    20437,
+    20439,
+    20439,
+    20441,
+    20441,
+    20443,
+    20444,
+    20445,
+    20446,
+    20447,
+    20448,
+    20448,
+    20450,
+    20451,
+    20452,
+    20453,
+    20454,
+    20455,
+    20456,
+    20456,
+    20458,
+    20455,
+    20460,
+    20460,
+    20462,
+    20462,
+    20464,
+    20465,
+    20466,
+    20466,
+    20468,
+    20469,
+    20470,
+    20471,
+    20464,
+    20473,
+    20474,
+    20475,
+    20475,
+    20477,
+    20478,
+    20477,
+    20480,
+    20480,
+    20482,
+    20483,
+    20484,
+    20485,
+    20486,
+    20487,
+    20488,
+    20489,
+    20490,
+    20490,
+    20492,
+    20492,
+    20494,
+    20495,
+    20489,
+    20497,
+    20497,
+    20499,
+    20500,
+    20501,
+    20501,
+    20503,
+    20503,
+    20505,
+    20505,
+    20507,
+    20507,
+    20509,
+    20510,
+    20510,
+    20512,
+    20512,
+    20514,
+    20515,
+    20516,
+    20517,
+    20517,
+    20516,
+    20520,
+    20520,
+    20522,
+    20522,
+    20524,
+    20525,
+    20526,
+    20515,
+    20528,
+    20528,
+    20530,
+    20531,
+    20532,
+    20533,
+    20534,
+    20535,
+    20536,
+    20536,
+    20538,
+    20538,
+    20540,
+    20541,
+    20541,
+    20543,
+    20544,
+    20544,
+    20546,
+    20546,
+    20548,
+    20549,
+    20549,
+    20548,
+    20552,
+    20553,
+    20554,
+    20555,
+    20556,
+    20557,
+    20558,
+    20557,
+    20560,
+    20561,
+    20562,
+    20563,
+    20562,
+    20565,
+    20565,
+    20565,
+    20555,
+    20569,
+    20570,
+    20571,
+    20572,
+    20573,
+    20572,
+    20571,
+    20576,
+    20576,
+    20578,
+    20578,
+    20578,
+    20581,
+    20581,
+    20583,
+    20583,
+    20581,
+    20586,
+    20586,
+    20588,
+    20589,
+    20589,
+    20591,
+    20591,
+    20588,
+    20594,
+    20595,
+    20596,
+    20597,
+    20597,
+    20599,
+    20599,
+    20594,
+    20602,
+    20602,
+    20604,
+    20605,
+    20605,
+    20607,
+    20607,
+    20609,
+    20609,
+    20611,
+    20611,
+    20604,
+    20614,
+    20615,
+    20615,
+    20617,
+    20617,
+    20619,
+    20619,
+    20621,
+    20621,
+    20623,
+    20624,
+    20619,
+    20626,
+    20627,
+    20627,
+    20629,
+    20630,
+    20630,
+    20629,
+    20633,
+    20634,
+    20634,
+    20634,
+    20637,
+    20637,
+    20637,
+    20640,
+    20640,
+    20642,
+    20642,
+    20644,
+    20644,
+    20646,
+    20646,
+    20648,
+    20648,
+    20648,
+    20642,
+    20652,
+    20653,
+    20654,
+    20655,
+    20626,
+    20657,
+    20657,
+    20659,
+    20659,
+    20661,
+    20661,
+    20663,
+    20663,
+    20659,
+    20666,
+    20667,
+    20668,
+    20669,
+    20669,
+    20671,
+    20671,
+    20668,
+    20674,
+    20675,
+    20676,
+    20676,
+    20678,
+    20679,
+    20680,
+    20680,
+    20675,
+    20683,
+    20683,
+    20685,
+    20685,
+    20685,
+    20685,
+    20675,
+    20690,
+    20691,
+    20692,
+    20692,
+    20694,
+    20694,
+    20692,
+    20697,
+    20698,
+    20698,
+    20700,
+    20700,
+    20702,
+    20702,
+    20700,
+    20705,
+    20705,
+    20707,
+    20708,
+    20709,
+    20709,
+    20708,
+    20712,
+    20712,
+    20714,
+    20715,
+    20715,
+    20708,
+    20718,
+    20719,
+    20720,
+    20721,
+    20722,
+    20722,
+    20724,
+    20724,
+    20726,
+    20727,
+    20728,
+    20728,
+    20730,
+    20731,
+    20732,
+    20733,
+    20734,
+    20734,
+    20731,
+    20737,
+    20737,
+    20731,
+    20740,
+    20740,
+    20742,
+    20743,
+    20744,
+    20745,
+    20745,
+    20744,
+    20748,
+    20748,
+    20750,
+    20750,
+    20752,
+    20752,
+    20752,
+    20755,
+    20743,
+    20757,
+    20758,
+    20759,
+    20760,
+    20760,
+    20762,
+    20763,
+    20763,
+    20765,
+    20765,
+    20762,
+    20768,
+    20768,
+    20762,
+    20771,
+    20771,
+    20773,
+    20774,
+    20775,
+    20775,
+    20774,
+    20778,
+    20778,
+    20780,
+    20780,
+    20778,
+    20721,
+    20784,
+    20785,
+    20786,
+    20786,
+    20788,
+    20788,
+    20786,
+    20791,
+    20792,
+    20792,
+    20794,
+    20795,
+    20796,
+    20795,
+    20798,
+    20799,
+    20799,
+    20801,
+    20802,
+    20803,
+    20804,
+    20805,
+    20805,
+    20807,
+    20807,
+    20809,
+    20809,
+    20804,
+    20812,
+    20812,
+    20798,
+    20815,
+    20815,
+    20815,
+    20818,
+    20819,
+    20819,
+    20819,
+    20822,
+    20822,
+    20824,
+    20825,
+    20825,
+    20827,
+    20828,
+    20829,
+    20830,
+    20831,
+    20831,
+    20833,
+    20834,
+    20835,
+    20834,
+    20837,
+    20838,
+    20839,
+    20840,
+    20841,
+    20842,
+    20843,
+    20844,
+    20843,
+    20842,
+    20847,
+    20848,
+    20848,
+    20850,
+    20851,
+    20852,
+    20852,
+    20852,
+    20855,
+    20852,
+    20847,
+    20858,
+    20859,
+    20859,
+    20839,
+    20862,
+    20863,
+    20864,
+    20864,
+    20866,
+    20866,
+    20868,
+    20868,
+    20870,
+    20871,
+    20864,
+    20873,
+    20874,
+    20875,
+    20875,
+    20877,
+    20878,
+    20878,
+    20880,
+    20880,
+    20882,
+    20882,
+    20884,
+    20884,
+    20886,
+    20887,
+    20888,
+    20888,
+    20886,
+    20886,
+    20886,
+    20893,
+    20893,
+    20895,
+    20895,
+    20893,
+    20893,
+    20893,
+    20900,
+    20900,
+    20902,
+    20903,
+    20900,
+    20900,
+    20906,
+    20907,
+    20907,
+    20909,
+    20909,
+    20907,
+    20912,
+    20913,
+    20914,
+    20914,
+    20916,
+    20916,
+    20914,
+    20914,
+    20914,
+    20921,
+    20922,
+    20922,
+    20924,
+    20925,
+    20926,
+    20926,
+    20928,
+    20928,
+    20930,
+    20930,
+    20928,
+    20933,
+    20924,
+    20935,
+    20935,
+    20937,
+    20938,
+    20939,
+    20940,
+    20941,
+    20941,
+    20943,
+    20943,
+    20941,
+    20941,
+    20947,
+    20947,
+    20949,
+    20950,
+    20950,
+    20952,
+    20952,
+    20954,
+    20954,
+    20949,
+    20957,
+    20958,
+    20958,
+    20957,
+    20961,
+    20962,
+    20962,
+    20961,
+    20965,
+    20965,
+    20967,
+    20967,
+    20961,
+    20970,
+    20970,
+    20972,
+    20972,
+    20974,
+    20974,
+    20961,
+    20977,
+    20978,
+    20979,
+    20980,
+    20980,
+    20982,
+    20982,
+    20984,
+    20985,
+    20986,
+    20987,
+    20988,
+    20989,
+    20990,
+    20990,
+    20989,
+    20993,
+    20994,
+    20995,
+    20995,
+    20994,
+    20998,
+    20998,
+    20988,
+    21001,
+    21001,
+    21003,
+    21003,
+    21005,
+    21001,
+    21001,
+    21008,
+    21009,
+    21010,
+    21010,
+    21012,
+    21012,
+    21014,
+    21014,
+    21016,
+    21017,
+    21017,
+    21019,
+    21020,
+    21021,
+    21022,
+    21022,
+    21024,
+    21024,
+    21009,
+    21027,
+    21027,
+    21029,
+    21030,
+    21031,
+    21031,
+    21033,
+    21033,
+    21035,
+    21036,
+    21036,
+    21038,
+    21038,
+    21040,
+    21040,
+    21042,
+    21042,
+    21044,
+    21045,
+    21045,
+    21047,
+    21048,
+    21047,
+    21044,
+    21051,
+    21052,
+    21052,
+    21054,
+    21055,
+    21056,
+    21056,
+    21058,
+    21058,
+    21056,
+    21061,
+    21062,
+    21063,
+    21063,
+    21063,
+    21066,
+    21066,
+    21068,
+    21068,
+    21070,
+    21071,
+    21071,
+    21073,
+    21073,
+    21073,
+    21054,
+    21077,
+    21078,
+    21079,
+    21080,
+    21081,
+    21082,
+    21083,
+    21083,
+    21085,
+    21086,
+    21086,
+    21088,
+    21088,
+    21086,
+    21086,
+    21085,
+    21093,
+    21094,
+    21095,
+    21095,
+    21097,
+    21098,
+    21098,
+    21100,
+    21101,
+    21102,
+    21102,
+    21102,
+    21102,
+    21106,
+    21107,
+    21108,
+    21108,
+    21110,
+    21111,
+    21112,
+    21111,
+    21114,
+    21114,
+    21114,
+    21117,
+    21108,
+    21119,
+    21120,
+    21121,
+    21117,
+    21123,
+    21124,
+    21125,
+    21126,
+    21124,
+    21128,
+    21129,
+    21130,
+    21131,
+    21131,
+    21130,
+    21134,
+    21134,
+    21136,
+    21137,
+    21138,
+    21139,
+    21129,
+    21141,
+    21142,
+    21143,
+    21144,
+    21145,
+    21146,
+    21146,
+    21148,
+    21149,
+    21150,
+    21151,
+    21151,
+    21153,
+    21153,
+    21155,
+    21156,
+    21156,
+    21158,
+    21159,
+    21160,
+    21160,
+    21162,
+    21162,
+    21160,
+    21165,
+    21166,
+    21167,
+    21168,
+    21158,
+    21170,
+    21171,
+    21171,
+    21173,
+    21171,
+    21171,
+    21170,
+    21177,
+    21178,
+    21178,
+    21180,
+    21178,
+    21177,
+    21183,
+    21143,
+    21185,
+    21185,
+    21187,
+    21185,
+    21189,
+    21190,
+    21190,
+    21190,
+    21193,
+    21190,
+    21195,
+    21196,
+    21196,
+    21198,
+    21198,
+    21198,
+    21201,
+    21201,
+    21203,
+    21203,
+    21205,
+    21206,
+    21206,
+    21208,
+    21208,
+    21205,
+    21211,
+    21212,
+    21212,
+    21205,
+    21215,
+    21216,
+    21216,
+    21218,
+    21219,
+    21205,
+    21205,
+    21222,
+    21223,
+    21224,
+    21225,
+    21226,
+    21226,
+    21228,
+    21228,
+    21228,
+    21231,
+    21226,
+    21233,
+    21226,
+    21226,
+    21236,
+    21237,
+    21237,
+    21239,
+    21239,
+    21241,
+    21222,
+    21243,
+    21244,
+    21244,
+    21246,
+    21246,
+    21246,
+    21246,
+    21250,
+    21250,
+    21252,
+    21253,
+    21253,
+    21253,
+    21253,
+    21257,
+    21258,
+    21259,
+    21259,
+    21261,
+    21261,
+    21261,
+    21264,
+    21264,
+    21266,
+    21267,
+    21268,
+    21268,
+    21266,
+    21271,
+    21272,
+    21273,
+    21274,
+    21274,
+    21276,
+    21276,
+    21278,
+    21278,
+    21280,
+    21281,
+    21282,
+    21283,
+    21278,
+    21278,
+    21282,
+    21287,
+    21288,
+    21288,
+    21290,
+    21291,
+    21291,
+    21293,
+    21294,
+    21294,
+    21290,
+    21297,
+    21298,
+    21298,
+    21300,
+    21301,
+    21302,
+    21302,
+    21304,
+    21304,
+    21302,
+    21301,
+    21308,
+    21308,
+    21310,
+    21311,
+    21312,
+    21313,
+    21313,
+    21315,
+    21315,
+    21317,
+    21317,
+    21319,
+    21319,
+    21319,
+    21322,
+    21319,
+    21324,
+    21324,
+    21326,
+    21327,
+    21328,
+    21329,
+    21329,
+    21331,
+    21331,
+    21333,
+    21333,
+    21335,
+    21336,
+    21336,
+    21338,
+    21339,
+    21329,
+    21341,
+    21329,
+    21343,
+    21312,
+    21345,
+    21346,
+    21347,
+    21347,
+    21349,
+    21349,
+    21351,
+    21346,
+    21353,
+    21353,
+    21353,
+    21356,
+    21356,
+    21358,
+    21359,
+    21359,
+    21358,
+    21362,
+    21362,
+    21358,
+    21365,
+    21365,
+    21367,
+    21367,
+    21369,
+    21369,
+    21371,
+    21371,
+    21373,
+    21374,
+    21374,
+    21376,
+    21376,
+    21374,
+    21379,
+    21380,
+    21381,
+    21380,
+    21373,
+    21384,
+    21384,
+    21386,
+    21386,
+    21388,
+    21388,
+    21390,
+    21391,
+    21392,
+    21393,
+    21394,
+    21394,
+    21396,
+    21396,
+    21393,
+    21345,
+    21400,
+    21400,
+    21400,
+    21391,
+    21404,
+    21405,
+    21405,
+    21404,
+    21408,
+    21409,
+    21410,
+    21410,
+    21410,
+    21410,
+    21414,
+    21415,
+    21415,
+    21414,
+    21418,
+    21418,
+    21418,
+    21421,
+    21422,
+    21423,
+    21418,
+    21425,
+    21425,
+    21425,
+    21425,
+    21409,
+    21430,
+    21431,
+    21432,
+    21432,
+    21434,
+    21432,
+    21436,
+    21437,
+    21438,
+    21438,
+    21440,
+    21440,
+    21442,
+    21438,
+    21444,
+    21445,
+    21445,
+    21447,
+    21445,
+    21449,
+    21450,
+    21451,
+    21450,
+    21453,
+    21454,
+    21454,
+    21456,
+    21450,
+    21458,
+    21459,
+    21459,
+    21461,
+    21461,
+    21450,
+    21450,
+    21465,
+    21465,
+    21467,
+    21467,
+    21467,
+    21467,
+    21471,
+    21472,
+    21472,
+    21472,
+    21472,
+    21476,
+    21476,
+    21478,
+    21479,
+    21478,
+    21481,
+    21482,
+    21483,
+    21483,
+    21485,
+    21485,
+    21487,
+    21488,
+    21489,
+    21490,
+    21491,
+    21492,
+    21492,
+    21494,
+    21494,
+    21496,
+    21496,
+    21498,
+    21499,
+    21500,
+    21500,
+    21502,
+    21502,
+    21504,
+    21504,
+    21506,
+    21507,
+    21507,
+    21509,
+    21510,
+    21510,
+    21512,
+    21513,
+    21514,
+    21509,
+    21506,
+    21517,
+    21517,
+    21519,
+    21519,
+    21517,
+    21522,
+    21522,
+    21524,
+    21524,
+    21526,
+    21527,
+    21527,
+    21529,
+    21529,
+    21531,
+    21532,
+    21532,
+    21531,
+    21535,
+    21536,
+    21537,
+    21538,
+    21538,
+    21540,
+    21541,
+    21538,
+    21543,
+    21544,
+    21545,
+    21545,
+    21547,
+    21548,
+    21548,
+    21550,
+    21551,
+    21551,
+    21553,
+    21554,
+    21554,
+    21556,
+    21556,
+    21554,
+    21559,
+    21560,
+    21561,
+    21561,
+    21560,
+    21564,
+    21564,
+    21566,
+    21564,
+    21568,
+    21568,
+    21559,
+    21571,
+    21571,
+    21554,
+    21574,
+    21575,
+    21576,
+    21577,
+    21577,
+    21577,
+    21577,
+    21575,
+    21582,
+    21582,
+    21582,
+    21585,
+    21585,
+    21587,
+    21588,
+    21588,
+    21590,
+    21587,
+    21553,
+    21593,
+    21593,
+    21593,
+    21593,
+    21597,
+    21598,
+    21599,
+    21600,
+    21601,
+    21602,
+    21603,
+    21603,
+    21605,
+    21605,
+    21597,
+    21608,
+    21608,
+    21610,
+    21610,
+    21612,
+    21613,
+    21612,
+    21615,
+    21616,
+    21617,
+    21618,
+    21618,
+    21620,
+    21621,
+    21621,
+    21623,
+    21624,
+    21625,
+    21624,
+    21627,
+    21627,
+    21629,
+    21630,
+    21630,
+    21632,
+    21633,
+    21634,
+    21634,
+    21636,
+    21636,
+    21633,
+    21639,
+    21640,
+    21640,
+    21642,
+    21642,
+    21644,
+    21644,
+    21646,
+    21646,
+    21620,
+    21649,
+    21649,
+    21651,
+    21652,
+    21653,
+    21653,
+    21655,
+    21656,
+    21656,
+    21658,
+    21658,
+    21660,
+    21660,
+    21662,
+    21663,
+    21664,
+    21663,
+    21662,
+    21667,
+    21668,
+    21669,
+    21668,
+    21671,
+    21672,
+    21672,
+    21674,
+    21674,
+    21676,
+    21676,
+    21674,
+    21671,
+    21680,
+    21681,
+    21682,
+    21683,
+    21684,
+    21681,
+    21686,
+    21686,
+    21688,
+    21688,
+    21690,
+    21691,
+    21692,
+    21692,
+    21694,
+    21695,
+    21696,
+    21697,
+    21697,
+    21697,
+    21700,
+    21701,
+    21697,
+    21703,
+    21704,
+    21705,
+    21706,
+    21706,
+    21705,
+    21709,
+    21710,
+    21710,
+    21712,
+    21705,
+    21705,
+    21712,
+    21716,
+    21716,
+    21704,
+    21719,
+    21720,
+    21721,
+    21721,
+    21723,
+    21724,
+    21720,
+    21726,
+    21727,
+    21728,
+    21729,
+    21730,
+    21731,
+    21731,
+    21733,
+    21733,
+    21735,
+    21735,
+    21733,
+    21738,
+    21739,
+    21739,
+    21741,
+    21741,
+    21728,
+    21744,
+    21745,
+    21746,
+    21746,
+    21748,
+    21749,
+    21750,
+    21719,
+    21752,
+    21752,
+    21752,
+    21755,
+    21752,
+    21752,
+    21758,
+    21752,
+    21760,
+    21761,
+    21762,
+    21762,
+    21764,
+    21764,
+    21766,
+    21766,
+    21768,
+    21769,
+    21770,
+    21770,
+    21772,
+    21773,
+    21773,
+    21775,
+    21775,
+    21777,
+    21778,
+    21778,
+    21777,
+    21781,
+    21777,
+    21783,
+    21784,
+    21785,
+    21786,
+    21787,
+    21787,
+    21789,
+    21789,
+    21786,
+    21792,
+    21793,
+    21794,
+    21795,
+    21796,
+    21796,
+    21798,
+    21798,
+    21796,
+    21801,
+    21801,
+    21803,
+    21803,
+    21801,
+    21806,
+    21806,
+    21785,
+    21809,
+    21810,
+    21810,
+    21812,
+    21813,
+    21813,
+    21815,
+    21815,
+    21815,
+    21818,
+    21819,
+    21820,
+    21821,
+    21821,
+    21823,
+    21823,
+    21825,
+    21825,
+    21827,
+    21827,
+    21812,
+    21830,
+    21830,
+    21832,
+    21832,
+    21834,
+    21834,
+    21836,
+    21837,
+    21838,
+    21839,
+    21840,
+    21841,
+    21842,
+    21843,
+    21844,
+    21844,
+    21843,
+    21847,
+    21847,
+    21849,
+    21850,
+    21851,
+    21851,
+    21761,
+    21854,
+    21855,
+    21855,
+    21857,
+    21858,
+    21859,
+    21859,
+    21861,
+    21861,
+    21863,
+    21864,
+    21864,
+    21864,
+    21867,
+    21868,
+    21869,
+    21870,
+    21870,
+    21872,
+    21873,
+    21873,
+    21873,
+    21876,
+    21877,
+    21878,
+    21879,
+    21880,
+    21881,
+    21882,
+    21883,
+    21883,
+    21885,
+    21882,
+    21887,
+    21887,
+    21889,
+    21890,
+    21890,
+    21890,
+    21893,
+    21890,
+    21890,
+    21896,
+    21897,
+    21898,
+    21898,
+    21900,
+    21900,
+    21902,
+    21900,
+    21904,
+    21900,
+    21906,
+    21907,
+    21907,
+    21897,
+    21910,
+    21911,
+    21912,
+    21913,
+    21912,
+    21915,
+    21916,
+    21917,
+    21917,
+    21916,
+    21920,
+    21920,
+    21920,
+    21915,
+    21924,
+    21925,
+    21925,
+    21927,
+    21927,
+    21929,
+    21930,
+    21931,
+    21931,
+    21930,
+    21934,
+    21935,
+    21935,
+    21935,
+    21935,
+    21930,
+    21930,
+    21930,
+    21942,
+    21942,
+    21944,
+    21945,
+    21945,
+    21947,
+    21948,
+    21949,
+    21949,
+    21945,
+    21945,
+    21948,
+    21954,
+    21954,
+    21944,
+    21957,
+    21958,
+    21959,
+    21959,
+    21961,
+    21961,
+    21963,
+    21964,
+    21889,
+    21966,
+    21967,
+    21967,
+    21969,
+    21970,
+    21971,
+    21971,
+    21973,
+    21974,
+    21974,
+    21976,
+    21971,
+    21976,
+    21971,
+    21980,
+    21980,
+    21982,
+    21980,
+    21984,
+    21985,
+    21986,
+    21987,
+    21987,
+    21989,
+    21990,
+    21991,
+    21992,
+    21993,
+    21994,
+    21994,
+    21996,
+    21997,
+    21994,
+    21999,
+    22000,
+    21992,
+    22002,
+    22003,
+    22003,
+    22005,
+    22006,
+    22003,
+    22008,
+    22009,
+    22009,
+    22011,
+    22012,
+    22013,
+    22014,
+    22015,
+    22016,
+    22016,
+    22018,
+    22019,
+    22018,
+    22021,
+    22022,
+    22023,
+    22024,
+    22016,
+    22026,
+    22027,
+    22028,
+    22029,
+    22030,
+    22031,
+    22032,
+    22033,
+    22034,
+    22034,
+    22033,
+    22037,
+    22037,
+    22033,
+    22033,
+    22041,
+    22041,
+    22043,
+    22043,
+    22045,
+    22045,
+    22043,
+    22048,
+    22049,
+    22049,
+    22051,
+    22051,
+    22053,
+    22054,
+    22055,
+    22056,
+    22043,
+    22043,
+    22056,
+    22060,
+    22061,
+    22062,
+    22062,
+    22062,
+    22065,
+    22065,
+    22067,
+    22068,
+    22068,
+    22068,
+    22071,
+    22067,
+    22032,
+    22074,
+    22074,
+    22076,
+    22074,
+    22074,
+    22079,
+    22080,
+    22081,
+    22081,
+    22080,
+    22084,
+    22085,
+    22084,
+    22087,
+    22088,
+    22089,
+    22090,
+    22090,
+    22092,
+    22092,
+    22090,
+    22090,
+    22090,
+    22097,
+    22098,
+    22099,
+    22100,
+    22100,
+    22102,
+    22099,
+    22102,
+    22105,
+    22106,
+    22107,
+    22107,
+    22109,
+    22109,
+    22111,
+    22112,
+    22113,
+    22114,
+    22114,
+    22116,
+    22116,
+    22118,
+    22118,
+    22120,
+    22120,
+    22122,
+    22123,
+    22123,
+    22123,
+    22126,
+    22127,
+    22128,
+    22129,
+    22126,
+    22131,
+    22132,
+    22133,
+    22133,
+    22123,
+    22136,
+    22136,
+    22136,
+    22139,
+    22136,
+    22139,
+    22142,
+    22143,
+    22143,
+    22145,
+    22145,
+    22147,
+    22142,
+    22149,
+    22150,
+    22151,
+    22151,
+    22142,
+    22142,
+    22155,
+    22156,
+    22156,
+    22158,
+    22155,
+    22160,
+    22160,
+    22142,
+    22163,
+    22164,
+    22164,
+    22166,
+    22166,
+    22168,
+    22169,
+    22170,
+    22170,
+    22166,
+    22173,
+    22174,
+    22175,
+    22166,
+    22177,
+    22178,
+    22179,
+    22179,
+    22181,
+    22182,
+    22182,
+    22184,
+    22181,
+    22186,
+    22186,
+    22188,
+    22189,
+    22189,
+    22191,
+    22192,
+    22193,
+    22191,
+    22195,
+    22196,
+    22197,
+    22198,
+    22199,
+    22200,
+    22199,
+    22202,
+    22203,
+    22204,
+    22205,
+    22206,
+    22207,
+    22207,
+    22209,
+    22209,
+    22205,
+    22212,
+    22212,
+    22214,
+    22215,
+    22215,
+    22217,
+    22215,
+    22219,
+    22220,
+    22221,
+    22222,
+    22222,
+    22224,
+    22225,
+    22224,
+    22227,
+    22227,
+    22220,
+    22230,
+    22230,
+    22232,
+    22233,
+    22234,
+    22234,
+    22236,
+    22236,
+    22238,
+    22238,
+    22240,
+    22240,
+    22233,
+    22243,
+    22243,
+    22245,
+    22245,
+    22247,
+    22248,
+    22248,
+    22250,
+    22250,
+    22214,
+    22253,
+    22254,
+    22255,
+    22256,
+    22257,
+    22258,
+    22258,
+    22260,
+    22260,
+    22262,
+    22263,
+    22263,
+    22265,
+    22265,
+    22267,
+    22267,
+    22269,
+    22270,
+    22271,
+    22272,
+    22272,
+    22274,
+    22275,
+    22276,
+    22276,
+    22278,
+    22278,
+    22280,
+    22281,
+    22281,
+    22280,
+    22256,
+    22285,
+    22286,
+    22286,
+    22288,
+    22289,
+    22289,
+    22291,
+    22292,
+    22293,
+    22294,
+    22295,
+    22296,
+    22296,
+    22298,
+    22299,
+    22299,
+    22301,
+    22302,
+    22295,
+    22304,
+    22305,
+    22305,
+    22305,
+    22308,
+    22309,
+    22309,
+    22311,
+    22312,
+    22312,
+    22314,
+    22315,
+    22316,
+    22316,
+    22318,
+    22318,
+    22320,
+    22294,
+    22322,
+    22323,
+    22323,
+    22322,
+    22326,
+    22326,
+    22322,
+    22329,
+    22330,
+    22331,
+    22331,
+    22333,
+    22334,
+    22335,
+    22336,
+    22337,
+    22337,
+    22333,
+    22340,
+    22340,
+    22342,
+    22342,
+    22344,
+    22344,
+    22346,
+    22346,
+    22348,
+    22349,
+    22350,
+    22350,
+    22352,
+    22353,
+    22354,
+    22355,
+    22356,
+    22357,
+    22358,
+    22359,
+    22360,
+    22361,
+    22362,
+    22363,
+    22364,
+    22364,
+    22366,
+    22367,
+    22367,
+    22369,
+    22366,
+    22371,
+    22372,
+    22372,
+    22371,
+    22375,
+    22375,
+    22375,
+    22378,
+    22378,
+    22378,
+    22381,
+    22382,
+    22382,
+    22384,
+    22385,
+    22386,
+    22387,
+    22387,
+    22389,
+    22381,
+    22391,
+    22392,
+    22393,
+    22394,
+    22394,
+    22396,
+    22396,
+    22398,
+    22399,
+    22398,
+    22401,
+    22402,
+    22402,
+    22404,
+    22404,
+    22406,
+    22406,
+    22401,
+    22409,
+    22409,
+    22411,
+    22412,
+    22413,
+    22414,
+    22415,
+    22416,
+    22417,
+    22418,
+    22419,
+    22420,
+    22420,
+    22422,
+    22423,
+    22424,
+    22425,
+    22426,
+    22427,
+    22428,
+    22429,
+    22430,
+    22431,
+    22432,
+    22433,
+    22434,
+    22435,
+    22436,
+    22437,
+    22438,
+    22439,
+    22440,
+    22441,
+    22442,
+    22443,
+    22444,
+    22445,
+    22445,
+    22445,
+    22448,
+    22448,
+    22448,
+    22451,
+    22452,
+    22451,
+    22454,
+    22455,
+    22456,
+    22457,
+    22457,
+    22459,
+    22459,
+    22461,
+    22462,
+    22463,
+    22464,
+    22465,
+    22463,
+    22467,
+    22468,
+    22456,
+    22470,
+    22471,
+    22472,
+    22470,
+    22474,
+    22475,
+    22475,
+    22477,
+    22478,
+    22479,
+    22480,
+    22480,
+    22482,
+    22483,
+    22483,
+    22485,
+    22486,
+    22485,
+    22488,
+    22489,
+    22490,
+    22490,
+    22442,
+    22493,
+    22493,
+    22495,
+    22495,
+    22497,
+    22497,
+    22497,
+    22495,
+    22501,
+    22502,
+    22502,
+    22504,
+    22504,
+    22506,
+    22506,
+    22508,
+    22508,
+    22508,
+    22511,
+    22511,
+    22511,
+    22514,
+    22514,
+    22514,
+    22517,
+    22517,
+    22519,
+    22520,
+    22521,
+    22521,
+    22523,
+    22523,
+    22525,
+    22526,
+    22527,
+    22527,
+    22529,
+    22527,
+    22531,
+    22532,
+    22532,
+    22534,
+    22535,
+    22534,
+    22537,
+    22531,
+    22539,
+    22540,
+    22540,
+    22542,
+    22543,
+    22544,
+    22544,
+    22546,
+    22547,
+    22547,
+    22549,
+    22549,
+    22551,
+    22552,
+    22552,
+    22554,
+    22543,
+    22556,
+    22556,
+    22556,
+    22520,
+    22560,
+    22561,
+    22562,
+    22563,
+    22563,
+    22565,
+    22565,
+    22567,
+    22567,
+    22569,
+    22569,
+    22571,
+    22571,
+    22573,
+    22573,
+    22575,
+    22576,
+    22560,
+    22578,
+    22579,
+    22580,
+    22581,
+    22582,
+    22583,
+    22583,
+    22585,
+    22585,
+    22587,
+    22587,
+    22589,
+    22589,
+    22591,
+    22591,
+    22593,
+    22593,
+    22595,
+    22596,
+    22597,
+    22597,
+    22599,
+    22600,
+    22600,
+    22602,
+    22602,
+    22602,
+    22595,
+    22606,
+    22607,
+    22607,
+    22609,
+    22609,
+    22611,
+    22611,
+    22611,
+    22614,
+    22615,
+    22615,
+    22617,
+    22618,
+    22618,
+    22617,
+    22621,
+    22621,
+    22623,
+    22624,
+    22625,
+    22626,
+    22627,
+    22628,
+    22628,
+    22630,
+    22631,
+    22632,
+    22632,
+    22634,
+    22635,
+    22636,
+    22636,
+    22638,
+    22631,
+    22640,
+    22641,
+    22642,
+    22642,
+    22640,
+    22645,
+    22645,
+    22647,
+    22647,
+    22649,
+    22645,
+    22651,
+    22652,
+    22653,
+    22653,
+    22655,
+    22656,
+    22656,
+    22658,
+    22659,
+    22659,
+    22658,
+    22662,
+    22662,
+    22626,
+    22665,
+    22665,
+    22667,
+    22667,
+    22665,
+    22670,
+    22671,
+    22672,
+    22673,
+    22674,
+    22675,
+    22675,
+    22671,
+    22678,
+    22679,
+    22679,
+    22681,
+    22681,
+    22683,
+    22683,
+    22681,
+    22686,
+    22687,
+    22688,
+    22689,
+    22689,
+    22691,
+    22691,
+    22693,
+    22687,
+    22695,
+    22696,
+    22697,
+    22697,
+    22699,
+    22700,
+    22701,
+    22700,
+    22699,
+    22697,
+    22705,
+    22706,
+    22707,
+    22708,
+    22709,
+    22709,
+    22711,
+    22711,
+    22709,
+    22706,
+    22715,
+    22716,
+    22717,
+    22717,
+    22716,
+    22720,
+    22721,
+    22722,
+    22722,
+    22721,
+    22721,
+    22720,
+    22727,
+    22728,
+    22729,
+    22730,
+    22730,
+    22729,
+    22733,
+    22733,
+    22729,
+    22736,
+    22737,
+    22737,
+    22739,
+    22740,
+    22740,
+    22742,
+    22743,
+    22740,
+    22745,
+    22745,
+    22747,
+    22748,
+    22749,
+    22750,
+    22750,
+    22752,
+    22752,
+    22754,
+    22754,
+    22754,
+    22757,
+    22758,
+    22759,
+    22758,
+    22761,
+    22762,
+    22749,
+    22764,
+    22765,
+    22764,
+    22767,
+    22767,
+    22769,
+    22770,
+    22770,
+    22772,
+    22773,
+    22773,
+    22775,
+    22776,
+    22777,
+    22778,
+    22779,
+    22779,
+    22781,
+    22782,
+    22783,
+    22784,
+    22785,
+    22786,
+    22787,
+    22787,
+    22789,
+    22790,
+    22791,
+    22791,
+    22793,
+    22794,
+    22794,
+    22796,
+    22797,
+    22798,
+    22799,
+    22800,
+    22801,
+    22802,
+    22803,
+    22804,
+    22805,
+    22806,
+    22807,
+    22808,
+    22809,
+    22810,
+    22811,
+    22812,
+    22813,
+    22814,
+    22815,
+    22816,
+    22817,
+    22818,
+    22819,
+    22820,
+    22821,
+    22822,
+    22822,
+    22824,
+    22825,
+    22826,
+    22827,
+    22828,
+    22829,
+    22830,
+    22831,
+    22832,
+    22833,
+    22834,
+    22835,
+    22836,
+    22837,
+    22838,
+    22839,
+    22840,
+    22840,
+    22842,
+    22843,
+    22844,
+    22845,
+    22846,
+    22847,
+    22848,
+    22848,
+    22850,
+    22851,
+    22852,
+    22852,
+    22854,
+    22854,
+    22856,
+    22856,
+    22858,
+    22859,
+    22859,
+    22861,
+    22862,
+    22862,
+    22864,
+    22864,
+    22866,
+    22867,
+    22868,
+    22868,
+    22870,
+    22871,
+    22871,
+    22873,
+    22874,
+    22875,
+    22876,
+    22876,
+    22878,
+    22878,
+    22880,
+    22881,
+    22882,
+    22883,
+    22884,
+    22885,
+    22886,
+    22887,
+    22880,
+    22889,
+    22890,
+    22891,
+    22892,
+    22893,
+    22894,
+    22895,
+    22896,
+    22896,
+    22898,
+    22898,
+    22900,
+    22901,
+    22901,
+    22903,
+    22904,
+    22904,
+    22906,
+    22906,
+    22908,
+    22909,
+    22910,
+    22910,
+    22912,
+    22913,
+    22914,
+    22914,
+    22913,
+    22917,
+    22917,
+    22919,
+    22919,
+    22921,
+    22922,
+    22922,
+    22922,
+    22925,
+    22926,
+    22927,
+    22927,
+    22929,
+    22930,
+    22931,
+    22931,
+    22929,
+    22934,
+    22935,
+    22936,
+    22936,
+    22929,
+    22939,
+    22940,
+    22940,
+    22942,
+    22942,
+    22944,
+    22944,
+    22946,
+    22947,
+    22948,
+    22948,
+    22950,
+    22950,
+    22952,
+    22952,
+    22954,
+    22955,
+    22956,
+    22956,
+    22958,
+    22958,
+    22960,
+    22956,
+    22954,
+    22963,
+    22963,
+    22963,
+    22966,
+    22963,
+    22968,
+    22969,
+    22969,
+    22971,
+    22971,
+    22973,
+    22974,
+    22974,
+    22976,
+    22976,
+    22968,
+    22979,
+    22980,
+    22981,
+    22982,
+    22983,
+    22983,
+    22985,
+    22986,
+    22986,
+    22985,
+    22982,
+    22990,
+    22991,
+    22990,
+    22993,
+    22993,
+    22995,
+    22990,
+    22997,
+    22990,
+    22999,
+    22999,
+    23001,
+    23001,
+    22981,
+    23004,
+    23005,
+    23006,
+    23007,
+    23005,
+    23009,
+    23010,
+    23010,
+    23010,
+    23013,
+    23010,
+    23015,
+    23016,
+    23017,
+    23018,
+    23019,
+    23020,
+    23021,
+    23018,
+    23009,
+    23024,
+    23025,
+    23026,
+    23027,
+    23028,
+    23029,
+    23030,
+    23031,
+    23032,
+    23033,
+    23004,
+    23035,
+    23036,
+    23037,
+    23035,
+    23039,
+    23039,
+    23039,
+    23042,
+    23042,
+    23044,
+    23045,
+    23046,
+    23046,
+    23048,
+    23049,
+    23049,
+    23048,
+    23052,
+    23052,
+    23052,
+    23055,
+    23055,
+    23057,
+    23057,
+    23059,
+    23059,
+    23061,
+    23061,
+    23063,
+    23063,
+    23065,
+    23066,
+    23067,
+    23068,
+    23068,
+    23070,
+    23070,
+    23068,
+    23073,
+    23074,
+    23074,
+    23076,
+    23077,
+    23078,
+    23079,
+    23080,
+    23081,
+    23082,
+    23082,
+    23084,
+    23084,
+    23086,
+    23086,
+    23088,
+    23089,
+    23090,
+    23091,
+    23092,
+    23091,
+    23090,
+    23095,
+    23096,
+    23096,
+    23096,
+    23099,
+    23100,
+    23095,
+    23095,
+    23095,
+    23104,
+    23104,
+    23106,
+    23107,
+    23107,
+    23109,
+    23110,
+    23110,
+    23112,
+    23113,
+    23089,
+    23115,
+    23115,
+    23117,
+    23118,
+    23118,
+    23115,
+    23115,
+    23112,
+    23123,
+    23123,
+    23125,
+    23112,
+    23127,
+    23128,
+    23129,
+    23129,
+    23131,
+    23131,
+    23133,
+    23131,
+    23135,
+    23136,
+    23137,
+    23138,
+    23136,
+    23137,
+    23128,
+    23142,
+    23143,
+    23144,
+    23144,
+    23146,
+    23146,
+    23148,
+    23149,
+    23150,
+    23151,
+    23152,
+    23152,
+    23151,
+    23155,
+    23156,
+    23156,
+    23158,
+    23158,
+    23160,
+    23161,
+    23162,
+    23163,
+    23163,
+    23165,
+    23166,
+    23166,
+    23168,
+    23168,
+    23170,
+    23170,
+    23172,
+    23173,
+    23174,
+    23175,
+    23176,
+    23175,
+    23178,
+    23179,
+    23180,
+    23173,
+    23127,
+    23183,
+    23184,
+    23185,
+    23186,
+    23186,
+    23186,
+    23189,
+    23189,
+    23191,
+    23192,
+    23192,
+    23191,
+    23195,
+    23196,
+    23196,
+    23198,
+    23198,
+    23166,
+    23201,
+    23202,
+    23203,
+    23203,
+    23203,
+    23206,
+    23206,
+    23208,
+    23203,
+    23210,
+    23211,
+    23211,
+    23213,
+    23214,
+    23215,
+    23203,
+    23217,
+    23218,
+    23219,
+    23201,
+    23221,
+    23222,
+    23223,
+    23224,
+    23225,
+    23226,
+    23227,
+    23227,
+    23229,
+    23230,
+    23230,
+    23232,
+    23232,
+    23234,
+    23235,
+    23236,
+    23236,
+    23236,
+    23229,
+    23225,
+    23241,
+    23242,
+    23242,
+    23244,
+    23245,
+    23246,
+    23247,
+    23248,
+    23249,
+    23250,
+    23250,
+    23252,
+    23253,
+    23252,
+    23255,
+    23255,
+    23257,
+    23258,
+    23259,
+    23259,
+    23257,
+    23262,
+    23263,
+    23263,
+    23262,
+    23266,
+    23267,
+    23267,
+    23269,
+    23269,
+    23271,
+    23272,
+    23273,
+    23274,
+    23275,
+    23276,
+    23276,
+    23278,
+    23275,
+    23280,
+    23281,
+    23280,
+    23283,
+    23283,
+    23285,
+    23286,
+    23287,
+    23287,
+    23280,
+    23280,
+    23286,
+    23280,
+    23224,
+    23294,
+    23295,
+    23295,
+    23297,
+    23298,
+    23298,
+    23300,
+    23300,
+    23300,
+    23303,
+    23304,
+    23304,
+    23297,
+    23307,
+    23308,
+    23307,
+    23310,
+    23311,
+    23312,
+    23313,
+    23314,
+    23315,
+    23316,
+    23316,
+    23318,
+    23319,
+    23318,
+    23321,
+    23318,
+    23323,
+    23324,
+    23325,
+    23326,
+    23327,
+    23328,
+    23329,
+    23329,
+    23331,
+    23331,
+    23329,
+    23334,
+    23335,
+    23336,
+    23336,
+    23336,
+    23339,
+    23340,
+    23336,
+    23342,
+    23343,
+    23344,
+    23335,
+    23346,
+    23347,
+    23348,
+    23348,
+    23350,
+    23350,
+    23347,
+    23353,
+    23353,
+    23353,
+    23356,
+    23356,
+    23358,
+    23359,
+    23328,
+    23361,
+    23361,
+    23363,
+    23364,
+    23365,
+    23365,
+    23365,
+    23365,
+    23369,
+    23369,
+    23371,
+    23371,
+    23373,
+    23373,
+    23375,
+    23376,
+    23376,
+    23376,
+    23379,
+    23380,
+    23375,
+    23375,
+    23375,
+    23384,
+    23385,
+    23386,
+    23386,
+    23388,
+    23388,
+    23390,
+    23390,
+    23388,
+    23388,
+    23394,
+    23395,
+    23395,
+    23397,
+    23398,
+    23397,
+    23400,
+    23400,
+    23397,
+    23403,
+    23403,
+    23405,
+    23405,
+    23405,
+    23363,
+    23409,
+    23410,
+    23409,
+    23412,
+    23413,
+    23414,
+    23415,
+    23416,
+    23417,
+    23418,
+    23419,
+    23409,
+    23421,
+    23422,
+    23422,
+    23424,
+    23425,
+    23426,
+    23426,
+    23428,
+    23428,
+    23430,
+    23431,
+    23426,
+    23433,
+    23426,
+    23435,
+    23436,
+    23436,
+    23438,
+    23439,
+    23439,
+    23441,
+    23442,
+    23441,
+    23444,
+    23444,
+    23441,
+    23447,
+    23448,
+    23448,
+    23447,
+    23441,
+    23452,
+    23453,
+    23454,
+    23454,
+    23453,
+    23457,
+    23457,
+    23453,
+    23453,
+    23461,
+    23453,
+    23463,
+    23463,
+    23465,
+    23465,
+    23467,
+    23468,
+    23468,
+    23470,
+    23471,
+    23470,
+    23467,
+    23474,
+    23475,
+    23475,
+    23477,
+    23478,
+    23479,
+    23479,
+    23481,
+    23482,
+    23483,
+    23484,
+    23485,
+    23485,
+    23487,
+    23487,
+    23489,
+    23489,
+    23491,
+    23491,
+    23493,
+    23494,
+    23495,
+    23489,
+    23497,
+    23498,
+    23497,
+    23500,
+    23489,
+    23502,
+    23503,
+    23504,
+    23503,
+    23506,
+    23506,
+    23508,
+    23509,
+    23510,
+    23511,
+    23512,
+    23513,
+    23514,
+    23515,
+    23513,
+    23517,
+    23518,
+    23518,
+    23520,
+    23520,
+    23522,
+    23522,
+    23524,
+    23525,
+    23525,
+    23527,
+    23527,
+    23529,
+    23529,
+    23531,
+    23531,
+    23533,
+    23533,
+    23535,
+    23536,
+    23536,
+    23538,
+    23538,
+    23540,
+    23540,
+    23542,
+    23542,
+    23544,
+    23544,
+    23517,
+    23547,
+    23548,
+    23549,
+    23550,
+    23551,
+    23551,
+    23553,
+    23554,
+    23554,
+    23553,
+    23557,
+    23558,
+    23558,
+    23560,
+    23560,
+    23562,
+    23562,
+    23564,
+    23564,
+    23566,
+    23566,
+    23568,
+    23569,
+    23570,
+    23570,
+    23572,
+    23572,
+    23574,
+    23574,
+    23574,
+    23574,
+    23578,
+    23578,
+    23578,
+    23578,
+    23582,
+    23582,
+    23582,
+    23585,
+    23585,
+    23587,
+    23587,
+    23589,
+    23590,
+    23590,
+    23582,
+    23593,
+    23594,
+    23595,
+    23596,
+    23597,
+    23598,
+    23599,
+    23600,
+    23601,
+    23602,
+    23603,
+    23603,
+    23605,
+    23606,
+    23607,
+    23607,
+    23609,
+    23610,
+    23611,
+    23612,
+    23613,
+    23614,
+    23615,
+    23616,
+    23617,
+    23617,
+    23616,
+    23620,
+    23620,
+    23616,
+    23623,
+    23624,
+    23625,
+    23626,
+    23625,
+    23628,
+    23629,
+    23630,
+    23631,
+    23632,
+    23632,
+    23632,
+    23629,
+    23636,
+    23637,
+    23636,
+    23615,
+    23640,
+    23641,
+    23642,
+    23643,
+    23643,
+    23645,
+    23645,
+    23641,
+    23648,
+    23649,
+    23648,
+    23651,
+    23652,
+    23652,
+    23654,
+    23654,
+    23656,
+    23656,
+    23658,
+    23656,
+    23651,
+    23661,
+    23661,
+    23663,
+    23663,
+    23661,
+    23666,
+    23667,
+    23667,
+    23667,
+    23670,
+    23670,
+    23672,
+    23673,
+    23673,
+    23675,
+    23675,
+    23661,
+    23678,
+    23679,
+    23680,
+    23681,
+    23682,
+    23678,
+    23684,
+    23685,
+    23685,
+    23687,
+    23687,
+    23689,
+    23690,
+    23691,
+    23692,
+    23693,
+    23694,
+    23695,
+    23696,
+    23697,
+    23698,
+    23699,
+    23700,
+    23701,
+    23702,
+    23702,
+    23704,
+    23702,
+    23706,
+    23707,
+    23708,
+    23708,
+    23710,
+    23711,
+    23712,
+    23713,
+    23712,
+    23715,
+    23716,
+    23717,
+    23712,
+    23719,
+    23720,
+    23721,
+    23722,
+    23723,
+    23724,
+    23724,
+    23708,
+    23727,
+    23728,
+    23729,
+    23730,
+    23731,
+    23732,
+    23733,
+    23734,
+    23735,
+    23736,
+    23737,
+    23738,
+    23739,
+    23740,
+    23741,
+    23742,
+    23742,
+    23744,
+    23745,
+    23746,
+    23747,
+    23747,
+    23749,
+    23750,
+    23751,
+    23752,
+    23746,
+    23754,
+    23755,
+    23755,
+    23757,
+    23758,
+    23759,
+    23746,
+    23761,
+    23762,
+    23763,
+    23764,
+    23765,
+    23766,
+    23765,
+    23768,
+    23769,
+    23769,
+    23771,
+    23771,
+    23773,
+    23773,
+    23775,
+    23776,
+    23777,
+    23773,
+    23779,
+    23779,
+    23781,
+    23782,
+    23782,
+    23782,
+    23773,
+    23786,
+    23781,
+    23788,
+    23788,
+    23762,
+    23791,
+    23791,
+    23791,
+    23791,
+    23795,
+    23795,
+    23797,
+    23798,
+    23798,
+    23800,
+    23800,
+    23802,
+    23803,
+    23804,
+    23805,
+    23806,
+    23807,
+    23808,
+    23809,
+    23810,
+    23810,
+    23812,
+    23813,
+    23812,
+    23815,
+    23806,
+    23817,
+    23817,
+    23819,
+    23820,
+    23821,
+    23822,
+    23822,
+    23824,
+    23824,
+    23826,
+    23827,
+    23827,
+    23826,
+    23830,
+    23831,
+    23832,
+    23832,
+    23834,
+    23835,
+    23836,
+    23837,
+    23838,
+    23838,
+    23840,
+    23840,
+    23842,
+    23843,
+    23844,
+    23845,
+    23846,
+    23846,
+    23848,
+    23848,
+    23850,
+    23850,
+    23852,
+    23852,
+    23854,
+    23854,
+    23856,
+    23857,
+    23858,
+    23859,
+    23860,
+    23861,
+    23862,
+    23863,
+    23864,
+    23865,
+    23866,
+    23867,
+    23868,
+    23861,
+    23870,
+    23871,
+    23870,
+    23873,
+    23874,
+    23875,
+    23876,
+    23876,
+    23878,
+    23878,
+    23880,
+    23880,
+    23875,
+    23883,
+    23884,
+    23884,
+    23886,
+    23886,
+    23888,
+    23888,
+    23890,
+    23888,
+    23892,
+    23892,
+    23894,
+    23895,
+    23895,
+    23897,
+    23898,
+    23899,
+    23900,
+    23901,
+    23900,
+    23903,
+    23904,
+    23905,
+    23906,
+    23904,
+    23908,
+    23908,
+    23910,
+    23911,
+    23910,
+    23913,
+    23913,
+    23910,
+    23916,
+    23917,
+    23917,
+    23919,
+    23920,
+    23921,
+    23919,
+    23923,
+    23924,
+    23925,
+    23926,
+    23927,
+    23928,
+    23928,
+    23930,
+    23931,
+    23931,
+    23933,
+    23934,
+    23934,
+    23931,
+    23930,
+    23938,
+    23939,
+    23940,
+    23941,
+    23942,
+    23943,
+    23942,
+    23945,
+    23945,
+    23947,
+    23941,
+    23949,
+    23949,
+    23951,
+    23952,
+    23953,
+    23954,
+    23953,
+    23956,
+    23957,
+    23957,
+    23959,
+    23960,
+    23960,
+    23960,
+    23949,
+    23964,
+    23964,
+    23966,
+    23966,
+    23968,
+    23968,
+    23970,
+    23971,
+    23971,
+    23970,
+    23974,
+    23975,
+    23976,
+    23976,
+    23978,
+    23976,
+    23980,
+    23981,
+    23982,
+    23983,
+    23984,
+    23985,
+    23986,
+    23987,
+    23988,
+    23989,
+    23990,
+    23989,
+    23992,
+    23993,
+    23994,
+    23993,
+    23996,
+    23996,
+    23989,
+    23999,
+    23999,
+    23999,
+    24002,
+    24003,
+    24004,
+    24005,
+    24006,
+    24007,
+    24004,
+    24006,
+    24010,
+    24010,
+    24012,
+    24013,
+    24014,
+    24014,
+    24016,
+    24016,
+    24013,
+    24019,
+    24020,
+    24020,
+    24022,
+    24012,
+    24022,
+    24025,
+    24026,
+    24026,
+    24028,
+    24028,
+    24030,
+    24031,
+    24032,
+    24030,
+    24034,
+    24034,
+    24036,
+    24036,
+    24038,
+    24039,
+    24040,
+    24041,
+    24038,
+    24043,
+    24044,
+    24045,
+    24043,
+    24047,
+    24047,
+    24049,
+    24050,
+    24049,
+    24052,
+    24053,
+    24054,
+    24054,
+    24056,
+    24057,
+    24058,
+    24057,
+    24060,
+    24061,
+    24061,
+    24063,
+    24064,
+    24065,
+    24063,
+    24067,
+    24068,
+    24068,
+    24070,
+    24071,
+    24072,
+    24072,
+    24074,
+    24074,
+    24067,
+    24077,
+    24078,
+    24078,
+    24080,
+    24081,
+    24082,
+    24081,
+    24084,
+    24084,
+    24080,
+    24087,
+    24088,
+    24088,
+    24090,
+    24090,
+    24092,
+    24092,
+    24094,
+    24094,
+    24096,
+    24096,
+    24098,
+    24099,
+    24099,
+    24101,
+    24102,
+    24103,
+    24104,
+    24105,
+    24106,
+    24106,
+    24108,
+    24109,
+    24110,
+    24110,
+    24112,
+    24113,
+    24105,
+    24115,
+    24116,
+    24117,
+    24116,
+    24119,
+    24120,
+    24121,
+    24120,
+    24123,
+    24124,
+    24124,
+    24123,
+    24127,
+    24128,
+    24129,
+    24130,
+    24131,
+    24132,
+    24133,
+    24134,
+    24135,
+    24136,
+    24137,
+    24138,
+    24139,
+    24139,
+    24141,
+    24141,
+    24138,
+    24144,
+    24145,
+    24145,
+    24147,
+    24147,
+    24149,
+    24150,
+    24151,
+    24149,
+    24153,
+    24154,
+    24155,
+    24155,
+    24157,
+    24158,
+    24158,
+    24160,
+    24161,
+    24161,
+    24163,
+    24164,
+    24165,
+    24165,
+    24167,
+    24168,
+    24169,
+    24168,
+    24171,
+    24172,
+    24173,
+    24174,
+    24175,
+    24176,
+    24176,
+    24178,
+    24179,
+    24175,
+    24181,
+    24182,
+    24183,
+    24184,
+    24185,
+    24185,
+    24187,
+    24188,
+    24187,
+    24190,
+    24191,
+    24192,
+    24193,
+    24193,
+    24192,
+    24196,
+    24196,
+    24198,
+    24199,
+    24200,
+    24201,
+    24202,
+    24203,
+    24203,
+    24203,
+    24206,
+    24207,
+    24208,
+    24208,
+    24210,
+    24211,
+    24210,
+    24213,
+    24213,
+    24215,
+    24215,
+    24215,
+    24208,
+    24219,
+    24219,
+    24221,
+    24222,
+    24223,
+    24223,
+    24225,
+    24225,
+    24227,
+    24227,
+    24229,
+    24221,
+    24231,
+    24232,
+    24233,
+    24234,
+    24234,
+    24232,
+    24237,
+    24237,
+    24239,
+    24240,
+    24241,
+    24241,
+    24243,
+    24244,
+    24245,
+    24241,
+    24247,
+    24248,
+    24248,
+    24250,
+    24250,
+    24252,
+    24252,
+    24254,
+    24255,
+    24255,
+    24247,
+    24258,
+    24259,
+    24260,
+    24261,
+    24262,
+    24262,
+    24264,
+    24265,
+    24266,
+    24266,
+    24268,
+    24268,
+    24265,
+    24271,
+    24272,
+    24272,
+    24274,
+    24275,
+    24276,
+    24277,
+    24272,
+    24279,
+    24279,
+    24281,
+    24282,
+    24282,
+    24284,
+    24284,
+    24271,
+    24287,
+    24287,
+    24289,
+    24290,
+    24290,
+    24289,
+    24293,
+    24293,
+    24295,
+    24295,
+    24297,
+    24298,
+    24299,
+    24300,
+    24301,
+    24301,
+    24303,
+    24300,
+    24305,
+    24305,
+    24307,
+    24307,
+    24309,
+    24310,
+    24311,
+    24312,
+    24313,
+    24299,
+    24315,
+    24316,
+    24317,
+    24318,
+    24318,
+    24320,
+    24318,
+    24322,
+    24323,
+    24323,
+    24325,
+    24325,
+    24327,
+    24328,
+    24328,
+    24330,
+    24331,
+    24330,
+    24333,
+    24334,
+    24327,
+    24336,
+    24337,
+    24337,
+    24337,
+    24340,
+    24340,
+    24342,
+    24342,
+    24344,
+    24345,
+    24346,
+    24347,
+    24347,
+    24349,
+    24350,
+    24344,
+    24352,
+    24353,
+    24353,
+    24355,
+    24356,
+    24356,
+    24358,
+    24359,
+    24360,
+    24360,
+    24362,
+    24363,
+    24363,
+    24365,
+    24366,
+    24366,
+    24368,
+    24368,
+    24370,
+    24371,
+    24371,
+    24373,
+    24374,
+    24375,
+    24376,
+    24375,
+    24378,
+    24379,
+    24378,
+    24379,
+    24374,
+    24383,
+    24384,
+    24384,
+    24386,
+    24386,
+    24388,
+    24373,
+    24390,
+    24390,
+    24390,
+    24393,
+    24394,
+    24395,
+    24396,
+    24397,
+    24398,
+    24398,
+    24397,
+    24401,
+    24401,
+    24403,
+    24403,
+    24405,
+    24406,
+    24407,
+    24408,
+    24408,
+    24410,
+    24410,
+    24412,
+    24413,
+    24413,
+    24413,
+    24407,
+    24417,
+    24405,
+    24405,
+    24420,
+    24420,
+    24422,
+    24423,
+    24424,
+    24423,
+    24426,
+    24427,
+    24428,
+    24429,
+    24430,
+    24430,
+    24432,
+    24433,
+    24434,
+    24435,
+    24436,
+    24437,
+    24438,
+    24439,
+    24440,
+    24441,
+    24441,
+    24443,
+    24444,
+    24445,
+    24446,
+    24446,
+    24448,
+    24448,
+    24448,
+    24445,
+    24452,
+    24452,
+    24454,
+    24454,
+    24452,
+    24457,
+    24458,
+    24459,
+    24460,
+    24460,
+    24459,
+    24463,
+    24464,
+    24464,
+    24466,
+    24466,
+    24459,
+    24469,
+    24470,
+    24471,
+    24470,
+    24473,
+    24473,
+    24470,
+    24476,
+    24477,
+    24478,
+    24478,
+    24480,
+    24481,
+    24480,
+    24483,
+    24484,
+    24485,
+    24483,
+    24487,
+    24487,
+    24489,
+    24489,
+    24491,
+    24492,
+    24493,
+    24494,
+    24495,
+    24496,
+    24497,
+    24494,
+    24499,
+    24499,
+    24499,
+    24502,
+    24503,
+    24502,
+    24505,
+    24506,
+    24507,
+    24507,
+    24509,
+    24510,
+    24511,
+    24512,
+    24512,
+    24511,
+    24515,
+    24516,
+    24516,
+    24511,
+    24519,
+    24519,
+    24521,
+    24522,
+    24523,
+    24522,
+    24525,
+    24526,
+    24526,
+    24528,
+    24529,
+    24525,
+    24525,
+    24532,
+    24533,
+    24534,
+    24534,
+    24536,
+    24537,
+    24533,
+    24539,
+    24540,
+    24541,
+    24541,
+    24543,
+    24544,
+    24544,
+    24546,
+    24547,
+    24547,
+    24549,
+    24549,
+    24551,
+    24552,
+    24553,
+    24551,
+    24555,
+    24556,
+    24557,
+    24557,
+    24556,
+    24560,
+    24560,
+    24562,
+    24563,
+    24564,
+    24565,
+    24565,
+    24567,
+    24568,
+    24569,
+    24567,
+    24571,
+    24571,
+    24571,
+    24574,
+    24575,
+    24575,
+    24574,
+    24578,
+    24578,
+    24580,
+    24581,
+    24582,
+    24583,
+    24574,
+    24585,
+    24585,
+    24587,
+    24588,
+    24589,
+    24589,
+    24591,
+    24592,
+    24591,
+    24594,
+    24595,
+    24595,
+    24597,
+    24598,
+    24599,
+    24598,
+    24601,
+    24601,
+    24601,
+    24597,
+    24605,
+    24605,
+    24607,
+    24608,
+    24609,
+    24610,
+    24611,
+    24611,
+    24613,
+    24614,
+    24615,
+    24616,
+    24616,
+    24618,
+    24618,
+    24620,
+    24620,
+    24610,
+    24623,
+    24624,
+    24625,
+    24626,
+    24627,
+    24628,
+    24629,
+    24628,
+    24631,
+    24631,
+    24633,
+    24633,
+    24635,
+    24636,
+    24637,
+    24638,
+    24639,
+    24639,
+    24635,
+    24642,
+    24643,
+    24642,
+    24645,
+    24645,
+    24635,
+    24648,
+    24649,
+    24650,
+    24651,
+    24652,
+    24650,
+    24654,
+    24654,
+    24656,
+    24657,
+    24658,
+    24659,
+    24654,
+    24661,
+    24661,
+    24663,
+    24664,
+    24664,
+    24666,
+    24664,
+    24668,
+    24664,
+    24670,
+    24671,
+    24672,
+    24673,
+    24674,
+    24675,
+    24675,
+    24677,
+    24677,
+    24679,
+    24680,
+    24673,
+    24682,
+    24683,
+    24684,
+    24685,
+    24684,
+    24687,
+    24687,
+    24689,
+    24690,
+    24691,
+    24692,
+    24693,
+    24694,
+    24693,
+    24696,
+    24697,
+    24698,
+    24699,
+    24699,
+    24701,
+    24702,
+    24703,
+    24692,
+    24705,
+    24706,
+    24706,
+    24706,
+    24709,
+    24709,
+    24711,
+    24712,
+    24712,
+    24711,
+    24715,
+    24716,
+    24715,
+    24718,
+    24719,
+    24720,
+    24719,
+    24719,
+    24723,
+    24723,
+    24725,
+    24725,
+    24725,
+    24728,
+    24729,
+    24730,
+    24730,
+    24732,
+    24732,
+    24732,
+    24735,
+    24735,
+    24737,
+    24737,
+    24735,
+    24740,
+    24740,
+    24740,
+    24743,
+    24744,
+    24745,
+    24746,
+    24746,
+    24748,
+    24746,
+    24750,
+    24751,
+    24752,
+    24753,
+    24754,
+    24755,
+    24756,
+    24757,
+    24757,
+    24759,
+    24759,
+    24757,
+    24762,
+    24763,
+    24763,
+    24763,
+    24766,
+    24767,
+    24767,
+    24769,
+    24770,
+    24771,
+    24772,
+    24772,
+    24772,
+    24775,
+    24775,
+    24775,
+    24778,
+    24778,
+    24780,
+    24780,
+    24782,
+    24783,
+    24784,
+    24784,
+    24784,
+    24787,
+    24783,
+    24789,
+    24790,
+    24790,
+    24789,
+    24789,
+    24789,
+    24795,
+    24796,
+    24796,
+    24798,
+    24798,
+    24800,
+    24801,
+    24801,
+    24803,
+    24804,
+    24805,
+    24805,
+    24807,
+    24807,
+    24804,
+    24800,
+    24811,
+    24811,
+    24813,
+    24813,
+    24813,
+    24816,
+    24813,
+    24818,
+    24819,
+    24819,
+    24821,
+    24822,
+    24821,
+    24824,
+    24825,
+    24826,
+    24827,
+    24827,
+    24829,
+    24830,
+    24831,
+    24829,
+    24826,
+    24834,
+    24835,
+    24834,
+    24837,
+    24837,
+    24839,
+    24840,
+    24841,
+    24842,
+    24842,
+    24844,
+    24845,
+    24846,
+    24846,
+    24845,
+    24849,
+    24850,
+    24851,
+    24852,
+    24853,
+    24854,
+    24855,
+    24855,
+    24857,
+    24858,
+    24859,
+    24860,
+    24860,
+    24862,
+    24863,
+    24864,
+    24862,
+    24866,
+    24867,
+    24867,
+    24869,
+    24869,
+    24871,
+    24871,
+    24869,
+    24874,
+    24875,
+    24876,
+    24877,
+    24878,
+    24875,
+    24880,
+    24881,
+    24882,
+    24883,
+    24884,
+    24884,
+    24886,
+    24887,
+    24888,
+    24888,
+    24890,
+    24887,
+    24892,
+    24893,
+    24893,
+    24895,
+    24896,
+    24896,
+    24898,
+    24898,
+    24900,
+    24900,
+    24895,
+    24903,
+    24903,
+    24905,
+    24906,
+    24907,
+    24908,
+    24895,
+    24910,
+    24910,
+    24912,
+    24913,
+    24913,
+    24913,
+    24916,
+    24916,
+    24918,
+    24919,
+    24920,
+    24920,
+    24919,
+    24923,
+    24923,
+    24923,
+    24923,
+    24927,
+    24928,
+    24928,
+    24927,
+    24931,
+    24931,
+    24933,
+    24933,
+    24935,
+    24936,
+    24937,
+    24938,
+    24939,
+    24940,
+    24941,
+    24942,
+    24935,
+    24944,
+    24945,
+    24946,
+    24947,
+    24941,
+    24949,
+    24950,
+    24951,
+    24951,
+    24953,
+    24954,
+    24954,
+    24956,
+    24949,
+    24958,
+    24959,
+    24959,
+    24961,
+    24962,
+    24962,
+    24964,
+    24965,
+    24965,
+    24967,
+    24968,
+    24969,
+    24970,
+    24969,
+    24972,
+    24972,
+    24974,
+    24974,
+    24976,
+    24976,
+    24978,
+    24978,
+    24980,
+    24980,
+    24982,
+    24982,
+    24984,
+    24984,
+    24986,
+    24986,
+    24988,
+    24988,
+    24990,
+    24991,
+    24992,
+    24992,
+    24994,
+    24994,
+    24996,
+    24996,
+    24998,
+    24999,
+    24999,
+    25001,
+    25002,
+    25003,
+    25004,
+    25005,
+    25006,
+    25006,
+    25008,
+    25009,
+    25010,
+    25011,
+    25010,
+    25013,
+    25014,
+    25015,
+    25016,
+    25016,
+    25018,
+    25014,
+    25020,
+    25021,
+    25022,
+    25023,
+    25023,
+    25025,
+    25026,
+    25027,
+    25028,
+    25029,
+    25030,
+    25031,
+    25032,
+    25033,
+    25034,
+    25035,
+    25036,
+    25037,
+    25037,
+    25039,
+    25040,
+    25041,
+    25041,
+    25043,
+    25044,
+    25045,
+    25046,
+    25046,
+    25048,
+    25049,
+    25050,
+    25050,
+    25048,
+    25053,
+    25053,
+    25055,
+    25056,
+    25053,
+    25058,
+    25053,
+    25043,
+    25061,
+    25062,
+    25063,
+    25064,
+    25065,
+    25063,
+    25067,
+    25068,
+    25069,
+    25069,
+    25071,
+    25072,
+    25071,
+    25067,
+    25075,
+    25075,
+    25077,
+    25078,
+    25061,
+    25080,
+    25080,
+    25082,
+    25082,
+    25082,
+    25085,
+    25085,
+    25085,
+    25088,
+    25089,
+    25089,
+    25091,
+    25092,
+    25093,
+    25094,
+    25094,
+    25096,
+    25097,
+    25096,
+    25099,
+    25100,
+    25101,
+    25101,
+    25094,
+    25094,
+    25105,
+    25106,
+    25107,
+    25107,
+    25109,
+    25110,
+    25111,
+    25112,
+    25112,
+    25114,
+    25115,
+    25115,
+    25114,
+    25118,
+    25119,
+    25120,
+    25121,
+    25122,
+    25119,
+    25124,
+    25125,
+    25125,
+    25127,
+    25128,
+    25129,
+    25124,
+    25131,
+    25132,
+    25133,
+    25133,
+    25133,
+    25133,
+    25137,
+    25137,
+    25139,
+    25140,
+    25137,
+    25142,
+    25143,
+    25143,
+    25142,
+    25142,
+    25147,
+    25137,
+    25149,
+    25150,
+    25151,
+    25151,
+    25153,
+    25153,
+    25155,
+    25155,
+    25157,
+    25157,
+    25159,
+    25160,
+    25160,
+    25150,
+    25163,
+    25164,
+    25164,
+    25166,
+    25166,
+    25168,
+    25168,
+    25170,
+    25170,
+    25172,
+    25163,
+    25174,
+    25175,
+    25175,
+    25174,
+    25178,
+    25179,
+    25180,
+    25181,
+    25181,
+    25180,
+    25184,
+    25185,
+    25186,
+    25187,
+    25187,
+    25189,
+    25189,
+    25191,
+    25191,
+    25193,
+    25194,
+    25195,
+    25195,
+    25195,
+    25185,
+    25199,
+    25199,
+    25201,
+    25201,
+    25203,
+    25204,
+    25205,
+    25205,
+    25207,
+    25208,
+    25209,
+    25210,
+    25211,
+    25184,
+    25213,
+    25211,
+    25215,
+    25215,
+    25217,
+    25218,
+    25218,
+    25217,
+    25221,
+    25221,
+    25223,
+    25223,
+    25225,
+    25226,
+    25226,
+    25228,
+    25229,
+    25230,
+    25231,
+    25232,
+    25232,
+    25232,
+    25235,
+    25236,
+    25237,
+    25238,
+    25238,
+    25240,
+    25241,
+    25241,
+    25240,
+    25244,
+    25245,
+    25246,
+    25246,
+    25245,
+    25249,
+    25250,
+    25250,
+    25252,
+    25253,
+    25254,
+    25255,
+    25256,
+    25257,
+    25258,
+    25258,
+    25258,
+    25261,
+    25262,
+    25263,
+    25264,
+    25265,
+    25266,
+    25267,
+    25268,
+    25269,
+    25270,
+    25271,
+    25272,
+    25273,
+    25274,
+    25275,
+    25276,
+    25277,
+    25278,
+    25279,
+    25280,
+    25280,
+    25282,
+    25283,
+    25284,
+    25285,
+    25285,
+    25287,
+    25288,
+    25288,
+    25290,
+    25291,
+    25292,
+    25293,
+    25293,
+    25293,
+    25293,
+    25297,
+    25297,
+    25299,
+    25299,
+    25299,
+    25302,
+    25302,
+    25304,
+    25304,
+    25306,
+    25307,
+    25308,
+    25308,
+    25310,
+    25310,
+    25312,
+    25312,
+    25314,
+    25315,
+    25315,
+    25317,
+    25318,
+    25318,
+    25317,
+    25321,
+    25322,
+    25322,
+    25324,
+    25324,
+    25326,
+    25327,
+    25321,
+    25329,
+    25330,
+    25331,
+    25332,
+    25332,
+    25334,
+    25332,
+    25336,
+    25337,
+    25338,
+    25338,
+    25340,
+    25340,
+    25340,
+    25343,
+    25344,
+    25344,
+    25343,
+    25347,
+    25347,
+    25347,
+    25347,
+    25351,
+    25352,
+    25352,
+    25352,
+    25352,
+    25356,
+    25356,
+    25356,
+    25356,
+    25360,
+    25360,
+    25360,
+    25363,
+    25363,
+    25365,
+    25365,
+    25367,
+    25367,
+    25367,
+    25367,
+    25371,
+    25371,
+    25373,
+    25373,
+    25373,
+    25373,
+    25377,
+    25377,
+    25377,
+    25380,
+    25380,
+    25382,
+    25382,
+    25384,
+    25385,
+    25386,
+    25387,
+    25387,
+    25389,
+    25389,
+    25389,
+    25392,
+    25392,
+    25392,
+    25395,
+    25395,
+    25395,
+    25398,
+    25399,
+    25399,
+    25401,
+    25401,
+    25401,
+    25404,
+    25404,
+    25404,
+    25407,
+    25407,
+    25407,
+    25407,
+    25411,
+    25411,
+    25413,
+    25413,
+    25413,
+    25416,
+    25417,
+    25417,
+    25419,
+    25420,
+    25420,
+    25422,
+    25420,
+    25424,
+    25424,
+    25426,
+    25427,
+    25427,
+    25429,
+    25430,
+    25429,
+    25432,
+    25432,
+    25336,
+    25435,
+    25435,
+    25437,
+    25437,
+    25437,
+    25437,
+    25441,
+    25441,
+    25441,
+    25441,
+    25445,
+    25445,
+    25445,
+    25448,
+    25448,
+    25450,
+    25450,
+    25452,
+    25452,
+    25452,
+    25455,
+    25455,
+    25457,
+    25457,
+    25457,
+    25460,
+    25461,
+    25461,
+    25461,
+    25464,
+    25464,
+    25464,
+    25467,
+    25467,
+    25467,
+    25470,
+    25471,
+    25472,
+    25472,
+    25472,
+    25475,
+    25476,
+    25475,
+    25478,
+    25478,
+    25478,
+    25481,
+    25481,
+    25483,
+    25483,
+    25483,
+    25486,
+    25487,
+    25487,
+    25489,
+    25489,
+    25491,
+    25492,
+    25491,
+    25491,
+    25495,
+    25336,
+    25336,
+    25498,
+    25498,
+    25498,
+    25501,
+    25502,
+    25503,
+    25504,
+    25504,
+    25506,
+    25503,
+    25508,
+    25508,
+    25508,
+    25511,
+    25511,
+    25508,
+    25514,
+    25514,
+    25514,
+    25517,
+    25517,
+    25517,
+    25520,
+    25520,
+    25520,
+    25523,
+    25523,
+    25525,
+    25526,
+    25527,
+    25527,
+    25526,
+    25530,
+    25530,
+    25532,
+    25532,
+    25530,
+    25535,
+    25535,
+    25537,
+    25537,
+    25535,
+    25540,
+    25541,
+    25541,
+    25543,
+    25543,
+    25543,
+    25546,
+    25546,
+    25546,
+    25546,
+    25550,
+    25550,
+    25550,
+    25550,
+    25554,
+    25554,
+    25556,
+    25556,
+    25556,
+    25556,
+    25560,
+    25560,
+    25560,
+    25560,
+    25564,
+    25564,
+    25566,
+    25566,
+    25566,
+    25569,
+    25569,
+    25571,
+    25571,
+    25571,
+    25571,
+    25575,
+    25575,
+    25575,
+    25578,
+    25578,
+    25580,
+    25581,
+    25581,
+    25583,
+    25584,
+    25584,
+    25584,
+    25587,
+    25587,
+    25587,
+    25590,
+    25590,
+    25590,
+    25590,
+    25594,
+    25595,
+    25596,
+    25596,
+    25596,
+    25599,
+    25599,
+    25601,
+    25601,
+    25594,
+    25594,
+    25605,
+    25605,
+    25605,
+    25336,
+    25609,
+    25610,
+    25610,
+    25612,
+    25613,
+    25614,
+    25614,
+    25616,
+    25616,
+    25618,
+    25619,
+    25619,
+    25619,
+    25622,
+    25622,
+    25624,
+    25625,
+    25626,
+    25336,
+    25628,
+    25628,
+    25630,
+    25630,
+    25632,
+    25632,
+    25634,
+    25635,
+    25635,
+    25637,
+    25638,
+    25639,
+    25640,
+    25641,
+    25641,
+    25643,
+    25644,
+    25645,
+    25646,
+    25647,
+    25648,
+    25649,
+    25649,
+    25649,
+    25652,
+    25653,
+    25653,
+    25653,
+    25653,
+    25657,
+    25658,
+    25659,
+    25658,
+    25657,
+    25662,
+    25663,
+    25663,
+    25663,
+    25666,
+    25667,
+    25667,
+    25669,
+    25669,
+    25669,
+    25672,
+    25672,
+    25672,
+    25675,
+    25675,
+    25677,
+    25677,
+    25679,
+    25679,
+    25679,
+    25682,
+    25682,
+    25684,
+    25685,
+    25685,
+    25687,
+    25688,
+    25689,
+    25689,
+    25691,
+    25688,
+    25693,
+    25693,
+    25695,
+    25695,
+    25697,
+    25698,
+    25698,
+    25700,
+    25701,
+    25701,
+    25700,
+    25704,
+    25705,
+    25705,
+    25705,
+    25708,
+    25709,
+    25710,
+    25711,
+    25711,
+    25713,
+    25714,
+    25714,
+    25716,
+    25717,
+    25718,
+    25718,
+    25718,
+    25721,
+    25722,
+    25722,
+    25724,
+    25724,
+    25726,
+    25726,
+    25728,
+    25729,
+    25730,
+    25731,
+    25732,
+    25733,
+    25733,
+    25735,
+    25735,
+    25737,
+    25737,
+    25739,
+    25740,
+    25741,
+    25741,
+    25743,
+    25743,
+    25745,
+    25746,
+    25747,
+    25747,
+    25749,
+    25750,
+    25750,
+    25752,
+    25704,
+    25754,
+    25755,
+    25756,
+    25757,
+    25758,
+    25759,
+    25759,
+    25761,
+    25761,
+    25763,
+    25764,
+    25764,
+    25764,
+    25767,
+    25767,
+    25769,
+    25770,
+    25771,
+    25771,
+    25773,
+    25773,
+    25775,
+    25776,
+    25776,
+    25778,
+    25779,
+    25780,
+    25780,
+    25782,
+    25783,
+    25784,
+    25784,
+    25786,
+    25786,
+    25788,
+    25789,
+    25789,
+    25791,
+    25792,
+    25792,
+    25792,
+    25795,
+    25795,
+    25797,
+    25797,
+    25799,
+    25799,
+    25801,
+    25801,
+    25803,
+    25804,
+    25804,
+    25806,
+    25704,
+    25808,
+    25809,
+    25810,
+    25811,
+    25812,
+    25813,
+    25814,
+    25815,
+    25816,
+    25817,
+    25818,
+    25819,
+    25820,
+    25820,
+    25822,
+    25823,
+    25824,
+    25825,
+    25826,
+    25827,
+    25827,
+    25829,
+    25830,
+    25830,
+    25832,
+    25833,
+    25834,
+    25834,
+    25836,
+    25836,
+    25838,
+    25839,
+    25840,
+    25840,
+    25842,
+    25843,
+    25843,
+    25845,
+    25846,
+    25847,
+    25846,
+    25849,
+    25849,
+    25851,
+    25852,
+    25853,
+    25854,
+    25855,
+    25855,
+    25855,
+    25855,
+    25859,
+    25859,
+    25861,
+    25862,
+    25863,
+    25864,
+    25865,
+    25866,
+    25863,
+    25868,
+    25869,
+    25870,
+    25871,
+    25872,
+    25872,
+    25874,
+    25875,
+    25869,
+    25877,
+    25869,
+    25879,
+    25880,
+    25881,
+    25880,
+    25883,
+    25883,
+    25885,
+    25880,
+    25887,
+    25887,
+    25889,
+    25890,
+    25891,
+    25891,
+    25893,
+    25894,
+    25895,
+    25895,
+    25897,
+    25897,
+    25899,
+    25900,
+    25900,
+    25902,
+    25903,
+    25904,
+    25904,
+    25906,
+    25906,
+    25908,
+    25908,
+    25910,
+    25911,
+    25912,
+    25913,
+    25913,
+    25915,
+    25916,
+    25916,
+    25918,
+    25918,
+    25911,
+    25921,
+    25922,
+    25922,
+    25924,
+    25921,
+    25926,
+    25927,
+    25927,
+    25929,
+    25929,
+    25931,
+    25931,
+    25933,
+    25934,
+    25934,
+    25936,
+    25937,
+    25936,
+    25939,
+    25940,
+    25941,
+    25941,
+    25940,
+    25944,
+    25945,
+    25946,
+    25946,
+    25945,
+    25949,
+    25950,
+    25951,
+    25951,
+    25953,
+    25954,
+    25954,
+    25956,
+    25957,
+    25957,
+    25959,
+    25960,
+    25960,
+    25962,
+    25962,
+    25960,
+    25965,
+    25966,
+    25966,
+    25968,
+    25968,
+    25970,
+    25970,
+    25972,
+    25973,
+    25974,
+    25960,
+    25960,
+    25977,
+    25977,
+    25979,
+    25979,
+    25981,
+    25981,
+    25981,
+    25984,
+    25977,
+    25986,
+    25977,
+    25988,
+    25989,
+    25977,
+    25991,
+    25991,
+    25959,
+    25994,
+    25995,
+    25995,
+    25994,
+    25998,
+    25999,
+    25999,
+    26001,
+    26002,
+    26002,
+    26004,
+    26005,
+    26005,
+    26007,
+    26007,
+    26007,
+    26010,
+    26010,
+    26012,
+    26012,
+    26014,
+    26015,
+    26015,
+    26017,
+    26018,
+    26001,
+    26020,
+    26021,
+    26022,
+    26021,
+    26024,
+    26025,
+    26025,
+    26024,
+    26028,
+    26028,
+    26030,
+    26030,
+    26024,
+    26033,
+    26034,
+    26034,
+    26036,
+    26037,
+    26037,
+    26039,
+    26039,
+    26041,
+    26042,
+    26043,
+    26043,
+    26043,
+    26046,
+    26046,
+    26048,
+    26049,
+    26049,
+    26048,
+    26052,
+    26048,
+    26054,
+    26055,
+    26055,
+    26055,
+    26058,
+    26054,
+    26060,
+    26061,
+    26060,
+    26054,
+    26064,
+    26064,
+    26066,
+    26067,
+    26067,
+    26067,
+    26070,
+    26071,
+    26072,
+    26073,
+    26074,
+    26074,
+    26076,
+    26077,
+    26077,
+    26079,
+    26077,
+    26081,
+    26082,
+    26082,
+    26084,
+    26084,
+    26086,
+    26087,
+    26088,
+    26089,
+    26090,
+    26077,
+    26077,
+    26093,
+    26093,
+    26086,
+    26096,
+    26097,
+    26098,
+    26098,
+    26100,
+    26100,
+    26102,
+    26102,
+    26104,
+    26098,
+    26106,
+    26107,
+    26108,
+    26098,
+    26110,
+    26111,
+    26076,
+    26113,
+    26114,
+    26113,
+    26113,
+    26117,
+    26117,
+    26117,
+    26120,
+    26121,
+    26122,
+    26123,
+    26124,
+    26124,
+    26126,
+    26127,
+    26127,
+    26127,
+    26130,
+    26131,
+    26132,
+    26132,
+    26134,
+    26134,
+    26136,
+    26136,
+    26138,
+    26139,
+    26139,
+    26141,
+    26141,
+    26143,
+    26143,
+    26131,
+    26138,
+    26147,
+    26147,
+    26149,
+    26149,
+    26151,
+    26152,
+    26153,
+    26152,
+    26147,
+    26156,
+    26156,
+    26158,
+    26158,
+    26160,
+    26160,
+    26160,
+    26163,
+    26163,
+    26165,
+    26165,
+    26165,
+    26168,
+    26169,
+    26170,
+    26171,
+    26171,
+    26171,
+    26168,
+    26175,
+    26175,
+    26177,
+    26177,
+    26179,
+    26180,
+    26181,
+    26182,
+    26183,
+    26183,
+    26126,
+    26186,
+    26187,
+    26187,
+    26189,
+    26190,
+    26191,
+    26191,
+    26193,
+    26194,
+    26195,
+    26196,
+    26196,
+    26196,
+    26199,
+    26200,
+    26200,
+    26199,
+    26203,
+    26203,
+    26205,
+    26205,
+    26187,
+    26194,
+    26209,
+    26210,
+    26210,
+    26212,
+    26213,
+    26213,
+    26213,
+    26216,
+    26216,
+    26216,
+    26219,
+    26219,
+    26219,
+    26222,
+    26222,
+    26222,
+    26225,
+    26222,
+    26227,
+    26227,
+    26229,
+    26230,
+    26231,
+    26232,
+    26233,
+    26234,
+    26234,
+    26230,
+    26237,
+    26238,
+    26239,
+    26238,
+    26241,
+    26241,
+    26243,
+    26243,
+    26245,
+    26245,
+    26238,
+    26248,
+    26248,
+    26250,
+    26250,
+    26252,
+    26253,
+    26253,
+    26253,
+    26256,
+    26256,
+    26258,
+    26258,
+    26260,
+    26260,
+    26262,
+    26263,
+    26264,
+    26238,
+    26238,
+    26267,
+    26268,
+    26269,
+    26270,
+    26270,
+    26272,
+    26273,
+    26274,
+    26270,
+    26270,
+    26274,
+    26270,
+    26279,
+    26280,
+    26280,
+    26280,
+    26283,
+    26283,
+    26283,
+    26280,
+    26287,
+    26288,
+    26289,
+    26290,
+    26289,
+    26289,
+    26293,
+    26294,
+    26294,
+    26294,
+    26294,
+    26293,
+    26299,
+    26300,
+    26301,
+    26299,
+    26303,
+    26303,
+    26305,
+    26306,
+    26306,
+    26308,
+    26309,
+    26310,
+    26310,
+    26310,
+    26212,
+    26314,
+    26315,
+    26314,
+    26317,
+    26314,
+    26319,
+    26320,
+    26321,
+    26322,
+    26323,
+    26323,
+    26325,
+    26326,
+    26327,
+    26327,
+    26329,
+    26329,
+    26323,
+    26323,
+    26333,
+    26334,
+    26334,
+    26323,
+    26329,
+    26329,
+    26329,
+    26340,
+    26341,
+    26341,
+    26343,
+    26344,
+    26345,
+    26346,
+    26347,
+    26347,
+    26346,
+    26345,
+    26351,
+    26352,
+    26353,
+    26354,
+    26354,
+    26354,
+    26357,
+    26358,
+    26359,
+    26360,
+    26360,
+    26362,
+    26363,
+    26364,
+    26365,
+    26366,
+    26367,
+    26368,
+    26369,
+    26370,
+    26371,
+    26372,
+    26360,
+    26374,
+    26374,
+    26374,
+    26377,
+    26378,
+    26379,
+    26380,
+    26381,
+    26381,
+    26381,
+    26384,
+    26385,
+    26386,
+    26386,
+    26388,
+    26388,
+    26386,
+    26391,
+    26392,
+    26386,
+    26394,
+    26394,
+    26396,
+    26397,
+    26397,
+    26397,
+    26400,
+    26401,
+    26401,
+    26403,
+    26404,
+    26403,
+    26406,
+    26406,
+    26401,
+    26409,
+    26410,
+    26411,
+    26412,
+    26413,
+    26412,
+    26415,
+    26416,
+    26409,
+    26418,
+    26419,
+    26419,
+    26419,
+    26418,
+    26423,
+    26423,
+    26425,
+    26426,
+    26427,
+    26428,
+    26429,
+    26430,
+    26429,
+    26432,
+    26433,
+    26433,
+    26435,
+    26435,
+    26435,
+    26438,
+    26432,
+    26440,
+    26385,
+    26442,
+    26443,
+    26443,
+    26445,
+    26446,
+    26446,
+    26448,
+    26449,
+    26450,
+    26451,
+    26451,
+    26453,
+    26453,
+    26450,
+    26456,
+    26457,
+    26457,
+    26457,
+    26460,
+    26461,
+    26462,
+    26463,
+    26463,
+    26465,
+    26463,
+    26467,
+    26468,
+    26468,
+    26470,
+    26471,
+    26471,
+    26468,
+    26474,
+    26475,
+    26468,
+    26468,
+    26475,
+    26479,
+    26479,
+    26481,
+    26481,
+    26483,
+    26484,
+    26485,
+    26486,
+    26467,
+    26488,
+    26488,
+    26490,
+    26490,
+    26488,
+    26493,
+    26494,
+    26495,
+    26496,
+    26493,
+    26498,
+    26499,
+    26499,
+    26501,
+    26501,
+    26503,
+    26503,
+    26505,
+    26505,
+    26501,
+    26488,
+    26509,
+    26509,
+    26511,
+    26512,
+    26512,
+    26511,
+    26515,
+    26515,
+    26511,
+    26518,
+    26518,
+    26520,
+    26520,
+    26522,
+    26522,
+    26524,
+    26524,
+    26511,
+    26527,
+    26528,
+    26529,
+    26530,
+    26530,
+    26529,
+    26533,
+    26534,
+    26535,
+    26535,
+    26537,
+    26537,
+    26539,
+    26539,
+    26541,
+    26539,
+    26543,
+    26544,
+    26544,
+    26544,
+    26547,
+    26547,
+    26549,
+    26549,
+    26551,
+    26552,
+    26552,
+    26552,
+    26555,
+    26551,
+    26557,
+    26558,
+    26528,
+    26560,
+    26561,
+    26561,
+    26563,
+    26564,
+    26565,
+    26565,
+    26567,
+    26568,
+    26568,
+    26570,
+    26571,
+    26571,
+    26570,
+    26570,
+    26575,
+    26575,
+    26577,
+    26578,
+    26575,
+    26580,
+    26567,
+    26582,
+    26583,
+    26582,
+    26585,
+    26585,
+    26587,
+    26588,
+    26589,
+    26589,
+    26591,
+    26592,
+    26592,
+    26594,
+    26595,
+    26596,
+    26596,
+    26598,
+    26598,
+    26596,
+    26601,
+    26602,
+    26603,
+    26601,
+    26605,
+    26605,
+    26607,
+    26608,
+    26609,
+    26609,
+    26611,
+    26612,
+    26612,
+    26614,
+    26614,
+    26616,
+    26616,
+    26595,
+    26619,
+    26619,
+    26621,
+    26622,
+    26622,
+    26624,
+    26625,
+    26625,
+    26621,
+    26628,
+    26628,
+    26630,
+    26630,
+    26632,
+    26632,
+    26634,
+    26634,
+    26636,
+    26637,
+    26638,
+    26638,
+    26640,
+    26637,
+    26642,
+    26642,
+    26644,
+    26644,
+    26646,
+    26646,
+    26648,
+    26648,
+    26650,
+    26651,
+    26652,
+    26653,
+    26654,
+    26653,
+    26656,
+    26657,
+    26658,
+    26658,
+    26636,
+    26661,
+    26662,
+    26661,
+    26664,
+    26661,
+    26666,
+    26666,
+    26668,
+    26669,
+    26670,
+    26670,
+    26672,
+    26672,
+    26674,
+    26675,
+    26676,
+    26677,
+    26678,
+    26678,
+    26680,
+    26680,
+    26682,
+    26683,
+    26683,
+    26685,
+    26686,
+    26685,
+    26688,
+    26688,
+    26690,
+    26690,
+    26674,
+    26693,
+    26694,
+    26695,
+    26696,
+    26696,
+    26698,
+    26698,
+    26700,
+    26701,
+    26695,
+    26703,
+    26703,
+    26705,
+    26705,
+    26707,
+    26708,
+    26708,
+    26707,
+    26711,
+    26712,
+    26711,
+    26714,
+    26715,
+    26716,
+    26715,
+    26718,
+    26719,
+    26720,
+    26720,
+    26722,
+    26723,
+    26723,
+    26725,
+    26725,
+    26727,
+    26727,
+    26722,
+    26730,
+    26730,
+    26732,
+    26733,
+    26734,
+    26735,
+    26736,
+    26737,
+    26738,
+    26739,
+    26739,
+    26741,
+    26742,
+    26742,
+    26744,
+    26745,
+    26745,
+    26747,
+    26747,
+    26749,
+    26750,
+    26751,
+    26750,
+    26753,
+    26753,
+    26741,
+    26756,
+    26757,
+    26758,
+    26759,
+    26760,
+    26758,
+    26762,
+    26758,
+    26764,
+    26765,
+    26766,
+    26765,
+    26768,
+    26768,
+    26765,
+    26771,
+    26772,
+    26773,
+    26774,
+    26774,
+    26772,
+    26777,
+    26777,
+    26779,
+    26780,
+    26780,
+    26782,
+    26783,
+    26784,
+    26785,
+    26786,
+    26787,
+    26788,
+    26789,
+    26790,
+    26791,
+    26791,
+    26793,
+    26790,
+    26795,
+    26796,
+    26797,
+    26798,
+    26799,
+    26800,
+    26801,
+    26801,
+    26797,
+    26804,
+    26805,
+    26806,
+    26807,
+    26808,
+    26809,
+    26810,
+    26811,
+    26812,
+    26813,
+    26814,
+    26815,
+    26816,
+    26817,
+    26814,
+    26819,
+    26820,
+    26820,
+    26822,
+    26823,
+    26824,
+    26825,
+    26824,
+    26827,
+    26828,
+    26813,
+    26830,
+    26831,
+    26832,
+    26832,
+    26834,
+    26835,
+    26835,
+    26834,
+    26838,
+    26839,
+    26839,
+    26841,
+    26841,
+    26843,
+    26844,
+    26844,
+    26846,
+    26846,
+    26848,
+    26848,
+    26850,
+    26850,
+    26852,
+    26852,
+    26854,
+    26855,
+    26855,
+    26857,
+    26858,
+    26859,
+    26859,
+    26861,
+    26861,
+    26863,
+    26864,
+    26865,
+    26866,
+    26861,
+    26868,
+    26869,
+    26870,
+    26871,
+    26872,
+    26873,
+    26874,
+    26875,
+    26876,
+    26877,
+    26877,
+    26876,
+    26880,
+    26880,
+    26882,
+    26883,
+    26884,
+    26883,
+    26886,
+    26887,
+    26888,
+    26889,
+    26889,
+    26891,
+    26891,
+    26893,
+    26893,
+    26895,
+    26895,
+    26897,
+    26898,
+    26898,
+    26898,
+    26901,
+    26888,
+    26903,
+    26903,
+    26905,
+    26906,
+    26907,
+    26908,
+    26907,
+    26910,
+    26911,
+    26911,
+    26913,
+    26913,
+    26915,
+    26916,
+    26917,
+    26917,
+    26919,
+    26919,
+    26921,
+    26922,
+    26923,
+    26924,
+    26925,
+    26926,
+    26927,
+    26928,
+    26928,
+    26928,
+    26931,
+    26931,
+    26933,
+    26934,
+    26934,
+    26936,
+    26936,
+    26931,
+    26939,
+    26939,
+    26941,
+    26941,
+    26943,
+    26943,
+    26945,
+    26946,
+    26945,
+    26948,
+    26949,
+    26926,
+    26945,
+    26952,
+    26952,
+    26954,
+    26955,
+    26916,
+    26957,
+    26958,
+    26958,
+    26957,
+    26961,
+    26962,
+    26962,
+    26962,
+    26965,
+    26965,
+    26965,
+    26965,
+    26969,
+    26970,
+    26971,
+    26972,
+    26973,
+    26973,
+    26975,
+    26976,
+    26977,
+    26978,
+    26979,
+    26980,
+    26981,
+    26981,
+    26983,
+    26984,
+    26985,
+    26986,
+    26987,
+    26988,
+    26989,
+    26990,
+    26991,
+    26992,
+    26992,
+    26994,
+    26994,
+    26996,
+    26996,
+    26998,
+    26999,
+    27000,
+    27000,
+    27002,
+    27003,
+    27002,
+    27005,
+    27006,
+    27007,
+    27008,
+    27008,
+    27007,
+    27005,
+    27012,
+    27013,
+    27014,
+    27014,
+    27016,
+    27016,
+    27018,
+    27019,
+    27020,
+    27020,
+    27020,
+    27023,
+    27024,
+    27025,
+    27025,
+    27019,
+    27028,
+    27028,
+    27030,
+    27031,
+    27032,
+    27033,
+    27034,
+    27035,
+    27035,
+    27035,
+    27032,
+    27039,
+    27039,
+    27041,
+    27041,
+    27043,
+    27043,
+    27045,
+    27045,
+    27043,
+    27048,
+    27048,
+    27032,
+    27051,
+    27052,
+    27052,
+    27054,
+    27054,
+    27056,
+    27056,
+    27056,
+    27059,
+    27059,
+    27059,
+    27062,
+    27062,
+    27064,
+    27065,
+    27065,
+    27065,
+    27018,
+    27069,
+    27069,
+    27071,
+    27071,
+    27071,
+    27074,
+    27074,
+    27076,
+    27077,
+    27077,
+    27079,
+    27080,
+    27081,
+    27081,
+    27083,
+    27083,
+    27085,
+    27085,
+    27087,
+    27088,
+    27089,
+    27089,
+    27076,
+    27092,
+    27093,
+    27093,
+    27092,
+    27096,
+    27097,
+    27098,
+    27099,
+    27099,
+    27101,
+    27102,
+    27101,
+    27104,
+    27105,
+    27105,
+    27107,
+    27108,
+    27107,
+    27110,
+    27111,
+    27112,
+    27113,
+    27113,
+    27115,
+    27115,
+    27098,
+    27118,
+    27119,
+    27120,
+    27121,
+    27121,
+    27123,
+    27123,
+    27125,
+    27126,
+    27126,
+    27123,
+    27129,
+    27130,
+    27131,
+    27131,
+    27133,
+    27134,
+    27135,
+    27135,
+    27137,
+    27137,
+    27139,
+    27140,
+    27140,
+    27142,
+    27143,
+    27142,
+    27145,
+    27146,
+    27142,
+    27148,
+    27149,
+    27150,
+    27150,
+    27152,
+    27152,
+    27154,
+    27155,
+    27156,
+    27156,
+    27155,
+    27155,
+    27160,
+    27161,
+    27162,
+    27163,
+    27164,
+    27165,
+    27165,
+    27167,
+    27168,
+    27168,
+    27170,
+    27171,
+    27172,
+    27173,
+    27173,
+    27173,
+    27171,
+    27177,
+    27178,
+    27179,
+    27179,
+    27181,
+    27182,
+    27182,
+    27181,
+    27185,
+    27185,
+    27181,
+    27188,
+    27188,
+    27190,
+    27190,
+    27192,
+    27193,
+    27193,
+    27192,
+    27196,
+    27192,
+    27198,
+    27199,
+    27200,
+    27201,
+    27201,
+    27203,
+    27203,
+    27205,
+    27205,
+    27207,
+    27208,
+    27208,
+    27208,
+    27203,
+    27212,
+    27212,
+    27214,
+    27214,
+    27216,
+    27216,
+    27218,
+    27218,
+    27220,
+    27221,
+    27221,
+    27223,
+    27224,
+    27224,
+    27224,
+    27216,
+    27228,
+    27229,
+    27229,
+    27231,
+    27231,
+    27233,
+    27233,
+    27235,
+    27236,
+    27236,
+    27235,
+    27170,
+    27240,
+    27241,
+    27242,
+    27243,
+    27243,
+    27243,
+    27242,
+    27247,
+    27247,
+    27249,
+    27249,
+    27249,
+    27241,
+    27253,
+    27253,
+    27255,
+    27255,
+    27257,
+    27257,
+    27259,
+    27259,
+    27261,
+    27262,
+    27262,
+    27264,
+    27264,
+    27266,
+    27264,
+    27268,
+    27269,
+    27270,
+    27271,
+    27271,
+    27273,
+    27273,
+    27275,
+    27276,
+    27277,
+    27278,
+    27278,
+    27278,
+    27281,
+    27281,
+    27283,
+    27284,
+    27285,
+    27285,
+    27285,
+    27288,
+    27288,
+    27290,
+    27290,
+    27292,
+    27293,
+    27294,
+    27294,
+    27296,
+    27296,
+    27283,
+    27299,
+    27300,
+    27301,
+    27302,
+    27303,
+    27304,
+    27305,
+    27305,
+    27307,
+    27308,
+    27309,
+    27310,
+    27311,
+    27312,
+    27313,
+    27314,
+    27315,
+    27315,
+    27317,
+    27318,
+    27319,
+    27320,
+    27320,
+    27322,
+    27322,
+    27324,
+    27325,
+    27324,
+    27322,
+    27328,
+    27328,
+    27330,
+    27331,
+    27332,
+    27333,
+    27334,
+    27335,
+    27336,
+    27337,
+    27338,
+    27338,
+    27337,
+    27341,
+    27337,
+    27343,
+    27344,
+    27344,
+    27346,
+    27347,
+    27347,
+    27319,
+    27350,
+    27351,
+    27352,
+    27353,
+    27353,
+    27355,
+    27356,
+    27357,
+    27355,
+    27359,
+    27360,
+    27355,
+    27362,
+    27363,
+    27363,
+    27362,
+    27366,
+    27367,
+    27367,
+    27369,
+    27370,
+    27370,
+    27369,
+    27373,
+    27373,
+    27369,
+    27376,
+    27376,
+    27378,
+    27369,
+    27369,
+    27381,
+    27382,
+    27383,
+    27383,
+    27382,
+    27386,
+    27386,
+    27388,
+    27388,
+    27390,
+    27390,
+    27392,
+    27392,
+    27394,
+    27394,
+    27396,
+    27396,
+    27394,
+    27399,
+    27400,
+    27401,
+    27401,
+    27403,
+    27403,
+    27405,
+    27405,
+    27407,
+    27408,
+    27405,
+    27410,
+    27410,
+    27412,
+    27413,
+    27413,
+    27415,
+    27415,
+    27413,
+    27418,
+    27419,
+    27420,
+    27420,
+    27422,
+    27422,
+    27424,
+    27424,
+    27426,
+    27426,
+    27424,
+    27429,
+    27430,
+    27430,
+    27429,
+    27433,
+    27433,
+    27435,
+    27435,
+    27437,
+    27438,
+    27438,
+    27438,
+    27441,
+    27441,
+    27443,
+    27418,
+    27445,
+    27445,
+    27447,
+    27447,
+    27445,
+    27450,
+    27450,
+    27452,
+    27452,
+    27454,
+    27455,
+    27452,
+    27457,
+    27458,
+    27459,
+    27460,
+    27461,
+    27462,
+    27463,
+    27464,
+    27465,
+    27465,
+    27467,
+    27468,
+    27469,
+    27470,
+    27471,
+    27472,
+    27473,
+    27474,
+    27475,
+    27475,
+    27477,
+    27477,
+    27479,
+    27479,
+    27475,
+    27482,
+    27482,
+    27484,
+    27485,
+    27475,
+    27475,
+    27488,
+    27489,
+    27489,
+    27491,
+    27492,
+    27474,
+    27485,
+    27495,
+    27485,
+    27497,
+    27497,
+    27499,
+    27500,
+    27501,
+    27501,
+    27503,
+    27503,
+    27500,
+    27506,
+    27506,
+    27508,
+    27508,
+    27510,
+    27511,
+    27500,
+    27500,
+    27514,
+    27515,
+    27516,
+    27516,
+    27518,
+    27518,
+    27520,
+    27521,
+    27522,
+    27522,
+    27524,
+    27524,
+    27524,
+    27524,
+    27521,
+    27529,
+    27530,
+    27531,
+    27531,
+    27531,
+    27531,
+    27535,
+    27535,
+    27537,
+    27538,
+    27538,
+    27540,
+    27540,
+    27542,
+    27543,
+    27543,
+    27545,
+    27545,
+    27547,
+    27548,
+    27549,
+    27550,
+    27551,
+    27552,
+    27553,
+    27554,
+    27555,
+    27555,
+    27557,
+    27558,
+    27559,
+    27560,
+    27557,
+    27562,
+    27562,
+    27564,
+    27565,
+    27565,
+    27567,
+    27568,
+    27568,
+    27570,
+    27571,
+    27572,
+    27573,
+    27574,
+    27574,
+    27576,
+    27577,
+    27578,
+    27578,
+    27580,
+    27581,
+    27582,
+    27583,
+    27584,
+    27583,
+    27586,
+    27586,
+    27588,
+    27588,
+    27590,
+    27590,
+    27592,
+    27592,
+    27590,
+    27595,
+    27595,
+    27597,
+    27597,
+    27595,
+    27600,
+    27600,
+    27602,
+    27603,
+    27603,
+    27605,
+    27605,
+    27603,
+    27608,
+    27609,
+    27609,
+    27611,
+    27612,
+    27613,
+    27612,
+    27615,
+    27616,
+    27617,
+    27617,
+    27619,
+    27619,
+    27617,
+    27622,
+    27623,
+    27623,
+    27625,
+    27625,
+    27625,
+    27625,
+    27629,
+    27629,
+    27631,
+    27631,
+    27633,
+    27634,
+    27629,
+    27636,
+    27637,
+    27637,
+    27639,
+    27640,
+    27640,
+    27639,
+    27643,
+    27643,
+    27645,
+    27645,
+    27639,
+    27648,
+    27649,
+    27639,
+    27651,
+    27652,
+    27653,
+    27653,
+    27653,
+    27653,
+    27652,
+    27658,
+    27659,
+    27659,
+    27661,
+    27661,
+    27663,
+    27664,
+    27659,
+    27659,
+    27667,
+    27668,
+    27668,
+    27670,
+    27670,
+    27672,
+    27673,
+    27673,
+    27673,
+    27676,
+    27677,
+    27678,
+    27678,
+    27677,
+    27681,
+    27681,
+    27683,
+    27684,
+    27677,
+    27677,
+    27677,
+    27688,
+    27688,
+    27690,
+    27690,
+    27692,
+    27688,
+    27694,
+    27695,
+    27688,
+    27697,
+    27698,
+    27672,
+    27700,
+    27701,
+    27701,
+    27703,
+    27703,
+    27705,
+    27705,
+    27703,
+    27708,
+    27708,
+    27710,
+    27710,
+    27712,
+    27713,
+    27708,
+    27715,
+    27716,
+    27716,
+    27658,
+    27719,
+    27719,
+    27721,
+    27722,
+    27723,
+    27724,
+    27725,
+    27726,
+    27727,
+    27725,
+    27729,
+    27730,
+    27731,
+    27732,
+    27732,
+    27731,
+    27735,
+    27736,
+    27736,
+    27738,
+    27738,
+    27740,
+    27741,
+    27741,
+    27743,
+    27743,
+    27741,
+    27746,
+    27747,
+    27741,
+    27749,
+    27740,
+    27751,
+    27751,
+    27753,
+    27753,
+    27755,
+    27756,
+    27756,
+    27755,
+    27755,
+    27760,
+    27761,
+    27762,
+    27763,
+    27753,
+    27753,
+    27766,
+    27767,
+    27768,
+    27769,
+    27770,
+    27771,
+    27770,
+    27773,
+    27774,
+    27775,
+    27776,
+    27777,
+    27778,
+    27779,
+    27780,
+    27781,
+    27782,
+    27782,
+    27784,
+    27784,
+    27786,
+    27787,
+    27767,
+    27789,
+    27789,
+    27791,
+    27791,
+    27793,
+    27794,
+    27795,
+    27796,
+    27795,
+    27798,
+    27794,
+    27800,
+    27800,
+    27802,
+    27803,
+    27803,
+    27805,
+    27806,
+    27807,
+    27807,
+    27809,
+    27809,
+    27811,
+    27812,
+    27813,
+    27813,
+    27815,
+    27816,
+    27816,
+    27813,
+    27811,
+    27820,
+    27806,
+    27822,
+    27822,
+    27824,
+    27825,
+    27826,
+    27826,
+    27828,
+    27829,
+    27830,
+    27830,
+    27832,
+    27832,
+    27830,
+    27835,
+    27835,
+    27837,
+    27838,
+    27839,
+    27840,
+    27841,
+    27830,
+    27839,
+    27844,
+    27845,
+    27845,
+    27847,
+    27848,
+    27848,
+    27850,
+    27851,
+    27851,
+    27851,
+    27847,
+    27855,
+    27855,
+    27857,
+    27858,
+    27859,
+    27860,
+    27860,
+    27857,
+    27863,
+    27864,
+    27864,
+    27866,
+    27867,
+    27867,
+    27869,
+    27869,
+    27857,
+    27872,
+    27873,
+    27874,
+    27875,
+    27875,
+    27877,
+    27877,
+    27879,
+    27880,
+    27880,
+    27877,
+    27883,
+    27884,
+    27857,
+    27886,
+    27887,
+    27887,
+    27889,
+    27890,
+    27891,
+    27892,
+    27893,
+    27894,
+    27895,
+    27895,
+    27895,
+    27895,
+    27894,
+    27844,
+    27901,
+    27901,
+    27884,
+    27904,
+    27905,
+    27905,
+    27907,
+    27907,
+    27909,
+    27910,
+    27911,
+    27911,
+    27913,
+    27914,
+    27914,
+    27916,
+    27917,
+    27918,
+    27919,
+    27920,
+    27921,
+    27922,
+    27923,
+    27924,
+    27925,
+    27926,
+    27925,
+    27928,
+    27929,
+    27928,
+    27884,
+    27932,
+    27933,
+    27934,
+    27934,
+    27936,
+    27937,
+    27937,
+    27939,
+    27940,
+    27940,
+    27942,
+    27942,
+    27942,
+    27945,
+    27945,
+    27947,
+    27947,
+    27949,
+    27949,
+    27951,
+    27951,
+    27939,
+    27954,
+    27954,
+    27954,
+    27957,
+    27958,
+    27959,
+    27960,
+    27961,
+    27962,
+    27962,
+    27961,
+    27965,
+    27965,
+    27967,
+    27968,
+    27968,
+    27967,
+    27971,
+    27971,
+    27973,
+    27974,
+    27967,
+    27976,
+    27977,
+    27977,
+    27979,
+    27979,
+    27981,
+    27981,
+    27983,
+    27984,
+    27984,
+    27986,
+    27987,
+    27988,
+    27988,
+    27984,
+    27991,
+    27979,
+    27993,
+    27993,
+    27993,
+    27996,
+    27996,
+    27998,
+    27999,
+    28000,
+    28000,
+    28002,
+    28002,
+    28002,
+    28005,
+    28006,
+    28006,
+    28006,
+    28009,
+    28010,
+    28011,
+    28011,
+    28013,
+    28006,
+    28013,
+    28016,
+    28017,
+    28017,
+    28019,
+    28020,
+    28017,
+    28017,
+    28019,
+    28024,
+    28025,
+    28024,
+    28027,
+    28028,
+    28029,
+    28030,
+    28030,
+    28032,
+    28033,
+    28033,
+    28033,
+    28036,
+    28036,
+    28036,
+    28039,
+    28039,
+    28028,
+    28042,
+    28042,
+    28028,
+    28045,
+    28028,
+    28047,
+    28048,
+    28049,
+    28050,
+    28051,
+    28051,
+    28053,
+    28054,
+    28055,
+    28056,
+    28057,
+    28058,
+    28059,
+    28051,
+    28061,
+    28062,
+    28062,
+    28064,
+    28065,
+    28066,
+    28067,
+    28068,
+    28069,
+    28070,
+    28071,
+    28072,
+    28073,
+    28065,
+    28075,
+    28075,
+    28077,
+    28078,
+    28079,
+    28080,
+    28081,
+    28080,
+    28083,
+    28084,
+    28085,
+    28086,
+    28087,
+    28088,
+    28065,
+    28090,
+    28091,
+    28092,
+    28093,
+    28094,
+    28094,
+    28096,
+    28097,
+    28098,
+    28099,
+    28100,
+    28100,
+    28100,
+    28103,
+    28104,
+    28105,
+    28106,
+    28106,
+    28108,
+    28108,
+    28110,
+    28111,
+    28112,
+    28113,
+    28114,
+    28115,
+    28114,
+    28117,
+    28117,
+    28119,
+    28120,
+    28120,
+    28122,
+    28123,
+    28123,
+    28122,
+    28122,
+    28127,
+    28127,
+    28129,
+    28130,
+    28131,
+    28131,
+    28133,
+    28134,
+    28134,
+    28136,
+    28136,
+    28138,
+    28139,
+    28105,
+    28141,
+    28142,
+    28143,
+    28144,
+    28145,
+    28145,
+    28147,
+    28147,
+    28147,
+    28150,
+    28151,
+    28147,
+    28153,
+    28154,
+    28154,
+    28154,
+    28157,
+    28158,
+    28159,
+    28158,
+    28161,
+    28162,
+    28162,
+    28161,
+    28165,
+    28166,
+    28167,
+    28167,
+    28169,
+    28169,
+    28169,
+    28172,
+    28172,
+    28174,
+    28175,
+    28166,
+    28177,
+    28177,
+    28179,
+    28179,
+    28177,
+    28182,
+    28183,
+    28183,
+    28185,
+    28185,
+    28187,
+    28187,
+    28189,
+    28189,
+    28182,
+    28192,
+    28193,
+    28192,
+    28192,
+    28196,
+    28192,
+    28198,
+    28198,
+    28200,
+    28200,
+    28202,
+    28203,
+    28203,
+    28198,
+    28206,
+    28198,
+    28208,
+    28209,
+    28210,
+    28211,
+    28212,
+    28212,
+    28214,
+    28212,
+    28216,
+    28217,
+    28218,
+    28218,
+    28220,
+    28217,
+    28217,
+    28223,
+    28223,
+    28210,
+    28226,
+    28226,
+    28228,
+    28228,
+    28228,
+    28231,
+    28232,
+    28228,
+    28234,
+    28235,
+    28236,
+    28237,
+    28238,
+    28239,
+    28239,
+    28241,
+    28241,
+    28243,
+    28243,
+    28245,
+    28245,
+    28247,
+    28248,
+    28248,
+    28250,
+    28250,
+    28252,
+    28252,
+    28252,
+    28255,
+    28255,
+    28255,
+    28258,
+    28258,
+    28260,
+    28261,
+    28258,
+    28263,
+    28264,
+    28264,
+    28266,
+    28267,
+    28268,
+    28268,
+    28268,
+    28271,
+    28272,
+    28273,
+    28268,
+    28275,
+    28276,
+    28276,
+    28267,
+    28272,
+    28280,
+    28280,
+    28282,
+    28282,
+    28282,
+    28285,
+    28286,
+    28287,
+    28287,
+    28287,
+    28287,
+    28291,
+    28292,
+    28293,
+    28294,
+    28292,
+    28296,
+    28297,
+    28297,
+    28297,
+    28300,
+    28300,
+    28302,
+    28302,
+    28304,
+    28304,
+    28306,
+    28307,
+    28307,
+    28309,
+    28310,
+    28310,
+    28312,
+    28312,
+    28314,
+    28314,
+    28316,
+    28317,
+    28318,
+    28318,
+    28320,
+    28321,
+    28321,
+    28321,
+    28324,
+    28324,
+    28326,
+    28327,
+    28328,
+    28329,
+    28330,
+    28331,
+    28332,
+    28333,
+    28334,
+    28334,
+    28336,
+    28337,
+    28338,
+    28339,
+    28339,
+    28341,
+    28342,
+    28342,
+    28344,
+    28345,
+    28345,
+    28347,
+    28347,
+    28349,
+    28350,
+    28351,
+    28351,
+    28353,
+    28354,
+    28355,
+    28356,
+    28357,
+    28358,
+    28358,
+    28360,
+    28361,
+    28361,
+    28363,
+    28364,
+    28364,
+    28366,
+    28367,
+    28368,
+    28369,
+    28370,
+    28371,
+    28372,
+    28372,
+    28374,
+    28374,
+    28374,
+    28377,
+    28378,
+    28379,
+    28380,
+    28381,
+    28382,
+    28383,
+    28384,
+    28385,
+    28385,
+    28385,
+    28388,
+    28389,
+    28380,
+    28391,
+    28392,
+    28392,
+    28394,
+    28395,
+    28396,
+    28397,
+    28398,
+    28398,
+    28400,
+    28401,
+    28402,
+    28403,
+    28403,
+    28405,
+    28406,
+    28407,
+    28408,
+    28409,
+    28410,
+    28411,
+    28412,
+    28413,
+    28414,
+    28414,
+    28416,
+    28417,
+    28418,
+    28419,
+    28420,
+    28420,
+    28422,
+    28423,
+    28424,
+    28424,
+    28424,
+    28427,
+    28428,
+    28429,
+    28429,
+    28429,
+    28432,
+    28433,
+    28433,
+    28435,
+    28435,
+    28437,
+    28437,
+    28437,
+    28440,
+    28433,
+    28442,
+    28443,
+    28444,
+    28445,
+    28444,
+    28447,
+    28448,
+    28449,
+    28450,
+    28451,
+    28452,
+    28452,
+    28454,
+    28455,
+    28456,
+    28457,
+    28457,
+    28459,
+    28455,
+    28461,
+    28461,
+    28463,
+    28463,
+    28465,
+    28463,
+    28467,
+    28468,
+    28469,
+    28469,
+    28454,
+    28472,
+    28472,
+    28474,
+    28475,
+    28476,
+    28477,
+    28477,
+    28479,
+    28476,
+    28481,
+    28481,
+    28483,
+    28483,
+    28483,
+    28486,
+    28487,
+    28486,
+    28489,
+    28489,
+    28486,
+    28492,
+    28492,
+    28494,
+    28494,
+    28496,
+    28497,
+    28498,
+    28498,
+    28500,
+    28501,
+    28502,
+    28503,
+    28502,
+    28505,
+    28505,
+    28507,
+    28508,
+    28508,
+    28508,
+    28511,
+    28512,
+    28513,
+    28512,
+    28515,
+    28516,
+    28516,
+    28518,
+    28518,
+    28520,
+    28520,
+    28520,
+    28520,
+    28524,
+    28525,
+    28525,
+    28525,
+    28525,
+    28529,
+    28530,
+    28530,
+    28532,
+    28533,
+    28534,
+    28533,
+    28515,
+    28537,
+    28537,
+    28539,
+    28540,
+    28541,
+    28541,
+    28541,
+    28544,
+    28545,
+    28546,
+    28546,
+    28548,
+    28545,
+    28550,
+    28550,
+    28552,
+    28550,
+    28554,
+    28555,
+    28556,
+    28554,
+    28558,
+    28559,
+    28558,
+    28561,
+    28562,
+    28563,
+    28563,
+    28565,
+    28563,
+    28567,
+    28568,
+    28568,
+    28568,
+    28571,
+    28572,
+    28573,
+    28571,
+    28571,
+    28576,
+    28577,
+    28578,
+    28561,
+    28580,
+    28581,
+    28582,
+    28583,
+    28584,
+    28585,
+    28586,
+    28587,
+    28584,
+    28589,
+    28590,
+    28591,
+    28591,
+    28591,
+    28594,
+    28594,
+    28596,
+    28597,
+    28597,
+    28599,
+    28600,
+    28600,
+    28599,
+    28603,
+    28603,
+    28605,
+    28606,
+    28607,
+    28607,
+    28606,
+    28610,
+    28610,
+    28612,
+    28613,
+    28614,
+    28614,
+    28616,
+    28616,
+    28613,
+    28619,
+    28620,
+    28620,
+    28620,
+    28623,
+    28623,
+    28623,
+    28626,
+    28627,
+    28628,
+    28629,
+    28629,
+    28629,
+    28632,
+    28633,
+    28511,
+    28635,
+    28636,
+    28637,
+    28638,
+    28639,
+    28639,
+    28638,
+    28642,
+    28643,
+    28638,
+    28645,
+    28646,
+    28647,
+    28648,
+    28649,
+    28650,
+    28651,
+    28652,
+    28652,
+    28651,
+    28655,
+    28656,
+    28656,
+    28655,
+    28659,
+    28660,
+    28660,
+    28662,
+    28662,
+    28664,
+    28664,
+    28666,
+    28667,
+    28667,
+    28669,
+    28670,
+    28670,
+    28669,
+    28673,
+    28674,
+    28675,
+    28676,
+    28677,
+    28678,
+    28678,
+    28680,
+    28681,
+    28678,
+    28683,
+    28684,
+    28683,
+    28686,
+    28686,
+    28678,
+    28689,
+    28690,
+    28691,
+    28691,
+    28691,
+    28691,
+    28689,
+    28696,
+    28696,
+    28698,
+    28699,
+    28700,
+    28698,
+    28702,
+    28703,
+    28704,
+    28705,
+    28706,
+    28703,
+    28708,
+    28709,
+    28710,
+    28711,
+    28712,
+    28713,
+    28713,
+    28713,
+    28716,
+    28717,
+    28718,
+    28719,
+    28720,
+    28721,
+    28722,
+    28723,
+    28723,
+    28723,
+    28726,
+    28727,
+    28727,
+    28729,
+    28727,
+    28731,
+    28732,
+    28733,
+    28734,
+    28735,
+    28736,
+    28737,
+    28738,
+    28739,
+    28740,
+    28741,
+    28741,
+    28741,
+    28744,
+    28745,
+    28746,
+    28747,
+    28748,
+    28749,
+    28750,
+    28751,
+    28752,
+    28753,
+    28754,
+    28755,
+    28755,
+    28755,
+    28758,
+    28759,
+    28760,
+    28760,
+    28762,
+    28763,
+    28764,
+    28764,
+    28766,
+    28766,
+    28768,
+    28769,
+    28770,
+    28771,
+    28772,
+    28772,
+    28771,
+    28775,
+    28776,
+    28776,
+    28778,
+    28779,
+    28780,
+    28781,
+    28782,
+    28783,
+    28784,
+    28785,
+    28782,
+    28787,
+    28788,
+    28789,
+    28790,
+    28790,
+    28789,
+    28793,
+    28793,
+    28795,
+    28780,
+    28797,
+    28797,
+    28799,
+    28800,
+    28801,
+    28801,
+    28801,
+    28804,
+    28805,
+    28806,
+    28807,
+    28799,
+    28809,
+    28810,
+    28811,
+    28812,
+    28812,
+    28814,
+    28815,
+    28816,
+    28817,
+    28818,
+    28819,
+    28810,
+    28821,
+    28822,
+    28822,
+    28824,
+    28825,
+    28826,
+    28827,
+    28828,
+    28828,
+    28830,
+    28825,
+    28832,
+    28833,
+    28833,
+    28835,
+    28836,
+    28837,
+    28838,
+    28839,
+    28839,
+    28841,
+    28838,
+    28843,
+    28844,
+    28844,
+    28846,
+    28844,
+    28848,
+    28849,
+    28850,
+    28844,
+    28852,
+    28853,
+    28854,
+    28854,
+    28853,
+    28857,
+    28857,
+    28859,
+    28859,
+    28857,
+    28862,
+    28863,
+    28857,
+    28857,
+    28862,
+    28867,
+    28868,
+    28868,
+    28870,
+    28867,
+    28872,
+    28873,
+    28873,
+    28875,
+    28852,
+    28877,
+    28878,
+    28879,
+    28879,
+    28879,
+    28882,
+    28883,
+    28884,
+    28885,
+    28886,
+    28886,
+    28888,
+    28886,
+    28890,
+    28891,
+    28892,
+    28893,
+    28894,
+    28895,
+    28895,
+    28897,
+    28897,
+    28899,
+    28899,
+    28901,
+    28901,
+    28899,
+    28904,
+    28905,
+    28906,
+    28906,
+    28908,
+    28909,
+    28908,
+    28911,
+    28911,
+    28908,
+    28914,
+    28915,
+    28916,
+    28917,
+    28918,
+    28919,
+    28920,
+    28921,
+    28920,
+    28923,
+    28924,
+    28924,
+    28924,
+    28923,
+    28928,
+    28929,
+    28930,
+    28931,
+    28932,
+    28931,
+    28934,
+    28935,
+    28936,
+    28936,
+    28938,
+    28939,
+    28940,
+    28940,
+    28939,
+    28943,
+    28943,
+    28945,
+    28946,
+    28946,
+    28948,
+    28949,
+    28950,
+    28951,
+    28952,
+    28938,
+    28950,
+    28905,
+    28956,
+    28957,
+    28957,
+    28959,
+    28959,
+    28961,
+    28962,
+    28962,
+    28957,
+    28961,
+    28966,
+    28967,
+    28968,
+    28969,
+    28970,
+    28970,
+    28970,
+    28973,
+    28974,
+    28974,
+    28973,
+    28977,
+    28977,
+    28979,
+    28977,
+    28981,
+    28982,
+    28982,
+    28982,
+    28982,
+    28982,
+    28987,
+    28987,
+    28989,
+    28989,
+    28991,
+    28991,
+    28991,
+    28994,
+    28995,
+    28996,
+    28997,
+    28997,
+    28996,
+    28994,
+    29001,
+    29002,
+    29003,
+    29003,
+    29005,
+    29006,
+    29006,
+    29008,
+    29009,
+    29009,
+    29006,
+    29006,
+    29008,
+    29014,
+    29014,
+    29014,
+    29006,
+    29018,
+    29018,
+    28967,
+    29021,
+    29021,
+    29023,
+    29021,
+    29025,
+    29026,
+    29027,
+    29028,
+    29028,
+    29028,
+    29031,
+    29031,
+    29033,
+    29034,
+    29035,
+    29036,
+    29037,
+    29034,
+    29039,
+    29040,
+    29041,
+    29034,
+    29043,
+    29044,
+    29045,
+    29046,
+    29047,
+    29048,
+    29049,
+    29050,
+    29050,
+    29052,
+    29052,
+    29054,
+    29055,
+    29056,
+    29056,
+    29058,
+    29058,
+    29060,
+    29060,
+    29062,
+    29062,
+    29064,
+    29065,
+    29049,
+    29067,
+    29068,
+    29068,
+    29068,
+    29068,
+    29072,
+    29072,
+    29074,
+    29075,
+    29074,
+    29077,
+    29077,
+    29079,
+    29080,
+    29080,
+    29082,
+    29082,
+    29084,
+    29079,
+    29086,
+    29086,
+    29088,
+    29089,
+    29090,
+    29091,
+    29092,
+    29093,
+    29093,
+    29095,
+    29095,
+    29097,
+    29098,
+    29098,
+    29100,
+    29101,
+    29102,
+    29103,
+    29103,
+    29105,
+    29105,
+    29107,
+    29108,
+    29109,
+    29110,
+    29110,
+    29112,
+    29113,
+    29113,
+    29115,
+    29115,
+    29117,
+    29118,
+    29119,
+    29120,
+    29117,
+    29122,
+    29123,
+    29123,
+    29122,
+    29126,
+    29127,
+    29128,
+    29129,
+    29128,
+    29131,
+    29132,
+    29133,
+    29133,
+    29135,
+    29135,
+    29137,
+    29138,
+    29139,
+    29140,
+    29139,
+    29142,
+    29143,
+    29144,
+    29144,
+    29146,
+    29146,
+    29146,
+    29149,
+    29150,
+    29150,
+    29138,
+    29153,
+    29154,
+    29154,
+    29156,
+    29157,
+    29156,
+    29159,
+    29159,
+    29161,
+    29161,
+    29163,
+    29164,
+    29164,
+    29166,
+    29167,
+    29167,
+    29169,
+    29169,
+    29171,
+    29171,
+    29173,
+    29173,
+    29175,
+    29175,
+    29177,
+    29178,
+    29178,
+    29177,
+    29181,
+    29181,
+    29183,
+    29184,
+    29184,
+    29186,
+    29187,
+    29187,
+    29189,
+    29190,
+    29191,
+    29187,
+    29193,
+    29194,
+    29187,
+    29196,
+    29197,
+    29198,
+    29199,
+    29200,
+    29200,
+    29199,
+    29197,
+    29204,
+    29205,
+    29206,
+    29206,
+    29208,
+    29205,
+    29210,
+    29210,
+    29212,
+    29213,
+    29213,
+    29213,
+    29216,
+    29216,
+    29218,
+    29218,
+    29218,
+    29221,
+    29222,
+    29223,
+    29224,
+    29212,
+    29226,
+    29226,
+    29228,
+    29228,
+    29226,
+    29231,
+    29232,
+    29233,
+    29226,
+    29235,
+    29236,
+    29237,
+    29238,
+    29239,
+    29240,
+    29241,
+    29242,
+    29243,
+    29240,
+    29245,
+    29246,
+    29246,
+    29248,
+    29248,
+    29250,
+    29250,
+    29252,
+    29253,
+    29253,
+    29255,
+    29255,
+    29257,
+    29257,
+    29259,
+    29259,
+    29257,
+    29248,
+    29263,
+    29264,
+    29264,
+    29266,
+    29267,
+    29267,
+    29269,
+    29269,
+    29271,
+    29271,
+    29273,
+    29273,
+    29266,
+    29276,
+    29276,
+    29278,
+    29279,
+    29279,
+    29281,
+    29279,
+    29283,
+    29284,
+    29285,
+    29286,
+    29286,
+    29288,
+    29289,
+    29288,
+    29284,
+    29292,
+    29292,
+    29292,
+    29295,
+    29296,
+    29297,
+    29298,
+    29296,
+    29300,
+    29301,
+    29301,
+    29303,
+    29303,
+    29305,
+    29305,
+    29307,
+    29308,
+    29309,
+    29310,
+    29310,
+    29312,
+    29313,
+    29314,
+    29314,
+    29316,
+    29317,
+    29317,
+    29319,
+    29319,
+    29321,
+    29321,
+    29323,
+    29324,
+    29325,
+    29326,
+    29326,
+    29328,
+    29328,
+    29330,
+    29330,
+    29332,
+    29332,
+    29334,
+    29335,
+    29335,
+    29337,
+    29338,
+    29339,
+    29334,
+    29341,
+    29342,
+    29343,
+    29344,
+    29344,
+    29346,
+    29347,
+    29346,
+    29349,
+    29349,
+    29351,
+    29351,
+    29349,
+    29354,
+    29354,
+    29356,
+    29357,
+    29358,
+    29359,
+    29360,
+    29360,
+    29362,
+    29363,
+    29363,
+    29365,
+    29365,
+    29367,
+    29367,
+    29356,
+    29370,
+    29371,
+    29372,
+    29373,
+    29373,
+    29375,
+    29375,
+    29372,
+    29378,
+    29378,
+    29371,
+    29381,
+    29382,
+    29383,
+    29384,
+    29383,
+    29386,
+    29387,
+    29387,
+    29387,
+    29390,
+    29390,
+    29390,
+    29393,
+    29394,
+    29394,
+    29394,
+    29397,
+    29398,
+    29399,
+    29399,
+    29401,
+    29398,
+    29403,
+    29403,
+    29405,
+    29406,
+    29406,
+    29408,
+    29409,
+    29410,
+    29411,
+    29412,
+    29413,
+    29413,
+    29412,
+    29416,
+    29417,
+    29417,
+    29417,
+    29420,
+    29420,
+    29420,
+    29423,
+    29424,
+    29425,
+    29425,
+    29427,
+    29428,
+    29429,
+    29427,
+    29431,
+    29431,
+    29433,
+    29433,
+    29431,
+    29436,
+    29437,
+    29431,
+    29439,
+    29440,
+    29440,
+    29442,
+    29443,
+    29444,
+    29445,
+    29442,
+    29442,
+    29448,
+    29448,
+    29450,
+    29451,
+    29448,
+    29453,
+    29454,
+    29455,
+    29455,
+    29455,
+    29458,
+    29459,
+    29460,
+    29461,
+    29448,
+    29448,
+    29464,
+    29464,
+    29466,
+    29467,
+    29468,
+    29468,
+    29470,
+    29470,
+    29470,
+    29473,
+    29474,
+    29474,
+    29468,
+    29477,
+    29478,
+    29468,
+    29480,
+    29480,
+    29482,
+    29482,
+    29484,
+    29485,
+    29486,
+    29487,
+    29488,
+    29488,
+    29490,
+    29491,
+    29491,
+    29493,
+    29493,
+    29495,
+    29496,
+    29493,
+    29498,
+    29499,
+    29499,
+    29501,
+    29502,
+    29502,
+    29504,
+    29504,
+    29506,
+    29506,
+    29485,
+    29509,
+    29509,
+    29509,
+    29509,
+    29509,
+    29514,
+    29515,
+    29516,
+    29516,
+    29518,
+    29516,
+    29520,
+    29520,
+    29522,
+    29523,
+    29523,
+    29523,
+    29515,
+    29527,
+    29527,
+    29529,
+    29529,
+    29531,
+    29532,
+    29532,
+    29534,
+    29535,
+    29535,
+    29537,
+    29537,
+    29539,
+    29540,
+    29540,
+    29542,
+    29539,
+    29544,
+    29539,
+    29546,
+    29546,
+    29548,
+    29549,
+    29549,
+    29548,
+    29552,
+    29552,
+    29548,
+    29555,
+    29556,
+    29557,
+    29534,
+    29559,
+    29559,
+    29561,
+    29561,
+    29563,
+    29563,
+    29565,
+    29565,
+    29565,
+    29568,
+    29565,
+    29568,
+    29571,
+    29572,
+    29573,
+    29573,
+    29575,
+    29575,
+    29577,
+    29577,
+    29577,
+    29577,
+    29581,
+    29582,
+    29582,
+    29582,
+    29582,
+    29582,
+    29587,
+    29587,
+    29589,
+    29589,
+    29591,
+    29591,
+    29593,
+    29593,
+    29595,
+    29596,
+    29596,
+    29598,
+    29599,
+    29599,
+    29595,
+    29602,
+    29602,
+    29604,
+    29605,
+    29606,
+    29607,
+    29608,
+    29608,
+    29610,
+    29610,
+    29608,
+    29613,
+    29613,
+    29615,
+    29615,
+    29608,
+    29581,
+    29619,
+    29620,
+    29620,
+    29622,
+    29623,
+    29624,
+    29625,
+    29625,
+    29627,
+    29627,
+    29627,
+    29630,
+    29631,
+    29631,
+    29633,
+    29634,
+    29635,
+    29636,
+    29637,
+    29638,
+    29639,
+    29640,
+    29640,
+    29642,
+    29638,
+    29635,
+    29636,
+    29646,
+    29647,
+    29647,
+    29647,
+    29650,
+    29646,
+    29652,
+    29653,
+    29654,
+    29654,
+    29653,
+    29657,
+    29657,
+    29659,
+    29660,
+    29661,
+    29661,
+    29661,
+    29664,
+    29657,
+    29666,
+    29666,
+    29668,
+    29668,
+    29670,
+    29670,
+    29670,
+    29673,
+    29674,
+    29674,
+    29674,
+    29677,
+    29677,
+    29679,
+    29679,
+    29681,
+    29682,
+    29683,
+    29684,
+    29682,
+    29686,
+    29686,
+    29674,
+    29689,
+    29673,
+    29691,
+    29692,
+    29693,
+    29694,
+    29694,
+    29696,
+    29697,
+    29698,
+    29699,
+    29694,
+    29701,
+    29701,
+    29701,
+    29704,
+    29705,
+    29706,
+    29707,
+    29707,
+    29709,
+    29709,
+    29711,
+    29711,
+    29709,
+    29714,
+    29714,
+    29716,
+    29717,
+    29718,
+    29719,
+    29719,
+    29717,
+    29722,
+    29723,
+    29723,
+    29709,
+    29709,
+    29727,
+    29728,
+    29728,
+    29728,
+    29727,
+    29732,
+    29733,
+    29734,
+    29734,
+    29733,
+    29737,
+    29737,
+    29706,
+    29740,
+    29740,
+    29742,
+    29743,
+    29744,
+    29745,
+    29745,
+    29747,
+    29748,
+    29749,
+    29744,
+    29751,
+    29752,
+    29753,
+    29753,
+    29755,
+    29756,
+    29755,
+    29755,
+    29756,
+    29755,
+    29761,
+    29762,
+    29763,
+    29761,
+    29753,
+    29766,
+    29767,
+    29768,
+    29768,
+    29770,
+    29771,
+    29771,
+    29771,
+    29753,
+    29775,
+    29753,
+    29770,
+    29778,
+    29778,
+    29780,
+    29752,
+    29782,
+    29783,
+    29784,
+    29785,
+    29785,
+    29787,
+    29787,
+    29789,
+    29790,
+    29791,
+    29791,
+    29782,
+    29794,
+    29794,
+    29794,
+    29794,
+    29798,
+    29798,
+    29798,
+    29801,
+    29802,
+    29803,
+    29804,
+    29804,
+    29798,
+    29807,
+    29807,
+    29807,
+    29807,
+    29811,
+    29811,
+    29813,
+    29813,
+    29815,
+    29815,
+    29813,
+    29818,
+    29819,
+    29818,
+    29818,
+    29822,
+    29823,
+    29823,
+    29825,
+    29825,
+    29825,
+    29828,
+    29828,
+    29818,
+    29831,
+    29831,
+    29833,
+    29833,
+    29835,
+    29835,
+    29837,
+    29838,
+    29838,
+    29840,
+    29841,
+    29842,
+    29843,
+    29843,
+    29837,
+    29846,
+    29847,
+    29848,
+    29847,
+    29850,
+    29851,
+    29851,
+    29853,
+    29853,
+    29853,
+    29856,
+    29857,
+    29858,
+    29859,
+    29860,
+    29860,
+    29860,
+    29863,
+    29864,
+    29864,
+    29866,
+    29867,
+    29868,
+    29869,
+    29869,
+    29871,
+    29871,
+    29873,
+    29873,
+    29875,
+    29876,
+    29877,
+    29877,
+    29879,
+    29879,
+    29881,
+    29881,
+    29883,
+    29884,
+    29884,
+    29863,
+    29887,
+    29887,
+    29889,
+    29890,
+    29891,
+    29892,
+    29892,
+    29894,
+    29894,
+    29894,
+    29897,
+    29898,
+    29898,
+    29900,
+    29900,
+    29902,
+    29902,
+    29904,
+    29904,
+    29906,
+    29907,
+    29907,
+    29906,
+    29910,
+    29910,
+    29912,
+    29912,
+    29910,
+    29915,
+    29915,
+    29917,
+    29917,
+    29919,
+    29919,
+    29921,
+    29922,
+    29923,
+    29923,
+    29923,
+    29926,
+    29926,
+    29926,
+    29929,
+    29930,
+    29931,
+    29932,
+    29932,
+    29934,
+    29934,
+    29936,
+    29936,
+    29936,
+    29939,
+    29939,
+    29941,
+    29942,
+    29942,
+    29944,
+    29945,
+    29945,
+    29944,
+    29948,
+    29949,
+    29950,
+    29948,
+    29897,
+    29953,
+    29954,
+    29955,
+    29956,
+    29957,
+    29957,
+    29959,
+    29959,
+    29961,
+    29962,
+    29963,
+    29964,
+    29965,
+    29965,
+    29967,
+    29968,
+    29968,
+    29970,
+    29970,
+    29968,
+    29973,
+    29973,
+    29975,
+    29976,
+    29976,
+    29978,
+    29978,
+    29980,
+    29980,
+    29982,
+    29982,
+    29984,
+    29984,
+    29986,
+    29986,
+    29988,
+    29989,
+    29990,
+    29990,
+    29989,
+    29993,
+    29994,
+    29994,
+    29996,
+    29997,
+    29998,
+    29999,
+    30000,
+    30001,
+    30001,
+    30001,
+    30000,
+    30005,
+    30005,
+    30000,
+    30008,
+    30009,
+    30009,
+    30011,
+    30011,
+    30013,
+    30013,
+    30015,
+    29967,
+    30017,
+    30018,
+    30019,
+    30020,
+    30020,
+    30022,
+    30022,
+    30024,
+    30024,
+    30026,
+    30027,
+    30028,
+    30028,
+    30030,
+    30031,
+    30031,
+    30033,
+    30034,
+    30035,
+    30036,
+    30036,
+    30038,
+    30038,
+    30040,
+    30040,
+    30042,
+    30042,
+    30044,
+    30045,
+    30045,
+    30047,
+    30048,
+    30048,
+    30050,
+    30050,
+    30050,
+    30053,
+    30053,
+    30055,
+    30053,
+    30057,
+    30057,
+    30059,
+    30059,
+    30061,
+    30061,
+    30063,
+    30063,
+    30063,
+    30066,
+    30067,
+    30067,
+    30033,
+    30070,
+    30071,
+    30072,
+    30072,
+    30074,
+    30075,
+    30076,
+    30077,
+    30078,
+    30078,
+    30080,
+    30081,
+    30082,
+    30082,
+    30084,
+    30085,
+    30086,
+    30086,
+    30088,
+    30088,
+    30090,
+    30091,
+    30092,
+    30091,
+    30094,
+    30095,
+    30095,
+    30094,
+    30098,
+    30099,
+    30099,
+    30101,
+    30102,
+    30103,
+    30104,
+    30105,
+    30106,
+    30106,
+    30105,
+    30081,
+    30110,
+    30111,
+    30112,
+    30113,
+    30114,
+    30114,
+    30116,
+    30116,
+    30118,
+    30113,
+    30120,
+    30121,
+    30122,
+    30122,
+    30124,
+    30125,
+    30125,
+    30127,
+    30127,
+    30129,
+    30130,
+    30130,
+    30132,
+    30133,
+    30133,
+    30135,
+    30135,
+    30132,
+    30121,
+    30139,
+    30140,
+    30140,
+    30142,
+    30142,
+    30144,
+    30144,
+    30146,
+    30147,
+    30147,
+    30149,
+    30149,
+    30151,
+    30152,
+    30153,
+    30153,
+    30155,
+    30156,
+    30157,
+    30157,
+    30159,
+    30160,
+    30161,
+    30160,
+    30161,
+    30164,
+    30164,
+    30164,
+    30167,
+    30168,
+    30168,
+    30170,
+    30170,
+    30172,
+    30172,
+    30174,
+    30174,
+    30176,
+    30177,
+    30178,
+    30179,
+    30179,
+    30179,
+    30182,
+    30182,
+    30184,
+    30185,
+    30185,
+    30185,
+    30188,
+    30188,
+    30188,
+    30191,
+    30192,
+    30192,
+    30194,
+    30195,
+    30195,
+    30197,
+    30197,
+    30199,
+    30200,
+    30201,
+    30201,
+    30200,
+    30204,
+    30204,
+    30206,
+    30206,
+    30208,
+    30209,
+    30210,
+    30209,
+    30212,
+    30212,
+    30214,
+    30214,
+    30216,
+    30217,
+    30218,
+    30219,
+    30219,
+    30219,
+    30222,
+    30222,
+    30222,
+    30225,
+    30225,
+    30227,
+    30228,
+    30228,
+    30230,
+    30194,
+    30232,
+    30232,
+    30234,
+    30235,
+    30235,
+    30237,
+    30238,
+    30239,
+    30239,
+    30241,
+    30242,
+    30242,
+    30244,
+    30245,
+    30245,
+    30247,
+    30234,
+    30249,
+    30250,
+    30250,
+    30249,
+    30253,
+    30253,
+    30255,
+    30256,
+    30257,
+    30258,
+    30258,
+    30260,
+    30260,
+    30257,
+    30263,
+    30264,
+    30265,
+    30265,
+    30265,
+    30268,
+    30269,
+    30269,
+    30271,
+    30272,
+    30273,
+    30274,
+    30275,
+    30275,
+    30277,
+    30278,
+    30278,
+    30278,
+    30281,
+    30281,
+    30283,
+    30284,
+    30284,
+    30286,
+    30286,
+    30288,
+    30289,
+    30289,
+    30289,
+    30292,
+    30293,
+    30293,
+    30292,
+    30296,
+    30296,
+    30298,
+    30298,
+    30300,
+    30301,
+    30301,
+    30301,
+    30304,
+    30305,
+    30305,
+    30305,
+    30308,
+    30308,
+    30310,
+    30311,
+    30311,
+    30313,
+    30310,
+    30315,
+    30316,
+    30317,
+    30318,
+    30319,
+    30319,
+    30263,
+    30322,
+    30323,
+    30322,
+    30325,
+    30326,
+    30326,
+    30328,
+    30329,
+    30330,
+    30331,
+    30332,
+    30333,
+    30333,
+    30335,
+    30335,
+    30337,
+    30337,
+    30339,
+    30340,
+    30341,
+    30342,
+    30343,
+    30344,
+    30345,
+    30345,
+    30347,
+    30348,
+    30348,
+    30350,
+    30351,
+    30352,
+    30353,
+    30354,
+    30355,
+    30356,
+    30357,
+    30358,
+    30359,
+    30360,
+    30360,
+    30362,
+    30363,
+    30364,
+    30365,
+    30366,
+    30367,
+    30368,
+    30369,
+    30370,
+    30371,
+    30372,
+    30373,
+    30374,
+    30374,
+    30376,
+    30377,
+    30378,
+    30379,
+    30380,
+    30381,
+    30382,
+    30382,
+    30384,
+    30385,
+    30386,
+    30387,
+    30388,
+    30389,
+    30390,
+    30391,
+    30392,
+    30392,
+    30394,
+    30394,
+    30396,
+    30390,
+    30398,
+    30399,
+    30399,
+    30401,
+    30402,
+    30402,
+    30404,
+    30405,
+    30406,
+    30407,
+    30407,
+    30409,
+    30410,
+    30411,
+    30412,
+    30412,
+    30414,
+    30414,
+    30416,
+    30414,
+    30418,
+    30418,
+    30420,
+    30420,
+    30422,
+    30422,
+    30424,
+    30425,
+    30426,
+    30426,
+    30428,
+    30428,
+    30428,
+    30431,
+    30431,
+    30433,
+    30434,
+    30435,
+    30436,
+    30437,
+    30437,
+    30436,
+    30440,
+    30440,
+    30442,
+    30442,
+    30444,
+    30445,
+    30445,
+    30411,
+    30448,
+    30449,
+    30450,
+    30451,
+    30452,
+    30453,
+    30454,
+    30455,
+    30456,
+    30456,
+    30456,
+    30459,
+    30460,
+    30461,
+    30461,
+    30463,
+    30464,
+    30465,
+    30466,
+    30466,
+    30466,
+    30469,
+    30454,
+    30471,
+    30472,
+    30473,
+    30474,
+    30475,
+    30476,
+    30476,
+    30478,
+    30478,
+    30480,
+    30481,
+    30481,
+    30480,
+    30484,
+    30485,
+    30473,
+    30487,
+    30488,
+    30489,
+    30490,
+    30491,
+    30491,
+    30490,
+    30494,
+    30495,
+    30496,
+    30497,
+    30498,
+    30499,
+    30500,
+    30500,
+    30502,
+    30502,
+    30504,
+    30505,
+    30505,
+    30507,
+    30508,
+    30509,
+    30510,
+    30510,
+    30512,
+    30513,
+    30514,
+    30509,
+    30516,
+    30517,
+    30518,
+    30519,
+    30520,
+    30520,
+    30522,
+    30523,
+    30523,
+    30525,
+    30526,
+    30527,
+    30528,
+    30529,
+    30529,
+    30528,
+    30532,
+    30533,
+    30533,
+    30535,
+    30536,
+    30537,
+    30538,
+    30535,
+    30540,
+    30541,
+    30527,
+    30543,
+    30544,
+    30545,
+    30546,
+    30546,
+    30546,
+    30546,
+    30545,
+    30551,
+    30552,
+    30552,
+    30552,
+    30552,
+    30556,
+    30557,
+    30557,
+    30559,
+    30560,
+    30560,
+    30562,
+    30562,
+    30564,
+    30565,
+    30566,
+    30566,
+    30568,
+    30568,
+    30570,
+    30571,
+    30564,
+    30573,
+    30574,
+    30575,
+    30576,
+    30575,
+    30578,
+    30578,
+    30574,
+    30581,
+    30573,
+    30583,
+    30583,
+    30585,
+    30586,
+    30587,
+    30587,
+    30573,
+    30590,
+    30591,
+    30591,
+    30591,
+    30594,
+    30595,
+    30596,
+    30596,
+    30595,
+    30599,
+    30600,
+    30601,
+    30601,
+    30603,
+    30604,
+    30605,
+    30605,
+    30607,
+    30601,
+    30609,
+    30610,
+    30611,
+    30612,
+    30613,
+    30614,
+    30615,
+    30616,
+    30617,
+    30616,
+    30619,
+    30620,
+    30620,
+    30622,
+    30622,
+    30619,
+    30625,
+    30625,
+    30627,
+    30627,
+    30629,
+    30630,
+    30630,
+    30614,
+    30633,
+    30633,
+    30635,
+    30635,
+    30637,
+    30638,
+    30639,
+    30640,
+    30641,
+    30641,
+    30643,
+    30641,
+    30637,
+    30646,
+    30646,
+    30646,
+    30649,
+    30650,
+    30650,
+    30652,
+    30653,
+    30652,
+    30655,
+    30656,
+    30657,
+    30658,
+    30652,
+    30660,
+    30661,
+    30649,
+    30663,
+    30664,
+    30665,
+    30665,
+    30665,
+    30668,
+    30669,
+    30670,
+    30671,
+    30671,
+    30671,
+    30674,
+    30674,
+    30676,
+    30677,
+    30678,
+    30679,
+    30679,
+    30679,
+    30669,
+    30683,
+    30684,
+    30684,
+    30686,
+    30687,
+    30688,
+    30688,
+    30690,
+    30691,
+    30691,
+    30693,
+    30694,
+    30694,
+    30696,
+    30696,
+    30693,
+    30699,
+    30700,
+    30700,
+    30702,
+    30693,
+    30704,
+    30704,
+    30706,
+    30707,
+    30706,
+    30687,
+    30710,
+    30711,
+    30711,
+    30713,
+    30713,
+    30715,
+    30715,
+    30715,
+    30718,
+    30719,
+    30719,
+    30721,
+    30722,
+    30722,
+    30719,
+    30725,
+    30726,
+    30727,
+    30727,
+    30718,
+    30730,
+    30730,
+    30732,
+    30733,
+    30734,
+    30735,
+    30736,
+    30736,
+    30738,
+    30739,
+    30740,
+    30741,
+    30742,
+    30742,
+    30741,
+    30745,
+    30745,
+    30747,
+    30747,
+    30749,
+    30750,
+    30751,
+    30751,
+    30753,
+    30753,
+    30753,
+    30750,
+    30757,
+    30757,
+    30759,
+    30760,
+    30757,
+    30762,
+    30762,
+    30764,
+    30764,
+    30766,
+    30766,
+    30768,
+    30769,
+    30769,
+    30771,
+    30772,
+    30772,
+    30774,
+    30775,
+    30775,
+    30775,
+    30775,
+    30779,
+    30780,
+    30780,
+    30779,
+    30783,
+    30783,
+    30783,
+    30783,
+    30783,
+    30788,
+    30788,
+    30790,
+    30790,
+    30790,
+    30793,
+    30794,
+    30794,
+    30796,
+    30796,
+    30796,
+    30799,
+    30796,
+    30799,
+    30802,
+    30802,
+    30793,
+    30805,
+    30806,
+    30807,
+    30808,
+    30809,
+    30810,
+    30810,
+    30812,
+    30813,
+    30814,
+    30813,
+    30816,
+    30816,
+    30812,
+    30819,
+    30819,
+    30819,
+    30822,
+    30823,
+    30824,
+    30812,
+    30826,
+    30827,
+    30827,
+    30812,
+    30830,
+    30831,
+    30832,
+    30808,
+    30834,
+    30835,
+    30836,
+    30837,
+    30836,
+    30835,
+    30840,
+    30841,
+    30842,
+    30843,
+    30843,
+    30834,
+    30846,
+    30847,
+    30848,
+    30849,
+    30849,
+    30851,
+    30848,
+    30853,
+    30853,
+    30853,
+    30856,
+    30857,
+    30858,
+    30859,
+    30859,
+    30859,
+    30862,
+    30863,
+    30863,
+    30865,
+    30866,
+    30867,
+    30867,
+    30869,
+    30870,
+    30870,
+    30869,
+    30873,
+    30874,
+    30875,
+    30875,
+    30877,
+    30877,
+    30879,
+    30879,
+    30879,
+    30879,
+    30883,
+    30884,
+    30885,
+    30885,
+    30887,
+    30887,
+    30889,
+    30890,
+    30891,
+    30891,
+    30891,
+    30894,
+    30894,
+    30896,
+    30896,
+    30896,
+    30890,
+    30900,
+    30900,
+    30884,
+    30903,
+    30903,
+    30905,
+    30905,
+    30905,
+    30908,
+    30909,
+    30905,
+    30883,
+    30912,
+    30913,
+    30914,
+    30915,
+    30915,
+    30912,
+    30918,
+    30918,
+    30920,
+    30921,
+    30921,
+    30923,
+    30924,
+    30925,
+    30924,
+    30924,
+    30928,
+    30928,
+    30930,
+    30930,
+    30932,
+    30933,
+    30934,
+    30924,
+    30924,
+    30937,
+    30938,
+    30938,
+    30940,
+    30940,
+    30940,
+    30943,
+    30944,
+    30945,
+    30946,
+    30947,
+    30948,
+    30949,
+    30946,
+    30951,
+    30952,
+    30953,
+    30954,
+    30955,
+    30956,
+    30957,
+    30957,
+    30946,
+    30960,
+    30961,
+    30962,
+    30963,
+    30955,
+    30965,
+    30966,
+    30967,
+    30966,
+    30969,
+    30970,
+    30970,
+    30972,
+    30972,
+    30974,
+    30974,
+    30976,
+    30976,
+    30965,
+    30979,
+    30980,
+    30981,
+    30981,
+    30983,
+    30984,
+    30984,
+    30986,
+    30986,
+    30988,
+    30989,
+    30989,
+    30988,
+    30992,
+    30992,
+    30992,
+    30995,
+    30996,
+    30996,
+    30998,
+    30995,
+    31000,
+    31000,
+    31002,
+    31003,
+    31004,
+    31005,
+    31006,
+    31006,
+    30995,
+    31004,
+    31010,
+    31011,
+    31011,
+    31013,
+    31010,
+    31015,
+    31015,
+    31017,
+    31018,
+    31019,
+    31020,
+    31021,
+    31021,
+    31020,
+    31024,
+    31025,
+    31026,
+    31026,
+    31028,
+    31019,
+    31030,
+    31031,
+    31031,
+    31033,
+    31034,
+    31035,
+    31031,
+    31031,
+    31038,
+    31039,
+    31040,
+    31040,
+    31040,
+    31043,
+    31044,
+    31044,
+    31044,
+    31047,
+    31048,
+    31048,
+    31048,
+    31051,
+    31048,
+    31053,
+    31053,
+    31055,
+    31055,
+    31057,
+    31058,
+    31057,
+    31048,
+    31047,
+    31062,
+    31063,
+    31063,
+    31065,
+    31063,
+    31063,
+    31068,
+    31063,
+    31070,
+    31071,
+    31072,
+    31072,
+    31074,
+    31075,
+    31076,
+    31074,
+    31078,
+    31079,
+    31070,
+    31081,
+    31081,
+    31081,
+    31084,
+    31084,
+    31070,
+    31070,
+    31070,
+    31089,
+    31090,
+    31090,
+    31089,
+    31093,
+    31094,
+    31095,
+    31093,
+    31097,
+    31098,
+    31099,
+    31100,
+    31100,
+    31102,
+    31103,
+    31104,
+    31104,
+    31106,
+    31106,
+    31108,
+    31109,
+    31109,
+    31111,
+    31111,
+    31108,
+    31114,
+    31114,
+    31116,
+    31117,
+    31118,
+    31118,
+    31120,
+    31121,
+    31121,
+    31123,
+    31099,
+    31114,
+    31114,
+    31127,
+    31128,
+    31129,
+    31129,
+    31129,
+    31129,
+    31129,
+    31134,
+    31134,
+    31134,
+    31137,
+    31137,
+    31137,
+    31137,
+    31134,
+    31142,
+    31142,
+    31128,
+    31145,
+    31145,
+    31147,
+    31148,
+    31149,
+    31149,
+    31151,
+    31152,
+    31151,
+    31154,
+    31155,
+    31156,
+    31157,
+    31158,
+    31157,
+    31160,
+    31161,
+    31147,
+    31163,
+    31164,
+    31163,
+    31163,
+    31167,
+    31168,
+    31169,
+    31170,
+    31171,
+    31172,
+    31172,
+    31174,
+    31174,
+    31176,
+    31177,
+    31177,
+    31179,
+    31180,
+    31181,
+    31182,
+    31167,
+    31184,
+    31185,
+    31186,
+    31186,
+    31186,
+    31184,
+    31190,
+    31184,
+    31192,
+    31192,
+    31194,
+    31194,
+    31194,
+    31197,
+    31194,
+    31194,
+    31200,
+    31201,
+    31202,
+    31202,
+    31204,
+    31205,
+    31205,
+    31205,
+    31205,
+    31209,
+    31209,
+    31209,
+    31209,
+    31209,
+    31214,
+    31215,
+    31216,
+    31217,
+    31217,
+    31216,
+    31220,
+    31221,
+    31222,
+    31222,
+    31222,
+    31225,
+    31226,
+    31227,
+    31227,
+    31229,
+    31229,
+    31229,
+    31232,
+    31229,
+    31234,
+    31235,
+    31235,
+    31227,
+    31201,
+    31239,
+    31240,
+    31240,
+    31242,
+    31243,
+    31244,
+    31244,
+    31246,
+    31246,
+    31243,
+    31249,
+    31249,
+    31249,
+    31252,
+    31252,
+    31252,
+    31255,
+    31256,
+    31256,
+    31256,
+    31259,
+    31259,
+    31239,
+    31262,
+    31262,
+    31264,
+    31265,
+    31262,
+    31267,
+    31268,
+    31268,
+    31270,
+    31271,
+    31271,
+    31273,
+    31274,
+    31275,
+    31275,
+    31275,
+    31278,
+    31279,
+    31280,
+    31281,
+    31282,
+    31283,
+    31284,
+    31283,
+    31286,
+    31287,
+    31288,
+    31289,
+    31283,
+    31291,
+    31291,
+    31291,
+    31294,
+    31295,
+    31296,
+    31297,
+    31296,
+    31299,
+    31299,
+    31301,
+    31301,
+    31295,
+    31304,
+    31305,
+    31305,
+    31304,
+    31308,
+    31308,
+    31310,
+    31311,
+    31311,
+    31311,
+    31311,
+    31315,
+    31315,
+    31317,
+    31317,
+    31319,
+    31319,
+    31321,
+    31322,
+    31322,
+    31324,
+    31325,
+    31325,
+    31325,
+    31328,
+    31328,
+    31330,
+    31331,
+    31332,
+    31333,
+    31334,
+    31335,
+    31336,
+    31336,
+    31338,
+    31339,
+    31339,
+    31339,
+    31342,
+    31343,
+    31344,
+    31345,
+    31346,
+    31347,
+    31346,
+    31349,
+    31350,
+    31346,
+    31352,
+    31353,
+    31354,
+    31354,
+    31356,
+    31354,
+    31358,
+    31359,
+    31360,
+    31361,
+    31362,
+    31363,
+    31364,
+    31365,
+    31366,
+    31367,
+    31368,
+    31369,
+    31370,
+    31371,
+    31372,
+    31372,
+    31374,
+    31375,
+    31376,
+    31377,
+    31378,
+    31379,
+    31380,
+    31381,
+    31382,
+    31383,
+    31384,
+    31385,
+    31386,
+    31387,
+    31388,
+    31389,
+    31390,
+    31391,
+    31392,
+    31393,
+    31393,
+    31395,
+    31396,
+    31397,
+    31398,
+    31398,
+    31400,
+    31401,
+    31400,
+    31403,
+    31404,
+    31405,
+    31406,
+    31407,
+    31408,
+    31409,
+    31410,
+    31411,
+    31412,
+    31413,
+    31414,
+    31415,
+    31416,
+    31417,
+    31418,
+    31419,
+    31420,
+    31421,
+    31422,
+    31423,
+    31424,
+    31425,
+    31426,
+    31427,
+    31428,
+    31429,
+    31430,
+    31431,
+    31432,
+    31433,
+    31434,
+    31435,
+    31434,
+    31437,
+    31438,
+    31439,
+    31440,
+    31441,
+    31442,
+    31434,
+    31444,
+    31445,
+    31446,
+    31447,
+    31448,
+    31449,
+    31450,
+    31451,
+    31452,
+    31452,
+    31454,
+    31452,
+    31456,
+    31456,
+    31458,
+    31456,
+    31460,
+    31460,
+    31460,
+    31463,
+    31464,
+    31465,
+    31466,
+    31467,
+    31468,
+    31469,
+    31470,
+    31471,
+    31472,
+    31473,
+    31474,
+    31475,
+    31476,
+    31477,
+    31478,
+    31479,
+    31480,
+    31481,
+    31482,
+    31483,
+    31484,
+    31484,
+    31486,
+    31484,
+    31488,
+    31489,
+    31490,
+    31491,
+    31492,
+    31493,
+    31494,
+    31495,
+    31496,
+    31497,
+    31498,
+    31499,
+    31500,
+    31501,
+    31502,
+    31503,
+    31504,
+    31505,
+    31506,
+    31507,
+    31508,
+    31508,
+    31510,
+    31511,
+    31512,
+    31513,
+    31514,
+    31515,
+    31516,
+    31517,
+    31518,
+    31519,
+    31520,
+    31521,
+    31522,
+    31523,
+    31524,
+    31525,
+    31526,
+    31527,
+    31528,
+    31529,
+    31530,
+    31531,
+    31532,
+    31533,
+    31534,
+    31535,
+    31536,
+    31537,
+    31538,
+    31539,
+    31540,
+    31541,
+    31542,
+    31543,
+    31544,
+    31545,
+    31546,
+    31547,
+    31548,
+    31549,
+    31550,
+    31551,
+    31552,
+    31553,
+    31554,
+    31555,
+    31556,
+    31557,
+    31558,
+    31559,
+    31560,
+    31561,
+    31562,
+    31563,
+    31564,
+    31565,
+    31566,
+    31567,
+    31568,
+    31568,
+    31570,
+    31571,
+    31572,
+    31572,
+    31568,
+    31575,
+    31576,
+    31577,
+    31578,
+    31579,
+    31580,
+    31581,
+    31582,
+    31583,
+    31584,
+    31585,
+    31586,
+    31587,
+    31586,
+    31589,
+    31590,
+    31591,
+    31592,
+    31593,
+    31586,
+    31595,
+    31596,
+    31597,
+    31598,
+    31599,
+    31600,
+    31601,
+    31602,
+    31603,
+    31604,
+    31605,
+    31606,
+    31606,
+    31606,
+    31609,
+    31610,
+    31611,
+    31612,
+    31612,
+    31614,
+    31615,
+    31616,
+    31617,
+    31612,
+    31619,
+    31620,
+    31621,
+    31622,
+    31622,
+    31624,
+    31622,
+    31626,
+    31627,
+    31627,
+    31629,
+    31627,
+    31631,
+    31632,
+    31633,
+    31634,
+    31633,
+    31636,
+    31637,
+    31633,
+    31639,
+    31640,
+    31641,
+    31642,
+    31643,
+    31644,
+    31645,
+    31646,
+    31647,
+    31648,
+    31649,
+    31650,
+    31651,
+    31651,
+    31653,
+    31654,
+    31651,
+    31656,
+    31657,
+    31658,
+    31659,
+    31660,
+    31661,
+    31662,
+    31663,
+    31663,
+    31665,
+    31665,
+    31667,
+    31668,
+    31669,
+    31670,
+    31665,
+    31672,
+    31672,
+    31674,
+    31672,
+    31676,
+    31677,
+    31678,
+    31678,
+    31680,
+    31680,
+    31682,
+    31678,
+    31684,
+    31685,
+    31686,
+    31686,
+    31688,
+    31689,
+    31690,
+    31691,
+    31692,
+    31693,
+    31693,
+    31695,
+    31693,
+    31697,
+    31698,
+    31699,
+    31699,
+    31701,
+    31702,
+    31702,
+    31702,
+    31705,
+    31706,
+    31707,
+    31699,
+    31709,
+    31710,
+    31710,
+    31712,
+    31712,
+    31712,
+    31710,
+    31716,
+    31716,
+    31716,
+    31719,
+    31720,
+    31721,
+    31722,
+    31723,
+    31723,
+    31723,
+    31726,
+    31727,
+    31728,
+    31729,
+    31730,
+    31731,
+    31732,
+    31733,
+    31734,
+    31735,
+    31736,
+    31737,
+    31738,
+    31739,
+    31740,
+    31741,
+    31742,
+    31743,
+    31744,
+    31745,
+    31746,
+    31747,
+    31748,
+    31749,
+    31750,
+    31751,
+    31752,
+    31753,
+    31754,
+    31754,
+    31756,
+    31754,
+    31758,
+    31759,
+    31760,
+    31761,
+    31762,
+    31763,
+    31764,
+    31764,
+    31766,
+    31767,
+    31768,
+    31769,
+    31770,
+    31771,
+    31772,
+    31773,
+    31774,
+    31775,
+    31776,
+    31777,
+    31778,
+    31779,
+    31780,
+    31781,
+    31782,
+    31783,
+    31784,
+    31785,
+    31786,
+    31787,
+    31787,
+    31789,
+    31790,
+    31791,
+    31792,
+    31793,
+    31794,
+    31795,
+    31796,
+    31797,
+    31798,
+    31799,
+    31800,
+    31801,
+    31802,
+    31803,
+    31802,
+    31805,
+    31806,
+    31807,
+    31808,
+    31809,
+    31810,
+    31811,
+    31811,
+    31811,
+    31814,
+    31815,
+    31816,
+    31817,
+    31817,
+    31819,
+    31820,
+    31821,
+    31822,
+    31817,
+    31824,
+    31824,
+    31826,
+    31827,
+    31828,
+    31829,
+    31830,
+    31831,
+    31832,
+    31833,
+    31834,
+    31834,
+    31836,
+    31836,
+    31834,
+    31839,
+    31839,
+    31841,
+    31842,
+    31843,
+    31839,
+    31845,
+    31846,
+    31846,
+    31845,
+    31845,
+    31850,
+    31850,
+    31852,
+    31850,
+    31854,
+    31855,
+    31856,
+    31857,
+    31857,
+    31859,
+    31860,
+    31860,
+    31860,
+    31863,
+    31864,
+    31865,
+    31866,
+    31866,
+    31868,
+    31866,
+    31870,
+    31871,
+    31871,
+    31873,
+    31874,
+    31874,
+    31874,
+    31877,
+    31877,
+    31877,
+    31880,
+    31881,
+    31882,
+    31883,
+    31883,
+    31885,
+    31886,
+    31886,
+    31888,
+    31888,
+    31890,
+    31891,
+    31892,
+    31893,
+    31893,
+    31895,
+    31896,
+    31897,
+    31897,
+    31899,
+    31900,
+    31901,
+    31902,
+    31902,
+    31904,
+    31904,
+    31906,
+    31907,
+    31908,
+    31909,
+    31910,
+    31911,
+    31912,
+    31913,
+    31914,
+    31914,
+    31916,
+    31916,
+    31918,
+    31919,
+    31920,
+    31921,
+    31922,
+    31923,
+    31924,
+    31925,
+    31926,
+    31926,
+    31926,
+    31929,
+    31930,
+    31931,
+    31931,
+    31931,
+    31934,
+    31935,
+    31936,
+    31895,
+    31895,
+    31939,
+    31939,
+    31939,
+    31942,
+    31943,
+    31944,
+    31945,
+    31946,
+    31947,
+    31948,
+    31948,
+    31950,
+    31948,
+    31952,
+    31952,
+    31954,
+    31952,
+    31956,
+    31956,
+    31956,
+    31959,
+    31960,
+    31961,
+    31962,
+    31962,
+    31962,
+    31965,
+    31966,
+    31967,
+    31968,
+    31969,
+    31970,
+    31971,
+    31972,
+    31973,
+    31974,
+    31975,
+    31976,
+    31977,
+    31978,
+    31979,
+    31980,
+    31981,
+    31982,
+    31983,
+    31984,
+    31985,
+    31985,
+    31985,
+    31988,
+    31989,
+    31990,
+    31991,
+    31992,
+    31993,
+    31994,
+    31995,
+    31996,
+    31997,
+    31998,
+    31997,
+    32000,
+    32001,
+    32001,
+    32003,
+    32004,
+    32005,
+    32006,
+    32007,
+    32008,
+    32008,
+    32007,
+    32011,
+    32012,
+    32013,
+    32013,
+    32015,
+    32016,
+    32017,
+    32018,
+    32019,
+    32020,
+    32021,
+    32022,
+    32023,
+    32024,
+    32025,
+    32026,
+    32027,
+    32028,
+    32029,
+    32030,
+    32031,
+    32032,
+    32033,
+    32034,
+    32035,
+    32036,
+    32034,
+    32038,
+    32039,
+    32040,
+    32041,
+    32042,
+    32043,
+    32044,
+    32045,
+    32046,
+    32047,
+    32048,
+    32049,
+    32050,
+    32051,
+    32052,
+    32053,
+    32054,
+    32055,
+    32056,
+    32057,
+    32058,
+    32059,
+    32060,
+    32061,
+    32062,
+    32063,
+    32064,
+    32065,
+    32066,
+    32067,
+    32068,
+    32069,
+    32070,
+    32071,
+    32072,
+    32073,
+    32074,
+    32075,
+    32076,
+    32077,
+    32076,
+    32079,
+    32080,
+    32081,
+    32082,
+    32083,
+    32084,
+    32085,
+    32086,
+    32087,
+    32088,
+    32089,
+    32090,
+    32091,
+    32092,
+    32093,
+    32094,
+    32095,
+    32096,
+    32097,
+    32098,
+    32099,
+    32100,
+    32101,
+    32102,
+    32103,
+    32104,
+    32105,
+    32106,
+    32107,
+    32108,
+    32109,
+    32110,
+    32111,
+    32112,
+    32113,
+    32114,
+    32115,
+    32116,
+    32117,
+    32118,
+    32119,
+    32120,
+    32121,
+    32122,
+    32123,
+    32124,
+    32125,
+    32126,
+    32127,
+    32128,
+    32129,
+    32130,
+    32131,
+    32132,
+    32133,
+    32134,
+    32135,
+    32136,
+    32137,
+    32138,
+    32139,
+    32140,
+    32141,
+    32142,
+    32143,
+    32144,
+    32145,
+    32146,
+    32147,
+    32148,
+    32149,
+    32150,
+    32151,
+    32152,
+    32153,
+    32154,
+    32155,
+    32156,
+    32157,
+    32158,
+    32159,
+    32160,
+    32161,
+    32162,
+    32163,
+    32164,
+    32165,
+    32166,
+    32167,
+    32168,
+    32169,
+    32170,
+    32171,
+    32172,
+    32173,
+    32174,
+    32175,
+    32176,
+    32177,
+    32178,
+    32179,
+    32180,
+    32181,
+    32182,
+    32183,
+    32184,
+    32185,
+    32186,
+    32187,
+    32188,
+    32189,
+    32190,
+    32191,
+    32192,
+    32193,
+    32194,
+    32191,
+    32196,
+    32197,
+    32198,
+    32199,
+    32197,
+    32201,
+    32202,
+    32203,
+    32204,
+    32202,
+    32206,
+    32207,
+    32208,
+    32209,
+    32210,
+    32211,
+    32212,
+    32213,
+    32214,
+    32215,
+    32216,
+    32217,
+    32218,
+    32219,
+    32219,
+    32221,
+    32219,
+    32223,
+    32223,
+    32225,
+    32223,
+    32227,
+    32228,
+    32229,
+    32230,
+    32231,
+    32232,
+    32233,
+    32234,
+    32235,
+    32236,
+    32232,
+    32238,
+    32239,
+    32240,
+    32241,
+    32238,
+    32243,
+    32244,
+    32245,
+    32246,
+    32247,
+    32247,
+    32247,
+    32250,
+    32251,
+    32252,
+    32253,
+    32254,
+    32255,
+    32256,
+    32257,
+    32258,
+    32259,
+    32260,
+    32253,
+    32262,
+    32263,
+    32264,
+    32264,
+    32266,
+    32267,
+    32268,
+    32269,
+    32270,
+    32271,
+    32272,
+    32273,
+    32273,
+    32273,
+    32276,
+    32277,
+    32277,
+    32279,
+    32280,
+    32277,
+    32282,
+    32282,
+    32282,
+    32285,
+    32286,
+    32287,
+    32288,
+    32289,
+    32289,
+    32291,
+    32292,
+    32293,
+    32294,
+    32294,
+    32296,
+    32297,
+    32298,
+    32294,
+    32300,
+    32301,
+    32301,
+    32303,
+    32301,
+    32305,
+    32305,
+    32305,
+    32308,
+    32309,
+    32310,
+    32310,
+    32312,
+    32313,
+    32310,
+    32315,
+    32316,
+    32317,
+    32318,
+    32318,
+    32318,
+    32321,
+    32321,
+    32323,
+    32324,
+    32325,
+    32326,
+    32321,
+    32328,
+    32328,
+    32328,
+    32331,
+    32331,
+    32331,
+    32334,
+    32335,
+    32336,
+    32337,
+    32338,
+    32339,
+    32339,
+    32341,
+    32342,
+    32342,
+    32344,
+    32344,
+    32346,
+    32347,
+    32348,
+    32349,
+    32350,
+    32351,
+    32352,
+    32353,
+    32354,
+    32355,
+    32356,
+    32357,
+    32358,
+    32359,
+    32360,
+    32361,
+    32362,
+    32363,
+    32364,
+    32365,
+    32366,
+    32367,
+    32368,
+    32369,
+    32370,
+    32371,
+    32372,
+    32373,
+    32374,
+    32375,
+    32376,
+    32377,
+    32377,
+    32379,
+    32380,
+    32381,
+    32382,
+    32383,
+    32384,
+    32385,
+    32386,
+    32387,
+    32386,
+    32389,
+    32390,
+    32391,
+    32392,
+    32393,
+    32394,
+    32395,
+    32396,
+    32397,
+    32397,
+    32399,
+    32400,
+    32400,
+    32402,
+    32403,
+    32404,
+    32405,
+    32406,
+    32407,
+    32408,
+    32409,
+    32410,
+    32411,
+    32412,
+    32413,
+    32414,
+    32415,
+    32416,
+    32417,
+    32418,
+    32419,
+    32420,
+    32421,
+    32422,
+    32423,
+    32424,
+    32425,
+    32426,
+    32426,
+    32428,
+    32428,
+    32430,
+    32431,
+    32432,
+    32433,
+    32434,
+    32435,
+    32436,
+    32436,
+    32438,
+    32439,
+    32440,
+    32441,
+    32442,
+    32443,
+    32444,
+    32445,
+    32446,
+    32447,
+    32448,
+    32449,
+    32450,
+    32451,
+    32452,
+    32453,
+    32453,
+    32453,
+    32456,
+    32457,
+    32458,
+    32459,
+    32460,
+    32460,
+    32462,
+    32463,
+    32464,
+    32465,
+    32466,
+    32467,
+    32468,
+    32468,
+    32470,
+    32471,
+    32468,
+    32473,
+    32474,
+    32475,
+    32476,
+    32475,
+    32478,
+    32475,
+    32480,
+    32481,
+    32482,
+    32483,
+    32482,
+    32482,
+    32486,
+    32487,
+    32488,
+    32489,
+    32490,
+    32491,
+    32492,
+    32493,
+    32494,
+    32495,
+    32496,
+    32497,
+    32498,
+    32499,
+    32500,
+    32501,
+    32502,
+    32502,
+    32504,
+    32505,
+    32506,
+    32507,
+    32508,
+    32509,
+    32509,
+    32509,
+    32512,
+    32513,
+    32514,
+    32515,
+    32516,
+    32514,
+    32518,
+    32519,
+    32520,
+    32514,
+    32522,
+    32523,
+    32523,
+    32525,
+    32526,
+    32527,
+    32528,
+    32529,
+    32530,
+    32531,
+    32532,
+    32532,
+    32534,
+    32535,
+    32532,
+    32537,
+    32538,
+    32538,
+    32540,
+    32540,
+    32542,
+    32538,
+    32544,
+    32545,
+    32546,
+    32547,
+    32544,
+    32549,
+    32549,
+    32551,
+    32552,
+    32553,
+    32554,
+    32544,
+    32556,
+    32557,
+    32558,
+    32559,
+    32560,
+    32561,
+    32562,
+    32563,
+    32564,
+    32565,
+    32566,
+    32567,
+    32568,
+    32569,
+    32570,
+    32568,
+    32572,
+    32573,
+    32574,
+    32575,
+    32576,
+    32577,
+    32578,
+    32579,
+    32579,
+    32581,
+    32582,
+    32582,
+    32584,
+    32585,
+    32586,
+    32587,
+    32587,
+    32589,
+    32590,
+    32591,
+    32591,
+    32593,
+    32594,
+    32595,
+    32596,
+    32597,
+    32598,
+    32599,
+    32600,
+    32601,
+    32602,
+    32603,
+    32604,
+    32605,
+    32605,
+    32607,
+    32605,
+    32609,
+    32610,
+    32611,
+    32612,
+    32613,
+    32614,
+    32615,
+    32615,
+    32617,
+    32618,
+    32618,
+    32620,
+    32621,
+    32622,
+    32623,
+    32624,
+    32624,
+    32624,
+    32627,
+    32628,
+    32628,
+    32630,
+    32631,
+    32632,
+    32631,
+    32634,
+    32634,
+    32634,
+    32637,
+    32638,
+    32639,
+    32640,
+    32641,
+    32642,
+    32643,
+    32644,
+    32645,
+    32646,
+    32644,
+    32648,
+    32649,
+    32650,
+    32651,
+    32651,
+    32653,
+    32654,
+    32655,
+    32656,
+    32657,
+    32658,
+    32659,
+    32660,
+    32661,
+    32662,
+    32663,
+    32664,
+    32665,
+    32666,
+    32667,
+    32668,
+    32669,
+    32670,
+    32671,
+    32672,
+    32673,
+    32674,
+    32675,
+    32676,
+    32677,
+    32677,
+    32679,
+    32680,
+    32681,
+    32681,
+    32683,
+    32684,
+    32684,
+    32686,
+    32687,
+    32688,
+    32689,
+    32690,
+    32691,
+    32692,
+    32693,
+    32694,
+    32694,
+    32696,
+    32697,
+    32698,
+    32699,
+    32692,
+    32701,
+    32702,
+    32703,
+    32704,
+    32705,
+    32706,
+    32707,
+    32708,
+    32709,
+    32710,
+    32711,
+    32712,
+    32713,
+    32714,
+    32715,
+    32716,
+    32717,
+    32718,
+    32719,
+    32720,
+    32720,
+    32722,
+    32723,
+    32724,
+    32725,
+    32725,
+    32727,
+    32728,
+    32728,
+    32730,
+    32731,
+    32732,
+    32733,
+    32734,
+    32735,
+    32736,
+    32737,
+    32738,
+    32739,
+    32740,
+    32741,
+    32742,
+    32743,
+    32744,
+    32745,
+    32746,
+    32747,
+    32748,
+    32749,
+    32750,
+    32751,
+    32752,
+    32753,
+    32754,
+    32755,
+    32756,
+    32756,
+    32758,
+    32759,
+    32760,
+    32761,
+    32762,
+    32763,
+    32764,
+    32765,
+    32766,
+    32767,
+    32768,
+    32768,
+    32770,
+    32771,
+    32771,
+    32773,
+    32774,
+    32775,
+    32776,
+    32777,
+    32778,
+    32779,
+    32780,
+    32781,
+    32782,
+    32783,
+    32784,
+    32784,
+    32786,
+    32787,
+    32788,
+    32788,
+    32790,
+    32791,
+    32792,
+    32790,
+    32794,
+    32795,
+    32795,
+    32797,
+    32798,
+    32799,
+    32799,
+    32801,
+    32802,
+    32803,
+    32804,
+    32805,
+    32805,
+    32807,
+    32808,
+    32809,
+    32810,
+    32811,
+    32812,
+    32813,
+    32814,
+    32815,
+    32816,
+    32817,
+    32818,
+    32819,
+    32820,
+    32821,
+    32822,
+    32823,
+    32824,
+    32825,
+    32826,
+    32827,
+    32828,
+    32829,
+    32830,
+    32829,
+    32832,
+    32833,
+    32834,
+    32835,
+    32835,
+    32835,
+    32838,
+    32839,
+    32840,
+    32841,
+    32842,
+    32843,
+    32844,
+    32845,
+    32846,
+    32847,
+    32847,
+    32849,
+    32847,
+    32851,
+    32852,
+    32853,
+    32854,
+    32855,
+    32856,
+    32857,
+    32858,
+    32859,
+    32860,
+    32860,
+    32862,
+    32863,
+    32864,
+    32865,
+    32866,
+    32867,
+    32868,
+    32869,
+    32870,
+    32871,
+    32870,
+    32873,
+    32874,
+    32875,
+    32876,
+    32875,
+    32878,
+    32879,
+    32880,
+    32881,
+    32878,
+    32883,
+    32884,
+    32885,
+    32886,
+    32887,
+    32888,
+    32889,
+    32889,
+    32891,
+    32892,
+    32893,
+    32894,
+    32895,
+    32896,
+    32897,
+    32898,
+    32899,
+    32899,
+    32901,
+    32901,
+    32903,
+    32904,
+    32905,
+    32905,
+    32907,
+    32907,
+    32909,
+    32910,
+    32911,
+    32912,
+    32912,
+    32912,
+    32915,
+    32915,
+    32915,
+    32918,
+    32919,
+    32920,
+    32921,
+    32921,
+    32921,
+    32924,
+    32925,
+    32926,
+    32927,
+    32928,
+    32929,
+    32930,
+    32931,
+    32932,
+    32933,
+    32934,
+    32926,
+    32936,
+    32937,
+    32938,
+    32926,
+    32940,
+    32941,
+    32941,
+    32943,
+    32944,
+    32945,
+    32946,
+    32947,
+    32948,
+    32949,
+    32950,
+    32951,
+    32951,
+    32953,
+    32954,
+    32955,
+    32956,
+    32956,
+    32958,
+    32959,
+    32956,
+    32961,
+    32962,
+    32963,
+    32964,
+    32965,
+    32966,
+    32966,
+    32968,
+    32966,
+    32970,
+    32971,
+    32972,
+    32973,
+    32973,
+    32975,
+    32976,
+    32977,
+    32978,
+    32978,
+    32980,
+    32981,
+    32982,
+    32983,
+    32984,
+    32985,
+    32985,
+    32987,
+    32988,
+    32989,
+    32989,
+    32991,
+    32985,
+    32993,
+    32994,
+    32995,
+    32995,
+    32997,
+    32997,
+    32999,
+    33000,
+    32995,
+    33002,
+    33003,
+    33003,
+    33005,
+    33003,
+    33007,
+    33008,
+    33009,
+    33010,
+    33011,
+    33012,
+    33013,
+    33014,
+    33015,
+    33016,
+    33017,
+    33017,
+    33019,
+    33019,
+    33021,
+    33022,
+    33022,
+    33024,
+    33025,
+    33025,
+    33027,
+    33028,
+    33029,
+    33030,
+    33031,
+    33032,
+    33033,
+    33034,
+    33034,
+    33036,
+    33037,
+    33038,
+    33038,
+    33040,
+    33041,
+    33042,
+    33043,
+    33044,
+    33044,
+    33046,
+    33047,
+    33048,
+    33049,
+    33049,
+    33051,
+    33051,
+    33053,
+    33054,
+    33055,
+    33056,
+    33057,
+    33058,
+    33059,
+    33060,
+    33061,
+    33062,
+    33063,
+    33064,
+    33065,
+    33066,
+    33067,
+    33068,
+    33068,
+    33070,
+    33070,
+    33072,
+    33073,
+    33074,
+    33075,
+    33075,
+    33077,
+    33075,
+    33079,
+    33080,
+    33081,
+    33082,
+    33083,
+    33083,
+    33085,
+    33086,
+    33083,
+    33088,
+    33089,
+    33089,
+    33091,
+    33092,
+    33093,
+    33094,
+    33094,
+    33096,
+    33094,
+    33098,
+    33099,
+    33100,
+    33101,
+    33102,
+    33103,
+    33103,
+    33105,
+    33106,
+    33107,
+    33107,
+    33109,
+    33110,
+    33103,
+    33112,
+    33113,
+    33114,
+    33114,
+    33116,
+    33117,
+    33117,
+    33119,
+    33117,
+    33121,
+    33122,
+    33123,
+    33124,
+    33125,
+    33126,
+    33127,
+    33127,
+    33127,
+    33130,
+    33131,
+    33132,
+    33133,
+    33134,
+    33135,
+    33136,
+    33137,
+    33138,
+    33139,
+    33138,
+    33141,
+    33138,
+    33143,
+    33144,
+    33145,
+    33146,
+    33147,
+    33148,
+    33148,
+    33150,
+    33151,
+    33148,
+    33153,
+    33154,
+    33155,
+    33156,
+    33157,
+    33158,
+    33158,
+    33160,
+    33161,
+    33162,
+    33162,
+    33164,
+    33164,
+    33166,
+    33167,
+    33168,
+    33169,
+    33170,
+    33171,
+    33171,
+    33173,
+    33174,
+    33175,
+    33176,
+    33177,
+    33177,
+    33179,
+    33179,
+    33181,
+    33182,
+    33183,
+    33184,
+    33185,
+    33186,
+    33187,
+    33188,
+    33189,
+    33189,
+    33189,
+    33192,
+    33193,
+    33194,
+    33194,
+    33196,
+    33197,
+    33198,
+    33199,
+    33200,
+    33194,
+    33202,
+    33203,
+    33203,
+    33205,
+    33206,
+    33207,
+    33208,
+    33209,
+    33209,
+    33211,
+    33212,
+    33213,
+    33214,
+    33215,
+    33216,
+    33217,
+    33218,
+    33219,
+    33220,
+    33221,
+    33222,
+    33223,
+    33224,
+    33224,
+    33226,
+    33224,
+    33228,
+    33229,
+    33230,
+    33230,
+    33232,
+    33233,
+    33234,
+    33235,
+    33235,
+    33237,
+    33238,
+    33239,
+    33239,
+    33241,
+    33242,
+    33243,
+    33244,
+    33242,
+    33246,
+    33247,
+    33248,
+    33249,
+    33250,
+    33251,
+    33252,
+    33253,
+    33253,
+    33255,
+    33255,
+    33257,
+    33257,
+    33259,
+    33260,
+    33261,
+    33262,
+    33262,
+    33264,
+    33265,
+    33266,
+    33266,
+    33268,
+    33269,
+    33270,
+    33271,
+    33272,
+    33273,
+    33273,
+    33275,
+    33276,
+    33277,
+    33278,
+    33279,
+    33280,
+    33281,
+    33282,
+    33283,
+    33284,
+    33285,
+    33285,
+    33287,
+    33288,
+    33289,
+    33290,
+    33291,
+    33292,
+    33293,
+    33294,
+    33295,
+    33296,
+    33297,
+    33298,
+    33298,
+    33300,
+    33301,
+    33302,
+    33303,
+    33304,
+    33304,
+    33306,
+    33307,
+    33308,
+    33309,
+    33310,
+    33311,
+    33312,
+    33312,
+    33314,
+    33315,
+    33315,
+    33317,
+    33318,
+    33318,
+    33320,
+    33321,
+    33322,
+    33323,
+    33324,
+    33325,
+    33326,
+    33327,
+    33322,
+    33329,
+    33330,
+    33331,
+    33332,
+    33333,
+    33334,
+    33335,
+    33336,
+    33337,
+    33338,
+    33339,
+    33340,
+    33341,
+    33342,
+    33343,
+    33344,
+    33345,
+    33346,
+    33346,
+    33348,
+    33349,
+    33346,
+    33351,
+    33351,
+    33353,
+    33354,
+    33355,
+    33356,
+    33351,
+    33358,
+    33359,
+    33360,
+    33360,
+    33360,
+    33363,
+    33364,
+    33365,
+    33366,
+    33367,
+    33368,
+    33368,
+    33370,
+    33371,
+    33372,
+    33373,
+    33374,
+    33375,
+    33376,
+    33377,
+    33378,
+    33379,
+    33380,
+    33381,
+    33382,
+    33383,
+    33383,
+    33385,
+    33385,
+    33383,
+    33388,
+    33389,
+    33390,
+    33391,
+    33392,
+    33393,
+    33394,
+    33395,
+    33396,
+    33397,
+    33398,
+    33399,
+    33400,
+    33401,
+    33402,
+    33403,
+    33404,
+    33405,
+    33406,
+    33407,
+    33408,
+    33409,
+    33410,
+    33411,
+    33412,
+    33413,
+    33414,
+    33415,
+    33416,
+    33417,
+    33418,
+    33417,
+    33420,
+    33421,
+    33422,
+    33423,
+    33424,
+    33425,
+    33426,
+    33427,
+    33428,
+    33429,
+    33429,
+    33431,
+    33429,
+    33433,
+    33434,
+    33435,
+    33436,
+    33437,
+    33438,
+    33439,
+    33440,
+    33440,
+    33442,
+    33442,
+    33442,
+    33445,
+    33446,
+    33447,
+    33448,
+    33448,
+    33450,
+    33451,
+    33452,
+    33453,
+    33453,
+    33455,
+    33456,
+    33457,
+    33458,
+    33459,
+    33460,
+    33461,
+    33462,
+    33463,
+    33464,
+    33465,
+    33453,
+    33467,
+    33468,
+    33469,
+    33470,
+    33471,
+    33472,
+    33472,
+    33472,
+    33475,
+    33475,
+    33477,
+    33478,
+    33479,
+    33475,
+    33481,
+    33482,
+    33483,
+    33484,
+    33485,
+    33486,
+    33487,
+    33488,
+    33489,
+    33490,
+    33491,
+    33492,
+    33493,
+    33494,
+    33495,
+    33496,
+    33497,
+    33498,
+    33499,
+    33500,
+    33501,
+    33502,
+    33503,
+    33504,
+    33505,
+    33506,
+    33507,
+    33508,
+    33508,
+    33510,
+    33511,
+    33512,
+    33513,
+    33514,
+    33515,
+    33516,
+    33517,
+    33517,
+    33519,
+    33520,
+    33521,
+    33522,
+    33523,
+    33522,
+    33525,
+    33526,
+    33527,
+    33522,
+    33529,
+    33530,
+    33531,
+    33532,
+    33533,
+    33533,
+    33535,
+    33536,
+    33537,
+    33538,
+    33539,
+    33533,
+    33541,
+    33541,
+    33543,
+    33544,
+    33545,
+    33546,
+    33547,
+    33548,
+    33544,
+    33550,
+    33550,
+    33544,
+    33553,
+    33554,
+    33555,
+    33556,
+    33557,
+    33558,
+    33559,
+    33560,
+    33560,
+    33562,
+    33563,
+    33564,
+    33564,
+    33566,
+    33567,
+    33568,
+    33569,
+    33570,
+    33571,
+    33572,
+    33573,
+    33574,
+    33574,
+    33576,
+    33577,
+    33578,
+    33574,
+    33580,
+    33581,
+    33582,
+    33583,
+    33584,
+    33585,
+    33585,
+    33585,
+    33588,
+    33584,
+    33584,
+    33591,
+    33592,
+    33593,
+    33594,
+    33595,
+    33596,
+    33596,
+    33598,
+    33599,
+    33600,
+    33596,
+    33602,
+    33603,
+    33604,
+    33605,
+    33606,
+    33607,
+    33608,
+    33609,
+    33610,
+    33611,
+    33612,
+    33613,
+    33614,
+    33615,
+    33616,
+    33617,
+    33618,
+    33619,
+    33620,
+    33613,
+    33622,
+    33623,
+    33613,
+    33625,
+    33626,
+    33627,
+    33628,
+    33629,
+    33630,
+    33631,
+    33632,
+    33633,
+    33634,
+    33635,
+    33636,
+    33637,
+    33638,
+    33639,
+    33640,
+    33641,
+    33642,
+    33643,
+    33644,
+    33644,
+    33646,
+    33647,
+    33648,
+    33644,
+    33650,
+    33650,
+    33650,
+    33653,
+    33654,
+    33655,
+    33656,
+    33657,
+    33654,
+    33659,
+    33660,
+    33661,
+    33661,
+    33661,
+    33664,
+    33665,
+    33666,
+    33667,
+    33668,
+    33669,
+    33670,
+    33671,
+    33672,
+    33673,
+    33673,
+    33675,
+    33676,
+    33677,
+    33678,
+    33679,
+    33680,
+    33681,
+    33682,
+    33683,
+    33684,
+    33684,
+    33686,
+    33687,
+    33688,
+    33689,
+    33689,
+    33691,
+    33692,
+    33693,
+    33694,
+    33694,
+    33696,
+    33697,
+    33697,
+    33699,
+    33694,
+    33701,
+    33702,
+    33702,
+    33704,
+    33702,
+    33706,
+    33706,
+    33708,
+    33706,
+    33710,
+    33711,
+    33712,
+    33713,
+    33714,
+    33715,
+    33716,
+    33717,
+    33718,
+    33718,
+    33720,
+    33721,
+    33722,
+    33723,
+    33724,
+    33725,
+    33726,
+    33726,
+    33728,
+    33729,
+    33730,
+    33730,
+    33732,
+    33733,
+    33734,
+    33734,
+    33734,
+    33737,
+    33738,
+    33738,
+    33740,
+    33741,
+    33742,
+    33743,
+    33742,
+    33745,
+    33746,
+    33747,
+    33748,
+    33749,
+    33750,
+    33751,
+    33752,
+    33752,
+    33754,
+    33755,
+    33756,
+    33756,
+    33758,
+    33759,
+    33760,
+    33761,
+    33762,
+    33763,
+    33764,
+    33765,
+    33766,
+    33767,
+    33768,
+    33769,
+    33770,
+    33771,
+    33772,
+    33773,
+    33774,
+    33775,
+    33776,
+    33777,
+    33778,
+    33779,
+    33780,
+    33781,
+    33781,
+    33783,
+    33784,
+    33785,
+    33786,
+    33787,
+    33788,
+    33789,
+    33790,
+    33791,
+    33792,
+    33793,
+    33794,
+    33795,
+    33796,
+    33797,
+    33798,
+    33799,
+    33800,
+    33801,
+    33802,
+    33803,
+    33803,
+    33805,
+    33805,
+    33807,
+    33808,
+    33809,
+    33810,
+    33811,
+    33812,
+    33813,
+    33814,
+    33815,
+    33816,
+    33817,
+    33818,
+    33819,
+    33820,
+    33820,
+    33822,
+    33823,
+    33824,
+    33825,
+    33826,
+    33827,
+    33827,
+    33829,
+    33830,
+    33831,
+    33832,
+    33833,
+    33833,
+    33835,
+    33836,
+    33837,
+    33838,
+    33839,
+    33840,
+    33841,
+    33842,
+    33843,
+    33844,
+    33845,
+    33846,
+    33847,
+    33848,
+    33849,
+    33849,
+    33851,
+    33852,
+    33853,
+    33854,
+    33855,
+    33856,
+    33857,
+    33858,
+    33859,
+    33860,
+    33861,
+    33862,
+    33862,
+    33864,
+    33865,
+    33866,
+    33865,
+    33868,
+    33869,
+    33869,
+    33871,
+    33872,
+    33873,
+    33874,
+    33875,
+    33876,
+    33876,
+    33878,
+    33879,
+    33880,
+    33881,
+    33882,
+    33883,
+    33883,
+    33883,
+    33886,
+    33887,
+    33888,
+    33889,
+    33890,
+    33891,
+    33892,
+    33893,
+    33893,
+    33895,
+    33896,
+    33896,
+    33898,
+    33898,
+    33900,
+    33901,
+    33902,
+    33903,
+    33904,
+    33905,
+    33906,
+    33907,
+    33908,
+    33908,
+    33910,
+    33908,
+    33912,
+    33913,
+    33913,
+    33915,
+    33916,
+    33916,
+    33916,
+    33919,
+    33920,
+    33921,
+    33922,
+    33921,
+    33924,
+    33925,
+    33926,
+    33926,
+    33928,
+    33926,
+    33930,
+    33930,
+    33932,
+    33933,
+    33933,
+    33935,
+    33936,
+    33937,
+    33938,
+    33939,
+    33940,
+    33941,
+    33942,
+    33942,
+    33942,
+    33945,
+    33946,
+    33947,
+    33946,
+    33949,
+    33950,
+    33951,
+    33946,
+    33953,
+    33954,
+    33955,
+    33956,
+    33957,
+    33957,
+    33959,
+    33960,
+    33961,
+    33961,
+    33963,
+    33961,
+    33965,
+    33966,
+    33967,
+    33968,
+    33968,
+    33970,
+    33971,
+    33972,
+    33972,
+    33972,
+    33975,
+    33976,
+    33976,
+    33976,
+    33979,
+    33979,
+    33981,
+    33982,
+    33983,
+    33984,
+    33985,
+    33986,
+    33987,
+    33988,
+    33989,
+    33990,
+    33991,
+    33992,
+    33993,
+    33993,
+    33995,
+    33996,
+    33997,
+    33998,
+    33999,
+    33999,
+    34001,
+    34001,
+    34003,
+    34004,
+    34005,
+    34006,
+    34006,
+    34008,
+    34009,
+    34010,
+    34011,
+    34012,
+    34013,
+    34013,
+    34013,
+    34016,
+    34016,
+    34018,
+    34016,
+    34020,
+    34021,
+    34022,
+    34023,
+    34024,
+    34025,
+    34026,
+    34027,
+    34028,
+    34028,
+    34030,
+    34028,
+    34032,
+    34033,
+    34033,
+    34035,
+    34033,
+    34037,
+    34038,
+    34039,
+    34040,
+    34041,
+    34042,
+    34042,
+    34044,
+    34045,
+    34046,
+    34047,
+    34048,
+    34049,
+    34050,
+    34050,
+    34052,
+    34052,
+    34052,
+    34055,
+    34056,
+    34057,
+    34058,
+    34059,
+    34060,
+    34058,
+    34062,
+    34063,
+    34064,
+    34065,
+    34066,
+    34067,
+    34068,
+    34069,
+    34070,
+    34071,
+    34072,
+    34072,
+    34074,
+    34072,
+    34076,
+    34077,
+    34078,
+    34079,
+    34080,
+    34080,
+    34082,
+    34080,
+    34084,
+    34085,
+    34084,
+    34087,
+    34088,
+    34088,
+    34090,
+    34091,
+    34091,
+    34093,
+    34094,
+    34095,
+    34096,
+    34097,
+    34098,
+    34098,
+    34100,
+    34100,
+    34102,
+    34103,
+    34104,
+    34105,
+    34105,
+    34107,
+    34108,
+    34109,
+    34109,
+    34111,
+    34112,
+    34112,
+    34114,
+    34115,
+    34116,
+    34117,
+    34118,
+    34118,
+    34120,
+    34118,
+    34122,
+    34123,
+    34123,
+    34125,
+    34123,
+    34127,
+    34128,
+    34129,
+    34130,
+    34131,
+    34132,
+    34132,
+    34134,
+    34135,
+    34136,
+    34137,
+    34138,
+    34139,
+    34140,
+    34141,
+    34142,
+    34143,
+    34144,
+    34145,
+    34146,
+    34146,
+    34148,
+    34149,
+    34150,
+    34150,
+    34152,
+    34153,
+    34150,
+    34155,
+    34155,
+    34157,
+    34155,
+    34159,
+    34159,
+    34161,
+    34162,
+    34159,
+    34164,
+    34165,
+    34166,
+    34167,
+    34168,
+    34168,
+    34170,
+    34171,
+    34168,
+    34173,
+    34174,
+    34174,
+    34176,
+    34177,
+    34178,
+    34179,
+    34180,
+    34181,
+    34182,
+    34182,
+    34184,
+    34184,
+    34186,
+    34187,
+    34188,
+    34189,
+    34190,
+    34191,
+    34191,
+    34193,
+    34191,
+    34195,
+    34196,
+    34196,
+    34198,
+    34196,
+    34200,
+    34201,
+    34202,
+    34202,
+    34202,
+    34205,
+    34206,
+    34207,
+    34208,
+    34209,
+    34210,
+    34211,
+    34211,
+    34213,
+    34211,
+    34215,
+    34216,
+    34216,
+    34218,
+    34219,
+    34220,
+    34220,
+    34220,
+    34223,
+    34224,
+    34225,
+    34225,
+    34227,
+    34225,
+    34229,
+    34230,
+    34229,
+    34232,
+    34232,
+    34234,
+    34235,
+    34235,
+    34237,
+    34238,
+    34237,
+    34240,
+    34241,
+    34242,
+    34243,
+    34244,
+    34245,
+    34246,
+    34247,
+    34248,
+    34249,
+    34250,
+    34251,
+    34252,
+    34253,
+    34254,
+    34255,
+    34256,
+    34257,
+    34258,
+    34259,
+    34260,
+    34261,
+    34262,
+    34263,
+    34264,
+    34265,
+    34266,
+    34267,
+    34268,
+    34269,
+    34270,
+    34271,
+    34272,
+    34273,
+    34274,
+    34275,
+    34276,
+    34277,
+    34278,
+    34279,
+    34280,
+    34281,
+    34282,
+    34283,
+    34283,
+    34285,
+    34282,
+    34287,
+    34288,
+    34289,
+    34290,
+    34291,
+    34292,
+    34293,
+    34294,
+    34293,
+    34296,
+    34297,
+    34298,
+    34299,
+    34299,
+    34301,
+    34302,
+    34303,
+    34304,
+    34305,
+    34306,
+    34307,
+    34307,
+    34309,
+    34309,
+    34311,
+    34312,
+    34313,
+    34314,
+    34315,
+    34316,
+    34317,
+    34318,
+    34319,
+    34320,
+    34321,
+    34322,
+    34323,
+    34324,
+    34325,
+    34326,
+    34327,
+    34328,
+    34329,
+    34330,
+    34331,
+    34332,
+    34333,
+    34333,
+    34335,
+    34336,
+    34337,
+    34338,
+    34338,
+    34340,
+    34341,
+    34342,
+    34343,
+    34344,
+    34345,
+    34346,
+    34347,
+    34348,
+    34349,
+    34350,
+    34351,
+    34351,
+    34353,
+    34350,
+    34355,
+    34356,
+    34357,
+    34350,
+    34350,
+    34360,
+    34361,
+    34362,
+    34362,
+    34364,
+    34365,
+    34366,
+    34367,
+    34368,
+    34369,
+    34370,
+    34371,
+    34372,
+    34373,
+    34374,
+    34375,
+    34376,
+    34377,
+    34377,
+    34379,
+    34377,
+    34381,
+    34381,
+    34383,
+    34384,
+    34385,
+    34386,
+    34387,
+    34387,
+    34389,
+    34387,
+    34391,
+    34392,
+    34393,
+    34393,
+    34395,
+    34396,
+    34393,
+    34398,
+    34399,
+    34400,
+    34401,
+    34401,
+    34403,
+    34404,
+    34405,
+    34406,
+    34407,
+    34408,
+    34409,
+    34409,
+    34411,
+    34412,
+    34413,
+    34414,
+    34415,
+    34416,
+    34416,
+    34418,
+    34416,
+    34420,
+    34421,
+    34422,
+    34423,
+    34423,
+    34425,
+    34426,
+    34427,
+    34428,
+    34423,
+    34430,
+    34431,
+    34431,
+    34433,
+    34431,
+    34435,
+    34428,
+    34428,
+    34438,
+    34439,
+    34440,
+    34441,
+    34442,
+    34443,
+    34444,
+    34445,
+    34445,
+    34447,
+    34445,
+    34449,
+    34450,
+    34451,
+    34449,
+    34449,
+    34454,
+    34455,
+    34456,
+    34457,
+    34457,
+    34457,
+    34460,
+    34460,
+    34462,
+    34462,
+    34464,
+    34465,
+    34466,
+    34467,
+    34468,
+    34469,
+    34469,
+    34471,
+    34472,
+    34473,
+    34473,
+    34473,
+    34476,
+    34477,
+    34477,
+    34477,
+    34480,
+    34481,
+    34480,
+    34483,
+    34484,
+    34484,
+    34486,
+    34487,
+    34488,
+    34488,
+    34490,
+    34491,
+    34492,
+    34493,
+    34494,
+    34495,
+    34496,
+    34496,
+    34498,
+    34499,
+    34500,
+    34501,
+    34502,
+    34503,
+    34504,
+    34505,
+    34506,
+    34506,
+    34508,
+    34509,
+    34510,
+    34510,
+    34512,
+    34513,
+    34514,
+    34514,
+    34516,
+    34517,
+    34518,
+    34518,
+    34520,
+    34521,
+    34522,
+    34523,
+    34524,
+    34518,
+    34526,
+    34527,
+    34527,
+    34527,
+    34530,
+    34531,
+    34532,
+    34533,
+    34534,
+    34535,
+    34536,
+    34537,
+    34538,
+    34539,
+    34540,
+    34541,
+    34542,
+    34542,
+    34544,
+    34545,
+    34545,
+    34545,
+    34548,
+    34549,
+    34549,
+    34549,
+    34552,
+    34553,
+    34554,
+    34555,
+    34556,
+    34557,
+    34558,
+    34559,
+    34560,
+    34561,
+    34562,
+    34563,
+    34564,
+    34565,
+    34566,
+    34567,
+    34567,
+    34569,
+    34570,
+    34571,
+    34572,
+    34572,
+    34574,
+    34572,
+    34576,
+    34577,
+    34578,
+    34577,
+    34580,
+    34581,
+    34582,
+    34577,
+    34584,
+    34585,
+    34586,
+    34587,
+    34588,
+    34589,
+    34590,
+    34590,
+    34592,
+    34593,
+    34594,
+    34595,
+    34596,
+    34597,
+    34598,
+    34599,
+    34600,
+    34601,
+    34601,
+    34603,
+    34601,
+    34605,
+    34606,
+    34606,
+    34608,
+    34609,
+    34610,
+    34606,
+    34612,
+    34612,
+    34614,
+    34615,
+    34616,
+    34617,
+    34618,
+    34619,
+    34620,
+    34621,
+    34622,
+    34623,
+    34624,
+    34625,
+    34626,
+    34627,
+    34628,
+    34629,
+    34630,
+    34631,
+    34632,
+    34633,
+    34634,
+    34635,
+    34636,
+    34637,
+    34638,
+    34639,
+    34640,
+    34641,
+    34642,
+    34643,
+    34644,
+    34645,
+    34646,
+    34647,
+    34648,
+    34649,
+    34650,
+    34651,
+    34652,
+    34653,
+    34654,
+    34655,
+    34656,
+    34657,
+    34658,
+    34659,
+    34660,
+    34661,
+    34662,
+    34663,
+    34664,
+    34665,
+    34666,
+    34667,
+    34668,
+    34669,
+    34670,
+    34671,
+    34672,
+    34673,
+    34674,
+    34674,
+    34676,
+    34677,
+    34678,
+    34679,
+    34680,
+    34681,
+    34681,
+    34683,
+    34684,
+    34684,
+    34686,
+    34687,
+    34687,
+    34687,
+    34690,
+    34691,
+    34692,
+    34692,
+    34694,
+    34694,
+    34696,
+    34697,
+    34698,
+    34699,
+    34699,
+    34701,
+    34702,
+    34703,
+    34704,
+    34705,
+    34706,
+    34707,
+    34708,
+    34709,
+    34710,
+    34711,
+    34712,
+    34713,
+    34714,
+    34715,
+    34715,
+    34717,
+    34718,
+    34719,
+    34720,
+    34721,
+    34722,
+    34723,
+    34724,
+    34725,
+    34726,
+    34727,
+    34728,
+    34729,
+    34730,
+    34731,
+    34732,
+    34733,
+    34734,
+    34735,
+    34736,
+    34737,
+    34738,
+    34739,
+    34739,
+    34741,
+    34742,
+    34743,
+    34744,
+    34745,
+    34746,
+    34747,
+    34748,
+    34749,
+    34750,
+    34751,
+    34752,
+    34753,
+    34754,
+    34755,
+    34756,
+    34757,
+    34758,
+    34759,
+    34759,
+    34761,
+    34762,
+    34763,
+    34764,
+    34765,
+    34765,
+    34765,
+    34768,
+    34769,
+    34770,
+    34771,
+    34772,
+    34773,
+    34773,
+    34775,
+    34776,
+    34776,
+    34778,
+    34779,
+    34779,
+    34779,
+    34782,
+    34773,
+    34784,
+    34784,
+    34786,
+    34784,
+    34788,
+    34789,
+    34790,
+    34791,
+    34790,
+    34793,
+    34794,
+    34795,
+    34796,
+    34797,
+    34798,
+    34799,
+    34800,
+    34801,
+    34802,
+    34803,
+    34804,
+    34805,
+    34806,
+    34807,
+    34808,
+    34809,
+    34810,
+    34811,
+    34811,
+    34813,
+    34814,
+    34815,
+    34815,
+    34817,
+    34818,
+    34819,
+    34820,
+    34821,
+    34822,
+    34823,
+    34824,
+    34825,
+    34825,
+    34827,
+    34828,
+    34829,
+    34830,
+    34831,
+    34831,
+    34833,
+    34834,
+    34835,
+    34836,
+    34837,
+    34837,
+    34837,
+    34840,
+    34841,
+    34842,
+    34843,
+    34844,
+    34845,
+    34845,
+    34847,
+    34848,
+    34849,
+    34850,
+    34850,
+    34852,
+    34853,
+    34850,
+    34855,
+    34856,
+    34857,
+    34857,
+    34857,
+    34860,
+    34861,
+    34862,
+    34863,
+    34864,
+    34865,
+    34866,
+    34867,
+    34868,
+    34869,
+    34870,
+    34871,
+    34872,
+    34873,
+    34874,
+    34875,
+    34876,
+    34877,
+    34878,
+    34879,
+    34880,
+    34880,
+    34882,
+    34883,
+    34883,
+    34885,
+    34886,
+    34887,
+    34888,
+    34889,
+    34890,
+    34891,
+    34892,
+    34893,
+    34894,
+    34895,
+    34896,
+    34897,
+    34898,
+    34899,
+    34900,
+    34901,
+    34902,
+    34903,
+    34904,
+    34905,
+    34906,
+    34907,
+    34908,
+    34909,
+    34910,
+    34911,
+    34912,
+    34913,
+    34914,
+    34915,
+    34916,
+    34917,
+    34918,
+    34919,
+    34920,
+    34921,
+    34922,
+    34923,
+    34924,
+    34925,
+    34926,
+    34927,
+    34928,
+    34929,
+    34930,
+    34931,
+    34932,
+    34933,
+    34934,
+    34935,
+    34936,
+    34937,
+    34920,
+    34939,
+    34940,
+    34941,
+    34942,
+    34943,
+    34944,
+    34945,
+    34946,
+    34947,
+    34948,
+    34949,
+    34950,
+    34951,
+    34952,
+    34953,
+    34954,
+    34955,
+    34955,
+    34957,
+    34958,
+    34959,
+    34960,
+    34961,
+    34962,
+    34963,
+    34964,
+    34965,
+    34966,
+    34967,
+    34968,
+    34969,
+    34970,
+    34970,
+    34972,
+    34973,
+    34974,
+    34975,
+    34976,
+    34977,
+    34978,
+    34979,
+    34979,
+    34979,
+    34982,
+    34977,
+    34984,
+    34985,
+    34977,
+    34987,
+    34988,
+    34989,
+    34990,
+    34991,
+    34991,
+    34993,
+    34994,
+    34995,
+    34996,
+    34997,
+    34998,
+    34999,
+    34999,
+    35001,
+    35001,
+    35003,
+    35003,
+    35003,
+    35006,
+    35007,
+    35007,
+    35009,
+    35010,
+    35011,
+    35012,
+    35013,
+    35014,
+    35015,
+    35016,
+    35016,
+    35018,
+    35019,
+    35020,
+    35020,
+    35022,
+    35023,
+    35024,
+    35025,
+    35026,
+    35027,
+    35028,
+    35029,
+    35029,
+    35031,
+    35032,
+    35029,
+    35034,
+    35035,
+    35036,
+    35037,
+    35038,
+    35039,
+    35039,
+    35041,
+    35042,
+    35043,
+    35044,
+    35045,
+    35046,
+    35047,
+    35048,
+    35048,
+    35050,
+    35051,
+    35052,
+    35053,
+    35053,
+    35053,
+    35056,
+    35057,
+    35058,
+    35059,
+    35060,
+    35061,
+    35062,
+    35063,
+    35064,
+    35065,
+    35065,
+    35067,
+    35065,
+    35069,
+    35069,
+    35071,
+    35072,
+    35069,
+    35074,
+    35075,
+    35075,
+    35077,
+    35078,
+    35079,
+    35080,
+    35078,
+    35082,
+    35083,
+    35084,
+    35085,
+    35086,
+    35087,
+    35088,
+    35089,
+    35090,
+    35091,
+    35092,
+    35093,
+    35094,
+    35095,
+    35096,
+    35097,
+    35098,
+    35099,
+    35100,
+    35101,
+    35102,
+    35103,
+    35104,
+    35104,
+    35106,
+    35107,
+    35107,
+    35109,
+    35110,
+    35110,
+    35112,
+    35112,
+    35114,
+    35115,
+    35116,
+    35117,
+    35117,
+    35119,
+    35120,
+    35121,
+    35120,
+    35123,
+    35124,
+    35125,
+    35126,
+    35127,
+    35128,
+    35129,
+    35130,
+    35131,
+    35132,
+    35132,
+    35134,
+    35135,
+    35136,
+    35137,
+    35138,
+    35139,
+    35140,
+    35141,
+    35142,
+    35143,
+    35144,
+    35145,
+    35146,
+    35147,
+    35148,
+    35149,
+    35150,
+    35149,
+    35152,
+    35153,
+    35154,
+    35155,
+    35156,
+    35157,
+    35158,
+    35159,
+    35160,
+    35161,
+    35162,
+    35163,
+    35164,
+    35165,
+    35166,
+    35167,
+    35168,
+    35169,
+    35170,
+    35171,
+    35172,
+    35173,
+    35174,
+    35175,
+    35176,
+    35177,
+    35178,
+    35179,
+    35180,
+    35181,
+    35182,
+    35183,
+    35184,
+    35185,
+    35186,
+    35187,
+    35187,
+    35189,
+    35187,
+    35191,
+    35191,
+    35193,
+    35194,
+    35195,
+    35196,
+    35197,
+    35198,
+    35199,
+    35200,
+    35201,
+    35202,
+    35203,
+    35204,
+    35203,
+    35206,
+    35206,
+    35208,
+    35209,
+    35210,
+    35211,
+    35211,
+    35213,
+    35214,
+    35215,
+    35216,
+    35217,
+    35218,
+    35219,
+    35220,
+    35221,
+    35222,
+    35223,
+    35224,
+    35225,
+    35226,
+    35227,
+    35228,
+    35229,
+    35230,
+    35231,
+    35232,
+    35233,
+    35234,
+    35235,
+    35236,
+    35237,
+    35238,
+    35239,
+    35240,
+    35241,
+    35242,
+    35243,
+    35244,
+    35244,
+    35246,
+    35247,
+    35248,
+    35246,
+    35250,
+    35251,
+    35252,
+    35253,
+    35254,
+    35255,
+    35254,
+    35257,
+    35258,
+    35259,
+    35260,
+    35260,
+    35260,
+    35263,
+    35264,
+    35265,
+    35266,
+    35267,
+    35267,
+    35267,
+    35266,
+    35271,
+    35272,
+    35271,
+    35274,
+    35274,
+    35274,
+    35274,
+    35274,
+    35279,
+    35279,
+    35281,
+    35281,
+    35283,
+    35284,
+    35284,
+    35281,
+    35283,
+    35281,
+    35289,
+    35289,
+    35291,
+    35291,
+    35293,
+    35293,
+    35293,
+    35293,
+    35266,
+    35298,
+    35298,
+    35298,
+    35301,
+    35301,
+    35303,
+    35303,
+    35298,
+    35306,
+    35306,
+    35308,
+    35309,
+    35309,
+    35311,
+    35312,
+    35313,
+    35308,
+    35315,
+    35315,
+    35317,
+    35317,
+    35319,
+    35319,
+    35321,
+    35322,
+    35323,
+    35324,
+    35325,
+    35317,
+    35327,
+    35327,
+    35329,
+    35329,
+    35327,
+    35327,
+    35327,
+    35317,
+    35335,
+    35336,
+    35336,
+    35338,
+    35338,
+    35340,
+    35340,
+    35342,
+    35343,
+    35343,
+    35342,
+    35346,
+    35347,
+    35348,
+    35348,
+    35346,
+    35351,
+    35352,
+    35352,
+    35354,
+    35355,
+    35355,
+    35351,
+    35358,
+    35358,
+    35360,
+    35361,
+    35362,
+    35362,
+    35364,
+    35364,
+    35366,
+    35367,
+    35367,
+    35366,
+    35370,
+    35370,
+    35372,
+    35372,
+    35372,
+    35366,
+    35376,
+    35377,
+    35377,
+    35361,
+    35380,
+    35381,
+    35381,
+    35383,
+    35383,
+    35385,
+    35386,
+    35387,
+    35387,
+    35389,
+    35390,
+    35390,
+    35386,
+    35393,
+    35393,
+    35395,
+    35396,
+    35397,
+    35386,
+    35399,
+    35386,
+    35397,
+    35396,
+    35385,
+    35404,
+    35405,
+    35405,
+    35407,
+    35405,
+    35409,
+    35410,
+    35411,
+    35412,
+    35413,
+    35405,
+    35415,
+    35415,
+    35415,
+    35415,
+    35419,
+    35420,
+    35421,
+    35421,
+    35420,
+    35424,
+    35425,
+    35426,
+    35426,
+    35428,
+    35428,
+    35430,
+    35431,
+    35431,
+    35433,
+    35433,
+    35435,
+    35436,
+    35437,
+    35431,
+    35431,
+    35431,
+    35437,
+    35442,
+    35443,
+    35443,
+    35443,
+    35446,
+    35446,
+    35448,
+    35449,
+    35450,
+    35450,
+    35452,
+    35453,
+    35450,
+    35452,
+    35450,
+    35457,
+    35457,
+    35459,
+    35459,
+    35461,
+    35461,
+    35463,
+    35464,
+    35464,
+    35459,
+    35467,
+    35459,
+    35469,
+    35470,
+    35470,
+    35472,
+    35472,
+    35474,
+    35474,
+    35474,
+    35472,
+    35472,
+    35479,
+    35479,
+    35481,
+    35481,
+    35481,
+    35472,
+    35449,
+    35486,
+    35487,
+    35488,
+    35488,
+    35490,
+    35491,
+    35491,
+    35493,
+    35493,
+    35495,
+    35496,
+    35496,
+    35498,
+    35499,
+    35500,
+    35500,
+    35502,
+    35502,
+    35500,
+    35505,
+    35506,
+    35507,
+    35507,
+    35507,
+    35510,
+    35507,
+    35491,
+    35513,
+    35514,
+    35514,
+    35516,
+    35516,
+    35518,
+    35518,
+    35520,
+    35520,
+    35516,
+    35523,
+    35524,
+    35524,
+    35486,
+    35527,
+    35528,
+    35528,
+    35530,
+    35530,
+    35532,
+    35533,
+    35533,
+    35535,
+    35536,
+    35536,
+    35536,
+    35533,
+    35540,
+    35540,
+    35542,
+    35542,
+    35544,
+    35533,
+    35533,
+    35547,
+    35547,
+    35549,
+    35532,
+    35551,
+    35551,
+    35553,
+    35554,
+    35555,
+    35556,
+    35557,
+    35558,
+    35555,
+    35560,
+    35560,
+    35562,
+    35562,
+    35562,
+    35565,
+    35565,
+    35562,
+    35568,
+    35569,
+    35569,
+    35568,
+    35572,
+    35573,
+    35573,
+    35575,
+    35575,
+    35572,
+    35578,
+    35579,
+    35579,
+    35578,
+    35553,
+    35583,
+    35584,
+    35585,
+    35585,
+    35587,
+    35588,
+    35585,
+    35590,
+    35590,
+    35592,
+    35590,
+    35594,
+    35595,
+    35596,
+    35596,
+    35598,
+    35598,
+    35600,
+    35600,
+    35594,
+    35603,
+    35604,
+    35604,
+    35603,
+    35607,
+    35608,
+    35609,
+    35608,
+    35607,
+    35612,
+    35612,
+    35614,
+    35615,
+    35607,
+    35617,
+    35617,
+    35619,
+    35620,
+    35621,
+    35620,
+    35623,
+    35623,
+    35607,
+    35626,
+    35626,
+    35626,
+    35629,
+    35629,
+    35631,
+    35632,
+    35632,
+    35629,
+    35635,
+    35636,
+    35635,
+    35635,
+    35639,
+    35629,
+    35641,
+    35635,
+    35643,
+    35644,
+    35644,
+    35646,
+    35646,
+    35648,
+    35649,
+    35650,
+    35651,
+    35652,
+    35652,
+    35654,
+    35655,
+    35655,
+    35657,
+    35658,
+    35658,
+    35658,
+    35661,
+    35661,
+    35663,
+    35663,
+    35665,
+    35665,
+    35667,
+    35667,
+    35669,
+    35669,
+    35669,
+    35672,
+    35672,
+    35674,
+    35674,
+    35676,
+    35677,
+    35677,
+    35679,
+    35680,
+    35676,
+    35676,
+    35683,
+    35654,
+    35685,
+    35686,
+    35687,
+    35688,
+    35689,
+    35690,
+    35691,
+    35692,
+    35693,
+    35693,
+    35692,
+    35690,
+    35697,
+    35689,
+    35699,
+    35700,
+    35701,
+    35702,
+    35702,
+    35699,
+    35705,
+    35706,
+    35707,
+    35707,
+    35706,
+    35710,
+    35711,
+    35712,
+    35710,
+    35714,
+    35715,
+    35715,
+    35717,
+    35718,
+    35719,
+    35714,
+    35721,
+    35721,
+    35723,
+    35724,
+    35725,
+    35725,
+    35727,
+    35728,
+    35728,
+    35727,
+    35731,
+    35732,
+    35733,
+    35734,
+    35735,
+    35735,
+    35737,
+    35738,
+    35738,
+    35740,
+    35741,
+    35742,
+    35743,
+    35744,
+    35745,
+    35731,
+    35747,
+    35748,
+    35749,
+    35749,
+    35751,
+    35752,
+    35751,
+    35754,
+    35754,
+    35756,
+    35757,
+    35757,
+    35756,
+    35751,
+    35761,
+    35761,
+    35763,
+    35764,
+    35765,
+    35766,
+    35767,
+    35767,
+    35769,
+    35770,
+    35771,
+    35772,
+    35771,
+    35770,
+    35775,
+    35775,
+    35777,
+    35777,
+    35779,
+    35779,
+    35781,
+    35781,
+    35783,
+    35783,
+    35764,
+    35786,
+    35787,
+    35788,
+    35789,
+    35790,
+    35791,
+    35791,
+    35793,
+    35794,
+    35794,
+    35793,
+    35797,
+    35797,
+    35799,
+    35800,
+    35801,
+    35801,
+    35803,
+    35803,
+    35805,
+    35805,
+    35807,
+    35807,
+    35809,
+    35809,
+    35811,
+    35812,
+    35813,
+    35814,
+    35814,
+    35816,
+    35817,
+    35817,
+    35819,
+    35819,
+    35821,
+    35822,
+    35823,
+    35824,
+    35825,
+    35826,
+    35826,
+    35824,
+    35829,
+    35830,
+    35824,
+    35832,
+    35832,
+    35834,
+    35834,
+    35836,
+    35837,
+    35838,
+    35839,
+    35840,
+    35841,
+    35841,
+    35841,
+    35844,
+    35845,
+    35846,
+    35846,
+    35846,
+    35846,
+    35850,
+    35851,
+    35851,
+    35853,
+    35853,
+    35855,
+    35855,
+    35857,
+    35857,
+    35859,
+    35860,
+    35861,
+    35861,
+    35860,
+    35864,
+    35864,
+    35866,
+    35866,
+    35868,
+    35868,
+    35870,
+    35866,
+    35872,
+    35873,
+    35873,
+    35875,
+    35875,
+    35877,
+    35877,
+    35875,
+    35875,
+    35875,
+    35882,
+    35883,
+    35884,
+    35885,
+    35886,
+    35887,
+    35886,
+    35889,
+    35890,
+    35890,
+    35892,
+    35892,
+    35894,
+    35895,
+    35896,
+    35897,
+    35898,
+    35899,
+    35900,
+    35900,
+    35898,
+    35903,
+    35904,
+    35905,
+    35906,
+    35907,
+    35908,
+    35908,
+    35910,
+    35910,
+    35912,
+    35913,
+    35913,
+    35915,
+    35916,
+    35916,
+    35918,
+    35912,
+    35920,
+    35921,
+    35922,
+    35923,
+    35924,
+    35925,
+    35926,
+    35927,
+    35927,
+    35929,
+    35930,
+    35931,
+    35932,
+    35932,
+    35931,
+    35935,
+    35936,
+    35937,
+    35937,
+    35939,
+    35940,
+    35941,
+    35936,
+    35943,
+    35944,
+    35945,
+    35945,
+    35947,
+    35948,
+    35948,
+    35950,
+    35951,
+    35952,
+    35952,
+    35954,
+    35955,
+    35955,
+    35957,
+    35958,
+    35959,
+    35960,
+    35961,
+    35961,
+    35958,
+    35964,
+    35951,
+    35966,
+    35967,
+    35967,
+    35969,
+    35969,
+    35971,
+    35971,
+    35973,
+    35974,
+    35974,
+    35976,
+    35977,
+    35976,
+    35979,
+    35980,
+    35980,
+    35979,
+    35979,
+    35984,
+    35985,
+    35986,
+    35986,
+    35988,
+    35988,
+    35986,
+    35986,
+    35992,
+    35993,
+    35994,
+    35994,
+    35996,
+    35994,
+    35998,
+    35999,
+    36000,
+    36000,
+    36002,
+    36002,
+    36004,
+    36005,
+    36005,
+    36007,
+    36008,
+    36008,
+    36007,
+    36011,
+    36012,
+    36007,
+    36014,
+    36015,
+    36016,
+    36016,
+    36015,
+    36019,
+    36020,
+    36020,
+    36020,
+    36020,
+    36024,
+    36025,
+    36025,
+    36027,
+    36027,
+    36029,
+    36029,
+    36029,
+    36032,
+    36033,
+    36029,
+    36035,
+    36036,
+    36036,
+    36035,
+    36035,
+    36040,
+    36041,
+    36041,
+    36041,
+    36044,
+    36045,
+    36046,
+    36047,
+    36048,
+    36049,
+    36049,
+    36051,
+    36051,
+    36053,
+    36051,
+    36055,
+    36056,
+    36056,
+    36058,
+    36058,
+    36060,
+    36061,
+    36061,
+    36063,
+    36064,
+    36064,
+    36064,
+    36067,
+    36067,
+    36067,
+    36070,
+    36071,
+    36072,
+    36073,
+    36073,
+    36075,
+    36075,
+    36077,
+    36071,
+    36079,
+    36080,
+    36080,
+    36082,
+    36082,
+    36084,
+    36084,
+    36086,
+    36086,
+    36088,
+    36088,
+    36090,
+    36090,
+    36092,
+    36092,
+    36092,
+    36095,
+    36095,
+    36097,
+    36097,
+    36092,
+    36100,
+    36101,
+    36102,
+    36102,
+    36104,
+    36104,
+    36102,
+    36107,
+    36102,
+    36109,
+    36110,
+    36109,
+    36112,
+    36113,
+    36113,
+    36113,
+    36116,
+    36116,
+    36118,
+    36119,
+    36119,
+    36121,
+    36122,
+    36123,
+    36123,
+    36125,
+    36125,
+    36127,
+    36127,
+    36125,
+    36125,
+    36131,
+    36132,
+    36133,
+    36133,
+    36135,
+    36135,
+    36137,
+    36137,
+    36139,
+    36140,
+    36141,
+    36142,
+    36142,
+    36144,
+    36144,
+    36146,
+    36147,
+    36148,
+    36149,
+    36150,
+    36149,
+    36152,
+    36153,
+    36153,
+    36152,
+    36156,
+    36157,
+    36158,
+    36159,
+    36160,
+    36160,
+    36162,
+    36162,
+    36164,
+    36165,
+    36165,
+    36167,
+    36167,
+    36169,
+    36170,
+    36170,
+    36172,
+    36172,
+    36174,
+    36175,
+    36175,
+    36177,
+    36177,
+    36175,
+    36180,
+    36170,
+    36182,
+    36164,
+    36184,
+    36185,
+    36186,
+    36187,
+    36187,
+    36186,
+    36190,
+    36190,
+    36184,
+    36193,
+    36194,
+    36195,
+    36195,
+    36197,
+    36198,
+    36199,
+    36199,
+    36194,
+    36202,
+    36203,
+    36204,
+    36204,
+    36206,
+    36203,
+    36208,
+    36208,
+    36210,
+    36210,
+    36202,
+    36213,
+    36214,
+    36214,
+    36216,
+    36216,
+    36218,
+    36219,
+    36219,
+    36221,
+    36222,
+    36222,
+    36224,
+    36224,
+    36226,
+    36226,
+    36228,
+    36224,
+    36218,
+    36231,
+    36232,
+    36232,
+    36231,
+    36235,
+    36236,
+    36235,
+    36238,
+    36239,
+    36240,
+    36241,
+    36242,
+    36242,
+    36241,
+    36245,
+    36246,
+    36246,
+    36245,
+    36249,
+    36250,
+    36251,
+    36252,
+    36252,
+    36245,
+    36255,
+    36255,
+    36257,
+    36258,
+    36259,
+    36260,
+    36261,
+    36262,
+    36263,
+    36263,
+    36265,
+    36265,
+    36267,
+    36268,
+    36269,
+    36270,
+    36269,
+    36272,
+    36267,
+    36274,
+    36275,
+    36276,
+    36275,
+    36274,
+    36274,
+    36280,
+    36280,
+    36282,
+    36283,
+    36283,
+    36283,
+    36286,
+    36286,
+    36288,
+    36288,
+    36286,
+    36291,
+    36282,
+    36293,
+    36294,
+    36294,
+    36296,
+    36296,
+    36298,
+    36298,
+    36296,
+    36301,
+    36293,
+    36261,
+    36304,
+    36305,
+    36306,
+    36306,
+    36308,
+    36308,
+    36310,
+    36311,
+    36311,
+    36313,
+    36314,
+    36315,
+    36315,
+    36317,
+    36318,
+    36318,
+    36320,
+    36318,
+    36322,
+    36323,
+    36323,
+    36323,
+    36326,
+    36326,
+    36328,
+    36329,
+    36330,
+    36331,
+    36332,
+    36332,
+    36334,
+    36334,
+    36336,
+    36337,
+    36338,
+    36339,
+    36340,
+    36336,
+    36338,
+    36338,
+    36344,
+    36345,
+    36346,
+    36347,
+    36347,
+    36349,
+    36350,
+    36350,
+    36350,
+    36353,
+    36354,
+    36354,
+    36354,
+    36354,
+    36349,
+    36359,
+    36360,
+    36361,
+    36361,
+    36363,
+    36364,
+    36360,
+    36364,
+    36367,
+    36368,
+    36368,
+    36370,
+    36370,
+    36372,
+    36372,
+    36374,
+    36367,
+    36376,
+    36376,
+    36378,
+    36378,
+    36367,
+    36381,
+    36382,
+    36382,
+    36384,
+    36385,
+    36386,
+    36386,
+    36385,
+    36389,
+    36390,
+    36390,
+    36390,
+    36384,
+    36394,
+    36394,
+    36396,
+    36397,
+    36397,
+    36399,
+    36399,
+    36397,
+    36396,
+    36403,
+    36404,
+    36404,
+    36406,
+    36406,
+    36408,
+    36409,
+    36408,
+    36411,
+    36411,
+    36406,
+    36406,
+    36415,
+    36416,
+    36417,
+    36417,
+    36416,
+    36420,
+    36421,
+    36420,
+    36415,
+    36424,
+    36425,
+    36425,
+    36425,
+    36425,
+    36429,
+    36430,
+    36431,
+    36432,
+    36433,
+    36433,
+    36432,
+    36430,
+    36437,
+    36438,
+    36438,
+    36440,
+    36441,
+    36442,
+    36442,
+    36441,
+    36445,
+    36445,
+    36447,
+    36440,
+    36440,
+    36450,
+    36440,
+    36452,
+    36452,
+    36454,
+    36437,
+    36456,
+    36457,
+    36458,
+    36459,
+    36460,
+    36460,
+    36459,
+    36463,
+    36464,
+    36464,
+    36459,
+    36467,
+    36468,
+    36469,
+    36469,
+    36468,
+    36472,
+    36472,
+    36468,
+    36475,
+    36475,
+    36475,
+    36478,
+    36478,
+    36480,
+    36481,
+    36482,
+    36483,
+    36484,
+    36485,
+    36484,
+    36456,
+    36488,
+    36489,
+    36490,
+    36490,
+    36492,
+    36493,
+    36494,
+    36495,
+    36495,
+    36497,
+    36498,
+    36498,
+    36500,
+    36501,
+    36501,
+    36498,
+    36504,
+    36505,
+    36506,
+    36506,
+    36508,
+    36505,
+    36510,
+    36511,
+    36512,
+    36513,
+    36514,
+    36514,
+    36513,
+    36498,
+    36511,
+    36519,
+    36519,
+    36521,
+    36494,
+    36523,
+    36524,
+    36525,
+    36526,
+    36526,
+    36528,
+    36529,
+    36530,
+    36530,
+    36532,
+    36529,
+    36534,
+    36535,
+    36536,
+    36535,
+    36535,
+    36535,
+    36540,
+    36541,
+    36534,
+    36543,
+    36543,
+    36545,
+    36546,
+    36546,
+    36548,
+    36549,
+    36550,
+    36551,
+    36551,
+    36553,
+    36554,
+    36553,
+    36556,
+    36556,
+    36556,
+    36559,
+    36556,
+    36528,
+    36562,
+    36563,
+    36564,
+    36565,
+    36566,
+    36567,
+    36568,
+    36567,
+    36570,
+    36548,
+    36572,
+    36573,
+    36574,
+    36575,
+    36576,
+    36576,
+    36578,
+    36579,
+    36580,
+    36581,
+    36581,
+    36583,
+    36583,
+    36581,
+    36586,
+    36587,
+    36581,
+    36589,
+    36590,
+    36590,
+    36592,
+    36592,
+    36594,
+    36594,
+    36596,
+    36597,
+    36597,
+    36599,
+    36599,
+    36601,
+    36602,
+    36603,
+    36604,
+    36597,
+    36603,
+    36607,
+    36607,
+    36609,
+    36609,
+    36611,
+    36611,
+    36613,
+    36613,
+    36613,
+    36613,
+    36617,
+    36618,
+    36619,
+    36619,
+    36613,
+    36622,
+    36622,
+    36624,
+    36622,
+    36626,
+    36626,
+    36626,
+    36629,
+    36630,
+    36631,
+    36631,
+    36631,
+    36631,
+    36635,
+    36635,
+    36637,
+    36638,
+    36639,
+    36640,
+    36640,
+    36642,
+    36643,
+    36642,
+    36645,
+    36638,
+    36647,
+    36647,
+    36647,
+    36647,
+    36651,
+    36651,
+    36653,
+    36651,
+    36655,
+    36656,
+    36656,
+    36658,
+    36659,
+    36638,
+    36661,
+    36662,
+    36662,
+    36664,
+    36665,
+    36666,
+    36667,
+    36664,
+    36669,
+    36670,
+    36666,
+    36672,
+    36672,
+    36638,
+    36675,
+    36676,
+    36659,
+    36678,
+    36679,
+    36680,
+    36680,
+    36682,
+    36683,
+    36684,
+    36683,
+    36686,
+    36687,
+    36687,
+    36686,
+    36690,
+    36690,
+    36692,
+    36692,
+    36690,
+    36695,
+    36696,
+    36696,
+    36698,
+    36690,
+    36700,
+    36700,
+    36702,
+    36702,
+    36702,
+    36705,
+    36705,
+    36707,
+    36707,
+    36707,
+    36707,
+    36702,
+    36702,
+    36713,
+    36714,
+    36714,
+    36713,
+    36713,
+    36713,
+    36719,
+    36713,
+    36721,
+    36722,
+    36723,
+    36723,
+    36723,
+    36723,
+    36722,
+    36728,
+    36728,
+    36728,
+    36731,
+    36732,
+    36733,
+    36733,
+    36735,
+    36736,
+    36736,
+    36736,
+    36739,
+    36739,
+    36739,
+    36742,
+    36742,
+    36744,
+    36744,
+    36742,
+    36747,
+    36747,
+    36749,
+    36750,
+    36750,
+    36750,
+    36753,
+    36753,
+    36755,
+    36755,
+    36755,
+    36758,
+    36759,
+    36759,
+    36759,
+    36759,
+    36763,
+    36755,
+    36765,
+    36749,
+    36767,
+    36767,
+    36767,
+    36770,
+    36770,
+    36772,
+    36772,
+    36772,
+    36775,
+    36775,
+    36777,
+    36778,
+    36779,
+    36780,
+    36781,
+    36781,
+    36783,
+    36784,
+    36785,
+    36786,
+    36787,
+    36787,
+    36787,
+    36790,
+    36790,
+    36792,
+    36793,
+    36793,
+    36795,
+    36796,
+    36796,
+    36786,
+    36799,
+    36799,
+    36801,
+    36799,
+    36803,
+    36804,
+    36805,
+    36805,
+    36805,
+    36805,
+    36809,
+    36810,
+    36810,
+    36812,
+    36812,
+    36810,
+    36809,
+    36816,
+    36817,
+    36818,
+    36818,
+    36820,
+    36820,
+    36818,
+    36818,
+    36824,
+    36825,
+    36826,
+    36826,
+    36824,
+    36829,
+    36830,
+    36830,
+    36832,
+    36830,
+    36834,
+    36835,
+    36836,
+    36837,
+    36838,
+    36839,
+    36840,
+    36841,
+    36841,
+    36843,
+    36843,
+    36845,
+    36846,
+    36847,
+    36848,
+    36845,
+    36850,
+    36850,
+    36852,
+    36853,
+    36854,
+    36855,
+    36853,
+    36857,
+    36858,
+    36859,
+    36859,
+    36858,
+    36862,
+    36863,
+    36864,
+    36864,
+    36866,
+    36867,
+    36868,
+    36866,
+    36870,
+    36871,
+    36872,
+    36872,
+    36874,
+    36874,
+    36876,
+    36876,
+    36876,
+    36879,
+    36879,
+    36881,
+    36881,
+    36879,
+    36884,
+    36884,
+    36884,
+    36887,
+    36887,
+    36889,
+    36889,
+    36891,
+    36891,
+    36893,
+    36893,
+    36895,
+    36896,
+    36896,
+    36898,
+    36898,
+    36900,
+    36900,
+    36902,
+    36902,
+    36900,
+    36905,
+    36906,
+    36906,
+    36908,
+    36908,
+    36910,
+    36910,
+    36910,
+    36913,
+    36908,
+    36915,
+    36916,
+    36917,
+    36917,
+    36919,
+    36920,
+    36921,
+    36921,
+    36923,
+    36921,
+    36925,
+    36925,
+    36925,
+    36928,
+    36928,
+    36930,
+    36931,
+    36932,
+    36933,
+    36934,
+    36934,
+    36936,
+    36937,
+    36938,
+    36937,
+    36936,
+    36931,
+    36942,
+    36942,
+    36944,
+    36945,
+    36946,
+    36947,
+    36948,
+    36947,
+    36950,
+    36951,
+    36952,
+    36952,
+    36954,
+    36954,
+    36954,
+    36957,
+    36958,
+    36950,
+    36960,
+    36961,
+    36961,
+    36963,
+    36963,
+    36960,
+    36966,
+    36967,
+    36968,
+    36968,
+    36970,
+    36971,
+    36970,
+    36973,
+    36974,
+    36974,
+    36976,
+    36976,
+    36976,
+    36979,
+    36980,
+    36981,
+    36982,
+    36983,
+    36984,
+    36983,
+    36986,
+    36987,
+    36967,
+    36989,
+    36990,
+    36991,
+    36992,
+    36993,
+    36992,
+    36992,
+    36996,
+    36996,
+    36998,
+    36999,
+    36999,
+    36999,
+    36999,
+    37003,
+    37004,
+    37004,
+    37006,
+    37006,
+    37008,
+    37009,
+    37009,
+    37011,
+    37011,
+    37011,
+    37011,
+    37015,
+    37016,
+    37015,
+    37018,
+    37018,
+    37020,
+    37020,
+    37022,
+    37023,
+    37024,
+    37024,
+    37024,
+    37024,
+    37028,
+    37028,
+    37030,
+    37031,
+    37031,
+    37031,
+    37031,
+    37030,
+    37036,
+    37037,
+    37037,
+    37039,
+    37040,
+    37040,
+    37042,
+    37036,
+    37044,
+    37045,
+    37045,
+    37047,
+    37048,
+    37049,
+    37049,
+    37049,
+    37049,
+    37053,
+    37054,
+    37054,
+    37053,
+    37057,
+    37058,
+    37058,
+    37057,
+    37061,
+    37062,
+    37063,
+    37063,
+    37063,
+    37063,
+    37067,
+    37067,
+    37069,
+    37069,
+    37069,
+    37072,
+    37072,
+    37072,
+    37075,
+    37075,
+    37072,
+    37078,
+    37079,
+    37079,
+    37081,
+    37081,
+    37083,
+    37083,
+    37083,
+    37081,
+    37081,
+    37088,
+    37088,
+    37090,
+    37090,
+    37092,
+    37092,
+    37092,
+    37092,
+    37096,
+    37097,
+    37098,
+    37098,
+    37096,
+    37096,
+    37096,
+    37103,
+    37103,
+    37103,
+    37106,
+    37106,
+    37103,
+    37109,
+    37109,
+    37111,
+    37112,
+    37096,
+    37114,
+    37115,
+    37115,
+    37117,
+    37117,
+    37114,
+    37120,
+    37120,
+    37120,
+    37120,
+    37124,
+    37124,
+    37120,
+    37127,
+    37128,
+    37129,
+    37129,
+    37129,
+    37129,
+    37133,
+    37134,
+    37134,
+    37136,
+    37136,
+    37138,
+    37136,
+    37140,
+    37141,
+    37141,
+    37143,
+    37144,
+    37145,
+    37144,
+    37147,
+    37148,
+    37149,
+    37150,
+    37151,
+    37152,
+    37153,
+    37154,
+    37154,
+    37154,
+    37157,
+    37158,
+    37159,
+    37160,
+    37161,
+    37161,
+    37163,
+    37164,
+    37165,
+    37166,
+    37167,
+    37164,
+    37169,
+    37169,
+    37171,
+    37172,
+    37171,
+    37169,
+    37175,
+    37175,
+    37177,
+    37177,
+    37179,
+    37180,
+    37169,
+    37169,
+    37183,
+    37163,
+    37185,
+    37186,
+    37187,
+    37187,
+    37189,
+    37189,
+    37186,
+    37192,
+    37192,
+    37192,
+    37186,
+    37186,
+    37186,
+    37198,
+    37199,
+    37199,
+    37201,
+    37202,
+    37203,
+    37204,
+    37204,
+    37206,
+    37206,
+    37202,
+    37209,
+    37210,
+    37210,
+    37209,
+    37213,
+    37214,
+    37214,
+    37216,
+    37217,
+    37217,
+    37219,
+    37219,
+    37221,
+    37222,
+    37223,
+    37224,
+    37225,
+    37225,
+    37227,
+    37228,
+    37228,
+    37225,
+    37225,
+    37225,
+    37233,
+    37233,
+    37235,
+    37235,
+    37237,
+    37237,
+    37239,
+    37240,
+    37241,
+    37242,
+    37243,
+    37244,
+    37242,
+    37246,
+    37246,
+    37246,
+    37249,
+    37249,
+    37246,
+    37252,
+    37224,
+    37254,
+    37255,
+    37256,
+    37257,
+    37255,
+    37259,
+    37260,
+    37261,
+    37261,
+    37259,
+    37264,
+    37265,
+    37264,
+    37267,
+    37267,
+    37264,
+    37270,
+    37270,
+    37272,
+    37273,
+    37274,
+    37275,
+    37275,
+    37272,
+    37254,
+    37279,
+    37280,
+    37281,
+    37282,
+    37282,
+    37284,
+    37284,
+    37286,
+    37286,
+    37280,
+    37289,
+    37289,
+    37291,
+    37291,
+    37291,
+    37294,
+    37295,
+    37296,
+    37296,
+    37298,
+    37298,
+    37300,
+    37301,
+    37301,
+    37300,
+    37304,
+    37305,
+    37304,
+    37307,
+    37307,
+    37309,
+    37307,
+    37311,
+    37312,
+    37313,
+    37307,
+    37315,
+    37316,
+    37317,
+    37318,
+    37319,
+    37320,
+    37320,
+    37322,
+    37323,
+    37323,
+    37325,
+    37322,
+    37327,
+    37328,
+    37328,
+    37328,
+    37327,
+    37332,
+    37333,
+    37332,
+    37335,
+    37335,
+    37337,
+    37338,
+    37338,
+    37340,
+    37340,
+    37332,
+    37343,
+    37344,
+    37344,
+    37346,
+    37347,
+    37348,
+    37349,
+    37349,
+    37351,
+    37352,
+    37349,
+    37354,
+    37355,
+    37355,
+    37357,
+    37358,
+    37355,
+    37360,
+    37361,
+    37362,
+    37362,
+    37361,
+    37365,
+    37365,
+    37367,
+    37368,
+    37368,
+    37370,
+    37370,
+    37372,
+    37373,
+    37373,
+    37375,
+    37375,
+    37377,
+    37378,
+    37378,
+    37380,
+    37380,
+    37378,
+    37373,
+    37384,
+    37385,
+    37386,
+    37386,
+    37367,
+    37389,
+    37390,
+    37390,
+    37392,
+    37392,
+    37392,
+    37395,
+    37396,
+    37396,
+    37398,
+    37399,
+    37400,
+    37400,
+    37402,
+    37402,
+    37404,
+    37405,
+    37405,
+    37407,
+    37407,
+    37405,
+    37410,
+    37410,
+    37410,
+    37413,
+    37414,
+    37414,
+    37416,
+    37416,
+    37414,
+    37419,
+    37413,
+    37421,
+    37404,
+    37423,
+    37424,
+    37425,
+    37425,
+    37425,
+    37428,
+    37429,
+    37429,
+    37431,
+    37432,
+    37433,
+    37433,
+    37431,
+    37436,
+    37437,
+    37437,
+    37439,
+    37440,
+    37441,
+    37442,
+    37443,
+    37443,
+    37441,
+    37446,
+    37446,
+    37448,
+    37449,
+    37450,
+    37450,
+    37452,
+    37453,
+    37454,
+    37454,
+    37456,
+    37457,
+    37458,
+    37459,
+    37460,
+    37461,
+    37457,
+    37456,
+    37464,
+    37465,
+    37465,
+    37465,
+    37468,
+    37469,
+    37470,
+    37471,
+    37471,
+    37470,
+    37470,
+    37475,
+    37476,
+    37475,
+    37478,
+    37479,
+    37478,
+    37481,
+    37482,
+    37483,
+    37484,
+    37485,
+    37486,
+    37470,
+    37470,
+    37489,
+    37489,
+    37491,
+    37491,
+    37493,
+    37494,
+    37494,
+    37494,
+    37494,
+    37498,
+    37499,
+    37498,
+    37501,
+    37502,
+    37503,
+    37504,
+    37504,
+    37506,
+    37503,
+    37508,
+    37508,
+    37510,
+    37511,
+    37512,
+    37513,
+    37514,
+    37515,
+    37516,
+    37516,
+    37514,
+    37519,
+    37520,
+    37520,
+    37522,
+    37522,
+    37524,
+    37524,
+    37526,
+    37527,
+    37528,
+    37529,
+    37527,
+    37531,
+    37532,
+    37533,
+    37533,
+    37535,
+    37535,
+    37537,
+    37538,
+    37538,
+    37540,
+    37540,
+    37542,
+    37543,
+    37544,
+    37542,
+    37542,
+    37547,
+    37548,
+    37548,
+    37547,
+    37551,
+    37552,
+    37553,
+    37554,
+    37555,
+    37556,
+    37557,
+    37553,
+    37559,
+    37560,
+    37559,
+    37562,
+    37563,
+    37564,
+    37563,
+    37566,
+    37567,
+    37568,
+    37569,
+    37570,
+    37571,
+    37572,
+    37573,
+    37568,
+    37575,
+    37576,
+    37577,
+    37577,
+    37579,
+    37580,
+    37580,
+    37582,
+    37583,
+    37583,
+    37585,
+    37585,
+    37585,
+    37588,
+    37589,
+    37589,
+    37591,
+    37592,
+    37593,
+    37594,
+    37595,
+    37591,
+    37597,
+    37598,
+    37599,
+    37599,
+    37601,
+    37597,
+    37603,
+    37604,
+    37605,
+    37606,
+    37607,
+    37607,
+    37606,
+    37610,
+    37610,
+    37612,
+    37612,
+    37614,
+    37614,
+    37616,
+    37616,
+    37614,
+    37619,
+    37619,
+    37621,
+    37621,
+    37621,
+    37614,
+    37625,
+    37626,
+    37627,
+    37628,
+    37629,
+    37630,
+    37630,
+    37632,
+    37633,
+    37634,
+    37635,
+    37636,
+    37637,
+    37638,
+    37639,
+    37640,
+    37638,
+    37642,
+    37642,
+    37644,
+    37645,
+    37646,
+    37647,
+    37648,
+    37648,
+    37648,
+    37651,
+    37652,
+    37653,
+    37654,
+    37655,
+    37656,
+    37657,
+    37658,
+    37659,
+    37659,
+    37659,
+    37662,
+    37663,
+    37664,
+    37663,
+    37666,
+    37667,
+    37668,
+    37669,
+    37670,
+    37671,
+    37671,
+    37670,
+    37674,
+    37674,
+    37676,
+    37676,
+    37678,
+    37679,
+    37680,
+    37681,
+    37682,
+    37682,
+    37682,
+    37685,
+    37686,
+    37686,
+    37686,
+    37689,
+    37690,
+    37690,
+    37692,
+    37692,
+    37692,
+    37695,
+    37695,
+    37695,
+    37698,
+    37698,
+    37700,
+    37700,
+    37700,
+    37703,
+    37704,
+    37705,
+    37705,
+    37707,
+    37708,
+    37709,
+    37710,
+    37710,
+    37710,
+    37713,
+    37713,
+    37713,
+    37716,
+    37717,
+    37716,
+    37719,
+    37720,
+    37720,
+    37722,
+    37723,
+    37723,
+    37725,
+    37726,
+    37727,
+    37728,
+    37729,
+    37729,
+    37731,
+    37731,
+    37731,
+    37734,
+    37734,
+    37734,
+    37737,
+    37738,
+    37739,
+    37740,
+    37741,
+    37742,
+    37742,
+    37742,
+    37745,
+    37746,
+    37747,
+    37748,
+    37748,
+    37750,
+    37750,
+    37752,
+    37753,
+    37754,
+    37754,
+    37754,
+    37757,
+    37758,
+    37758,
+    37760,
+    37761,
+    37762,
+    37763,
+    37761,
+    37765,
+    37766,
+    37767,
+    37767,
+    37769,
+    37769,
+    37769,
+    37772,
+    37772,
+    37774,
+    37774,
+    37776,
+    37776,
+    37776,
+    37779,
+    37780,
+    37781,
+    37782,
+    37782,
+    37782,
+    37785,
+    37786,
+    37787,
+    37788,
+    37788,
+    37788,
+    37780,
+    37792,
+    37792,
+    37794,
+    37795,
+    37796,
+    37796,
+    37796,
+    37799,
+    37800,
+    37801,
+    37802,
+    37803,
+    37803,
+    37805,
+    37805,
+    37807,
+    37807,
+    37809,
+    37809,
+    37811,
+    37812,
+    37812,
+    37814,
+    37814,
+    37816,
+    37816,
+    37818,
+    37818,
+    37820,
+    37821,
+    37822,
+    37822,
+    37824,
+    37824,
+    37826,
+    37826,
+    37828,
+    37829,
+    37830,
+    37831,
+    37832,
+    37832,
+    37834,
+    37835,
+    37836,
+    37837,
+    37838,
+    37839,
+    37839,
+    37841,
+    37841,
+    37836,
+    37844,
+    37845,
+    37836,
+    37847,
+    37847,
+    37849,
+    37849,
+    37851,
+    37852,
+    37853,
+    37835,
+    37855,
+    37856,
+    37855,
+    37858,
+    37859,
+    37860,
+    37861,
+    37862,
+    37863,
+    37864,
+    37865,
+    37866,
+    37867,
+    37868,
+    37869,
+    37869,
+    37871,
+    37872,
+    37873,
+    37874,
+    37875,
+    37876,
+    37877,
+    37878,
+    37879,
+    37880,
+    37881,
+    37882,
+    37881,
+    37884,
+    37884,
+    37884,
+    37887,
+    37888,
+    37889,
+    37890,
+    37891,
+    37892,
+    37892,
+    37891,
+    37895,
+    37896,
+    37897,
+    37898,
+    37899,
+    37899,
+    37901,
+    37902,
+    37902,
+    37902,
+    37905,
+    37906,
+    37907,
+    37908,
+    37909,
+    37910,
+    37911,
+    37911,
+    37913,
+    37914,
+    37915,
+    37916,
+    37917,
+    37917,
+    37917,
+    37920,
+    37921,
+    37922,
+    37923,
+    37924,
+    37925,
+    37926,
+    37927,
+    37927,
+    37927,
+    37930,
+    37931,
+    37932,
+    37932,
+    37934,
+    37935,
+    37935,
+    37937,
+    37938,
+    37939,
+    37939,
+    37941,
+    37942,
+    37943,
+    37944,
+    37945,
+    37946,
+    37947,
+    37948,
+    37949,
+    37950,
+    37951,
+    37951,
+    37953,
+    37954,
+    37955,
+    37956,
+    37957,
+    37957,
+    37959,
+    37960,
+    37961,
+    37961,
+    37963,
+    37964,
+    37964,
+    37966,
+    37966,
+    37968,
+    37969,
+    37966,
+    37971,
+    37972,
+    37973,
+    37974,
+    37975,
+    37976,
+    37976,
+    37978,
+    37955,
+    37980,
+    37981,
+    37982,
+    37982,
+    37984,
+    37985,
+    37985,
+    37987,
+    37988,
+    37988,
+    37990,
+    37991,
+    37991,
+    37988,
+    37981,
+    37995,
+    37996,
+    37997,
+    37997,
+    37999,
+    37999,
+    38001,
+    38002,
+    38003,
+    38004,
+    38005,
+    38006,
+    38006,
+    38006,
+    38009,
+    38009,
+    38011,
+    38012,
+    38012,
+    38014,
+    38014,
+    38014,
+    38017,
+    38018,
+    38018,
+    38020,
+    38020,
+    38022,
+    38023,
+    38024,
+    38025,
+    38026,
+    38027,
+    38027,
+    38029,
+    38029,
+    38031,
+    38031,
+    38033,
+    38034,
+    38034,
+    38034,
+    38037,
+    38038,
+    38038,
+    38037,
+    38041,
+    38042,
+    38043,
+    38043,
+    38043,
+    38046,
+    38047,
+    38047,
+    38049,
+    38050,
+    38051,
+    38052,
+    38053,
+    38053,
+    38052,
+    38056,
+    38057,
+    38057,
+    38059,
+    38060,
+    38060,
+    38062,
+    38063,
+    38064,
+    38065,
+    38065,
+    38067,
+    38067,
+    38069,
+    38069,
+    38069,
+    38072,
+    38073,
+    38072,
+    38075,
+    38072,
+    38077,
+    38078,
+    38079,
+    38079,
+    38081,
+    38082,
+    38083,
+    38083,
+    38085,
+    38086,
+    38087,
+    38088,
+    38089,
+    38090,
+    38090,
+    38092,
+    38092,
+    38090,
+    38095,
+    38096,
+    38097,
+    38098,
+    38099,
+    38100,
+    38101,
+    38102,
+    38102,
+    38104,
+    38104,
+    38104,
+    38107,
+    38107,
+    38107,
+    38110,
+    38111,
+    38111,
+    38111,
+    38114,
+    38115,
+    38116,
+    38117,
+    38118,
+    38119,
+    38120,
+    38121,
+    38122,
+    38123,
+    38124,
+    38125,
+    38126,
+    38127,
+    38128,
+    38129,
+    38130,
+    38131,
+    38132,
+    38133,
+    38134,
+    38135,
+    38135,
+    38137,
+    38137,
+    38139,
+    38140,
+    38141,
+    38142,
+    38143,
+    38144,
+    38145,
+    38146,
+    38147,
+    38148,
+    38149,
+    38150,
+    38151,
+    38152,
+    38152,
+    38154,
+    38155,
+    38156,
+    38157,
+    38158,
+    38155,
+    38160,
+    38161,
+    38161,
+    38163,
+    38164,
+    38164,
+    38164,
+    38167,
+    38167,
+    38167,
+    38170,
+    38171,
+    38172,
+    38173,
+    38174,
+    38175,
+    38176,
+    38177,
+    38178,
+    38179,
+    38180,
+    38181,
+    38182,
+    38183,
+    38184,
+    38185,
+    38186,
+    38187,
+    38188,
+    38189,
+    38190,
+    38191,
+    38192,
+    38193,
+    38194,
+    38195,
+    38196,
+    38196,
+    38198,
+    38199,
+    38200,
+    38201,
+    38202,
+    38203,
+    38204,
+    38205,
+    38205,
+    38207,
+    38208,
+    38209,
+    38210,
+    38211,
+    38212,
+    38213,
+    38214,
+    38215,
+    38215,
+    38217,
+    38218,
+    38219,
+    38220,
+    38221,
+    38222,
+    38223,
+    38224,
+    38225,
+    38226,
+    38227,
+    38228,
+    38229,
+    38230,
+    38231,
+    38232,
+    38233,
+    38234,
+    38235,
+    38236,
+    38237,
+    38238,
+    38239,
+    38240,
+    38241,
+    38242,
+    38243,
+    38244,
+    38245,
+    38246,
+    38247,
+    38248,
+    38249,
+    38250,
+    38250,
+    38252,
+    38253,
+    38254,
+    38255,
+    38256,
+    38257,
+    38258,
+    38259,
+    38260,
+    38261,
+    38262,
+    38263,
+    38264,
+    38264,
+    38266,
+    38267,
+    38268,
+    38269,
+    38270,
+    38270,
+    38270,
+    38273,
+    38273,
+    38275,
+    38275,
+    38277,
+    38278,
+    38279,
+    38279,
+    38279,
+    38282,
+    38282,
+    38284,
+    38285,
+    38285,
+    38285,
+    38288,
+    38289,
+    38290,
+    38291,
+    38292,
+    38293,
+    38294,
+    38295,
+    38296,
+    38297,
+    38298,
+    38299,
+    38299,
+    38299,
+    38302,
+    38302,
+    38304,
+    38305,
+    38305,
+    38307,
+    38308,
+    38309,
+    38310,
+    38310,
+    38312,
+    38313,
+    38314,
+    38315,
+    38315,
+    38315,
+    38318,
+    38319,
+    38320,
+    38321,
+    38322,
+    38323,
+    38324,
+    38325,
+    38326,
+    38327,
+    38328,
+    38329,
+    38330,
+    38331,
+    38332,
+    38332,
+    38334,
+    38335,
+    38336,
+    38337,
+    38338,
+    38339,
+    38340,
+    38341,
+    38342,
+    38343,
+    38343,
+    38345,
+    38346,
+    38346,
+    38348,
+    38349,
+    38348,
+    38351,
+    38352,
+    38351,
+    38354,
+    38355,
+    38355,
+    38357,
+    38357,
+    38357,
+    38360,
+    38360,
+    38362,
+    38362,
+    38364,
+    38365,
+    38365,
+    38365,
+    38368,
+    38368,
+    38370,
+    38370,
+    38372,
+    38373,
+    38373,
+    38373,
+    38376,
+    38377,
+    38378,
+    38378,
+    38380,
+    38380,
+    38382,
+    38383,
+    38384,
+    38385,
+    38385,
+    38387,
+    38387,
+    38387,
+    38390,
+    38391,
+    38391,
+    38391,
+    38394,
+    38394,
+    38396,
+    38396,
+    38396,
+    38399,
+    38400,
+    38400,
+    38399,
+    38399,
+    38404,
+    38404,
+    38406,
+    38407,
+    38407,
+    38406,
+    38410,
+    38411,
+    38411,
+    38413,
+    38414,
+    38414,
+    38414,
+    38417,
+    38417,
+    38419,
+    38420,
+    38420,
+    38422,
+    38422,
+    38422,
+    38422,
+    38426,
+    38426,
+    38426,
+    38429,
+    38430,
+    38430,
+    38432,
+    38432,
+    38432,
+    38435,
+    38435,
+    38437,
+    38438,
+    38439,
+    38439,
+    38441,
+    38442,
+    38443,
+    38442,
+    38445,
+    38445,
+    38447,
+    38448,
+    38449,
+    38450,
+    38451,
+    38451,
+    38451,
+    38454,
+    38455,
+    38455,
+    38454,
+    38454,
+    38459,
+    38459,
+    38461,
+    38462,
+    38463,
+    38463,
+    38465,
+    38466,
+    38466,
+    38465,
+    38469,
+    38470,
+    38471,
+    38472,
+    38472,
+    38474,
+    38474,
+    38472,
+    38477,
+    38477,
+    38477,
+    38480,
+    38480,
+    38480,
+    38483,
+    38484,
+    38484,
+    38484,
+    38487,
+    38488,
+    38489,
+    38490,
+    38491,
+    38492,
+    38492,
+    38494,
+    38494,
+    38494,
+    38494,
+    38498,
+    38498,
+    38500,
+    38501,
+    38501,
+    38503,
+    38501,
+    38505,
+    38506,
+    38507,
+    38508,
+    38509,
+    38510,
+    38510,
+    38510,
+    38513,
+    38514,
+    38515,
+    38516,
+    38516,
+    38518,
+    38519,
+    38520,
+    38521,
+    38521,
+    38523,
+    38523,
+    38523,
+    38526,
+    38527,
+    38527,
+    38529,
+    38530,
+    38531,
+    38532,
+    38532,
+    38532,
+    38535,
+    38536,
+    38537,
+    38538,
+    38539,
+    38540,
+    38541,
+    38541,
+    38543,
+    38543,
+    38543,
+    38543,
+    38547,
+    38547,
+    38547,
+    38550,
+    38551,
+    38551,
+    38553,
+    38551,
+    38555,
+    38555,
+    38557,
+    38558,
+    38558,
+    38560,
+    38561,
+    38562,
+    38563,
+    38563,
+    38562,
+    38566,
+    38566,
+    38568,
+    38568,
+    38568,
+    38568,
+    38572,
+    38573,
+    38572,
+    38575,
+    38575,
+    38577,
+    38577,
+    38579,
+    38580,
+    38581,
+    38582,
+    38582,
+    38584,
+    38585,
+    38585,
+    38587,
+    38588,
+    38588,
+    38590,
+    38591,
+    38590,
+    38593,
+    38593,
+    38595,
+    38596,
+    38596,
+    38598,
+    38599,
+    38600,
+    38601,
+    38602,
+    38603,
+    38604,
+    38604,
+    38606,
+    38607,
+    38608,
+    38608,
+    38610,
+    38611,
+    38611,
+    38611,
+    38614,
+    38614,
+    38599,
+    38617,
+    38617,
+    38619,
+    38620,
+    38619,
+    38622,
+    38623,
+    38624,
+    38625,
+    38624,
+    38627,
+    38628,
+    38629,
+    38629,
+    38631,
+    38632,
+    38632,
+    38634,
+    38635,
+    38623,
+    38637,
+    38637,
+    38639,
+    38640,
+    38640,
+    38642,
+    38643,
+    38644,
+    38640,
+    38646,
+    38646,
+    38648,
+    38649,
+    38649,
+    38651,
+    38651,
+    38651,
+    38654,
+    38654,
+    38656,
+    38657,
+    38658,
+    38659,
+    38660,
+    38661,
+    38659,
+    38663,
+    38664,
+    38665,
+    38665,
+    38667,
+    38668,
+    38669,
+    38670,
+    38670,
+    38670,
+    38670,
+    38674,
+    38675,
+    38676,
+    38677,
+    38678,
+    38679,
+    38679,
+    38681,
+    38682,
+    38683,
+    38684,
+    38685,
+    38686,
+    38687,
+    38688,
+    38689,
+    38690,
+    38690,
+    38692,
+    38693,
+    38694,
+    38695,
+    38696,
+    38696,
+    38698,
+    38699,
+    38700,
+    38698,
+    38702,
+    38702,
+    38704,
+    38705,
+    38706,
+    38706,
+    38705,
+    38709,
+    38710,
+    38710,
+    38712,
+    38713,
+    38714,
+    38713,
+    38716,
+    38717,
+    38716,
+    38719,
+    38720,
+    38720,
+    38722,
+    38723,
+    38723,
+    38725,
+    38725,
+    38727,
+    38728,
+    38728,
+    38730,
+    38731,
+    38732,
+    38733,
+    38730,
+    38735,
+    38736,
+    38737,
+    38738,
+    38739,
+    38740,
+    38741,
+    38741,
+    38743,
+    38743,
+    38743,
+    38746,
+    38747,
+    38748,
+    38749,
+    38750,
+    38751,
+    38752,
+    38751,
+    38754,
+    38755,
+    38755,
+    38757,
+    38758,
+    38759,
+    38757,
+    38761,
+    38761,
+    38761,
+    38764,
+    38765,
+    38766,
+    38767,
+    38768,
+    38768,
+    38766,
+    38771,
+    38772,
+    38773,
+    38774,
+    38774,
+    38773,
+    38777,
+    38778,
+    38778,
+    38780,
+    38780,
+    38780,
+    38777,
+    38784,
+    38784,
+    38786,
+    38787,
+    38787,
+    38789,
+    38789,
+    38787,
+    38787,
+    38793,
+    38793,
+    38793,
+    38796,
+    38796,
+    38798,
+    38798,
+    38800,
+    38801,
+    38796,
+    38803,
+    38803,
+    38805,
+    38806,
+    38796,
+    38808,
+    38809,
+    38810,
+    38810,
+    38812,
+    38812,
+    38812,
+    38815,
+    38815,
+    38817,
+    38817,
+    38819,
+    38820,
+    38821,
+    38822,
+    38822,
+    38824,
+    38809,
+    38826,
+    38826,
+    38828,
+    38829,
+    38830,
+    38830,
+    38830,
+    38830,
+    38834,
+    38834,
+    38836,
+    38837,
+    38837,
+    38837,
+    38840,
+    38830,
+    38842,
+    38843,
+    38843,
+    38843,
+    38843,
+    38847,
+    38848,
+    38848,
+    38850,
+    38851,
+    38852,
+    38852,
+    38851,
+    38855,
+    38856,
+    38856,
+    38858,
+    38859,
+    38858,
+    38861,
+    38862,
+    38862,
+    38864,
+    38865,
+    38866,
+    38867,
+    38867,
+    38869,
+    38869,
+    38871,
+    38871,
+    38873,
+    38873,
+    38875,
+    38850,
+    38877,
+    38878,
+    38858,
+    38858,
+    38881,
+    38882,
+    38883,
+    38884,
+    38885,
+    38886,
+    38887,
+    38888,
+    38889,
+    38890,
+    38891,
+    38892,
+    38892,
+    38894,
+    38891,
+    38896,
+    38896,
+    38898,
+    38899,
+    38899,
+    38901,
+    38901,
+    38903,
+    38899,
+    38899,
+    38899,
+    38907,
+    38908,
+    38909,
+    38909,
+    38911,
+    38911,
+    38913,
+    38909,
+    38915,
+    38898,
+    38917,
+    38917,
+    38919,
+    38920,
+    38919,
+    38922,
+    38922,
+    38922,
+    38925,
+    38926,
+    38927,
+    38926,
+    38926,
+    38930,
+    38931,
+    38932,
+    38933,
+    38934,
+    38933,
+    38932,
+    38937,
+    38937,
+    38939,
+    38940,
+    38941,
+    38941,
+    38943,
+    38944,
+    38945,
+    38943,
+    38947,
+    38948,
+    38949,
+    38950,
+    38949,
+    38952,
+    38952,
+    38954,
+    38954,
+    38956,
+    38956,
+    38958,
+    38959,
+    38959,
+    38961,
+    38962,
+    38963,
+    38964,
+    38965,
+    38966,
+    38967,
+    38968,
+    38969,
+    38969,
+    38967,
+    38972,
+    38972,
+    38974,
+    38975,
+    38975,
+    38977,
+    38978,
+    38978,
+    38980,
+    38981,
+    38982,
+    38983,
+    38984,
+    38984,
+    38986,
+    38986,
+    38983,
+    38989,
+    38989,
+    38991,
+    38992,
+    38993,
+    38994,
+    38995,
+    38996,
+    38997,
+    38998,
+    38999,
+    38997,
+    39001,
+    39002,
+    39002,
+    39004,
+    39005,
+    38996,
+    39007,
+    39008,
+    39009,
+    39010,
+    39011,
+    39011,
+    39013,
+    39013,
+    39015,
+    39016,
+    39017,
+    39018,
+    39019,
+    39020,
+    39021,
+    39022,
+    39023,
+    39024,
+    39025,
+    39026,
+    39027,
+    39028,
+    39029,
+    39028,
+    39031,
+    39032,
+    39032,
+    39034,
+    39034,
+    39036,
+    39037,
+    39038,
+    39038,
+    39040,
+    39040,
+    39042,
+    39043,
+    39044,
+    39045,
+    39046,
+    39047,
+    39048,
+    39049,
+    39050,
+    39051,
+    39052,
+    39053,
+    39053,
+    39055,
+    39055,
+    39057,
+    39052,
+    39059,
+    39059,
+    39061,
+    39062,
+    39063,
+    39064,
+    39065,
+    39066,
+    39067,
+    39068,
+    39069,
+    39070,
+    39071,
+    39072,
+    39073,
+    39074,
+    39070,
+    39076,
+    39077,
+    39078,
+    39079,
+    39080,
+    39081,
+    39082,
+    39083,
+    39083,
+    39085,
+    39086,
+    39086,
+    39081,
+    39089,
+    39090,
+    39090,
+    39092,
+    39093,
+    39093,
+    39089,
+    39096,
+    39097,
+    39098,
+    39099,
+    39100,
+    39101,
+    39102,
+    39102,
+    39104,
+    39105,
+    39105,
+    39105,
+    39108,
+    39108,
+    39099,
+    39111,
+    39111,
+    39111,
+    39114,
+    39114,
+    39116,
+    39117,
+    39117,
+    39119,
+    39120,
+    39121,
+    39120,
+    39123,
+    39124,
+    39124,
+    39124,
+    39127,
+    39127,
+    39129,
+    39130,
+    39131,
+    39130,
+    39133,
+    39133,
+    39135,
+    39136,
+    39136,
+    39133,
+    39139,
+    39140,
+    39140,
+    39142,
+    39142,
+    39144,
+    39145,
+    39145,
+    39147,
+    39147,
+    39145,
+    39150,
+    39150,
+    39152,
+    39152,
+    39154,
+    39155,
+    39156,
+    39157,
+    39158,
+    39158,
+    39157,
+    39154,
+    39162,
+    39163,
+    39164,
+    39165,
+    39165,
+    39163,
+    39168,
+    39169,
+    39170,
+    39170,
+    39168,
+    39173,
+    39174,
+    39175,
+    39176,
+    39176,
+    39178,
+    39178,
+    39180,
+    39180,
+    39175,
+    39183,
+    39183,
+    39185,
+    39185,
+    39187,
+    39188,
+    39189,
+    39190,
+    39191,
+    39192,
+    39191,
+    39194,
+    39195,
+    39196,
+    39197,
+    39197,
+    39195,
+    39200,
+    39200,
+    39202,
+    39203,
+    39204,
+    39205,
+    39204,
+    39207,
+    39207,
+    39209,
+    39210,
+    39211,
+    39204,
+    39213,
+    39214,
+    39214,
+    39216,
+    39216,
+    39218,
+    39219,
+    39194,
+    39221,
+    39222,
+    39223,
+    39223,
+    39222,
+    39226,
+    39221,
+    39228,
+    39229,
+    39229,
+    39231,
+    39232,
+    39232,
+    39234,
+    39234,
+    39236,
+    39236,
+    39234,
+    39239,
+    39240,
+    39240,
+    39231,
+    39243,
+    39244,
+    39245,
+    39243,
+    39247,
+    39247,
+    39247,
+    39250,
+    39251,
+    39252,
+    39252,
+    39254,
+    39255,
+    39255,
+    39251,
+    39258,
+    39259,
+    39258,
+    39261,
+    39261,
+    39263,
+    39264,
+    39265,
+    39265,
+    39267,
+    39267,
+    39269,
+    39270,
+    39271,
+    39272,
+    39272,
+    39274,
+    39275,
+    39270,
+    39277,
+    39278,
+    39279,
+    39280,
+    39281,
+    39281,
+    39283,
+    39283,
+    39285,
+    39286,
+    39287,
+    39288,
+    39289,
+    39290,
+    39291,
+    39291,
+    39293,
+    39294,
+    39295,
+    39295,
+    39297,
+    39297,
+    39299,
+    39300,
+    39301,
+    39302,
+    39303,
+    39303,
+    39305,
+    39305,
+    39301,
+    39308,
+    39309,
+    39310,
+    39311,
+    39312,
+    39311,
+    39314,
+    39314,
+    39316,
+    39316,
+    39316,
+    39319,
+    39320,
+    39321,
+    39321,
+    39323,
+    39324,
+    39324,
+    39326,
+    39327,
+    39327,
+    39329,
+    39330,
+    39330,
+    39332,
+    39329,
+    39334,
+    39335,
+    39335,
+    39337,
+    39338,
+    39339,
+    39340,
+    39340,
+    39342,
+    39342,
+    39340,
+    39334,
+    39346,
+    39347,
+    39348,
+    39349,
+    39349,
+    39351,
+    39351,
+    39353,
+    39353,
+    39355,
+    39355,
+    39357,
+    39358,
+    39357,
+    39360,
+    39360,
+    39362,
+    39362,
+    39360,
+    39365,
+    39366,
+    39367,
+    39367,
+    39369,
+    39370,
+    39370,
+    39367,
+    39373,
+    39374,
+    39375,
+    39375,
+    39377,
+    39377,
+    39373,
+    39380,
+    39381,
+    39381,
+    39383,
+    39384,
+    39385,
+    39386,
+    39387,
+    39388,
+    39389,
+    39390,
+    39390,
+    39390,
+    39393,
+    39393,
+    39395,
+    39393,
+    39397,
+    39393,
+    39399,
+    39399,
+    39383,
+    39402,
+    39403,
+    39404,
+    39404,
+    39406,
+    39407,
+    39408,
+    39408,
+    39408,
+    39411,
+    39408,
+    39402,
+    39414,
+    39414,
+    39414,
+    39417,
+    39414,
+    39419,
+    39419,
+    39421,
+    39421,
+    39421,
+    39424,
+    39425,
+    39426,
+    39426,
+    39426,
+    39429,
+    39429,
+    39431,
+    39431,
+    39433,
+    39434,
+    39435,
+    39435,
+    39437,
+    39437,
+    39435,
+    39440,
+    39441,
+    39442,
+    39443,
+    39444,
+    39444,
+    39446,
+    39442,
+    39448,
+    39449,
+    39450,
+    39451,
+    39452,
+    39452,
+    39454,
+    39454,
+    39456,
+    39456,
+    39454,
+    39454,
+    39460,
+    39460,
+    39462,
+    39462,
+    39462,
+    39460,
+    39460,
+    39467,
+    39467,
+    39469,
+    39470,
+    39471,
+    39471,
+    39473,
+    39473,
+    39475,
+    39475,
+    39471,
+    39471,
+    39479,
+    39480,
+    39480,
+    39482,
+    39482,
+    39484,
+    39484,
+    39482,
+    39487,
+    39487,
+    39489,
+    39490,
+    39489,
+    39492,
+    39493,
+    39494,
+    39494,
+    39496,
+    39497,
+    39497,
+    39499,
+    39499,
+    39501,
+    39502,
+    39502,
+    39504,
+    39501,
+    39506,
+    39506,
+    39508,
+    39509,
+    39509,
+    39511,
+    39511,
+    39513,
+    39511,
+    39515,
+    39516,
+    39517,
+    39517,
+    39519,
+    39520,
+    39521,
+    39521,
+    39521,
+    39515,
+    39525,
+    39525,
+    39527,
+    39528,
+    39528,
+    39528,
+    39531,
+    39532,
+    39532,
+    39534,
+    39535,
+    39535,
+    39537,
+    39538,
+    39538,
+    39540,
+    39538,
+    39542,
+    39543,
+    39544,
+    39545,
+    39546,
+    39546,
+    39546,
+    39537,
+    39550,
+    39551,
+    39552,
+    39553,
+    39553,
+    39552,
+    39556,
+    39557,
+    39552,
+    39556,
+    39560,
+    39560,
+    39562,
+    39562,
+    39551,
+    39565,
+    39566,
+    39566,
+    39557,
+    39569,
+    39570,
+    39569,
+    39572,
+    39573,
+    39573,
+    39573,
+    39576,
+    39577,
+    39577,
+    39579,
+    39579,
+    39577,
+    39577,
+    39583,
+    39583,
+    39585,
+    39586,
+    39586,
+    39588,
+    39588,
+    39586,
+    39591,
+    39591,
+    39593,
+    39593,
+    39591,
+    39585,
+    39597,
+    39598,
+    39599,
+    39600,
+    39600,
+    39602,
+    39603,
+    39604,
+    39604,
+    39604,
+    39607,
+    39608,
+    39608,
+    39610,
+    39610,
+    39608,
+    39613,
+    39614,
+    39614,
+    39616,
+    39616,
+    39618,
+    39618,
+    39618,
+    39602,
+    39622,
+    39622,
+    39624,
+    39625,
+    39625,
+    39627,
+    39627,
+    39629,
+    39630,
+    39630,
+    39625,
+    39633,
+    39633,
+    39635,
+    39635,
+    39633,
+    39638,
+    39638,
+    39640,
+    39641,
+    39641,
+    39643,
+    39643,
+    39638,
+    39646,
+    39646,
+    39638,
+    39638,
+    39650,
+    39651,
+    39651,
+    39650,
+    39654,
+    39654,
+    39656,
+    39656,
+    39654,
+    39659,
+    39660,
+    39661,
+    39654,
+    39659,
+    39664,
+    39664,
+    39666,
+    39666,
+    39668,
+    39664,
+    39664,
+    39671,
+    39671,
+    39673,
+    39673,
+    39664,
+    39676,
+    39676,
+    39678,
+    39678,
+    39680,
+    39680,
+    39682,
+    39683,
+    39684,
+    39684,
+    39686,
+    39686,
+    39684,
+    39684,
+    39661,
+    39691,
+    39691,
+    39691,
+    39694,
+    39695,
+    39691,
+    39697,
+    39698,
+    39699,
+    39699,
+    39701,
+    39701,
+    39698,
+    39704,
+    39704,
+    39706,
+    39707,
+    39708,
+    39709,
+    39710,
+    39711,
+    39710,
+    39713,
+    39709,
+    39715,
+    39715,
+    39715,
+    39718,
+    39719,
+    39720,
+    39720,
+    39722,
+    39722,
+    39720,
+    39719,
+    39726,
+    39727,
+    39728,
+    39728,
+    39730,
+    39730,
+    39732,
+    39728,
+    39734,
+    39735,
+    39708,
+    39737,
+    39737,
+    39739,
+    39739,
+    39737,
+    39742,
+    39743,
+    39744,
+    39745,
+    39745,
+    39747,
+    39747,
+    39749,
+    39750,
+    39751,
+    39751,
+    39751,
+    39754,
+    39751,
+    39756,
+    39750,
+    39758,
+    39759,
+    39759,
+    39761,
+    39758,
+    39758,
+    39764,
+    39765,
+    39765,
+    39765,
+    39768,
+    39769,
+    39770,
+    39765,
+    39772,
+    39773,
+    39773,
+    39773,
+    39773,
+    39777,
+    39778,
+    39778,
+    39780,
+    39780,
+    39782,
+    39780,
+    39780,
+    39785,
+    39786,
+    39787,
+    39787,
+    39780,
+    39790,
+    39790,
+    39792,
+    39792,
+    39794,
+    39794,
+    39794,
+    39797,
+    39797,
+    39797,
+    39797,
+    39749,
+    39802,
+    39803,
+    39803,
+    39803,
+    39806,
+    39807,
+    39807,
+    39807,
+    39810,
+    39810,
+    39812,
+    39812,
+    39814,
+    39814,
+    39816,
+    39810,
+    39818,
+    39819,
+    39820,
+    39821,
+    39822,
+    39822,
+    39824,
+    39825,
+    39826,
+    39822,
+    39828,
+    39829,
+    39830,
+    39831,
+    39832,
+    39833,
+    39834,
+    39835,
+    39836,
+    39837,
+    39838,
+    39839,
+    39840,
+    39840,
+    39842,
+    39843,
+    39844,
+    39845,
+    39840,
+    39847,
+    39848,
+    39849,
+    39850,
+    39851,
+    39852,
+    39853,
+    39853,
+    39852,
+    39856,
+    39856,
+    39852,
+    39859,
+    39852,
+    39861,
+    39862,
+    39863,
+    39864,
+    39864,
+    39866,
+    39867,
+    39867,
+    39869,
+    39863,
+    39863,
+    39872,
+    39873,
+    39873,
+    39863,
+    39876,
+    39877,
+    39851,
+    39879,
+    39879,
+    39879,
+    39879,
+    39883,
+    39884,
+    39884,
+    39884,
+    39887,
+    39887,
+    39887,
+    39887,
+    39891,
+    39891,
+    39891,
+    39894,
+    39891,
+    39896,
+    39897,
+    39897,
+    39899,
+    39899,
+    39901,
+    39901,
+    39903,
+    39903,
+    39905,
+    39903,
+    39907,
+    39897,
+    39909,
+    39909,
+    39911,
+    39911,
+    39913,
+    39914,
+    39915,
+    39916,
+    39897,
+    39914,
+    39919,
+    39919,
+    39921,
+    39922,
+    39922,
+    39919,
+    39925,
+    39926,
+    39927,
+    39919,
+    39919,
+    39930,
+    39931,
+    39932,
+    39932,
+    39934,
+    39934,
+    39932,
+    39937,
+    39938,
+    39939,
+    39940,
+    39941,
+    39942,
+    39942,
+    39944,
+    39945,
+    39945,
+    39947,
+    39948,
+    39948,
+    39947,
+    39951,
+    39951,
+    39953,
+    39954,
+    39954,
+    39956,
+    39957,
+    39956,
+    39959,
+    39960,
+    39961,
+    39959,
+    39963,
+    39964,
+    39959,
+    39966,
+    39966,
+    39968,
+    39969,
+    39969,
+    39971,
+    39972,
+    39972,
+    39974,
+    39974,
+    39976,
+    39977,
+    39977,
+    39979,
+    39979,
+    39971,
+    39982,
+    39982,
+    39984,
+    39985,
+    39986,
+    39986,
+    39984,
+    39989,
+    39989,
+    39991,
+    39991,
+    39993,
+    39994,
+    39993,
+    39996,
+    39996,
+    39998,
+    39999,
+    39999,
+    40001,
+    40002,
+    40003,
+    40004,
+    40004,
+    40002,
+    40007,
+    40001,
+    40009,
+    40010,
+    40010,
+    40012,
+    40012,
+    40014,
+    40015,
+    40015,
+    40017,
+    40017,
+    40015,
+    40020,
+    40021,
+    40022,
+    40023,
+    40023,
+    40022,
+    40026,
+    40027,
+    40027,
+    40029,
+    40029,
+    40029,
+    40029,
+    40033,
+    40034,
+    40034,
+    40033,
+    40037,
+    40038,
+    40039,
+    40040,
+    40040,
+    40039,
+    40037,
+    40044,
+    40045,
+    40046,
+    40046,
+    40048,
+    40048,
+    40050,
+    40051,
+    40052,
+    40053,
+    40054,
+    40053,
+    40056,
+    40057,
+    40057,
+    40059,
+    40059,
+    40061,
+    40061,
+    40063,
+    40056,
+    40065,
+    40066,
+    40066,
+    40068,
+    40069,
+    40069,
+    40071,
+    40071,
+    40073,
+    40074,
+    40069,
+    40069,
+    40077,
+    40077,
+    40077,
+    40069,
+    40081,
+    40082,
+    40083,
+    40083,
+    40085,
+    40085,
+    40083,
+    40083,
+    40083,
+    40090,
+    40091,
+    40090,
+    40093,
+    40093,
+    40093,
+    40096,
+    40096,
+    40098,
+    40099,
+    40099,
+    40099,
+    40099,
+    40103,
+    40103,
+    40105,
+    40105,
+    40107,
+    40108,
+    40108,
+    40110,
+    40110,
+    40112,
+    40112,
+    40114,
+    40115,
+    40116,
+    40116,
+    40118,
+    40119,
+    40119,
+    40119,
+    40122,
+    40122,
+    40122,
+    40125,
+    40126,
+    40125,
+    40128,
+    40128,
+    40130,
+    40130,
+    40132,
+    40132,
+    40134,
+    40134,
+    40136,
+    40137,
+    40137,
+    40139,
+    40139,
+    40139,
+    40142,
+    40143,
+    40144,
+    40145,
+    40146,
+    40146,
+    40148,
+    40148,
+    40145,
+    40151,
+    40152,
+    40153,
+    40154,
+    40154,
+    40156,
+    40156,
+    40154,
+    40159,
+    40160,
+    40161,
+    40161,
+    40163,
+    40163,
+    40161,
+    40166,
+    40153,
+    40168,
+    40169,
+    40170,
+    40171,
+    40171,
+    40173,
+    40173,
+    40170,
+    40176,
+    40177,
+    40178,
+    40179,
+    40180,
+    40180,
+    40180,
+    40183,
+    40179,
+    40185,
+    40185,
+    40187,
+    40188,
+    40189,
+    40190,
+    40191,
+    40192,
+    40193,
+    40193,
+    40195,
+    40195,
+    40197,
+    40198,
+    40199,
+    40200,
+    40201,
+    40201,
+    40203,
+    40203,
+    40199,
+    40206,
+    40207,
+    40208,
+    40208,
+    40210,
+    40199,
+    40212,
+    40212,
+    40214,
+    40214,
+    40198,
+    40217,
+    40218,
+    40219,
+    40219,
+    40221,
+    40222,
+    40223,
+    40223,
+    40225,
+    40225,
+    40227,
+    40228,
+    40229,
+    40229,
+    40229,
+    40229,
+    40233,
+    40233,
+    40235,
+    40235,
+    40235,
+    40235,
+    40235,
+    40240,
+    40240,
+    40240,
+    40240,
+    40244,
+    40245,
+    40245,
+    40244,
+    40248,
+    40249,
+    40250,
+    40251,
+    40251,
+    40251,
+    40251,
+    40255,
+    40255,
+    40257,
+    40258,
+    40258,
+    40257,
+    40261,
+    40262,
+    40262,
+    40264,
+    40265,
+    40265,
+    40264,
+    40268,
+    40269,
+    40269,
+    40271,
+    40271,
+    40264,
+    40274,
+    40275,
+    40275,
+    40277,
+    40278,
+    40279,
+    40280,
+    40280,
+    40282,
+    40282,
+    40284,
+    40278,
+    40286,
+    40287,
+    40287,
+    40289,
+    40289,
+    40291,
+    40291,
+    40293,
+    40294,
+    40294,
+    40296,
+    40293,
+    40298,
+    40298,
+    40300,
+    40300,
+    40302,
+    40298,
+    40304,
+    40304,
+    40306,
+    40307,
+    40308,
+    40308,
+    40310,
+    40310,
+    40312,
+    40312,
+    40314,
+    40314,
+    40316,
+    40316,
+    40307,
+    40319,
+    40320,
+    40321,
+    40321,
+    40323,
+    40323,
+    40325,
+    40320,
+    40327,
+    40327,
+    40327,
+    40330,
+    40331,
+    40331,
+    40333,
+    40331,
+    40335,
+    40336,
+    40336,
+    40336,
+    40339,
+    40339,
+    40341,
+    40341,
+    40341,
+    40339,
+    40345,
+    40346,
+    40347,
+    40347,
+    40349,
+    40349,
+    40347,
+    40352,
+    40352,
+    40354,
+    40354,
+    40356,
+    40356,
+    40354,
+    40359,
+    40360,
+    40361,
+    40361,
+    40363,
+    40363,
+    40365,
+    40366,
+    40366,
+    40368,
+    40368,
+    40370,
+    40370,
+    40372,
+    40372,
+    40374,
+    40374,
+    40359,
+    40377,
+    40378,
+    40379,
+    40380,
+    40381,
+    40381,
+    40383,
+    40384,
+    40384,
+    40386,
+    40384,
+    40388,
+    40389,
+    40390,
+    40391,
+    40392,
+    40393,
+    40393,
+    40395,
+    40395,
+    40397,
+    40397,
+    40399,
+    40400,
+    40400,
+    40402,
+    40402,
+    40404,
+    40404,
+    40392,
+    40407,
+    40408,
+    40409,
+    40409,
+    40411,
+    40411,
+    40413,
+    40413,
+    40415,
+    40415,
+    40413,
+    40409,
+    40419,
+    40419,
+    40419,
+    40346,
+    40423,
+    40424,
+    40425,
+    40426,
+    40427,
+    40426,
+    40429,
+    40430,
+    40430,
+    40430,
+    40433,
+    40434,
+    40435,
+    40435,
+    40437,
+    40437,
+    40439,
+    40439,
+    40441,
+    40441,
+    40437,
+    40444,
+    40445,
+    40445,
+    40447,
+    40445,
+    40449,
+    40449,
+    40451,
+    40452,
+    40453,
+    40454,
+    40454,
+    40456,
+    40457,
+    40458,
+    40459,
+    40460,
+    40461,
+    40462,
+    40460,
+    40464,
+    40465,
+    40466,
+    40467,
+    40467,
+    40469,
+    40470,
+    40470,
+    40472,
+    40473,
+    40474,
+    40475,
+    40475,
+    40477,
+    40477,
+    40479,
+    40477,
+    40481,
+    40482,
+    40482,
+    40482,
+    40485,
+    40486,
+    40487,
+    40487,
+    40489,
+    40490,
+    40490,
+    40489,
+    40487,
+    40494,
+    40495,
+    40495,
+    40474,
+    40498,
+    40498,
+    40500,
+    40501,
+    40501,
+    40500,
+    40498,
+    40505,
+    40506,
+    40507,
+    40506,
+    40509,
+    40509,
+    40505,
+    40512,
+    40512,
+    40514,
+    40514,
+    40516,
+    40516,
+    40518,
+    40518,
+    40520,
+    40521,
+    40520,
+    40523,
+    40523,
+    40525,
+    40526,
+    40526,
+    40528,
+    40529,
+    40530,
+    40528,
+    40532,
+    40533,
+    40532,
+    40535,
+    40535,
+    40537,
+    40537,
+    40535,
+    40540,
+    40541,
+    40542,
+    40541,
+    40535,
+    40545,
+    40546,
+    40547,
+    40548,
+    40548,
+    40547,
+    40551,
+    40551,
+    40553,
+    40553,
+    40555,
+    40556,
+    40557,
+    40558,
+    40559,
+    40559,
+    40561,
+    40561,
+    40559,
+    40564,
+    40565,
+    40566,
+    40567,
+    40551,
+    40569,
+    40570,
+    40570,
+    40569,
+    40573,
+    40574,
+    40575,
+    40569,
+    40577,
+    40578,
+    40579,
+    40579,
+    40581,
+    40582,
+    40583,
+    40584,
+    40583,
+    40586,
+    40587,
+    40588,
+    40589,
+    40590,
+    40591,
+    40592,
+    40593,
+    40588,
+    40595,
+    40582,
+    40597,
+    40597,
+    40599,
+    40597,
+    40601,
+    40601,
+    40603,
+    40604,
+    40604,
+    40606,
+    40607,
+    40603,
+    40603,
+    40610,
+    40610,
+    40603,
+    40613,
+    40614,
+    40615,
+    40616,
+    40617,
+    40618,
+    40619,
+    40619,
+    40621,
+    40622,
+    40622,
+    40616,
+    40616,
+    40616,
+    40627,
+    40628,
+    40629,
+    40629,
+    40631,
+    40632,
+    40632,
+    40616,
+    40635,
+    40636,
+    40637,
+    40637,
+    40639,
+    40639,
+    40641,
+    40642,
+    40642,
+    40641,
+    40645,
+    40646,
+    40645,
+    40648,
+    40649,
+    40649,
+    40645,
+    40652,
+    40653,
+    40645,
+    40655,
+    40656,
+    40657,
+    40658,
+    40659,
+    40660,
+    40660,
+    40662,
+    40663,
+    40663,
+    40663,
+    40666,
+    40667,
+    40667,
+    40669,
+    40670,
+    40671,
+    40667,
+    40667,
+    40670,
+    40666,
+    40676,
+    40677,
+    40678,
+    40679,
+    40680,
+    40678,
+    40682,
+    40683,
+    40684,
+    40685,
+    40685,
+    40687,
+    40688,
+    40689,
+    40689,
+    40691,
+    40692,
+    40693,
+    40693,
+    40695,
+    40693,
+    40693,
+    40698,
+    40698,
+    40700,
+    40701,
+    40701,
+    40701,
+    40700,
+    40705,
+    40705,
+    40707,
+    40708,
+    40708,
+    40708,
+    40711,
+    40711,
+    40713,
+    40714,
+    40711,
+    40711,
+    40717,
+    40718,
+    40718,
+    40718,
+    40721,
+    40722,
+    40721,
+    40724,
+    40725,
+    40726,
+    40725,
+    40725,
+    40725,
+    40730,
+    40731,
+    40730,
+    40733,
+    40733,
+    40735,
+    40736,
+    40737,
+    40737,
+    40737,
+    40740,
+    40741,
+    40742,
+    40743,
+    40743,
+    40745,
+    40745,
+    40745,
+    40748,
+    40748,
+    40748,
+    40751,
+    40752,
+    40753,
+    40752,
+    40755,
+    40756,
+    40757,
+    40758,
+    40759,
+    40759,
+    40761,
+    40761,
+    40763,
+    40763,
+    40765,
+    40763,
+    40757,
+    40768,
+    40769,
+    40769,
+    40771,
+    40772,
+    40773,
+    40769,
+    40775,
+    40776,
+    40777,
+    40778,
+    40779,
+    40780,
+    40781,
+    40780,
+    40783,
+    40783,
+    40785,
+    40785,
+    40787,
+    40788,
+    40788,
+    40790,
+    40791,
+    40791,
+    40791,
+    40794,
+    40795,
+    40795,
+    40797,
+    40797,
+    40799,
+    40799,
+    40799,
+    40802,
+    40802,
+    40799,
+    40805,
+    40806,
+    40806,
+    40805,
+    40809,
+    40809,
+    40809,
+    40809,
+    40813,
+    40813,
+    40815,
+    40816,
+    40817,
+    40818,
+    40819,
+    40818,
+    40821,
+    40822,
+    40823,
+    40824,
+    40825,
+    40825,
+    40827,
+    40827,
+    40829,
+    40830,
+    40831,
+    40832,
+    40833,
+    40834,
+    40835,
+    40836,
+    40837,
+    40838,
+    40838,
+    40840,
+    40840,
+    40842,
+    40843,
+    40843,
+    40845,
+    40846,
+    40845,
+    40848,
+    40849,
+    40849,
+    40851,
+    40852,
+    40853,
+    40854,
+    40855,
+    40856,
+    40856,
+    40858,
+    40858,
+    40860,
+    40861,
+    40856,
+    40856,
+    40864,
+    40856,
+    40866,
+    40867,
+    40867,
+    40869,
+    40867,
+    40867,
+    40872,
+    40872,
+    40874,
+    40875,
+    40876,
+    40877,
+    40877,
+    40876,
+    40880,
+    40880,
+    40875,
+    40883,
+    40884,
+    40884,
+    40883,
+    40887,
+    40887,
+    40889,
+    40889,
+    40891,
+    40892,
+    40892,
+    40894,
+    40895,
+    40895,
+    40894,
+    40898,
+    40898,
+    40900,
+    40900,
+    40902,
+    40902,
+    40904,
+    40902,
+    40906,
+    40906,
+    40908,
+    40909,
+    40910,
+    40910,
+    40912,
+    40912,
+    40914,
+    40914,
+    40909,
+    40917,
+    40918,
+    40919,
+    40919,
+    40921,
+    40921,
+    40923,
+    40919,
+    40925,
+    40926,
+    40927,
+    40928,
+    40928,
+    40930,
+    40930,
+    40932,
+    40932,
+    40932,
+    40935,
+    40936,
+    40936,
+    40938,
+    40938,
+    40940,
+    40941,
+    40941,
+    40943,
+    40943,
+    40941,
+    40946,
+    40946,
+    40941,
+    40949,
+    40949,
+    40949,
+    40952,
+    40952,
+    40952,
+    40952,
+    40956,
+    40956,
+    40958,
+    40958,
+    40956,
+    40961,
+    40961,
+    40963,
+    40963,
+    40961,
+    40961,
+    40961,
+    40949,
+    40969,
+    40970,
+    40971,
+    40972,
+    40973,
+    40974,
+    40975,
+    40976,
+    40977,
+    40978,
+    40978,
+    40980,
+    40980,
+    40982,
+    40982,
+    40984,
+    40984,
+    40986,
+    40987,
+    40988,
+    40988,
+    40990,
+    40991,
+    40991,
+    40986,
+    40977,
+    40995,
+    40995,
+    40997,
+    40998,
+    40998,
+    41000,
+    41001,
+    41002,
+    41002,
+    41004,
+    41004,
+    41006,
+    41006,
+    41006,
+    41009,
+    41009,
+    41011,
+    41011,
+    41013,
+    41013,
+    41015,
+    41016,
+    41017,
+    41017,
+    41019,
+    41020,
+    41020,
+    41022,
+    41022,
+    41024,
+    41024,
+    41024,
+    41027,
+    41028,
+    41029,
+    41030,
+    41031,
+    41032,
+    41033,
+    41033,
+    41035,
+    41035,
+    41035,
+    41038,
+    41038,
+    41040,
+    41041,
+    41042,
+    41043,
+    41044,
+    41044,
+    41046,
+    41046,
+    41048,
+    41048,
+    41050,
+    41051,
+    41050,
+    41053,
+    41054,
+    41054,
+    41053,
+    41057,
+    41057,
+    41059,
+    41059,
+    41061,
+    41061,
+    41063,
+    41063,
+    41065,
+    41065,
+    41067,
+    41067,
+    41069,
+    41070,
+    41070,
+    41072,
+    41073,
+    41074,
+    41074,
+    41076,
+    41076,
+    41076,
+    41076,
+    41080,
+    41081,
+    41082,
+    41082,
+    41082,
+    41085,
+    41085,
+    41087,
+    41088,
+    41088,
+    41087,
+    41087,
+    41080,
+    41093,
+    41093,
+    41095,
+    41095,
+    41097,
+    41097,
+    41099,
+    41100,
+    41101,
+    41101,
+    41099,
+    41104,
+    41105,
+    41105,
+    41107,
+    41107,
+    41109,
+    41109,
+    41111,
+    41112,
+    41113,
+    41113,
+    41115,
+    41116,
+    41115,
+    41113,
+    41113,
+    41112,
+    41121,
+    41122,
+    41122,
+    41124,
+    41125,
+    41122,
+    41127,
+    41128,
+    41128,
+    41128,
+    41128,
+    41132,
+    41133,
+    41134,
+    41134,
+    41136,
+    41137,
+    41136,
+    41139,
+    41139,
+    41141,
+    41142,
+    41143,
+    41143,
+    41145,
+    41145,
+    41147,
+    41147,
+    41149,
+    41150,
+    41150,
+    41152,
+    41153,
+    41153,
+    41155,
+    41156,
+    41152,
+    41158,
+    41159,
+    41159,
+    41161,
+    41162,
+    41161,
+    41164,
+    41149,
+    41166,
+    41167,
+    41168,
+    41168,
+    41167,
+    41171,
+    41172,
+    41173,
+    41173,
+    41173,
+    41176,
+    41176,
+    41173,
+    41179,
+    41179,
+    41181,
+    41181,
+    41179,
+    41179,
+    41172,
+    41186,
+    41187,
+    41188,
+    41189,
+    41190,
+    41191,
+    41192,
+    41193,
+    41193,
+    41195,
+    41195,
+    41197,
+    41198,
+    41198,
+    41200,
+    41201,
+    41198,
+    41198,
+    41204,
+    41204,
+    41206,
+    41207,
+    41207,
+    41206,
+    41210,
+    41211,
+    41211,
+    41213,
+    41213,
+    41210,
+    41216,
+    41216,
+    41218,
+    41218,
+    41220,
+    41221,
+    41221,
+    41223,
+    41224,
+    41225,
+    41225,
+    41224,
+    41228,
+    41229,
+    41228,
+    41231,
+    41232,
+    41232,
+    41234,
+    41235,
+    41236,
+    41236,
+    41238,
+    41239,
+    41239,
+    41241,
+    41241,
+    41243,
+    41244,
+    41244,
+    41246,
+    41247,
+    41247,
+    41249,
+    41250,
+    41251,
+    41252,
+    41253,
+    41250,
+    41255,
+    41256,
+    41256,
+    41258,
+    41258,
+    41260,
+    41260,
+    41262,
+    41263,
+    41264,
+    41265,
+    41266,
+    41266,
+    41268,
+    41268,
+    41270,
+    41270,
+    41272,
+    41273,
+    41274,
+    41275,
+    41274,
+    41277,
+    41273,
+    41272,
+    41280,
+    41280,
+    41282,
+    41283,
+    41284,
+    41285,
+    41285,
+    41282,
+    41288,
+    41289,
+    41289,
+    41291,
+    41291,
+    41293,
+    41294,
+    41294,
+    41296,
+    41297,
+    41293,
+    41299,
+    41299,
+    41301,
+    41301,
+    41303,
+    41304,
+    41304,
+    41303,
+    41307,
+    41307,
+    41309,
+    41310,
+    41311,
+    41312,
+    41313,
+    41313,
+    41315,
+    41316,
+    41315,
+    41318,
+    41319,
+    41319,
+    41318,
+    41322,
+    41323,
+    41323,
+    41325,
+    41325,
+    41325,
+    41328,
+    41329,
+    41330,
+    41331,
+    41329,
+    41333,
+    41334,
+    41334,
+    41336,
+    41336,
+    41338,
+    41339,
+    41340,
+    41340,
+    41342,
+    41343,
+    41343,
+    41342,
+    41346,
+    41347,
+    41347,
+    41346,
+    41350,
+    41350,
+    41352,
+    41352,
+    41354,
+    41354,
+    41356,
+    41356,
+    41358,
+    41359,
+    41360,
+    41360,
+    41359,
+    41363,
+    41364,
+    41365,
+    41365,
+    41367,
+    41359,
+    41369,
+    41370,
+    41371,
+    41371,
+    41370,
+    41374,
+    41375,
+    41376,
+    41374,
+    41378,
+    41378,
+    41374,
+    41381,
+    41382,
+    41383,
+    41358,
+    41385,
+    41386,
+    41387,
+    41387,
+    41385,
+    41390,
+    41390,
+    41392,
+    41392,
+    41394,
+    41394,
+    41396,
+    41397,
+    41398,
+    41399,
+    41399,
+    41398,
+    41396,
+    41403,
+    41404,
+    41404,
+    41406,
+    41404,
+    41408,
+    41409,
+    41409,
+    41411,
+    41411,
+    41413,
+    41413,
+    41415,
+    41416,
+    41415,
+    41418,
+    41419,
+    41420,
+    41421,
+    41422,
+    41423,
+    41424,
+    41425,
+    41425,
+    41427,
+    41428,
+    41428,
+    41430,
+    41427,
+    41432,
+    41433,
+    41434,
+    41435,
+    41436,
+    41436,
+    41438,
+    41438,
+    41440,
+    41440,
+    41438,
+    41443,
+    41443,
+    41443,
+    41446,
+    41447,
+    41448,
+    41446,
+    41450,
+    41450,
+    41452,
+    41453,
+    41453,
+    41453,
+    41453,
+    41457,
+    41458,
+    41458,
+    41457,
+    41461,
+    41462,
+    41463,
+    41463,
+    41465,
+    41465,
+    41467,
+    41468,
+    41469,
+    41470,
+    41470,
+    41472,
+    41472,
+    41474,
+    41474,
+    41476,
+    41476,
+    41478,
+    41479,
+    41480,
+    41481,
+    41481,
+    41483,
+    41483,
+    41485,
+    41486,
+    41487,
+    41487,
+    41489,
+    41490,
+    41491,
+    41491,
+    41493,
+    41494,
+    41495,
+    41494,
+    41497,
+    41498,
+    41499,
+    41500,
+    41501,
+];
@@ -1,4 +1,25 @@
 #[path = "generated/words.rs"]
 pub mod generated;
 
+#[path = "generated/playable_words.rs"]
+pub mod playable_words;
+
+#[path = "generated/word_families.rs"]
+pub mod word_families;
+
 pub use generated::WORDS;
+
+/// The inflectional family of each word in [`WORDS`], at the same index as the word. Words that
+/// differ only by a plural or a tense, such as "ABDUCT", "ABDUCTS" and "ABDUCTING", share a family,
+/// which is identified by the index in [`WORDS`] of its first word. A word with no inflections
+/// among [`WORDS`] is alone in its family, which is identified by its own index.
+pub use word_families::WORD_FAMILIES;
+
+/// Every word that can be played on at least one board under the standard rules, in which
+/// letters may be repeated, as uppercase strings. Unlike [`WORDS`], this includes words with
+/// repeated letters and words of any length from 3 letters.
+pub use playable_words::PLAYABLE_WORDS;
+
+/// The CRC32 hash of the raw word-list data that [`WORDS`] was generated from, as eight
+/// uppercase hexadecimal digits. Any change to the dictionary changes this hash.
+pub static ALL_WORDS_CRC: &str = include_str!("../data/all_words.crc");
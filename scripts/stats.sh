@@ -0,0 +1,7 @@
+#!/bin/bash
+set -euo pipefail
+
+cd "$(dirname $0)/.."
+
+cd rust
+cargo run --release --package letrboxd-benchmarks --features stats --bin stats -- "$@"
@@ -1,30 +1,18 @@
 /* tslint:disable */
 /* eslint-disable */
 /**
- * Gathers valid words for a given 12-letter input, returning them in serialized form.
- *
- * # Panics
- *
- * Panics if the letter sequences cannot be serialized.
- */
-export function getValidWords(input: string): SerializedSequences;
-/**
- * Deserializes and stores valid words in thread-local storage for later use.
- * Solutions are generated in chunks, so this vector is reused multiple times.
- *
- * # Panics
- *
- * Panics if the serialized words cannot be deserialized.
- */
-export function registerValidWords(serialized_words: Uint8Array): void;
-/**
- * Clears the currently registered valid words from thread-local storage.
+ * The rules for which letters of a board may follow one another within a word, which appear in
+ * the TypeScript declarations as a union of string literals. See [`AdjacencyRule`].
  */
-export function clearValidWords(): void;
+
+export type WasmAdjacencyRule = "standard" | "easy" | "clockwise";
 /**
- * Generates puzzle solutions for valid words in the specified index range.
+ * The kinds of [`WasmError`], which appear in the TypeScript declarations as a union of
+ * string literals, e.g. `"RangeOutOfBounds"`.
  */
-export function solutions(range_start: number, range_end: number): SolutionsPayload;
+
+export type WasmErrorKind = "InvalidBoard" | "InvalidWord" | "InvalidRule" | "CorruptedBuffer" | "RangeOutOfBounds" | "NoWordsRegistered";
+
 /**
  * [`LetterSequence`] is a stack-allocated vector of up to 12 uppercase [ASCII] letters represented internally by
  * a single [u64] value.
@@ -98,99 +86,504 @@ export function solutions(range_start: number, range_end: number): SolutionsPayl
  * [ASCII]: https://en.wikipedia.org/wiki/ASCII
  */
 export class LetterSequence {
-  private constructor();
-  free(): void;
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+}
+
+/**
+ * A payload to hold the closest partial chains for a board that has no solution,
+ * along with the letters that each chain leaves uncovered.
+ *
+ * The chain at position `n` in [`chains`](Self::take_chains) is missing the letters
+ * at position `n` in [`missingLetters`](Self::take_missing_letters).
+ */
+export class PartialChainsPayload {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Takes and returns the solution string of each chain, e.g. `"FLACK KNIGHT"`,
+     * clearing them from the internal list.
+     */
+    readonly chains: string[];
+    /**
+     * Takes and returns the letters that each chain leaves uncovered, e.g. `"JP"`,
+     * clearing them from the internal list.
+     */
+    readonly missingLetters: string[];
 }
+
+/**
+ * A payload to hold the tables of a [`Participation`], each as a list of labels and a list of
+ * the counts of solutions that each label takes part in, from the most solutions to the fewest.
+ *
+ * The tables are complete, so the payloads for separate ranges of starting words can be combined
+ * by adding the counts for each label. Counts that do not fit in a [`u32`] are saturated.
+ */
+export class ParticipationPayload {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Takes and returns the count of solutions that start with each of the
+     * [`firstWords`](Self::take_first_words), clearing them from the internal list.
+     */
+    readonly firstWordSolutionCounts: Uint32Array;
+    /**
+     * Takes and returns each word that starts a solution, clearing them from the internal list.
+     */
+    readonly firstWords: string[];
+    /**
+     * Takes and returns the count of solutions in which each of the
+     * [`junctionLetters`](Self::take_junction_letters) joins two words,
+     * clearing them from the internal list.
+     */
+    readonly junctionLetterSolutionCounts: Uint32Array;
+    /**
+     * Takes and returns each letter that joins two words in a solution, e.g. `"D"`,
+     * clearing them from the internal list.
+     */
+    readonly junctionLetters: string[];
+    /**
+     * Takes and returns the count of solutions that follow each of the
+     * [`wordLengths`](Self::take_word_lengths), clearing them from the internal list.
+     */
+    readonly wordLengthSolutionCounts: Uint32Array;
+    /**
+     * Takes and returns the length of each word in order for every pattern of word lengths,
+     * e.g. `"6-7"`, clearing them from the internal list.
+     */
+    readonly wordLengths: string[];
+    /**
+     * Takes and returns the count of solutions that contain each of the [`words`](Self::take_words),
+     * clearing them from the internal list.
+     */
+    readonly wordSolutionCounts: Uint32Array;
+    /**
+     * Takes and returns each word that takes part in a solution, e.g. `"QUA"`,
+     * clearing them from the internal list.
+     */
+    readonly words: string[];
+}
+
+/**
+ * Options that narrow down the solutions of every search, such as words that every solution must
+ * contain, or the count of words in every solution. See [`solver::filter`].
+ *
+ * The options only take effect once they are registered with `setSearchOptions`.
+ */
+export class SearchOptions {
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Only admits solutions that do not start with any of the `letters`, e.g. `"XYZ"`.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `letters` are not distinct letters, in either case.
+     */
+    excludeFirstLetters(letters: string): void;
+    /**
+     * Only admits solutions that do not contain the `word`. May be called more than once.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+     */
+    excludeWord(word: string): void;
+    /**
+     * Creates options with no constraints, which admit every solution.
+     */
+    constructor();
+    /**
+     * Only admits solutions that contain the `word`. May be called more than once.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+     */
+    requireWord(word: string): void;
+    /**
+     * Only admits solutions that start with the `word`.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+     */
+    setFirstWord(word: string): void;
+    /**
+     * Only admits solutions in which every word has at least `min_word_len` letters.
+     */
+    setMinWordLength(min_word_len: number): void;
+    /**
+     * Only admits solutions with at least `min_word_count` and at most `max_word_count` words.
+     */
+    setWordCountRange(min_word_count: number, max_word_count: number): void;
+}
+
 /**
  * A structure holding serialized words along with the total word count.
+ *
+ * The words are serialized in the compact format defined by [`solver::wire`],
+ * which holds only the packed letters of each word.
  */
 export class SerializedSequences {
-  private constructor();
-  free(): void;
-  /**
-   * Returns the number of words in the serialized word list.
-   */
-  readonly wordCount: number;
-  /**
-   * Returns the serialized list of valid words.
-   */
-  readonly serializedWords: Uint8Array;
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Takes and returns the serialized list of valid words, leaving an empty list in its place.
+     *
+     * The buffer is moved out rather than cloned, so the resulting `Uint8Array` owns the only copy
+     * outside of WASM memory, and its underlying `ArrayBuffer` may be transferred between workers.
+     * Calling this again returns an empty list.
+     */
+    takeSerializedWords(): Uint8Array;
+    /**
+     * Returns the number of words in the serialized word list.
+     */
+    readonly wordCount: number;
 }
+
+/**
+ * A payload to hold a [`SolutionPaths`]: each distinct path through the letters, the count of
+ * its splits into words, and every split as a solution string, e.g. `"IMPART TED DUNKS"`.
+ *
+ * The splits of every path are flattened into a single list, in which the splits of each path
+ * follow those of the path before it. The payloads for separate ranges of starting words can be
+ * combined by merging the splits of equal paths.
+ */
+export class SolutionPathsPayload {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Takes and returns the letters of each distinct path, e.g. `"IMPARTEDUNKS"`,
+     * clearing them from the internal list.
+     */
+    readonly paths: string[];
+    /**
+     * Takes and returns the count of splits of each of the [`paths`](Self::take_paths),
+     * clearing them from the internal list.
+     */
+    readonly splitCounts: Uint32Array;
+    /**
+     * Takes and returns every split of every path as a solution string, from the fewest words to
+     * the most within each path, clearing them from the internal list.
+     */
+    readonly splits: string[];
+}
+
+/**
+ * A resumable search over the registered valid words in an index range, which yields
+ * its solutions in batches so that the caller may report progress or cancel between them.
+ *
+ * The stream keeps its own copy of the words that the registered search options admit when it
+ * is created, so later batches are unaffected by registering other words or options.
+ */
+export class SolutionStream {
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Cancels the stream, so that every later call to [`next_batch`](Self::next_batch)
+     * returns `undefined` without searching.
+     */
+    cancel(): void;
+    /**
+     * Creates a stream of solutions that start with the registered valid words in the specified
+     * index range. Each batch holds the solutions from as many starting words as it takes to reach
+     * at least `batch_size` solutions, or the rest of the range, whichever comes first.
+     *
+     * # Errors
+     *
+     * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+     * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+     */
+    constructor(range_start: number, range_end: number, batch_size: number);
+    /**
+     * Searches the next starting words in the range, returning the next batch of solutions,
+     * or `undefined` once every starting word has been searched or the stream is cancelled.
+     */
+    nextBatch(): SolutionsPayload | undefined;
+    /**
+     * Returns `true` once every starting word has been searched, or the stream is cancelled.
+     */
+    readonly isDone: boolean;
+    /**
+     * Returns the fraction of the starting words in the range that have been searched, from `0`
+     * to `1`, counting only those that the search options admit. A range that has no such words
+     * is reported as fully searched.
+     */
+    readonly progress: number;
+}
+
 /**
  * A payload to hold solution strings grouped by how many words are in the solution.
  * There must be at least 1 word in a solution, and there can be at most 5 words.
  */
 export class SolutionsPayload {
-  private constructor();
-  free(): void;
-  /**
-   * Adds a [`LetterSequence`] solution to the relevant bucket based on the word count.
-   */
-  push(sequence: LetterSequence): void;
-  /**
-   * Takes and returns all one-word solutions, clearing them from the internal list.
-   */
-  readonly oneWordSolutions: string[];
-  /**
-   * Takes and returns all two-word solutions, clearing them from the internal list.
-   */
-  readonly twoWordSolutions: string[];
-  /**
-   * Takes and returns all three-word solutions, clearing them from the internal list.
-   */
-  readonly threeWordSolutions: string[];
-  /**
-   * Takes and returns all four-word solutions, clearing them from the internal list.
-   */
-  readonly fourWordSolutions: string[];
-  /**
-   * Takes and returns all five-word solutions, clearing them from the internal list.
-   */
-  readonly fiveWordSolutions: string[];
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Adds a [`LetterSequence`] solution to the relevant bucket based on the word count.
+     */
+    push(sequence: LetterSequence): void;
+    /**
+     * Takes and returns all five-word solutions, clearing them from the internal list.
+     */
+    readonly fiveWordSolutions: string[];
+    /**
+     * Takes and returns all four-word solutions, clearing them from the internal list.
+     */
+    readonly fourWordSolutions: string[];
+    /**
+     * Takes and returns all one-word solutions, clearing them from the internal list.
+     */
+    readonly oneWordSolutions: string[];
+    /**
+     * Takes and returns all three-word solutions, clearing them from the internal list.
+     */
+    readonly threeWordSolutions: string[];
+    /**
+     * Takes and returns all two-word solutions, clearing them from the internal list.
+     */
+    readonly twoWordSolutions: string[];
 }
 
+/**
+ * The error that is thrown by every fallible exported function, which carries its
+ * [`kind`](Self::kind) apart from its message, so that JavaScript can switch on it.
+ */
+export class WasmError {
+    private constructor();
+    free(): void;
+    [Symbol.dispose](): void;
+    /**
+     * Returns the kind of the error followed by its message, e.g.
+     * `"NoWordsRegistered: No valid words have been registered."`.
+     */
+    toString(): string;
+    /**
+     * Returns the kind of the error, e.g. `"RangeOutOfBounds"`.
+     */
+    readonly kind: WasmErrorKind;
+    /**
+     * Returns a description of the error, which does not include its kind.
+     */
+    readonly message: string;
+}
+
+/**
+ * Clears the registered search options, so that every later search admits every solution.
+ */
+export function clearSearchOptions(): void;
+
+/**
+ * Clears the currently registered valid words from thread-local storage.
+ */
+export function clearValidWords(): void;
+
+/**
+ * Divides the indices of the registered valid words into `range_count` contiguous ranges
+ * of roughly equal estimated cost, returning the `range_count + 1` boundaries between them.
+ *
+ * The range at position `n` runs from `boundaries[n]` (inclusive) to `boundaries[n + 1]` (exclusive).
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if `range_count` is zero, or a `NoWordsRegistered`
+ * error if no valid words have been registered.
+ */
+export function costBalancedRanges(range_count: number): Uint32Array;
+
+/**
+ * Estimates the cost of searching for every solution that starts with each of the
+ * registered valid words, in order. Costs that do not fit in a [`u32`] are saturated.
+ *
+ * # Errors
+ *
+ * Throws a `NoWordsRegistered` error if no valid words have been registered.
+ */
+export function estimateCosts(): Uint32Array;
+
+/**
+ * Gathers valid words for a given 12-letter input, returning them in serialized form.
+ *
+ * The letters that may follow one another are decided by the `rule`, which defaults to the
+ * standard rule, and by the `forbiddenPairs`, if any, in the format that is accepted by
+ * [`Adjacency::with_forbidden_pairs`], e.g. `"QU, TH"`.
+ *
+ * # Errors
+ *
+ * Throws an `InvalidBoard` error if the input is not 12 distinct uppercase letters, an
+ * `InvalidRule` error if the rule is not known, or an `InvalidWord` error if the forbidden
+ * pairs cannot be parsed.
+ */
+export function getValidWords(input: string, rule?: WasmAdjacencyRule | null, forbiddenPairs?: string | null): SerializedSequences;
+
+/**
+ * Finds the chains that come closest to solving a 12-letter input, for a board that has no
+ * unique-letter solution. See [`solver::partial::best_partial_chains`].
+ *
+ * The valid words are gathered under the `rule` and `forbiddenPairs` in the same way as
+ * `getValidWords`.
+ *
+ * # Errors
+ *
+ * Throws the same errors as `getValidWords`.
+ */
+export function partialChains(input: string, rule?: WasmAdjacencyRule | null, forbiddenPairs?: string | null): PartialChainsPayload;
+
+/**
+ * Tallies how the words and letters take part in the solutions that start with the registered
+ * valid words in the specified index range, narrowed down by the registered search options.
+ * See [`solver::participation`].
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+ * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+ */
+export function participation(range_start: number, range_end: number): ParticipationPayload;
+
+/**
+ * Deserializes and stores valid words in thread-local storage for later use.
+ * Solutions are generated in chunks, so this vector is reused multiple times.
+ *
+ * # Errors
+ *
+ * Throws a `CorruptedBuffer` error if the serialized words cannot be deserialized,
+ * in which case any previously registered words are left in place.
+ */
+export function registerValidWords(serialized_words: Uint8Array): void;
+
+/**
+ * Registers the search options that narrow down the solutions of every later search on this
+ * thread, replacing any options that were registered before.
+ */
+export function setSearchOptions(options: SearchOptions): void;
+
+/**
+ * Groups the solutions that start with the registered valid words in the specified index range
+ * by their path through the letters, narrowed down by the registered search options.
+ * See [`solver::paths`].
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+ * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+ */
+export function solutionPaths(range_start: number, range_end: number): SolutionPathsPayload;
+
+/**
+ * Generates puzzle solutions for valid words in the specified index range, narrowed down by
+ * the registered search options.
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+ * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+ */
+export function solutions(range_start: number, range_end: number): SolutionsPayload;
+
+/**
+ * Returns the first word of the family of inflections of each of the registered valid words,
+ * in order, e.g. `"ABDUCT"` for `"ABDUCTS"`. Two solutions differ only by inflection if their words
+ * have the same family heads in the same order. See [`solver::families`].
+ *
+ * # Errors
+ *
+ * Throws a `NoWordsRegistered` error if no valid words have been registered.
+ */
+export function wordFamilyHeads(): string[];
+
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
-  readonly memory: WebAssembly.Memory;
-  readonly __wbg_serializedsequences_free: (a: number, b: number) => void;
-  readonly serializedsequences_wordCount: (a: number) => number;
-  readonly serializedsequences_serializedWords: (a: number, b: number) => void;
-  readonly __wbg_solutionspayload_free: (a: number, b: number) => void;
-  readonly solutionspayload_push: (a: number, b: number) => void;
-  readonly solutionspayload_oneWordSolutions: (a: number, b: number) => void;
-  readonly solutionspayload_twoWordSolutions: (a: number, b: number) => void;
-  readonly solutionspayload_threeWordSolutions: (a: number, b: number) => void;
-  readonly solutionspayload_fourWordSolutions: (a: number, b: number) => void;
-  readonly solutionspayload_fiveWordSolutions: (a: number, b: number) => void;
-  readonly getValidWords: (a: number, b: number) => number;
-  readonly registerValidWords: (a: number, b: number) => void;
-  readonly clearValidWords: () => void;
-  readonly solutions: (a: number, b: number) => number;
-  readonly __wbg_lettersequence_free: (a: number, b: number) => void;
-  readonly __wbindgen_add_to_stack_pointer: (a: number) => number;
-  readonly __wbindgen_export_0: (a: number, b: number, c: number) => void;
-  readonly __wbindgen_export_1: (a: number, b: number) => number;
-  readonly __wbindgen_export_2: (a: number, b: number, c: number, d: number) => number;
+    readonly memory: WebAssembly.Memory;
+    readonly __wbg_lettersequence_free: (a: number, b: number) => void;
+    readonly __wbg_partialchainspayload_free: (a: number, b: number) => void;
+    readonly __wbg_participationpayload_free: (a: number, b: number) => void;
+    readonly __wbg_searchoptions_free: (a: number, b: number) => void;
+    readonly __wbg_serializedsequences_free: (a: number, b: number) => void;
+    readonly __wbg_solutionpathspayload_free: (a: number, b: number) => void;
+    readonly __wbg_solutionspayload_free: (a: number, b: number) => void;
+    readonly __wbg_solutionstream_free: (a: number, b: number) => void;
+    readonly __wbg_wasmerror_free: (a: number, b: number) => void;
+    readonly clearSearchOptions: () => void;
+    readonly clearValidWords: () => void;
+    readonly costBalancedRanges: (a: number, b: number) => void;
+    readonly estimateCosts: (a: number) => void;
+    readonly getValidWords: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+    readonly partialChains: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+    readonly partialchainspayload_chains: (a: number, b: number) => void;
+    readonly partialchainspayload_missingLetters: (a: number, b: number) => void;
+    readonly participation: (a: number, b: number, c: number) => void;
+    readonly participationpayload_firstWordSolutionCounts: (a: number, b: number) => void;
+    readonly participationpayload_firstWords: (a: number, b: number) => void;
+    readonly participationpayload_junctionLetterSolutionCounts: (a: number, b: number) => void;
+    readonly participationpayload_junctionLetters: (a: number, b: number) => void;
+    readonly participationpayload_wordLengthSolutionCounts: (a: number, b: number) => void;
+    readonly participationpayload_wordLengths: (a: number, b: number) => void;
+    readonly participationpayload_wordSolutionCounts: (a: number, b: number) => void;
+    readonly participationpayload_words: (a: number, b: number) => void;
+    readonly registerValidWords: (a: number, b: number, c: number) => void;
+    readonly searchoptions_excludeFirstLetters: (a: number, b: number, c: number, d: number) => void;
+    readonly searchoptions_excludeWord: (a: number, b: number, c: number, d: number) => void;
+    readonly searchoptions_new: () => number;
+    readonly searchoptions_requireWord: (a: number, b: number, c: number, d: number) => void;
+    readonly searchoptions_setFirstWord: (a: number, b: number, c: number, d: number) => void;
+    readonly searchoptions_setMinWordLength: (a: number, b: number) => void;
+    readonly searchoptions_setWordCountRange: (a: number, b: number, c: number) => void;
+    readonly serializedsequences_takeSerializedWords: (a: number, b: number) => void;
+    readonly serializedsequences_wordCount: (a: number) => number;
+    readonly setSearchOptions: (a: number) => void;
+    readonly solutionPaths: (a: number, b: number, c: number) => void;
+    readonly solutionpathspayload_paths: (a: number, b: number) => void;
+    readonly solutionpathspayload_splitCounts: (a: number, b: number) => void;
+    readonly solutionpathspayload_splits: (a: number, b: number) => void;
+    readonly solutions: (a: number, b: number, c: number) => void;
+    readonly solutionspayload_fiveWordSolutions: (a: number, b: number) => void;
+    readonly solutionspayload_fourWordSolutions: (a: number, b: number) => void;
+    readonly solutionspayload_oneWordSolutions: (a: number, b: number) => void;
+    readonly solutionspayload_push: (a: number, b: number) => void;
+    readonly solutionspayload_threeWordSolutions: (a: number, b: number) => void;
+    readonly solutionspayload_twoWordSolutions: (a: number, b: number) => void;
+    readonly solutionstream_cancel: (a: number) => void;
+    readonly solutionstream_isDone: (a: number) => number;
+    readonly solutionstream_new: (a: number, b: number, c: number, d: number) => void;
+    readonly solutionstream_nextBatch: (a: number) => number;
+    readonly solutionstream_progress: (a: number) => number;
+    readonly wasmerror_kind: (a: number) => number;
+    readonly wasmerror_message: (a: number, b: number) => void;
+    readonly wasmerror_toString: (a: number, b: number) => void;
+    readonly wordFamilyHeads: (a: number) => void;
+    readonly __wbindgen_add_to_stack_pointer: (a: number) => number;
+    readonly __wbindgen_export: (a: number, b: number, c: number) => void;
+    readonly __wbindgen_export2: (a: number, b: number) => number;
+    readonly __wbindgen_export3: (a: number, b: number, c: number, d: number) => number;
 }
 
 export type SyncInitInput = BufferSource | WebAssembly.Module;
+
 /**
-* Instantiates the given `module`, which can either be bytes or
-* a precompiled `WebAssembly.Module`.
-*
-* @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
-*
-* @returns {InitOutput}
-*/
+ * Instantiates the given `module`, which can either be bytes or
+ * a precompiled `WebAssembly.Module`.
+ *
+ * @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
+ *
+ * @returns {InitOutput}
+ */
 export function initSync(module: { module: SyncInitInput } | SyncInitInput): InitOutput;
 
 /**
-* If `module_or_path` is {RequestInfo} or {URL}, makes a request and
-* for everything else, calls `WebAssembly.instantiate` directly.
-*
-* @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
-*
-* @returns {Promise<InitOutput>}
-*/
+ * If `module_or_path` is {RequestInfo} or {URL}, makes a request and
+ * for everything else, calls `WebAssembly.instantiate` directly.
+ *
+ * @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
+ *
+ * @returns {Promise<InitOutput>}
+ */
 export default function __wbg_init (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>): Promise<InitOutput>;
@@ -1,195 +1,5 @@
-let wasm;
+/* @ts-self-types="./letrboxd.d.ts" */
 
-const cachedTextDecoder = (typeof TextDecoder !== 'undefined' ? new TextDecoder('utf-8', { ignoreBOM: true, fatal: true }) : { decode: () => { throw Error('TextDecoder not available') } } );
-
-if (typeof TextDecoder !== 'undefined') { cachedTextDecoder.decode(); };
-
-let cachedUint8ArrayMemory0 = null;
-
-function getUint8ArrayMemory0() {
-    if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
-        cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
-    }
-    return cachedUint8ArrayMemory0;
-}
-
-function getStringFromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
-}
-
-const heap = new Array(128).fill(undefined);
-
-heap.push(undefined, null, true, false);
-
-let heap_next = heap.length;
-
-function addHeapObject(obj) {
-    if (heap_next === heap.length) heap.push(heap.length + 1);
-    const idx = heap_next;
-    heap_next = heap[idx];
-
-    heap[idx] = obj;
-    return idx;
-}
-
-let cachedDataViewMemory0 = null;
-
-function getDataViewMemory0() {
-    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
-        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
-    }
-    return cachedDataViewMemory0;
-}
-
-function getArrayU8FromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
-}
-
-function _assertClass(instance, klass) {
-    if (!(instance instanceof klass)) {
-        throw new Error(`expected instance of ${klass.name}`);
-    }
-}
-
-function getObject(idx) { return heap[idx]; }
-
-function dropObject(idx) {
-    if (idx < 132) return;
-    heap[idx] = heap_next;
-    heap_next = idx;
-}
-
-function takeObject(idx) {
-    const ret = getObject(idx);
-    dropObject(idx);
-    return ret;
-}
-
-function getArrayJsValueFromWasm0(ptr, len) {
-    ptr = ptr >>> 0;
-    const mem = getDataViewMemory0();
-    const result = [];
-    for (let i = ptr; i < ptr + 4 * len; i += 4) {
-        result.push(takeObject(mem.getUint32(i, true)));
-    }
-    return result;
-}
-
-let WASM_VECTOR_LEN = 0;
-
-const cachedTextEncoder = (typeof TextEncoder !== 'undefined' ? new TextEncoder('utf-8') : { encode: () => { throw Error('TextEncoder not available') } } );
-
-const encodeString = (typeof cachedTextEncoder.encodeInto === 'function'
-    ? function (arg, view) {
-    return cachedTextEncoder.encodeInto(arg, view);
-}
-    : function (arg, view) {
-    const buf = cachedTextEncoder.encode(arg);
-    view.set(buf);
-    return {
-        read: arg.length,
-        written: buf.length
-    };
-});
-
-function passStringToWasm0(arg, malloc, realloc) {
-
-    if (realloc === undefined) {
-        const buf = cachedTextEncoder.encode(arg);
-        const ptr = malloc(buf.length, 1) >>> 0;
-        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);
-        WASM_VECTOR_LEN = buf.length;
-        return ptr;
-    }
-
-    let len = arg.length;
-    let ptr = malloc(len, 1) >>> 0;
-
-    const mem = getUint8ArrayMemory0();
-
-    let offset = 0;
-
-    for (; offset < len; offset++) {
-        const code = arg.charCodeAt(offset);
-        if (code > 0x7F) break;
-        mem[ptr + offset] = code;
-    }
-
-    if (offset !== len) {
-        if (offset !== 0) {
-            arg = arg.slice(offset);
-        }
-        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
-        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
-        const ret = encodeString(arg, view);
-
-        offset += ret.written;
-        ptr = realloc(ptr, len, offset, 1) >>> 0;
-    }
-
-    WASM_VECTOR_LEN = offset;
-    return ptr;
-}
-/**
- * Gathers valid words for a given 12-letter input, returning them in serialized form.
- *
- * # Panics
- *
- * Panics if the letter sequences cannot be serialized.
- * @param {string} input
- * @returns {SerializedSequences}
- */
-export function getValidWords(input) {
-    const ptr0 = passStringToWasm0(input, wasm.__wbindgen_export_1, wasm.__wbindgen_export_2);
-    const len0 = WASM_VECTOR_LEN;
-    const ret = wasm.getValidWords(ptr0, len0);
-    return SerializedSequences.__wrap(ret);
-}
-
-function passArray8ToWasm0(arg, malloc) {
-    const ptr = malloc(arg.length * 1, 1) >>> 0;
-    getUint8ArrayMemory0().set(arg, ptr / 1);
-    WASM_VECTOR_LEN = arg.length;
-    return ptr;
-}
-/**
- * Deserializes and stores valid words in thread-local storage for later use.
- * Solutions are generated in chunks, so this vector is reused multiple times.
- *
- * # Panics
- *
- * Panics if the serialized words cannot be deserialized.
- * @param {Uint8Array} serialized_words
- */
-export function registerValidWords(serialized_words) {
-    const ptr0 = passArray8ToWasm0(serialized_words, wasm.__wbindgen_export_1);
-    const len0 = WASM_VECTOR_LEN;
-    wasm.registerValidWords(ptr0, len0);
-}
-
-/**
- * Clears the currently registered valid words from thread-local storage.
- */
-export function clearValidWords() {
-    wasm.clearValidWords();
-}
-
-/**
- * Generates puzzle solutions for valid words in the specified index range.
- * @param {number} range_start
- * @param {number} range_end
- * @returns {SolutionsPayload}
- */
-export function solutions(range_start, range_end) {
-    const ret = wasm.solutions(range_start, range_end);
-    return SolutionsPayload.__wrap(ret);
-}
-
-const LetterSequenceFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_lettersequence_free(ptr >>> 0, 1));
 /**
  * [`LetterSequence`] is a stack-allocated vector of up to 12 uppercase [ASCII] letters represented internally by
  * a single [u64] value.
@@ -263,263 +73,1366 @@ const LetterSequenceFinalization = (typeof FinalizationRegistry === 'undefined')
  * [ASCII]: https://en.wikipedia.org/wiki/ASCII
  */
 export class LetterSequence {
-
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
         LetterSequenceFinalization.unregister(this);
         return ptr;
     }
-
     free() {
         const ptr = this.__destroy_into_raw();
         wasm.__wbg_lettersequence_free(ptr, 0);
     }
 }
+if (Symbol.dispose) LetterSequence.prototype[Symbol.dispose] = LetterSequence.prototype.free;
 
-const SerializedSequencesFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_serializedsequences_free(ptr >>> 0, 1));
 /**
- * A structure holding serialized words along with the total word count.
+ * A payload to hold the closest partial chains for a board that has no solution,
+ * along with the letters that each chain leaves uncovered.
+ *
+ * The chain at position `n` in [`chains`](Self::take_chains) is missing the letters
+ * at position `n` in [`missingLetters`](Self::take_missing_letters).
  */
-export class SerializedSequences {
-
+export class PartialChainsPayload {
     static __wrap(ptr) {
-        ptr = ptr >>> 0;
-        const obj = Object.create(SerializedSequences.prototype);
+        const obj = Object.create(PartialChainsPayload.prototype);
         obj.__wbg_ptr = ptr;
-        SerializedSequencesFinalization.register(obj, obj.__wbg_ptr, obj);
+        PartialChainsPayloadFinalization.register(obj, obj.__wbg_ptr, obj);
         return obj;
     }
-
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
-        SerializedSequencesFinalization.unregister(this);
+        PartialChainsPayloadFinalization.unregister(this);
         return ptr;
     }
-
     free() {
         const ptr = this.__destroy_into_raw();
-        wasm.__wbg_serializedsequences_free(ptr, 0);
+        wasm.__wbg_partialchainspayload_free(ptr, 0);
     }
     /**
-     * Returns the number of words in the serialized word list.
-     * @returns {number}
+     * Takes and returns the solution string of each chain, e.g. `"FLACK KNIGHT"`,
+     * clearing them from the internal list.
+     * @returns {string[]}
      */
-    get wordCount() {
-        const ret = wasm.serializedsequences_wordCount(this.__wbg_ptr);
-        return ret >>> 0;
+    get chains() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.partialchainspayload_chains(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
     }
     /**
-     * Returns the serialized list of valid words.
-     * @returns {Uint8Array}
+     * Takes and returns the letters that each chain leaves uncovered, e.g. `"JP"`,
+     * clearing them from the internal list.
+     * @returns {string[]}
      */
-    get serializedWords() {
+    get missingLetters() {
         try {
             const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.serializedsequences_serializedWords(retptr, this.__wbg_ptr);
+            wasm.partialchainspayload_missingLetters(retptr, this.__wbg_ptr);
             var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
             var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayU8FromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_0(r0, r1 * 1, 1);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
             return v1;
         } finally {
             wasm.__wbindgen_add_to_stack_pointer(16);
         }
     }
 }
+if (Symbol.dispose) PartialChainsPayload.prototype[Symbol.dispose] = PartialChainsPayload.prototype.free;
 
-const SolutionsPayloadFinalization = (typeof FinalizationRegistry === 'undefined')
-    ? { register: () => {}, unregister: () => {} }
-    : new FinalizationRegistry(ptr => wasm.__wbg_solutionspayload_free(ptr >>> 0, 1));
 /**
- * A payload to hold solution strings grouped by how many words are in the solution.
- * There must be at least 1 word in a solution, and there can be at most 5 words.
+ * A payload to hold the tables of a [`Participation`], each as a list of labels and a list of
+ * the counts of solutions that each label takes part in, from the most solutions to the fewest.
+ *
+ * The tables are complete, so the payloads for separate ranges of starting words can be combined
+ * by adding the counts for each label. Counts that do not fit in a [`u32`] are saturated.
  */
-export class SolutionsPayload {
-
+export class ParticipationPayload {
     static __wrap(ptr) {
-        ptr = ptr >>> 0;
-        const obj = Object.create(SolutionsPayload.prototype);
+        const obj = Object.create(ParticipationPayload.prototype);
         obj.__wbg_ptr = ptr;
-        SolutionsPayloadFinalization.register(obj, obj.__wbg_ptr, obj);
+        ParticipationPayloadFinalization.register(obj, obj.__wbg_ptr, obj);
         return obj;
     }
-
     __destroy_into_raw() {
         const ptr = this.__wbg_ptr;
         this.__wbg_ptr = 0;
-        SolutionsPayloadFinalization.unregister(this);
+        ParticipationPayloadFinalization.unregister(this);
         return ptr;
     }
-
     free() {
         const ptr = this.__destroy_into_raw();
-        wasm.__wbg_solutionspayload_free(ptr, 0);
+        wasm.__wbg_participationpayload_free(ptr, 0);
     }
     /**
-     * Adds a [`LetterSequence`] solution to the relevant bucket based on the word count.
-     * @param {LetterSequence} sequence
+     * Takes and returns the count of solutions that start with each of the
+     * [`firstWords`](Self::take_first_words), clearing them from the internal list.
+     * @returns {Uint32Array}
      */
-    push(sequence) {
-        _assertClass(sequence, LetterSequence);
-        var ptr0 = sequence.__destroy_into_raw();
-        wasm.solutionspayload_push(this.__wbg_ptr, ptr0);
+    get firstWordSolutionCounts() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.participationpayload_firstWordSolutionCounts(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
     }
     /**
-     * Takes and returns all one-word solutions, clearing them from the internal list.
+     * Takes and returns each word that starts a solution, clearing them from the internal list.
      * @returns {string[]}
      */
-    get oneWordSolutions() {
+    get firstWords() {
         try {
             const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.solutionspayload_oneWordSolutions(retptr, this.__wbg_ptr);
+            wasm.participationpayload_firstWords(retptr, this.__wbg_ptr);
             var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
             var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_0(r0, r1 * 4, 4);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
             return v1;
         } finally {
             wasm.__wbindgen_add_to_stack_pointer(16);
         }
     }
     /**
-     * Takes and returns all two-word solutions, clearing them from the internal list.
-     * @returns {string[]}
+     * Takes and returns the count of solutions in which each of the
+     * [`junctionLetters`](Self::take_junction_letters) joins two words,
+     * clearing them from the internal list.
+     * @returns {Uint32Array}
      */
-    get twoWordSolutions() {
+    get junctionLetterSolutionCounts() {
         try {
             const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.solutionspayload_twoWordSolutions(retptr, this.__wbg_ptr);
+            wasm.participationpayload_junctionLetterSolutionCounts(retptr, this.__wbg_ptr);
             var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
             var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_0(r0, r1 * 4, 4);
+            var v1 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
             return v1;
         } finally {
             wasm.__wbindgen_add_to_stack_pointer(16);
         }
     }
     /**
-     * Takes and returns all three-word solutions, clearing them from the internal list.
+     * Takes and returns each letter that joins two words in a solution, e.g. `"D"`,
+     * clearing them from the internal list.
      * @returns {string[]}
      */
-    get threeWordSolutions() {
+    get junctionLetters() {
         try {
             const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.solutionspayload_threeWordSolutions(retptr, this.__wbg_ptr);
+            wasm.participationpayload_junctionLetters(retptr, this.__wbg_ptr);
             var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
             var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_0(r0, r1 * 4, 4);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
             return v1;
         } finally {
             wasm.__wbindgen_add_to_stack_pointer(16);
         }
     }
     /**
-     * Takes and returns all four-word solutions, clearing them from the internal list.
+     * Takes and returns the count of solutions that follow each of the
+     * [`wordLengths`](Self::take_word_lengths), clearing them from the internal list.
+     * @returns {Uint32Array}
+     */
+    get wordLengthSolutionCounts() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.participationpayload_wordLengthSolutionCounts(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns the length of each word in order for every pattern of word lengths,
+     * e.g. `"6-7"`, clearing them from the internal list.
      * @returns {string[]}
      */
-    get fourWordSolutions() {
+    get wordLengths() {
         try {
             const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.solutionspayload_fourWordSolutions(retptr, this.__wbg_ptr);
+            wasm.participationpayload_wordLengths(retptr, this.__wbg_ptr);
             var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
             var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_0(r0, r1 * 4, 4);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
             return v1;
         } finally {
             wasm.__wbindgen_add_to_stack_pointer(16);
         }
     }
     /**
-     * Takes and returns all five-word solutions, clearing them from the internal list.
+     * Takes and returns the count of solutions that contain each of the [`words`](Self::take_words),
+     * clearing them from the internal list.
+     * @returns {Uint32Array}
+     */
+    get wordSolutionCounts() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.participationpayload_wordSolutionCounts(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns each word that takes part in a solution, e.g. `"QUA"`,
+     * clearing them from the internal list.
      * @returns {string[]}
      */
-    get fiveWordSolutions() {
+    get words() {
         try {
             const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
-            wasm.solutionspayload_fiveWordSolutions(retptr, this.__wbg_ptr);
+            wasm.participationpayload_words(retptr, this.__wbg_ptr);
             var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
             var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
-            var v1 = getArrayJsValueFromWasm0(r0, r1).slice();
-            wasm.__wbindgen_export_0(r0, r1 * 4, 4);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
             return v1;
         } finally {
             wasm.__wbindgen_add_to_stack_pointer(16);
         }
     }
 }
+if (Symbol.dispose) ParticipationPayload.prototype[Symbol.dispose] = ParticipationPayload.prototype.free;
 
-async function __wbg_load(module, imports) {
-    if (typeof Response === 'function' && module instanceof Response) {
-        if (typeof WebAssembly.instantiateStreaming === 'function') {
-            try {
-                return await WebAssembly.instantiateStreaming(module, imports);
+/**
+ * Options that narrow down the solutions of every search, such as words that every solution must
+ * contain, or the count of words in every solution. See [`solver::filter`].
+ *
+ * The options only take effect once they are registered with `setSearchOptions`.
+ */
+export class SearchOptions {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SearchOptionsFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_searchoptions_free(ptr, 0);
+    }
+    /**
+     * Only admits solutions that do not start with any of the `letters`, e.g. `"XYZ"`.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `letters` are not distinct letters, in either case.
+     * @param {string} letters
+     */
+    excludeFirstLetters(letters) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(letters, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.searchoptions_excludeFirstLetters(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Only admits solutions that do not contain the `word`. May be called more than once.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+     * @param {string} word
+     */
+    excludeWord(word) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(word, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.searchoptions_excludeWord(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Creates options with no constraints, which admit every solution.
+     */
+    constructor() {
+        const ret = wasm.searchoptions_new();
+        this.__wbg_ptr = ret;
+        SearchOptionsFinalization.register(this, this.__wbg_ptr, this);
+        return this;
+    }
+    /**
+     * Only admits solutions that contain the `word`. May be called more than once.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+     * @param {string} word
+     */
+    requireWord(word) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(word, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.searchoptions_requireWord(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Only admits solutions that start with the `word`.
+     *
+     * # Errors
+     *
+     * Throws an `InvalidWord` error if the `word` is not a valid word with unique letters.
+     * @param {string} word
+     */
+    setFirstWord(word) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            const ptr0 = passStringToWasm0(word, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+            const len0 = WASM_VECTOR_LEN;
+            wasm.searchoptions_setFirstWord(retptr, this.__wbg_ptr, ptr0, len0);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            if (r1) {
+                throw takeObject(r0);
+            }
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Only admits solutions in which every word has at least `min_word_len` letters.
+     * @param {number} min_word_len
+     */
+    setMinWordLength(min_word_len) {
+        wasm.searchoptions_setMinWordLength(this.__wbg_ptr, min_word_len);
+    }
+    /**
+     * Only admits solutions with at least `min_word_count` and at most `max_word_count` words.
+     * @param {number} min_word_count
+     * @param {number} max_word_count
+     */
+    setWordCountRange(min_word_count, max_word_count) {
+        wasm.searchoptions_setWordCountRange(this.__wbg_ptr, min_word_count, max_word_count);
+    }
+}
+if (Symbol.dispose) SearchOptions.prototype[Symbol.dispose] = SearchOptions.prototype.free;
+
+/**
+ * A structure holding serialized words along with the total word count.
+ *
+ * The words are serialized in the compact format defined by [`solver::wire`],
+ * which holds only the packed letters of each word.
+ */
+export class SerializedSequences {
+    static __wrap(ptr) {
+        const obj = Object.create(SerializedSequences.prototype);
+        obj.__wbg_ptr = ptr;
+        SerializedSequencesFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SerializedSequencesFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_serializedsequences_free(ptr, 0);
+    }
+    /**
+     * Takes and returns the serialized list of valid words, leaving an empty list in its place.
+     *
+     * The buffer is moved out rather than cloned, so the resulting `Uint8Array` owns the only copy
+     * outside of WASM memory, and its underlying `ArrayBuffer` may be transferred between workers.
+     * Calling this again returns an empty list.
+     * @returns {Uint8Array}
+     */
+    takeSerializedWords() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.serializedsequences_takeSerializedWords(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU8FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 1, 1);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Returns the number of words in the serialized word list.
+     * @returns {number}
+     */
+    get wordCount() {
+        const ret = wasm.serializedsequences_wordCount(this.__wbg_ptr);
+        return ret >>> 0;
+    }
+}
+if (Symbol.dispose) SerializedSequences.prototype[Symbol.dispose] = SerializedSequences.prototype.free;
+
+/**
+ * A payload to hold a [`SolutionPaths`]: each distinct path through the letters, the count of
+ * its splits into words, and every split as a solution string, e.g. `"IMPART TED DUNKS"`.
+ *
+ * The splits of every path are flattened into a single list, in which the splits of each path
+ * follow those of the path before it. The payloads for separate ranges of starting words can be
+ * combined by merging the splits of equal paths.
+ */
+export class SolutionPathsPayload {
+    static __wrap(ptr) {
+        const obj = Object.create(SolutionPathsPayload.prototype);
+        obj.__wbg_ptr = ptr;
+        SolutionPathsPayloadFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SolutionPathsPayloadFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_solutionpathspayload_free(ptr, 0);
+    }
+    /**
+     * Takes and returns the letters of each distinct path, e.g. `"IMPARTEDUNKS"`,
+     * clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get paths() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionpathspayload_paths(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns the count of splits of each of the [`paths`](Self::take_paths),
+     * clearing them from the internal list.
+     * @returns {Uint32Array}
+     */
+    get splitCounts() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionpathspayload_splitCounts(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayU32FromWasm0(r0, r1).slice();
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns every split of every path as a solution string, from the fewest words to
+     * the most within each path, clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get splits() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionpathspayload_splits(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+if (Symbol.dispose) SolutionPathsPayload.prototype[Symbol.dispose] = SolutionPathsPayload.prototype.free;
+
+/**
+ * A resumable search over the registered valid words in an index range, which yields
+ * its solutions in batches so that the caller may report progress or cancel between them.
+ *
+ * The stream keeps its own copy of the words that the registered search options admit when it
+ * is created, so later batches are unaffected by registering other words or options.
+ */
+export class SolutionStream {
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SolutionStreamFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_solutionstream_free(ptr, 0);
+    }
+    /**
+     * Cancels the stream, so that every later call to [`next_batch`](Self::next_batch)
+     * returns `undefined` without searching.
+     */
+    cancel() {
+        wasm.solutionstream_cancel(this.__wbg_ptr);
+    }
+    /**
+     * Returns `true` once every starting word has been searched, or the stream is cancelled.
+     * @returns {boolean}
+     */
+    get isDone() {
+        const ret = wasm.solutionstream_isDone(this.__wbg_ptr);
+        return ret !== 0;
+    }
+    /**
+     * Creates a stream of solutions that start with the registered valid words in the specified
+     * index range. Each batch holds the solutions from as many starting words as it takes to reach
+     * at least `batch_size` solutions, or the rest of the range, whichever comes first.
+     *
+     * # Errors
+     *
+     * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+     * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+     * @param {number} range_start
+     * @param {number} range_end
+     * @param {number} batch_size
+     */
+    constructor(range_start, range_end, batch_size) {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionstream_new(retptr, range_start, range_end, batch_size);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+            if (r2) {
+                throw takeObject(r1);
+            }
+            this.__wbg_ptr = r0;
+            SolutionStreamFinalization.register(this, this.__wbg_ptr, this);
+            return this;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Searches the next starting words in the range, returning the next batch of solutions,
+     * or `undefined` once every starting word has been searched or the stream is cancelled.
+     * @returns {SolutionsPayload | undefined}
+     */
+    nextBatch() {
+        const ret = wasm.solutionstream_nextBatch(this.__wbg_ptr);
+        return ret === 0 ? undefined : SolutionsPayload.__wrap(ret);
+    }
+    /**
+     * Returns the fraction of the starting words in the range that have been searched, from `0`
+     * to `1`, counting only those that the search options admit. A range that has no such words
+     * is reported as fully searched.
+     * @returns {number}
+     */
+    get progress() {
+        const ret = wasm.solutionstream_progress(this.__wbg_ptr);
+        return ret;
+    }
+}
+if (Symbol.dispose) SolutionStream.prototype[Symbol.dispose] = SolutionStream.prototype.free;
+
+/**
+ * A payload to hold solution strings grouped by how many words are in the solution.
+ * There must be at least 1 word in a solution, and there can be at most 5 words.
+ */
+export class SolutionsPayload {
+    static __wrap(ptr) {
+        const obj = Object.create(SolutionsPayload.prototype);
+        obj.__wbg_ptr = ptr;
+        SolutionsPayloadFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        SolutionsPayloadFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_solutionspayload_free(ptr, 0);
+    }
+    /**
+     * Takes and returns all five-word solutions, clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get fiveWordSolutions() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionspayload_fiveWordSolutions(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns all four-word solutions, clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get fourWordSolutions() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionspayload_fourWordSolutions(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns all one-word solutions, clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get oneWordSolutions() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionspayload_oneWordSolutions(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Adds a [`LetterSequence`] solution to the relevant bucket based on the word count.
+     * @param {LetterSequence} sequence
+     */
+    push(sequence) {
+        _assertClass(sequence, LetterSequence);
+        var ptr0 = sequence.__destroy_into_raw();
+        wasm.solutionspayload_push(this.__wbg_ptr, ptr0);
+    }
+    /**
+     * Takes and returns all three-word solutions, clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get threeWordSolutions() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionspayload_threeWordSolutions(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+    /**
+     * Takes and returns all two-word solutions, clearing them from the internal list.
+     * @returns {string[]}
+     */
+    get twoWordSolutions() {
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.solutionspayload_twoWordSolutions(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            var v1 = getArrayJsValueFromWasm0(r0, r1);
+            wasm.__wbindgen_export(r0, r1 * 4, 4);
+            return v1;
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+        }
+    }
+}
+if (Symbol.dispose) SolutionsPayload.prototype[Symbol.dispose] = SolutionsPayload.prototype.free;
+
+/**
+ * The error that is thrown by every fallible exported function, which carries its
+ * [`kind`](Self::kind) apart from its message, so that JavaScript can switch on it.
+ */
+export class WasmError {
+    static __wrap(ptr) {
+        const obj = Object.create(WasmError.prototype);
+        obj.__wbg_ptr = ptr;
+        WasmErrorFinalization.register(obj, obj.__wbg_ptr, obj);
+        return obj;
+    }
+    __destroy_into_raw() {
+        const ptr = this.__wbg_ptr;
+        this.__wbg_ptr = 0;
+        WasmErrorFinalization.unregister(this);
+        return ptr;
+    }
+    free() {
+        const ptr = this.__destroy_into_raw();
+        wasm.__wbg_wasmerror_free(ptr, 0);
+    }
+    /**
+     * Returns the kind of the error, e.g. `"RangeOutOfBounds"`.
+     * @returns {WasmErrorKind}
+     */
+    get kind() {
+        const ret = wasm.wasmerror_kind(this.__wbg_ptr);
+        return __wbindgen_enum_WasmErrorKind[ret];
+    }
+    /**
+     * Returns a description of the error, which does not include its kind.
+     * @returns {string}
+     */
+    get message() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.wasmerror_message(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export(deferred1_0, deferred1_1, 1);
+        }
+    }
+    /**
+     * Returns the kind of the error followed by its message, e.g.
+     * `"NoWordsRegistered: No valid words have been registered."`.
+     * @returns {string}
+     */
+    toString() {
+        let deferred1_0;
+        let deferred1_1;
+        try {
+            const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+            wasm.wasmerror_toString(retptr, this.__wbg_ptr);
+            var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+            var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+            deferred1_0 = r0;
+            deferred1_1 = r1;
+            return getStringFromWasm0(r0, r1);
+        } finally {
+            wasm.__wbindgen_add_to_stack_pointer(16);
+            wasm.__wbindgen_export(deferred1_0, deferred1_1, 1);
+        }
+    }
+}
+if (Symbol.dispose) WasmError.prototype[Symbol.dispose] = WasmError.prototype.free;
+
+/**
+ * Clears the registered search options, so that every later search admits every solution.
+ */
+export function clearSearchOptions() {
+    wasm.clearSearchOptions();
+}
+
+/**
+ * Clears the currently registered valid words from thread-local storage.
+ */
+export function clearValidWords() {
+    wasm.clearValidWords();
+}
+
+/**
+ * Divides the indices of the registered valid words into `range_count` contiguous ranges
+ * of roughly equal estimated cost, returning the `range_count + 1` boundaries between them.
+ *
+ * The range at position `n` runs from `boundaries[n]` (inclusive) to `boundaries[n + 1]` (exclusive).
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if `range_count` is zero, or a `NoWordsRegistered`
+ * error if no valid words have been registered.
+ * @param {number} range_count
+ * @returns {Uint32Array}
+ */
+export function costBalancedRanges(range_count) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.costBalancedRanges(retptr, range_count);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v1 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export(r0, r1 * 4, 4);
+        return v1;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+ * Estimates the cost of searching for every solution that starts with each of the
+ * registered valid words, in order. Costs that do not fit in a [`u32`] are saturated.
+ *
+ * # Errors
+ *
+ * Throws a `NoWordsRegistered` error if no valid words have been registered.
+ * @returns {Uint32Array}
+ */
+export function estimateCosts() {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.estimateCosts(retptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v1 = getArrayU32FromWasm0(r0, r1).slice();
+        wasm.__wbindgen_export(r0, r1 * 4, 4);
+        return v1;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 
-            } catch (e) {
-                if (module.headers.get('Content-Type') != 'application/wasm') {
-                    console.warn("`WebAssembly.instantiateStreaming` failed because your server does not serve Wasm with `application/wasm` MIME type. Falling back to `WebAssembly.instantiate` which is slower. Original error:\n", e);
+/**
+ * Gathers valid words for a given 12-letter input, returning them in serialized form.
+ *
+ * The letters that may follow one another are decided by the `rule`, which defaults to the
+ * standard rule, and by the `forbiddenPairs`, if any, in the format that is accepted by
+ * [`Adjacency::with_forbidden_pairs`], e.g. `"QU, TH"`.
+ *
+ * # Errors
+ *
+ * Throws an `InvalidBoard` error if the input is not 12 distinct uppercase letters, an
+ * `InvalidRule` error if the rule is not known, or an `InvalidWord` error if the forbidden
+ * pairs cannot be parsed.
+ * @param {string} input
+ * @param {WasmAdjacencyRule | null} [rule]
+ * @param {string | null} [forbiddenPairs]
+ * @returns {SerializedSequences}
+ */
+export function getValidWords(input, rule, forbiddenPairs) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(input, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(forbiddenPairs) ? 0 : passStringToWasm0(forbiddenPairs, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.getValidWords(retptr, ptr0, len0, isLikeNone(rule) ? 4 : ((__wbindgen_enum_WasmAdjacencyRule.indexOf(rule) + 1 || 4) - 1), ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return SerializedSequences.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 
-                } else {
-                    throw e;
-                }
-            }
+/**
+ * Finds the chains that come closest to solving a 12-letter input, for a board that has no
+ * unique-letter solution. See [`solver::partial::best_partial_chains`].
+ *
+ * The valid words are gathered under the `rule` and `forbiddenPairs` in the same way as
+ * `getValidWords`.
+ *
+ * # Errors
+ *
+ * Throws the same errors as `getValidWords`.
+ * @param {string} input
+ * @param {WasmAdjacencyRule | null} [rule]
+ * @param {string | null} [forbiddenPairs]
+ * @returns {PartialChainsPayload}
+ */
+export function partialChains(input, rule, forbiddenPairs) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passStringToWasm0(input, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        const len0 = WASM_VECTOR_LEN;
+        var ptr1 = isLikeNone(forbiddenPairs) ? 0 : passStringToWasm0(forbiddenPairs, wasm.__wbindgen_export2, wasm.__wbindgen_export3);
+        var len1 = WASM_VECTOR_LEN;
+        wasm.partialChains(retptr, ptr0, len0, isLikeNone(rule) ? 4 : ((__wbindgen_enum_WasmAdjacencyRule.indexOf(rule) + 1 || 4) - 1), ptr1, len1);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
         }
+        return PartialChainsPayload.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 
-        const bytes = await module.arrayBuffer();
-        return await WebAssembly.instantiate(bytes, imports);
+/**
+ * Tallies how the words and letters take part in the solutions that start with the registered
+ * valid words in the specified index range, narrowed down by the registered search options.
+ * See [`solver::participation`].
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+ * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+ * @param {number} range_start
+ * @param {number} range_end
+ * @returns {ParticipationPayload}
+ */
+export function participation(range_start, range_end) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.participation(retptr, range_start, range_end);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return ParticipationPayload.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 
-    } else {
-        const instance = await WebAssembly.instantiate(module, imports);
+/**
+ * Deserializes and stores valid words in thread-local storage for later use.
+ * Solutions are generated in chunks, so this vector is reused multiple times.
+ *
+ * # Errors
+ *
+ * Throws a `CorruptedBuffer` error if the serialized words cannot be deserialized,
+ * in which case any previously registered words are left in place.
+ * @param {Uint8Array} serialized_words
+ */
+export function registerValidWords(serialized_words) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        const ptr0 = passArray8ToWasm0(serialized_words, wasm.__wbindgen_export2);
+        const len0 = WASM_VECTOR_LEN;
+        wasm.registerValidWords(retptr, ptr0, len0);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        if (r1) {
+            throw takeObject(r0);
+        }
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 
-        if (instance instanceof WebAssembly.Instance) {
-            return { instance, module };
+/**
+ * Registers the search options that narrow down the solutions of every later search on this
+ * thread, replacing any options that were registered before.
+ * @param {SearchOptions} options
+ */
+export function setSearchOptions(options) {
+    _assertClass(options, SearchOptions);
+    wasm.setSearchOptions(options.__wbg_ptr);
+}
 
-        } else {
-            return instance;
+/**
+ * Groups the solutions that start with the registered valid words in the specified index range
+ * by their path through the letters, narrowed down by the registered search options.
+ * See [`solver::paths`].
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+ * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+ * @param {number} range_start
+ * @param {number} range_end
+ * @returns {SolutionPathsPayload}
+ */
+export function solutionPaths(range_start, range_end) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.solutionPaths(retptr, range_start, range_end);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
+        }
+        return SolutionPathsPayload.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
+
+/**
+ * Generates puzzle solutions for valid words in the specified index range, narrowed down by
+ * the registered search options.
+ *
+ * # Errors
+ *
+ * Throws a `RangeOutOfBounds` error if the range is reversed or extends past the registered
+ * valid words, or a `NoWordsRegistered` error if no valid words have been registered.
+ * @param {number} range_start
+ * @param {number} range_end
+ * @returns {SolutionsPayload}
+ */
+export function solutions(range_start, range_end) {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.solutions(retptr, range_start, range_end);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        if (r2) {
+            throw takeObject(r1);
         }
+        return SolutionsPayload.__wrap(r0);
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
     }
 }
 
+/**
+ * Returns the first word of the family of inflections of each of the registered valid words,
+ * in order, e.g. `"ABDUCT"` for `"ABDUCTS"`. Two solutions differ only by inflection if their words
+ * have the same family heads in the same order. See [`solver::families`].
+ *
+ * # Errors
+ *
+ * Throws a `NoWordsRegistered` error if no valid words have been registered.
+ * @returns {string[]}
+ */
+export function wordFamilyHeads() {
+    try {
+        const retptr = wasm.__wbindgen_add_to_stack_pointer(-16);
+        wasm.wordFamilyHeads(retptr);
+        var r0 = getDataViewMemory0().getInt32(retptr + 4 * 0, true);
+        var r1 = getDataViewMemory0().getInt32(retptr + 4 * 1, true);
+        var r2 = getDataViewMemory0().getInt32(retptr + 4 * 2, true);
+        var r3 = getDataViewMemory0().getInt32(retptr + 4 * 3, true);
+        if (r3) {
+            throw takeObject(r2);
+        }
+        var v1 = getArrayJsValueFromWasm0(r0, r1);
+        wasm.__wbindgen_export(r0, r1 * 4, 4);
+        return v1;
+    } finally {
+        wasm.__wbindgen_add_to_stack_pointer(16);
+    }
+}
 function __wbg_get_imports() {
-    const imports = {};
-    imports.wbg = {};
-    imports.wbg.__wbindgen_string_new = function(arg0, arg1) {
-        const ret = getStringFromWasm0(arg0, arg1);
-        return addHeapObject(ret);
+    const import0 = {
+        __proto__: null,
+        __wbg___wbindgen_throw_41e9ee4f547fc59a: function(arg0, arg1) {
+            throw new Error(getStringFromWasm0(arg0, arg1));
+        },
+        __wbg_wasmerror_new: function(arg0) {
+            const ret = WasmError.__wrap(arg0);
+            return addHeapObject(ret);
+        },
+        __wbindgen_generic_0000000000000001: function(arg0, arg1) {
+            // Cast intrinsic for `Ref(String) -> Externref`.
+            const ret = getStringFromWasm0(arg0, arg1);
+            return addHeapObject(ret);
+        },
     };
-    imports.wbg.__wbindgen_throw = function(arg0, arg1) {
-        throw new Error(getStringFromWasm0(arg0, arg1));
+    return {
+        __proto__: null,
+        "./letrboxd_bg.js": import0,
     };
+}
+
+const __wbindgen_enum_WasmAdjacencyRule = ["standard", "easy", "clockwise"];
+
+
+const __wbindgen_enum_WasmErrorKind = ["InvalidBoard", "InvalidWord", "InvalidRule", "CorruptedBuffer", "RangeOutOfBounds", "NoWordsRegistered"];
+const LetterSequenceFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_lettersequence_free(ptr, 1));
+const PartialChainsPayloadFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_partialchainspayload_free(ptr, 1));
+const ParticipationPayloadFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_participationpayload_free(ptr, 1));
+const SearchOptionsFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_searchoptions_free(ptr, 1));
+const SerializedSequencesFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_serializedsequences_free(ptr, 1));
+const SolutionPathsPayloadFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_solutionpathspayload_free(ptr, 1));
+const SolutionStreamFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_solutionstream_free(ptr, 1));
+const SolutionsPayloadFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_solutionspayload_free(ptr, 1));
+const WasmErrorFinalization = (typeof FinalizationRegistry === 'undefined')
+    ? { register: () => {}, unregister: () => {} }
+    : new FinalizationRegistry(ptr => wasm.__wbg_wasmerror_free(ptr, 1));
+
+function addHeapObject(obj) {
+    if (heap_next === heap.length) heap.push(heap.length + 1);
+    const idx = heap_next;
+    heap_next = heap[idx];
+
+    heap[idx] = obj;
+    return idx;
+}
+
+function _assertClass(instance, klass) {
+    if (!(instance instanceof klass)) {
+        throw new Error(`expected instance of ${klass.name}`);
+    }
+}
+
+function dropObject(idx) {
+    if (idx < 1028) return;
+    heap[idx] = heap_next;
+    heap_next = idx;
+}
+
+function getArrayJsValueFromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    const mem = getDataViewMemory0();
+    const result = [];
+    for (let i = ptr; i < ptr + 4 * len; i += 4) {
+        result.push(takeObject(mem.getUint32(i, true)));
+    }
+    return result;
+}
+
+function getArrayU32FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint32ArrayMemory0().subarray(ptr / 4, ptr / 4 + len);
+}
+
+function getArrayU8FromWasm0(ptr, len) {
+    ptr = ptr >>> 0;
+    return getUint8ArrayMemory0().subarray(ptr / 1, ptr / 1 + len);
+}
+
+let cachedDataViewMemory0 = null;
+function getDataViewMemory0() {
+    if (cachedDataViewMemory0 === null || cachedDataViewMemory0.buffer.detached === true || (cachedDataViewMemory0.buffer.detached === undefined && cachedDataViewMemory0.buffer !== wasm.memory.buffer)) {
+        cachedDataViewMemory0 = new DataView(wasm.memory.buffer);
+    }
+    return cachedDataViewMemory0;
+}
+
+function getStringFromWasm0(ptr, len) {
+    return decodeText(ptr >>> 0, len);
+}
+
+let cachedUint32ArrayMemory0 = null;
+function getUint32ArrayMemory0() {
+    if (cachedUint32ArrayMemory0 === null || cachedUint32ArrayMemory0.byteLength === 0) {
+        cachedUint32ArrayMemory0 = new Uint32Array(wasm.memory.buffer);
+    }
+    return cachedUint32ArrayMemory0;
+}
+
+let cachedUint8ArrayMemory0 = null;
+function getUint8ArrayMemory0() {
+    if (cachedUint8ArrayMemory0 === null || cachedUint8ArrayMemory0.byteLength === 0) {
+        cachedUint8ArrayMemory0 = new Uint8Array(wasm.memory.buffer);
+    }
+    return cachedUint8ArrayMemory0;
+}
+
+function getObject(idx) { return heap[idx]; }
+
+let heap = new Array(1024).fill(undefined);
+heap.push(undefined, null, true, false);
+
+let heap_next = heap.length;
+
+function isLikeNone(x) {
+    return x === undefined || x === null;
+}
+
+function passArray8ToWasm0(arg, malloc) {
+    const ptr = malloc(arg.length * 1, 1) >>> 0;
+    getUint8ArrayMemory0().set(arg, ptr / 1);
+    WASM_VECTOR_LEN = arg.length;
+    return ptr;
+}
+
+function passStringToWasm0(arg, malloc, realloc) {
+    if (realloc === undefined) {
+        const buf = cachedTextEncoder.encode(arg);
+        const ptr = malloc(buf.length, 1) >>> 0;
+        getUint8ArrayMemory0().subarray(ptr, ptr + buf.length).set(buf);
+        WASM_VECTOR_LEN = buf.length;
+        return ptr;
+    }
+
+    let len = arg.length;
+    let ptr = malloc(len, 1) >>> 0;
+
+    const mem = getUint8ArrayMemory0();
+
+    let offset = 0;
+
+    for (; offset < len; offset++) {
+        const code = arg.charCodeAt(offset);
+        if (code > 0x7F) break;
+        mem[ptr + offset] = code;
+    }
+    if (offset !== len) {
+        if (offset !== 0) {
+            arg = arg.slice(offset);
+        }
+        ptr = realloc(ptr, len, len = offset + arg.length * 3, 1) >>> 0;
+        const view = getUint8ArrayMemory0().subarray(ptr + offset, ptr + len);
+        const ret = cachedTextEncoder.encodeInto(arg, view);
+
+        offset += ret.written;
+        ptr = realloc(ptr, len, offset, 1) >>> 0;
+    }
+
+    WASM_VECTOR_LEN = offset;
+    return ptr;
+}
+
+function takeObject(idx) {
+    const ret = getObject(idx);
+    dropObject(idx);
+    return ret;
+}
 
-    return imports;
+let cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+cachedTextDecoder.decode();
+const MAX_SAFARI_DECODE_BYTES = 2146435072;
+let numBytesDecoded = 0;
+function decodeText(ptr, len) {
+    numBytesDecoded += len;
+    if (numBytesDecoded >= MAX_SAFARI_DECODE_BYTES) {
+        cachedTextDecoder = new TextDecoder('utf-8', { ignoreBOM: true, fatal: true });
+        cachedTextDecoder.decode();
+        numBytesDecoded = len;
+    }
+    return cachedTextDecoder.decode(getUint8ArrayMemory0().subarray(ptr, ptr + len));
 }
 
-function __wbg_init_memory(imports, memory) {
+const cachedTextEncoder = new TextEncoder();
 
+if (!('encodeInto' in cachedTextEncoder)) {
+    cachedTextEncoder.encodeInto = function (arg, view) {
+        const buf = cachedTextEncoder.encode(arg);
+        view.set(buf);
+        return {
+            read: arg.length,
+            written: buf.length
+        };
+    };
 }
 
+let WASM_VECTOR_LEN = 0;
+
+let wasmModule, wasmInstance, wasm;
 function __wbg_finalize_init(instance, module) {
+    wasmInstance = instance;
     wasm = instance.exports;
-    __wbg_init.__wbindgen_wasm_module = module;
+    wasmModule = module;
     cachedDataViewMemory0 = null;
+    cachedUint32ArrayMemory0 = null;
     cachedUint8ArrayMemory0 = null;
+    return wasm;
+}
 
+async function __wbg_load(module, imports) {
+    if (typeof Response === 'function' && module instanceof Response) {
+        if (!module.ok) {
+            throw new Error(`failed to fetch Wasm: ${module.status} ${module.statusText} fetching '${module.url}'`);
+        }
 
+        if (typeof WebAssembly.instantiateStreaming === 'function') {
+            try {
+                return await WebAssembly.instantiateStreaming(module, imports);
+            } catch (e) {
+                const validResponse = expectedResponseType(module.type);
 
-    return wasm;
+                if (validResponse && module.headers.get('Content-Type') !== 'application/wasm') {
+                    console.warn("`WebAssembly.instantiateStreaming` failed because your server does not serve Wasm with `application/wasm` MIME type. Falling back to `WebAssembly.instantiate` which is slower. Original error:\n", e);
+
+                } else { throw e; }
+            }
+        }
+
+        const bytes = await module.arrayBuffer();
+        return await WebAssembly.instantiate(bytes, imports);
+    } else {
+        const instance = await WebAssembly.instantiate(module, imports);
+
+        if (instance instanceof WebAssembly.Instance) {
+            return { instance, module };
+        } else {
+            return instance;
+        }
+    }
+
+    function expectedResponseType(type) {
+        switch (type) {
+            case 'basic': case 'cors': case 'default': return true;
+        }
+        return false;
+    }
 }
 
 function initSync(module) {
     if (wasm !== undefined) return wasm;
 
 
-    if (typeof module !== 'undefined') {
+    if (module !== undefined) {
         if (Object.getPrototypeOf(module) === Object.prototype) {
             ({module} = module)
         } else {
@@ -528,15 +1441,10 @@ function initSync(module) {
     }
 
     const imports = __wbg_get_imports();
-
-    __wbg_init_memory(imports);
-
     if (!(module instanceof WebAssembly.Module)) {
         module = new WebAssembly.Module(module);
     }
-
     const instance = new WebAssembly.Instance(module, imports);
-
     return __wbg_finalize_init(instance, module);
 }
 
@@ -544,7 +1452,7 @@ async function __wbg_init(module_or_path) {
     if (wasm !== undefined) return wasm;
 
 
-    if (typeof module_or_path !== 'undefined') {
+    if (module_or_path !== undefined) {
         if (Object.getPrototypeOf(module_or_path) === Object.prototype) {
             ({module_or_path} = module_or_path)
         } else {
@@ -552,7 +1460,7 @@ async function __wbg_init(module_or_path) {
         }
     }
 
-    if (typeof module_or_path === 'undefined') {
+    if (module_or_path === undefined) {
         module_or_path = new URL('letrboxd_bg.wasm', import.meta.url);
     }
     const imports = __wbg_get_imports();
@@ -561,12 +1469,9 @@ async function __wbg_init(module_or_path) {
         module_or_path = fetch(module_or_path);
     }
 
-    __wbg_init_memory(imports);
-
     const { instance, module } = await __wbg_load(await module_or_path, imports);
 
     return __wbg_finalize_init(instance, module);
 }
 
-export { initSync };
-export default __wbg_init;
+export { initSync, __wbg_init as default };
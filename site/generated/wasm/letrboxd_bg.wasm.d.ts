@@ -1,22 +1,64 @@
 /* tslint:disable */
 /* eslint-disable */
 export const memory: WebAssembly.Memory;
+export const __wbg_lettersequence_free: (a: number, b: number) => void;
+export const __wbg_partialchainspayload_free: (a: number, b: number) => void;
+export const __wbg_participationpayload_free: (a: number, b: number) => void;
+export const __wbg_searchoptions_free: (a: number, b: number) => void;
 export const __wbg_serializedsequences_free: (a: number, b: number) => void;
-export const serializedsequences_wordCount: (a: number) => number;
-export const serializedsequences_serializedWords: (a: number, b: number) => void;
+export const __wbg_solutionpathspayload_free: (a: number, b: number) => void;
 export const __wbg_solutionspayload_free: (a: number, b: number) => void;
-export const solutionspayload_push: (a: number, b: number) => void;
+export const __wbg_solutionstream_free: (a: number, b: number) => void;
+export const __wbg_wasmerror_free: (a: number, b: number) => void;
+export const clearSearchOptions: () => void;
+export const clearValidWords: () => void;
+export const costBalancedRanges: (a: number, b: number) => void;
+export const estimateCosts: (a: number) => void;
+export const getValidWords: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const partialChains: (a: number, b: number, c: number, d: number, e: number, f: number) => void;
+export const partialchainspayload_chains: (a: number, b: number) => void;
+export const partialchainspayload_missingLetters: (a: number, b: number) => void;
+export const participation: (a: number, b: number, c: number) => void;
+export const participationpayload_firstWordSolutionCounts: (a: number, b: number) => void;
+export const participationpayload_firstWords: (a: number, b: number) => void;
+export const participationpayload_junctionLetterSolutionCounts: (a: number, b: number) => void;
+export const participationpayload_junctionLetters: (a: number, b: number) => void;
+export const participationpayload_wordLengthSolutionCounts: (a: number, b: number) => void;
+export const participationpayload_wordLengths: (a: number, b: number) => void;
+export const participationpayload_wordSolutionCounts: (a: number, b: number) => void;
+export const participationpayload_words: (a: number, b: number) => void;
+export const registerValidWords: (a: number, b: number, c: number) => void;
+export const searchoptions_excludeFirstLetters: (a: number, b: number, c: number, d: number) => void;
+export const searchoptions_excludeWord: (a: number, b: number, c: number, d: number) => void;
+export const searchoptions_new: () => number;
+export const searchoptions_requireWord: (a: number, b: number, c: number, d: number) => void;
+export const searchoptions_setFirstWord: (a: number, b: number, c: number, d: number) => void;
+export const searchoptions_setMinWordLength: (a: number, b: number) => void;
+export const searchoptions_setWordCountRange: (a: number, b: number, c: number) => void;
+export const serializedsequences_takeSerializedWords: (a: number, b: number) => void;
+export const serializedsequences_wordCount: (a: number) => number;
+export const setSearchOptions: (a: number) => void;
+export const solutionPaths: (a: number, b: number, c: number) => void;
+export const solutionpathspayload_paths: (a: number, b: number) => void;
+export const solutionpathspayload_splitCounts: (a: number, b: number) => void;
+export const solutionpathspayload_splits: (a: number, b: number) => void;
+export const solutions: (a: number, b: number, c: number) => void;
+export const solutionspayload_fiveWordSolutions: (a: number, b: number) => void;
+export const solutionspayload_fourWordSolutions: (a: number, b: number) => void;
 export const solutionspayload_oneWordSolutions: (a: number, b: number) => void;
-export const solutionspayload_twoWordSolutions: (a: number, b: number) => void;
+export const solutionspayload_push: (a: number, b: number) => void;
 export const solutionspayload_threeWordSolutions: (a: number, b: number) => void;
-export const solutionspayload_fourWordSolutions: (a: number, b: number) => void;
-export const solutionspayload_fiveWordSolutions: (a: number, b: number) => void;
-export const getValidWords: (a: number, b: number) => number;
-export const registerValidWords: (a: number, b: number) => void;
-export const clearValidWords: () => void;
-export const solutions: (a: number, b: number) => number;
-export const __wbg_lettersequence_free: (a: number, b: number) => void;
+export const solutionspayload_twoWordSolutions: (a: number, b: number) => void;
+export const solutionstream_cancel: (a: number) => void;
+export const solutionstream_isDone: (a: number) => number;
+export const solutionstream_new: (a: number, b: number, c: number, d: number) => void;
+export const solutionstream_nextBatch: (a: number) => number;
+export const solutionstream_progress: (a: number) => number;
+export const wasmerror_kind: (a: number) => number;
+export const wasmerror_message: (a: number, b: number) => void;
+export const wasmerror_toString: (a: number, b: number) => void;
+export const wordFamilyHeads: (a: number) => void;
 export const __wbindgen_add_to_stack_pointer: (a: number) => number;
-export const __wbindgen_export_0: (a: number, b: number, c: number) => void;
-export const __wbindgen_export_1: (a: number, b: number) => number;
-export const __wbindgen_export_2: (a: number, b: number, c: number, d: number) => number;
+export const __wbindgen_export: (a: number, b: number, c: number) => void;
+export const __wbindgen_export2: (a: number, b: number) => number;
+export const __wbindgen_export3: (a: number, b: number, c: number, d: number) => number;
@@ -4,6 +4,7 @@ import { SolverPool } from "./solver-pool.mjs";
 
 /**
  * @typedef {import("./types/event-data").SolutionsUpdatedDetail} SolutionsUpdatedDetail
+ * @typedef {import("./types/event-data").PartialChainsUpdatedDetail} PartialChainsUpdatedDetail
  */
 
 /**
@@ -54,6 +55,34 @@ const solutionLists = document.querySelectorAll("solution-list");
  */
 const noSolutionsCard = document.getElementById("no-solutions-card");
 
+/**
+ * The text of the message displayed when there are no solutions.
+ *
+ * @type {HTMLElement}
+ */
+const noSolutionsCardText = document.getElementById("no-solutions-card-text");
+
+/**
+ * A list within the 'no solutions' message of the chains that come closest to solving the input.
+ *
+ * @type {HTMLUListElement}
+ */
+const partialChainsList = document.getElementById("partial-chains-list");
+
+/**
+ * The largest count of partial chains to list when an input has no solutions.
+ *
+ * @type {number}
+ */
+const MAX_PARTIAL_CHAINS_SHOWN = 10;
+
+/**
+ * The text of the 'no solutions' message before any partial chains are shown.
+ *
+ * @type {string}
+ */
+const NO_SOLUTIONS_TEXT = noSolutionsCardText.textContent.trim();
+
 /**
  * A message displayed to inform the user of which word list the solvers use.
  *
@@ -138,6 +167,7 @@ let activeRequestId = 0;
  */
 function clearSolutionLists(requestId) {
   noSolutionsCard.style.visibility = "hidden";
+  clearPartialChains();
   for (const solutionList of solutionLists) {
     solutionList.setSolutions([], requestId);
   }
@@ -278,6 +308,15 @@ async function maybeMatchInputToDate(requestId) {
   letrboxdInput.date = null;
 }
 
+/**
+ * Removes any partial chains from the 'no solutions' message.
+ */
+function clearPartialChains() {
+  noSolutionsCardText.textContent = NO_SOLUTIONS_TEXT;
+  partialChainsList.replaceChildren();
+  partialChainsList.hidden = true;
+}
+
 /**
  * Handles the "InputChanged" custom event.
  * Increments the activeRequestId to invalidate older requests,
@@ -288,6 +327,7 @@ document.addEventListener("InputChanged", async () => {
   const requestId = ++activeRequestId;
 
   setSolutionsHeaderCount(0);
+  clearPartialChains();
 
   await maybeMatchInputToDate(requestId).then(updateUrlState);
   if (requestId !== activeRequestId) {
@@ -327,6 +367,32 @@ document.addEventListener("SolutionsUpdated", (event) => {
   }
 });
 
+/**
+ * Handles the "PartialChainsUpdated" custom event.
+ * Lists the chains that come closest to solving an input with no solutions,
+ * and the letters that each one leaves uncovered, in the 'no solutions' message.
+ */
+document.addEventListener("PartialChainsUpdated", (event) => {
+  /** @type {PartialChainsUpdatedDetail} */
+  const { requestId, chains, missingLetters } = event.detail;
+  if (requestId !== activeRequestId || chains.length === 0) {
+    return;
+  }
+
+  const chainCount = numberFormat.format(chains.length);
+  noSolutionsCardText.textContent =
+    `No solution uses every letter exactly once. The closest of ${chainCount} partial chains are:`;
+
+  partialChainsList.replaceChildren(
+    ...chains.slice(0, MAX_PARTIAL_CHAINS_SHOWN).map((chain, index) => {
+      const item = document.createElement("li");
+      item.textContent = `${chain} (missing ${missingLetters[index].split("").join(", ")})`;
+      return item;
+    })
+  );
+  partialChainsList.hidden = false;
+});
+
 /**
  * Runs when the DOM is fully loaded. Sets up the initial URL-based puzzle input,
  * shows relevant messages, and adds a popstate listener to handle back/forward navigation.
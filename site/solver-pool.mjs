@@ -12,6 +12,14 @@ const WORKER_PATH = "./site/workers/solver.worker.mjs";
  */
 const WASM_BINARY_PATH = "./site/generated/wasm/letrboxd_bg.wasm";
 
+/**
+ * The minimum number of solutions that a worker gathers before posting them back to the pool.
+ * Smaller batches show results sooner and let stale requests stop sooner, at the cost of more messages.
+ *
+ * @type {number}
+ */
+const SOLUTION_BATCH_SIZE = 2000;
+
 /**
  * Import type definitions for messages exchanged with the solver workers.
  *
@@ -19,10 +27,14 @@ const WASM_BINARY_PATH = "./site/generated/wasm/letrboxd_bg.wasm";
  * @typedef {import("./types/message-data").WorkerMessage} WorkerMessage
  * @typedef {import("./types/message-data").InitializeWasmRequest} InitializeWasmRequest
  * @typedef {import("./types/message-data").InitializeWasmResponse} InitializeWasmResponse
+ * @typedef {import("./types/message-data").AdjacencyOptions} AdjacencyOptions
  * @typedef {import("./types/message-data").ValidWordsRequest} ValidWordsRequest
  * @typedef {import("./types/message-data").ValidWordsResponse} ValidWordsResponse
  * @typedef {import("./types/message-data").SolutionsRequest} SolutionsRequest
  * @typedef {import("./types/message-data").SolutionsResponse} SolutionsResponse
+ * @typedef {import("./types/message-data").PartialChainsRequest} PartialChainsRequest
+ * @typedef {import("./types/message-data").PartialChainsResponse} PartialChainsResponse
+ * @typedef {import("./types/message-data").SolverErrorResponse} SolverErrorResponse
  */
 
 /**
@@ -62,6 +74,21 @@ export class SolverPool {
    */
   #activeRequestId = null;
 
+  /**
+   * The puzzle input of the most recent request, which is needed to request partial chains
+   * if the input turns out to have no solutions.
+   *
+   * @type {string | null}
+   */
+  #activeInput = null;
+
+  /**
+   * The adjacency options of the most recent request, which are reused to request partial chains.
+   *
+   * @type {AdjacencyOptions}
+   */
+  #activeOptions = {};
+
   /**
    * The count of workers that have signaled a final payload for the current request.
    *
@@ -94,6 +121,8 @@ export class SolverPool {
       worker.ready = promise;
       worker.resolveReadyPromise = resolve;
       worker.hasPendingRequests = false;
+      worker.rangeLength = 0;
+      worker.progress = 0;
       worker.onmessage = event => this.#handleWorkerMessage(event, worker);
     });
 
@@ -105,20 +134,31 @@ export class SolverPool {
    *
    * @param {number} requestId - The unique ID assigned to this request.
    * @param {string} input - The puzzle text or letters from which to find valid solutions.
+   * @param {AdjacencyOptions} [options] - The rule and forbidden pairs that decide which letters
+   *   may follow one another, which default to the standard rule with no forbidden pairs.
    * @returns {Promise<void>}
    */
-  sendSolutionsRequest(requestId, input) {
+  sendSolutionsRequest(requestId, input, options = {}) {
     this.#activeRequestId = requestId;
+    this.#activeInput = input;
+    this.#activeOptions = options;
 
     // Reset any previously stored solutions
     this.#resetSolutions();
     this.#activeWorkerCount = this.#workers.length;
+    this.#workers.forEach(worker => {
+      worker.rangeLength = 0;
+      worker.progress = 0;
+    });
 
     /** @type {ValidWordsRequest} */
     const validWordsRequest = {
       type: "ValidWordsRequest",
       requestId,
       text: input,
+      solverCount: this.#workers.length,
+      rule: options.rule,
+      forbiddenPairs: options.forbiddenPairs,
     };
 
     const worker = this.#workers[requestId % this.#workers.length];
@@ -133,6 +173,42 @@ export class SolverPool {
     });
   }
 
+  /**
+   * Sends a request for the chains that come closest to solving the active input,
+   * which is made once every worker has finished and no solutions were found.
+   *
+   * @param {number} requestId - The ID of the request that found no solutions.
+   */
+  #sendPartialChainsRequest(requestId) {
+    /** @type {PartialChainsRequest} */
+    const partialChainsRequest = {
+      type: "PartialChainsRequest",
+      requestId,
+      text: this.#activeInput,
+      rule: this.#activeOptions.rule,
+      forbiddenPairs: this.#activeOptions.forbiddenPairs,
+    };
+
+    const worker = this.#workers[requestId % this.#workers.length];
+    worker.ready.then(() => {
+      if (requestId !== this.#activeRequestId) {
+        // This request is no longer relevant.
+        return;
+      }
+
+      worker.postMessage(partialChainsRequest);
+    });
+  }
+
+  /**
+   * Returns the total count of solutions gathered for the active request.
+   *
+   * @returns {number}
+   */
+  #solutionCount() {
+    return this.#solutions.reduce((count, solutions) => count + solutions.length, 0);
+  }
+
   /**
    * Clears the internal arrays that store solutions across different word counts.
    * This is useful for preparing the solver pool for a fresh request.
@@ -147,6 +223,23 @@ export class SolverPool {
     ];
   }
 
+  /**
+   * Returns the fraction of all starting words that the workers have searched for the active request,
+   * weighting each worker's progress by the length of its range.
+   *
+   * @returns {number}
+   */
+  #progress() {
+    let totalLength = 0;
+    let searchedLength = 0;
+    for (const worker of this.#workers) {
+      totalLength += worker.rangeLength;
+      searchedLength += worker.rangeLength * worker.progress;
+    }
+
+    return totalLength > 0 ? searchedLength / totalLength : 1;
+  }
+
   /**
    * Fetches the WASM binary, then broadcasts an "InitializeWasmRequest" message to each worker.
    * @returns {Promise<void>}
@@ -199,7 +292,8 @@ export class SolverPool {
        * Emitted by a worker in response to a "ValidWordsRequest" message,
        * indicating that the worker has loaded and serialized a list of valid words.
        *
-       * The pool then divides this list among all workers to begin solution generation.
+       * The pool then divides this list among all workers to begin solution generation,
+       * using the range boundaries that balance the estimated cost of each worker's range.
        *
        * @type {ValidWordsResponse}
        */
@@ -210,14 +304,10 @@ export class SolverPool {
           return;
         }
 
-        const { serializedWords, wordCount } = data;
+        const { serializedWords, rangeBoundaries } = data;
         const solverCount = this.#workers.length;
 
         // Divide the word list among all workers
-        let rangeStart = 0;
-        const sliceSize = Math.floor(wordCount / solverCount);
-        const remainder = wordCount % solverCount;
-
         for (let workerIndex = 0; workerIndex < solverCount; workerIndex++) {
           const worker = this.#workers[workerIndex];
           worker.ready.then(() => {
@@ -226,9 +316,10 @@ export class SolverPool {
               return;
             }
 
-            const additionalElement = workerIndex < remainder ? 1 : 0;
-            const rangeEnd = rangeStart + sliceSize + additionalElement;
+            const rangeStart = rangeBoundaries[workerIndex];
+            const rangeEnd = rangeBoundaries[workerIndex + 1];
             worker.hasPendingRequests = true;
+            worker.rangeLength = rangeEnd - rangeStart;
 
             /** @type {SolutionsRequest} */
             const solutionsRequest = {
@@ -237,10 +328,10 @@ export class SolverPool {
               serializedWords,
               rangeStart,
               rangeEnd,
+              batchSize: SOLUTION_BATCH_SIZE,
             };
 
             worker.postMessage(solutionsRequest);
-            rangeStart = rangeEnd;
           });
         }
 
@@ -260,6 +351,7 @@ export class SolverPool {
         const {
           requestId,
           isFinalResponse,
+          progress,
           oneWordSolutions,
           twoWordSolutions,
           threeWordSolutions,
@@ -277,6 +369,7 @@ export class SolverPool {
         this.#solutions[2].push(...threeWordSolutions);
         this.#solutions[3].push(...fourWordSolutions);
         this.#solutions[4].push(...fiveWordSolutions);
+        worker.progress = progress;
 
         if (isFinalResponse) {
           worker.hasPendingRequests = false;
@@ -291,10 +384,82 @@ export class SolverPool {
               requestId,
               solutions: this.#solutions,
               isFinalResponse: this.#activeWorkerCount == 0,
+              progress: this.#progress(),
             },
           })
         );
 
+        if (this.#activeWorkerCount == 0 && this.#solutionCount() == 0) {
+          this.#sendPartialChainsRequest(requestId);
+        }
+
+        break;
+      }
+
+      /**
+       * Emitted by a worker in response to a "PartialChainsRequest" message, with the chains
+       * that come closest to solving an input that has no solutions.
+       *
+       * Each "PartialChainsResponse" triggers a "PartialChainsUpdated" custom event,
+       * so that the UI can show the chains in place of an empty list.
+       *
+       * @type {PartialChainsResponse}
+       */
+      case "PartialChainsResponse": {
+        const { requestId, chains, missingLetters } = data;
+        if (requestId !== this.#activeRequestId) {
+          // This request is no longer relevant.
+          return;
+        }
+
+        document.dispatchEvent(
+          new CustomEvent("PartialChainsUpdated", {
+            detail: { requestId, chains, missingLetters },
+          })
+        );
+
+        break;
+      }
+
+      /**
+       * Emitted by a worker when the WASM module throws an error while handling a request.
+       *
+       * If the error occurred while gathering valid words, no solutions requests were sent,
+       * so the request is complete. Otherwise, only the failing worker's range is abandoned.
+       * Either way, a final "SolutionsUpdated" event is dispatched once no workers remain,
+       * so that the UI does not wait on solutions that will never arrive.
+       *
+       * @type {SolverErrorResponse}
+       */
+      case "SolverError": {
+        const { requestId, kind, message } = data;
+        if (requestId !== this.#activeRequestId) {
+          // This request is no longer relevant.
+          return;
+        }
+
+        console.error(`Solver failed with ${kind}:`, message);
+
+        if (worker.hasPendingRequests) {
+          worker.hasPendingRequests = false;
+          this.#activeWorkerCount -= 1;
+        } else {
+          this.#activeWorkerCount = 0;
+        }
+
+        if (this.#activeWorkerCount == 0) {
+          document.dispatchEvent(
+            new CustomEvent("SolutionsUpdated", {
+              detail: {
+                requestId,
+                solutions: this.#solutions,
+                isFinalResponse: true,
+                progress: this.#progress(),
+              },
+            })
+          );
+        }
+
         break;
       }
 
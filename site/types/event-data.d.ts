@@ -8,12 +8,23 @@
 export interface SolutionsUpdatedDetail {
   requestId: number;
   isFinalResponse: boolean;
+  progress: number;
   solutions: string[][];
 }
 
+/**
+ * Detail for the "PartialChainsUpdated" event.
+ */
+export interface PartialChainsUpdatedDetail {
+  requestId: number;
+  chains: string[];
+  missingLetters: string[];
+}
+
 // Extend the global DocumentEventMap for custom events
 declare global {
   interface DocumentEventMap {
     "SolutionsUpdated": CustomEvent<SolutionsUpdatedDetail>;
+    "PartialChainsUpdated": CustomEvent<PartialChainsUpdatedDetail>;
   }
 }
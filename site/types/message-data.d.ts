@@ -11,7 +11,10 @@ export type SolverWorkerMessage =
   | ValidWordsRequest
   | ValidWordsResponse
   | SolutionsRequest
-  | SolutionsResponse;
+  | SolutionsResponse
+  | PartialChainsRequest
+  | PartialChainsResponse
+  | SolverErrorResponse;
 
 /**
  * Union type for all SorterWorker messages.
@@ -35,13 +38,24 @@ export interface InitializeWasmResponse {
   type: "WasmInitialized";
 }
 
+/**
+ * The options that decide which letters may follow one another when gathering valid words.
+ */
+export interface AdjacencyOptions {
+  /** The rule for which letters may follow one another, which defaults to "standard". */
+  rule?: import("../generated/wasm/letrboxd").WasmAdjacencyRule;
+  /** Pairs of letters that may not follow one another, e.g. "QU, TH". */
+  forbiddenPairs?: string;
+}
+
 /**
  * Message for requesting valid words.
  */
-export interface ValidWordsRequest {
+export interface ValidWordsRequest extends AdjacencyOptions {
   type: "ValidWordsRequest";
   requestId: number;
   text: string;
+  solverCount: number;
 }
 
 /**
@@ -52,6 +66,7 @@ export interface ValidWordsResponse {
   requestId: number;
   serializedWords: Uint8Array<ArrayBufferLike>;
   wordCount: number;
+  rangeBoundaries: Uint32Array<ArrayBufferLike>;
 }
 
 /**
@@ -63,6 +78,7 @@ export interface SolutionsRequest {
   serializedWords: Uint8Array<ArrayBufferLike>;
   rangeStart: number;
   rangeEnd: number;
+  batchSize: number;
 }
 
 /**
@@ -72,6 +88,7 @@ export interface SolutionsResponse {
   type: "SolutionsResponse";
   requestId: number;
   isFinalResponse: boolean;
+  progress: number;
   oneWordSolutions: string[];
   twoWordSolutions: string[];
   threeWordSolutions: string[];
@@ -79,6 +96,44 @@ export interface SolutionsResponse {
   fiveWordSolutions: string[];
 }
 
+/**
+ * Message for requesting the closest partial chains for an input with no solutions.
+ */
+export interface PartialChainsRequest extends AdjacencyOptions {
+  type: "PartialChainsRequest";
+  requestId: number;
+  text: string;
+}
+
+/**
+ * Message for partial chains payload, where the chain at each index
+ * leaves the letters at the same index in `missingLetters` uncovered.
+ */
+export interface PartialChainsResponse {
+  type: "PartialChainsResponse";
+  requestId: number;
+  chains: string[];
+  missingLetters: string[];
+}
+
+/**
+ * The kinds of errors posted by a solver worker: the `kind` of a `WasmError` thrown by the
+ * WASM module's exported functions, or "Unexpected" for any other error.
+ */
+export type SolverErrorKind =
+  | import("../generated/wasm/letrboxd").WasmErrorKind
+  | "Unexpected";
+
+/**
+ * Message for an error thrown by the WASM module while handling a request.
+ */
+export interface SolverErrorResponse {
+  type: "SolverError";
+  requestId: number;
+  kind: SolverErrorKind;
+  message: string;
+}
+
 /**
  * Message for sorting solutions.
  */
@@ -2,4 +2,6 @@ export interface WasmWorker {
   ready: Promise<void>;
   resolveReadyPromise: () => void;
   hasPendingRequests: boolean;
+  rangeLength: number;
+  progress: number;
 }
\ No newline at end of file
@@ -8,6 +8,9 @@
  * @typedef {import("./../types/message-data").ValidWordsResponse} ValidWordsResponse
  * @typedef {import("./../types/message-data").SolutionsRequest} SolutionsRequest
  * @typedef {import("./../types/message-data").SolutionsResponse} SolutionsResponse
+ * @typedef {import("./../types/message-data").PartialChainsRequest} PartialChainsRequest
+ * @typedef {import("./../types/message-data").PartialChainsResponse} PartialChainsResponse
+ * @typedef {import("./../types/message-data").SolverErrorResponse} SolverErrorResponse
  * @typedef {import("./../types/message-data").SolverWorkerMessage} SolverWorkerMessage
  */
 
@@ -28,6 +31,37 @@ let wasmInitialized = false;
  */
 let activeRequestId = null;
 
+/**
+ * Posts a "SolverError" message to the main thread for an error thrown while handling a request.
+ * A `WasmError` thrown by the WASM module carries its own kind, and any other error is unexpected.
+ *
+ * @param {number} requestId - The ID of the request that failed.
+ * @param {unknown} error - The error that was thrown.
+ */
+function postSolverError(requestId, error) {
+  /** @type {SolverErrorResponse} */
+  let errorMessage;
+
+  if (error instanceof wasm.WasmError) {
+    errorMessage = {
+      type: "SolverError",
+      requestId,
+      kind: error.kind,
+      message: error.message,
+    };
+    error.free();
+  } else {
+    errorMessage = {
+      type: "SolverError",
+      requestId,
+      kind: "Unexpected",
+      message: String(error),
+    };
+  }
+
+  self.postMessage(errorMessage);
+}
+
 async function waitForTick() {
   const { promise, resolve } = Promise.withResolvers();
   setTimeout(resolve, 0);
@@ -75,7 +109,8 @@ self.onmessage = async (event) => {
     /**
      * Emitted by the main thread to request a list of valid words for a given input text.
      * The worker responds with a "ValidWordsResponse" message, containing a serialized
-     * list of valid words and a count of how many words are valid.
+     * list of valid words, a count of how many words are valid, and the boundaries of
+     * one range of starting words per solver, balanced by their estimated search cost.
      *
      * @type {ValidWordsRequest}
      */
@@ -85,10 +120,24 @@ self.onmessage = async (event) => {
         return;
       }
 
-      const { requestId, text } = data;
+      const { requestId, text, solverCount, rule, forbiddenPairs } = data;
       activeRequestId = requestId;
 
-      const { wordCount, serializedWords } = wasm.getValidWords(text);
+      let wordCount, serializedWords, rangeBoundaries;
+      try {
+        const validWords = wasm.getValidWords(text, rule, forbiddenPairs);
+        wordCount = validWords.wordCount;
+        serializedWords = validWords.takeSerializedWords();
+        validWords.free();
+
+        wasm.registerValidWords(serializedWords);
+        rangeBoundaries = wasm.costBalancedRanges(solverCount);
+      } catch (error) {
+        postSolverError(requestId, error);
+        return;
+      } finally {
+        wasm.clearValidWords();
+      }
 
       /** @type {ValidWordsResponse} */
       const payload = {
@@ -96,17 +145,22 @@ self.onmessage = async (event) => {
         requestId,
         wordCount,
         serializedWords,
+        rangeBoundaries,
       };
 
-      self.postMessage(payload, undefined, [serializedWords]);
+      // The serialized words are owned by this buffer alone, so it can be transferred without a copy.
+      self.postMessage(payload, [serializedWords.buffer]);
       break;
     }
 
     /**
      * Emitted by the main thread to request puzzle solutions for a particular range of words.
-     * The worker splits this range into sections, processing each subrange and posting
-     * "SolutionsResponse" messages back to the main thread. The final section is marked
-     * with `isFinalResponse: true`.
+     * The worker streams solutions from this range in batches of at least `batchSize` solutions,
+     * posting a "SolutionsResponse" message with the fraction of starting words searched after
+     * each batch. The final batch is marked with `isFinalResponse: true`.
+     *
+     * Between batches, the worker yields to the event loop and stops searching if a newer
+     * request has arrived in the meantime.
      *
      * @type {SolutionsRequest}
      */
@@ -116,59 +170,93 @@ self.onmessage = async (event) => {
         return;
       }
 
-      const { requestId, serializedWords, rangeStart, rangeEnd } = data;
+      const { requestId, serializedWords, rangeStart, rangeEnd, batchSize } = data;
 
       activeRequestId = requestId;
-      wasm.registerValidWords(serializedWords);
 
-      const range = rangeEnd - rangeStart;
-      if (range <= 0) {
-        console.error("Invalid range: rangeStart must be less than rangeEnd");
+      // The stream keeps its own copy of the words it searches, so they are cleared at once.
+      let stream;
+      try {
+        wasm.registerValidWords(serializedWords);
+        stream = new wasm.SolutionStream(rangeStart, rangeEnd, batchSize);
+      } catch (error) {
+        postSolverError(requestId, error);
         return;
+      } finally {
+        wasm.clearValidWords();
       }
 
-      const maxSections = 4;
-      const sections = Math.min(range, maxSections);
-      const baseSize = Math.floor(range / sections);
-      const extra = range % sections;
-
-      let start = rangeStart;
-      for (let index = 0; index < sections; index++) {
-        const size = baseSize + (index < extra ? 1 : 0);
-        const end = start + size;
+      while (true) {
+        const payload = stream.nextBatch();
 
-        const {
-          oneWordSolutions,
-          twoWordSolutions,
-          threeWordSolutions,
-          fourWordSolutions,
-          fiveWordSolutions,
-        } = wasm.solutions(start, end);
+        const isFinalResponse = stream.isDone;
 
         /** @type {SolutionsResponse} */
         const solutionsMessage = {
           type: "SolutionsResponse",
           requestId,
-          oneWordSolutions,
-          twoWordSolutions,
-          threeWordSolutions,
-          fourWordSolutions,
-          fiveWordSolutions,
-          isFinalResponse: index === sections - 1,
+          oneWordSolutions: payload?.oneWordSolutions ?? [],
+          twoWordSolutions: payload?.twoWordSolutions ?? [],
+          threeWordSolutions: payload?.threeWordSolutions ?? [],
+          fourWordSolutions: payload?.fourWordSolutions ?? [],
+          fiveWordSolutions: payload?.fiveWordSolutions ?? [],
+          progress: stream.progress,
+          isFinalResponse,
         };
 
         self.postMessage(solutionsMessage);
 
+        if (isFinalResponse) {
+          break;
+        }
+
         await waitForTick();
         if (requestId !== activeRequestId) {
-          // This request is no longer relevant.
+          // This request is no longer relevant, so stop searching before the next batch.
+          stream.cancel();
+          stream.free();
           return;
         }
+      }
+
+      stream.free();
+      break;
+    }
+
+    /**
+     * Emitted by the main thread once an input is found to have no solutions, to request the
+     * chains that come closest to solving it. The worker responds with a "PartialChainsResponse"
+     * message, containing each chain along with the letters that it leaves uncovered.
+     *
+     * @type {PartialChainsRequest}
+     */
+    case "PartialChainsRequest": {
+      if (!wasmInitialized) {
+        console.error("WASM module is not initialized");
+        return;
+      }
+
+      const { requestId, text, rule, forbiddenPairs } = data;
 
-        start = end;
+      let chains, missingLetters;
+      try {
+        const partialChains = wasm.partialChains(text, rule, forbiddenPairs);
+        ({ chains, missingLetters } = partialChains);
+        partialChains.free();
+      } catch (error) {
+        postSolverError(requestId, error);
+        return;
       }
 
-      wasm.clearValidWords();
+      /** @type {PartialChainsResponse} */
+      const payload = {
+        type: "PartialChainsResponse",
+        requestId,
+        chains,
+        missingLetters,
+      };
+
+      self.postMessage(payload);
       break;
     }
 